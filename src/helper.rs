@@ -1,14 +1,23 @@
 pub mod address;
 pub mod binary;
 pub mod color;
+pub(crate) mod conditional_format;
 pub mod const_str;
 pub mod coordinate;
 pub mod crypt;
+pub(crate) mod data_validation;
 pub mod date;
+pub(crate) mod expression;
 pub mod formula;
 pub mod html;
+#[cfg(feature = "json")]
+pub(crate) mod json;
 pub mod number_format;
 pub mod range;
+#[cfg(feature = "serde")]
+pub(crate) mod rows;
 pub mod string_helper;
+pub(crate) mod strict;
+pub(crate) mod structured_reference;
 pub mod time_zone;
 pub mod utils;
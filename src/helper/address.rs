@@ -81,6 +81,16 @@ pub fn is_address<S: AsRef<str>>(input: S) -> bool {
     regex.is_match(input.as_ref()).unwrap()
 }
 
+/// Checks if the given input string is a whole-column (`$A:$C`) or
+/// whole-row (`$1:$2`) reference, optionally prefixed by a sheet name.
+#[must_use]
+pub fn is_line_address<S: AsRef<str>>(input: S) -> bool {
+    let regex = compile_regex!(
+        r"^([^\:\\\?\[\]\/\*]+\!)?((\$?[A-Z]{1,3}\:\$?[A-Z]{1,3})|(\$?[0-9]+\:\$?[0-9]+))$"
+    );
+    regex.is_match(input.as_ref()).unwrap()
+}
+
 #[test]
 fn split_address_test() {
     assert_eq!(split_address("A1"), ("", "A1"));
@@ -90,6 +100,16 @@ fn split_address_test() {
     assert_eq!(split_address(r#"'she"et1'!A1:B2"#), (r#"she"et1"#, "A1:B2"));
 }
 
+#[test]
+fn is_line_address_test() {
+    assert!(is_line_address("$A:$C"));
+    assert!(is_line_address("A:A"));
+    assert!(is_line_address("$1:$2"));
+    assert!(is_line_address("'Sheet 1'!$1:$2"));
+    assert!(!is_line_address("A1:B2"));
+    assert!(!is_line_address("A:2"));
+}
+
 #[test]
 fn is_address_test() {
     assert!(is_address("A1"));
@@ -0,0 +1,628 @@
+//! Evaluation of conditional formatting rules.
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+};
+
+use chrono::{
+    Datelike,
+    Local,
+    NaiveDate,
+    TimeDelta,
+};
+
+use crate::{
+    helper::{
+        coordinate::{
+            coordinate_from_index,
+            index_from_coordinate,
+        },
+        date::excel_to_date_time_chrono,
+        expression::{
+            Resolver,
+            Value,
+            evaluate,
+        },
+        range::range_bounds,
+    },
+    structs::{
+        ARGB8,
+        Color,
+        ColorScale,
+        ConditionalFormatMatch,
+        ConditionalFormatValueObject,
+        ConditionalFormatValueObjectValues,
+        ConditionalFormatValues,
+        ConditionalFormatting,
+        ConditionalFormattingIcon,
+        ConditionalFormattingOperatorValues,
+        ConditionalFormattingRule,
+        DataBar,
+        IconSet,
+        TimePeriodValues,
+        Worksheet,
+    },
+};
+
+/// Values of the cells a conditional formatting applies to.
+#[derive(Debug, Default)]
+struct RangeValues {
+    numbers: Vec<f64>,
+    counts:  HashMap<String, usize>,
+}
+
+/// Evaluates the conditional formatting of a worksheet.
+/// Values of the ranges are collected once and reused for every cell.
+pub(crate) struct ConditionalFormatEvaluator<'a> {
+    worksheet: &'a Worksheet,
+    today:     NaiveDate,
+    ranges:    HashMap<usize, RangeValues>,
+}
+
+impl<'a> ConditionalFormatEvaluator<'a> {
+    pub(crate) fn new(worksheet: &'a Worksheet) -> Self {
+        Self {
+            worksheet,
+            today: Local::now().date_naive(),
+            ranges: HashMap::new(),
+        }
+    }
+
+    /// Get the rules that apply to a cell, in priority order.
+    /// Evaluation stops after the first matching rule with `stopIfTrue`.
+    pub(crate) fn matches(&mut self, col: u32, row: u32) -> Vec<ConditionalFormatMatch> {
+        let worksheet = self.worksheet;
+        let mut rules: Vec<(usize, &ConditionalFormatting, &ConditionalFormattingRule)> = worksheet
+            .conditional_formatting_collection()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| contains(v, col, row))
+            .flat_map(|(index, v)| {
+                v.conditional_collection()
+                    .iter()
+                    .map(move |rule| (index, v, rule))
+            })
+            .collect();
+        rules.sort_by_key(|(.., rule)| rule.priority());
+
+        let mut result = Vec::new();
+        for (index, conditional_formatting, rule) in rules {
+            self.ranges
+                .entry(index)
+                .or_insert_with(|| collect_range_values(worksheet, conditional_formatting));
+            let context = Context {
+                values: &self.ranges[&index],
+                origin: origin(conditional_formatting),
+                col,
+                row,
+            };
+            if let Some(v) = self.evaluate(rule, &context) {
+                let is_stop = rule.stop_if_true() && !v.is_unsupported();
+                result.push(v);
+                if is_stop {
+                    break;
+                }
+            }
+        }
+        result
+    }
+
+    fn evaluate(
+        &self,
+        rule: &ConditionalFormattingRule,
+        context: &Context,
+    ) -> Option<ConditionalFormatMatch> {
+        let value = self.cell_value(context.col, context.row);
+        let mut result = ConditionalFormatMatch::new(
+            coordinate_from_index(context.col, context.row),
+            rule.get_type().clone(),
+            rule.priority(),
+        );
+
+        let is_match = match rule.get_type() {
+            ConditionalFormatValues::CellIs => self.cell_is(rule, &value, context),
+            ConditionalFormatValues::Expression => {
+                let formula = rule.formula()?.address_str();
+                let Some(is_match) = self.expression(&formula, context) else {
+                    result.set_unsupported(true);
+                    return Some(result);
+                };
+                is_match
+            }
+            ConditionalFormatValues::Top10 => value
+                .number()
+                .is_some_and(|v| top10(rule, &context.values.numbers, v)),
+            ConditionalFormatValues::AboveAverage => value
+                .number()
+                .is_some_and(|v| above_average(rule, &context.values.numbers, v)),
+            ConditionalFormatValues::DuplicateValues => self.count(context) > 1,
+            ConditionalFormatValues::UniqueValues => self.count(context) == 1,
+            ConditionalFormatValues::ContainsText
+            | ConditionalFormatValues::NotContainsText
+            | ConditionalFormatValues::BeginsWith
+            | ConditionalFormatValues::EndsWith => {
+                !matches!(value, Value::Error) && self.text(rule, context)
+            }
+            ConditionalFormatValues::ContainsBlanks => self.is_blank(context),
+            ConditionalFormatValues::NotContainsBlanks => !self.is_blank(context),
+            ConditionalFormatValues::ContainsErrors => matches!(value, Value::Error),
+            ConditionalFormatValues::NotContainsErrors => !matches!(value, Value::Error),
+            ConditionalFormatValues::TimePeriod => value
+                .number()
+                .is_some_and(|v| self.time_period(rule.time_period(), v)),
+            ConditionalFormatValues::ColorScale => {
+                let color = rule
+                    .color_scale()
+                    .zip(value.number())
+                    .and_then(|(color_scale, v)| self.color_scale(color_scale, v, context))?;
+                result.set_color(color);
+                true
+            }
+            ConditionalFormatValues::DataBar => {
+                let percent = rule
+                    .data_bar()
+                    .zip(value.number())
+                    .and_then(|(data_bar, v)| self.data_bar(data_bar, v, context))?;
+                result.set_data_bar_percent(percent);
+                true
+            }
+            ConditionalFormatValues::IconSet => {
+                let (icon, index) = rule
+                    .icon_set()
+                    .zip(value.number())
+                    .and_then(|(icon_set, v)| self.icon_set(icon_set, v, context))?;
+                result.set_icon(icon, index);
+                true
+            }
+        };
+        if !is_match {
+            return None;
+        }
+
+        if let Some(v) = rule.style() {
+            result.set_style(v.clone());
+        }
+        Some(result)
+    }
+
+    fn cell_value(&self, col: u32, row: u32) -> Value {
+        self.worksheet.cell((col, row)).map_or(Value::Blank, |v| {
+            Value::from_raw(v.cell_value().raw_value())
+        })
+    }
+
+    fn cell_text(&self, col: u32, row: u32) -> String {
+        self.worksheet
+            .cell((col, row))
+            .map_or_else(String::new, |v| v.value().to_string())
+    }
+
+    /// Evaluate a formula of a rule for the cell of the context.
+    fn operand(&self, formula: &str, context: &Context) -> Option<Value> {
+        let resolver = CellResolver {
+            worksheet: self.worksheet,
+            context,
+        };
+        evaluate(formula, &resolver)
+    }
+
+    fn cell_is(&self, rule: &ConditionalFormattingRule, value: &Value, context: &Context) -> bool {
+        if matches!(value, Value::Error) {
+            return false;
+        }
+        let Some(first) = rule
+            .formula()
+            .and_then(|v| self.operand(&v.address_str(), context))
+        else {
+            return false;
+        };
+
+        match rule.operator() {
+            ConditionalFormattingOperatorValues::Between
+            | ConditionalFormattingOperatorValues::NotBetween => {
+                let Some(second) = rule
+                    .formula2()
+                    .and_then(|v| self.operand(&v.address_str(), context))
+                else {
+                    return false;
+                };
+                let (low, high) = if first.compare(&second) == Some(Ordering::Greater) {
+                    (second, first)
+                } else {
+                    (first, second)
+                };
+                let is_between = value.compare(&low).is_some_and(|v| v != Ordering::Less)
+                    && value.compare(&high).is_some_and(|v| v != Ordering::Greater);
+                is_between == (rule.operator() == &ConditionalFormattingOperatorValues::Between)
+            }
+            operator => value
+                .compare(&first)
+                .is_some_and(|v| is_ordering_match(operator, v)),
+        }
+    }
+
+    /// Evaluate the formula of an expression rule.
+    /// Returns `None` when the formula is not supported by the evaluator.
+    fn expression(&self, formula: &str, context: &Context) -> Option<bool> {
+        match self.operand(formula, context)? {
+            Value::Bool(v) => Some(v),
+            Value::Number(v) => Some(v != 0.0),
+            _ => Some(false),
+        }
+    }
+
+    fn count(&self, context: &Context) -> usize {
+        let text = self.cell_text(context.col, context.row).to_lowercase();
+        if text.is_empty() {
+            return 0;
+        }
+        context
+            .values
+            .counts
+            .get(&text)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn text(&self, rule: &ConditionalFormattingRule, context: &Context) -> bool {
+        let value = self.cell_text(context.col, context.row).to_lowercase();
+        let text = rule.text().to_lowercase();
+        match rule.get_type() {
+            ConditionalFormatValues::ContainsText => value.contains(&text),
+            ConditionalFormatValues::NotContainsText => !value.contains(&text),
+            ConditionalFormatValues::BeginsWith => value.starts_with(&text),
+            ConditionalFormatValues::EndsWith => value.ends_with(&text),
+            _ => false,
+        }
+    }
+
+    fn is_blank(&self, context: &Context) -> bool {
+        self.cell_text(context.col, context.row).trim().is_empty()
+    }
+
+    fn time_period(&self, time_period: &TimePeriodValues, value: f64) -> bool {
+        let date = excel_to_date_time_chrono(value).date();
+        let days = (date - self.today).num_days();
+        let week_start =
+            |v: NaiveDate| v - TimeDelta::days(i64::from(v.weekday().num_days_from_sunday()));
+        let weeks = (week_start(date) - week_start(self.today)).num_days() / 7;
+        let months = (i64::from(date.year()) - i64::from(self.today.year())) * 12
+            + i64::from(date.month())
+            - i64::from(self.today.month());
+
+        match time_period {
+            TimePeriodValues::Today => days == 0,
+            TimePeriodValues::Yesterday => days == -1,
+            TimePeriodValues::Tomorrow => days == 1,
+            TimePeriodValues::Last7Days => (-6..=0).contains(&days),
+            TimePeriodValues::ThisWeek => weeks == 0,
+            TimePeriodValues::LastWeek => weeks == -1,
+            TimePeriodValues::NextWeek => weeks == 1,
+            TimePeriodValues::ThisMonth => months == 0,
+            TimePeriodValues::LastMonth => months == -1,
+            TimePeriodValues::NextMonth => months == 1,
+        }
+    }
+
+    /// Get the number a threshold of a color scale, data bar or icon set
+    /// stands for.
+    fn threshold(&self, cfvo: &ConditionalFormatValueObject, context: &Context) -> Option<f64> {
+        let numbers = &context.values.numbers;
+        let min = *numbers.first()?;
+        let max = *numbers.last()?;
+        let number = || self.operand(cfvo.val(), context).and_then(|v| v.number());
+
+        match cfvo.get_type() {
+            ConditionalFormatValueObjectValues::Min => Some(min),
+            ConditionalFormatValueObjectValues::Max => Some(max),
+            ConditionalFormatValueObjectValues::AutoMin => Some(min.min(0.0)),
+            ConditionalFormatValueObjectValues::AutoMax => Some(max.max(0.0)),
+            ConditionalFormatValueObjectValues::Number
+            | ConditionalFormatValueObjectValues::Formula => number(),
+            ConditionalFormatValueObjectValues::Percent => {
+                number().map(|v| min + (max - min) * v / 100.0)
+            }
+            ConditionalFormatValueObjectValues::Percentile => {
+                number().map(|v| percentile(numbers, v / 100.0))
+            }
+        }
+    }
+
+    fn color_scale(
+        &self,
+        color_scale: &ColorScale,
+        value: f64,
+        context: &Context,
+    ) -> Option<Color> {
+        let cfvo_collection = color_scale.cfvo_collection();
+        let color_collection = color_scale.color_collection();
+        if cfvo_collection.len() < 2 || color_collection.len() < cfvo_collection.len() {
+            return None;
+        }
+        let thresholds = cfvo_collection
+            .iter()
+            .map(|v| self.threshold(v, context))
+            .collect::<Option<Vec<f64>>>()?;
+
+        let last = thresholds.len() - 1;
+        let argb = if value <= thresholds[0] {
+            color_collection[0].argb()
+        } else if value >= thresholds[last] {
+            color_collection[last].argb()
+        } else {
+            let index = thresholds
+                .windows(2)
+                .position(|v| value <= v[1])
+                .unwrap_or_default();
+            let (low, high) = (thresholds[index], thresholds[index + 1]);
+            let ratio = if high > low {
+                (value - low) / (high - low)
+            } else {
+                0.0
+            };
+            interpolate(
+                color_collection[index].argb(),
+                color_collection[index + 1].argb(),
+                ratio,
+            )
+        };
+
+        let mut color = Color::default();
+        color.set_argb(argb);
+        Some(color)
+    }
+
+    fn data_bar(&self, data_bar: &DataBar, value: f64, context: &Context) -> Option<f64> {
+        let cfvo_collection = data_bar.cfvo_collection();
+        if cfvo_collection.len() < 2 {
+            return None;
+        }
+        let min = self.threshold(&cfvo_collection[0], context)?;
+        let max = self.threshold(&cfvo_collection[1], context)?;
+        let ratio = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else if value >= max {
+            1.0
+        } else {
+            0.0
+        };
+        let min_length = f64::from(data_bar.min_length());
+        let max_length = f64::from(data_bar.max_length());
+        Some(min_length + (max_length - min_length) * ratio)
+    }
+
+    fn icon_set(
+        &self,
+        icon_set: &IconSet,
+        value: f64,
+        context: &Context,
+    ) -> Option<(ConditionalFormattingIcon, usize)> {
+        let cfvo_collection = icon_set.cfvo_collection();
+        if cfvo_collection.is_empty() {
+            return None;
+        }
+
+        // The first threshold is the lower bound of the first icon.
+        let mut position = 0;
+        for (index, cfvo) in cfvo_collection.iter().enumerate().skip(1) {
+            let threshold = self.threshold(cfvo, context)?;
+            if value > threshold || (cfvo.gte() && value >= threshold) {
+                position = index;
+            }
+        }
+
+        let index = if icon_set.reverse() {
+            cfvo_collection.len() - 1 - position
+        } else {
+            position
+        };
+        if let Some(v) = icon_set.custom_icons().get(index) {
+            return Some((v.clone(), index));
+        }
+        let icon = ConditionalFormattingIcon::new(
+            icon_set.icon_set().clone(),
+            u32::try_from(index).unwrap_or_default(),
+        );
+        Some((icon, index))
+    }
+}
+
+/// Cell being evaluated and the values of its range.
+struct Context<'a> {
+    values: &'a RangeValues,
+    origin: (u32, u32),
+    col:    u32,
+    row:    u32,
+}
+
+/// Resolves the references of a rule from the cell of the context.
+struct CellResolver<'a> {
+    worksheet: &'a Worksheet,
+    context:   &'a Context<'a>,
+}
+
+impl Resolver for CellResolver<'_> {
+    fn reference(&self, reference: &str) -> Option<Value> {
+        let reference = match reference.rsplit_once('!') {
+            Some((sheet_name, reference)) => {
+                let sheet_name = sheet_name.trim_matches('\'');
+                if !sheet_name.eq_ignore_ascii_case(self.worksheet.name()) {
+                    return None;
+                }
+                reference
+            }
+            None => reference,
+        };
+        if !is_cell_reference(reference) {
+            return None;
+        }
+        let (Some(ref_col), Some(ref_row), col_lock, row_lock) =
+            index_from_coordinate(reference.to_uppercase())
+        else {
+            return None;
+        };
+        let context = self.context;
+        let col = if col_lock == Some(true) {
+            ref_col
+        } else {
+            (ref_col + context.col)
+                .checked_sub(context.origin.0)
+                .filter(|v| *v > 0)?
+        };
+        let row = if row_lock == Some(true) {
+            ref_row
+        } else {
+            (ref_row + context.row)
+                .checked_sub(context.origin.1)
+                .filter(|v| *v > 0)?
+        };
+        Some(self.worksheet.cell((col, row)).map_or(Value::Blank, |v| {
+            Value::from_raw(v.cell_value().raw_value())
+        }))
+    }
+
+    fn position(&self) -> (u32, u32) {
+        (self.context.col, self.context.row)
+    }
+}
+
+fn contains(conditional_formatting: &ConditionalFormatting, col: u32, row: u32) -> bool {
+    conditional_formatting
+        .sequence_of_references()
+        .range_collection()
+        .iter()
+        .any(|range| {
+            let (col_start, row_start, col_end, row_end) = range_bounds(range);
+            (col_start..=col_end).contains(&col) && (row_start..=row_end).contains(&row)
+        })
+}
+
+fn origin(conditional_formatting: &ConditionalFormatting) -> (u32, u32) {
+    conditional_formatting
+        .sequence_of_references()
+        .range_collection()
+        .first()
+        .map_or((1, 1), |range| {
+            let (col, row, ..) = range_bounds(range);
+            (col, row)
+        })
+}
+
+fn collect_range_values(
+    worksheet: &Worksheet,
+    conditional_formatting: &ConditionalFormatting,
+) -> RangeValues {
+    let mut result = RangeValues::default();
+    for cell in worksheet.cells_crate().iter_collection() {
+        let coordinate = cell.coordinate();
+        if !contains(
+            conditional_formatting,
+            coordinate.col_num(),
+            coordinate.row_num(),
+        ) {
+            continue;
+        }
+        let raw_value = cell.cell_value().raw_value();
+        if let Some(v) = Value::from_raw(raw_value).number() {
+            result.numbers.push(v);
+        }
+        let text = raw_value.to_string().to_lowercase();
+        if !text.is_empty() {
+            *result.counts.entry(text).or_default() += 1;
+        }
+    }
+    result.numbers.sort_by(f64::total_cmp);
+    result
+}
+
+pub(super) fn is_cell_reference(value: &str) -> bool {
+    let value = value.strip_prefix('$').unwrap_or(value);
+    let letters = value.chars().take_while(char::is_ascii_alphabetic).count();
+    let rest = &value[letters..];
+    let rest = rest.strip_prefix('$').unwrap_or(rest);
+    letters > 0 && !rest.is_empty() && rest.chars().all(|v| v.is_ascii_digit())
+}
+
+fn is_ordering_match(operator: &ConditionalFormattingOperatorValues, ordering: Ordering) -> bool {
+    match operator {
+        ConditionalFormattingOperatorValues::Equal => ordering == Ordering::Equal,
+        ConditionalFormattingOperatorValues::NotEqual => ordering != Ordering::Equal,
+        ConditionalFormattingOperatorValues::GreaterThan => ordering == Ordering::Greater,
+        ConditionalFormattingOperatorValues::GreaterThanOrEqual => ordering != Ordering::Less,
+        ConditionalFormattingOperatorValues::LessThan => ordering == Ordering::Less,
+        ConditionalFormattingOperatorValues::LessThanOrEqual => ordering != Ordering::Greater,
+        _ => false,
+    }
+}
+
+fn top10(rule: &ConditionalFormattingRule, numbers: &[f64], value: f64) -> bool {
+    let count = numbers.len();
+    let rank = usize::try_from(rule.rank()).unwrap_or(usize::MAX);
+    let rank = if rule.percent() {
+        (count.saturating_mul(rank) / 100).max(1)
+    } else {
+        rank
+    }
+    .min(count);
+    if rank == 0 {
+        return false;
+    }
+    if rule.bottom() {
+        value <= numbers[rank - 1]
+    } else {
+        value >= numbers[count - rank]
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn above_average(rule: &ConditionalFormattingRule, numbers: &[f64], value: f64) -> bool {
+    if numbers.is_empty() {
+        return false;
+    }
+    let count = numbers.len() as f64;
+    let average = numbers.iter().sum::<f64>() / count;
+    let deviation = if rule.std_dev() > 0 && numbers.len() > 1 {
+        let variance = numbers.iter().map(|v| (v - average).powi(2)).sum::<f64>() / (count - 1.0);
+        variance.sqrt() * f64::from(rule.std_dev())
+    } else {
+        0.0
+    };
+
+    if rule.above_average() {
+        let threshold = average + deviation;
+        value > threshold || (rule.equal_average() && value >= threshold)
+    } else {
+        let threshold = average - deviation;
+        value < threshold || (rule.equal_average() && value <= threshold)
+    }
+}
+
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(numbers: &[f64], value: f64) -> f64 {
+    let rank = value.clamp(0.0, 1.0) * (numbers.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    match numbers.get(lower + 1) {
+        Some(upper) => numbers[lower] + (upper - numbers[lower]) * (rank - rank.floor()),
+        None => numbers[lower],
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn interpolate(from: ARGB8, to: ARGB8, ratio: f64) -> ARGB8 {
+    let channel = |from: u8, to: u8| {
+        let from = f64::from(from);
+        (from + (f64::from(to) - from) * ratio)
+            .round()
+            .clamp(0.0, 255.0) as u8
+    };
+    ARGB8 {
+        a: channel(from.a, to.a),
+        r: channel(from.r, to.r),
+        g: channel(from.g, to.g),
+        b: channel(from.b, to.b),
+    }
+}
@@ -7,9 +7,16 @@ macro_rules! declare_const_strings {
 }
 
 declare_const_strings! {
+    ACTIVEX_NS           => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/control",
+    ACTIVEX_TYPE         => "application/vnd.ms-office.activeX+xml",
+    ACTIVEX_BIN_NS       => "http://schemas.microsoft.com/office/2006/relationships/activeXControlBinary",
+    ACTIVEX_BIN_TYPE     => "application/vnd.ms-office.activeX",
+    CALC_CHAIN_NS        => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/calcChain",
     CERTIFICATE_NS       => "http://schemas.microsoft.com/office/2006/keyEncryptor/certificate",
     CHART_NS             => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
     CHART_TYPE           => "application/vnd.openxmlformats-officedocument.drawingml.chart+xml",
+    CHARTSHEET_NS        => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chartsheet",
+    CHARTSHEET_TYPE      => "application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml",
     COMMENTS_NS          => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments",
     COMMENTS_TYPE        => "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml",
     THREADED_COMMENT_NS  => "http://schemas.microsoft.com/office/2017/10/relationships/threadedComment",
@@ -17,6 +24,8 @@ declare_const_strings! {
     PERSION_NS           => "http://schemas.microsoft.com/office/2017/10/relationships/person",
     PKG_PERSON           => "xl/persons/person.xml",
     CONTYPES_NS          => "http://schemas.openxmlformats.org/package/2006/content-types",
+    CTRL_PROP_NS         => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/ctrlProp",
+    CTRL_PROP_TYPE       => "application/vnd.ms-excel.controlproperties+xml",
     CORE_PROPS_TYPE      => "application/vnd.openxmlformats-package.core-properties+xml",
     COREPROPS_NS         => "http://schemas.openxmlformats.org/package/2006/metadata/core-properties",
     COREPROPS_REL        => "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties",
@@ -54,14 +63,17 @@ declare_const_strings! {
     REL_TYPE             => "application/vnd.openxmlformats-package.relationships+xml",
     SHARED_STRINGS_NS    => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/sharedStrings",
     SHARED_STRINGS_TYPE  => "application/vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings+xml",
+    SHARED_STRINGS_BIN_TYPE => "application/vnd.ms-excel.sharedStrings",
     SHEET_DRAWING_NS     => "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
     SHEET_MAIN_NS        => "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
     SHEET_MS_MAIN_NS     => "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
     SHEET_MS_REVISION_NS => "http://schemas.microsoft.com/office/spreadsheetml/2014/revision",
     SHEET_TYPE           => "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml",
+    SHEET_BIN_TYPE       => "application/vnd.ms-excel.worksheet",
     SHEETML_AC_NS        => "http://schemas.microsoft.com/office/spreadsheetml/2009/9/ac",
     STYLES_NS            => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles",
     STYLES_TYPE          => "application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml",
+    STYLES_BIN_TYPE      => "application/vnd.ms-excel.styles",
     TABLE_NS             => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/table",
     PIVOT_TABLE_NS       => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotTable",
     TABLE_TYPE           => "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml",
@@ -79,21 +91,28 @@ declare_const_strings! {
     WORKBOOK             => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
     WORKBOOK_MACRO_TYPE  => "application/vnd.ms-excel.sheet.macroEnabled.main+xml",
     WORKBOOK_TYPE        => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml",
+    WORKBOOK_BIN_TYPE    => "application/vnd.ms-excel.sheet.binary.macroEnabled.main",
     WORKSHEET_NS         => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet",
     XPROPS_NS            => "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
     XPROPS_REL           => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties",
     XPROPS_TYPE          => "application/vnd.openxmlformats-officedocument.extended-properties+xml",
     XSI_NS               => "http://www.w3.org/2001/XMLSchema-instance",
+    PKG_ACTIVEX          => "xl/activeX",
     PKG_CHARTS           => "xl/charts",
+    PKG_CHARTSHEET       => "xl/chartsheets/sheet",
+    PKG_CHARTSHEET_RELS  => "xl/chartsheets/_rels/sheet",
+    PKG_CTRL_PROPS       => "xl/ctrlProps",
     PKG_DRAWINGS         => "xl/drawings",
     PKG_DRAWINGS_RELS    => "xl/drawings/_rels/drawing",
     PKG_EMBEDDINGS       => "xl/embeddings",
     PKG_MEDIA            => "xl/media",
     PKG_PRNTR_SETTINGS   => "xl/printerSettings",
     PKG_SHARED_STRINGS   => "xl/sharedStrings.xml",
+    PKG_SHARED_STRINGS_BIN => "xl/sharedStrings.bin",
     PKG_SHEET            => "xl/worksheets/sheet",
     PKG_SHEET_RELS       => "xl/worksheets/_rels/sheet",
     PKG_STYLES           => "xl/styles.xml",
+    PKG_STYLES_BIN       => "xl/styles.bin",
     PKG_TABLES           => "xl/tables",
     PKG_THEME            => "xl/theme/theme1.xml",
     PKG_VBA_PROJECT      => "xl/vbaProject.bin",
@@ -105,6 +124,25 @@ declare_const_strings! {
     PKG_PIVOT_CACHE_RELS => "xl/pivotCache/_rels/pivotCache",
     PKG_WORKBOOK         => "xl/workbook.xml",
     PKG_WORKBOOK_RELS    => "xl/_rels/workbook.xml.rels",
+    PKG_WORKBOOK_BIN     => "xl/workbook.bin",
+    PKG_WORKBOOK_BIN_RELS => "xl/_rels/workbook.bin.rels",
+
+    ODF_DRAW_NS          => "urn:oasis:names:tc:opendocument:xmlns:drawing:1.0",
+    ODF_FO_NS            => "urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0",
+    ODF_MANIFEST_NS      => "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0",
+    ODF_NUMBER_NS        => "urn:oasis:names:tc:opendocument:xmlns:datastyle:1.0",
+    ODF_OF_NS            => "urn:oasis:names:tc:opendocument:xmlns:of:1.2",
+    ODF_OFFICE_NS        => "urn:oasis:names:tc:opendocument:xmlns:office:1.0",
+    ODF_STYLE_NS         => "urn:oasis:names:tc:opendocument:xmlns:style:1.0",
+    ODF_SVG_NS           => "urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0",
+    ODF_TABLE_NS         => "urn:oasis:names:tc:opendocument:xmlns:table:1.0",
+    ODF_TEXT_NS          => "urn:oasis:names:tc:opendocument:xmlns:text:1.0",
+    ODF_XLINK_NS         => "http://www.w3.org/1999/xlink",
+    ODS_TYPE             => "application/vnd.oasis.opendocument.spreadsheet",
+    PKG_ODS_CONTENT      => "content.xml",
+    PKG_ODS_MANIFEST     => "META-INF/manifest.xml",
+    PKG_ODS_MIMETYPE     => "mimetype",
+    PKG_ODS_STYLES       => "styles.xml",
 
     ARC_APP              => "docProps/app.xml",
     ARC_CORE             => "docProps/core.xml",
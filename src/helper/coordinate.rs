@@ -408,6 +408,24 @@ impl CellCoordinates {
     fn new(col: u32, row: u32) -> Self {
         CellCoordinates { row, col }
     }
+
+    /// Parses an Excel-style coordinate (e.g., "A1" or "$B$2").
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: A string slice containing the cell coordinate.
+    ///
+    /// # Returns
+    ///
+    /// A `CellCoordinates` instance, or `None` if the coordinate has no
+    /// column or no row.
+    #[inline]
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match index_from_coordinate(value.to_uppercase()) {
+            (Some(col), Some(row), ..) => Some(Self::new(col, row)),
+            _ => None,
+        }
+    }
 }
 
 impl From<(u32, u32)> for CellCoordinates {
@@ -460,10 +478,13 @@ impl From<&str> for CellCoordinates {
     /// # Returns
     ///
     /// A `CellCoordinates` instance representing the specified coordinate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinate has no column or no row.
     #[inline]
     fn from(value: &str) -> Self {
-        let (col, row, ..) = index_from_coordinate(value.to_uppercase());
-        CellCoordinates::new(col.unwrap(), row.unwrap())
+        CellCoordinates::parse(value).unwrap_or_else(|| panic!("invalid cell coordinate '{value}'"))
     }
 }
 
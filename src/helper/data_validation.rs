@@ -0,0 +1,401 @@
+//! Evaluation of data validation rules.
+use std::cmp::Ordering;
+
+use super::{
+    conditional_format::is_cell_reference,
+    expression::{
+        Resolver,
+        Value,
+        evaluate,
+        string_literal,
+    },
+};
+use crate::{
+    helper::{
+        coordinate::{
+            coordinate_from_index,
+            index_from_coordinate,
+        },
+        range::range_bounds,
+    },
+    structs::{
+        DataValidationOperatorValues,
+        DataValidationValues,
+        ValidationResult,
+        Workbook,
+        Worksheet,
+    },
+};
+
+/// A data validation rule of either the main or the x14 list.
+#[derive(Debug)]
+struct ValidationRule {
+    r#type:        DataValidationValues,
+    operator:      DataValidationOperatorValues,
+    allow_blank:   bool,
+    formula1:      String,
+    formula2:      String,
+    error_title:   String,
+    error_message: String,
+    bounds:        Vec<(u32, u32, u32, u32)>,
+}
+
+impl ValidationRule {
+    fn contains(&self, col: u32, row: u32) -> bool {
+        self.bounds
+            .iter()
+            .any(|(col_start, row_start, col_end, row_end)| {
+                (*col_start..=*col_end).contains(&col) && (*row_start..=*row_end).contains(&row)
+            })
+    }
+
+    fn origin(&self) -> (u32, u32) {
+        self.bounds
+            .first()
+            .map_or((1, 1), |(col, row, ..)| (*col, *row))
+    }
+}
+
+/// Evaluates the data validation of a worksheet.
+/// With the workbook at hand, list sources and references on other worksheets
+/// and workbook defined names are resolved as well.
+pub(crate) struct DataValidationEvaluator<'a> {
+    worksheet: &'a Worksheet,
+    workbook:  Option<&'a Workbook>,
+    rules:     Vec<ValidationRule>,
+}
+
+impl<'a> DataValidationEvaluator<'a> {
+    pub(crate) fn new(worksheet: &'a Worksheet, workbook: Option<&'a Workbook>) -> Self {
+        let mut rules = Vec::new();
+        if let Some(data_validations) = worksheet.data_validations() {
+            for v in data_validations.data_validation_list() {
+                rules.push(ValidationRule {
+                    r#type:        v.get_type().clone(),
+                    operator:      if v.has_operator() {
+                        v.operator().clone()
+                    } else {
+                        DataValidationOperatorValues::Between
+                    },
+                    allow_blank:   v.allow_blank(),
+                    formula1:      v.formula1().to_string(),
+                    formula2:      v.formula2().to_string(),
+                    error_title:   v.error_title().to_string(),
+                    error_message: v.error_message().to_string(),
+                    bounds:        v
+                        .sequence_of_references()
+                        .range_collection()
+                        .iter()
+                        .map(range_bounds)
+                        .collect(),
+                });
+            }
+        }
+        if let Some(data_validations) = worksheet.data_validations_2010() {
+            for v in data_validations.data_validation_list() {
+                rules.push(ValidationRule {
+                    r#type:        v.get_type().clone(),
+                    operator:      if v.has_operator() {
+                        v.operator().clone()
+                    } else {
+                        DataValidationOperatorValues::Between
+                    },
+                    allow_blank:   v.allow_blank(),
+                    formula1:      v
+                        .formula1()
+                        .map(|f| f.value().value().address())
+                        .unwrap_or_default(),
+                    formula2:      v
+                        .formula2()
+                        .map(|f| f.value().value().address())
+                        .unwrap_or_default(),
+                    error_title:   String::new(),
+                    error_message: String::new(),
+                    bounds:        v.reference_sequence().value().iter().map(range_bounds).collect(),
+                });
+            }
+        }
+        Self {
+            worksheet,
+            workbook,
+            rules,
+        }
+    }
+
+    /// Get whether any rule applies to a cell.
+    pub(crate) fn applies_to(&self, col: u32, row: u32) -> bool {
+        self.rules.iter().any(|v| v.contains(col, row))
+    }
+
+    pub(crate) fn validate(&self, col: u32, row: u32) -> ValidationResult {
+        let cell = self.worksheet.cell((col, row));
+        let mut result = ValidationResult::new(
+            self.worksheet.name(),
+            coordinate_from_index(col, row),
+            cell.map_or_else(String::new, |v| v.value().to_string()),
+        );
+        let Some(rule) = self.rules.iter().find(|v| v.contains(col, row)) else {
+            return result;
+        };
+        result.set_rule(rule.r#type.clone(), &rule.error_title, &rule.error_message);
+
+        let value = cell.map_or(Value::Blank, |v| {
+            Value::from_raw(v.cell_value().raw_value())
+        });
+        if matches!(value, Value::Blank) || matches!(&value, Value::Text(v) if v.is_empty()) {
+            let is_valid = rule.allow_blank || rule.r#type == DataValidationValues::None;
+            result.set_valid(Some(is_valid));
+            return result;
+        }
+
+        let context = Context {
+            origin: rule.origin(),
+            col,
+            row,
+        };
+        let is_valid = match rule.r#type {
+            DataValidationValues::None => Some(true),
+            DataValidationValues::Whole => match value.number() {
+                Some(v) if v.fract() == 0.0 => self.compare(rule, v, &context),
+                _ => Some(false),
+            },
+            DataValidationValues::Decimal
+            | DataValidationValues::Date
+            | DataValidationValues::Time => match value.number() {
+                Some(v) => self.compare(rule, v, &context),
+                None => Some(false),
+            },
+            DataValidationValues::TextLength => {
+                let length = cell.map_or(0, |v| v.value().chars().count());
+                self.compare(
+                    rule,
+                    f64::from(u32::try_from(length).unwrap_or(u32::MAX)),
+                    &context,
+                )
+            }
+            DataValidationValues::List => self.list(&rule.formula1, &context).map(|list| {
+                list.iter()
+                    .any(|v| value.compare(v) == Some(Ordering::Equal))
+            }),
+            DataValidationValues::Custom => self.expression(&rule.formula1, &context),
+        };
+        result.set_valid(is_valid);
+        result
+    }
+
+    /// Compare a number with the bounds of a rule.
+    /// Returns `None` when a bound can not be evaluated.
+    fn compare(&self, rule: &ValidationRule, value: f64, context: &Context) -> Option<bool> {
+        let first = self.number(&rule.formula1, context)?;
+        match rule.operator {
+            DataValidationOperatorValues::Between | DataValidationOperatorValues::NotBetween => {
+                let second = self.number(&rule.formula2, context)?;
+                let (low, high) = if first > second {
+                    (second, first)
+                } else {
+                    (first, second)
+                };
+                let is_between = low <= value && value <= high;
+                Some(is_between == (rule.operator == DataValidationOperatorValues::Between))
+            }
+            DataValidationOperatorValues::Equal => Some(value.total_cmp(&first).is_eq()),
+            DataValidationOperatorValues::NotEqual => Some(value.total_cmp(&first).is_ne()),
+            DataValidationOperatorValues::GreaterThan => Some(value > first),
+            DataValidationOperatorValues::GreaterThanOrEqual => Some(value >= first),
+            DataValidationOperatorValues::LessThan => Some(value < first),
+            DataValidationOperatorValues::LessThanOrEqual => Some(value <= first),
+        }
+    }
+
+    /// Evaluate a bound of a rule to a number.
+    fn number(&self, formula: &str, context: &Context) -> Option<f64> {
+        match self.operand(formula, context)? {
+            Value::Number(v) => Some(v),
+            Value::Blank => Some(0.0),
+            _ => None,
+        }
+    }
+
+    /// Evaluate a formula of a rule for the cell of the context.
+    fn operand(&self, formula: &str, context: &Context) -> Option<Value> {
+        let resolver = CellResolver {
+            evaluator: self,
+            context,
+        };
+        evaluate(formula, &resolver)
+    }
+
+    /// Get the values of a list source: a literal list, a range or a
+    /// defined name.
+    fn list(&self, formula: &str, context: &Context) -> Option<Vec<Value>> {
+        if let Some(text) = string_literal(formula) {
+            return Some(
+                text.split(',')
+                    .map(|v| {
+                        v.parse::<f64>()
+                            .map_or_else(|_| Value::Text(v.to_string()), Value::Number)
+                    })
+                    .collect(),
+            );
+        }
+        self.reference(strip_formula(formula), context)
+    }
+
+    /// Evaluate the formula of a custom rule.
+    /// Returns `None` when the formula is not supported by the evaluator.
+    fn expression(&self, formula: &str, context: &Context) -> Option<bool> {
+        match self.operand(formula, context)? {
+            Value::Bool(v) => Some(v),
+            Value::Number(v) => Some(v != 0.0),
+            _ => Some(false),
+        }
+    }
+
+    /// Get the values of the cells of a reference or a defined name.
+    /// Relative references are shifted from the top left cell of the range.
+    fn reference(&self, formula: &str, context: &Context) -> Option<Vec<Value>> {
+        let (sheet_name, reference) = match formula.rsplit_once('!') {
+            Some((sheet_name, reference)) => (
+                Some(sheet_name.trim_matches('\'').replace("''", "'")),
+                reference,
+            ),
+            None => (None, formula),
+        };
+        let reference = reference.to_uppercase();
+        let mut points = reference.split(':');
+        let start = points.next()?;
+        let end = points.next().unwrap_or(start);
+        if points.next().is_some() {
+            return None;
+        }
+        let is_reference = if start == end {
+            is_cell_reference(start)
+        } else {
+            is_reference_point(start) && is_reference_point(end)
+        };
+        if !is_reference {
+            return if sheet_name.is_none() {
+                self.defined_name(formula)
+            } else {
+                None
+            };
+        }
+
+        let worksheet = match sheet_name {
+            Some(v) if !v.eq_ignore_ascii_case(self.worksheet.name()) => {
+                self.workbook?.sheet_by_name(&v).ok()?
+            }
+            _ => self.worksheet,
+        };
+        let (col_start, row_start) = point(start, context, worksheet, false)?;
+        let (col_end, row_end) = point(end, context, worksheet, true)?;
+
+        let mut result = Vec::new();
+        for row in row_start.min(row_end)..=row_start.max(row_end) {
+            for col in col_start.min(col_end)..=col_start.max(col_end) {
+                result.push(worksheet.cell((col, row)).map_or(Value::Blank, |v| {
+                    Value::from_raw(v.cell_value().raw_value())
+                }));
+            }
+        }
+        Some(result)
+    }
+
+    /// Resolve a defined name of the worksheet or of the workbook.
+    fn defined_name(&self, name: &str) -> Option<Vec<Value>> {
+        let local = self.worksheet.defined_names().iter();
+        let global = self.workbook.into_iter().flat_map(|workbook| {
+            workbook.defined_names().iter().chain(
+                workbook
+                    .sheet_collection_no_check()
+                    .iter()
+                    .flat_map(Worksheet::defined_names)
+                    .filter(|v| !v.has_local_sheet_id()),
+            )
+        });
+        let defined_name = local
+            .chain(global)
+            .find(|v| v.name().eq_ignore_ascii_case(name))?;
+        let address = defined_name.address();
+        if address.contains(',') {
+            return None;
+        }
+        let context = Context {
+            origin: (1, 1),
+            col:    1,
+            row:    1,
+        };
+        self.reference(strip_formula(&address), &context)
+    }
+}
+
+struct Context {
+    origin: (u32, u32),
+    col:    u32,
+    row:    u32,
+}
+
+/// Resolves the references of a rule from the cell of the context.
+struct CellResolver<'a> {
+    evaluator: &'a DataValidationEvaluator<'a>,
+    context:   &'a Context,
+}
+
+impl Resolver for CellResolver<'_> {
+    fn reference(&self, reference: &str) -> Option<Value> {
+        let mut values = self.evaluator.reference(reference, self.context)?;
+        if values.len() == 1 {
+            values.pop()
+        } else {
+            None
+        }
+    }
+
+    fn position(&self) -> (u32, u32) {
+        (self.context.col, self.context.row)
+    }
+}
+
+fn strip_formula(formula: &str) -> &str {
+    let formula = formula.trim();
+    formula.strip_prefix('=').unwrap_or(formula).trim()
+}
+
+/// Whether a value is a cell reference, or a column or row of a whole
+/// column or row reference.
+fn is_reference_point(value: &str) -> bool {
+    let value = value.trim_start_matches('$');
+    is_cell_reference(value)
+        || ((1..=3).contains(&value.len()) && value.chars().all(|v| v.is_ascii_alphabetic()))
+        || (!value.is_empty() && value.chars().all(|v| v.is_ascii_digit()))
+}
+
+/// Resolve one end of a reference to a cell.
+/// Whole columns and rows are limited to the used part of the worksheet.
+fn point(
+    value: &str,
+    context: &Context,
+    worksheet: &Worksheet,
+    is_end: bool,
+) -> Option<(u32, u32)> {
+    let (col, row, col_lock, row_lock) = index_from_coordinate(value);
+    let (highest_col, highest_row) = worksheet.highest_column_and_row();
+    let shift = |index: u32, lock: Option<bool>, current: u32, origin: u32| {
+        if lock == Some(true) {
+            Some(index)
+        } else {
+            (index + current).checked_sub(origin).filter(|v| *v > 0)
+        }
+    };
+    let col = match col {
+        Some(v) => shift(v, col_lock, context.col, context.origin.0)?,
+        None if is_end => highest_col.max(1),
+        None => 1,
+    };
+    let row = match row {
+        Some(v) => shift(v, row_lock, context.row, context.origin.1)?,
+        None if is_end => highest_row.max(1),
+        None => 1,
+    };
+    Some((col, row))
+}
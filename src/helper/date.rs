@@ -290,6 +290,41 @@ pub fn jiff_date_time_to_excel(value: jiff::civil::DateTime) -> f64 {
     duration.as_millis_f64() / (24.0 * 60.0 * 60.0 * 1000.0)
 }
 
+/// Converts an ISO 8601 value of a date cell (`t="d"`) to an Excel timestamp.
+///
+/// Accepts a date, a date with time or a time alone; a trailing `Z` is
+/// ignored. A time alone becomes the fraction of a day.
+/// Returns `None` when the value is not an ISO 8601 date or time.
+pub(crate) fn iso_8601_to_excel(value: &str) -> Option<f64> {
+    let value = value.trim().trim_end_matches('Z');
+    if let Ok(date_time) = value.parse::<jiff::civil::DateTime>() {
+        return Some(jiff_date_time_to_excel(date_time));
+    }
+    if let Ok(date) = value.parse::<jiff::civil::Date>() {
+        return Some(jiff_date_time_to_excel(date.to_datetime(jiff::civil::Time::midnight())));
+    }
+    let time = value.trim_start_matches('T').parse::<jiff::civil::Time>().ok()?;
+    let millis = (time - jiff::civil::Time::midnight())
+        .to_duration(jiff::SpanRelativeTo::days_are_24_hours())
+        .ok()?
+        .as_millis_f64();
+    Some(millis / (24.0 * 60.0 * 60.0 * 1000.0))
+}
+
+/// Converts an Excel timestamp to an ISO 8601 date, time or date with time,
+/// such as `2024-01-15`, `12:30:00` or `2024-01-15T12:30:00`.
+#[cfg(any(feature = "json", feature = "serde"))]
+pub(crate) fn excel_to_iso_8601(excel_timestamp: f64) -> String {
+    let date_time = excel_to_date_time_jiff(excel_timestamp);
+    if excel_timestamp < 1.0 {
+        date_time.time().to_string()
+    } else if excel_timestamp.fract() == 0.0 {
+        date_time.date().to_string()
+    } else {
+        date_time.to_string()
+    }
+}
+
 /// See docs for `excel_to_date_time_chrono` for details on how this function
 /// works. Note that the `time_zone` is not used and is ignored. Excel doesn't
 /// store associated timezone info with the dates.
@@ -0,0 +1,430 @@
+//! Evaluation of the formulas of conditional formatting and data validation
+//! rules.
+use std::cmp::Ordering;
+
+use crate::{
+    helper::{
+        date::jiff_date_time_to_excel,
+        formula::{
+            FormulaToken,
+            FormulaTokenSubTypes,
+            FormulaTokenTypes,
+            parse_to_tokens,
+        },
+    },
+    structs::CellRawValue,
+};
+
+/// A value taking part in a comparison.
+#[derive(Clone, Debug)]
+pub(super) enum Value {
+    Blank,
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error,
+}
+
+impl Value {
+    pub(super) fn from_raw(value: &CellRawValue) -> Self {
+        match value {
+            CellRawValue::Empty => Self::Blank,
+            CellRawValue::Numeric(v) => Self::Number(*v),
+            CellRawValue::Bool(v) => Self::Bool(*v),
+            CellRawValue::Error(_) => Self::Error,
+            CellRawValue::Lazy(v) => v
+                .parse::<f64>()
+                .map_or_else(|_| Self::Text(v.to_string()), Self::Number),
+            CellRawValue::String(_) | CellRawValue::RichText(_) => Self::Text(value.to_string()),
+        }
+    }
+
+    pub(super) fn number(&self) -> Option<f64> {
+        match self {
+            Self::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    // Excel sorts numbers before text and text before booleans.
+    fn type_order(&self) -> u8 {
+        match self {
+            Self::Text(_) => 1,
+            Self::Bool(_) => 2,
+            _ => 0,
+        }
+    }
+
+    // A blank cell compares as zero, an empty string or `FALSE`.
+    fn blank_of(&self) -> Self {
+        match self {
+            Self::Text(_) => Self::Text(String::new()),
+            Self::Bool(_) => Self::Bool(false),
+            _ => Self::Number(0.0),
+        }
+    }
+
+    pub(super) fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Error, _) | (_, Self::Error) => None,
+            (Self::Blank, Self::Blank) => Some(Ordering::Equal),
+            (Self::Blank, _) => other.blank_of().compare(other),
+            (_, Self::Blank) => self.compare(&self.blank_of()),
+            (Self::Number(a), Self::Number(b)) => a.partial_cmp(b),
+            (Self::Text(a), Self::Text(b)) => Some(a.to_lowercase().cmp(&b.to_lowercase())),
+            (Self::Bool(a), Self::Bool(b)) => Some(a.cmp(b)),
+            _ => Some(self.type_order().cmp(&other.type_order())),
+        }
+    }
+
+    // Conversions of the operands of operators and functions.
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Blank => Some(0.0),
+            Self::Number(v) => Some(*v),
+            Self::Text(v) => v.trim().parse::<f64>().ok(),
+            Self::Bool(v) => Some(f64::from(u8::from(*v))),
+            Self::Error => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Blank => Some(false),
+            Self::Number(v) => Some(*v != 0.0),
+            Self::Text(v) if v.eq_ignore_ascii_case("TRUE") => Some(true),
+            Self::Text(v) if v.eq_ignore_ascii_case("FALSE") => Some(false),
+            Self::Bool(v) => Some(*v),
+            Self::Text(_) | Self::Error => None,
+        }
+    }
+
+    fn as_text(&self) -> Option<String> {
+        match self {
+            Self::Blank => Some(String::new()),
+            Self::Number(v) => Some(v.to_string()),
+            Self::Text(v) => Some(v.clone()),
+            Self::Bool(v) => Some(if *v { "TRUE" } else { "FALSE" }.to_string()),
+            Self::Error => None,
+        }
+    }
+}
+
+/// Resolves the references of a formula.
+pub(super) trait Resolver {
+    /// Get the value of a reference to a single cell.
+    /// Relative references are shifted to the cell being evaluated.
+    fn reference(&self, reference: &str) -> Option<Value>;
+
+    /// Get the column and row of the cell being evaluated.
+    fn position(&self) -> (u32, u32);
+}
+
+/// Evaluate a formula such as `MOD(ROW(),2)=0` or `AND($A1>0,$B1<>"")`.
+///
+/// Operators, constants, references to single cells and a small set of
+/// functions are supported. Returns `None` when the formula uses anything
+/// else, so that callers can tell an unsupported formula from a false one.
+pub(super) fn evaluate<R: Resolver>(formula: &str, resolver: &R) -> Option<Value> {
+    let tokens = tokens(formula);
+    let mut parser = Parser {
+        tokens: &tokens,
+        position: 0,
+        resolver,
+    };
+    let value = parser.expression(0)?;
+    (parser.position == tokens.len()).then_some(value)
+}
+
+/// Get the text of a formula made of a single string literal.
+/// ex) `"a,b"` gives `a,b`.
+pub(super) fn string_literal(formula: &str) -> Option<String> {
+    match &tokens(formula)[..] {
+        [token]
+            if token.get_token_type() == &FormulaTokenTypes::Operand
+                && token.get_token_sub_type() == &FormulaTokenSubTypes::Text =>
+        {
+            Some(token.get_value().to_string())
+        }
+        _ => None,
+    }
+}
+
+fn tokens(formula: &str) -> Vec<FormulaToken> {
+    let formula = formula.trim();
+    let formula = formula.strip_prefix('=').unwrap_or(formula).trim();
+    if formula.is_empty() {
+        return Vec::new();
+    }
+    parse_to_tokens(format!("={formula}"))
+        .into_iter()
+        .filter(|v| v.get_token_type() != &FormulaTokenTypes::Whitespace)
+        .collect()
+}
+
+/// Precedence climbing parser over the tokens of a formula.
+struct Parser<'a, R> {
+    tokens:   &'a [FormulaToken],
+    position: usize,
+    resolver: &'a R,
+}
+
+impl<'a, R: Resolver> Parser<'a, R> {
+    fn peek(&self) -> Option<&'a FormulaToken> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&'a FormulaToken> {
+        let token = self.peek()?;
+        self.position += 1;
+        Some(token)
+    }
+
+    /// Skip the closing token of a function or a subexpression.
+    fn stop(&mut self, token_type: &FormulaTokenTypes) -> bool {
+        let is_stop = self.peek().is_some_and(|v| {
+            v.get_token_type() == token_type
+                && v.get_token_sub_type() == &FormulaTokenSubTypes::Stop
+        });
+        if is_stop {
+            self.position += 1;
+        }
+        is_stop
+    }
+
+    /// Parse operators binding at least as tight as `precedence`.
+    fn expression(&mut self, precedence: u8) -> Option<Value> {
+        let mut left = self.unary()?;
+        while let Some(token) = self.peek() {
+            if token.get_token_type() != &FormulaTokenTypes::OperatorInfix {
+                break;
+            }
+            let operator = token.get_value();
+            let Some(operator_precedence) = infix_precedence(operator) else {
+                break;
+            };
+            if operator_precedence < precedence {
+                break;
+            }
+            self.position += 1;
+            let right = self.expression(operator_precedence + 1)?;
+            left = infix(operator, &left, &right);
+        }
+        Some(left)
+    }
+
+    // Negation binds tighter than `^` in Excel, so `-2^2` is 4.
+    fn unary(&mut self) -> Option<Value> {
+        let token = self.peek()?;
+        if token.get_token_type() == &FormulaTokenTypes::OperatorPrefix {
+            self.position += 1;
+            let value = self.unary()?;
+            return Some(match token.get_value() {
+                "-" => value
+                    .as_number()
+                    .map_or(Value::Error, |v| Value::Number(-v)),
+                _ => value,
+            });
+        }
+
+        let mut value = self.primary()?;
+        while self
+            .peek()
+            .is_some_and(|v| v.get_token_type() == &FormulaTokenTypes::OperatorPostfix)
+        {
+            self.position += 1;
+            value = value
+                .as_number()
+                .map_or(Value::Error, |v| Value::Number(v / 100.0));
+        }
+        Some(value)
+    }
+
+    fn primary(&mut self) -> Option<Value> {
+        let token = self.next()?;
+        let value = token.get_value();
+        match (token.get_token_type(), token.get_token_sub_type()) {
+            (FormulaTokenTypes::Operand, FormulaTokenSubTypes::Number) => {
+                value.parse::<f64>().ok().map(Value::Number)
+            }
+            (FormulaTokenTypes::Operand, FormulaTokenSubTypes::Text) => {
+                Some(Value::Text(value.to_string()))
+            }
+            (FormulaTokenTypes::Operand, FormulaTokenSubTypes::Logical) => {
+                Some(Value::Bool(value.eq_ignore_ascii_case("TRUE")))
+            }
+            (FormulaTokenTypes::Operand, FormulaTokenSubTypes::Error) => Some(Value::Error),
+            (FormulaTokenTypes::Operand, _) => self.resolver.reference(value),
+            (FormulaTokenTypes::Subexpression, FormulaTokenSubTypes::Start) => {
+                let value = self.expression(0)?;
+                self.stop(&FormulaTokenTypes::Subexpression)
+                    .then_some(value)
+            }
+            (FormulaTokenTypes::Function, FormulaTokenSubTypes::Start) => {
+                let name = value.to_uppercase();
+                let name = name.strip_prefix("_XLFN.").unwrap_or(&name);
+                let arguments = self.arguments()?;
+                function(name, &arguments, self.resolver)
+            }
+            _ => None,
+        }
+    }
+
+    fn arguments(&mut self) -> Option<Vec<Value>> {
+        let mut result = Vec::new();
+        if self.stop(&FormulaTokenTypes::Function) {
+            return Some(result);
+        }
+        loop {
+            result.push(self.expression(0)?);
+            if self.stop(&FormulaTokenTypes::Function) {
+                return Some(result);
+            }
+            let token = self.next()?;
+            if token.get_token_type() != &FormulaTokenTypes::Argument && token.get_value() != "," {
+                return None;
+            }
+        }
+    }
+}
+
+fn infix_precedence(operator: &str) -> Option<u8> {
+    match operator {
+        "=" | "<>" | "<" | ">" | "<=" | ">=" => Some(1),
+        "&" => Some(2),
+        "+" | "-" => Some(3),
+        "*" | "/" => Some(4),
+        "^" => Some(5),
+        _ => None,
+    }
+}
+
+fn infix(operator: &str, left: &Value, right: &Value) -> Value {
+    match operator {
+        "&" => match (left.as_text(), right.as_text()) {
+            (Some(left), Some(right)) => Value::Text(left + &right),
+            _ => Value::Error,
+        },
+        "=" | "<>" | "<" | ">" | "<=" | ">=" => left.compare(right).map_or(Value::Error, |v| {
+            Value::Bool(match operator {
+                "=" => v.is_eq(),
+                "<>" => v.is_ne(),
+                "<" => v.is_lt(),
+                ">" => v.is_gt(),
+                "<=" => v.is_le(),
+                _ => v.is_ge(),
+            })
+        }),
+        _ => {
+            let (Some(left), Some(right)) = (left.as_number(), right.as_number()) else {
+                return Value::Error;
+            };
+            let value = match operator {
+                "+" => left + right,
+                "-" => left - right,
+                "*" => left * right,
+                "/" => left / right,
+                _ => left.powf(right),
+            };
+            if value.is_finite() {
+                Value::Number(value)
+            } else {
+                Value::Error
+            }
+        }
+    }
+}
+
+fn function<R: Resolver>(name: &str, arguments: &[Value], resolver: &R) -> Option<Value> {
+    let number = |value: &Value, f: fn(f64) -> f64| {
+        value
+            .as_number()
+            .map_or(Value::Error, |v| Value::Number(f(v)))
+    };
+    let value = match (name, arguments) {
+        ("TRUE", []) => Value::Bool(true),
+        ("FALSE", []) => Value::Bool(false),
+        ("ROW", []) => Value::Number(f64::from(resolver.position().1)),
+        ("COLUMN", []) => Value::Number(f64::from(resolver.position().0)),
+        ("AND", [_, ..]) => arguments
+            .iter()
+            .map(Value::as_bool)
+            .collect::<Option<Vec<bool>>>()
+            .map_or(Value::Error, |v| Value::Bool(v.into_iter().all(|v| v))),
+        ("OR", [_, ..]) => arguments
+            .iter()
+            .map(Value::as_bool)
+            .collect::<Option<Vec<bool>>>()
+            .map_or(Value::Error, |v| Value::Bool(v.into_iter().any(|v| v))),
+        ("NOT", [value]) => value.as_bool().map_or(Value::Error, |v| Value::Bool(!v)),
+        ("IF", [condition, then, otherwise @ ..]) if otherwise.len() <= 1 => {
+            match condition.as_bool() {
+                Some(true) => then.clone(),
+                Some(false) => otherwise.first().cloned().unwrap_or(Value::Bool(false)),
+                None => Value::Error,
+            }
+        }
+        ("MOD", [value, divisor]) => match (value.as_number(), divisor.as_number()) {
+            (Some(value), Some(divisor)) if divisor != 0.0 => {
+                Value::Number(value - divisor * (value / divisor).floor())
+            }
+            _ => Value::Error,
+        },
+        ("ABS", [value]) => number(value, f64::abs),
+        ("INT", [value]) => number(value, f64::floor),
+        ("LEN", [value]) => value.as_text().map_or(Value::Error, |v| {
+            Value::Number(f64::from(
+                u32::try_from(v.chars().count()).unwrap_or(u32::MAX),
+            ))
+        }),
+        ("ISBLANK", [value]) => Value::Bool(matches!(value, Value::Blank)),
+        ("ISNUMBER", [value]) => Value::Bool(matches!(value, Value::Number(_))),
+        ("ISTEXT", [value]) => Value::Bool(matches!(value, Value::Text(_))),
+        ("ISERROR", [value]) => Value::Bool(matches!(value, Value::Error)),
+        ("DATE", [year, month, day]) => date(year, month, day).unwrap_or(Value::Error),
+        ("TIME", [hour, minute, second]) => time(hour, minute, second).unwrap_or(Value::Error),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn integer(value: &Value) -> Option<i64> {
+    value.as_number().and_then(|v| num_traits::cast(v.trunc()))
+}
+
+// Months and days out of their range roll over into the next year or month.
+fn date(year: &Value, month: &Value, day: &Value) -> Option<Value> {
+    let (year, month, day) = (integer(year)?, integer(month)?, integer(day)?);
+    let year = if (0..1900).contains(&year) {
+        year + 1900
+    } else {
+        year
+    };
+    let date = jiff::civil::Date::new(i16::try_from(year).ok()?, 1, 1)
+        .ok()?
+        .checked_add(jiff::Span::new().try_months(month - 1).ok()?)
+        .ok()?
+        .checked_add(jiff::Span::new().try_days(day - 1).ok()?)
+        .ok()?;
+    Some(Value::Number(jiff_date_time_to_excel(
+        date.to_datetime(jiff::civil::Time::midnight()),
+    )))
+}
+
+// Times of 24 hours or more wrap around to the next day. Like Excel, parts
+// above 32767 are an error.
+fn time(hour: &Value, minute: &Value, second: &Value) -> Option<Value> {
+    let (hour, minute, second) = (integer(hour)?, integer(minute)?, integer(second)?);
+    if hour.max(minute).max(second) > 32767 {
+        return None;
+    }
+    let seconds = hour
+        .checked_mul(3600)?
+        .checked_add(minute.checked_mul(60)?)?
+        .checked_add(second)?;
+    if seconds < 0 {
+        return None;
+    }
+    let seconds = u32::try_from(seconds % 86400).ok()?;
+    Some(Value::Number(f64::from(seconds) / 86400.0))
+}
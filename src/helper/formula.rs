@@ -1064,6 +1064,73 @@ pub fn adjustment_remove_formula_coordinate(
     render(token_list)
 }
 
+/// Replace the sheet name of the references to a sheet in a formula.
+/// # Arguments
+/// * `formula` - Formula without the leading `=`.
+/// * `old_name` - Current name of the sheet. (not case sensitive)
+/// * `new_name` - New name of the sheet.
+pub(crate) fn rename_sheet_in_formula(formula: &str, old_name: &str, new_name: &str) -> String {
+    replace_sheet_reference(formula, old_name, Some(new_name))
+}
+
+/// Turn the references to a removed sheet in a formula into `#REF!`.
+/// # Arguments
+/// * `formula` - Formula without the leading `=`.
+/// * `sheet_name` - Name of the removed sheet. (not case sensitive)
+pub(crate) fn remove_sheet_in_formula(formula: &str, sheet_name: &str) -> String {
+    replace_sheet_reference(formula, sheet_name, None)
+}
+
+fn replace_sheet_reference(formula: &str, sheet_name: &str, new_name: Option<&str>) -> String {
+    if !formula.contains('!') {
+        return formula.to_string();
+    }
+    let mut token_list = parse_to_tokens(format!("={formula}"));
+    let mut is_changed = false;
+    for token in &mut token_list {
+        if token.get_token_type() != &FormulaTokenTypes::Operand
+            || token.get_token_sub_type() != &FormulaTokenSubTypes::Range
+        {
+            continue;
+        }
+        let (sheet, range) = split_address(token.get_value());
+        // a 3-D reference such as `Sheet1:Sheet3!A1` names two sheets
+        let sheets: Vec<String> = sheet.split(':').map(|v| v.replace("''", "'")).collect();
+        if sheet.is_empty() || !sheets.iter().any(|v| is_same_sheet_name(v, sheet_name)) {
+            continue;
+        }
+        let new_value = match new_name {
+            Some(new_name) => {
+                let sheets: Vec<&str> = sheets
+                    .iter()
+                    .map(|v| {
+                        if is_same_sheet_name(v, sheet_name) {
+                            new_name
+                        } else {
+                            v.as_str()
+                        }
+                    })
+                    .collect();
+                join_address(&sheets.join(":"), range)
+            }
+            None => format!("#REF!{range}"),
+        };
+        token.set_value(new_value);
+        is_changed = true;
+    }
+    if is_changed {
+        render(&token_list)
+    } else {
+        formula.to_string()
+    }
+}
+
+/// Sheet names are compared without regard to case.
+#[inline]
+pub(crate) fn is_same_sheet_name(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1136,6 +1203,34 @@ mod tests {
         assert_eq!(adjusted_formula("=SUM(A:B)", -1, 0), "SUM(#REF!)");
         assert_eq!(adjusted_formula("=SUM(1:3)", 0, -1), "SUM(#REF!)");
     }
+
+    #[test]
+    fn rename_sheet_test() {
+        assert_eq!(
+            rename_sheet_in_formula(
+                "SUM(Sheet1!A1:B2)+sheet1!C3+'Sheet10'!A1",
+                "Sheet1",
+                "Data 1"
+            ),
+            "SUM('Data 1'!A1:B2)+'Data 1'!C3+'Sheet10'!A1"
+        );
+        assert_eq!(
+            rename_sheet_in_formula("SUM('It''s'!A1,Sheet1:Sheet3!B2)", "Sheet3", "End"),
+            "SUM('It''s'!A1,'Sheet1:End'!B2)"
+        );
+        assert_eq!(
+            rename_sheet_in_formula("\"Sheet1!A1\"&A1", "Sheet1", "Data"),
+            "\"Sheet1!A1\"&A1"
+        );
+    }
+
+    #[test]
+    fn remove_sheet_test() {
+        assert_eq!(
+            remove_sheet_in_formula("Sheet2!$A$1*2+'My Sheet'!B2", "My Sheet"),
+            "Sheet2!$A$1*2+#REF!B2"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     error::Error,
     fmt,
+    fmt::Write,
 };
 
 use html5gum::{
@@ -45,6 +46,98 @@ pub fn html_to_richtext(html: &str) -> Result<RichText, HtmlParseError> {
     html_to_richtext_custom(html, &DataAnalysis::default())
 }
 
+/// Generate html from rich text.
+/// The result can be read back with `html_to_richtext`.
+/// # Arguments
+/// * `rich_text` - Rich text.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::{
+///     helper::html::richtext_to_html,
+///     structs::RichText,
+/// };
+///
+/// let rich_text = RichText::builder()
+///     .text("Total: ")
+///     .text("42")
+///     .bold()
+///     .color("FF0000")
+///     .build();
+/// assert_eq!(
+///     richtext_to_html(&rich_text),
+///     r##"Total: <font color="#FF0000"><b>42</b></font>"##
+/// );
+/// ```
+#[must_use]
+pub fn richtext_to_html(rich_text: &RichText) -> String {
+    let mut result = String::new();
+    for element in rich_text.rich_text_elements() {
+        let mut close: Vec<&str> = Vec::new();
+        if let Some(font) = element.font() {
+            let mut attributes = String::new();
+            if !font.name().is_empty() {
+                write!(attributes, r#" face="{}""#, escape_html(font.name())).unwrap();
+            }
+            if font.size() > 0.0 {
+                write!(attributes, r#" style="font-size:{}pt""#, font.size()).unwrap();
+            }
+            let argb = font.color().argb_str();
+            if argb != "00000000" {
+                write!(attributes, r##" color="#{}""##, &argb[2..]).unwrap();
+            }
+            if !attributes.is_empty() {
+                write!(result, "<font{attributes}>").unwrap();
+                close.push("font");
+            }
+            let mut tag = |name: &'static str| {
+                write!(result, "<{name}>").unwrap();
+                close.push(name);
+            };
+            if font.bold() {
+                tag("b");
+            }
+            if font.italic() {
+                tag("i");
+            }
+            if *font.font_underline().val() != UnderlineValues::None {
+                tag("u");
+            }
+            if font.strikethrough() {
+                tag("del");
+            }
+            match font.vertical_text_alignment().val() {
+                VerticalAlignmentRunValues::Superscript => tag("sup"),
+                VerticalAlignmentRunValues::Subscript => tag("sub"),
+                VerticalAlignmentRunValues::Baseline => (),
+            }
+        }
+        result.push_str(&escape_html(element.text()).replace('\n', "<br>"));
+        for name in close.iter().rev() {
+            write!(result, "</{name}>").unwrap();
+        }
+    }
+    result
+}
+
+/// The point size of a `font-size:14pt` declaration in a style attribute.
+fn font_size_from_style(style: &str) -> Option<f64> {
+    style.split(';').find_map(|declaration| {
+        let (name, value) = declaration.split_once(':')?;
+        if !name.trim().eq_ignore_ascii_case("font-size") {
+            return None;
+        }
+        value.trim().strip_suffix("pt")?.trim().parse().ok()
+    })
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 /// Use here for custom html parsing.
 /// # Arguments
 /// * `html` - HTML String.
@@ -312,6 +405,11 @@ impl AnalysisMethod for DataAnalysis {
             element
                 .get_by_name_and_attribute("font", "size")
                 .and_then(|v| v.parse::<f64>().ok())
+                .or_else(|| {
+                    element
+                        .get_by_name_and_attribute("font", "style")
+                        .and_then(font_size_from_style)
+                })
         })
     }
 
@@ -952,4 +1050,13 @@ mod tests {
             &VerticalAlignmentRunValues::Subscript
         );
     }
+
+    #[test]
+    fn html_to_richtext_reads_the_font_size_of_a_style() {
+        let html = r#"<font style="color: red; font-size: 10.5pt">A</font>"#;
+        let rich_text = html_to_richtext(html).unwrap();
+
+        let font = rich_text.rich_text_elements()[0].font().unwrap();
+        assert!((font.size() - 10.5).abs() < f64::EPSILON);
+    }
 }
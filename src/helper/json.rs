@@ -0,0 +1,288 @@
+//! Conversion between the cells of a worksheet and JSON records.
+
+use std::{
+    collections::BTreeMap,
+    io,
+};
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::{
+    helper::{
+        coordinate::{
+            index_from_coordinate,
+            string_from_column_index,
+        },
+        date::{
+            excel_to_iso_8601,
+            iso_8601_to_excel,
+        },
+        number_format::is_date_time_format,
+    },
+    structs::{
+        Cell,
+        CellRawValue,
+        CellValue,
+        ColumnSpec,
+        RecordColumn,
+        RecordsOption,
+        Style,
+        Worksheet,
+        XlsxError,
+    },
+};
+
+/// The largest integer that a `f64` holds exactly.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+pub(crate) fn to_records(
+    worksheet: &Worksheet,
+    header_row: u32,
+    range: &str,
+    option: &RecordsOption,
+) -> Result<Vec<Map<String, Value>>, XlsxError> {
+    let (row_start, row_end, col_start, col_end) = range_bounds(range)?;
+    let (highest_col, highest_row) = worksheet.highest_column_and_row();
+    let col_start = col_start.max(1);
+    let col_end = if col_end == 0 { highest_col } else { col_end };
+    let row_start = row_start.max(header_row + 1);
+    let row_end = if row_end == 0 { highest_row } else { row_end };
+
+    let keys: Vec<(u32, String)> = (col_start..=col_end)
+        .map(|col| (col, header_key(worksheet.cell((col, header_row)), col)))
+        .collect();
+    let records = (row_start..=row_end)
+        .filter_map(|row| {
+            let record: Map<String, Value> = keys
+                .iter()
+                .map(|(col, key)| {
+                    let value = worksheet
+                        .cell((*col, row))
+                        .map_or(Value::Null, |v| cell_to_value(v, option));
+                    (key.clone(), value)
+                })
+                .collect();
+            record.values().any(|v| !v.is_null()).then_some(record)
+        })
+        .collect();
+    Ok(records)
+}
+
+pub(crate) fn from_records(
+    worksheet: &mut Worksheet,
+    records: &[Value],
+    spec: &ColumnSpec,
+) -> Result<(), XlsxError> {
+    let mut objects = Vec::with_capacity(records.len());
+    for (index, record) in records.iter().enumerate() {
+        let Value::Object(v) = record else {
+            return Err(XlsxError::CellError(format!(
+                "Record {index} is not a JSON object."
+            )));
+        };
+        objects.push(v);
+    }
+
+    let columns = if spec.columns().is_empty() {
+        let mut keys: Vec<&str> = Vec::new();
+        for key in objects.iter().flat_map(|v| v.keys()) {
+            if !keys.contains(&key.as_str()) {
+                keys.push(key);
+            }
+        }
+        keys.into_iter()
+            .map(|key| {
+                let mut column = RecordColumn::default();
+                column.set_key(key);
+                column
+            })
+            .collect()
+    } else {
+        spec.columns().to_vec()
+    };
+
+    for (col, column) in (1u32..).zip(&columns) {
+        let cell = worksheet.cell_mut((col, 1));
+        cell.set_value_string(column.header());
+        if let Some(style) = spec.header_style() {
+            cell.set_style(style.clone());
+        }
+        let is_date = column.number_format().is_some_and(is_date_time_format);
+        for (row, record) in (2u32..).zip(&objects) {
+            let Some(value) = record.get(column.key()).filter(|v| !v.is_null()) else {
+                continue;
+            };
+            let cell = worksheet.cell_mut((col, row));
+            set_cell_value(cell, value, is_date);
+            if let Some(format_code) = column.number_format() {
+                cell.style_mut()
+                    .number_format_mut()
+                    .set_format_code(format_code);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write the records of a streamed worksheet as NDJSON, one line for each
+/// row below the header row.
+pub(crate) struct RecordStream<'a, W: io::Write> {
+    header_row: u32,
+    option:     &'a RecordsOption,
+    writer:     W,
+    headers:    BTreeMap<u32, String>,
+    row:        u32,
+    record:     Map<String, Value>,
+    result:     Result<(), XlsxError>,
+}
+
+impl<'a, W: io::Write> RecordStream<'a, W> {
+    pub(crate) fn new(header_row: u32, option: &'a RecordsOption, writer: W) -> Self {
+        Self {
+            header_row,
+            option,
+            writer,
+            headers: BTreeMap::new(),
+            row: 0,
+            record: Map::new(),
+            result: Ok(()),
+        }
+    }
+
+    /// Add a cell, the cells come sorted by row.
+    pub(crate) fn push(&mut self, cell: &Cell) {
+        let col = cell.coordinate().col_num();
+        let row = cell.coordinate().row_num();
+        if self.result.is_err() || row < self.header_row {
+            return;
+        }
+        if row == self.header_row {
+            self.headers.insert(col, header_key(Some(cell), col));
+            return;
+        }
+        if row != self.row {
+            self.result = self.flush();
+            self.row = row;
+        }
+        let value = cell_to_value(cell, self.option);
+        if value.is_null() {
+            return;
+        }
+        let key = self
+            .headers
+            .get(&col)
+            .cloned()
+            .unwrap_or_else(|| string_from_column_index(col));
+        self.record.insert(key, value);
+    }
+
+    pub(crate) fn finish(mut self) -> Result<(), XlsxError> {
+        std::mem::replace(&mut self.result, Ok(()))?;
+        self.flush()?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), XlsxError> {
+        if self.record.is_empty() {
+            return Ok(());
+        }
+        let mut record: Map<String, Value> = self
+            .headers
+            .values()
+            .map(|key| (key.clone(), Value::Null))
+            .collect();
+        record.append(&mut self.record);
+        serde_json::to_writer(&mut self.writer, &record).map_err(io::Error::from)?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// Rows and columns of a range such as `A1:D100`, `A:D` or `2:10`, 0 where
+/// the range is not bounded.
+fn range_bounds(range: &str) -> Result<(u32, u32, u32, u32), XlsxError> {
+    let check = |start: Option<u32>, end: Option<u32>, max: u32| match (start, end) {
+        (Some(start), Some(end)) if 1 <= start && start <= end && end <= max => Ok((start, end)),
+        (None, None) => Ok((0, 0)),
+        _ => Err(XlsxError::InvalidRange(range.to_string())),
+    };
+    let upper_range = range.to_uppercase();
+    let (first, last) = upper_range
+        .split_once(':')
+        .unwrap_or((&upper_range, &upper_range));
+    let (col_start, row_start, ..) = index_from_coordinate(first);
+    let (col_end, row_end, ..) = index_from_coordinate(last);
+    let (col_start, col_end) = check(col_start, col_end, 16_384)?;
+    let (row_start, row_end) = check(row_start, row_end, 1_048_576)?;
+    if col_end == 0 && row_end == 0 {
+        return Err(XlsxError::InvalidRange(range.to_string()));
+    }
+    Ok((row_start, row_end, col_start, col_end))
+}
+
+/// The key of a column, the value of the header cell or the column letter
+/// when it is empty.
+fn header_key(header: Option<&Cell>, col: u32) -> String {
+    match header.map(Cell::value) {
+        Some(v) if !v.is_empty() => v.into_owned(),
+        _ => string_from_column_index(col),
+    }
+}
+
+pub(crate) fn cell_to_value(cell: &Cell, option: &RecordsOption) -> Value {
+    raw_value_to_value(cell.raw_value(), cell.style(), option)
+}
+
+fn raw_value_to_value(raw_value: &CellRawValue, style: &Style, option: &RecordsOption) -> Value {
+    match raw_value {
+        CellRawValue::String(v) => Value::String(v.to_string()),
+        CellRawValue::RichText(v) => Value::String(v.text().into_owned()),
+        CellRawValue::Lazy(v) => {
+            raw_value_to_value(&CellValue::guess_typed_data(v), style, option)
+        }
+        CellRawValue::Numeric(v) => number_to_value(*v, style, option),
+        CellRawValue::Bool(v) => Value::Bool(*v),
+        CellRawValue::Error(v) => Value::String(v.to_string()),
+        CellRawValue::Empty => Value::Null,
+    }
+}
+
+fn number_to_value(value: f64, style: &Style, option: &RecordsOption) -> Value {
+    let is_date = style
+        .number_format()
+        .is_some_and(|v| is_date_time_format(v.format_code()));
+    if option.date_as_iso() && is_date && value >= 0.0 {
+        return Value::String(excel_to_iso_8601(value));
+    }
+    if value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER {
+        return Value::from(num_traits::cast::<f64, i64>(value).unwrap());
+    }
+    Value::from(value)
+}
+
+fn set_cell_value(cell: &mut Cell, value: &Value, is_date: bool) {
+    match value {
+        Value::Null => {}
+        Value::Bool(v) => {
+            cell.set_value_bool(*v);
+        }
+        Value::Number(v) => {
+            cell.set_value_number(v.as_f64().unwrap_or_default());
+        }
+        Value::String(v) => match is_date.then(|| iso_8601_to_excel(v)).flatten() {
+            Some(number) => {
+                cell.set_value_number(number);
+            }
+            None => {
+                cell.set_value_string(v);
+            }
+        },
+        Value::Array(_) | Value::Object(_) => {
+            cell.set_value_string(value.to_string());
+        }
+    }
+}
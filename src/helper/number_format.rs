@@ -136,6 +136,41 @@ pub fn to_formatted_string<S: AsRef<str>, P: AsRef<str>>(value: S, format: P) ->
     value.trim().to_string()
 }
 
+/// Whether the first section of the format code shows the number as a date
+/// or a time. Quoted text, escaped characters and colors are not counted.
+pub(crate) fn is_date_time_format(format: &str) -> bool {
+    let mut chars = format.chars();
+    let mut bracket: Option<String> = None;
+    while let Some(c) = chars.next() {
+        if let Some(content) = bracket.as_mut() {
+            if c != ']' {
+                content.push(c.to_ascii_lowercase());
+                continue;
+            }
+            // Elapsed time, such as `[h]` or `[mm]`.
+            let first = content.chars().next();
+            if matches!(first, Some('h' | 'm' | 's')) && content.chars().all(|v| Some(v) == first) {
+                return true;
+            }
+            bracket = None;
+            continue;
+        }
+        match c {
+            '"' => {
+                chars.by_ref().find(|v| *v == '"');
+            }
+            '\\' | '_' | '*' => {
+                chars.next();
+            }
+            '[' => bracket = Some(String::new()),
+            ';' => return false,
+            'd' | 'D' | 'm' | 'M' | 'y' | 'Y' | 'h' | 'H' | 's' | 'S' => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
 fn split_format(sections: Vec<&str>, value: f64) -> (String, String, String) {
     let mut converted_sections: Vec<String> = Vec::new();
 
@@ -319,6 +319,7 @@ fn complex_number_format_mask(number: f64, mask: &str, split_on_point: bool) ->
 }
 
 #[cfg(test)]
+#[allow(clippy::unreadable_literal)]
 mod tests {
     use super::*;
 
@@ -44,6 +44,7 @@ fn shift_decimal_right_two(value: &str) -> String {
 }
 
 #[cfg(test)]
+#[allow(clippy::unreadable_literal)]
 mod tests {
     use super::*;
 
@@ -1,4 +1,16 @@
-use crate::helper::coordinate::index_from_coordinate;
+use crate::{
+    helper::coordinate::index_from_coordinate,
+    structs::{
+        ColumnReference,
+        Range,
+        RowReference,
+    },
+};
+
+/// The last column of a sheet.
+const MAX_COL: u32 = 16_384;
+/// The last row of a sheet.
+const MAX_ROW: u32 = 1_048_576;
 
 /// `(col, row)`
 pub type BasicCellIndex = (u32, u32);
@@ -67,6 +79,31 @@ pub fn get_start_and_end_point(range_str: &str) -> (u32, u32, u32, u32) {
     (row_start, row_end, col_start, col_end)
 }
 
+/// Get the bounds of a range as `(col_start, row_start, col_end, row_end)`.
+/// Whole columns such as `A:C` and whole rows such as `1:3` extend to the
+/// end of the sheet. The bounds are capped at the sheet maximum.
+pub(crate) fn range_bounds(range: &Range) -> (u32, u32, u32, u32) {
+    let (col_start, col_end) = bounds_of(
+        range.coordinate_start_col().map(ColumnReference::num),
+        range.coordinate_end_col().map(ColumnReference::num),
+        MAX_COL,
+    );
+    let (row_start, row_end) = bounds_of(
+        range.coordinate_start_row().map(RowReference::num),
+        range.coordinate_end_row().map(RowReference::num),
+        MAX_ROW,
+    );
+    (col_start, row_start, col_end, row_end)
+}
+
+fn bounds_of(start: Option<u32>, end: Option<u32>, max: u32) -> (u32, u32) {
+    let Some(start) = start else {
+        return (1, max);
+    };
+    let end = end.unwrap_or(start);
+    (start.min(end).clamp(1, max), start.max(end).clamp(1, max))
+}
+
 #[inline]
 #[must_use]
 pub fn get_split_range(range: &str) -> Vec<&str> {
@@ -78,3 +115,32 @@ pub fn get_split_range(range: &str) -> Vec<&str> {
 pub fn get_join_range(coordinate_list: &[String]) -> String {
     coordinate_list.join(":")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_start_and_end_point_test() {
+        assert_eq!(get_start_and_end_point("A1:D4"), (1, 4, 1, 4));
+        assert_eq!(get_start_and_end_point("b2"), (2, 2, 2, 2));
+        // The end may leave out the row or the column of the start.
+        assert_eq!(get_start_and_end_point("A1:D"), (1, 1, 1, 4));
+        assert_eq!(get_start_and_end_point("A1:4"), (1, 4, 1, 1));
+    }
+
+    #[test]
+    fn range_bounds_test() {
+        let bounds = |value: &str| {
+            let mut range = Range::default();
+            range.set_range(value);
+            range_bounds(&range)
+        };
+        assert_eq!(bounds("B2:D4"), (2, 2, 4, 4));
+        assert_eq!(bounds("C3"), (3, 3, 3, 3));
+        assert_eq!(bounds("A:A"), (1, 1, 1, MAX_ROW));
+        assert_eq!(bounds("B:C"), (2, 1, 3, MAX_ROW));
+        assert_eq!(bounds("1:3"), (1, 1, MAX_COL, 3));
+        assert_eq!(bounds("Sheet1!$A$1:$B$2"), (1, 1, 2, 2));
+    }
+}
@@ -0,0 +1,833 @@
+//! Conversion between the rows of a worksheet and Rust values with serde.
+
+use std::borrow::Cow;
+
+use serde::{
+    de::{
+        self,
+        DeserializeOwned,
+        IntoDeserializer,
+        Unexpected,
+    },
+    forward_to_deserialize_any,
+    ser::{
+        self,
+        Impossible,
+    },
+};
+
+use crate::{
+    helper::{
+        coordinate::coordinate_from_index,
+        date::{
+            excel_to_iso_8601,
+            iso_8601_to_excel,
+        },
+        number_format::is_date_time_format,
+    },
+    structs::{
+        Cell,
+        CellRawValue,
+        CellValue,
+        RecordColumn,
+        RowMapping,
+        Worksheet,
+        XlsxError,
+    },
+};
+
+/// Deserialize a row from the cells of its columns, `(field, col, cell)`.
+pub(crate) fn row_to_value<T: DeserializeOwned>(
+    row: u32,
+    cells: &[(&str, u32, Option<&Cell>)],
+) -> Result<T, XlsxError> {
+    T::deserialize(RowDeserializer { row, cells }).map_err(|e| match e {
+        XlsxError::Serde(v) => XlsxError::InvalidCell(format!("{row}:{row}"), v),
+        e => e,
+    })
+}
+
+/// Write the rows below the header row, a column for each field.
+pub(crate) fn write_rows<T: ser::Serialize>(
+    worksheet: &mut Worksheet,
+    rows: &[T],
+    mapping: &RowMapping,
+) -> Result<(), XlsxError> {
+    let header_row = mapping.header_row();
+    let mut fields: Vec<String> = Vec::new();
+    for (row, value) in (header_row + 1..).zip(rows) {
+        let values = value.serialize(RowSerializer).map_err(|e| match e {
+            XlsxError::Serde(v) => XlsxError::InvalidCell(format!("{row}:{row}"), v),
+            e => e,
+        })?;
+        for (field, value) in values {
+            let index = if let Some(index) = fields.iter().position(|v| *v == field) {
+                index
+            } else {
+                let col = num_traits::cast(fields.len() + 1).unwrap();
+                worksheet
+                    .cell_mut((col, header_row))
+                    .set_value_string(mapping.header(&field));
+                fields.push(field);
+                fields.len() - 1
+            };
+            if matches!(value, CellData::Empty) {
+                continue;
+            }
+            let col = num_traits::cast(index + 1).unwrap();
+            value.write(worksheet.cell_mut((col, row)), mapping.column(&fields[index]));
+        }
+    }
+    Ok(())
+}
+
+struct RowDeserializer<'a> {
+    row:   u32,
+    cells: &'a [(&'a str, u32, Option<&'a Cell>)],
+}
+
+impl<'de> de::Deserializer<'de> for RowDeserializer<'_> {
+    type Error = XlsxError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        visitor.visit_map(RowAccess {
+            row:   self.row,
+            cells: self.cells.iter(),
+            value: None,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct RowAccess<'a, I> {
+    row:   u32,
+    cells: I,
+    value: Option<(u32, Option<&'a Cell>)>,
+}
+
+impl<'de, 'a, I> de::MapAccess<'de> for RowAccess<'a, I>
+where
+    I: Iterator<Item = &'a (&'a str, u32, Option<&'a Cell>)>,
+{
+    type Error = XlsxError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, XlsxError> {
+        let Some((field, col, cell)) = self.cells.next() else {
+            return Ok(None);
+        };
+        self.value = Some((*col, *cell));
+        seed.deserialize((*field).into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, XlsxError> {
+        let (col, cell) = self.value.take().unwrap_or_default();
+        seed.deserialize(CellDeserializer::new(cell))
+            .map_err(|e| match e {
+                XlsxError::Serde(v) => {
+                    XlsxError::InvalidCell(coordinate_from_index(col, self.row), v)
+                }
+                e => e,
+            })
+    }
+}
+
+/// A cell as a value, a number with a date or time format is an ISO 8601
+/// string unless a number is expected.
+struct CellDeserializer<'a> {
+    raw_value: Cow<'a, CellRawValue>,
+    is_date:   bool,
+}
+
+impl<'a> CellDeserializer<'a> {
+    fn new(cell: Option<&'a Cell>) -> Self {
+        let Some(cell) = cell else {
+            return Self {
+                raw_value: Cow::Owned(CellRawValue::Empty),
+                is_date:   false,
+            };
+        };
+        let raw_value = match cell.raw_value() {
+            CellRawValue::Lazy(v) => Cow::Owned(CellValue::guess_typed_data(v)),
+            v => Cow::Borrowed(v),
+        };
+        let is_date = cell
+            .style()
+            .number_format()
+            .is_some_and(|v| is_date_time_format(v.format_code()));
+        Self { raw_value, is_date }
+    }
+
+    fn text(&self) -> Option<Cow<'_, str>> {
+        match self.raw_value.as_ref() {
+            CellRawValue::String(v) => Some(Cow::Borrowed(v)),
+            CellRawValue::RichText(v) => Some(v.text()),
+            CellRawValue::Numeric(v) if self.is_date && *v >= 0.0 => {
+                Some(Cow::Owned(excel_to_iso_8601(*v)))
+            }
+            CellRawValue::Numeric(_) | CellRawValue::Bool(_) => {
+                Some(Cow::Owned(self.raw_value.to_string()))
+            }
+            CellRawValue::Empty => Some(Cow::Borrowed("")),
+            CellRawValue::Error(_) | CellRawValue::Lazy(_) => None,
+        }
+    }
+
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self.raw_value.as_ref() {
+            CellRawValue::String(v) => Unexpected::Str(v),
+            CellRawValue::RichText(_) => Unexpected::Other("rich text"),
+            CellRawValue::Numeric(v) => Unexpected::Float(*v),
+            CellRawValue::Bool(v) => Unexpected::Bool(*v),
+            CellRawValue::Error(_) => Unexpected::Other("error"),
+            CellRawValue::Lazy(_) | CellRawValue::Empty => Unexpected::Other("empty cell"),
+        }
+    }
+
+    fn check_error(&self) -> Result<(), XlsxError> {
+        match self.raw_value.as_ref() {
+            CellRawValue::Error(v) => Err(de::Error::custom(format!("the cell has the error {v}"))),
+            _ => Ok(()),
+        }
+    }
+
+    fn deserialize_integer<'de, V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, XlsxError> {
+        self.check_error()?;
+        match self.raw_value.as_ref() {
+            CellRawValue::Numeric(v) if v.fract() == 0.0 => {
+                if let Some(v) = num_traits::cast::<f64, i64>(*v) {
+                    return visitor.visit_i64(v);
+                }
+                if let Some(v) = num_traits::cast::<f64, u64>(*v) {
+                    return visitor.visit_u64(v);
+                }
+            }
+            CellRawValue::String(v) => {
+                if let Ok(v) = v.trim().parse::<i64>() {
+                    return visitor.visit_i64(v);
+                }
+                if let Ok(v) = v.trim().parse::<u64>() {
+                    return visitor.visit_u64(v);
+                }
+            }
+            _ => {}
+        }
+        Err(de::Error::invalid_type(self.unexpected(), &visitor))
+    }
+
+    fn deserialize_float<'de, V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, XlsxError> {
+        self.check_error()?;
+        match self.raw_value.as_ref() {
+            CellRawValue::Numeric(v) => visitor.visit_f64(*v),
+            CellRawValue::String(v) => match v.trim().parse::<f64>() {
+                Ok(v) => visitor.visit_f64(v),
+                Err(_) => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+            },
+            _ => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for CellDeserializer<'_> {
+    type Error = XlsxError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.check_error()?;
+        match self.raw_value.as_ref() {
+            CellRawValue::Numeric(v) if self.is_date && *v >= 0.0 => {
+                visitor.visit_string(excel_to_iso_8601(*v))
+            }
+            CellRawValue::Numeric(v) => match num_traits::cast::<f64, i64>(*v) {
+                Some(integer) if v.fract() == 0.0 => visitor.visit_i64(integer),
+                _ => visitor.visit_f64(*v),
+            },
+            CellRawValue::Bool(v) => visitor.visit_bool(*v),
+            CellRawValue::String(v) => visitor.visit_str(v),
+            CellRawValue::RichText(v) => visitor.visit_str(&v.text()),
+            _ => visitor.visit_unit(),
+        }
+    }
+
+    fn deserialize_bool<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.check_error()?;
+        match self.raw_value.as_ref() {
+            CellRawValue::Bool(v) => visitor.visit_bool(*v),
+            CellRawValue::String(v) if v.trim().eq_ignore_ascii_case("true") => {
+                visitor.visit_bool(true)
+            }
+            CellRawValue::String(v) if v.trim().eq_ignore_ascii_case("false") => {
+                visitor.visit_bool(false)
+            }
+            _ => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_i8<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_i16<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_i32<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_i64<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u8<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u16<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u32<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u64<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_f32<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_float(visitor)
+    }
+
+    fn deserialize_f64<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_float(visitor)
+    }
+
+    fn deserialize_char<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.check_error()?;
+        match self.text() {
+            Some(v) => visitor.visit_str(&v),
+            None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_string<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        match self.raw_value.as_ref() {
+            CellRawValue::Empty => visitor.visit_none(),
+            CellRawValue::String(v) if v.is_empty() => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, XlsxError> {
+        match self.raw_value.as_ref() {
+            CellRawValue::Empty => visitor.visit_unit(),
+            _ => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, XlsxError> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, XlsxError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, XlsxError> {
+        self.check_error()?;
+        match self.text() {
+            Some(v) => visitor.visit_enum(v.into_owned().into_deserializer()),
+            None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, XlsxError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf seq tuple tuple_struct map struct identifier
+    }
+}
+
+/// The value of a field.
+enum CellData {
+    Empty,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl CellData {
+    /// A text is written as a date when the column has a date or time
+    /// format and the text is an ISO 8601 date or time.
+    fn write(self, cell: &mut Cell, column: Option<&RecordColumn>) {
+        let format_code = column.and_then(RecordColumn::number_format);
+        match self {
+            Self::Empty => {}
+            Self::Bool(v) => {
+                cell.set_value_bool(v);
+            }
+            Self::Number(v) => {
+                cell.set_value_number(v);
+            }
+            Self::Text(v) => {
+                match format_code
+                    .filter(|v| is_date_time_format(v))
+                    .and_then(|_| iso_8601_to_excel(&v))
+                {
+                    Some(number) => cell.set_value_number(number),
+                    None => cell.set_value_string(v),
+                };
+            }
+        }
+        if let Some(format_code) = format_code {
+            cell.style_mut()
+                .number_format_mut()
+                .set_format_code(format_code);
+        }
+    }
+}
+
+fn unsupported(kind: &str) -> XlsxError {
+    ser::Error::custom(format!("{kind} can not be written to a cell"))
+}
+
+/// A row, a struct or a map of fields.
+struct RowSerializer;
+
+impl ser::Serializer for RowSerializer {
+    type Error = XlsxError;
+    type Ok = Vec<(String, CellData)>;
+    type SerializeMap = RowFields;
+    type SerializeSeq = Impossible<Self::Ok, XlsxError>;
+    type SerializeStruct = RowFields;
+    type SerializeStructVariant = Impossible<Self::Ok, XlsxError>;
+    type SerializeTuple = Impossible<Self::Ok, XlsxError>;
+    type SerializeTupleStruct = Impossible<Self::Ok, XlsxError>;
+    type SerializeTupleVariant = Impossible<Self::Ok, XlsxError>;
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<RowFields, XlsxError> {
+        Ok(RowFields {
+            fields: Vec::with_capacity(len),
+            key:    None,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<RowFields, XlsxError> {
+        Ok(RowFields {
+            fields: Vec::with_capacity(len.unwrap_or_default()),
+            key:    None,
+        })
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + ser::Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, XlsxError> {
+        value.serialize(self)
+    }
+
+    fn serialize_some<T: ?Sized + ser::Serialize>(self, value: &T) -> Result<Self::Ok, XlsxError> {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + ser::Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, XlsxError> {
+        Err(not_a_row())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, XlsxError> {
+        Err(not_a_row())
+    }
+}
+
+fn not_a_row() -> XlsxError {
+    ser::Error::custom("a row must be a struct or a map")
+}
+
+struct RowFields {
+    fields: Vec<(String, CellData)>,
+    key:    Option<String>,
+}
+
+impl RowFields {
+    fn push<T: ?Sized + ser::Serialize>(
+        &mut self,
+        key: String,
+        value: &T,
+    ) -> Result<(), XlsxError> {
+        let value = value.serialize(CellSerializer).map_err(|e| match e {
+            XlsxError::Serde(v) => XlsxError::Serde(format!("{key}: {v}")),
+            e => e,
+        })?;
+        self.fields.push((key, value));
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for RowFields {
+    type Error = XlsxError;
+    type Ok = Vec<(String, CellData)>;
+
+    fn serialize_field<T: ?Sized + ser::Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), XlsxError> {
+        self.push(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, XlsxError> {
+        Ok(self.fields)
+    }
+}
+
+impl ser::SerializeMap for RowFields {
+    type Error = XlsxError;
+    type Ok = Vec<(String, CellData)>;
+
+    fn serialize_key<T: ?Sized + ser::Serialize>(&mut self, key: &T) -> Result<(), XlsxError> {
+        self.key = Some(match key.serialize(CellSerializer)? {
+            CellData::Text(v) => v,
+            CellData::Number(v) => v.to_string(),
+            CellData::Bool(v) => v.to_string(),
+            CellData::Empty => return Err(ser::Error::custom("a key can not be empty")),
+        });
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + ser::Serialize>(&mut self, value: &T) -> Result<(), XlsxError> {
+        let key = self.key.take().unwrap_or_default();
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, XlsxError> {
+        Ok(self.fields)
+    }
+}
+
+/// The value of a field.
+struct CellSerializer;
+
+impl ser::Serializer for CellSerializer {
+    type Error = XlsxError;
+    type Ok = CellData;
+    type SerializeMap = Impossible<CellData, XlsxError>;
+    type SerializeSeq = Impossible<CellData, XlsxError>;
+    type SerializeStruct = Impossible<CellData, XlsxError>;
+    type SerializeStructVariant = Impossible<CellData, XlsxError>;
+    type SerializeTuple = Impossible<CellData, XlsxError>;
+    type SerializeTupleStruct = Impossible<CellData, XlsxError>;
+    type SerializeTupleVariant = Impossible<CellData, XlsxError>;
+
+    fn serialize_bool(self, v: bool) -> Result<CellData, XlsxError> {
+        Ok(CellData::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(f64::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(f64::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(f64::from(v)))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(num_traits::cast(v).unwrap()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(num_traits::cast(v).unwrap()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(f64::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(f64::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(f64::from(v)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(num_traits::cast(v).unwrap()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(num_traits::cast(v).unwrap()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<CellData, XlsxError> {
+        Ok(CellData::Number(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<CellData, XlsxError> {
+        Ok(CellData::Text(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<CellData, XlsxError> {
+        Ok(CellData::Text(v.to_string()))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<CellData, XlsxError> {
+        Err(unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<CellData, XlsxError> {
+        Ok(CellData::Empty)
+    }
+
+    fn serialize_some<T: ?Sized + ser::Serialize>(self, value: &T) -> Result<CellData, XlsxError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<CellData, XlsxError> {
+        Ok(CellData::Empty)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<CellData, XlsxError> {
+        Ok(CellData::Empty)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<CellData, XlsxError> {
+        Ok(CellData::Text(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + ser::Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<CellData, XlsxError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + ser::Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<CellData, XlsxError> {
+        Err(unsupported("an enum variant with a value"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, XlsxError> {
+        Err(unsupported("a sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, XlsxError> {
+        Err(unsupported("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, XlsxError> {
+        Err(unsupported("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, XlsxError> {
+        Err(unsupported("an enum variant with a value"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, XlsxError> {
+        Err(unsupported("a map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, XlsxError> {
+        Err(unsupported("a struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, XlsxError> {
+        Err(unsupported("an enum variant with a value"))
+    }
+}
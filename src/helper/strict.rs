@@ -0,0 +1,68 @@
+//! Namespaces of the Strict conformance (ISO/IEC 29500 Strict).
+
+/// Namespaces and relationship types of the Transitional conformance with the
+/// Strict ones, the more specific first.
+const NAMESPACES: &[(&str, &str)] = &[
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships/customProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/extendedProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/customProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
+        "http://purl.oclc.org/ooxml/officeDocument/docPropsVTypes",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/sharedTypes",
+        "http://purl.oclc.org/ooxml/officeDocument/sharedTypes",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/math",
+        "http://purl.oclc.org/ooxml/officeDocument/math",
+    ),
+    (
+        "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+        "http://purl.oclc.org/ooxml/spreadsheetml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/",
+        "http://purl.oclc.org/ooxml/drawingml/",
+    ),
+];
+
+/// Transitional namespace or relationship type for a Strict one.
+/// Other values are returned as they are.
+pub(crate) fn to_transitional(value: &str) -> String {
+    for (transitional, strict) in NAMESPACES {
+        if let Some(rest) = value.strip_prefix(strict) {
+            return format!("{transitional}{rest}");
+        }
+    }
+    value.to_string()
+}
+
+/// Strict namespace or relationship type for a Transitional one.
+/// Other values are returned as they are.
+pub(crate) fn to_strict(value: &str) -> String {
+    for (transitional, strict) in NAMESPACES {
+        if let Some(rest) = value.strip_prefix(transitional) {
+            return format!("{strict}{rest}");
+        }
+    }
+    value.to_string()
+}
@@ -0,0 +1,256 @@
+//! Rewriting of structured references such as `Table1[[#This Row],[Amount]]`.
+
+/// Characters that must be escaped with `'` in a column name.
+const ESCAPED_CHARS: [char; 4] = ['[', ']', '#', '\''];
+
+/// Escape a column name for use within the brackets of a structured
+/// reference.
+pub(crate) fn escape_column(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    for c in name.chars() {
+        if ESCAPED_CHARS.contains(&c) {
+            result.push('\'');
+        }
+        result.push(c);
+    }
+    result
+}
+
+fn unescape_column(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if let Some(n) = chars.next() {
+                result.push(n);
+            }
+            continue;
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Whether a column name can be written without inner brackets,
+/// as in `[@Amount]`.
+fn is_simple_column(name: &str) -> bool {
+    name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+#[inline]
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '\\' || c == '.'
+}
+
+/// Find the index of the bracket closing the one at `start`.
+fn closing_bracket(chars: &[char], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut index = start;
+    while index < chars.len() {
+        match chars[index] {
+            '\'' => index += 1,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Walk the structured references of a formula.
+/// `f` receives the table name before the brackets (empty when the
+/// reference is unqualified) and the text between the outer brackets, and
+/// returns the replacement for both.
+fn rewrite<F>(formula: &str, mut f: F) -> String
+where
+    F: FnMut(&str, &str) -> Option<(String, String)>,
+{
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::with_capacity(formula.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            '"' | '\'' => {
+                // string literal or quoted sheet name
+                result.push(c);
+                index += 1;
+                while index < chars.len() {
+                    result.push(chars[index]);
+                    if chars[index] == c {
+                        if chars.get(index + 1) == Some(&c) {
+                            result.push(c);
+                            index += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    index += 1;
+                }
+                index += 1;
+            }
+            '[' => {
+                let Some(end) = closing_bracket(&chars, index) else {
+                    result.extend(&chars[index..]);
+                    break;
+                };
+                let name_len = result
+                    .chars()
+                    .rev()
+                    .take_while(|v| is_name_char(*v))
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+                let name = result.split_off(result.len() - name_len);
+                let content: String = chars[index + 1..end].iter().collect();
+                if let Some((name, content)) = f(&name, &content) {
+                    result.push_str(&name);
+                    result.push('[');
+                    result.push_str(&content);
+                    result.push(']');
+                } else {
+                    result.push_str(&name);
+                    result.extend(&chars[index..=end]);
+                }
+                index = end + 1;
+            }
+            _ => {
+                result.push(c);
+                index += 1;
+            }
+        }
+    }
+    result
+}
+
+/// Rename the table of the structured references of a formula.
+pub(crate) fn rename_table(formula: &str, old_name: &str, new_name: &str) -> String {
+    if !formula.contains('[') {
+        return formula.to_string();
+    }
+    rewrite(formula, |name, content| {
+        name.eq_ignore_ascii_case(old_name)
+            .then(|| (new_name.to_string(), content.to_string()))
+    })
+}
+
+/// Rename a column in the structured references of a formula.
+/// # Arguments
+/// * `table_name` - Name of the table the column belongs to.
+/// * `in_table` - Whether the formula is within the table, where references
+///   without a table name point at the table.
+pub(crate) fn rename_column(
+    formula: &str,
+    table_name: &str,
+    old_name: &str,
+    new_name: &str,
+    in_table: bool,
+) -> String {
+    if !formula.contains('[') {
+        return formula.to_string();
+    }
+    rewrite(formula, |name, content| {
+        let is_target = if name.is_empty() {
+            in_table
+        } else {
+            name.eq_ignore_ascii_case(table_name)
+        };
+        if !is_target {
+            return None;
+        }
+        Some((
+            name.to_string(),
+            rename_column_items(content, old_name, new_name),
+        ))
+    })
+}
+
+/// Rename a column within the outer brackets of a structured reference,
+/// either a single item such as `@Amount` or a list such as
+/// `[#This Row],[Amount]`.
+fn rename_column_items(content: &str, old_name: &str, new_name: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    if !chars.contains(&'[') {
+        return rename_column_item(content, old_name, new_name);
+    }
+    let mut result = String::with_capacity(content.len());
+    let mut index = 0;
+    while index < chars.len() {
+        if chars[index] == '[' {
+            if let Some(end) = closing_bracket(&chars, index) {
+                let item: String = chars[index + 1..end].iter().collect();
+                result.push('[');
+                if unescape_column(item.trim()).eq_ignore_ascii_case(old_name) {
+                    result.push_str(&escape_column(new_name));
+                } else {
+                    result.push_str(&item);
+                }
+                result.push(']');
+                index = end + 1;
+                continue;
+            }
+        }
+        result.push(chars[index]);
+        index += 1;
+    }
+    result
+}
+
+fn rename_column_item(item: &str, old_name: &str, new_name: &str) -> String {
+    let (prefix, name) = match item.strip_prefix('@') {
+        Some(v) => ("@", v),
+        None => ("", item),
+    };
+    if name.starts_with('#') || !unescape_column(name.trim()).eq_ignore_ascii_case(old_name) {
+        return item.to_string();
+    }
+    if prefix.is_empty() || is_simple_column(new_name) {
+        format!("{prefix}{}", escape_column(new_name))
+    } else {
+        format!("@[{}]", escape_column(new_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rename_table_test() {
+        assert_eq!(
+            rename_table("SUM(Sales[Amount])+sales[@Qty]", "Sales", "Orders"),
+            "SUM(Orders[Amount])+Orders[@Qty]"
+        );
+        assert_eq!(
+            rename_table("\"Sales[Amount]\"&MySales[Amount]", "Sales", "Orders"),
+            "\"Sales[Amount]\"&MySales[Amount]"
+        );
+    }
+
+    #[test]
+    fn rename_column_test() {
+        assert_eq!(
+            rename_column(
+                "Sales[[#This Row],[Amount]]*[@Amount]+SUM(Sales[[Amount]:[Qty]])",
+                "Sales",
+                "amount",
+                "Net Amount",
+                true
+            ),
+            "Sales[[#This Row],[Net Amount]]*[@[Net Amount]]+SUM(Sales[[Net Amount]:[Qty]])"
+        );
+        assert_eq!(
+            rename_column("[@Amount]+Other[Amount]", "Sales", "Amount", "Net", false),
+            "[@Amount]+Other[Amount]"
+        );
+        assert_eq!(
+            rename_column("Sales[Price '#]", "Sales", "Price #", "Price [USD]", false),
+            "Sales[Price '[USD']]"
+        );
+    }
+}
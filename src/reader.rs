@@ -1,4 +1,7 @@
 //! file reader library.
 
 pub(crate) mod driver;
+pub mod ods;
+pub mod xls;
+pub mod xlsb;
 pub mod xlsx;
@@ -8,7 +8,21 @@ use std::{
     string::FromUtf8Error,
 };
 
-use quick_xml::events::attributes::Attribute;
+use quick_xml::{
+    Reader,
+    events::{
+        BytesStart,
+        attributes::Attribute,
+    },
+};
+
+use crate::{
+    helper::coordinate::CellCoordinates,
+    structs::{
+        XlsxError,
+        XmlLocation,
+    },
+};
 
 #[macro_export]
 macro_rules! xml_read_loop {
@@ -17,7 +31,9 @@ macro_rules! xml_read_loop {
         loop {
             let ev = match $reader.read_event_into(&mut buf) {
                 Ok(v) => v,
-                Err(e) => panic!("Error at position {}: {e:?}", $reader.buffer_position()),
+                Err(e) => {
+                    return Err($crate::reader::driver::invalid_xml($reader.error_position(), &e));
+                }
             };
 
             match ev {
@@ -43,6 +59,91 @@ macro_rules! set_string_from_xml {
 
 pub(crate) use crate::set_string_from_xml;
 
+/// Error for an element that is not closed before the end of the part.
+#[inline]
+pub(crate) fn unexpected_eof<R>(reader: &Reader<R>, element: &str) -> XlsxError {
+    XlsxError::UnexpectedEof(XmlLocation::new(reader.buffer_position(), element))
+}
+
+/// Error for XML that is not well-formed.
+#[inline]
+pub(crate) fn invalid_xml(position: u64, error: &quick_xml::Error) -> XlsxError {
+    XlsxError::InvalidXml(XmlLocation::new(position, ""), error.to_string())
+}
+
+/// Error for an element with content that can not be read.
+#[inline]
+pub(crate) fn invalid_element<R, S: Into<String>>(
+    reader: &Reader<R>,
+    element: &str,
+    message: S,
+) -> XlsxError {
+    XlsxError::InvalidElement(
+        XmlLocation::new(reader.buffer_position(), element),
+        message.into(),
+    )
+}
+
+/// Get an attribute that the element must have.
+#[inline]
+pub(crate) fn required_attribute<R>(
+    reader: &Reader<R>,
+    e: &BytesStart<'_>,
+    key: &str,
+) -> Result<String, XlsxError> {
+    get_attribute(e, key.as_bytes()).ok_or_else(|| {
+        XlsxError::MissingAttribute(
+            XmlLocation::new(
+                reader.buffer_position(),
+                String::from_utf8_lossy(e.name().into_inner()),
+            ),
+            key.into(),
+        )
+    })
+}
+
+/// Error for an attribute with a value that can not be read.
+#[inline]
+pub(crate) fn invalid_attribute<R>(
+    reader: &Reader<R>,
+    e: &BytesStart<'_>,
+    key: &str,
+    value: &str,
+) -> XlsxError {
+    invalid_element(
+        reader,
+        &String::from_utf8_lossy(e.name().into_inner()),
+        format!("invalid value '{value}' of attribute '{key}'"),
+    )
+}
+
+/// Get an attribute that the element must have, parsed as a value.
+#[inline]
+pub(crate) fn parse_attribute<R, T: std::str::FromStr>(
+    reader: &Reader<R>,
+    e: &BytesStart<'_>,
+    key: &str,
+) -> Result<T, XlsxError> {
+    let value = required_attribute(reader, e, key)?;
+    value
+        .parse()
+        .map_err(|_| invalid_attribute(reader, e, key, &value))
+}
+
+/// Check that an attribute value is a cell coordinate. ex) "A1"
+#[inline]
+pub(crate) fn check_coordinate<R>(
+    reader: &Reader<R>,
+    e: &BytesStart<'_>,
+    key: &str,
+    value: String,
+) -> Result<String, XlsxError> {
+    match CellCoordinates::parse(&value) {
+        Some(_) => Ok(value),
+        None => Err(invalid_attribute(reader, e, key, &value)),
+    }
+}
+
 pub(crate) fn normalize_path(path: &str) -> PathBuf {
     let path = Path::new(path);
     let mut components = path.components().peekable();
@@ -131,14 +232,14 @@ pub(crate) fn zip_by_name<'a, R: io::Read + io::Seek>(
 /// This handles XLSX files that use non-standard namespace prefixes
 /// (e.g. `d3p1:id` instead of `r:id`).
 #[inline]
-pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -> Option<String> {
+pub(crate) fn get_attribute(e: &BytesStart<'_>, key: &[u8]) -> Option<String> {
     // 1. Exact match (fast path)
     let result = e
         .attributes()
         .with_checks(false)
         .find_map(|attr| match attr {
             Ok(ref attr) if attr.key.into_inner() == key => {
-                Some(get_attribute_value(attr).unwrap())
+                Some(get_attribute_value_lossy(attr))
             }
             _ => None,
         });
@@ -164,7 +265,7 @@ pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -
                     None => attr_key,
                 };
                 if attr_local == local_name {
-                    Some(get_attribute_value(attr).unwrap())
+                    Some(get_attribute_value_lossy(attr))
                 } else {
                     None
                 }
@@ -177,3 +278,8 @@ pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -
 pub(crate) fn get_attribute_value(attr: &Attribute) -> Result<String, FromUtf8Error> {
     String::from_utf8(attr.value.to_vec())
 }
+
+#[inline]
+fn get_attribute_value_lossy(attr: &Attribute) -> String {
+    String::from_utf8_lossy(&attr.value).into_owned()
+}
@@ -0,0 +1,133 @@
+//! Reader of the `OpenDocument` spreadsheet.
+
+use std::{
+    fs::File,
+    io,
+    path::Path,
+};
+
+use quick_xml::{
+    Reader,
+    events::{
+        BytesRef,
+        BytesStart,
+        Event,
+    },
+};
+
+use self::styles::OdsStyles;
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        PKG_ODS_CONTENT,
+        PKG_ODS_STYLES,
+    },
+    new_file_empty_worksheet,
+    reader::driver::{
+        get_attribute,
+        xml_read_loop,
+        zip_by_name,
+    },
+    structs::Workbook,
+};
+
+pub(crate) mod content;
+pub(crate) mod formula;
+pub(crate) mod styles;
+
+/// read spreadsheet from arbitrary reader.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is `Workbook`. Err is error message.
+pub fn read_reader<R: io::Read + io::Seek>(reader: R) -> Result<Workbook, XlsxError> {
+    let mut arv = zip::ZipArchive::new(reader)?;
+    let mut wb = new_file_empty_worksheet();
+    let mut styles = OdsStyles::default();
+
+    if let Some(data) = read_part(&mut arv, PKG_ODS_STYLES)? {
+        read_styles(&data, &mut styles).map_err(|e| e.in_part(PKG_ODS_STYLES))?;
+    }
+    let data = read_part(&mut arv, PKG_ODS_CONTENT)?
+        .ok_or(zip::result::ZipError::FileNotFound)?;
+    content::read(&data, &mut arv, &mut wb, &mut styles)
+        .map_err(|e| e.in_part(PKG_ODS_CONTENT))?;
+
+    if wb.sheet_count() == 0 {
+        wb.new_sheet("Sheet1")?;
+    }
+    wb.set_active_sheet(0);
+    Ok(wb)
+}
+
+/// read spreadsheet file.
+/// Cell values, formulas, styles, merged cells, column widths, row heights,
+/// sheets and the images anchored to cells are read. Charts, comments and
+/// the images anchored to the page are skipped.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Workbook. Err is error message.
+/// # Examples
+/// ```no_run
+/// let path = std::path::Path::new("./tests/test_files/aaa.ods");
+/// let book = umya_spreadsheet::reader::ods::read(path).unwrap();
+/// let path = std::path::Path::new("./tests/result_files/aaa_from_ods.xlsx");
+/// let _unused = umya_spreadsheet::writer::xlsx::write(&book, path);
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Workbook, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(file)
+}
+
+/// Data of a part, `None` when the package does not have it.
+pub(crate) fn read_part<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    name: &str,
+) -> Result<Option<Vec<u8>>, XlsxError> {
+    let mut file = match zip_by_name(arv, name) {
+        Ok(v) => v,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut data = Vec::new();
+    io::Read::read_to_end(&mut file, &mut data)?;
+    Ok(Some(data))
+}
+
+fn read_styles(data: &[u8], styles: &mut OdsStyles) -> Result<(), XlsxError> {
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(false);
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            let name = e.name().into_inner();
+            if name == b"office:styles" || name == b"office:automatic-styles" {
+                styles.read(&mut reader, name)?;
+            }
+        },
+        Event::Eof => break,
+    );
+    Ok(())
+}
+
+/// Value of an attribute with its entities resolved.
+pub(crate) fn attribute(e: &BytesStart<'_>, key: &[u8]) -> Option<String> {
+    let value = get_attribute(e, key)?;
+    match quick_xml::escape::unescape(&value) {
+        Ok(v) => Some(v.into_owned()),
+        Err(_) => Some(value),
+    }
+}
+
+/// Text of a character or an entity reference.
+pub(crate) fn text_of(e: &BytesRef<'_>) -> String {
+    if let Ok(Some(v)) = e.resolve_char_ref() {
+        return v.to_string();
+    }
+    let name = e.decode().unwrap_or_default();
+    quick_xml::escape::resolve_predefined_entity(&name)
+        .unwrap_or_default()
+        .to_string()
+}
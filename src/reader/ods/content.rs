@@ -0,0 +1,652 @@
+use std::io;
+
+use quick_xml::{
+    Reader,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    attribute,
+    formula::to_excel,
+    read_part,
+    styles::{
+        OdsStyles,
+        length_to_points,
+    },
+    text_of,
+};
+use crate::{
+    XlsxError,
+    helper::{
+        coordinate::coordinate_from_index,
+        date::iso_8601_to_excel,
+        formula::ERRORS,
+    },
+    reader::driver::{
+        invalid_xml,
+        xml_read_loop,
+    },
+    structs::{
+        DefinedName,
+        Font,
+        Image,
+        RichText,
+        Style,
+        TextElement,
+        Workbook,
+        Worksheet,
+        drawing::spreadsheet::MarkerType,
+    },
+};
+
+/// Runs of columns and rows longer than these fill the rest of the sheet.
+const COLUMN_RUN_LIMIT: u32 = 256;
+const ROW_RUN_LIMIT: u32 = 1024;
+const MAX_COLUMN: u32 = 16384;
+const MAX_ROW: u32 = 1_048_576;
+
+/// A run of columns of `table:table-column`.
+#[derive(Debug)]
+struct ColumnRun {
+    first:      u32,
+    last:       u32,
+    /// Style of the cells that have no style of their own.
+    cell_style: Option<String>,
+}
+
+/// An image of a `draw:frame` anchored to a cell.
+#[derive(Debug, Default)]
+struct FrameImage {
+    href:   String,
+    width:  f64,
+    height: f64,
+}
+
+/// A `table:table-cell` with its content.
+#[derive(Debug, Default)]
+struct OdsCell {
+    repeated:      u32,
+    covered:       bool,
+    value_type:    Option<String>,
+    value:         Option<String>,
+    date_value:    Option<String>,
+    time_value:    Option<String>,
+    boolean_value: Option<String>,
+    string_value:  Option<String>,
+    formula:       Option<String>,
+    style_name:    Option<String>,
+    columns:       u32,
+    rows:          u32,
+    /// Paragraphs of runs of text, with the text style of each run.
+    paragraphs:    Vec<Vec<(String, Option<String>)>>,
+    images:        Vec<FrameImage>,
+}
+
+impl OdsCell {
+    fn new(e: &BytesStart<'_>, covered: bool) -> Self {
+        let number = |key: &[u8]| {
+            attribute(e, key)
+                .and_then(|v| v.parse::<u32>().ok())
+                .unwrap_or(1)
+                .max(1)
+        };
+        Self {
+            repeated: number(b"table:number-columns-repeated"),
+            covered,
+            value_type: attribute(e, b"office:value-type"),
+            value: attribute(e, b"office:value"),
+            date_value: attribute(e, b"office:date-value"),
+            time_value: attribute(e, b"office:time-value"),
+            boolean_value: attribute(e, b"office:boolean-value"),
+            string_value: attribute(e, b"office:string-value"),
+            formula: attribute(e, b"table:formula"),
+            style_name: attribute(e, b"table:style-name"),
+            columns: number(b"table:number-columns-spanned"),
+            rows: number(b"table:number-rows-spanned"),
+            ..Default::default()
+        }
+    }
+
+    fn has_content(&self) -> bool {
+        self.value_type.is_some()
+            || self.formula.is_some()
+            || !self.paragraphs.is_empty()
+            || !self.images.is_empty()
+            || self.columns > 1
+            || self.rows > 1
+    }
+
+    fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|v| v.iter().map(|(text, _)| text.as_str()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Read the content of the cell up to its end.
+    fn read_content<R: io::BufRead>(&mut self, reader: &mut Reader<R>) -> Result<(), XlsxError> {
+        let mut span_styles: Vec<Option<String>> = Vec::new();
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"text:p" | b"text:h" => self.paragraphs.push(Vec::new()),
+                b"text:span" => span_styles.push(attribute(e, b"text:style-name")),
+                b"draw:frame" => self.images.extend(read_frame(reader, e)?),
+                b"office:annotation" | b"text:note" | b"text:ruby-text" => {
+                    reader
+                        .read_to_end_into(e.name(), &mut Vec::new())
+                        .map_err(|v| invalid_xml(reader.error_position(), &v))?;
+                }
+                _ => {}
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"text:p" | b"text:h" => self.paragraphs.push(Vec::new()),
+                b"text:s" => {
+                    let count = attribute(e, b"text:c")
+                        .and_then(|v| v.parse::<usize>().ok())
+                        .unwrap_or(1);
+                    self.push_text(&" ".repeat(count), &span_styles);
+                }
+                b"text:tab" => self.push_text("\t", &span_styles),
+                b"text:line-break" => self.push_text("\n", &span_styles),
+                _ => {}
+            },
+            Event::Text(ref e) => {
+                self.push_text(&e.decode().unwrap_or_default(), &span_styles);
+            },
+            Event::GeneralRef(ref e) => self.push_text(&text_of(e), &span_styles),
+            Event::End(ref e) => match e.name().into_inner() {
+                b"text:span" => {
+                    span_styles.pop();
+                }
+                b"table:table-cell" | b"table:covered-table-cell" => break,
+                _ => {}
+            },
+            Event::Eof => break,
+        );
+        Ok(())
+    }
+
+    fn push_text(&mut self, value: &str, span_styles: &[Option<String>]) {
+        let Some(paragraph) = self.paragraphs.last_mut() else {
+            return;
+        };
+        let style = span_styles.iter().rev().flatten().next().cloned();
+        match paragraph.last_mut() {
+            Some((text, v)) if *v == style => text.push_str(value),
+            _ => paragraph.push((value.to_string(), style)),
+        }
+    }
+}
+
+/// Read the image of a `draw:frame` up to its end.
+fn read_frame<R: io::BufRead>(
+    reader: &mut Reader<R>,
+    e: &BytesStart<'_>,
+) -> Result<Option<FrameImage>, XlsxError> {
+    let length = |key: &[u8]| {
+        attribute(e, key)
+            .and_then(|v| length_to_points(&v))
+            .unwrap_or_default()
+    };
+    let mut image = FrameImage {
+        href:   String::new(),
+        width:  length(b"svg:width"),
+        height: length(b"svg:height"),
+    };
+    let mut depth = 0;
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"draw:image" && image.href.is_empty() {
+                image.href = attribute(e, b"xlink:href").unwrap_or_default();
+            }
+            depth += 1;
+        },
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"draw:image" && image.href.is_empty() {
+                image.href = attribute(e, b"xlink:href").unwrap_or_default();
+            }
+        },
+        Event::End(_) => {
+            if depth == 0 {
+                break;
+            }
+            depth -= 1;
+        },
+        Event::Eof => break,
+    );
+    Ok((!image.href.is_empty()).then_some(image))
+}
+
+/// Read the tables and the named ranges of `content.xml`.
+pub(crate) fn read<R: io::Read + io::Seek>(
+    data: &[u8],
+    arv: &mut zip::ZipArchive<R>,
+    wb: &mut Workbook,
+    styles: &mut OdsStyles,
+) -> Result<(), XlsxError> {
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(false);
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => match e.name().into_inner() {
+            b"office:automatic-styles" => styles.read(&mut reader, b"office:automatic-styles")?,
+            b"table:table" => read_table(&mut reader, e, arv, wb, styles)?,
+            _ => {}
+        },
+        Event::Empty(ref e) => match e.name().into_inner() {
+            b"table:named-range" | b"table:named-expression" => {
+                if let Some(v) = defined_name(e) {
+                    add_defined_name(wb, v);
+                }
+            }
+            b"table:table" => {
+                let name = attribute(e, b"table:name").unwrap_or_default();
+                wb.new_sheet(name)?;
+            }
+            _ => {}
+        },
+        Event::Eof => break,
+    );
+    Ok(())
+}
+
+/// A named range or a named expression.
+fn defined_name(e: &BytesStart<'_>) -> Option<DefinedName> {
+    let name = attribute(e, b"table:name")?;
+    let address = match attribute(e, b"table:cell-range-address") {
+        Some(v) => to_excel(&format!("[{v}]")),
+        None => to_excel(&attribute(e, b"table:expression")?),
+    };
+    let mut defined_name = DefinedName::default();
+    defined_name.set_name(name);
+    defined_name.set_address(address);
+    Some(defined_name)
+}
+
+fn add_defined_name(wb: &mut Workbook, defined_name: DefinedName) {
+    if let Some(v) = defined_name.address_obj().first() {
+        if let Ok(sheet) = wb.sheet_by_name_mut(v.sheet_name()) {
+            sheet.add_defined_names(defined_name);
+            return;
+        }
+    }
+    wb.add_defined_names(defined_name);
+}
+
+fn read_table<R: io::Read + io::Seek>(
+    reader: &mut Reader<&[u8]>,
+    e: &BytesStart<'_>,
+    arv: &mut zip::ZipArchive<R>,
+    wb: &mut Workbook,
+    styles: &mut OdsStyles,
+) -> Result<(), XlsxError> {
+    let name = attribute(e, b"table:name").unwrap_or_default();
+    let sheet_index: u32 = num_traits::cast(wb.sheet_count()).unwrap();
+    let worksheet = wb.new_sheet(name)?;
+    if let Some(v) = attribute(e, b"table:style-name") {
+        if styles.is_hidden_table(&v) {
+            worksheet.set_sheet_state("hidden".into());
+        }
+    }
+
+    let mut columns: Vec<ColumnRun> = Vec::new();
+    let mut row: u32 = 1;
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => match e.name().into_inner() {
+            b"table:table-column" => read_column(e, &mut columns, worksheet, styles),
+            b"table:table-row" => {
+                let cells = read_row(reader)?;
+                row = apply_row(e, row, &cells, &columns, arv, worksheet, styles)?;
+            }
+            b"table:shapes" | b"office:forms" | b"table:named-expressions" => {
+                if e.name().into_inner() == b"table:named-expressions" {
+                    read_local_names(reader, sheet_index, worksheet)?;
+                } else {
+                    reader
+                        .read_to_end_into(e.name(), &mut Vec::new())
+                        .map_err(|v| invalid_xml(reader.error_position(), &v))?;
+                }
+            }
+            _ => {}
+        },
+        Event::Empty(ref e) => match e.name().into_inner() {
+            b"table:table-column" => read_column(e, &mut columns, worksheet, styles),
+            b"table:table-row" => {
+                row = apply_row(e, row, &[], &columns, arv, worksheet, styles)?;
+            }
+            _ => {}
+        },
+        Event::End(ref e) => {
+            if e.name().into_inner() == b"table:table" {
+                break;
+            }
+        },
+        Event::Eof => break,
+    );
+    Ok(())
+}
+
+fn read_local_names<R: io::BufRead>(
+    reader: &mut Reader<R>,
+    sheet_index: u32,
+    worksheet: &mut Worksheet,
+) -> Result<(), XlsxError> {
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) => {
+            if let Some(mut v) = defined_name(e) {
+                v.set_local_sheet_id(sheet_index);
+                worksheet.add_defined_names(v);
+            }
+        },
+        Event::End(ref e) => {
+            if e.name().into_inner() == b"table:named-expressions" {
+                break;
+            }
+        },
+        Event::Eof => break,
+    );
+    Ok(())
+}
+
+fn read_column(
+    e: &BytesStart<'_>,
+    columns: &mut Vec<ColumnRun>,
+    worksheet: &mut Worksheet,
+    styles: &OdsStyles,
+) {
+    let repeated = attribute(e, b"table:number-columns-repeated")
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(1)
+        .max(1);
+    let first = columns.last().map_or(1, |v| v.last + 1);
+    let last = first.saturating_add(repeated - 1).min(MAX_COLUMN);
+    if first > MAX_COLUMN {
+        return;
+    }
+    let width = attribute(e, b"table:style-name").and_then(|v| styles.column_width(&v));
+    let hidden = attribute(e, b"table:visibility").is_some_and(|v| v != "visible");
+    if repeated >= COLUMN_RUN_LIMIT {
+        if let Some(v) = width {
+            worksheet
+                .sheet_format_properties_mut()
+                .set_default_column_width(v);
+        }
+    } else if width.is_some() || hidden {
+        for col in first..=last {
+            let dimension = worksheet.column_dimension_by_number_mut(col);
+            if let Some(v) = width {
+                dimension.set_width(v);
+            }
+            dimension.set_hidden(hidden);
+        }
+    }
+    let cell_style =
+        attribute(e, b"table:default-cell-style-name").filter(|v| v != "Default");
+    columns.push(ColumnRun {
+        first,
+        last,
+        cell_style,
+    });
+}
+
+/// Read the cells of a row up to its end.
+fn read_row<R: io::BufRead>(reader: &mut Reader<R>) -> Result<Vec<OdsCell>, XlsxError> {
+    let mut cells = Vec::new();
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => match e.name().into_inner() {
+            b"table:table-cell" | b"table:covered-table-cell" => {
+                let covered = e.name().into_inner() == b"table:covered-table-cell";
+                let mut cell = OdsCell::new(e, covered);
+                cell.read_content(reader)?;
+                cells.push(cell);
+            }
+            _ => {}
+        },
+        Event::Empty(ref e) => match e.name().into_inner() {
+            b"table:table-cell" => cells.push(OdsCell::new(e, false)),
+            b"table:covered-table-cell" => cells.push(OdsCell::new(e, true)),
+            _ => {}
+        },
+        Event::End(ref e) => {
+            if e.name().into_inner() == b"table:table-row" {
+                break;
+            }
+        },
+        Event::Eof => break,
+    );
+    Ok(cells)
+}
+
+/// Apply a row and its repeats from `row`, returns the next row.
+#[allow(clippy::too_many_arguments)]
+fn apply_row<R: io::Read + io::Seek>(
+    e: &BytesStart<'_>,
+    row: u32,
+    cells: &[OdsCell],
+    columns: &[ColumnRun],
+    arv: &mut zip::ZipArchive<R>,
+    worksheet: &mut Worksheet,
+    styles: &mut OdsStyles,
+) -> Result<u32, XlsxError> {
+    let repeated = attribute(e, b"table:number-rows-repeated")
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(1)
+        .max(1);
+    let next = row.saturating_add(repeated);
+    if repeated > ROW_RUN_LIMIT || row > MAX_ROW {
+        return Ok(next);
+    }
+    let last = (next - 1).min(MAX_ROW);
+
+    let height = attribute(e, b"table:style-name")
+        .and_then(|v| styles.row_height(&v))
+        .filter(|v| v.1);
+    let hidden = attribute(e, b"table:visibility").is_some_and(|v| v != "visible");
+    if height.is_some() || hidden {
+        for r in row..=last {
+            let dimension = worksheet.row_dimension_mut(r);
+            if let Some((v, _)) = height {
+                dimension.set_height(v);
+            }
+            dimension.set_hidden(hidden);
+        }
+    }
+
+    let mut col: u32 = 1;
+    for cell in cells {
+        let first = col;
+        col = col.saturating_add(cell.repeated);
+        if cell.covered || first > MAX_COLUMN {
+            continue;
+        }
+        let style_name = cell.style_name.clone().filter(|v| v != "Default").or_else(|| {
+            columns
+                .iter()
+                .find(|v| v.first <= first && first <= v.last)
+                .and_then(|v| v.cell_style.clone())
+        });
+        if !cell.has_content() && (style_name.is_none() || cell.repeated > COLUMN_RUN_LIMIT) {
+            continue;
+        }
+        let style = style_name.map(|v| styles.cell_style(&v));
+        for c in first..col.min(MAX_COLUMN + 1) {
+            for r in row..=last {
+                apply_cell(cell, c, r, style.as_ref(), arv, worksheet, styles)?;
+            }
+        }
+    }
+    Ok(next)
+}
+
+fn apply_cell<R: io::Read + io::Seek>(
+    cell: &OdsCell,
+    col: u32,
+    row: u32,
+    style: Option<&Style>,
+    arv: &mut zip::ZipArchive<R>,
+    worksheet: &mut Worksheet,
+    styles: &OdsStyles,
+) -> Result<(), XlsxError> {
+    if cell.columns > 1 || cell.rows > 1 {
+        worksheet.add_merge_cells(format!(
+            "{}:{}",
+            coordinate_from_index(col, row),
+            coordinate_from_index(col + cell.columns - 1, row + cell.rows - 1)
+        ));
+    }
+    for image in &cell.images {
+        add_image(image, col, row, arv, worksheet)?;
+    }
+
+    let mut style = style.cloned().unwrap_or_default();
+    let target = worksheet.cell_mut((col, row));
+    match cell.value_type.as_deref() {
+        Some("float" | "percentage" | "currency") => {
+            if let Some(v) = cell.value.as_deref().and_then(|v| v.parse::<f64>().ok()) {
+                target.set_value_number(v);
+            }
+        }
+        Some("date") => {
+            if let Some(v) = cell.date_value.as_deref().and_then(iso_8601_to_excel) {
+                target.set_value_number(v);
+            }
+            set_default_format(&mut style, "yyyy-mm-dd");
+        }
+        Some("time") => {
+            if let Some(v) = cell.time_value.as_deref().and_then(duration_to_excel) {
+                target.set_value_number(v);
+            }
+            set_default_format(&mut style, "hh:mm:ss");
+        }
+        Some("boolean") => {
+            target.set_value_bool(cell.boolean_value.as_deref() == Some("true"));
+        }
+        _ => {
+            if let Some(v) = &cell.string_value {
+                target.set_value_string(v);
+            } else if let Some(v) = rich_text(cell, &style, styles) {
+                target.set_rich_text(v);
+            } else if !cell.paragraphs.is_empty() {
+                target.set_value_string(cell.text());
+            }
+        }
+    }
+    if let Some(v) = &cell.formula {
+        let formula = to_excel(v);
+        if ERRORS.contains(&formula.as_str()) {
+            target.set_error(formula);
+        } else {
+            target.set_formula(formula);
+        }
+    }
+    if style != Style::default() {
+        target.set_style(style);
+    }
+    Ok(())
+}
+
+fn set_default_format(style: &mut Style, format_code: &str) {
+    if style.number_format().is_none_or(|v| v.format_code() == "General") {
+        style.number_format_mut().set_format_code(format_code);
+    }
+}
+
+/// Rich text of a cell that has runs of styled text.
+fn rich_text(cell: &OdsCell, style: &Style, styles: &OdsStyles) -> Option<RichText> {
+    if !cell.paragraphs.iter().flatten().any(|v| v.1.is_some()) {
+        return None;
+    }
+    let base = style.font().cloned().unwrap_or_else(Font::default_value);
+    let mut rich_text = RichText::default();
+    for (index, paragraph) in cell.paragraphs.iter().enumerate() {
+        if index > 0 {
+            let mut element = TextElement::default();
+            element.set_text("\n");
+            rich_text.add_rich_text_elements(element);
+        }
+        for (text, text_style) in paragraph {
+            let mut element = TextElement::default();
+            element.set_text(text);
+            if let Some(v) = text_style.as_deref().and_then(|v| styles.text_font(v, &base)) {
+                element.set_run_properties(v);
+            }
+            rich_text.add_rich_text_elements(element);
+        }
+    }
+    Some(rich_text)
+}
+
+/// A duration such as `PT12H30M00S` in days.
+fn duration_to_excel(value: &str) -> Option<f64> {
+    let value = value.strip_prefix('-').unwrap_or(value);
+    let mut days = 0.0;
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in value.strip_prefix('P')?.chars() {
+        let unit = match c {
+            'T' => {
+                in_time = true;
+                continue;
+            }
+            'Y' => 365.0,
+            'M' if !in_time => 30.0,
+            'D' => 1.0,
+            'H' => 1.0 / 24.0,
+            'M' => 1.0 / 1440.0,
+            'S' => 1.0 / 86400.0,
+            _ => {
+                number.push(c);
+                continue;
+            }
+        };
+        days += number.parse::<f64>().ok()? * unit;
+        number.clear();
+    }
+    Some(days)
+}
+
+fn add_image<R: io::Read + io::Seek>(
+    image: &FrameImage,
+    col: u32,
+    row: u32,
+    arv: &mut zip::ZipArchive<R>,
+    worksheet: &mut Worksheet,
+) -> Result<(), XlsxError> {
+    let path = image.href.trim_start_matches("./");
+    if path.contains("://") {
+        return Ok(());
+    }
+    let Some(data) = read_part(arv, path)? else {
+        return Ok(());
+    };
+    let (width, height) = if image.width > 0.0 && image.height > 0.0 {
+        (image.width * 96.0 / 72.0, image.height * 96.0 / 72.0)
+    } else if let Ok(v) = imagesize::blob_size(&data) {
+        (num_traits::cast(v.width).unwrap(), num_traits::cast(v.height).unwrap())
+    } else {
+        return Ok(());
+    };
+    // Skip an image too large for the sheet.
+    let (Some(height), Some(width)) = (
+        num_traits::cast(height.round()),
+        num_traits::cast(width.round()),
+    ) else {
+        return Ok(());
+    };
+    let mut marker = MarkerType::default();
+    marker.set_coordinate(coordinate_from_index(col, row));
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let mut obj = Image::default();
+    obj.new_image_with_dimensions(height, width, name, data, marker);
+    worksheet.add_image(obj);
+    Ok(())
+}
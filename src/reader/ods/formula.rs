@@ -0,0 +1,259 @@
+use crate::helper::{
+    address::join_address,
+    formula::{
+        ERRORS,
+        FormulaTokenSubTypes,
+        FormulaTokenTypes,
+        parse_to_tokens,
+    },
+};
+
+/// Prefix of the functions of Excel that have no `OpenFormula` name.
+const MICROSOFT_PREFIX: &str = "COM.MICROSOFT.";
+
+#[derive(Debug, PartialEq)]
+enum Group {
+    Paren,
+    Function,
+    Array,
+    ArrayRow,
+}
+
+/// Convert an `OpenFormula` expression, such as `of:=SUM([.A1:.B2];1)`, to
+/// the A1 syntax of Excel.
+/// Formulas in the Excel syntax (`msoxl:`) are kept as they are.
+pub(crate) fn to_excel(formula: &str) -> String {
+    let (namespace, body) = match formula.split_once(':') {
+        Some((v @ ("of" | "oooc" | "msoxl"), body)) => (v, body),
+        _ => ("of", formula),
+    };
+    let body = body.strip_prefix('=').unwrap_or(body);
+    if namespace == "msoxl" {
+        return body.to_string();
+    }
+
+    let tokens = parse_to_tokens(format!("={}", excel_operators(body)));
+    let mut result = String::with_capacity(body.len());
+    let mut stack: Vec<Group> = Vec::new();
+    for token in &tokens {
+        let value = token.get_value();
+        let is_start = token.get_token_sub_type() == &FormulaTokenSubTypes::Start;
+        match token.get_token_type() {
+            FormulaTokenTypes::Function if is_start => match value {
+                "ARRAY" => {
+                    result.push('{');
+                    stack.push(Group::Array);
+                }
+                "ARRAYROW" => stack.push(Group::ArrayRow),
+                _ => {
+                    result.push_str(value.strip_prefix(MICROSOFT_PREFIX).unwrap_or(value));
+                    result.push('(');
+                    stack.push(Group::Function);
+                }
+            },
+            FormulaTokenTypes::Function | FormulaTokenTypes::Subexpression if !is_start => {
+                match stack.pop() {
+                    Some(Group::Array) => result.push('}'),
+                    Some(Group::ArrayRow) => {}
+                    _ => result.push(')'),
+                }
+            }
+            FormulaTokenTypes::Subexpression => {
+                result.push('(');
+                stack.push(Group::Paren);
+            }
+            FormulaTokenTypes::OperatorInfix | FormulaTokenTypes::Argument if value == "," => {
+                result.push(if stack.last() == Some(&Group::Array) {
+                    ';'
+                } else {
+                    ','
+                });
+            }
+            // The tokenizer may report one intersection twice.
+            FormulaTokenTypes::OperatorInfix
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Intersection =>
+            {
+                if !result.ends_with(' ') {
+                    result.push(' ');
+                }
+            }
+            FormulaTokenTypes::Operand => match token.get_token_sub_type() {
+                FormulaTokenSubTypes::Text => {
+                    result.push('"');
+                    result.push_str(&value.replace('"', "\"\""));
+                    result.push('"');
+                }
+                FormulaTokenSubTypes::Range => {
+                    match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                        Some(v) => result.push_str(&reference(v)),
+                        None => result.push_str(value),
+                    }
+                }
+                _ => result.push_str(value),
+            },
+            FormulaTokenTypes::Whitespace | FormulaTokenTypes::Noop => {}
+            _ => result.push_str(value),
+        }
+    }
+    result
+}
+
+/// Replace the operators of `OpenFormula` that Excel spells differently, so
+/// that the expression can be tokenized: `;` and `~` by `,`, `|` by `;` and
+/// `!` by a space. Strings, references and errors are kept as they are.
+fn excel_operators(body: &str) -> String {
+    let mut result = String::with_capacity(body.len());
+    let mut in_string = false;
+    let mut in_quote = false;
+    let mut in_reference = false;
+    let mut error_start = None;
+    for c in body.chars() {
+        if let Some(start) = error_start {
+            let error = format!("{}{c}", &result[start..]);
+            if ERRORS.iter().any(|v| v.starts_with(&error)) {
+                result.push(c);
+                if ERRORS.contains(&error.as_str()) {
+                    error_start = None;
+                }
+                continue;
+            }
+            error_start = None;
+        }
+        match c {
+            '#' if !in_string && !in_reference => error_start = Some(result.len()),
+            '"' if !in_reference => in_string = !in_string,
+            '\'' if in_reference => in_quote = !in_quote,
+            '[' if !in_string => in_reference = true,
+            ']' if !in_string && !in_quote => in_reference = false,
+            _ => {}
+        }
+        if in_string || in_reference {
+            result.push(c);
+            continue;
+        }
+        result.push(match c {
+            ';' | '~' => ',',
+            '|' => ';',
+            '!' => ' ',
+            _ => c,
+        });
+    }
+    result
+}
+
+/// Index after the closing quote of the literal starting at `start`, a
+/// doubled quote is part of the literal.
+fn quoted_end(chars: &[char], start: usize, quote: char) -> usize {
+    let mut index = start + 1;
+    while index < chars.len() {
+        if chars[index] == quote {
+            if chars.get(index + 1) == Some(&quote) {
+                index += 2;
+                continue;
+            }
+            return index + 1;
+        }
+        index += 1;
+    }
+    chars.len()
+}
+
+/// A reference such as `.A1`, `$Sheet1.$A$1:.$B$2` or `'My Sheet'.A1`.
+fn reference(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut parts: Vec<(String, String)> = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+    while index <= chars.len() {
+        if index == chars.len() || chars[index] == ':' {
+            let part: String = chars[start..index].iter().collect();
+            parts.push(split_sheet(&part));
+            start = index + 1;
+        } else if chars[index] == '\'' {
+            index = quoted_end(&chars, index, '\'');
+            continue;
+        }
+        index += 1;
+    }
+
+    let sheet_name = parts
+        .iter()
+        .map(|v| v.0.as_str())
+        .find(|v| !v.is_empty())
+        .unwrap_or_default()
+        .to_string();
+    let last_sheet = parts
+        .last()
+        .map(|v| v.0.as_str())
+        .filter(|v| !v.is_empty() && *v != sheet_name);
+    let address = parts
+        .iter()
+        .map(|v| v.1.as_str())
+        .collect::<Vec<_>>()
+        .join(":");
+    match last_sheet {
+        Some(v) => join_address(&format!("{sheet_name}:{v}"), &address),
+        None => join_address(&sheet_name, &address),
+    }
+}
+
+/// Sheet and cell of one end of a reference, the sheet without its quotes.
+fn split_sheet(value: &str) -> (String, String) {
+    let mut in_quote = false;
+    let mut dot = None;
+    for (index, c) in value.char_indices() {
+        match c {
+            '\'' => in_quote = !in_quote,
+            '.' if !in_quote => dot = Some(index),
+            _ => {}
+        }
+    }
+    let Some(dot) = dot else {
+        return (String::new(), value.to_string());
+    };
+    let sheet = value[..dot].trim_start_matches('$');
+    let sheet = match sheet.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        Some(v) => v.replace("''", "'"),
+        None => sheet.to_string(),
+    };
+    (sheet, value[dot + 1..].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_excel_test() {
+        assert_eq!(to_excel("of:=SUM([.A1:.B2];1)"), "SUM(A1:B2,1)");
+        assert_eq!(
+            to_excel("of:=IF([.A3];['My Data'.A1]&\"x;y\";\"no\")"),
+            "IF(A3,'My Data'!A1&\"x;y\",\"no\")"
+        );
+        assert_eq!(to_excel("of:=SUM({1;2|3;4})"), "SUM({1,2;3,4})");
+        assert_eq!(to_excel("of:=[.A1:.B2]![.B1:.C3]"), "A1:B2 B1:C3");
+        assert_eq!(
+            to_excel("of:=IF([.A1];#DIV/0!;#N/A)"),
+            "IF(A1,#DIV/0!,#N/A)"
+        );
+        assert_eq!(to_excel("of:=\"say \"\"hi\"\"\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(to_excel("msoxl:=A1;1"), "A1;1");
+    }
+
+    #[test]
+    fn to_excel_microsoft_functions() {
+        assert_eq!(
+            to_excel("of:=COM.MICROSOFT.CONCAT(\"a\";[.A1])"),
+            "CONCAT(\"a\",A1)"
+        );
+        // Only the function names of Excel have the prefix.
+        assert_eq!(
+            to_excel("of:=ABC.COM.MICROSOFT.X()"),
+            "ABC.COM.MICROSOFT.X()"
+        );
+        assert_eq!(
+            to_excel("of:=\"COM.MICROSOFT.\"&COM.MICROSOFT.X"),
+            "\"COM.MICROSOFT.\"&COM.MICROSOFT.X"
+        );
+    }
+}
@@ -0,0 +1,624 @@
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    io,
+};
+
+use quick_xml::{
+    Reader,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    attribute,
+    text_of,
+};
+use crate::{
+    XlsxError,
+    reader::driver::xml_read_loop,
+    structs::{
+        Border,
+        Color,
+        Font,
+        HorizontalAlignmentValues,
+        Style,
+        VerticalAlignmentRunValues,
+        VerticalAlignmentValues,
+    },
+};
+
+/// Properties of a style, with the style it inherits from.
+#[derive(Debug, Default)]
+struct RawStyle {
+    parent:     Option<String>,
+    data_style: Option<String>,
+    properties: Vec<(String, String)>,
+}
+
+/// Styles of `styles.xml` and the automatic styles of `content.xml`.
+#[derive(Debug, Default)]
+pub(crate) struct OdsStyles {
+    cell_styles:   HashMap<String, RawStyle>,
+    text_styles:   HashMap<String, RawStyle>,
+    /// Format codes of the data styles.
+    data_styles:   HashMap<String, String>,
+    /// Widths of the column styles in points.
+    column_widths: HashMap<String, f64>,
+    /// Heights of the row styles in points, with their custom flags.
+    row_heights:   HashMap<String, (f64, bool)>,
+    hidden_tables: HashSet<String>,
+    resolved:      HashMap<String, Style>,
+}
+
+impl OdsStyles {
+    /// Read the styles up to the end of the element `end`.
+    pub(crate) fn read<R: io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        end: &[u8],
+    ) -> Result<(), XlsxError> {
+        let mut current: Option<(String, String, RawStyle)> = None;
+        let mut data_style: Option<DataStyle> = None;
+        let mut in_text = false;
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if let Some(v) = data_style.as_mut() {
+                    in_text = v.push(e);
+                } else if let Some(v) = Self::start(e, &mut current) {
+                    current = Some(v);
+                } else if let Some(v) = DataStyle::new(e) {
+                    data_style = Some(v);
+                }
+            },
+            Event::Empty(ref e) => {
+                if let Some(v) = data_style.as_mut() {
+                    v.push(e);
+                } else if let Some((name, family, style)) = Self::start(e, &mut current) {
+                    self.insert(name, &family, style);
+                }
+            },
+            Event::Text(ref e) => {
+                if in_text {
+                    if let Some(v) = data_style.as_mut() {
+                        v.push_text(&e.decode().unwrap_or_default());
+                    }
+                }
+            },
+            Event::GeneralRef(ref e) => {
+                if in_text {
+                    if let Some(v) = data_style.as_mut() {
+                        v.push_text(&text_of(e));
+                    }
+                }
+            },
+            Event::End(ref e) => {
+                let name = e.name().into_inner();
+                if name == end {
+                    break;
+                }
+                match name {
+                    b"style:style" => {
+                        if let Some((name, family, style)) = current.take() {
+                            self.insert(name, &family, style);
+                        }
+                    }
+                    b"number:text" | b"number:currency-symbol" => {
+                        if let Some(v) = data_style.as_mut() {
+                            v.end_text();
+                        }
+                        in_text = false;
+                    }
+                    _ => {
+                        if let Some(v) = data_style.take() {
+                            if name == v.element.as_bytes() {
+                                self.data_styles.insert(v.name.clone(), v.format_code());
+                            } else {
+                                data_style = Some(v);
+                            }
+                        }
+                    }
+                }
+            },
+            Event::Eof => break,
+        );
+        Ok(())
+    }
+
+    /// A `style:style` starts, or the properties of the current one are
+    /// added to it.
+    fn start(
+        e: &BytesStart<'_>,
+        current: &mut Option<(String, String, RawStyle)>,
+    ) -> Option<(String, String, RawStyle)> {
+        let name = e.name().into_inner();
+        if name == b"style:style" {
+            let style = RawStyle {
+                parent:     attribute(e, b"style:parent-style-name"),
+                data_style: attribute(e, b"style:data-style-name"),
+                properties: Vec::new(),
+            };
+            return Some((
+                attribute(e, b"style:name")?,
+                attribute(e, b"style:family").unwrap_or_default(),
+                style,
+            ));
+        }
+        if let Some((_, _, style)) = current {
+            if name.starts_with(b"style:") && name.ends_with(b"-properties") {
+                for attr in e.attributes().with_checks(false).flatten() {
+                    style.properties.push((
+                        String::from_utf8_lossy(attr.key.into_inner()).into_owned(),
+                        String::from_utf8_lossy(&attr.value).into_owned(),
+                    ));
+                }
+            }
+        }
+        None
+    }
+
+    fn insert(&mut self, name: String, family: &str, style: RawStyle) {
+        let property = |key: &str| {
+            style
+                .properties
+                .iter()
+                .find(|v| v.0 == key)
+                .map(|v| v.1.as_str())
+        };
+        match family {
+            "table-cell" => {
+                self.cell_styles.insert(name, style);
+            }
+            "text" => {
+                self.text_styles.insert(name, style);
+            }
+            "table-column" => {
+                if let Some(v) = property("style:column-width").and_then(length_to_points) {
+                    self.column_widths.insert(name, v);
+                }
+            }
+            "table-row" => {
+                if let Some(v) = property("style:row-height").and_then(length_to_points) {
+                    let custom = property("style:use-optimal-row-height") != Some("true");
+                    self.row_heights.insert(name, (v, custom));
+                }
+            }
+            "table" => {
+                if property("table:display") == Some("false") {
+                    self.hidden_tables.insert(name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Width of a column style in characters.
+    pub(crate) fn column_width(&self, name: &str) -> Option<f64> {
+        let points = self.column_widths.get(name)?;
+        let pixels = points * 96.0 / 72.0;
+        Some((((pixels - 5.0) / 7.0).max(0.0) * 100.0).round() / 100.0)
+    }
+
+    /// Height of a row style in points, with its custom flag.
+    pub(crate) fn row_height(&self, name: &str) -> Option<(f64, bool)> {
+        self.row_heights.get(name).copied()
+    }
+
+    pub(crate) fn is_hidden_table(&self, name: &str) -> bool {
+        self.hidden_tables.contains(name)
+    }
+
+    /// Format code of the data style of a cell style.
+    pub(crate) fn format_code(&self, name: &str) -> Option<&str> {
+        let mut style = self.cell_styles.get(name)?;
+        loop {
+            if let Some(v) = style.data_style.as_ref().and_then(|v| self.data_styles.get(v)) {
+                return Some(v);
+            }
+            style = self.cell_styles.get(style.parent.as_ref()?)?;
+        }
+    }
+
+    /// Style of a cell style, with the properties it inherits.
+    pub(crate) fn cell_style(&mut self, name: &str) -> Style {
+        if let Some(v) = self.resolved.get(name) {
+            return v.clone();
+        }
+        let mut chain = Vec::new();
+        let mut current = self.cell_styles.get(name);
+        while let Some(style) = current {
+            if chain.len() > 16 {
+                break;
+            }
+            chain.push(style);
+            current = style.parent.as_ref().and_then(|v| self.cell_styles.get(v));
+        }
+        let mut style = Style::default();
+        for raw in chain.iter().rev() {
+            for (key, value) in &raw.properties {
+                apply_cell_property(&mut style, key, value, &raw.properties);
+            }
+        }
+        if let Some(v) = self.format_code(name) {
+            style.number_format_mut().set_format_code(v);
+        }
+        self.resolved.insert(name.to_string(), style.clone());
+        style
+    }
+
+    /// Font of a text style.
+    pub(crate) fn text_font(&self, name: &str, base: &Font) -> Option<Font> {
+        let style = self.text_styles.get(name)?;
+        let mut font = base.clone();
+        for (key, value) in &style.properties {
+            apply_text_property(&mut font, key, value);
+        }
+        Some(font)
+    }
+}
+
+/// A length such as `2.258cm` or `10pt` in points.
+pub(crate) fn length_to_points(value: &str) -> Option<f64> {
+    let index = value
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(index);
+    let number: f64 = number.trim().parse().ok()?;
+    let factor = match unit {
+        "pt" | "" => 1.0,
+        "in" => 72.0,
+        "cm" => 72.0 / 2.54,
+        "mm" => 72.0 / 25.4,
+        "pc" => 12.0,
+        "px" => 0.75,
+        _ => return None,
+    };
+    Some(number * factor)
+}
+
+/// `#rrggbb` as `FFRRGGBB`.
+pub(crate) fn argb(value: &str) -> Option<String> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(format!("FF{}", hex.to_uppercase()))
+}
+
+fn color(value: &str) -> Option<Color> {
+    let mut color = Color::default();
+    color.set_argb_str(argb(value)?);
+    Some(color)
+}
+
+fn apply_cell_property(style: &mut Style, key: &str, value: &str, properties: &[(String, String)]) {
+    match key {
+        "fo:background-color" => {
+            if let Some(v) = argb(value) {
+                style.set_background_color(v);
+            }
+        }
+        "fo:border" | "fo:border-left" | "fo:border-right" | "fo:border-top"
+        | "fo:border-bottom" | "style:diagonal-bl-tr" | "style:diagonal-tl-br" => {
+            let Some((line, color)) = border(value) else {
+                return;
+            };
+            let sides: &[usize] = match key {
+                "fo:border" => &[0, 1, 2, 3],
+                "fo:border-left" => &[0],
+                "fo:border-right" => &[1],
+                "fo:border-top" => &[2],
+                "fo:border-bottom" => &[3],
+                _ => &[4],
+            };
+            let borders = style.borders_mut();
+            if key == "style:diagonal-bl-tr" {
+                borders.set_diagonal_up(true);
+            } else if key == "style:diagonal-tl-br" {
+                borders.set_diagonal_down(true);
+            }
+            for side in sides {
+                let border = match side {
+                    0 => borders.left_mut(),
+                    1 => borders.right_mut(),
+                    2 => borders.top_mut(),
+                    3 => borders.bottom_mut(),
+                    _ => borders.diagonal_mut(),
+                };
+                border.set_border_style(line);
+                if let Some(v) = color.clone() {
+                    border.set_color(v);
+                }
+            }
+        }
+        "fo:wrap-option" => {
+            if value == "wrap" {
+                style.alignment_mut().set_wrap_text(true);
+            }
+        }
+        "style:vertical-align" => {
+            let vertical = match value {
+                "top" => VerticalAlignmentValues::Top,
+                "middle" => VerticalAlignmentValues::Center,
+                "bottom" => VerticalAlignmentValues::Bottom,
+                _ => return,
+            };
+            style.alignment_mut().set_vertical(vertical);
+        }
+        "style:rotation-angle" => {
+            let angle: f64 = value.trim_end_matches("deg").parse().unwrap_or(0.0);
+            let angle: u32 = num_traits::cast(angle.round().rem_euclid(360.0)).unwrap_or(0);
+            let rotation = match angle {
+                1..=90 => angle,
+                270..=359 => 90 + (360 - angle),
+                _ => return,
+            };
+            style.alignment_mut().set_text_rotation(rotation);
+        }
+        "fo:text-align" => {
+            let source = properties
+                .iter()
+                .find(|v| v.0 == "style:text-align-source")
+                .map(|v| v.1.as_str());
+            if source == Some("value-type") {
+                return;
+            }
+            let horizontal = match value {
+                "start" | "left" => HorizontalAlignmentValues::Left,
+                "center" => HorizontalAlignmentValues::Center,
+                "end" | "right" => HorizontalAlignmentValues::Right,
+                "justify" => HorizontalAlignmentValues::Justify,
+                _ => return,
+            };
+            style.alignment_mut().set_horizontal(horizontal);
+        }
+        "style:cell-protect" => {
+            let protection = style.protection_mut();
+            protection.set_locked(value.contains("protected"));
+            protection.set_hidden(value.contains("hidden"));
+        }
+        _ => apply_text_property(style.font_mut(), key, value),
+    }
+}
+
+fn apply_text_property(font: &mut Font, key: &str, value: &str) {
+    match key {
+        "style:font-name" | "fo:font-family" => {
+            font.set_name(value.trim_matches(&['\'', '"'][..]));
+        }
+        "fo:font-size" => {
+            if let Some(v) = length_to_points(value) {
+                font.set_size(v);
+            }
+        }
+        "fo:font-weight" => {
+            font.set_bold(value == "bold" || value.parse::<u32>().is_ok_and(|v| v >= 600));
+        }
+        "fo:font-style" => {
+            font.set_italic(value == "italic" || value == "oblique");
+        }
+        "fo:color" => {
+            if let Some(v) = color(value) {
+                font.set_color(v);
+            }
+        }
+        "style:text-underline-style" => {
+            font.set_underline(if value == "none" { "none" } else { "single" });
+        }
+        "style:text-underline-type" => {
+            if value == "double" {
+                font.set_underline("double");
+            }
+        }
+        "style:text-line-through-style" => {
+            font.set_strikethrough(value != "none");
+        }
+        "style:text-position" => {
+            let position = value.split_whitespace().next().unwrap_or_default();
+            let alignment = match position {
+                "super" => VerticalAlignmentRunValues::Superscript,
+                "sub" => VerticalAlignmentRunValues::Subscript,
+                _ => match position.trim_end_matches('%').parse::<f64>() {
+                    Ok(v) if v > 0.0 => VerticalAlignmentRunValues::Superscript,
+                    Ok(v) if v < 0.0 => VerticalAlignmentRunValues::Subscript,
+                    _ => VerticalAlignmentRunValues::Baseline,
+                },
+            };
+            font.vertical_text_alignment_mut().set_val(alignment);
+        }
+        _ => {}
+    }
+}
+
+/// Line style and color of a border such as `0.74pt solid #000000`.
+fn border(value: &str) -> Option<(&'static str, Option<Color>)> {
+    let mut width = 0.75;
+    let mut line = "";
+    let mut border_color = None;
+    for part in value.split_whitespace() {
+        if part.starts_with('#') {
+            border_color = color(part);
+        } else if let Some(v) = length_to_points(part) {
+            width = v;
+        } else {
+            line = part;
+        }
+    }
+    let is_thick = width > 1.0;
+    let style = match line {
+        "none" | "hidden" | "" => return None,
+        "dotted" => Border::BORDER_DOTTED,
+        "dashed" | "fine-dashed" if is_thick => Border::BORDER_MEDIUMDASHED,
+        "dashed" | "fine-dashed" => Border::BORDER_DASHED,
+        "dash-dot" if is_thick => Border::BORDER_MEDIUMDASHDOT,
+        "dash-dot" => Border::BORDER_DASHDOT,
+        "dash-dot-dot" if is_thick => Border::BORDER_MEDIUMDASHDOTDOT,
+        "dash-dot-dot" => Border::BORDER_DASHDOTDOT,
+        "double" | "double-thin" => Border::BORDER_DOUBLE,
+        _ if width > 2.0 => Border::BORDER_THICK,
+        _ if is_thick => Border::BORDER_MEDIUM,
+        _ if width < 0.1 => Border::BORDER_HAIR,
+        _ => Border::BORDER_THIN,
+    };
+    Some((style, border_color))
+}
+
+/// A `number:*-style` being read.
+#[derive(Debug)]
+struct DataStyle {
+    element: String,
+    name:    String,
+    code:    String,
+    text:    Option<String>,
+    is_time: bool,
+    /// Whether the hours are not truncated, as in `[h]:mm`.
+    elapsed: bool,
+}
+
+impl DataStyle {
+    fn new(e: &BytesStart<'_>) -> Option<Self> {
+        let element = String::from_utf8_lossy(e.name().into_inner()).into_owned();
+        if !(element.starts_with("number:") && element.ends_with("-style")) {
+            return None;
+        }
+        Some(Self {
+            name: attribute(e, b"style:name")?,
+            code: String::new(),
+            text: None,
+            is_time: element == "number:time-style",
+            elapsed: attribute(e, b"number:truncate-on-overflow").as_deref()
+                == Some("false"),
+            element,
+        })
+    }
+
+    /// Add an element of the style, `true` is returned when its text is
+    /// part of the format.
+    fn push(&mut self, e: &BytesStart<'_>) -> bool {
+        let attribute = |key: &[u8]| attribute(e, key);
+        let number = |key: &[u8]| -> usize {
+            attribute(key).and_then(|v| v.parse().ok()).unwrap_or(0)
+        };
+        let is_long = attribute(b"number:style").as_deref() == Some("long");
+        match e.name().into_inner() {
+            b"number:number" => {
+                let integer = number(b"number:min-integer-digits");
+                let mut code = if attribute(b"number:grouping").as_deref() == Some("true") {
+                    format!("#,{}{}", "#".repeat(3 - integer.min(3)), "0".repeat(integer))
+                } else if integer == 0 {
+                    "#".to_string()
+                } else {
+                    "0".repeat(integer)
+                };
+                let decimal = number(b"number:decimal-places");
+                if decimal > 0 {
+                    code.push('.');
+                    code.push_str(&"0".repeat(decimal));
+                }
+                self.code.push_str(&code);
+            }
+            b"number:scientific-number" => {
+                let integer = number(b"number:min-integer-digits").max(1);
+                let decimal = number(b"number:decimal-places");
+                let exponent = attribute(b"number:min-exponent-digits")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(2);
+                self.code.push_str(&"0".repeat(integer));
+                if decimal > 0 {
+                    self.code.push('.');
+                    self.code.push_str(&"0".repeat(decimal));
+                }
+                self.code.push_str("E+");
+                self.code.push_str(&"0".repeat(exponent));
+            }
+            b"number:fraction" => {
+                // The integer part is shown when its digits are given.
+                match attribute(b"number:min-integer-digits") {
+                    Some(v) if v.parse::<usize>().unwrap_or(0) > 0 => self.code.push_str("0 "),
+                    Some(_) => self.code.push_str("# "),
+                    None => {}
+                }
+                let numerator = number(b"number:min-numerator-digits").max(1);
+                self.code.push_str(&"?".repeat(numerator));
+                self.code.push('/');
+                if let Some(v) = attribute(b"number:denominator-value") {
+                    self.code.push_str(&v);
+                } else {
+                    let denominator = number(b"number:min-denominator-digits").max(1);
+                    self.code.push_str(&"?".repeat(denominator));
+                }
+            }
+            b"number:text-content" => self.code.push('@'),
+            b"number:year" => self.code.push_str(if is_long { "yyyy" } else { "yy" }),
+            b"number:month" => {
+                let textual = attribute(b"number:textual").as_deref() == Some("true");
+                self.code.push_str(match (textual, is_long) {
+                    (true, true) => "mmmm",
+                    (true, false) => "mmm",
+                    (false, true) => "mm",
+                    (false, false) => "m",
+                });
+            }
+            b"number:day" => self.code.push_str(if is_long { "dd" } else { "d" }),
+            b"number:day-of-week" => self.code.push_str(if is_long { "dddd" } else { "ddd" }),
+            b"number:hours" => {
+                let hours = if is_long { "hh" } else { "h" };
+                if self.is_time && self.elapsed {
+                    self.code.push('[');
+                    self.code.push_str(hours);
+                    self.code.push(']');
+                } else {
+                    self.code.push_str(hours);
+                }
+            }
+            b"number:minutes" => self.code.push_str(if is_long { "mm" } else { "m" }),
+            b"number:seconds" => {
+                self.code.push_str(if is_long { "ss" } else { "s" });
+                let decimal = number(b"number:decimal-places");
+                if decimal > 0 {
+                    self.code.push('.');
+                    self.code.push_str(&"0".repeat(decimal));
+                }
+            }
+            b"number:am-pm" => self.code.push_str("AM/PM"),
+            b"number:text" | b"number:currency-symbol" => {
+                self.text = Some(String::new());
+                return true;
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn push_text(&mut self, value: &str) {
+        if let Some(v) = self.text.as_mut() {
+            v.push_str(value);
+        }
+    }
+
+    fn end_text(&mut self) {
+        let Some(text) = self.text.take() else {
+            return;
+        };
+        if text.chars().all(|c| " -/:,.()%$".contains(c)) {
+            self.code.push_str(&text);
+        } else {
+            self.code.push('"');
+            self.code.push_str(&text.replace('"', "\\\""));
+            self.code.push('"');
+        }
+    }
+
+    fn format_code(&self) -> String {
+        if self.code.is_empty() {
+            "General".to_string()
+        } else {
+            self.code.clone()
+        }
+    }
+}
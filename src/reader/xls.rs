@@ -0,0 +1,623 @@
+//! Reader of the Excel 97-2003 workbook (BIFF8).
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io,
+    io::Read,
+    path::Path,
+};
+
+use self::{
+    formula::{
+        FormulaContext,
+        SupBook,
+        Xti,
+        decompile,
+        error_text,
+    },
+    record::{
+        Record,
+        RecordReader,
+    },
+    style::StyleTable,
+};
+use crate::{
+    XlsxError,
+    helper::{
+        coordinate::{
+            coordinate_from_index,
+            string_from_column_index,
+        },
+        number_format::is_date_time_format,
+    },
+    new_file_empty_worksheet,
+    structs::{
+        Cell,
+        CellFormula,
+        CellFormulaValues,
+        DefinedName,
+        Style,
+        Workbook,
+        Worksheet,
+    },
+};
+
+pub(crate) mod formula;
+pub(crate) mod record;
+pub(crate) mod style;
+
+/// Built-in names by the character stored in the `NAME` record.
+pub(crate) const BUILT_IN_NAMES: [&str; 14] = [
+    "_xlnm.Consolidate_Area",
+    "_xlnm.Auto_Open",
+    "_xlnm.Auto_Close",
+    "_xlnm.Extract",
+    "_xlnm.Database",
+    "_xlnm.Criteria",
+    "_xlnm.Print_Area",
+    "_xlnm.Print_Titles",
+    "_xlnm.Recorder",
+    "_xlnm.Data_Form",
+    "_xlnm.Auto_Activate",
+    "_xlnm.Auto_Deactivate",
+    "_xlnm.Sheet_Title",
+    "_xlnm._FilterDatabase",
+];
+
+const BIFF8: u16 = 0x0600;
+const GLOBALS: u16 = 0x0005;
+const WORKSHEET: u16 = 0x0010;
+/// Days from the epoch of the 1900 date system to the one of 1904.
+const DAYS_1900_TO_1904: f64 = 1462.0;
+
+#[derive(Debug, Default)]
+struct BoundSheet {
+    offset: usize,
+    state:  u8,
+    kind:   u8,
+    name:   String,
+}
+
+/// A defined name, `sheet_index` is the 1-based index of the sheet it is
+/// local to, 0 for a workbook name.
+#[derive(Debug, Default)]
+pub(crate) struct NameRecord {
+    pub(crate) name:        String,
+    pub(crate) sheet_index: u16,
+    pub(crate) hidden:      bool,
+    pub(crate) is_macro:    bool,
+    pub(crate) rgce:        Vec<u8>,
+    pub(crate) rgcb:        Vec<u8>,
+}
+
+#[derive(Debug, Default)]
+struct Globals {
+    is_1904:     bool,
+    styles:      StyleTable,
+    shared_list: Vec<String>,
+    sheets:      Vec<BoundSheet>,
+    names:       Vec<NameRecord>,
+    context:     FormulaContext,
+}
+
+/// A formula of a sheet: the cell and the tokens, with the data following
+/// them.
+type FormulaTokens = (Vec<u8>, Vec<u8>);
+
+/// read spreadsheet from arbitrary reader.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is `Workbook`. Err is error message.
+pub fn read_reader<R: Read + io::Seek>(reader: R) -> Result<Workbook, XlsxError> {
+    let mut compound_file = cfb::CompoundFile::open(reader)?;
+    if !compound_file.exists("/Workbook") {
+        return Err(XlsxError::InvalidRecord(
+            "the file has no BIFF8 workbook stream".into(),
+        ));
+    }
+    let mut stream = Vec::new();
+    compound_file
+        .open_stream("/Workbook")?
+        .read_to_end(&mut stream)?;
+    read_stream(&stream)
+}
+
+/// read spreadsheet file.
+/// Cell values, formulas, styles, merged cells, column widths, row heights,
+/// sheets and defined names are read. Chart sheets, macro sheets and drawings
+/// are skipped.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Workbook. Err is error message.
+/// # Examples
+/// ```no_run
+/// let path = std::path::Path::new("./tests/test_files/aaa.xls");
+/// let book = umya_spreadsheet::reader::xls::read(path).unwrap();
+/// let path = std::path::Path::new("./tests/result_files/aaa_from_xls.xlsx");
+/// let _unused = umya_spreadsheet::writer::xlsx::write(&book, path);
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Workbook, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(file)
+}
+
+fn read_stream(stream: &[u8]) -> Result<Workbook, XlsxError> {
+    let mut pos = 0;
+    check_bof(stream, &mut pos, GLOBALS)?;
+    let globals = read_globals(stream, &mut pos)?;
+
+    let mut wb = new_file_empty_worksheet();
+    if let Some(font) = globals.styles.default_font() {
+        if let Some(v) = wb.stylesheet_mut().fonts_mut().font_mut().first_mut() {
+            *v = font;
+        }
+    }
+    let styles = globals.styles.styles();
+
+    // Worksheet index of each sheet of the workbook.
+    let mut sheet_index_list: Vec<Option<u32>> = Vec::new();
+    let mut index = 0;
+    for sheet in &globals.sheets {
+        if sheet.kind != 0 {
+            sheet_index_list.push(None);
+            continue;
+        }
+        let worksheet = wb.new_sheet(sheet.name.as_str())?;
+        match sheet.state {
+            1 => worksheet.set_sheet_state("hidden".into()),
+            2 => worksheet.set_sheet_state("veryHidden".into()),
+            _ => worksheet,
+        };
+        let mut pos = sheet.offset;
+        check_bof(stream, &mut pos, WORKSHEET)?;
+        read_sheet(stream, &mut pos, worksheet, &globals, &styles)?;
+        sheet_index_list.push(Some(index));
+        index += 1;
+    }
+    if wb.sheet_count() == 0 {
+        wb.new_sheet("Sheet1")?;
+    }
+    wb.set_active_sheet(0);
+
+    read_defined_names(&mut wb, &globals.names, &globals.context, &sheet_index_list)?;
+    Ok(wb)
+}
+
+fn check_bof(stream: &[u8], pos: &mut usize, kind: u16) -> Result<(), XlsxError> {
+    let record = Record::read(stream, pos)
+        .filter(|v| v.kind == record::BOF)
+        .ok_or_else(|| XlsxError::InvalidRecord("BOF record is not found".into()))?;
+    let mut reader = record.reader();
+    if reader.u16()? != BIFF8 {
+        return Err(XlsxError::InvalidRecord("only BIFF8 workbooks are supported".into()));
+    }
+    if reader.u16()? != kind {
+        return Err(XlsxError::InvalidRecord(format!(
+            "unexpected substream type at {pos}"
+        )));
+    }
+    Ok(())
+}
+
+fn read_globals(stream: &[u8], pos: &mut usize) -> Result<Globals, XlsxError> {
+    let mut globals = Globals::default();
+    while let Some(record) = Record::read(stream, pos) {
+        match record.kind {
+            record::EOF => break,
+            record::FILEPASS => {
+                return Err(XlsxError::InvalidRecord(
+                    "encrypted workbooks are not supported".into(),
+                ));
+            }
+            record::DATEMODE => globals.is_1904 = record.reader().u16()? == 1,
+            record::FONT => globals.styles.read_font(&record)?,
+            record::FORMAT => globals.styles.read_format(&record)?,
+            record::XF => globals.styles.read_xf(&record)?,
+            record::PALETTE => globals.styles.read_palette(&record)?,
+            record::BOUNDSHEET => {
+                let mut reader = record.reader();
+                let offset = num_traits::cast(reader.u32()?).unwrap();
+                let state = reader.u8()? & 0x03;
+                let kind = reader.u8()?;
+                let name = reader.short_string()?;
+                globals.sheets.push(BoundSheet {
+                    offset,
+                    state,
+                    kind,
+                    name,
+                });
+            }
+            record::SST => {
+                let mut reader = record.reader();
+                reader.skip(4)?;
+                let count = reader.u32()?;
+                for _ in 0..count {
+                    globals.shared_list.push(reader.rich_string()?);
+                }
+            }
+            record::SUPBOOK => {
+                let mut reader = record.reader();
+                reader.skip(2)?;
+                let is_internal = reader.u16()? == 0x0401;
+                globals.context.sup_books.push(SupBook {
+                    is_internal,
+                    names: Vec::new(),
+                });
+            }
+            record::EXTERNNAME => {
+                let mut reader = record.reader();
+                reader.skip(6)?;
+                let name = reader.short_string()?;
+                if let Some(sup_book) = globals.context.sup_books.last_mut() {
+                    sup_book.names.push(name);
+                }
+            }
+            record::EXTERNSHEET => {
+                let mut reader = record.reader();
+                let count = reader.u16()?;
+                for _ in 0..count {
+                    globals.context.xti_list.push(Xti {
+                        sup_book:    usize::from(reader.u16()?),
+                        first_sheet: reader.u16()?,
+                        last_sheet:  reader.u16()?,
+                    });
+                }
+            }
+            record::NAME => globals.names.push(read_name(&record)?),
+            _ => {}
+        }
+    }
+    globals.context.sheet_names = globals.sheets.iter().map(|v| v.name.clone()).collect();
+    globals.context.names = globals.names.iter().map(|v| v.name.clone()).collect();
+    Ok(globals)
+}
+
+fn read_name(record: &Record) -> Result<NameRecord, XlsxError> {
+    let mut reader = record.reader();
+    let options = reader.u16()?;
+    reader.skip(1)?;
+    let len = usize::from(reader.u8()?);
+    let size = usize::from(reader.u16()?);
+    reader.skip(2)?;
+    let sheet_index = reader.u16()?;
+    reader.skip(4)?;
+    let mut name = reader.string_chars(len)?;
+    if options & 0x20 != 0 {
+        let code = name.chars().next().map_or(0, |v| v as usize);
+        if let Some(v) = BUILT_IN_NAMES.get(code) {
+            name = (*v).to_string();
+        }
+    }
+    let rgce = reader.bytes(size)?.to_vec();
+    let rgcb = reader.bytes(reader.remaining())?.to_vec();
+    Ok(NameRecord {
+        name,
+        sheet_index,
+        hidden: options & 0x01 != 0,
+        is_macro: options & 0x0E != 0,
+        rgce,
+        rgcb,
+    })
+}
+
+pub(crate) fn read_defined_names(
+    wb: &mut Workbook,
+    names: &[NameRecord],
+    context: &FormulaContext,
+    sheet_index_list: &[Option<u32>],
+) -> Result<(), XlsxError> {
+    for record in names {
+        if record.is_macro || record.name.starts_with("_xlfn.") {
+            continue;
+        }
+        let Some(address) = decompile(&record.rgce, &record.rgcb, Some((0, 0)), context) else {
+            continue;
+        };
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(record.name.as_str());
+        defined_name.set_address(address);
+        defined_name.set_hidden(record.hidden);
+        if record.sheet_index > 0 {
+            let Some(Some(sheet_index)) = sheet_index_list.get(usize::from(record.sheet_index - 1))
+            else {
+                continue;
+            };
+            defined_name.set_local_sheet_id(*sheet_index);
+            wb.sheet_mut(*sheet_index as usize)?
+                .add_defined_names(defined_name);
+            continue;
+        }
+        if let Some(v) = defined_name.address_obj().first() {
+            if let Ok(s) = wb.sheet_by_name_mut(v.sheet_name()) {
+                s.add_defined_names(defined_name);
+                continue;
+            }
+        }
+        wb.add_defined_names(defined_name);
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+fn read_sheet(
+    stream: &[u8],
+    pos: &mut usize,
+    worksheet: &mut Worksheet,
+    globals: &Globals,
+    styles: &[Style],
+) -> Result<(), XlsxError> {
+    let set_style = |worksheet: &mut Worksheet, row: u16, col: u16, xf: u16| {
+        if let Some(style) = styles.get(usize::from(xf)) {
+            worksheet
+                .cell_mut((u32::from(col) + 1, u32::from(row) + 1))
+                .set_style(style.clone());
+        }
+    };
+    // the workbook is written in the 1900 date system
+    let number = |xf: u16, value: f64| {
+        let is_date = globals.is_1904
+            && styles
+                .get(usize::from(xf))
+                .and_then(Style::number_format)
+                .is_some_and(|v| shows_date(v.format_code()));
+        if is_date {
+            value + DAYS_1900_TO_1904
+        } else {
+            value
+        }
+    };
+    let mut string_cell: Option<(u16, u16)> = None;
+    let mut exp_list: Vec<(u16, u16, (u16, u16))> = Vec::new();
+    let mut shared_list: HashMap<(u16, u16), FormulaTokens> = HashMap::new();
+    let mut array_list: HashMap<(u16, u16), (String, FormulaTokens)> = HashMap::new();
+
+    while let Some(record) = Record::read(stream, pos) {
+        let mut reader = record.reader();
+        match record.kind {
+            record::EOF => break,
+            record::LABELSST => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                set_style(worksheet, row, col, xf);
+                let index = reader.u32()?;
+                let value = globals
+                    .shared_list
+                    .get(num_traits::cast::<u32, usize>(index).unwrap())
+                    .cloned()
+                    .unwrap_or_default();
+                cell(worksheet, row, col).set_value_string(value);
+            }
+            record::LABEL => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                set_style(worksheet, row, col, xf);
+                let value = reader.string()?;
+                cell(worksheet, row, col).set_value_string(value);
+            }
+            record::NUMBER => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                set_style(worksheet, row, col, xf);
+                let value = number(xf, reader.f64()?);
+                cell(worksheet, row, col).set_value_number(value);
+            }
+            record::RK => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                set_style(worksheet, row, col, xf);
+                let value = number(xf, rk_value(reader.u32()?));
+                cell(worksheet, row, col).set_value_number(value);
+            }
+            record::MULRK => {
+                let row = reader.u16()?;
+                let first = reader.u16()?;
+                let count = reader.remaining().saturating_sub(2) / 6;
+                for col in (first..).take(count) {
+                    let xf = reader.u16()?;
+                    set_style(worksheet, row, col, xf);
+                    let value = number(xf, rk_value(reader.u32()?));
+                    cell(worksheet, row, col).set_value_number(value);
+                }
+            }
+            record::BLANK => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                set_style(worksheet, row, col, xf);
+            }
+            record::MULBLANK => {
+                let row = reader.u16()?;
+                let first = reader.u16()?;
+                let count = reader.remaining().saturating_sub(2) / 2;
+                for col in (first..).take(count) {
+                    let xf = reader.u16()?;
+                    set_style(worksheet, row, col, xf);
+                }
+            }
+            record::BOOLERR => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                set_style(worksheet, row, col, xf);
+                let value = reader.u8()?;
+                let cell = cell(worksheet, row, col);
+                if reader.u8()? == 0 {
+                    cell.set_value_bool(value != 0);
+                } else {
+                    cell.set_error(error_text(value));
+                }
+            }
+            record::FORMULA => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                set_style(worksheet, row, col, xf);
+                let result = reader.bytes(8)?;
+                reader.skip(6)?;
+                let size = usize::from(reader.u16()?);
+                let rgce = reader.bytes(size)?;
+                let rgcb = reader.bytes(reader.remaining())?;
+
+                let cell = cell(worksheet, row, col);
+                if result[6..8] == [0xFF, 0xFF] {
+                    match result[0] {
+                        0x00 => string_cell = Some((row, col)),
+                        0x01 => {
+                            cell.set_formula_result_bool(result[2] != 0);
+                        }
+                        0x02 => {
+                            cell.set_formula_result_default(error_text(result[2]));
+                        }
+                        _ => {
+                            cell.set_formula_result_string("");
+                        }
+                    }
+                } else {
+                    let mut value = RecordReader::new(result);
+                    cell.set_formula_result_number(number(xf, value.f64()?));
+                }
+
+                if rgce.len() == 5 && rgce[0] == 0x01 {
+                    let mut value = RecordReader::new(&rgce[1..]);
+                    exp_list.push((row, col, (value.u16()?, value.u16()?)));
+                } else if let Some(v) = decompile(rgce, rgcb, None, &globals.context) {
+                    cell.set_formula(v);
+                }
+            }
+            record::STRING => {
+                if let Some((row, col)) = string_cell.take() {
+                    let value = reader.string()?;
+                    cell(worksheet, row, col).set_formula_result_string(value);
+                }
+            }
+            record::SHRFMLA | record::ARRAY => {
+                let first_row = reader.u16()?;
+                let last_row = reader.u16()?;
+                let first_col = u16::from(reader.u8()?);
+                let last_col = u16::from(reader.u8()?);
+                reader.skip(if record.kind == record::ARRAY { 6 } else { 2 })?;
+                let size = usize::from(reader.u16()?);
+                let rgce = reader.bytes(size)?.to_vec();
+                let rgcb = reader.bytes(reader.remaining())?.to_vec();
+                if record.kind == record::ARRAY {
+                    let range = format!(
+                        "{}:{}",
+                        coordinate_from_index(u32::from(first_col) + 1, u32::from(first_row) + 1),
+                        coordinate_from_index(u32::from(last_col) + 1, u32::from(last_row) + 1)
+                    );
+                    array_list.insert((first_row, first_col), (range, (rgce, rgcb)));
+                } else {
+                    shared_list.insert((first_row, first_col), (rgce, rgcb));
+                }
+            }
+            record::ROW => {
+                let row = reader.u16()?;
+                reader.skip(4)?;
+                let height = reader.u16()? & 0x7FFF;
+                reader.skip(4)?;
+                let options = reader.u32()?;
+                let hidden = options & 0x20 != 0;
+                let custom_height = options & 0x40 != 0;
+                if hidden || custom_height {
+                    let dimension = worksheet.row_dimension_mut(u32::from(row) + 1);
+                    dimension.set_hidden(hidden);
+                    if custom_height {
+                        dimension.set_height(f64::from(height) / 20.0);
+                        dimension.set_custom_height(true);
+                    }
+                }
+            }
+            record::COLINFO => {
+                let first = reader.u16()?;
+                let last = reader.u16()?.min(255);
+                let width = f64::from(reader.u16()?) / 256.0;
+                reader.skip(2)?;
+                let hidden = reader.u16()? & 0x01 != 0;
+                for col in first..=last {
+                    let column = string_from_column_index(u32::from(col) + 1);
+                    let dimension = worksheet.column_dimension_mut(&column);
+                    dimension.set_width(width);
+                    dimension.set_hidden(hidden);
+                }
+            }
+            record::MERGECELLS => {
+                let count = reader.u16()?;
+                for _ in 0..count {
+                    let first_row = u32::from(reader.u16()?) + 1;
+                    let last_row = u32::from(reader.u16()?) + 1;
+                    let first_col = u32::from(reader.u16()?) + 1;
+                    let last_col = u32::from(reader.u16()?) + 1;
+                    worksheet.add_merge_cells(format!(
+                        "{}:{}",
+                        coordinate_from_index(first_col, first_row),
+                        coordinate_from_index(last_col, last_row)
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // The shared and array formulas follow the first cell using them.
+    for (row, col, anchor) in exp_list {
+        if let Some((rgce, rgcb)) = shared_list.get(&anchor) {
+            let base = Some((u32::from(row), u32::from(col)));
+            if let Some(v) = decompile(rgce, rgcb, base, &globals.context) {
+                cell(worksheet, row, col).set_formula(v);
+            }
+        } else if let Some((range, (rgce, rgcb))) = array_list.get(&anchor) {
+            if (row, col) != anchor {
+                continue;
+            }
+            if let Some(v) = decompile(rgce, rgcb, None, &globals.context) {
+                let mut formula = CellFormula::default();
+                formula.set_formula_type(CellFormulaValues::Array);
+                formula.set_reference(range.as_str());
+                formula.set_text(v);
+                cell(worksheet, row, col)
+                    .cell_value_mut()
+                    .set_formula_obj(formula);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether a number format shows a date, so that the value depends on the
+/// date system. Times and elapsed times do not.
+fn shows_date(format: &str) -> bool {
+    if !is_date_time_format(format) {
+        return false;
+    }
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                chars.by_ref().find(|v| *v == '"');
+            }
+            '[' => {
+                chars.by_ref().find(|v| *v == ']');
+            }
+            '\\' | '_' | '*' => {
+                chars.next();
+            }
+            ';' => return false,
+            'd' | 'D' | 'y' | 'Y' => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn cell(worksheet: &mut Worksheet, row: u16, col: u16) -> &mut Cell {
+    worksheet.cell_mut((u32::from(col) + 1, u32::from(row) + 1))
+}
+
+fn read_cell_header(reader: &mut RecordReader) -> Result<(u16, u16, u16), XlsxError> {
+    Ok((reader.u16()?, reader.u16()?, reader.u16()?))
+}
+
+/// Number of a `RK` value: an integer or the high bits of a float, either
+/// of them multiplied by 100.
+pub(crate) fn rk_value(rk: u32) -> f64 {
+    let value = if rk & 0x02 != 0 {
+        f64::from(i32::from_le_bytes(rk.to_le_bytes()) >> 2)
+    } else {
+        f64::from_bits(u64::from(rk & 0xFFFF_FFFC) << 32)
+    };
+    if rk & 0x01 != 0 { value / 100.0 } else { value }
+}
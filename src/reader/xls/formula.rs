@@ -0,0 +1,822 @@
+use super::record::RecordReader;
+use crate::{
+    XlsxError,
+    helper::{
+        address::join_address,
+        coordinate::{
+            coordinate_from_index_with_lock,
+            string_from_column_index,
+        },
+    },
+};
+
+/// Built-in functions by their index: name and argument count, `-1` when
+/// the count is stored with the call.
+const FUNCTIONS: &[(u16, &str, i8)] = &[
+    (0, "COUNT", -1),
+    (1, "IF", -1),
+    (2, "ISNA", 1),
+    (3, "ISERROR", 1),
+    (4, "SUM", -1),
+    (5, "AVERAGE", -1),
+    (6, "MIN", -1),
+    (7, "MAX", -1),
+    (8, "ROW", -1),
+    (9, "COLUMN", -1),
+    (10, "NA", 0),
+    (11, "NPV", -1),
+    (12, "STDEV", -1),
+    (13, "DOLLAR", -1),
+    (14, "FIXED", -1),
+    (15, "SIN", 1),
+    (16, "COS", 1),
+    (17, "TAN", 1),
+    (18, "ATAN", 1),
+    (19, "PI", 0),
+    (20, "SQRT", 1),
+    (21, "EXP", 1),
+    (22, "LN", 1),
+    (23, "LOG10", 1),
+    (24, "ABS", 1),
+    (25, "INT", 1),
+    (26, "SIGN", 1),
+    (27, "ROUND", 2),
+    (28, "LOOKUP", -1),
+    (29, "INDEX", -1),
+    (30, "REPT", 2),
+    (31, "MID", 3),
+    (32, "LEN", 1),
+    (33, "VALUE", 1),
+    (34, "TRUE", 0),
+    (35, "FALSE", 0),
+    (36, "AND", -1),
+    (37, "OR", -1),
+    (38, "NOT", 1),
+    (39, "MOD", 2),
+    (40, "DCOUNT", 3),
+    (41, "DSUM", 3),
+    (42, "DAVERAGE", 3),
+    (43, "DMIN", 3),
+    (44, "DMAX", 3),
+    (45, "DSTDEV", 3),
+    (46, "VAR", -1),
+    (47, "DVAR", 3),
+    (48, "TEXT", 2),
+    (49, "LINEST", -1),
+    (50, "TREND", -1),
+    (51, "LOGEST", -1),
+    (52, "GROWTH", -1),
+    (56, "PV", -1),
+    (57, "FV", -1),
+    (58, "NPER", -1),
+    (59, "PMT", -1),
+    (60, "RATE", -1),
+    (61, "MIRR", 3),
+    (62, "IRR", -1),
+    (63, "RAND", 0),
+    (64, "MATCH", -1),
+    (65, "DATE", 3),
+    (66, "TIME", 3),
+    (67, "DAY", 1),
+    (68, "MONTH", 1),
+    (69, "YEAR", 1),
+    (70, "WEEKDAY", -1),
+    (71, "HOUR", 1),
+    (72, "MINUTE", 1),
+    (73, "SECOND", 1),
+    (74, "NOW", 0),
+    (75, "AREAS", 1),
+    (76, "ROWS", 1),
+    (77, "COLUMNS", 1),
+    (78, "OFFSET", -1),
+    (82, "SEARCH", -1),
+    (83, "TRANSPOSE", 1),
+    (86, "TYPE", 1),
+    (97, "ATAN2", 2),
+    (98, "ASIN", 1),
+    (99, "ACOS", 1),
+    (100, "CHOOSE", -1),
+    (101, "HLOOKUP", -1),
+    (102, "VLOOKUP", -1),
+    (105, "ISREF", 1),
+    (109, "LOG", -1),
+    (111, "CHAR", 1),
+    (112, "LOWER", 1),
+    (113, "UPPER", 1),
+    (114, "PROPER", 1),
+    (115, "LEFT", -1),
+    (116, "RIGHT", -1),
+    (117, "EXACT", 2),
+    (118, "TRIM", 1),
+    (119, "REPLACE", 4),
+    (120, "SUBSTITUTE", -1),
+    (121, "CODE", 1),
+    (124, "FIND", -1),
+    (125, "CELL", -1),
+    (126, "ISERR", 1),
+    (127, "ISTEXT", 1),
+    (128, "ISNUMBER", 1),
+    (129, "ISBLANK", 1),
+    (130, "T", 1),
+    (131, "N", 1),
+    (140, "DATEVALUE", 1),
+    (141, "TIMEVALUE", 1),
+    (142, "SLN", 3),
+    (143, "SYD", 4),
+    (144, "DDB", -1),
+    (148, "INDIRECT", -1),
+    (162, "CLEAN", 1),
+    (163, "MDETERM", 1),
+    (164, "MINVERSE", 1),
+    (165, "MMULT", 2),
+    (167, "IPMT", -1),
+    (168, "PPMT", -1),
+    (169, "COUNTA", -1),
+    (183, "PRODUCT", -1),
+    (184, "FACT", 1),
+    (189, "DPRODUCT", 3),
+    (190, "ISNONTEXT", 1),
+    (193, "STDEVP", -1),
+    (194, "VARP", -1),
+    (195, "DSTDEVP", 3),
+    (196, "DVARP", 3),
+    (197, "TRUNC", -1),
+    (198, "ISLOGICAL", 1),
+    (199, "DCOUNTA", 3),
+    (204, "USDOLLAR", -1),
+    (205, "FINDB", -1),
+    (206, "SEARCHB", -1),
+    (207, "REPLACEB", 4),
+    (208, "LEFTB", -1),
+    (209, "RIGHTB", -1),
+    (210, "MIDB", 3),
+    (211, "LENB", 1),
+    (212, "ROUNDUP", 2),
+    (213, "ROUNDDOWN", 2),
+    (214, "ASC", 1),
+    (215, "DBCS", 1),
+    (216, "RANK", -1),
+    (219, "ADDRESS", -1),
+    (220, "DAYS360", -1),
+    (221, "TODAY", 0),
+    (222, "VDB", -1),
+    (227, "MEDIAN", -1),
+    (228, "SUMPRODUCT", -1),
+    (229, "SINH", 1),
+    (230, "COSH", 1),
+    (231, "TANH", 1),
+    (232, "ASINH", 1),
+    (233, "ACOSH", 1),
+    (234, "ATANH", 1),
+    (235, "DGET", 3),
+    (244, "INFO", 1),
+    (247, "DB", -1),
+    (252, "FREQUENCY", 2),
+    (261, "ERROR.TYPE", 1),
+    (269, "AVEDEV", -1),
+    (270, "BETADIST", -1),
+    (271, "GAMMALN", 1),
+    (272, "BETAINV", -1),
+    (273, "BINOMDIST", 4),
+    (274, "CHIDIST", 2),
+    (275, "CHIINV", 2),
+    (276, "COMBIN", 2),
+    (277, "CONFIDENCE", 3),
+    (278, "CRITBINOM", 3),
+    (279, "EVEN", 1),
+    (280, "EXPONDIST", 3),
+    (281, "FDIST", 3),
+    (282, "FINV", 3),
+    (283, "FISHER", 1),
+    (284, "FISHERINV", 1),
+    (285, "FLOOR", 2),
+    (286, "GAMMADIST", 4),
+    (287, "GAMMAINV", 3),
+    (288, "CEILING", 2),
+    (289, "HYPGEOMDIST", 4),
+    (290, "LOGNORMDIST", 3),
+    (291, "LOGINV", 3),
+    (292, "NEGBINOMDIST", 3),
+    (293, "NORMDIST", 4),
+    (294, "NORMSDIST", 1),
+    (295, "NORMINV", 3),
+    (296, "NORMSINV", 1),
+    (297, "STANDARDIZE", 3),
+    (298, "ODD", 1),
+    (299, "PERMUT", 2),
+    (300, "POISSON", 3),
+    (301, "TDIST", 3),
+    (302, "WEIBULL", 4),
+    (303, "SUMXMY2", 2),
+    (304, "SUMX2MY2", 2),
+    (305, "SUMX2PY2", 2),
+    (306, "CHITEST", 2),
+    (307, "CORREL", 2),
+    (308, "COVAR", 2),
+    (309, "FORECAST", 3),
+    (310, "FTEST", 2),
+    (311, "INTERCEPT", 2),
+    (312, "PEARSON", 2),
+    (313, "RSQ", 2),
+    (314, "STEYX", 2),
+    (315, "SLOPE", 2),
+    (316, "TTEST", 4),
+    (317, "PROB", -1),
+    (318, "DEVSQ", -1),
+    (319, "GEOMEAN", -1),
+    (320, "HARMEAN", -1),
+    (321, "SUMSQ", -1),
+    (322, "KURT", -1),
+    (323, "SKEW", -1),
+    (324, "ZTEST", -1),
+    (325, "LARGE", 2),
+    (326, "SMALL", 2),
+    (327, "QUARTILE", 2),
+    (328, "PERCENTILE", 2),
+    (329, "PERCENTRANK", -1),
+    (330, "MODE", -1),
+    (331, "TRIMMEAN", 2),
+    (332, "TINV", 2),
+    (336, "CONCATENATE", -1),
+    (337, "POWER", 2),
+    (342, "RADIANS", 1),
+    (343, "DEGREES", 1),
+    (344, "SUBTOTAL", -1),
+    (345, "SUMIF", -1),
+    (346, "COUNTIF", 2),
+    (347, "COUNTBLANK", 1),
+    (350, "ISPMT", 4),
+    (351, "DATEDIF", 3),
+    (352, "DATESTRING", 1),
+    (353, "NUMBERSTRING", 2),
+    (354, "ROMAN", -1),
+    (358, "GETPIVOTDATA", -1),
+    (359, "HYPERLINK", -1),
+    (360, "PHONETIC", 1),
+    (361, "AVERAGEA", -1),
+    (362, "MAXA", -1),
+    (363, "MINA", -1),
+    (364, "STDEVPA", -1),
+    (365, "VARPA", -1),
+    (366, "STDEVA", -1),
+    (367, "VARA", -1),
+    (368, "BAHTTEXT", 1),
+];
+
+/// Index of the function calling an add-in or a newer function by name.
+pub(crate) const USER_DEFINED_FUNCTION: u16 = 255;
+
+/// A supporting link: this workbook, an add-in or another workbook.
+#[derive(Debug, Default)]
+pub(crate) struct SupBook {
+    pub(crate) is_internal: bool,
+    pub(crate) names:       Vec<String>,
+}
+
+/// An entry of the `EXTERNSHEET` record.
+#[derive(Debug, Default)]
+pub(crate) struct Xti {
+    pub(crate) sup_book:    usize,
+    pub(crate) first_sheet: u16,
+    pub(crate) last_sheet:  u16,
+}
+
+/// Layout of the tokens: BIFF8 of the xls files or BIFF12 of the xlsb
+/// files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum TokenFormat {
+    #[default]
+    Biff8,
+    Biff12,
+}
+
+impl TokenFormat {
+    fn last_row(self) -> u32 {
+        match self {
+            Self::Biff8 => 0xFFFF,
+            Self::Biff12 => 0xF_FFFF,
+        }
+    }
+
+    fn last_col(self) -> u32 {
+        match self {
+            Self::Biff8 => 0xFF,
+            Self::Biff12 => 0x3FFF,
+        }
+    }
+
+    /// Size of a cell reference: the row and the column.
+    fn cell_size(self) -> usize {
+        match self {
+            Self::Biff8 => 4,
+            Self::Biff12 => 6,
+        }
+    }
+
+    fn read_row(self, reader: &mut RecordReader) -> Result<u32, XlsxError> {
+        match self {
+            Self::Biff8 => Ok(u32::from(reader.u16()?)),
+            Self::Biff12 => reader.u32(),
+        }
+    }
+}
+
+/// Workbook data the tokens of a formula refer to.
+#[derive(Debug, Default)]
+pub(crate) struct FormulaContext {
+    pub(crate) format:      TokenFormat,
+    pub(crate) sheet_names: Vec<String>,
+    pub(crate) names:       Vec<String>,
+    pub(crate) sup_books:   Vec<SupBook>,
+    pub(crate) xti_list:    Vec<Xti>,
+}
+
+impl FormulaContext {
+    /// Sheet prefix of a 3D reference, `None` for other workbooks.
+    fn sheet_prefix(&self, ixti: u16) -> Option<String> {
+        let xti = self.xti_list.get(usize::from(ixti))?;
+        if !self.sup_books.get(xti.sup_book)?.is_internal {
+            return None;
+        }
+        let first = self.sheet_names.get(usize::from(xti.first_sheet));
+        let last = self.sheet_names.get(usize::from(xti.last_sheet));
+        Some(match (first, last) {
+            (Some(first), Some(last)) if first != last => {
+                join_address(&format!("{first}:{last}"), "")
+            }
+            (Some(first), _) => join_address(first, ""),
+            _ => "#REF!".to_string(),
+        })
+    }
+
+    fn extern_name(&self, ixti: u16, index: u32) -> Option<String> {
+        let xti = self.xti_list.get(usize::from(ixti))?;
+        let sup_book = self.sup_books.get(xti.sup_book)?;
+        let index = usize::try_from(index).ok()?.checked_sub(1)?;
+        if sup_book.is_internal {
+            return self.names.get(index).cloned();
+        }
+        sup_book.names.get(index).cloned()
+    }
+}
+
+/// Convert the parsed tokens of a formula to A1 text.
+/// # Arguments
+/// * `rgce` - tokens.
+/// * `rgcb` - data of the array constants following the tokens.
+/// * `base` - row and column of the cell, zero based, for shared formulas
+///   and names. Their relative references are offsets from it.
+/// * `context` - names and sheets of the workbook.
+/// # Return value
+/// * `Option<String>` - formula without `=`. `None` when the formula uses
+///   tokens that can not be converted.
+pub(crate) fn decompile(
+    rgce: &[u8],
+    rgcb: &[u8],
+    base: Option<(u32, u32)>,
+    context: &FormulaContext,
+) -> Option<String> {
+    decompile_tokens(rgce, rgcb, base, context).ok()
+}
+
+fn unsupported(ptg: u8) -> XlsxError {
+    XlsxError::InvalidRecord(format!("unsupported formula token 0x{ptg:02X}"))
+}
+
+fn decompile_tokens(
+    rgce: &[u8],
+    rgcb: &[u8],
+    base: Option<(u32, u32)>,
+    context: &FormulaContext,
+) -> Result<String, XlsxError> {
+    let format = context.format;
+    let relative_base = Some(base.unwrap_or_default());
+    let mut reader = RecordReader::new(rgce);
+    let mut extra = RecordReader::new(rgcb);
+    let mut stack: Vec<String> = Vec::new();
+    let pop = |stack: &mut Vec<String>| {
+        stack
+            .pop()
+            .ok_or_else(|| XlsxError::InvalidRecord("formula stack is empty".into()))
+    };
+
+    while reader.remaining() > 0 {
+        let ptg = reader.u8()?;
+        match ptg {
+            0x03..=0x11 => {
+                let right = pop(&mut stack)?;
+                let left = pop(&mut stack)?;
+                let operator = match ptg {
+                    0x03 => "+",
+                    0x04 => "-",
+                    0x05 => "*",
+                    0x06 => "/",
+                    0x07 => "^",
+                    0x08 => "&",
+                    0x09 => "<",
+                    0x0A => "<=",
+                    0x0B => "=",
+                    0x0C => ">=",
+                    0x0D => ">",
+                    0x0E => "<>",
+                    0x0F => " ",
+                    0x10 => ",",
+                    _ => ":",
+                };
+                stack.push(format!("{left}{operator}{right}"));
+            }
+            0x12 => {
+                let value = pop(&mut stack)?;
+                stack.push(format!("+{value}"));
+            }
+            0x13 => {
+                let value = pop(&mut stack)?;
+                stack.push(format!("-{value}"));
+            }
+            0x14 => {
+                let value = pop(&mut stack)?;
+                stack.push(format!("{value}%"));
+            }
+            0x15 => {
+                let value = pop(&mut stack)?;
+                stack.push(format!("({value})"));
+            }
+            0x16 => stack.push(String::new()),
+            0x17 => {
+                let value = match format {
+                    TokenFormat::Biff8 => reader.short_string()?,
+                    TokenFormat::Biff12 => {
+                        let cch = reader.u16()?;
+                        reader.wide_chars(usize::from(cch))?
+                    }
+                };
+                stack.push(format!("\"{}\"", value.replace('"', "\"\"")));
+            }
+            0x19 => {
+                let options = reader.u8()?;
+                let count = reader.u16()?;
+                if options & 0x04 != 0 {
+                    reader.skip((usize::from(count) + 1) * 2)?;
+                }
+                if options & 0x10 != 0 {
+                    let value = pop(&mut stack)?;
+                    stack.push(format!("SUM({value})"));
+                }
+            }
+            0x1C => stack.push(error_text(reader.u8()?).to_string()),
+            0x1D => stack.push(if reader.u8()? == 0 { "FALSE" } else { "TRUE" }.to_string()),
+            0x1E => stack.push(reader.u16()?.to_string()),
+            0x1F => stack.push(reader.f64()?.to_string()),
+            0x20..=0x7F => match (ptg & 0x1F) | 0x20 {
+                0x20 => {
+                    reader.skip(if format == TokenFormat::Biff8 { 7 } else { 14 })?;
+                    stack.push(array_constant(&mut extra, format)?);
+                }
+                0x21 => {
+                    let index = reader.u16()?;
+                    let (name, count) = function(index).ok_or_else(|| unsupported(ptg))?;
+                    let count = usize::try_from(count).map_err(|_| unsupported(ptg))?;
+                    let args = pop_args(&mut stack, count)?;
+                    stack.push(format!("{name}({})", args.join(",")));
+                }
+                0x22 => {
+                    let count = usize::from(reader.u8()? & 0x7F);
+                    let index = reader.u16()? & 0x7FFF;
+                    let mut args = pop_args(&mut stack, count)?;
+                    let name = if index == USER_DEFINED_FUNCTION {
+                        if args.is_empty() {
+                            return Err(unsupported(ptg));
+                        }
+                        args.remove(0)
+                    } else {
+                        function(index).ok_or_else(|| unsupported(ptg))?.0.to_string()
+                    };
+                    stack.push(format!("{name}({})", args.join(",")));
+                }
+                0x23 => {
+                    let index = reader.u32()?;
+                    let name = usize::try_from(index)
+                        .ok()
+                        .and_then(|v| v.checked_sub(1))
+                        .and_then(|v| context.names.get(v))
+                        .ok_or_else(|| unsupported(ptg))?;
+                    stack.push(name.clone());
+                }
+                0x24 => {
+                    let (row, col) = read_cell(&mut reader, format)?;
+                    stack.push(cell_ref(row, col, None, format));
+                }
+                0x25 => {
+                    let area = read_area(&mut reader, format)?;
+                    stack.push(area_ref(area, None, format));
+                }
+                0x26 => {
+                    reader.skip(6)?;
+                    match format {
+                        TokenFormat::Biff8 => {
+                            let count = extra.u16()?;
+                            extra.skip(usize::from(count) * 8)?;
+                        }
+                        TokenFormat::Biff12 => {
+                            let count = extra.u32()?;
+                            extra.skip(num_traits::cast::<u32, usize>(count).unwrap() * 16)?;
+                        }
+                    }
+                }
+                0x27 | 0x28 => reader.skip(6)?,
+                0x29 | 0x2E | 0x2F => reader.skip(2)?,
+                0x2A => {
+                    reader.skip(format.cell_size())?;
+                    stack.push("#REF!".to_string());
+                }
+                0x2B => {
+                    reader.skip(format.cell_size() * 2)?;
+                    stack.push("#REF!".to_string());
+                }
+                0x2C => {
+                    let (row, col) = read_cell(&mut reader, format)?;
+                    stack.push(cell_ref(row, col, relative_base, format));
+                }
+                0x2D => {
+                    let area = read_area(&mut reader, format)?;
+                    stack.push(area_ref(area, relative_base, format));
+                }
+                0x39 => {
+                    let ixti = reader.u16()?;
+                    let index = reader.u32()?;
+                    let name = context
+                        .extern_name(ixti, index)
+                        .ok_or_else(|| unsupported(ptg))?;
+                    stack.push(name);
+                }
+                0x3A => {
+                    let prefix = sheet_prefix(&mut reader, context, ptg)?;
+                    let (row, col) = read_cell(&mut reader, format)?;
+                    stack.push(format!("{prefix}{}", cell_ref(row, col, base, format)));
+                }
+                0x3B => {
+                    let prefix = sheet_prefix(&mut reader, context, ptg)?;
+                    let area = read_area(&mut reader, format)?;
+                    stack.push(format!("{prefix}{}", area_ref(area, base, format)));
+                }
+                0x3C => {
+                    let prefix = sheet_prefix(&mut reader, context, ptg)?;
+                    reader.skip(format.cell_size())?;
+                    stack.push(format!("{prefix}#REF!"));
+                }
+                0x3D => {
+                    let prefix = sheet_prefix(&mut reader, context, ptg)?;
+                    reader.skip(format.cell_size() * 2)?;
+                    stack.push(format!("{prefix}#REF!"));
+                }
+                _ => return Err(unsupported(ptg)),
+            },
+            _ => return Err(unsupported(ptg)),
+        }
+    }
+
+    match (stack.pop(), stack.is_empty()) {
+        (Some(v), true) => Ok(v),
+        _ => Err(XlsxError::InvalidRecord("formula stack is not balanced".into())),
+    }
+}
+
+fn function(index: u16) -> Option<(&'static str, i8)> {
+    FUNCTIONS
+        .binary_search_by_key(&index, |v| v.0)
+        .ok()
+        .map(|v| (FUNCTIONS[v].1, FUNCTIONS[v].2))
+}
+
+/// Index and argument count of a built-in function by its name.
+pub(crate) fn function_by_name(name: &str) -> Option<(u16, i8)> {
+    FUNCTIONS
+        .iter()
+        .find(|v| v.1.eq_ignore_ascii_case(name))
+        .map(|v| (v.0, v.2))
+}
+
+fn pop_args(stack: &mut Vec<String>, count: usize) -> Result<Vec<String>, XlsxError> {
+    if stack.len() < count {
+        return Err(XlsxError::InvalidRecord("formula stack is empty".into()));
+    }
+    Ok(stack.split_off(stack.len() - count))
+}
+
+fn sheet_prefix(
+    reader: &mut RecordReader,
+    context: &FormulaContext,
+    ptg: u8,
+) -> Result<String, XlsxError> {
+    let ixti = reader.u16()?;
+    context.sheet_prefix(ixti).ok_or_else(|| unsupported(ptg))
+}
+
+pub(crate) fn error_text(code: u8) -> &'static str {
+    match code {
+        0x00 => "#NULL!",
+        0x07 => "#DIV/0!",
+        0x0F => "#VALUE!",
+        0x17 => "#REF!",
+        0x1D => "#NAME?",
+        0x24 => "#NUM!",
+        0x2B => "#GETTING_DATA",
+        _ => "#N/A",
+    }
+}
+
+/// Code of an error value, `#N/A` for unknown ones.
+pub(crate) fn error_code(text: &str) -> u8 {
+    match text {
+        "#NULL!" => 0x00,
+        "#DIV/0!" => 0x07,
+        "#VALUE!" => 0x0F,
+        "#REF!" => 0x17,
+        "#NAME?" => 0x1D,
+        "#NUM!" => 0x24,
+        "#GETTING_DATA" => 0x2B,
+        _ => 0x2A,
+    }
+}
+
+fn array_constant(extra: &mut RecordReader, format: TokenFormat) -> Result<String, XlsxError> {
+    let (rows, cols) = match format {
+        TokenFormat::Biff8 => {
+            let cols = usize::from(extra.u8()?) + 1;
+            (usize::from(extra.u16()?) + 1, cols)
+        }
+        TokenFormat::Biff12 => (
+            num_traits::cast(extra.u32()?).unwrap(),
+            num_traits::cast(extra.u32()?).unwrap(),
+        ),
+    };
+    // Each value takes at least two bytes, so the counts are checked against
+    // the record before anything is allocated.
+    if rows
+        .checked_mul(cols)
+        .is_none_or(|count| count > extra.remaining() / 2)
+    {
+        return Err(XlsxError::InvalidRecord(
+            "array constant is larger than its record".into(),
+        ));
+    }
+    let mut row_list: Vec<String> = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let mut values: Vec<String> = Vec::with_capacity(cols);
+        for _ in 0..cols {
+            let value = match format {
+                TokenFormat::Biff8 => biff8_constant(extra)?,
+                TokenFormat::Biff12 => biff12_constant(extra)?,
+            };
+            values.push(value);
+        }
+        row_list.push(values.join(","));
+    }
+    Ok(format!("{{{}}}", row_list.join(";")))
+}
+
+fn biff8_constant(extra: &mut RecordReader) -> Result<String, XlsxError> {
+    Ok(match extra.u8()? {
+        0x01 => extra.f64()?.to_string(),
+        0x02 => format!("\"{}\"", extra.string()?.replace('"', "\"\"")),
+        0x04 => {
+            let value = extra.u8()?;
+            extra.skip(7)?;
+            if value == 0 { "FALSE" } else { "TRUE" }.to_string()
+        }
+        0x10 => {
+            let value = extra.u8()?;
+            extra.skip(7)?;
+            error_text(value).to_string()
+        }
+        _ => {
+            extra.skip(8)?;
+            String::new()
+        }
+    })
+}
+
+fn biff12_constant(extra: &mut RecordReader) -> Result<String, XlsxError> {
+    Ok(match extra.u8()? {
+        0x00 => extra.f64()?.to_string(),
+        0x01 => {
+            let cch = extra.u16()?;
+            let value = extra.wide_chars(usize::from(cch))?;
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        0x02 => if extra.u8()? == 0 { "FALSE" } else { "TRUE" }.to_string(),
+        0x04 => {
+            let value = extra.u8()?;
+            extra.skip(3)?;
+            error_text(value).to_string()
+        }
+        _ => return Err(XlsxError::InvalidRecord("unknown array constant".into())),
+    })
+}
+
+fn read_cell(reader: &mut RecordReader, format: TokenFormat) -> Result<(u32, u16), XlsxError> {
+    Ok((format.read_row(reader)?, reader.u16()?))
+}
+
+/// First row, last row, first column and last column of an area.
+fn read_area(
+    reader: &mut RecordReader,
+    format: TokenFormat,
+) -> Result<(u32, u32, u16, u16), XlsxError> {
+    Ok((
+        format.read_row(reader)?,
+        format.read_row(reader)?,
+        reader.u16()?,
+        reader.u16()?,
+    ))
+}
+
+/// Row, column and their relative flags of a reference. The reference is
+/// an offset from `base` when it is relative and `base` is given.
+fn resolve(
+    row: u32,
+    col: u16,
+    base: Option<(u32, u32)>,
+    format: TokenFormat,
+) -> (u32, u32, bool, bool) {
+    let row_relative = col & 0x8000 != 0;
+    let col_relative = col & 0x4000 != 0;
+    let mut row = row;
+    let mut col = u32::from(col & 0x3FFF);
+    if let Some((base_row, base_col)) = base {
+        if row_relative {
+            row = base_row.wrapping_add(row) & format.last_row();
+        }
+        if col_relative {
+            col = (base_col + (col & format.last_col())) & format.last_col();
+        }
+    }
+    (row, col, row_relative, col_relative)
+}
+
+fn cell_ref(row: u32, col: u16, base: Option<(u32, u32)>, format: TokenFormat) -> String {
+    let (row, col, row_relative, col_relative) = resolve(row, col, base, format);
+    coordinate_from_index_with_lock(col + 1, row + 1, !col_relative, !row_relative)
+}
+
+fn area_ref(
+    area: (u32, u32, u16, u16),
+    base: Option<(u32, u32)>,
+    format: TokenFormat,
+) -> String {
+    let (first_row, last_row, first_col, last_col) = area;
+    let (row1, col1, row1_relative, col1_relative) = resolve(first_row, first_col, base, format);
+    let (row2, col2, row2_relative, col2_relative) = resolve(last_row, last_col, base, format);
+    let lock = |relative: bool| if relative { "" } else { "$" };
+    if row1 == 0 && row2 == format.last_row() {
+        return format!(
+            "{}{}:{}{}",
+            lock(col1_relative),
+            string_from_column_index(col1 + 1),
+            lock(col2_relative),
+            string_from_column_index(col2 + 1)
+        );
+    }
+    if col1 == 0 && col2 == format.last_col() {
+        return format!(
+            "{}{}:{}{}",
+            lock(row1_relative),
+            row1 + 1,
+            lock(row2_relative),
+            row2 + 1
+        );
+    }
+    format!(
+        "{}:{}",
+        coordinate_from_index_with_lock(col1 + 1, row1 + 1, !col1_relative, !row1_relative),
+        coordinate_from_index_with_lock(col2 + 1, row2 + 1, !col2_relative, !row2_relative)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_constant_test() {
+        let biff12 = FormulaContext {
+            format: TokenFormat::Biff12,
+            ..Default::default()
+        };
+        let rgce = [0x40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut rgcb = vec![1, 0, 0, 0, 2, 0, 0, 0];
+        rgcb.extend_from_slice(&[0x02, 1, 0x02, 0]);
+        assert_eq!(
+            decompile(&rgce, &rgcb, None, &biff12).as_deref(),
+            Some("{TRUE,FALSE}")
+        );
+
+        let rgcb = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02, 1];
+        assert!(
+            decompile_tokens(&rgce, &rgcb, None, &biff12)
+                .unwrap_err()
+                .to_string()
+                .contains("array constant is larger than its record")
+        );
+    }
+}
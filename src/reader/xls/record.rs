@@ -0,0 +1,204 @@
+use crate::XlsxError;
+
+pub(crate) const FORMULA: u16 = 0x0006;
+pub(crate) const EOF: u16 = 0x000A;
+pub(crate) const EXTERNSHEET: u16 = 0x0017;
+pub(crate) const NAME: u16 = 0x0018;
+pub(crate) const DATEMODE: u16 = 0x0022;
+pub(crate) const EXTERNNAME: u16 = 0x0023;
+pub(crate) const FILEPASS: u16 = 0x002F;
+pub(crate) const FONT: u16 = 0x0031;
+pub(crate) const CONTINUE: u16 = 0x003C;
+pub(crate) const COLINFO: u16 = 0x007D;
+pub(crate) const BOUNDSHEET: u16 = 0x0085;
+pub(crate) const PALETTE: u16 = 0x0092;
+pub(crate) const MULRK: u16 = 0x00BD;
+pub(crate) const MULBLANK: u16 = 0x00BE;
+pub(crate) const XF: u16 = 0x00E0;
+pub(crate) const MERGECELLS: u16 = 0x00E5;
+pub(crate) const SST: u16 = 0x00FC;
+pub(crate) const LABELSST: u16 = 0x00FD;
+pub(crate) const SUPBOOK: u16 = 0x01AE;
+pub(crate) const BLANK: u16 = 0x0201;
+pub(crate) const NUMBER: u16 = 0x0203;
+pub(crate) const LABEL: u16 = 0x0204;
+pub(crate) const BOOLERR: u16 = 0x0205;
+pub(crate) const STRING: u16 = 0x0207;
+pub(crate) const ROW: u16 = 0x0208;
+pub(crate) const ARRAY: u16 = 0x0221;
+pub(crate) const RK: u16 = 0x027E;
+pub(crate) const FORMAT: u16 = 0x041E;
+pub(crate) const SHRFMLA: u16 = 0x04BC;
+pub(crate) const BOF: u16 = 0x0809;
+
+/// A record with the data of the `CONTINUE` records following it.
+#[derive(Debug, Default)]
+pub(crate) struct Record {
+    pub(crate) kind: u16,
+    pub(crate) data: Vec<u8>,
+    /// Offsets in `data` where a `CONTINUE` record starts.
+    pub(crate) breaks: Vec<usize>,
+}
+
+impl Record {
+    /// Read the record at `pos` of the stream and move `pos` after it.
+    pub(crate) fn read(stream: &[u8], pos: &mut usize) -> Option<Self> {
+        let (kind, data) = Self::read_raw(stream, pos)?;
+        let mut record = Record {
+            kind,
+            data: data.to_vec(),
+            breaks: Vec::new(),
+        };
+        let mut next = *pos;
+        while let Some((CONTINUE, data)) = Self::read_raw(stream, &mut next) {
+            record.breaks.push(record.data.len());
+            record.data.extend_from_slice(data);
+            *pos = next;
+        }
+        Some(record)
+    }
+
+    fn read_raw<'a>(stream: &'a [u8], pos: &mut usize) -> Option<(u16, &'a [u8])> {
+        let header = stream.get(*pos..*pos + 4)?;
+        let kind = u16::from_le_bytes([header[0], header[1]]);
+        let len = usize::from(u16::from_le_bytes([header[2], header[3]]));
+        let data = stream.get(*pos + 4..*pos + 4 + len)?;
+        *pos += 4 + len;
+        Some((kind, data))
+    }
+
+    pub(crate) fn reader(&self) -> RecordReader<'_> {
+        RecordReader {
+            data:   &self.data,
+            breaks: &self.breaks,
+            pos:    0,
+        }
+    }
+}
+
+/// Reads the little endian values of a record.
+pub(crate) struct RecordReader<'a> {
+    data:   &'a [u8],
+    breaks: &'a [usize],
+    pos:    usize,
+}
+
+impl<'a> RecordReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            breaks: &[],
+            pos: 0,
+        }
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.pos)
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<&'a [u8], XlsxError> {
+        let value = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| XlsxError::InvalidRecord("unexpected end of record".into()))?;
+        self.pos += len;
+        Ok(value)
+    }
+
+    pub(crate) fn skip(&mut self, len: usize) -> Result<(), XlsxError> {
+        self.bytes(len).map(|_| ())
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, XlsxError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, XlsxError> {
+        let v = self.bytes(2)?;
+        Ok(u16::from_le_bytes([v[0], v[1]]))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, XlsxError> {
+        let v = self.bytes(4)?;
+        Ok(u32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+    }
+
+    pub(crate) fn f64(&mut self) -> Result<f64, XlsxError> {
+        let mut v = [0; 8];
+        v.copy_from_slice(self.bytes(8)?);
+        Ok(f64::from_le_bytes(v))
+    }
+
+    /// `ShortXLUnicodeString`: 8-bit character count.
+    pub(crate) fn short_string(&mut self) -> Result<String, XlsxError> {
+        let cch = self.u8()?;
+        self.string_chars(usize::from(cch))
+    }
+
+    /// `XLUnicodeString`: 16-bit character count.
+    pub(crate) fn string(&mut self) -> Result<String, XlsxError> {
+        let cch = self.u16()?;
+        self.string_chars(usize::from(cch))
+    }
+
+    /// `XLUnicodeStringNoCch`: the character count is stored elsewhere.
+    pub(crate) fn string_chars(&mut self, cch: usize) -> Result<String, XlsxError> {
+        let flags = self.u8()?;
+        self.chars(cch, flags & 0x01 != 0)
+    }
+
+    /// `XLWideString` of BIFF12: 32-bit character count and UTF-16 characters.
+    /// The count `0xFFFFFFFF` of `XLNullableWideString` is an empty string.
+    pub(crate) fn wide_string(&mut self) -> Result<String, XlsxError> {
+        let cch = self.u32()?;
+        if cch == u32::MAX {
+            return Ok(String::new());
+        }
+        self.wide_chars(num_traits::cast(cch).unwrap())
+    }
+
+    /// UTF-16 characters of BIFF12.
+    pub(crate) fn wide_chars(&mut self, cch: usize) -> Result<String, XlsxError> {
+        let data = self.bytes(cch * 2)?;
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|v| u16::from_le_bytes([v[0], v[1]]))
+            .collect();
+        Ok(String::from_utf16_lossy(&units))
+    }
+
+    /// `XLUnicodeRichExtendedString` of the shared string table.
+    /// The formatting runs and the phonetic data are skipped.
+    pub(crate) fn rich_string(&mut self) -> Result<String, XlsxError> {
+        let cch = self.u16()?;
+        let flags = self.u8()?;
+        let runs = if flags & 0x08 != 0 { self.u16()? } else { 0 };
+        let ext = if flags & 0x04 != 0 { self.u32()? } else { 0 };
+        let value = self.chars(usize::from(cch), flags & 0x01 != 0)?;
+        self.skip(usize::from(runs) * 4)?;
+        self.skip(num_traits::cast(ext).unwrap())?;
+        Ok(value)
+    }
+
+    /// Characters of a string. A `CONTINUE` record in the middle of the
+    /// characters starts with its own encoding flag.
+    fn chars(&mut self, cch: usize, high_byte: bool) -> Result<String, XlsxError> {
+        let mut high_byte = high_byte;
+        let mut units: Vec<u16> = Vec::with_capacity(cch);
+        while units.len() < cch {
+            if self.breaks.contains(&self.pos) {
+                high_byte = self.u8()? & 0x01 != 0;
+            }
+            if high_byte {
+                units.push(self.u16()?);
+            } else {
+                units.push(u16::from(self.u8()?));
+            }
+        }
+        Ok(String::from_utf16_lossy(&units))
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+
+use super::record::Record;
+use crate::{
+    XlsxError,
+    structs::{
+        Border,
+        FILL_BUILT_IN_FORMAT_CODES,
+        Color,
+        Font,
+        HorizontalAlignmentValues,
+        NumberingFormat,
+        PatternValues,
+        Style,
+        VerticalAlignmentRunValues,
+        VerticalAlignmentValues,
+    },
+};
+
+/// Border styles by their `dg` value.
+pub(crate) const BORDER_STYLES: [&str; 14] = [
+    Border::BORDER_NONE,
+    Border::BORDER_THIN,
+    Border::BORDER_MEDIUM,
+    Border::BORDER_DASHED,
+    Border::BORDER_DOTTED,
+    Border::BORDER_THICK,
+    Border::BORDER_DOUBLE,
+    Border::BORDER_HAIR,
+    Border::BORDER_MEDIUMDASHED,
+    Border::BORDER_DASHDOT,
+    Border::BORDER_MEDIUMDASHDOT,
+    Border::BORDER_DASHDOTDOT,
+    Border::BORDER_MEDIUMDASHDOTDOT,
+    Border::BORDER_SLANTDASHDOT,
+];
+
+/// Fill patterns by their `fls` value.
+pub(crate) const PATTERN_TYPES: [PatternValues; 19] = [
+    PatternValues::None,
+    PatternValues::Solid,
+    PatternValues::MediumGray,
+    PatternValues::DarkGray,
+    PatternValues::LightGray,
+    PatternValues::DarkHorizontal,
+    PatternValues::DarkVertical,
+    PatternValues::DarkDown,
+    PatternValues::DarkUp,
+    PatternValues::DarkGrid,
+    PatternValues::DarkTrellis,
+    PatternValues::LightHorizontal,
+    PatternValues::LightVertical,
+    PatternValues::LightDown,
+    PatternValues::LightUp,
+    PatternValues::LightGrid,
+    PatternValues::LightTrellis,
+    PatternValues::Gray125,
+    PatternValues::Gray0625,
+];
+
+#[derive(Debug, Default)]
+struct FontRecord {
+    font:  Font,
+    color: u16,
+}
+
+#[derive(Debug, Default)]
+struct XfRecord {
+    font:        u16,
+    format:      u16,
+    protection:  u16,
+    alignment:   u8,
+    rotation:    u8,
+    indent:      u8,
+    /// Lines of the sides, colors of the left and right sides and the
+    /// diagonal flags.
+    border:      u32,
+    /// Colors of the top, bottom and diagonal sides, the diagonal line and
+    /// the fill pattern.
+    border_fill: u32,
+    fill:        u16,
+}
+
+/// Fonts, number formats, palette and cell formats of the workbook globals.
+#[derive(Debug, Default)]
+pub(crate) struct StyleTable {
+    fonts:   Vec<FontRecord>,
+    formats: HashMap<u16, String>,
+    palette: Vec<String>,
+    xf_list: Vec<XfRecord>,
+}
+
+impl StyleTable {
+    pub(crate) fn read_font(&mut self, record: &Record) -> Result<(), XlsxError> {
+        let mut reader = record.reader();
+        let height = reader.u16()?;
+        let options = reader.u16()?;
+        let color = reader.u16()?;
+        let weight = reader.u16()?;
+        let script = reader.u16()?;
+        let underline = reader.u8()?;
+        let family = reader.u8()?;
+        let charset = reader.u8()?;
+        reader.skip(1)?;
+        let name = reader.short_string()?;
+
+        let mut font = Font::default();
+        font.set_name(name);
+        font.set_size(f64::from(height) / 20.0);
+        font.set_bold(weight >= 700);
+        font.set_italic(options & 0x02 != 0);
+        font.set_strikethrough(options & 0x08 != 0);
+        match underline {
+            0x01 => font.set_underline("single"),
+            0x02 => font.set_underline("double"),
+            0x21 => font.set_underline("singleAccounting"),
+            0x22 => font.set_underline("doubleAccounting"),
+            _ => &mut font,
+        };
+        match script {
+            1 => {
+                font.vertical_text_alignment_mut()
+                    .set_val(VerticalAlignmentRunValues::Superscript);
+            }
+            2 => {
+                font.vertical_text_alignment_mut()
+                    .set_val(VerticalAlignmentRunValues::Subscript);
+            }
+            _ => {}
+        }
+        if family != 0 {
+            font.set_family(i32::from(family));
+        }
+        if charset != 0 {
+            font.set_charset(i32::from(charset));
+        }
+        self.fonts.push(FontRecord { font, color });
+        Ok(())
+    }
+
+    pub(crate) fn read_format(&mut self, record: &Record) -> Result<(), XlsxError> {
+        let mut reader = record.reader();
+        let index = reader.u16()?;
+        let code = reader.string()?;
+        self.formats.insert(index, code);
+        Ok(())
+    }
+
+    pub(crate) fn read_palette(&mut self, record: &Record) -> Result<(), XlsxError> {
+        let mut reader = record.reader();
+        let count = reader.u16()?;
+        self.palette.clear();
+        for _ in 0..count {
+            let rgb = reader.bytes(4)?;
+            self.palette
+                .push(format!("FF{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2]));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn read_xf(&mut self, record: &Record) -> Result<(), XlsxError> {
+        let mut reader = record.reader();
+        let xf = XfRecord {
+            font:        reader.u16()?,
+            format:      reader.u16()?,
+            protection:  reader.u16()?,
+            alignment:   reader.u8()?,
+            rotation:    reader.u8()?,
+            indent:      reader.u8()?,
+            border:      {
+                reader.skip(1)?;
+                reader.u32()?
+            },
+            border_fill: reader.u32()?,
+            fill:        reader.u16()?,
+        };
+        self.xf_list.push(xf);
+        Ok(())
+    }
+
+    /// Color of a palette index. The indexes from 64 are system colors.
+    fn color(&self, index: u16) -> Option<Color> {
+        let mut color = Color::default();
+        match index {
+            0..8 => color.set_indexed(u32::from(index)),
+            8..64 => match self.palette.get(usize::from(index - 8)) {
+                Some(v) => color.set_argb_str(v),
+                None => color.set_indexed(u32::from(index)),
+            },
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    /// The font used by the cells without a format.
+    pub(crate) fn default_font(&self) -> Option<Font> {
+        self.font(0)
+    }
+
+    fn font(&self, index: u16) -> Option<Font> {
+        // The index 4 is not used.
+        let index = if index >= 4 { index - 1 } else { index };
+        let record = self.fonts.get(usize::from(index))?;
+        let mut font = record.font.clone();
+        if let Some(color) = self.color(record.color) {
+            font.set_color(color);
+        }
+        Some(font)
+    }
+
+    fn number_format(&self, index: u16) -> Option<NumberingFormat> {
+        let mut format = NumberingFormat::default();
+        if let Some(code) = self.formats.get(&index) {
+            format.set_format_code(code.as_str());
+        } else if FILL_BUILT_IN_FORMAT_CODES.contains_key(&u32::from(index)) {
+            format.set_number_format_id(u32::from(index));
+        } else {
+            return None;
+        }
+        Some(format)
+    }
+
+    /// Styles of the cell formats by their index.
+    pub(crate) fn styles(&self) -> Vec<Style> {
+        self.xf_list.iter().map(|xf| self.style(xf)).collect()
+    }
+
+    fn style(&self, xf: &XfRecord) -> Style {
+        let mut style = Style::default();
+        if let Some(font) = self.font(xf.font) {
+            style.set_font(font);
+        }
+        if xf.format != 0 {
+            if let Some(format) = self.number_format(xf.format) {
+                style.set_number_format(format);
+            }
+        }
+        Self::set_alignment(&mut style, xf);
+        self.set_borders(&mut style, xf);
+        self.set_fill(&mut style, xf);
+
+        let locked = xf.protection & 0x01 != 0;
+        let hidden = xf.protection & 0x02 != 0;
+        if !locked || hidden {
+            let protection = style.protection_mut();
+            protection.set_locked(locked);
+            protection.set_hidden(hidden);
+        }
+        style
+    }
+
+    fn set_alignment(style: &mut Style, xf: &XfRecord) {
+        let horizontal = match xf.alignment & 0x07 {
+            1 => Some(HorizontalAlignmentValues::Left),
+            2 => Some(HorizontalAlignmentValues::Center),
+            3 => Some(HorizontalAlignmentValues::Right),
+            4 => Some(HorizontalAlignmentValues::Fill),
+            5 => Some(HorizontalAlignmentValues::Justify),
+            6 => Some(HorizontalAlignmentValues::CenterContinuous),
+            7 => Some(HorizontalAlignmentValues::Distributed),
+            _ => None,
+        };
+        let vertical = match (xf.alignment >> 4) & 0x07 {
+            0 => Some(VerticalAlignmentValues::Top),
+            1 => Some(VerticalAlignmentValues::Center),
+            3 => Some(VerticalAlignmentValues::Justify),
+            4 => Some(VerticalAlignmentValues::Distributed),
+            _ => None,
+        };
+        let wrap_text = xf.alignment & 0x08 != 0;
+        let indent = xf.indent & 0x0F;
+        if horizontal.is_none()
+            && vertical.is_none()
+            && !wrap_text
+            && xf.rotation == 0
+            && indent == 0
+        {
+            return;
+        }
+        let alignment = style.alignment_mut();
+        if let Some(v) = horizontal {
+            alignment.set_horizontal(v);
+        }
+        if let Some(v) = vertical {
+            alignment.set_vertical(v);
+        }
+        alignment.set_wrap_text(wrap_text);
+        alignment.set_text_rotation(u32::from(xf.rotation));
+        alignment.set_indent(u32::from(indent));
+    }
+
+    fn set_borders(&self, style: &mut Style, xf: &XfRecord) {
+        let style_of =
+            |value: u32| BORDER_STYLES.get(num_traits::cast::<u32, usize>(value).unwrap());
+        let color_of = |value: u32| self.color(num_traits::cast(value & 0x7F).unwrap());
+        let sides = [
+            (xf.border & 0x0F, xf.border >> 16),
+            (xf.border >> 4 & 0x0F, xf.border >> 23),
+            (xf.border >> 8 & 0x0F, xf.border_fill),
+            (xf.border >> 12 & 0x0F, xf.border_fill >> 7),
+            (xf.border_fill >> 21 & 0x0F, xf.border_fill >> 14),
+        ];
+        for (index, (line, color)) in sides.into_iter().enumerate() {
+            let Some(line) = style_of(line).filter(|v| **v != Border::BORDER_NONE) else {
+                continue;
+            };
+            let borders = style.borders_mut();
+            let border = match index {
+                0 => borders.left_mut(),
+                1 => borders.right_mut(),
+                2 => borders.top_mut(),
+                3 => borders.bottom_mut(),
+                _ => borders.diagonal_mut(),
+            };
+            border.set_border_style(*line);
+            if let Some(color) = color_of(color) {
+                border.set_color(color);
+            }
+        }
+        let diagonal = xf.border >> 30;
+        if diagonal != 0 {
+            let borders = style.borders_mut();
+            borders.set_diagonal_down(diagonal & 0x01 != 0);
+            borders.set_diagonal_up(diagonal & 0x02 != 0);
+        }
+    }
+
+    fn set_fill(&self, style: &mut Style, xf: &XfRecord) {
+        let index: usize = num_traits::cast(xf.border_fill >> 26).unwrap();
+        let pattern = match PATTERN_TYPES.get(index) {
+            Some(v) if *v != PatternValues::None => v.clone(),
+            _ => return,
+        };
+        let foreground = self.color(xf.fill & 0x7F);
+        let background = self.color((xf.fill >> 7) & 0x7F);
+        let pattern_fill = style.fill_mut().pattern_fill_mut();
+        pattern_fill.set_pattern_type(pattern);
+        if let Some(color) = foreground {
+            pattern_fill.set_foreground_color(color);
+        }
+        if let Some(color) = background {
+            pattern_fill.set_background_color(color);
+        }
+    }
+}
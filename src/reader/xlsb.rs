@@ -0,0 +1,284 @@
+//! Reader of the Excel binary workbook (BIFF12).
+
+use std::{
+    fs::File,
+    io,
+    path::Path,
+};
+
+use super::{
+    xls::{
+        BUILT_IN_NAMES,
+        NameRecord,
+        formula::{
+            FormulaContext,
+            SupBook,
+            TokenFormat,
+            Xti,
+        },
+        read_defined_names,
+        record::RecordReader,
+    },
+    xlsx::{
+        doc_props_app,
+        doc_props_core,
+        theme,
+    },
+};
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        PKG_WORKBOOK_BIN,
+        SHARED_STRINGS_NS,
+        STYLES_NS,
+        THEME_NS,
+        WORKSHEET_NS,
+    },
+    new_file_empty_worksheet,
+    reader::driver::zip_by_name,
+    structs::{
+        Workbook,
+        drawing::Theme,
+        raw::RawRelationships,
+    },
+};
+
+pub(crate) mod record;
+pub(crate) mod styles;
+pub(crate) mod worksheet;
+
+#[derive(Debug, Default)]
+struct BundleSheet {
+    state:  u32,
+    rel_id: String,
+    name:   String,
+}
+
+#[derive(Debug, Default)]
+struct Globals {
+    sheets:     Vec<BundleSheet>,
+    names:      Vec<NameRecord>,
+    context:    FormulaContext,
+    active_tab: Option<u32>,
+}
+
+/// read spreadsheet from arbitrary reader.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is `Workbook`. Err is error message.
+pub fn read_reader<R: io::Read + io::Seek>(reader: R) -> Result<Workbook, XlsxError> {
+    let mut arv = zip::ZipArchive::new(reader)?;
+    let mut wb = new_file_empty_worksheet();
+    doc_props_app::read(&mut arv, &mut wb)?;
+    doc_props_core::read(&mut arv, &mut wb)?;
+
+    let stream = read_part(&mut arv, PKG_WORKBOOK_BIN)?;
+    let globals = read_globals(&stream).map_err(|e| e.in_part(PKG_WORKBOOK_BIN))?;
+
+    let mut relationships = RawRelationships::default();
+    relationships.set_attributes(&mut arv, "xl", "_rels/workbook.bin.rels", None)?;
+
+    let mut shared_list = Vec::new();
+    wb.set_theme(Theme::default_value());
+    for relationship in relationships.relationship_list() {
+        let data = relationship.raw_file().file_data();
+        match relationship.get_type() {
+            SHARED_STRINGS_NS => shared_list = read_shared_strings(data)?,
+            STYLES_NS => {
+                wb.set_stylesheet(styles::read(data)?);
+            }
+            THEME_NS => {
+                wb.set_theme(theme::read(&mut arv, relationship.target())?);
+            }
+            _ => {}
+        }
+    }
+
+    // Worksheet index of each sheet of the workbook.
+    let mut sheet_index_list: Vec<Option<u32>> = Vec::new();
+    let mut index = 0;
+    for sheet in &globals.sheets {
+        let Some(relationship) = relationships
+            .relationship_by_rid(&sheet.rel_id)
+            .ok()
+            .filter(|v| v.get_type() == WORKSHEET_NS)
+        else {
+            sheet_index_list.push(None);
+            continue;
+        };
+        let stylesheet = wb.stylesheet().clone();
+        let worksheet = wb.new_sheet(sheet.name.as_str())?;
+        match sheet.state {
+            1 => worksheet.set_sheet_state("hidden".into()),
+            2 => worksheet.set_sheet_state("veryHidden".into()),
+            _ => worksheet,
+        };
+        let raw_file = relationship.raw_file();
+        worksheet::read(
+            raw_file.file_data(),
+            worksheet,
+            &shared_list,
+            &stylesheet,
+            &globals.context,
+        )
+        .map_err(|e| e.in_part(raw_file.file_target()))?;
+        sheet_index_list.push(Some(index));
+        index += 1;
+    }
+    if wb.sheet_count() == 0 {
+        wb.new_sheet("Sheet1")?;
+    }
+    let active_sheet = globals
+        .active_tab
+        .and_then(|v| sheet_index_list.get(num_traits::cast::<u32, usize>(v).unwrap()))
+        .copied()
+        .flatten()
+        .unwrap_or(0);
+    wb.set_active_sheet(active_sheet);
+
+    read_defined_names(&mut wb, &globals.names, &globals.context, &sheet_index_list)?;
+    Ok(wb)
+}
+
+/// read spreadsheet file.
+/// Cell values, formulas, styles, merged cells, column widths, row heights,
+/// sheets and defined names are read. Chart sheets, macro sheets and drawings
+/// are skipped.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Workbook. Err is error message.
+/// # Examples
+/// ```no_run
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsb");
+/// let book = umya_spreadsheet::reader::xlsb::read(path).unwrap();
+/// let path = std::path::Path::new("./tests/result_files/aaa_from_xlsb.xlsx");
+/// let _unused = umya_spreadsheet::writer::xlsx::write(&book, path);
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Workbook, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(file)
+}
+
+fn read_part<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    name: &str,
+) -> Result<Vec<u8>, XlsxError> {
+    let mut data = Vec::new();
+    io::Read::read_to_end(&mut zip_by_name(arv, name)?, &mut data)?;
+    Ok(data)
+}
+
+fn read_globals(stream: &[u8]) -> Result<Globals, XlsxError> {
+    let mut globals = Globals {
+        context: FormulaContext {
+            format: TokenFormat::Biff12,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut pos = 0;
+    while let Some((kind, data)) = record::read(stream, &mut pos) {
+        let mut reader = RecordReader::new(data);
+        match kind {
+            record::END_BOOK => break,
+            record::BOOK_VIEW => {
+                if globals.active_tab.is_none() {
+                    reader.skip(24)?;
+                    globals.active_tab = Some(reader.u32()?);
+                }
+            }
+            record::BUNDLE_SH => {
+                let state = reader.u32()?;
+                reader.skip(4)?;
+                let rel_id = reader.wide_string()?;
+                let name = reader.wide_string()?;
+                globals.sheets.push(BundleSheet {
+                    state,
+                    rel_id,
+                    name,
+                });
+            }
+            record::SUP_SELF | record::SUP_SAME => {
+                globals.context.sup_books.push(SupBook {
+                    is_internal: true,
+                    names:       Vec::new(),
+                });
+            }
+            record::SUP_BOOK_SRC | record::SUP_ADDIN => {
+                globals.context.sup_books.push(SupBook {
+                    is_internal: false,
+                    names:       Vec::new(),
+                });
+            }
+            record::EXTERN_SHEET => {
+                let count = reader.u32()?;
+                for _ in 0..count {
+                    let sup_book = num_traits::cast(reader.u32()?).unwrap();
+                    let first_sheet = sheet_tab(reader.u32()?);
+                    let last_sheet = sheet_tab(reader.u32()?);
+                    globals.context.xti_list.push(Xti {
+                        sup_book,
+                        first_sheet,
+                        last_sheet,
+                    });
+                }
+            }
+            record::NAME => globals.names.push(read_name(&mut reader)?),
+            _ => {}
+        }
+    }
+    globals.context.sheet_names = globals.sheets.iter().map(|v| v.name.clone()).collect();
+    globals.context.names = globals.names.iter().map(|v| v.name.clone()).collect();
+    Ok(globals)
+}
+
+/// Sheet of an `XTI`, negative values refer to no sheet.
+fn sheet_tab(value: u32) -> u16 {
+    u16::try_from(value).unwrap_or(u16::MAX)
+}
+
+fn read_name(reader: &mut RecordReader) -> Result<NameRecord, XlsxError> {
+    let options = reader.u32()?;
+    reader.skip(1)?;
+    let sheet_index = reader.u32()?;
+    let mut name = reader.wide_string()?;
+    if options & 0x20 != 0 {
+        let code = name.chars().next().map_or(0, |v| v as usize);
+        if let Some(v) = BUILT_IN_NAMES.get(code) {
+            name = (*v).to_string();
+        }
+    }
+    let (rgce, rgcb) = worksheet::read_formula(reader)?;
+    Ok(NameRecord {
+        name,
+        sheet_index: if sheet_index == u32::MAX {
+            0
+        } else {
+            sheet_tab(sheet_index).saturating_add(1)
+        },
+        hidden: options & 0x01 != 0,
+        is_macro: options & 0x0E != 0,
+        rgce: rgce.to_vec(),
+        rgcb: rgcb.to_vec(),
+    })
+}
+
+fn read_shared_strings(stream: &[u8]) -> Result<Vec<String>, XlsxError> {
+    let mut shared_list = Vec::new();
+    let mut pos = 0;
+    while let Some((kind, data)) = record::read(stream, &mut pos) {
+        match kind {
+            record::SST_ITEM => {
+                let mut reader = RecordReader::new(data);
+                reader.skip(1)?;
+                shared_list.push(reader.wide_string()?);
+            }
+            record::END_SST => break,
+            _ => {}
+        }
+    }
+    Ok(shared_list)
+}
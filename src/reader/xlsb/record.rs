@@ -0,0 +1,94 @@
+pub(crate) const ROW_HDR: u16 = 0;
+pub(crate) const CELL_BLANK: u16 = 1;
+pub(crate) const CELL_RK: u16 = 2;
+pub(crate) const CELL_ERROR: u16 = 3;
+pub(crate) const CELL_BOOL: u16 = 4;
+pub(crate) const CELL_REAL: u16 = 5;
+pub(crate) const CELL_ST: u16 = 6;
+pub(crate) const CELL_ISST: u16 = 7;
+pub(crate) const FMLA_STRING: u16 = 8;
+pub(crate) const FMLA_NUM: u16 = 9;
+pub(crate) const FMLA_BOOL: u16 = 10;
+pub(crate) const FMLA_ERROR: u16 = 11;
+pub(crate) const SST_ITEM: u16 = 19;
+pub(crate) const NAME: u16 = 39;
+pub(crate) const FONT: u16 = 43;
+pub(crate) const FMT: u16 = 44;
+pub(crate) const FILL: u16 = 45;
+pub(crate) const BORDER: u16 = 46;
+pub(crate) const XF: u16 = 47;
+pub(crate) const STYLE: u16 = 48;
+pub(crate) const COL_INFO: u16 = 60;
+pub(crate) const CELL_R_STRING: u16 = 62;
+pub(crate) const FILE_VERSION: u16 = 128;
+pub(crate) const BEGIN_SHEET: u16 = 129;
+pub(crate) const END_SHEET: u16 = 130;
+pub(crate) const BEGIN_BOOK: u16 = 131;
+pub(crate) const END_BOOK: u16 = 132;
+pub(crate) const BEGIN_BOOK_VIEWS: u16 = 135;
+pub(crate) const END_BOOK_VIEWS: u16 = 136;
+pub(crate) const BEGIN_BUNDLE_SHS: u16 = 143;
+pub(crate) const END_BUNDLE_SHS: u16 = 144;
+pub(crate) const BEGIN_SHEET_DATA: u16 = 145;
+pub(crate) const END_SHEET_DATA: u16 = 146;
+pub(crate) const WS_DIM: u16 = 148;
+pub(crate) const WB_PROP: u16 = 153;
+pub(crate) const BUNDLE_SH: u16 = 156;
+pub(crate) const BOOK_VIEW: u16 = 158;
+pub(crate) const BEGIN_SST: u16 = 159;
+pub(crate) const END_SST: u16 = 160;
+pub(crate) const MERGE_CELL: u16 = 176;
+pub(crate) const BEGIN_MERGE_CELLS: u16 = 177;
+pub(crate) const END_MERGE_CELLS: u16 = 178;
+pub(crate) const BEGIN_STYLE_SHEET: u16 = 278;
+pub(crate) const END_STYLE_SHEET: u16 = 279;
+pub(crate) const BEGIN_EXTERNALS: u16 = 353;
+pub(crate) const END_EXTERNALS: u16 = 354;
+pub(crate) const SUP_BOOK_SRC: u16 = 355;
+pub(crate) const SUP_SELF: u16 = 357;
+pub(crate) const SUP_SAME: u16 = 358;
+pub(crate) const EXTERN_SHEET: u16 = 362;
+pub(crate) const BEGIN_COL_INFOS: u16 = 390;
+pub(crate) const END_COL_INFOS: u16 = 391;
+pub(crate) const ARR_FMLA: u16 = 426;
+pub(crate) const SHR_FMLA: u16 = 428;
+pub(crate) const BEGIN_FILLS: u16 = 603;
+pub(crate) const END_FILLS: u16 = 604;
+pub(crate) const BEGIN_FONTS: u16 = 611;
+pub(crate) const END_FONTS: u16 = 612;
+pub(crate) const BEGIN_BORDERS: u16 = 613;
+pub(crate) const END_BORDERS: u16 = 614;
+pub(crate) const BEGIN_FMTS: u16 = 615;
+pub(crate) const END_FMTS: u16 = 616;
+pub(crate) const BEGIN_CELL_XFS: u16 = 617;
+pub(crate) const END_CELL_XFS: u16 = 618;
+pub(crate) const BEGIN_STYLES: u16 = 619;
+pub(crate) const END_STYLES: u16 = 620;
+pub(crate) const BEGIN_CELL_STYLE_XFS: u16 = 626;
+pub(crate) const END_CELL_STYLE_XFS: u16 = 627;
+pub(crate) const SUP_ADDIN: u16 = 667;
+
+/// Read the record at `pos` of a part and move `pos` after it.
+/// The type and the size of a record are stored in 7 bits per byte, the
+/// high bit tells that another byte follows.
+pub(crate) fn read<'a>(stream: &'a [u8], pos: &mut usize) -> Option<(u16, &'a [u8])> {
+    let kind = read_varint(stream, pos, 2)?;
+    let len = read_varint(stream, pos, 4)?;
+    let len = usize::try_from(len).ok()?;
+    let data = stream.get(*pos..*pos + len)?;
+    *pos += len;
+    Some((u16::try_from(kind).ok()?, data))
+}
+
+fn read_varint(stream: &[u8], pos: &mut usize, max_len: usize) -> Option<u32> {
+    let mut value = 0;
+    for index in 0..max_len {
+        let byte = *stream.get(*pos)?;
+        *pos += 1;
+        value |= u32::from(byte & 0x7F) << (7 * index);
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    Some(value)
+}
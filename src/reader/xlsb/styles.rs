@@ -0,0 +1,341 @@
+use super::record;
+use crate::{
+    XlsxError,
+    reader::xls::{
+        record::RecordReader,
+        style::{
+            BORDER_STYLES,
+            PATTERN_TYPES,
+        },
+    },
+    structs::{
+        Alignment,
+        Border,
+        Borders,
+        CellFormat,
+        CellStyle,
+        Color,
+        Fill,
+        Font,
+        GradientFill,
+        GradientStop,
+        HorizontalAlignmentValues,
+        NumberingFormat,
+        PatternValues,
+        Protection,
+        Stylesheet,
+        VerticalAlignmentRunValues,
+        VerticalAlignmentValues,
+    },
+};
+
+/// `fls` value of a gradient fill.
+pub(crate) const GRADIENT_FILL: u32 = 0x28;
+
+/// Horizontal alignments by their `alc` value.
+pub(crate) const HORIZONTAL_ALIGNMENTS: [HorizontalAlignmentValues; 8] = [
+    HorizontalAlignmentValues::General,
+    HorizontalAlignmentValues::Left,
+    HorizontalAlignmentValues::Center,
+    HorizontalAlignmentValues::Right,
+    HorizontalAlignmentValues::Fill,
+    HorizontalAlignmentValues::Justify,
+    HorizontalAlignmentValues::CenterContinuous,
+    HorizontalAlignmentValues::Distributed,
+];
+
+/// Vertical alignments by their `alcv` value.
+pub(crate) const VERTICAL_ALIGNMENTS: [VerticalAlignmentValues; 5] = [
+    VerticalAlignmentValues::Top,
+    VerticalAlignmentValues::Center,
+    VerticalAlignmentValues::Bottom,
+    VerticalAlignmentValues::Justify,
+    VerticalAlignmentValues::Distributed,
+];
+
+/// Read the records of `xl/styles.bin`.
+pub(crate) fn read(stream: &[u8]) -> Result<Stylesheet, XlsxError> {
+    let mut stylesheet = Stylesheet::default();
+    stylesheet.numbering_formats_mut().build_in_formats();
+    let mut is_style_xf = false;
+    let mut pos = 0;
+    while let Some((kind, data)) = record::read(stream, &mut pos) {
+        let mut reader = RecordReader::new(data);
+        match kind {
+            record::FMT => {
+                let id = reader.u16()?;
+                let code = reader.wide_string()?;
+                let mut obj = NumberingFormat::default();
+                obj.set_number_format_id_crate(u32::from(id))
+                    .set_format_code_crate(code);
+                stylesheet.numbering_formats_mut().set_numbering_format(obj);
+            }
+            record::FONT => {
+                stylesheet.fonts_mut().set_font(read_font(&mut reader)?);
+            }
+            record::FILL => {
+                stylesheet.fills_mut().set_fill(read_fill(&mut reader)?);
+            }
+            record::BORDER => {
+                stylesheet
+                    .borders_mut()
+                    .set_borders(read_borders(&mut reader)?);
+            }
+            record::BEGIN_CELL_STYLE_XFS => is_style_xf = true,
+            record::END_CELL_STYLE_XFS => is_style_xf = false,
+            record::XF => {
+                let cell_format = read_xf(&mut reader, is_style_xf)?;
+                if is_style_xf {
+                    stylesheet
+                        .cell_style_formats_mut()
+                        .set_cell_format(cell_format);
+                } else {
+                    stylesheet.cell_formats_mut().set_cell_format(cell_format);
+                }
+            }
+            record::STYLE => {
+                let format_id = reader.u32()?;
+                let options = reader.u16()?;
+                let builtin_id = reader.u8()?;
+                reader.skip(1)?;
+                let mut obj = CellStyle::default();
+                obj.set_name(reader.wide_string()?);
+                obj.set_format_id(format_id);
+                if options & 0x01 != 0 {
+                    obj.set_builtin_id(u32::from(builtin_id));
+                }
+                stylesheet.cell_styles_mut().add_cell_style(obj);
+            }
+            record::END_STYLE_SHEET => break,
+            _ => {}
+        }
+    }
+    stylesheet.make_style();
+    Ok(stylesheet)
+}
+
+/// `BrtColor`: type, index, tint and RGBA value.
+pub(crate) fn read_color(reader: &mut RecordReader) -> Result<Option<Color>, XlsxError> {
+    let kind = reader.u8()? >> 1;
+    let index = reader.u8()?;
+    let tint = i16::from_le_bytes(reader.u16()?.to_le_bytes());
+    let rgba = reader.bytes(4)?;
+    let mut color = Color::default();
+    match kind {
+        1 => color.set_indexed(u32::from(index)),
+        2 => color.set_argb_str(format!(
+            "{:02X}{:02X}{:02X}{:02X}",
+            rgba[3], rgba[0], rgba[1], rgba[2]
+        )),
+        3 => color.set_theme_index(u32::from(index)),
+        _ => return Ok(None),
+    };
+    if tint != 0 {
+        color.set_tint(f64::from(tint) / 32767.0);
+    }
+    Ok(Some(color))
+}
+
+fn read_font(reader: &mut RecordReader) -> Result<Font, XlsxError> {
+    let height = reader.u16()?;
+    let options = reader.u16()?;
+    let weight = reader.u16()?;
+    let script = reader.u16()?;
+    let underline = reader.u8()?;
+    let family = reader.u8()?;
+    let charset = reader.u8()?;
+    reader.skip(1)?;
+    let color = read_color(reader)?;
+    let scheme = reader.u8()?;
+    let name = reader.wide_string()?;
+
+    let mut font = Font::default();
+    font.set_name(name);
+    font.set_size(f64::from(height) / 20.0);
+    if weight >= 700 {
+        font.set_bold(true);
+    }
+    if options & 0x02 != 0 {
+        font.set_italic(true);
+    }
+    if options & 0x08 != 0 {
+        font.set_strikethrough(true);
+    }
+    match underline {
+        0x01 => font.set_underline("single"),
+        0x02 => font.set_underline("double"),
+        0x21 => font.set_underline("singleAccounting"),
+        0x22 => font.set_underline("doubleAccounting"),
+        _ => &mut font,
+    };
+    match script {
+        1 => {
+            font.vertical_text_alignment_mut()
+                .set_val(VerticalAlignmentRunValues::Superscript);
+        }
+        2 => {
+            font.vertical_text_alignment_mut()
+                .set_val(VerticalAlignmentRunValues::Subscript);
+        }
+        _ => {}
+    }
+    if let Some(color) = color {
+        font.set_color(color);
+    }
+    if family != 0 {
+        font.set_family(i32::from(family));
+    }
+    if charset != 0 {
+        font.set_charset(i32::from(charset));
+    }
+    match scheme {
+        1 => font.set_scheme("major"),
+        2 => font.set_scheme("minor"),
+        _ => &mut font,
+    };
+    Ok(font)
+}
+
+fn read_fill(reader: &mut RecordReader) -> Result<Fill, XlsxError> {
+    let pattern = reader.u32()?;
+    let foreground = read_color(reader)?;
+    let background = read_color(reader)?;
+    let mut fill = Fill::default();
+    if pattern == GRADIENT_FILL {
+        reader.skip(4)?;
+        let degree = reader.f64()?;
+        reader.skip(32)?;
+        let count = reader.u32()?;
+        let mut gradient_fill = GradientFill::default();
+        gradient_fill.set_degree(degree);
+        for _ in 0..count {
+            let color = read_color(reader)?;
+            let mut stop = GradientStop::default();
+            stop.set_position(reader.f64()?);
+            if let Some(color) = color {
+                stop.set_color(color);
+            }
+            gradient_fill.set_gradient_stop(stop);
+        }
+        fill.set_gradient_fill(gradient_fill);
+        return Ok(fill);
+    }
+    let index: usize = num_traits::cast(pattern).unwrap();
+    let pattern = PATTERN_TYPES
+        .get(index)
+        .cloned()
+        .unwrap_or(PatternValues::None);
+    let pattern_fill = fill.pattern_fill_mut();
+    // Excel keeps colors on an empty fill; setting them would make it solid.
+    if pattern == PatternValues::None {
+        return Ok(fill);
+    }
+    pattern_fill.set_pattern_type(pattern);
+    if let Some(color) = foreground {
+        pattern_fill.set_foreground_color(color);
+    }
+    if let Some(color) = background {
+        pattern_fill.set_background_color(color);
+    }
+    Ok(fill)
+}
+
+fn read_borders(reader: &mut RecordReader) -> Result<Borders, XlsxError> {
+    let options = reader.u8()?;
+    let mut borders = Borders::default();
+    for index in 0..5 {
+        let line = reader.u8()?;
+        reader.skip(1)?;
+        let color = read_color(reader)?;
+        let Some(line) = BORDER_STYLES
+            .get(usize::from(line))
+            .filter(|v| **v != Border::BORDER_NONE)
+        else {
+            continue;
+        };
+        let border = match index {
+            0 => borders.top_mut(),
+            1 => borders.bottom_mut(),
+            2 => borders.left_mut(),
+            3 => borders.right_mut(),
+            _ => borders.diagonal_mut(),
+        };
+        border.set_border_style(*line);
+        if let Some(color) = color {
+            border.set_color(color);
+        }
+    }
+    if options & 0x01 != 0 {
+        borders.set_diagonal_down(true);
+    }
+    if options & 0x02 != 0 {
+        borders.set_diagonal_up(true);
+    }
+    Ok(borders)
+}
+
+fn read_xf(reader: &mut RecordReader, is_style_xf: bool) -> Result<CellFormat, XlsxError> {
+    let parent = reader.u16()?;
+    let format = reader.u16()?;
+    let font = reader.u16()?;
+    let fill = reader.u16()?;
+    let border = reader.u16()?;
+    let rotation = reader.u8()?;
+    let indent = reader.u8()?;
+    let options = reader.u16()?;
+    let applied = reader.u8()?;
+
+    let mut cell_format = CellFormat::default();
+    cell_format.set_number_format_id(u32::from(format));
+    cell_format.set_font_id(u32::from(font));
+    cell_format.set_fill_id(u32::from(fill));
+    cell_format.set_border_id(u32::from(border));
+    if !is_style_xf {
+        cell_format.set_format_id(if parent == 0xFFFF { 0 } else { u32::from(parent) });
+        if applied & 0x01 != 0 {
+            cell_format.set_apply_number_format(true);
+        }
+        if applied & 0x02 != 0 {
+            cell_format.set_apply_font(true);
+        }
+        if applied & 0x04 != 0 {
+            cell_format.set_apply_alignment(true);
+        }
+        if applied & 0x08 != 0 {
+            cell_format.set_apply_border(true);
+        }
+        if applied & 0x10 != 0 {
+            cell_format.set_apply_fill(true);
+        }
+        if applied & 0x20 != 0 {
+            cell_format.set_apply_protection(true);
+        }
+    }
+
+    let horizontal = usize::from(options & 0x07);
+    let vertical = usize::from((options >> 3) & 0x07);
+    let wrap_text = options & 0x40 != 0;
+    if horizontal != 0 || vertical != 2 || wrap_text || rotation != 0 || indent != 0 {
+        let mut alignment = Alignment::default();
+        if let Some(v) = HORIZONTAL_ALIGNMENTS.get(horizontal).filter(|_| horizontal != 0) {
+            alignment.set_horizontal(v.clone());
+        }
+        if let Some(v) = VERTICAL_ALIGNMENTS.get(vertical).filter(|_| vertical != 2) {
+            alignment.set_vertical(v.clone());
+        }
+        alignment.set_wrap_text(wrap_text);
+        alignment.set_text_rotation(u32::from(rotation));
+        alignment.set_indent(u32::from(indent));
+        cell_format.set_alignment(alignment);
+    }
+
+    let locked = options & 0x1000 != 0;
+    let hidden = options & 0x2000 != 0;
+    if !locked || hidden {
+        let mut protection = Protection::default();
+        protection.set_locked(locked);
+        protection.set_hidden(hidden);
+        cell_format.set_protection(protection);
+    }
+    Ok(cell_format)
+}
@@ -0,0 +1,246 @@
+use super::record;
+use crate::{
+    XlsxError,
+    helper::coordinate::{
+        coordinate_from_index,
+        string_from_column_index,
+    },
+    reader::xls::{
+        formula::{
+            FormulaContext,
+            decompile,
+            error_text,
+        },
+        record::RecordReader,
+        rk_value,
+    },
+    structs::{
+        Cell,
+        CellFormula,
+        CellFormulaValues,
+        Stylesheet,
+        Worksheet,
+    },
+};
+
+/// Rows and columns of a range: first row, last row, first column and
+/// last column.
+type RfX = (u32, u32, u32, u32);
+
+/// A shared or array formula: the range, the tokens and the data following
+/// them.
+type RangeFormula = (RfX, Vec<u8>, Vec<u8>);
+
+/// Read the records of a worksheet part.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn read(
+    stream: &[u8],
+    worksheet: &mut Worksheet,
+    shared_list: &[String],
+    stylesheet: &Stylesheet,
+    context: &FormulaContext,
+) -> Result<(), XlsxError> {
+    let mut row = 0;
+    let mut exp_list: Vec<(u32, u32, u32)> = Vec::new();
+    let mut shared_formulas: Vec<RangeFormula> = Vec::new();
+    let mut array_formulas: Vec<RangeFormula> = Vec::new();
+
+    let mut pos = 0;
+    while let Some((kind, data)) = record::read(stream, &mut pos) {
+        let mut reader = RecordReader::new(data);
+        match kind {
+            record::END_SHEET => break,
+            record::ROW_HDR => {
+                row = reader.u32()?;
+                reader.skip(4)?;
+                let height = reader.u16()?;
+                reader.skip(1)?;
+                let options = reader.u8()?;
+                let hidden = options & 0x10 != 0;
+                let custom_height = options & 0x20 != 0;
+                if hidden || custom_height {
+                    let dimension = worksheet.row_dimension_mut(row + 1);
+                    dimension.set_hidden(hidden);
+                    if custom_height {
+                        dimension.set_height(f64::from(height) / 20.0);
+                        dimension.set_custom_height(true);
+                    }
+                }
+            }
+            record::CELL_BLANK => {
+                read_cell(&mut reader, worksheet, row, stylesheet)?;
+            }
+            record::CELL_RK => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                let value = rk_value(reader.u32()?);
+                cell(worksheet, row, col).set_value_number(value);
+            }
+            record::CELL_ERROR => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                let value = reader.u8()?;
+                cell(worksheet, row, col).set_error(error_text(value));
+            }
+            record::CELL_BOOL => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                let value = reader.u8()?;
+                cell(worksheet, row, col).set_value_bool(value != 0);
+            }
+            record::CELL_REAL => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                let value = reader.f64()?;
+                cell(worksheet, row, col).set_value_number(value);
+            }
+            record::CELL_ST => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                let value = reader.wide_string()?;
+                cell(worksheet, row, col).set_value_string(value);
+            }
+            record::CELL_R_STRING => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                reader.skip(1)?;
+                let value = reader.wide_string()?;
+                cell(worksheet, row, col).set_value_string(value);
+            }
+            record::CELL_ISST => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                let index = reader.u32()?;
+                let value = shared_list
+                    .get(num_traits::cast::<u32, usize>(index).unwrap())
+                    .cloned()
+                    .unwrap_or_default();
+                cell(worksheet, row, col).set_value_string(value);
+            }
+            record::FMLA_STRING | record::FMLA_NUM | record::FMLA_BOOL | record::FMLA_ERROR => {
+                let col = read_cell(&mut reader, worksheet, row, stylesheet)?;
+                let cell = cell(worksheet, row, col);
+                match kind {
+                    record::FMLA_STRING => {
+                        cell.set_formula_result_string(reader.wide_string()?);
+                    }
+                    record::FMLA_NUM => {
+                        cell.set_formula_result_number(reader.f64()?);
+                    }
+                    record::FMLA_BOOL => {
+                        cell.set_formula_result_bool(reader.u8()? != 0);
+                    }
+                    _ => {
+                        cell.set_formula_result_default(error_text(reader.u8()?));
+                    }
+                }
+                reader.skip(2)?;
+                let (rgce, rgcb) = read_formula(&mut reader)?;
+                if rgce.len() == 5 && rgce[0] == 0x01 {
+                    let mut value = RecordReader::new(&rgce[1..]);
+                    exp_list.push((row, col, value.u32()?));
+                } else if let Some(v) = decompile(rgce, rgcb, None, context) {
+                    cell.set_formula(v);
+                }
+            }
+            record::SHR_FMLA => {
+                let range = read_rfx(&mut reader)?;
+                let (rgce, rgcb) = read_formula(&mut reader)?;
+                shared_formulas.push((range, rgce.to_vec(), rgcb.to_vec()));
+            }
+            record::ARR_FMLA => {
+                let range = read_rfx(&mut reader)?;
+                reader.skip(1)?;
+                let (rgce, rgcb) = read_formula(&mut reader)?;
+                array_formulas.push((range, rgce.to_vec(), rgcb.to_vec()));
+            }
+            record::COL_INFO => {
+                let first = reader.u32()?;
+                let last = reader.u32()?.min(0x3FFF);
+                let width = f64::from(reader.u32()?) / 256.0;
+                reader.skip(4)?;
+                let hidden = reader.u16()? & 0x01 != 0;
+                for col in first..=last {
+                    let column = string_from_column_index(col + 1);
+                    let dimension = worksheet.column_dimension_mut(&column);
+                    dimension.set_width(width);
+                    dimension.set_hidden(hidden);
+                }
+            }
+            record::MERGE_CELL => {
+                let (first_row, last_row, first_col, last_col) = read_rfx(&mut reader)?;
+                worksheet.add_merge_cells(format!(
+                    "{}:{}",
+                    coordinate_from_index(first_col + 1, first_row + 1),
+                    coordinate_from_index(last_col + 1, last_row + 1)
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    // The cells of a shared or an array formula only store the first row of
+    // its range.
+    let contains = |range: &RfX, row: u32, col: u32, first_row: u32| {
+        range.0 == first_row && (range.0..=range.1).contains(&row) && (range.2..=range.3).contains(&col)
+    };
+    for (row, col, first_row) in exp_list {
+        if let Some((_, rgce, rgcb)) = shared_formulas
+            .iter()
+            .find(|v| contains(&v.0, row, col, first_row))
+        {
+            if let Some(v) = decompile(rgce, rgcb, Some((row, col)), context) {
+                cell(worksheet, row, col).set_formula(v);
+            }
+        } else if let Some((range, rgce, rgcb)) = array_formulas
+            .iter()
+            .find(|v| contains(&v.0, row, col, first_row))
+        {
+            if (row, col) != (range.0, range.2) {
+                continue;
+            }
+            if let Some(v) = decompile(rgce, rgcb, None, context) {
+                let mut formula = CellFormula::default();
+                formula.set_formula_type(CellFormulaValues::Array);
+                formula.set_reference(format!(
+                    "{}:{}",
+                    coordinate_from_index(range.2 + 1, range.0 + 1),
+                    coordinate_from_index(range.3 + 1, range.1 + 1)
+                ));
+                formula.set_text(v);
+                cell(worksheet, row, col)
+                    .cell_value_mut()
+                    .set_formula_obj(formula);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn cell(worksheet: &mut Worksheet, row: u32, col: u32) -> &mut Cell {
+    worksheet.cell_mut((col + 1, row + 1))
+}
+
+/// Read the column and the format of a cell and set its style.
+fn read_cell(
+    reader: &mut RecordReader,
+    worksheet: &mut Worksheet,
+    row: u32,
+    stylesheet: &Stylesheet,
+) -> Result<u32, XlsxError> {
+    let col = reader.u32()?;
+    let xf = reader.u32()? & 0x00FF_FFFF;
+    let cell = cell(worksheet, row, col);
+    if xf != 0 {
+        cell.set_style(stylesheet.style(num_traits::cast(xf).unwrap()));
+    }
+    Ok(col)
+}
+
+fn read_rfx(reader: &mut RecordReader) -> Result<RfX, XlsxError> {
+    Ok((reader.u32()?, reader.u32()?, reader.u32()?, reader.u32()?))
+}
+
+/// Tokens of a formula and the data following them.
+pub(crate) fn read_formula<'a>(
+    reader: &mut RecordReader<'a>,
+) -> Result<(&'a [u8], &'a [u8]), XlsxError> {
+    let size = reader.u32()?;
+    let rgce = reader.bytes(num_traits::cast(size).unwrap())?;
+    let size = reader.u32()?;
+    let rgcb = reader.bytes(num_traits::cast(size).unwrap())?;
+    Ok((rgce, rgcb))
+}
@@ -9,9 +9,18 @@ use super::driver;
 use crate::{
     XlsxError,
     helper::const_str::{
+        ARC_APP,
+        ARC_CORE,
+        ARC_CUSTOM,
+        CHARTSHEET_NS,
         COMMENTS_NS,
+        CONTENT_TYPES,
         DRAWINGS_NS,
         PIVOT_TABLE_NS,
+        PKG_SHARED_STRINGS,
+        PKG_STYLES,
+        PKG_WORKBOOK,
+        PKG_WORKBOOK_RELS,
         TABLE_NS,
         THEME_NS,
         THREADED_COMMENT_NS,
@@ -19,6 +28,8 @@ use crate::{
     },
     structs::{
         Cell,
+        Chartsheet,
+        ReadOptions,
         SharedStringTable,
         Stylesheet,
         Workbook,
@@ -29,20 +40,24 @@ use crate::{
 };
 
 pub(crate) mod chart;
+mod chartsheet;
 pub(crate) mod comment;
 mod content_types;
-mod doc_props_app;
-mod doc_props_core;
+pub(crate) mod doc_props_app;
+pub(crate) mod doc_props_core;
 mod doc_props_custom;
 pub(crate) mod drawing;
 mod jsa_project_bin;
+mod passthrough;
 mod pivot_cache;
 mod pivot_table;
 mod rels;
+#[cfg(feature = "serde")]
+mod rows_as;
 mod shared_strings;
 mod styles;
 pub(crate) mod table;
-mod theme;
+pub(crate) mod theme;
 pub(crate) mod threaded_comment;
 mod vba_project_bin;
 pub(crate) mod vml_drawing;
@@ -50,6 +65,9 @@ mod workbook;
 mod workbook_rels;
 pub(crate) mod worksheet;
 
+#[cfg(feature = "serde")]
+pub use rows_as::RowsAs;
+
 /// read spreadsheet from arbitrary reader.
 /// # Arguments
 /// * `reader` - reader to read from.
@@ -59,57 +77,177 @@ pub fn read_reader<R: io::Read + io::Seek>(
     reader: R,
     with_sheet_read: bool,
 ) -> Result<Workbook, XlsxError> {
-    read_reader_with_source(reader, with_sheet_read, None)
+    let options = ReadOptions::default();
+    let mut warnings = Vec::new();
+    read_reader_with_source(reader, with_sheet_read, None, &options, &mut warnings)
+}
+
+/// read spreadsheet from arbitrary reader with options.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `options` - read options.
+/// # Return value
+/// * `Result` - OK is `Workbook` and the errors skipped in lenient mode. Err
+///   is error message.
+pub fn read_reader_with_options<R: io::Read + io::Seek>(
+    reader: R,
+    options: &ReadOptions,
+) -> Result<(Workbook, Vec<XlsxError>), XlsxError> {
+    let mut warnings = Vec::new();
+    let book = read_reader_with_source(reader, true, None, options, &mut warnings)?;
+    Ok((book, warnings))
 }
 
 fn read_reader_with_source<R: io::Read + io::Seek>(
     reader: R,
     with_sheet_read: bool,
     source_file: Option<&Path>,
+    options: &ReadOptions,
+    warnings: &mut Vec<XlsxError>,
 ) -> Result<Workbook, XlsxError> {
     let mut arv = zip::read::ZipArchive::new(reader)?;
 
-    let mut book = workbook::read(&mut arv)?;
-    doc_props_app::read(&mut arv, &mut book)?;
-    doc_props_core::read(&mut arv, &mut book)?;
-    doc_props_custom::read(&mut arv, &mut book)?;
+    let mut book = workbook::read(&mut arv).map_err(|e| e.in_part(PKG_WORKBOOK))?;
+    let result = doc_props_app::read(&mut arv, &mut book).map_err(|e| e.in_part(ARC_APP));
+    options.recover(result, warnings)?;
+    let result = doc_props_core::read(&mut arv, &mut book).map_err(|e| e.in_part(ARC_CORE));
+    options.recover(result, warnings)?;
+    let result = doc_props_custom::read(&mut arv, &mut book).map_err(|e| e.in_part(ARC_CUSTOM));
+    options.recover(result, warnings)?;
     vba_project_bin::read(&mut arv, &mut book)?;
     jsa_project_bin::read(&mut arv, &mut book)?;
-    content_types::read(&mut arv, &mut book)?;
-    let workbook_rel = workbook_rels::read(&mut arv, &mut book)?;
+    content_types::read(&mut arv, &mut book).map_err(|e| e.in_part(CONTENT_TYPES))?;
+    let workbook_rel =
+        workbook_rels::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_WORKBOOK_RELS))?;
+
+    if options.preserve_unknown() {
+        let result = passthrough::read(&mut arv, &mut book, &workbook_rel, source_file);
+        options.recover(result, warnings)?;
+    }
 
     book.set_theme(Theme::default_value());
     for (_, type_value, rel_target) in &workbook_rel {
         if type_value == THEME_NS {
-            let theme = theme::read(&mut arv, rel_target)?;
-            book.set_theme(theme);
+            let result = theme::read(&mut arv, rel_target)
+                .map_err(|e| e.in_part(&format!("xl/{rel_target}")));
+            if let Some(theme) = options.recover(result, warnings)? {
+                book.set_theme(theme);
+            }
         }
     }
 
-    shared_strings::read(&mut arv, &mut book)?;
-    styles::read(&mut arv, &mut book)?;
+    let result = shared_strings::read(&mut arv, &mut book)
+        .map_err(|e| e.in_part(PKG_SHARED_STRINGS));
+    options.recover(result, warnings)?;
+    let result = styles::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_STYLES));
+    if options.recover(result, warnings)?.is_some() && options.preserve_unknown() {
+        let result =
+            passthrough::read_styles(&mut arv, &mut book).map_err(|e| e.in_part(PKG_STYLES));
+        options.recover(result, warnings)?;
+    }
 
     for sheet in book.sheet_collection_mut() {
-        for (rel_id, _, rel_target) in &workbook_rel {
+        for (rel_id, type_value, rel_target) in &workbook_rel {
             if sheet.r_id() != rel_id {
                 continue;
             }
+            if type_value == CHARTSHEET_NS {
+                let mut raw_chartsheet = RawWorksheet::default();
+                raw_chartsheet.read(&mut arv, rel_target)?;
+                let mut obj = Chartsheet::default();
+                chartsheet::read(&mut obj, &raw_chartsheet)
+                    .map_err(|e| e.in_part(raw_chartsheet.worksheet_file().file_target()))?;
+                sheet.set_chartsheet(obj);
+                continue;
+            }
             let mut raw_worksheet = RawWorksheet::default();
             match source_file {
-                Some(source_file) => raw_worksheet.read_lazy(&mut arv, rel_target, source_file),
-                None => raw_worksheet.read(&mut arv, rel_target),
+                Some(source_file) => raw_worksheet.read_lazy(&mut arv, rel_target, source_file)?,
+                None => raw_worksheet.read(&mut arv, rel_target)?,
             }
             sheet.set_raw_data_of_worksheet(raw_worksheet);
         }
     }
 
     if with_sheet_read {
-        book.read_sheet_collection();
+        book.read_sheet_collection_crate(options, warnings)?;
     }
 
     Ok(book)
 }
 
+/// read spreadsheet from an asynchronous reader.
+///
+/// The reader is seeked to its end to size the buffer, then the compressed
+/// package is read into memory and deserialized on a blocking thread, so the
+/// runtime is not blocked. Use [`read_stream_async`] for a reader that can
+/// not seek, such as the body of an upload.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `with_sheet_read` - deserialize the worksheets.
+/// # Return value
+/// * `Result` - OK is `Workbook`. Err is error message.
+/// # Examples
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let data = std::fs::read("./tests/test_files/aaa.xlsx").unwrap();
+/// let book = umya_spreadsheet::reader::xlsx::read_async(std::io::Cursor::new(data), true)
+///     .await
+///     .unwrap();
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub async fn read_async<R>(mut reader: R, with_sheet_read: bool) -> Result<Workbook, XlsxError>
+where
+    R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+{
+    use tokio::io::{
+        AsyncReadExt,
+        AsyncSeekExt,
+    };
+
+    let size = reader.seek(io::SeekFrom::End(0)).await?;
+    reader.seek(io::SeekFrom::Start(0)).await?;
+    let mut data = Vec::with_capacity(usize::try_from(size).unwrap_or_default());
+    reader.read_to_end(&mut data).await?;
+    read_package_async(data, with_sheet_read).await
+}
+
+/// read spreadsheet from an asynchronous reader that can not seek.
+///
+/// The central directory of a package is at its end, so the compressed
+/// package is read to the end of the stream before it is deserialized on a
+/// blocking thread. The entries are decompressed only once, when they are
+/// deserialized, and the CRC of each of them is checked against the central
+/// directory, including the entries that leave it to a data descriptor.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `with_sheet_read` - deserialize the worksheets.
+/// # Return value
+/// * `Result` - OK is `Workbook`. Err is error message.
+#[cfg(feature = "async")]
+pub async fn read_stream_async<R>(
+    mut reader: R,
+    with_sheet_read: bool,
+) -> Result<Workbook, XlsxError>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::AsyncReadExt;
+
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).await?;
+    read_package_async(data, with_sheet_read).await
+}
+
+/// Deserialize a package on a blocking thread.
+#[cfg(feature = "async")]
+async fn read_package_async(data: Vec<u8>, with_sheet_read: bool) -> Result<Workbook, XlsxError> {
+    tokio::task::spawn_blocking(move || read_reader(io::Cursor::new(data), with_sheet_read))
+        .await
+        .map_err(io::Error::other)?
+}
+
 /// read spreadsheet file.
 /// # Arguments
 /// * `path` - file path to read.
@@ -126,6 +264,39 @@ pub fn read<P: AsRef<Path>>(path: P) -> Result<Workbook, XlsxError> {
     read_reader(file, true)
 }
 
+/// read spreadsheet file with options.
+/// In lenient mode, the parts and worksheet elements that can not be read are
+/// skipped and their errors are returned as warnings.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `options` - read options.
+/// # Return value
+/// * `Result` - OK is Workbook and the errors skipped in lenient mode. Err is
+///   error message.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::ReadOptions;
+///
+/// let mut options = ReadOptions::default();
+/// options.set_strict(false);
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let (mut book, warnings) =
+///     umya_spreadsheet::reader::xlsx::read_with_options(path, &options).unwrap();
+/// for warning in &warnings {
+///     if let Some(location) = warning.location() {
+///         println!("skipped {} at byte {}", location.part(), location.position());
+///     }
+/// }
+/// ```
+#[inline]
+pub fn read_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &ReadOptions,
+) -> Result<(Workbook, Vec<XlsxError>), XlsxError> {
+    let file = File::open(path)?;
+    read_reader_with_options(file, options)
+}
+
 /// lazy read spreadsheet file.
 /// Delays the loading of the worksheet until it is needed.
 /// When loading a file with a large amount of data, response improvement can be
@@ -141,7 +312,9 @@ pub fn read<P: AsRef<Path>>(path: P) -> Result<Workbook, XlsxError> {
 #[inline]
 pub fn lazy_read(path: &Path) -> Result<Workbook, XlsxError> {
     let file = File::open(path)?;
-    read_reader_with_source(file, false, Some(path))
+    let options = ReadOptions::default();
+    let mut warnings = Vec::new();
+    read_reader_with_source(file, false, Some(path), &options, &mut warnings)
 }
 
 /// Stream cells from a worksheet without deserializing the worksheet into
@@ -179,71 +352,228 @@ where
     )
 }
 
+/// Stream the rows of a worksheet as NDJSON without deserializing the
+/// worksheet into memory.
+///
+/// Each row below the header row is written as a JSON object on its own
+/// line, keyed by the values of the header row. A column without a header
+/// is keyed by its letter, and rows without values are skipped.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
+/// * `header_row` - Specify the row of the headers. ex) 1
+/// * `option` - options.
+/// * `writer` - writer to write to.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::RecordsOption;
+///
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let mut ndjson = Vec::new();
+/// umya_spreadsheet::reader::xlsx::read_sheet_by_name_ndjson(
+///     path,
+///     "Sheet1",
+///     1,
+///     &RecordsOption::default(),
+///     &mut ndjson,
+/// )
+/// .unwrap();
+/// ```
+#[cfg(feature = "json")]
+pub fn read_sheet_by_name_ndjson<P, W>(
+    path: P,
+    sheet_name: &str,
+    header_row: u32,
+    option: &crate::structs::RecordsOption,
+    writer: W,
+) -> Result<(), XlsxError>
+where
+    P: AsRef<Path>,
+    W: io::Write,
+{
+    let mut stream = crate::helper::json::RecordStream::new(header_row, option, writer);
+    read_sheet_by_name_stream(path, sheet_name, |cell| stream.push(cell))?;
+    stream.finish()
+}
+
+/// Read the rows of a worksheet as values of `T` without deserializing the
+/// worksheet into memory.
+///
+/// The fields of `T` are matched with the headers of the header row of
+/// `mapping`. The cells are converted to the types of the fields, and a
+/// number with a date or time format is an ISO 8601 string for a field
+/// that is not a number, such as a `String` or a date of `jiff` or
+/// `chrono`. An empty cell is `None` for an `Option`. An error reports the
+/// coordinate of the cell.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
+/// * `mapping` - the header row and the headers of the fields.
+/// # Return value
+/// * `Result` - OK is an iterator over the rows. Err is error message.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::RowMapping;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Row {
+///     #[serde(rename = "B")]
+///     name: Option<String>,
+/// }
+///
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let rows = umya_spreadsheet::reader::xlsx::read_rows_as::<Row, _>(
+///     path,
+///     "Sheet1",
+///     &RowMapping::default(),
+/// )
+/// .unwrap();
+/// for row in rows {
+///     let _ = row.map(|v| v.name);
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub fn read_rows_as<T, P>(
+    path: P,
+    sheet_name: &str,
+    mapping: &crate::structs::RowMapping,
+) -> Result<RowsAs<T>, XlsxError>
+where
+    T: serde::de::DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let book = lazy_read(path.as_ref())?;
+    let Some(worksheet) = book
+        .sheet_collection_no_check()
+        .iter()
+        .find(|worksheet| worksheet.name() == sheet_name)
+    else {
+        return Err(XlsxError::CellError(format!(
+            "Worksheet '{sheet_name}' not found."
+        )));
+    };
+    let mut worksheet_file = worksheet.raw_data_of_worksheet().worksheet_file().clone();
+    worksheet_file.load_file_data_from_source()?;
+    let shared_string_table = book.shared_string_table().read().unwrap().clone();
+    Ok(RowsAs::new(
+        std::mem::take(worksheet_file.file_data_mut()),
+        shared_string_table,
+        book.stylesheet().clone(),
+        mapping,
+    ))
+}
+
 pub(crate) fn raw_to_deserialize_by_worksheet(
     worksheet: &mut Worksheet,
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &Stylesheet,
-) {
+) -> Result<(), XlsxError> {
+    deserialize_worksheet(
+        worksheet,
+        shared_string_table,
+        stylesheet,
+        &ReadOptions::default(),
+        &mut Vec::new(),
+    )
+}
+
+/// Deserialize a worksheet. When it fails, the raw data is kept so that the
+/// worksheet stays not deserialized.
+pub(crate) fn deserialize_worksheet(
+    worksheet: &mut Worksheet,
+    shared_string_table: &RwLock<SharedStringTable>,
+    stylesheet: &Stylesheet,
+    options: &ReadOptions,
+    warnings: &mut Vec<XlsxError>,
+) -> Result<(), XlsxError> {
     if worksheet.is_deserialized() {
-        return;
+        return Ok(());
     }
 
     let mut raw_data_of_worksheet = worksheet.take_raw_data_of_worksheet();
+    let result = read_worksheet_parts(
+        worksheet,
+        &mut raw_data_of_worksheet,
+        shared_string_table,
+        stylesheet,
+        options,
+        warnings,
+    );
+    if result.is_err() {
+        worksheet.set_raw_data_of_worksheet(raw_data_of_worksheet);
+    }
+    result
+}
+
+fn read_worksheet_parts(
+    worksheet: &mut Worksheet,
+    raw_data_of_worksheet: &mut RawWorksheet,
+    shared_string_table: &RwLock<SharedStringTable>,
+    stylesheet: &Stylesheet,
+    options: &ReadOptions,
+    warnings: &mut Vec<XlsxError>,
+) -> Result<(), XlsxError> {
+    if options.preserve_unknown() {
+        raw_data_of_worksheet.load_file_data_from_source()?;
+    } else {
+        raw_data_of_worksheet.load_relationship_file_data_from_source()?;
+    }
     let shared_string_table = &*shared_string_table.read().unwrap();
     worksheet::read(
         worksheet,
-        &raw_data_of_worksheet,
+        raw_data_of_worksheet,
         shared_string_table,
         stylesheet,
-    )
-    .unwrap();
-    raw_data_of_worksheet
-        .load_relationship_file_data_from_source()
-        .unwrap();
+        options,
+        warnings,
+    )?;
 
     if let Some(v) = raw_data_of_worksheet.worksheet_relationships() {
         for relationship in v.relationship_list() {
-            match relationship.get_type() {
+            let raw_file = relationship.raw_file();
+            let result = match relationship.get_type() {
                 // drawing, chart
-                DRAWINGS_NS => {
-                    drawing::read(
-                        worksheet,
-                        relationship.raw_file(),
-                        raw_data_of_worksheet.drawing_relationships(),
-                    );
-                }
+                DRAWINGS_NS => drawing::read(
+                    worksheet,
+                    raw_file,
+                    raw_data_of_worksheet.drawing_relationships(),
+                ),
                 // comment
-                COMMENTS_NS => {
-                    comment::read(worksheet, relationship.raw_file());
-                }
+                COMMENTS_NS => comment::read(worksheet, raw_file),
                 // threaded_comment
-                THREADED_COMMENT_NS => {
-                    threaded_comment::read(worksheet, relationship.raw_file());
-                }
+                THREADED_COMMENT_NS => threaded_comment::read(worksheet, raw_file),
                 // table
-                TABLE_NS => {
-                    table::read(worksheet, relationship.raw_file()).unwrap();
-                }
+                TABLE_NS => table::read(worksheet, raw_file, stylesheet),
                 // pivot table, pivot cache
-                PIVOT_TABLE_NS => {
-                    pivot_table::read(
-                        worksheet,
-                        relationship.raw_file(),
-                        raw_data_of_worksheet.pivot_table_relationships(),
-                    );
-                }
-                _ => {}
-            }
+                PIVOT_TABLE_NS => pivot_table::read(
+                    worksheet,
+                    raw_file,
+                    raw_data_of_worksheet.pivot_table_relationships(),
+                ),
+                _ => Ok(()),
+            };
+            options.recover(result.map_err(|e| e.in_part(raw_file.file_target())), warnings)?;
         }
         for relationship in v.relationship_list() {
             // vmlDrawing
             if relationship.get_type() == VML_DRAWING_NS {
-                vml_drawing::read(
+                let raw_file = relationship.raw_file();
+                let result = vml_drawing::read(
                     worksheet,
-                    relationship.raw_file(),
-                    raw_data_of_worksheet.vml_drawing_relationships(),
+                    raw_file,
+                    raw_data_of_worksheet.relationships_of(raw_file),
                 );
+                options.recover(result.map_err(|e| e.in_part(raw_file.file_target())), warnings)?;
             }
         }
     }
+
+    if options.preserve_unknown() {
+        let part = raw_data_of_worksheet.worksheet_file().file_target();
+        let result = passthrough::read_worksheet(worksheet, raw_data_of_worksheet, stylesheet);
+        options.recover(result.map_err(|e| e.in_part(part)), warnings)?;
+    }
+    Ok(())
 }
@@ -4,6 +4,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     structs::{
         drawing::charts::ChartSpace,
         raw::RawFile,
@@ -11,7 +12,7 @@ use crate::{
     xml_read_loop,
 };
 
-pub(crate) fn read(raw_file: &RawFile, chart_space: &mut ChartSpace) {
+pub(crate) fn read(raw_file: &RawFile, chart_space: &mut ChartSpace) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(raw_file.file_data());
     let mut reader = Reader::from_reader(data);
 
@@ -21,9 +22,10 @@ pub(crate) fn read(raw_file: &RawFile, chart_space: &mut ChartSpace) {
         reader,
         Event::Start(ref e) => {
             if e.name().into_inner() == b"c:chartSpace" {
-                chart_space.set_attributes(&mut reader, e);
+                chart_space.set_attributes(&mut reader, e)?;
             }
         },
         Event::Eof => break,
     );
+    Ok(())
 }
@@ -0,0 +1,43 @@
+use std::io;
+
+use quick_xml::{
+    Reader,
+    events::Event,
+};
+
+use super::XlsxError;
+use crate::structs::{
+    Chartsheet,
+    raw::RawWorksheet,
+};
+
+pub(crate) fn read(
+    chartsheet: &mut Chartsheet,
+    raw_data_of_chartsheet: &RawWorksheet,
+) -> Result<(), XlsxError> {
+    let data = io::Cursor::new(raw_data_of_chartsheet.worksheet_file().file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                if e.name().into_inner() == b"chartsheet" {
+                    chartsheet.set_attributes(
+                        &mut reader,
+                        e,
+                        raw_data_of_chartsheet.chartsheet_relationships(),
+                        raw_data_of_chartsheet.drawing_relationships(),
+                    )?;
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(XlsxError::Xml(e)),
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(())
+}
@@ -4,6 +4,8 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
     structs::{
         Comment,
         Worksheet,
@@ -12,7 +14,7 @@ use crate::{
     xml_read_loop,
 };
 
-pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) {
+pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(drawing_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -28,16 +30,18 @@ pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) {
         Event::Start(ref e) => {
             if e.name().into_inner() ==  b"comment" {
                 let mut obj = Comment::default();
-                obj.set_attributes(&mut reader, e, &authors);
+                obj.set_attributes(&mut reader, e, &authors)?;
                 worksheet.add_comments(obj);
             }
             if e.name().into_inner() ==  b"author" {
                 let mut buf = Vec::new();
                 let text = reader
-                    .read_text_into(e.name(), &mut buf).unwrap();
+                    .read_text_into(e.name(), &mut buf)
+                    .map_err(|e| invalid_xml(reader.error_position(), &e))?;
                 authors.push(crate::helper::utils::unescape_xml_text(&text).clone());
             }
         },
         Event::Eof => break,
     );
+    Ok(())
 }
@@ -8,7 +8,7 @@ use quick_xml::{
 use super::{
     XlsxError,
     driver::{
-        get_attribute,
+        required_attribute,
         xml_read_loop,
     },
 };
@@ -25,19 +25,26 @@ pub(crate) fn read<R: io::Read + io::Seek>(
     let mut reader = Reader::from_reader(r);
     reader.config_mut().trim_text(true);
     let mut list: Vec<(String, String)> = Vec::new();
+    let mut default_list: Vec<(String, String)> = Vec::new();
 
     xml_read_loop!(
         reader,
         Event::Empty(ref e) => {
             if e.name().into_inner() == b"Override" {
-                let part_name = get_attribute(e, b"PartName").unwrap();
-                let content_type = get_attribute(e, b"ContentType").unwrap();
+                let part_name = required_attribute(&reader, e, "PartName")?;
+                let content_type = required_attribute(&reader, e, "ContentType")?;
                 list.push((part_name, content_type));
             }
+            if e.name().into_inner() == b"Default" {
+                let extension = required_attribute(&reader, e, "Extension")?;
+                let content_type = required_attribute(&reader, e, "ContentType")?;
+                default_list.push((extension, content_type));
+            }
         },
         Event::Eof => break,
     );
 
     wb.set_backup_context_types(list);
+    wb.set_backup_default_context_types(default_list);
     Ok(())
 }
@@ -5,7 +5,10 @@ use quick_xml::{
     events::Event,
 };
 
-use super::XlsxError;
+use super::{
+    XlsxError,
+    driver::invalid_xml,
+};
 use crate::{
     helper::const_str::ARC_APP,
     structs::Workbook,
@@ -31,11 +34,11 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(ref e)) => {
                 if e.name().into_inner() == b"Properties" {
-                    wb.properties_mut().set_attributes_app(&mut reader, e);
+                    wb.properties_mut().set_attributes_app(&mut reader, e)?;
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
             _ => (),
         }
         buf.clear();
@@ -4,6 +4,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::{
         Worksheet,
@@ -19,7 +20,7 @@ pub(crate) fn read(
     worksheet: &mut Worksheet,
     drawing_file: &RawFile,
     drawing_relationships: Option<&RawRelationships>,
-) {
+) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(drawing_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
@@ -34,10 +35,11 @@ pub(crate) fn read(
                     e,
                     drawing_relationships,
                     worksheet.ole_objects_mut(),
-                );
+                )?;
                 worksheet.set_worksheet_drawing(obj);
             }
         },
         Event::Eof => break
     );
+    Ok(())
 }
@@ -0,0 +1,285 @@
+use std::{
+    io,
+    path::Path,
+    sync::RwLock,
+};
+
+use super::XlsxError;
+use crate::{
+    helper::const_str::{
+        ACTIVEX_NS,
+        CALC_CHAIN_NS,
+        CHARTSHEET_NS,
+        COMMENTS_NS,
+        COREPROPS_REL,
+        CTRL_PROP_NS,
+        CUSTOM_PROPS_REL,
+        DRAWINGS_NS,
+        HYPERLINK_NS,
+        IMAGE_NS,
+        JSA_PROJECT_NS,
+        OFCDOC_NS,
+        OLE_OBJECT_NS,
+        PACKAGE_NS,
+        PERSION_NS,
+        PIVOT_CACHE_DEF_NS,
+        PIVOT_TABLE_NS,
+        PKG_STYLES,
+        PKG_WORKBOOK,
+        PRINTER_SETTINGS_NS,
+        SHARED_STRINGS_NS,
+        STYLES_NS,
+        TABLE_NS,
+        THEME_NS,
+        THREADED_COMMENT_NS,
+        VBA_PROJECT_NS,
+        VML_DRAWING_NS,
+        WORKSHEET_NS,
+        XPROPS_REL,
+    },
+    structs::{
+        SharedStringTable,
+        Stylesheet,
+        Workbook,
+        Worksheet,
+        raw::{
+            RawFile,
+            RawPassthrough,
+            RawRelationship,
+            RawRelationships,
+            RawWorksheet,
+            STYLESHEET_ORDER,
+            WORKBOOK_ORDER,
+            WORKSHEET_ORDER,
+        },
+    },
+    writer::xlsx::{
+        styles,
+        workbook,
+        worksheet,
+    },
+};
+
+const PACKAGE_RELATIONSHIPS: &[&str] = &[OFCDOC_NS, COREPROPS_REL, XPROPS_REL, CUSTOM_PROPS_REL];
+
+// The calculation chain is left out, it is out of date once the workbook
+// is changed.
+const WORKBOOK_RELATIONSHIPS: &[&str] = &[
+    WORKSHEET_NS,
+    CHARTSHEET_NS,
+    THEME_NS,
+    STYLES_NS,
+    SHARED_STRINGS_NS,
+    VBA_PROJECT_NS,
+    JSA_PROJECT_NS,
+    PERSION_NS,
+    PIVOT_CACHE_DEF_NS,
+    CALC_CHAIN_NS,
+];
+
+const WORKSHEET_RELATIONSHIPS: &[&str] = &[
+    DRAWINGS_NS,
+    VML_DRAWING_NS,
+    COMMENTS_NS,
+    THREADED_COMMENT_NS,
+    TABLE_NS,
+    PIVOT_TABLE_NS,
+    HYPERLINK_NS,
+    PRINTER_SETTINGS_NS,
+    OLE_OBJECT_NS,
+    PACKAGE_NS,
+    IMAGE_NS,
+    CTRL_PROP_NS,
+    ACTIVEX_NS,
+];
+
+const WORKBOOK_ELEMENTS: &[&str] = &[
+    "fileVersion",
+    "workbookPr",
+    "workbookProtection",
+    "bookViews",
+    "sheets",
+    "definedNames",
+    "calcPr",
+    "pivotCaches",
+];
+
+const WORKSHEET_ELEMENTS: &[&str] = &[
+    "sheetPr",
+    "dimension",
+    "sheetViews",
+    "sheetFormatPr",
+    "cols",
+    "sheetData",
+    "sheetProtection",
+    "autoFilter",
+    "mergeCells",
+    "phoneticPr",
+    "conditionalFormatting",
+    "dataValidations",
+    "hyperlinks",
+    "printOptions",
+    "pageMargins",
+    "pageSetup",
+    "headerFooter",
+    "rowBreaks",
+    "colBreaks",
+    "drawing",
+    "legacyDrawing",
+    "legacyDrawingHF",
+    "oleObjects",
+    "controls",
+    "tableParts",
+    "extLst",
+];
+
+const WORKSHEET_EXTENSIONS: &[&str] = &["conditionalFormattings", "dataValidations"];
+
+const STYLESHEET_ELEMENTS: &[&str] = &[
+    "numFmts",
+    "fonts",
+    "fills",
+    "borders",
+    "cellStyleXfs",
+    "cellXfs",
+    "cellStyles",
+    "dxfs",
+    "tableStyles",
+    "colors",
+    "extLst",
+];
+
+const STYLESHEET_EXTENSIONS: &[&str] = &["slicerStyles"];
+
+/// Keep the package and workbook relationships that are not supported, the
+/// parts they target and the unknown elements of the workbook part.
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+    wb: &mut Workbook,
+    workbook_rel: &[(String, String, String)],
+    source_file: Option<&Path>,
+) -> Result<(), XlsxError> {
+    let mut raw_relationships = RawRelationships::default();
+    if raw_relationships.set_attributes(arv, ".", "_rels/.rels", source_file)? {
+        let passthrough = wb.package_passthrough_mut();
+        for relationship in raw_relationships.relationship_list() {
+            if !PACKAGE_RELATIONSHIPS.contains(&relationship.get_type()) {
+                add_relationship(arv, passthrough, relationship.clone(), source_file)?;
+            }
+        }
+    }
+
+    let mut raw_file = RawFile::default();
+    raw_file.set_attributes(arv, ".", PKG_WORKBOOK);
+    let written = workbook::make_part(wb).into_inner().into_inner();
+    let passthrough = wb.workbook_passthrough_mut();
+    passthrough
+        .read_elements(raw_file.file_data(), WORKBOOK_ORDER, WORKBOOK_ELEMENTS, &[])
+        .and_then(|()| passthrough.read_nested(raw_file.file_data(), &written, &[]))
+        .map_err(|e| e.in_part(PKG_WORKBOOK))?;
+    for (id, type_value, target) in workbook_rel {
+        if WORKBOOK_RELATIONSHIPS.contains(&type_value.as_str()) && !passthrough.is_referenced(id)
+        {
+            continue;
+        }
+        let mut relationship = RawRelationship::default();
+        relationship
+            .set_id(id)
+            .set_type(type_value)
+            .set_target(target);
+        match source_file {
+            Some(v) => relationship
+                .raw_file_mut()
+                .set_attributes_from_source(arv, "xl", target, v),
+            None => relationship.raw_file_mut().set_attributes(arv, "xl", target),
+        }
+        add_relationship(arv, passthrough, relationship, source_file)?;
+    }
+    Ok(())
+}
+
+/// Keep the worksheet relationships that are not supported, the parts they
+/// target and the unknown elements of the worksheet part.
+pub(crate) fn read_worksheet(
+    worksheet: &mut Worksheet,
+    raw_data_of_worksheet: &RawWorksheet,
+    stylesheet: &Stylesheet,
+) -> Result<(), XlsxError> {
+    let data = raw_data_of_worksheet.worksheet_file().file_data();
+    let written = worksheet::make_part(
+        worksheet,
+        &RwLock::new(SharedStringTable::default()),
+        &mut stylesheet.clone(),
+        false,
+    )
+    .into_inner()
+    .into_inner();
+    let passthrough = worksheet.passthrough_mut();
+    passthrough.read_elements(
+        data,
+        WORKSHEET_ORDER,
+        WORKSHEET_ELEMENTS,
+        WORKSHEET_EXTENSIONS,
+    )?;
+    passthrough.read_nested(data, &written, &["sheetData"])?;
+    if let Some(v) = raw_data_of_worksheet.worksheet_relationships() {
+        for relationship in v.relationship_list() {
+            if WORKSHEET_RELATIONSHIPS.contains(&relationship.get_type())
+                && !passthrough.is_referenced(relationship.id())
+            {
+                continue;
+            }
+            add_part_relationships(passthrough, raw_data_of_worksheet, relationship.raw_file());
+            passthrough.add_relationship_list(relationship.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Keep the unknown elements of the styles part.
+pub(crate) fn read_styles<R: io::Read + io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+    wb: &mut Workbook,
+) -> Result<(), XlsxError> {
+    let mut raw_file = RawFile::default();
+    raw_file.set_attributes(arv, ".", PKG_STYLES);
+    let written = styles::make_part(wb.stylesheet()).into_inner().into_inner();
+    let passthrough = wb.stylesheet_mut().passthrough_mut();
+    passthrough.read_elements(
+        raw_file.file_data(),
+        STYLESHEET_ORDER,
+        STYLESHEET_ELEMENTS,
+        STYLESHEET_EXTENSIONS,
+    )?;
+    passthrough.read_nested(raw_file.file_data(), &written, &[])
+}
+
+fn add_relationship<R: io::Read + io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+    passthrough: &mut RawPassthrough,
+    relationship: RawRelationship,
+    source_file: Option<&Path>,
+) -> Result<(), XlsxError> {
+    if relationship.target_mode() != "External" {
+        passthrough.read_part_relationships(arv, relationship.raw_file(), source_file)?;
+    }
+    passthrough.add_relationship_list(relationship);
+    Ok(())
+}
+
+fn add_part_relationships(
+    passthrough: &mut RawPassthrough,
+    raw_data_of_worksheet: &RawWorksheet,
+    raw_file: &RawFile,
+) {
+    let Some(relationships) = raw_data_of_worksheet.relationships_of(raw_file) else {
+        return;
+    };
+    if passthrough.has_relationships(relationships.file_target()) {
+        return;
+    }
+    passthrough.add_relationships_list(relationships.clone());
+    for relationship in relationships.relationship_list() {
+        add_part_relationships(passthrough, raw_data_of_worksheet, relationship.raw_file());
+    }
+}
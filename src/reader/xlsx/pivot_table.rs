@@ -4,9 +4,13 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::PIVOT_CACHE_DEF_NS,
     raw::RawRelationships,
-    reader::xlsx::pivot_cache,
+    reader::{
+        driver::invalid_xml,
+        xlsx::pivot_cache,
+    },
     structs::{
         PivotTable,
         PivotTableDefinition,
@@ -19,7 +23,7 @@ pub(crate) fn read(
     worksheet: &mut Worksheet,
     pivot_table_file: &RawFile,
     pivot_table_relationships: Option<&RawRelationships>,
-) {
+) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(pivot_table_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -30,7 +34,7 @@ pub(crate) fn read(
             Ok(Event::Start(ref e)) => {
                 if e.name().into_inner() == b"pivotTableDefinition" {
                     let mut obj = PivotTableDefinition::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e)?;
                     pivot_table.set_pivot_table_definition(obj);
                 }
             }
@@ -40,7 +44,7 @@ pub(crate) fn read(
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
             _ => (),
         }
         buf.clear();
@@ -48,10 +52,11 @@ pub(crate) fn read(
 
     if let Some(rrs_list) = pivot_table_relationships {
         pivot_cache::read(
-            rrs_list.relationship_by_type(PIVOT_CACHE_DEF_NS).raw_file(),
+            rrs_list.relationship_by_type(PIVOT_CACHE_DEF_NS)?.raw_file(),
             &mut pivot_table,
-        );
+        )?;
     }
 
     worksheet.add_pivot_table(pivot_table);
+    Ok(())
 }
@@ -0,0 +1,150 @@
+use std::{
+    collections::HashMap,
+    io,
+    marker::PhantomData,
+};
+
+use quick_xml::{
+    Reader,
+    events::Event,
+};
+
+use super::{
+    XlsxError,
+    driver::xml_read_loop,
+    worksheet::read_row_cells_stream,
+};
+use crate::{
+    helper::{
+        coordinate::string_from_column_index,
+        formula::FormulaToken,
+        rows::row_to_value,
+    },
+    structs::{
+        Cell,
+        RowMapping,
+        SharedStringTable,
+        Stylesheet,
+    },
+};
+
+/// Iterator over the rows of a worksheet below the header row, each
+/// deserialized into `T`.
+///
+/// Created by [`read_rows_as`](crate::reader::xlsx::read_rows_as). Rows
+/// without values are skipped. An error of a row does not end the
+/// iteration, an error of the file does.
+pub struct RowsAs<T> {
+    reader:              Reader<io::Cursor<Vec<u8>>>,
+    shared_string_table: SharedStringTable,
+    stylesheet:          Stylesheet,
+    formula_shared_list: HashMap<u32, (String, Vec<FormulaToken>)>,
+    mapping:             RowMapping,
+    headers:             Vec<(u32, String)>,
+    is_done:             bool,
+    marker:              PhantomData<fn() -> T>,
+}
+
+impl<T> RowsAs<T> {
+    pub(crate) fn new(
+        data: Vec<u8>,
+        shared_string_table: SharedStringTable,
+        stylesheet: Stylesheet,
+        mapping: &RowMapping,
+    ) -> Self {
+        let mut reader = Reader::from_reader(io::Cursor::new(data));
+        reader.config_mut().trim_text(true);
+        Self {
+            reader,
+            shared_string_table,
+            stylesheet,
+            formula_shared_list: HashMap::new(),
+            mapping: mapping.clone(),
+            headers: Vec::new(),
+            is_done: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// The cells of the next row, `None` at the end of the worksheet.
+    fn next_row(&mut self) -> Result<Option<Vec<Cell>>, XlsxError> {
+        let reader = &mut self.reader;
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"row" {
+                    let mut cells = Vec::new();
+                    read_row_cells_stream(
+                        reader,
+                        &self.shared_string_table,
+                        &self.stylesheet,
+                        &mut self.formula_shared_list,
+                        &mut |cell: &Cell| cells.push(cell.clone()),
+                    )?;
+                    if !cells.is_empty() {
+                        return Ok(Some(cells));
+                    }
+                }
+            },
+            Event::Eof => return Ok(None),
+        );
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> Iterator for RowsAs<T> {
+    type Item = Result<T, XlsxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.is_done {
+            let cells = match self.next_row() {
+                Ok(Some(v)) => v,
+                Ok(None) => {
+                    self.is_done = true;
+                    return None;
+                }
+                Err(e) => {
+                    self.is_done = true;
+                    return Some(Err(e));
+                }
+            };
+            let row = cells[0].coordinate().row_num();
+            let header_row = self.mapping.header_row();
+            if row < header_row {
+                continue;
+            }
+            if row == header_row {
+                self.headers = cells
+                    .iter()
+                    .map(|cell| {
+                        let col = cell.coordinate().col_num();
+                        let header = cell.value();
+                        let header = if header.is_empty() {
+                            string_from_column_index(col)
+                        } else {
+                            header.into_owned()
+                        };
+                        (col, self.mapping.field(&header).to_string())
+                    })
+                    .collect();
+                continue;
+            }
+
+            let values: Vec<(&str, u32, Option<&Cell>)> = self
+                .headers
+                .iter()
+                .map(|(col, field)| {
+                    let cell = cells.iter().find(|v| v.coordinate().col_num() == *col);
+                    (field.as_str(), *col, cell)
+                })
+                .collect();
+            if values
+                .iter()
+                .all(|(_, _, cell)| cell.is_none_or(|v| v.value().is_empty()))
+            {
+                continue;
+            }
+            return Some(row_to_value(row, &values));
+        }
+        None
+    }
+}
@@ -1,23 +1,42 @@
 use quick_xml::{
     Reader,
-    events::Event,
+    events::{
+        BytesStart,
+        Event,
+    },
 };
 
 use super::{
     XlsxError,
-    driver::get_attribute_value,
+    driver::{
+        get_attribute_value,
+        invalid_attribute,
+        invalid_xml,
+    },
 };
-use crate::structs::{
-    ShowColumn,
-    ShowStripes,
-    Table,
-    TableColumn,
-    TableStyleInfo,
-    Worksheet,
-    raw::RawFile,
+use crate::{
+    helper::coordinate::CellCoordinates,
+    structs::{
+        DifferentialFormat,
+        DifferentialFormats,
+        ShowColumn,
+        ShowStripes,
+        Style,
+        Stylesheet,
+        Table,
+        TableColumn,
+        TableStyleInfo,
+        Worksheet,
+        raw::RawFile,
+    },
 };
 
-pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<(), XlsxError> {
+pub(crate) fn read(
+    worksheet: &mut Worksheet,
+    table_file: &RawFile,
+    stylesheet: &Stylesheet,
+) -> Result<(), XlsxError> {
+    let differential_formats = stylesheet.differential_formats();
     let data = std::io::Cursor::new(table_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -27,25 +46,12 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
     loop {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Empty(ref e)) => match e.name().into_inner() {
+                b"autoFilter" => {
+                    table.auto_filter_mut().set_attributes(&mut reader, e, true)?;
+                }
                 b"tableColumn" => {
                     table_column = TableColumn::default();
-                    for attr in e.attributes().with_checks(false).flatten() {
-                        match attr.key.0 {
-                            b"name" => {
-                                let attr_val = get_attribute_value(&attr)?;
-                                table_column.set_name(attr_val);
-                            }
-                            b"totalsRowLabel" => {
-                                let attr_val = get_attribute_value(&attr)?;
-                                table_column.set_totals_row_label_str(&attr_val);
-                            }
-                            b"totalsRowFunction" => {
-                                let attr_val = get_attribute_value(&attr)?;
-                                table_column.set_totals_row_function_str(&attr_val);
-                            }
-                            _ => {}
-                        }
-                    }
+                    read_table_column(&mut table_column, e, differential_formats)?;
                     // add column to table (if it has a name)
                     if !table_column.name().is_empty() {
                         table.add_column(table_column);
@@ -110,7 +116,9 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
             Ok(Event::Start(ref e)) => match e.name().into_inner() {
                 b"calculatedColumnFormula" => {
                     let mut buf = Vec::new();
-                    let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
                     table_column.set_calculated_column_formula(
                         crate::helper::utils::unescape_xml_text(&text),
                     );
@@ -128,7 +136,15 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
                             b"ref" => {
                                 let area_coords: Vec<&str> = attr_val.split(':').collect();
                                 if area_coords.len() == 2 {
-                                    table.set_area((area_coords[0], area_coords[1]));
+                                    let (Some(start), Some(end)) = (
+                                        CellCoordinates::parse(area_coords[0]),
+                                        CellCoordinates::parse(area_coords[1]),
+                                    ) else {
+                                        return Err(invalid_attribute(
+                                            &reader, e, "ref", &attr_val,
+                                        ));
+                                    };
+                                    table.set_area((start, end));
                                 }
                             }
                             b"totalsRowShown" => {
@@ -137,30 +153,40 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
                             b"totalsRowCount" => {
                                 table.set_totals_row_count_str(&attr_val);
                             }
-                            _ => {}
-                        }
-                    }
-                }
-                b"tableColumn" => {
-                    table_column = TableColumn::default();
-                    for attr in e.attributes().with_checks(false).flatten() {
-                        match attr.key.0 {
-                            b"name" => {
-                                let attr_val = get_attribute_value(&attr)?;
-                                table_column.set_name(attr_val);
+                            b"headerRowDxfId" => {
+                                if let Some(v) = dxf_style(&attr_val, differential_formats) {
+                                    table.set_header_row_style(v);
+                                }
                             }
-                            b"totalsRowLabel" => {
-                                let attr_val = get_attribute_value(&attr)?;
-                                table_column.set_totals_row_label_str(&attr_val);
+                            b"dataDxfId" => {
+                                if let Some(v) = dxf_style(&attr_val, differential_formats) {
+                                    table.set_data_style(v);
+                                }
                             }
-                            b"totalsRowFunction" => {
-                                let attr_val = get_attribute_value(&attr)?;
-                                table_column.set_totals_row_function_str(&attr_val);
+                            b"totalsRowDxfId" => {
+                                if let Some(v) = dxf_style(&attr_val, differential_formats) {
+                                    table.set_totals_row_style(v);
+                                }
                             }
                             _ => {}
                         }
                     }
                 }
+                b"tableColumn" => {
+                    table_column = TableColumn::default();
+                    read_table_column(&mut table_column, e, differential_formats)?;
+                }
+                b"totalsRowFormula" => {
+                    let mut buf = Vec::new();
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    table_column
+                        .set_totals_row_formula(crate::helper::utils::unescape_xml_text(&text));
+                }
+                b"autoFilter" => {
+                    table.auto_filter_mut().set_attributes(&mut reader, e, false)?;
+                }
                 _ => (),
             },
             Ok(Event::End(ref e)) => {
@@ -173,7 +199,7 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
             _ => (),
         }
         buf.clear();
@@ -184,3 +210,49 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
     }
     Ok(())
 }
+
+fn read_table_column(
+    table_column: &mut TableColumn,
+    e: &BytesStart,
+    differential_formats: &DifferentialFormats,
+) -> Result<(), XlsxError> {
+    for attr in e.attributes().with_checks(false).flatten() {
+        let attr_val = get_attribute_value(&attr)?;
+        match attr.key.0 {
+            b"name" => {
+                table_column.set_name(attr_val);
+            }
+            b"totalsRowLabel" => {
+                table_column.set_totals_row_label_str(&attr_val);
+            }
+            b"totalsRowFunction" => {
+                table_column.set_totals_row_function_str(&attr_val);
+            }
+            b"headerRowDxfId" => {
+                if let Some(v) = dxf_style(&attr_val, differential_formats) {
+                    table_column.set_header_row_style(v);
+                }
+            }
+            b"dataDxfId" => {
+                if let Some(v) = dxf_style(&attr_val, differential_formats) {
+                    table_column.set_data_style(v);
+                }
+            }
+            b"totalsRowDxfId" => {
+                if let Some(v) = dxf_style(&attr_val, differential_formats) {
+                    table_column.set_totals_row_style(v);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn dxf_style(value: &str, differential_formats: &DifferentialFormats) -> Option<Style> {
+    let id = value.parse::<usize>().ok()?;
+    differential_formats
+        .differential_format()
+        .get(id)
+        .map(DifferentialFormat::style)
+}
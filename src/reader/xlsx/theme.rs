@@ -25,7 +25,7 @@ pub fn read<R: io::Read + io::Seek>(
         reader,
         Event::Start(ref e) => {
             if e.name().into_inner() == b"a:theme" {
-                theme.set_attributes(&mut reader, e);
+                theme.set_attributes(&mut reader, e)?;
             }
         },
         Event::Eof => break,
@@ -1,11 +1,19 @@
 use quick_xml::{
     Reader,
-    events::Event,
+    events::{
+        BytesStart,
+        Event,
+    },
 };
 
 use crate::{
     OleObject,
+    XlsxError,
+    reader::driver::get_attribute,
     structs::{
+        FormControl,
+        FormControlTypeValues,
+        HeaderFooterImage,
         Worksheet,
         raw::{
             RawFile,
@@ -20,7 +28,7 @@ pub(crate) fn read(
     worksheet: &mut Worksheet,
     drawing_file: &RawFile,
     drawing_relationships: Option<&RawRelationships>,
-) {
+) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(drawing_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
@@ -32,14 +40,50 @@ pub(crate) fn read(
         reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"v:shape" {
+                    let shape_id = shape_id(e);
                     let mut obj = Shape::default();
-                    obj.set_attributes(&mut reader, e, drawing_relationships);
-                    if obj.client_data().comment_column_target().is_some() {
+                    obj.set_attributes(&mut reader, e, drawing_relationships)?;
+                    let controls = worksheet.controls_mut();
+                    if let Some(id) = get_attribute(e, b"id")
+                        .filter(|v| HeaderFooterImage::is_header_footer_id(v))
+                    {
+                        // picture of the header or footer (legacyDrawingHF)
+                        let mut image = HeaderFooterImage::default();
+                        image.set_id(id).set_style(obj.style());
+                        if let Some(image_data) = obj.image_data() {
+                            if let Some(v) = image_data.image() {
+                                let mut media_object = v.clone();
+                                media_object.set_image_title(image_data.title());
+                                image.set_image(media_object);
+                            }
+                        }
+                        worksheet.header_footer_mut().add_image_crate(image);
+                    } else if let Some(control) = controls
+                        .form_control_mut()
+                        .iter_mut()
+                        .find(|v| shape_id.is_some_and(|id| v.shape_id() == id))
+                    {
+                        control.set_vml_shape(obj, false);
+                    } else if let Some(control) = controls
+                        .active_x_control_mut()
+                        .iter_mut()
+                        .find(|v| shape_id.is_some_and(|id| v.shape_id() == id))
+                    {
+                        control.set_shape(obj);
+                    } else if obj.client_data().comment_column_target().is_some() {
                         worksheet
                             .comments_mut()
                             .get_mut(comment_index)
                             .map(|comment| comment.set_shape(obj));
                         comment_index += 1;
+                    } else if let Some(object_type) =
+                        FormControlTypeValues::from_vml_object_type(obj.client_data().object_type())
+                    {
+                        // A form control stored in the VML drawing only.
+                        let mut control = FormControl::default();
+                        control.set_object_type(object_type);
+                        control.set_vml_shape(obj, true);
+                        controls.add_form_control(control);
                     } else {
                         let mut ole = OleObject::default();
                         ole.set_shape(obj);
@@ -53,6 +97,14 @@ pub(crate) fn read(
             },
             Event::Eof => break,
     );
+    Ok(())
+}
+
+// Shape id from "o:spid" (or "id"). ex) "_x0000_s1025" -> 1025
+fn shape_id(e: &BytesStart) -> Option<u32> {
+    get_attribute(e, b"o:spid")
+        .or_else(|| get_attribute(e, b"id"))
+        .and_then(|v| v.strip_prefix("_x0000_s")?.parse::<u32>().ok())
 }
 
 // fn set_style(comment:&mut Comment, style_string:&str) {
@@ -7,7 +7,7 @@ use quick_xml::{
 
 use super::{
     XlsxError,
-    driver::get_attribute,
+    driver::required_attribute,
 };
 use crate::{
     helper::const_str::PKG_WORKBOOK,
@@ -48,17 +48,17 @@ pub(crate) fn read<R: io::Read + io::Seek>(
                 }
                 b"sheet" => {
                     let mut worksheet = Worksheet::default();
-                    worksheet.set_attributes_from_wookbook(&mut reader, e, is_empty);
-                    wb.add_sheet(worksheet).unwrap();
+                    worksheet.set_attributes_from_wookbook(&mut reader, e, is_empty)?;
+                    wb.add_sheet(worksheet)?;
                 }
                 b"pivotCache" => {
-                    let cache_id = get_attribute(e, b"cacheId").unwrap();
-                    let r_id = get_attribute(e, b"r:id").unwrap();
+                    let cache_id = required_attribute(&reader, e, "cacheId")?;
+                    let r_id = required_attribute(&reader, e, "r:id")?;
                     wb.add_pivot_caches((r_id, cache_id, String::new()));
                 }
                 b"definedName" => {
                     let mut obj = DefinedName::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e)?;
                     defined_names.push(obj);
                 }
                 _ => (),
@@ -70,8 +70,7 @@ pub(crate) fn read<R: io::Read + io::Seek>(
     for defined_name in &defined_names {
         if defined_name.has_local_sheet_id() {
             let local_sheet_id = defined_name.local_sheet_id() as usize;
-            wb.sheet_mut(local_sheet_id)
-                .unwrap()
+            wb.sheet_mut(local_sheet_id)?
                 .add_defined_names(defined_name.clone());
         } else {
             if let Some(v) = defined_name.address_obj().first() {
@@ -8,14 +8,17 @@ use quick_xml::{
 use super::{
     XlsxError,
     driver::{
-        get_attribute,
+        required_attribute,
         xml_read_loop,
     },
 };
 use crate::{
-    helper::const_str::{
-        PIVOT_CACHE_DEF_NS,
-        PKG_WORKBOOK_RELS,
+    helper::{
+        const_str::{
+            PIVOT_CACHE_DEF_NS,
+            PKG_WORKBOOK_RELS,
+        },
+        strict,
     },
     structs::Workbook,
 };
@@ -34,9 +37,10 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         reader,
         Event::Empty(ref e) => {
             if e.name().into_inner() == b"Relationship" {
-                let id_value = get_attribute(e, b"Id").unwrap();
-                let type_value = get_attribute(e, b"Type").unwrap();
-                let target_value = get_attribute(e, b"Target").unwrap();
+                let id_value = required_attribute(&reader, e, "Id")?;
+                let type_value =
+                    strict::to_transitional(&required_attribute(&reader, e, "Type")?);
+                let target_value = required_attribute(&reader, e, "Target")?;
                 let target_value = target_value
                     .strip_prefix("/xl/")
                     .map(ToOwned::to_owned)
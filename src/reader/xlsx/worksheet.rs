@@ -7,7 +7,10 @@ use std::{
 use quick_xml::{
     Reader,
     escape,
-    events::Event,
+    events::{
+        BytesStart,
+        Event,
+    },
 };
 
 use super::{
@@ -15,6 +18,8 @@ use super::{
     driver::{
         get_attribute,
         get_attribute_value,
+        invalid_xml,
+        unexpected_eof,
         xml_read_loop,
         zip_by_name,
     },
@@ -22,6 +27,7 @@ use super::{
 use crate::{
     helper::formula::FormulaToken,
     structs::{
+        AutoFilter,
         Cell,
         Cells,
         Columns,
@@ -29,6 +35,7 @@ use crate::{
         DataValidations,
         Hyperlink,
         OleObjects,
+        ReadOptions,
         Row,
         SharedStringTable,
         SheetProtection,
@@ -47,6 +54,8 @@ pub(crate) fn read(
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
+    options: &ReadOptions,
+    warnings: &mut Vec<XlsxError>,
 ) -> Result<(), XlsxError> {
     if let Some(source_file) = raw_data_of_worksheet.worksheet_file().source_file() {
         if !raw_data_of_worksheet.worksheet_file().has_file_data() {
@@ -63,6 +72,8 @@ pub(crate) fn read(
                 raw_data_of_worksheet,
                 shared_string_table,
                 stylesheet,
+                options,
+                warnings,
             );
         }
     }
@@ -74,6 +85,8 @@ pub(crate) fn read(
         raw_data_of_worksheet,
         shared_string_table,
         stylesheet,
+        options,
+        warnings,
     )
 }
 
@@ -83,194 +96,266 @@ fn read_from_reader<R: io::BufRead>(
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
+    options: &ReadOptions,
+    warnings: &mut Vec<XlsxError>,
 ) -> Result<(), XlsxError> {
+    let part = raw_data_of_worksheet.worksheet_file().file_target();
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
     let mut formula_shared_list: HashMap<u32, (String, Vec<FormulaToken>)> = HashMap::new();
-    xml_read_loop!(
-        reader,
-        Event::Start(ref e) => match e.name().into_inner() {
-            b"sheetPr" => {
-                for a in e.attributes().with_checks(false) {
-                    match a {
-                        Ok(ref attr) if attr.key.0 == b"codeName" => {
-                            worksheet.set_code_name(get_attribute_value(attr)?);
-                        }
-                        Ok(_) | Err(_) => {}
-                    }
-                }
-            }
-            b"sheetViews" => {
-                worksheet
-                    .sheet_views_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            b"sheetFormatPr" => {
-                worksheet
-                    .sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            b"selection" => {
-                for a in e.attributes().with_checks(false) {
-                    match a {
-                        Ok(ref attr) if attr.key.0 == b"activeCell" => {
-                            worksheet.set_active_cell(get_attribute_value(attr)?);
-                        }
-                        Ok(_) | Err(_) => {}
-                    }
-                }
-            }
-            b"row" => {
-                let mut obj = Row::default();
-                obj.set_attributes(
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                let result = read_start_element(
+                    worksheet,
                     &mut reader,
                     e,
-                    worksheet.cells_crate_mut(),
+                    raw_data_of_worksheet,
                     shared_string_table,
                     stylesheet,
                     &mut formula_shared_list,
-                    false,
-                );
-                worksheet.set_row_dimension(obj);
-            }
-            b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
-            }
-            b"cols" => {
-                let mut obj = Columns::default();
-                obj.set_attributes(&mut reader, e, stylesheet);
-                worksheet.set_column_dimensions_crate(obj);
-            }
-            b"mergeCells" => {
-                worksheet
-                    .merge_cells_crate_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            b"conditionalFormatting" => {
-                let mut obj = ConditionalFormatting::default();
-                obj.set_attributes(&mut reader, e, stylesheet.differential_formats());
-                worksheet.add_conditional_formatting_collection(obj);
-            }
-            b"dataValidations" => {
-                let mut obj = DataValidations::default();
-                obj.set_attributes(&mut reader, e);
-                worksheet.set_data_validations(obj);
-            }
-            b"x14:dataValidations" => {
-                let mut obj = DataValidations2010::default();
-                obj.set_attributes(&mut reader, e);
-                worksheet.set_data_validations_2010(obj);
-            }
-            b"oleObjects" => {
-                let mut obj = OleObjects::default();
-                obj.set_attributes(
-                    &mut reader,
-                    e,
-                    raw_data_of_worksheet.worksheet_relationships().unwrap(),
                 );
-                worksheet.set_ole_objects(obj);
-            }
-            b"headerFooter" => {
-                worksheet
-                    .header_footer_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            b"rowBreaks" => {
-                worksheet
-                    .row_breaks_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            b"colBreaks" => {
-                worksheet
-                    .column_breaks_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            _ => (),
-        },
-        Event::Empty(ref e) => match e.name().into_inner() {
-            b"sheetPr" => {
-                for a in e.attributes().with_checks(false) {
-                    match a {
-                        Ok(ref attr) if attr.key.0 == b"codeName" => {
-                            worksheet.set_code_name(get_attribute_value(attr)?);
-                        }
-                        Ok(_) | Err(_) => {}
-                    }
-                }
-            }
-            b"tabColor" => {
-                worksheet
-                    .tab_color_mut()
-                    .set_attributes(&mut reader, e, true);
-            }
-            b"sheetFormatPr" => {
-                worksheet
-                    .sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            b"selection" => {
-                for a in e.attributes().with_checks(false) {
-                    match a {
-                        Ok(ref attr) if attr.key.0 == b"activeCell" => {
-                            worksheet.set_active_cell(get_attribute_value(attr)?);
-                        }
-                        Ok(_) | Err(_) => {}
+                if options
+                    .recover(result.map_err(|v| v.in_part(part)), warnings)?
+                    .is_none()
+                {
+                    // Skip the rest of the element so that the next one can be read.
+                    if let Err(v) = reader.read_to_end_into(e.name(), &mut Vec::new()) {
+                        warnings.push(invalid_xml(reader.error_position(), &v).in_part(part));
+                        break;
                     }
                 }
             }
-            b"row" => {
-                let mut obj = Row::default();
-                obj.set_attributes(
+            Ok(Event::Empty(ref e)) => {
+                let result = read_empty_element(
+                    worksheet,
                     &mut reader,
                     e,
-                    worksheet.cells_crate_mut(),
+                    raw_data_of_worksheet,
                     shared_string_table,
                     stylesheet,
                     &mut formula_shared_list,
-                    true,
                 );
-                worksheet.set_row_dimension(obj);
+                options.recover(result.map_err(|v| v.in_part(part)), warnings)?;
             }
-            b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                let error = invalid_xml(reader.error_position(), &e).in_part(part);
+                options.recover::<()>(Err(error), warnings)?;
+                break;
             }
-            b"pageMargins" => {
-                worksheet
-                    .page_margins_mut()
-                    .set_attributes(&mut reader, e);
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+fn read_start_element<R: io::BufRead>(
+    worksheet: &mut Worksheet,
+    reader: &mut Reader<R>,
+    e: &BytesStart<'_>,
+    raw_data_of_worksheet: &RawWorksheet,
+    shared_string_table: &SharedStringTable,
+    stylesheet: &Stylesheet,
+    formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
+) -> Result<(), XlsxError> {
+    match e.name().into_inner() {
+        b"sheetPr" => {
+            for a in e.attributes().with_checks(false) {
+                match a {
+                    Ok(ref attr) if attr.key.0 == b"codeName" => {
+                        worksheet.set_code_name(get_attribute_value(attr)?);
+                    }
+                    Ok(_) | Err(_) => {}
+                }
             }
-            b"hyperlink" => {
-                let (coor, hyperlink) = get_hyperlink(
-                    e,
-                    raw_data_of_worksheet.worksheet_relationships()
-                );
-                worksheet.cell_mut(coor).set_hyperlink(hyperlink);
+        }
+        b"sheetViews" => {
+            worksheet.sheet_views_mut().set_attributes(reader, e)?;
+        }
+        b"sheetFormatPr" => {
+            worksheet.sheet_format_properties_mut().set_attributes(reader, e);
+        }
+        b"selection" => {
+            for a in e.attributes().with_checks(false) {
+                match a {
+                    Ok(ref attr) if attr.key.0 == b"activeCell" => {
+                        worksheet.set_active_cell(get_attribute_value(attr)?);
+                    }
+                    Ok(_) | Err(_) => {}
+                }
             }
-            b"printOptions" => {
-                worksheet
-                    .print_options_mut()
-                    .set_attributes(&mut reader, e);
+        }
+        b"row" => {
+            let mut obj = Row::default();
+            obj.set_attributes(
+                reader,
+                e,
+                worksheet.cells_crate_mut(),
+                shared_string_table,
+                stylesheet,
+                formula_shared_list,
+                false,
+            )?;
+            worksheet.set_row_dimension(obj);
+        }
+        b"autoFilter" => {
+            let mut obj = AutoFilter::default();
+            obj.set_attributes(reader, e, false)?;
+            worksheet.set_auto_filter_crate(obj);
+        }
+        b"cols" => {
+            let mut obj = Columns::default();
+            obj.set_attributes(reader, e, stylesheet)?;
+            worksheet.set_column_dimensions_crate(obj);
+        }
+        b"mergeCells" => {
+            worksheet.merge_cells_crate_mut().set_attributes(reader, e)?;
+        }
+        b"conditionalFormatting" => {
+            let mut obj = ConditionalFormatting::default();
+            obj.set_attributes(reader, e, stylesheet.differential_formats())?;
+            worksheet.add_conditional_formatting_collection(obj);
+        }
+        b"dataValidations" => {
+            let mut obj = DataValidations::default();
+            obj.set_attributes(reader, e)?;
+            worksheet.set_data_validations(obj);
+        }
+        b"x14:dataValidations" => {
+            let mut obj = DataValidations2010::default();
+            obj.set_attributes(reader, e)?;
+            worksheet.set_data_validations_2010(obj);
+        }
+        b"x14:conditionalFormatting" => {
+            let mut obj = ConditionalFormatting::default();
+            obj.set_x14_attributes(reader, e)?;
+            worksheet.merge_x14_conditional_formatting(obj);
+        }
+        b"oleObjects" => {
+            let mut obj = OleObjects::default();
+            obj.set_attributes(
+                reader,
+                e,
+                raw_data_of_worksheet.required_worksheet_relationships()?,
+            )?;
+            worksheet.set_ole_objects(obj);
+        }
+        b"controls" => {
+            worksheet.controls_mut().set_attributes(reader, e, raw_data_of_worksheet)?;
+        }
+        b"headerFooter" => {
+            worksheet.header_footer_mut().set_attributes(reader, e, false)?;
+        }
+        b"rowBreaks" => {
+            worksheet.row_breaks_mut().set_attributes(reader, e)?;
+        }
+        b"colBreaks" => {
+            worksheet.column_breaks_mut().set_attributes(reader, e)?;
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+fn read_empty_element<R: io::BufRead>(
+    worksheet: &mut Worksheet,
+    reader: &mut Reader<R>,
+    e: &BytesStart<'_>,
+    raw_data_of_worksheet: &RawWorksheet,
+    shared_string_table: &SharedStringTable,
+    stylesheet: &Stylesheet,
+    formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
+) -> Result<(), XlsxError> {
+    match e.name().into_inner() {
+        b"sheetPr" => {
+            for a in e.attributes().with_checks(false) {
+                match a {
+                    Ok(ref attr) if attr.key.0 == b"codeName" => {
+                        worksheet.set_code_name(get_attribute_value(attr)?);
+                    }
+                    Ok(_) | Err(_) => {}
+                }
             }
-            b"pageSetup" => {
-                worksheet.page_setup_mut().set_attributes(
-                    &mut reader,
-                    e,
-                    raw_data_of_worksheet.worksheet_relationships(),
-                );
+        }
+        b"tabColor" => {
+            worksheet.tab_color_mut().set_attributes(reader, e, true)?;
+        }
+        b"pageSetUpPr" => {
+            for a in e.attributes().with_checks(false) {
+                match a {
+                    Ok(ref attr) if attr.key.0 == b"fitToPage" => {
+                        let value = get_attribute_value(attr)?;
+                        worksheet
+                            .page_setup_mut()
+                            .set_fit_to_page(value == "1" || value == "true");
+                    }
+                    Ok(_) | Err(_) => {}
+                }
             }
-            b"sheetProtection" => {
-                let mut obj = SheetProtection::default();
-                obj.set_attributes(
-                    &mut reader,
-                    e,
-                );
-                worksheet.set_sheet_protection(obj);
+        }
+        b"sheetFormatPr" => {
+            worksheet.sheet_format_properties_mut().set_attributes(reader, e);
+        }
+        b"selection" => {
+            for a in e.attributes().with_checks(false) {
+                match a {
+                    Ok(ref attr) if attr.key.0 == b"activeCell" => {
+                        worksheet.set_active_cell(get_attribute_value(attr)?);
+                    }
+                    Ok(_) | Err(_) => {}
+                }
             }
-            _ => (),
-        },
-        Event::Eof => break,
-    );
-
+        }
+        b"row" => {
+            let mut obj = Row::default();
+            obj.set_attributes(
+                reader,
+                e,
+                worksheet.cells_crate_mut(),
+                shared_string_table,
+                stylesheet,
+                formula_shared_list,
+                true,
+            )?;
+            worksheet.set_row_dimension(obj);
+        }
+        b"autoFilter" => {
+            let mut obj = AutoFilter::default();
+            obj.set_attributes(reader, e, true)?;
+            worksheet.set_auto_filter_crate(obj);
+        }
+        b"pageMargins" => {
+            worksheet.page_margins_mut().set_attributes(reader, e)?;
+        }
+        b"hyperlink" => {
+            let (coor, hyperlink) =
+                get_hyperlink(e, raw_data_of_worksheet.worksheet_relationships())?;
+            worksheet.cell_mut(coor).set_hyperlink(hyperlink);
+        }
+        b"printOptions" => {
+            worksheet.print_options_mut().set_attributes(reader, e);
+        }
+        b"headerFooter" => {
+            worksheet.header_footer_mut().set_attributes(reader, e, true)?;
+        }
+        b"pageSetup" => {
+            worksheet.page_setup_mut().set_attributes(
+                reader,
+                e,
+                raw_data_of_worksheet.worksheet_relationships(),
+            )?;
+        }
+        b"sheetProtection" => {
+            let mut obj = SheetProtection::default();
+            obj.set_attributes(reader, e);
+            worksheet.set_sheet_protection(obj);
+        }
+        _ => (),
+    }
     Ok(())
 }
 
@@ -278,16 +363,15 @@ pub(crate) fn read_lite(
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
-) -> Cells {
+) -> Result<Cells, XlsxError> {
     if let Some(source_file) = raw_data_of_worksheet.worksheet_file().source_file() {
         if !raw_data_of_worksheet.worksheet_file().has_file_data() {
-            let file = File::open(source_file).unwrap();
-            let mut archive = zip::read::ZipArchive::new(file).unwrap();
+            let file = File::open(source_file)?;
+            let mut archive = zip::read::ZipArchive::new(file)?;
             let source = zip_by_name(
                 &mut archive,
                 raw_data_of_worksheet.worksheet_file().file_target(),
-            )
-            .unwrap();
+            )?;
             let reader = io::BufReader::new(source);
             return read_lite_from_reader(reader, shared_string_table, stylesheet);
         }
@@ -301,7 +385,7 @@ fn read_lite_from_reader<R: io::BufRead>(
     data: R,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
-) -> Cells {
+) -> Result<Cells, XlsxError> {
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
 
@@ -320,7 +404,7 @@ fn read_lite_from_reader<R: io::BufRead>(
                     stylesheet,
                     &mut formula_shared_list,
                     false,
-                );
+                )?;
             }
         },
         Event::Empty(ref e) => {
@@ -334,13 +418,13 @@ fn read_lite_from_reader<R: io::BufRead>(
                     stylesheet,
                     &mut formula_shared_list,
                     true,
-                );
+                )?;
             }
         },
         Event::Eof => break,
     );
 
-    cells
+    Ok(cells)
 }
 
 pub(crate) fn read_cells_stream<F>(
@@ -361,13 +445,13 @@ where
                 raw_data_of_worksheet.worksheet_file().file_target(),
             )?;
             let reader = io::BufReader::new(source);
-            read_cells_stream_from_reader(reader, shared_string_table, stylesheet, callback);
+            read_cells_stream_from_reader(reader, shared_string_table, stylesheet, callback)?;
             return Ok(());
         }
     }
 
     let data = io::Cursor::new(raw_data_of_worksheet.worksheet_file().file_data());
-    read_cells_stream_from_reader(data, shared_string_table, stylesheet, callback);
+    read_cells_stream_from_reader(data, shared_string_table, stylesheet, callback)?;
     Ok(())
 }
 
@@ -376,7 +460,7 @@ fn read_cells_stream_from_reader<R, F>(
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
     mut callback: F,
-) where
+) -> Result<(), XlsxError> where
     R: io::BufRead,
     F: FnMut(&Cell),
 {
@@ -394,20 +478,21 @@ fn read_cells_stream_from_reader<R, F>(
                     stylesheet,
                     &mut formula_shared_list,
                     &mut callback,
-                );
+                )?;
             }
         },
         Event::Eof => break,
     );
+    Ok(())
 }
 
-fn read_row_cells_stream<R, F>(
+pub(crate) fn read_row_cells_stream<R, F>(
     reader: &mut Reader<R>,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
     formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
     callback: &mut F,
-) where
+) -> Result<(), XlsxError> where
     R: io::BufRead,
     F: FnMut(&Cell),
 {
@@ -423,7 +508,7 @@ fn read_row_cells_stream<R, F>(
                     stylesheet,
                     true,
                     formula_shared_list,
-                );
+                )?;
                 callback(&obj);
             }
         },
@@ -437,36 +522,40 @@ fn read_row_cells_stream<R, F>(
                     stylesheet,
                     false,
                     formula_shared_list,
-                );
+                )?;
                 callback(&obj);
             }
         },
         Event::End(ref e) => {
             if e.name().into_inner() == b"row" {
-                return
+                return Ok(())
             }
         },
-        Event::Eof => panic!("Error: Could not find {} end element", "row")
+        Event::Eof => return Err(unexpected_eof(reader, "row"))
     );
 }
 
 fn get_hyperlink(
-    e: &quick_xml::events::BytesStart<'_>,
+    e: &BytesStart<'_>,
     raw_relationships: Option<&RawRelationships>,
-) -> (String, Hyperlink) {
+) -> Result<(String, Hyperlink), XlsxError> {
     let mut hyperlink = Hyperlink::default();
 
     let coordition = get_attribute(e, b"ref").unwrap_or_default();
     if let Some(v) = get_attribute(e, b"location") {
-        hyperlink.set_url(escape::unescape(&v).unwrap().to_string());
+        hyperlink.set_url(unescape_attribute(&v));
         hyperlink.set_location(true);
     }
     if let Some(v) = get_attribute(e, b"tooltip") {
-        hyperlink.set_tooltip(escape::unescape(&v).unwrap().to_string());
+        hyperlink.set_tooltip(unescape_attribute(&v));
     }
     if let Some(v) = get_attribute(e, b"r:id") {
-        let relationship = raw_relationships.unwrap().relationship_by_rid(&v);
-        hyperlink.set_url(escape::unescape(relationship.target()).unwrap().to_string());
+        let relationship = RawRelationships::find_by_rid(raw_relationships, &v)?;
+        hyperlink.set_url(unescape_attribute(relationship.target()));
     }
-    (coordition, hyperlink)
+    Ok((coordition, hyperlink))
+}
+
+fn unescape_attribute(value: &str) -> String {
+    escape::unescape(value).map_or_else(|_| value.to_string(), |v| v.to_string())
 }
@@ -36,13 +36,14 @@ pub_mod_use![
     pub(crate) merge_cells,
     pub(crate) mru_colors,
     pub(crate) numbering_formats,
-    pub(crate) phonetic_run,
     pub(crate) rows,
     pub(crate) shared_string_item,
     pub(crate) shared_string_table,
     pub(crate) stylesheet,
+    pub(crate) table_styles,
     pub(crate) text,
 
+    pub active_x_control,
     pub address,
     pub attributes,
     pub alignment,
@@ -64,27 +65,42 @@ pub_mod_use![
     pub cell_value,
     pub cell,
     pub cells,
+    pub chart_builder,
+    pub chart_style,
     pub chart_type,
     pub chart,
+    pub chartsheet,
+    pub chartsheet_view,
+    pub chartsheet_views,
+    pub checked_values,
     pub color_scale,
     pub color,
     pub column_breaks,
     pub column_fields,
     pub column_items,
     pub column_reference,
+    pub column_spec,
     pub column,
     pub comment,
     pub comment_text,
+    pub conditional_format_match,
     pub conditional_format_value_object_values,
     pub conditional_format_value_object,
     pub conditional_format_values,
+    pub conditional_formatting_icon,
     pub conditional_formatting_operator_values,
     pub conditional_formatting_rule,
     pub conditional_formatting,
+    pub control_properties,
+    pub controls,
     pub coordinate,
     pub csv_encode_values,
     pub csv_writer_option,
+    pub custom_filter,
+    pub custom_filters,
     pub data_bar,
+    pub data_bar_axis_position_values,
+    pub data_bar_direction_values,
     pub data_field,
     pub data_fields,
     pub date_time_value,
@@ -93,13 +109,24 @@ pub_mod_use![
     pub data_validation,
     pub data_validations,
     pub defined_name,
+    pub defined_name_value,
+    pub defined_names,
     pub double_value,
+    pub dynamic_filter,
+    pub dynamic_filter_values,
     pub embedded_object_properties,
     pub enum_trait,
     pub enum_value,
     pub error,
+    pub even_footer,
+    pub even_header,
     pub field,
     pub fill,
+    pub filter_column,
+    pub filter_operator_values,
+    pub filters,
+    pub first_footer,
+    pub first_header,
     pub font_char_set,
     pub font_family_numbering,
     pub font_name,
@@ -110,13 +137,21 @@ pub_mod_use![
     pub formula,
     pub formula1,
     pub formula2,
+    pub form_control,
+    pub form_control_type_values,
     pub from_marker,
     pub gradient_fill,
     pub gradient_stop,
     pub header_footer,
+    pub header_footer_field,
+    pub header_footer_image,
+    pub header_footer_image_section,
+    pub header_footer_position,
+    pub header_footer_section,
     pub horizontal_alignment_values,
     pub hyperlink,
     pub icon_set,
+    pub icon_set_values,
     pub image,
     pub int16_value,
     pub int32_value,
@@ -136,11 +171,13 @@ pub_mod_use![
     pub orientation_values,
     pub page_margins,
     pub page_setup,
+    pub paper_size,
     pub pane_state_values,
     pub pane_values,
     pub pane,
     pub pattern_fill,
     pub pattern_values,
+    pub phonetic_run,
     pub pivot_cache_definition,
     pub pivot_field,
     pub pivot_fields,
@@ -153,13 +190,19 @@ pub_mod_use![
     pub protection,
     pub r#break,
     pub range,
+    pub read_options,
+    pub record_column,
+    pub records_option,
     pub rich_text,
+    pub rich_text_builder,
     pub row_breaks,
     pub row_fields,
     pub row_item,
     pub row_items,
+    pub row_mapping,
     pub row_reference,
     pub row,
+    pub rule,
     pub s_byte_value,
     pub selection,
     pub sequence_of_references,
@@ -175,9 +218,13 @@ pub_mod_use![
     pub string_value,
     pub style,
     pub table,
+    pub table_style,
+    pub table_style_element,
+    pub table_style_element_values,
     pub text_element,
     pub time_period_values,
     pub to_marker,
+    pub top10,
     pub totals_row_function_values,
     pub true_false_blank_value,
     pub true_false_value,
@@ -185,6 +232,7 @@ pub_mod_use![
     pub u_int32_value,
     pub underline_values,
     pub underline,
+    pub validation_result,
     pub vertical_alignment_run_values,
     pub vertical_alignment_values,
     pub vertical_text_alignment,
@@ -0,0 +1,236 @@
+// control (ActiveX control)
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    ControlProperties,
+    StringValue,
+};
+use crate::{
+    XlsxError,
+    helper::const_str::MC_NS,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    structs::{
+        raw::RawRelationships,
+        vml::Shape,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// An `ActiveX` Control Object.
+/// The control part and its binary persistence data are kept as they were
+/// read, so the control survives a read/write cycle unchanged.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActiveXControl {
+    name:                   StringValue,
+    control_data:           Vec<u8>,
+    binary_data:            Option<Vec<u8>>,
+    binary_relationship_id: StringValue,
+    control_properties:     ControlProperties,
+    shape:                  Shape,
+    shape_id:               u32,
+}
+
+impl ActiveXControl {
+    /// Get the name of the control. ex) `"CommandButton1"`
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    /// Get the control part (`xl/activeX/activeX*.xml`).
+    #[inline]
+    #[must_use]
+    pub fn control_data(&self) -> &[u8] {
+        &self.control_data
+    }
+
+    #[inline]
+    pub fn set_control_data(&mut self, value: impl Into<Vec<u8>>) -> &mut Self {
+        self.control_data = value.into();
+        self
+    }
+
+    /// Get the binary persistence data (`xl/activeX/activeX*.bin`).
+    #[inline]
+    #[must_use]
+    pub fn binary_data(&self) -> Option<&[u8]> {
+        self.binary_data.as_deref()
+    }
+
+    /// Set the binary persistence data.
+    /// # Arguments
+    /// * `value` - Binary data.
+    /// * `relationship_id` - Relationship id the control part refers to the
+    ///   data with. ex) "rId1"
+    #[inline]
+    pub fn set_binary_data<S: Into<String>>(
+        &mut self,
+        value: impl Into<Vec<u8>>,
+        relationship_id: S,
+    ) -> &mut Self {
+        self.binary_data = Some(value.into());
+        self.binary_relationship_id.set_value(relationship_id);
+        self
+    }
+
+    #[inline]
+    pub fn remove_binary_data(&mut self) -> &mut Self {
+        self.binary_data = None;
+        self.binary_relationship_id.remove_value();
+        self
+    }
+
+    #[inline]
+    pub(crate) fn binary_relationship_id(&self) -> &str {
+        self.binary_relationship_id.value_str()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn control_properties(&self) -> &ControlProperties {
+        &self.control_properties
+    }
+
+    #[inline]
+    pub fn control_properties_mut(&mut self) -> &mut ControlProperties {
+        &mut self.control_properties
+    }
+
+    #[inline]
+    pub fn set_control_properties(&mut self, value: ControlProperties) -> &mut Self {
+        self.control_properties = value;
+        self
+    }
+
+    /// Get the VML shape drawn for the control.
+    #[inline]
+    #[must_use]
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    #[inline]
+    pub fn shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    #[inline]
+    pub fn set_shape(&mut self, value: Shape) -> &mut Self {
+        self.shape = value;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn shape_id(&self) -> u32 {
+        self.shape_id
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        relationships: &RawRelationships,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        if let Some(v) = get_attribute(e, b"shapeId") {
+            self.shape_id = v.parse::<u32>().unwrap_or_default();
+        }
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"controlPr" {
+                    self.control_properties.set_attributes(reader, e, relationships, false)?;
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"controlPr" {
+                    self.control_properties.set_attributes(reader, e, relationships, true)?;
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"control" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "control"))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        r_id: usize,
+        shape_id: usize,
+    ) {
+        // mc:AlternateContent
+        write_start_tag(
+            writer,
+            "mc:AlternateContent",
+            vec![("xmlns:mc", MC_NS).into()],
+            false,
+        );
+
+        // mc:Choice
+        write_start_tag(writer, "mc:Choice", vec![("Requires", "x14").into()], false);
+
+        // control
+        let shape_id_str = shape_id.to_string();
+        let r_id_str = format!("rId{r_id}");
+        let attributes = || -> crate::structs::AttrCollection {
+            vec![
+                ("shapeId", shape_id_str.as_str()).into(),
+                ("r:id", r_id_str.as_str()).into(),
+                ("name", self.name.value_str()).into(),
+            ]
+        };
+        write_start_tag(writer, "control", attributes(), false);
+
+        // controlPr
+        self.control_properties.write_to(writer, r_id + 1);
+
+        write_end_tag(writer, "control");
+
+        write_end_tag(writer, "mc:Choice");
+
+        // mc:Fallback
+        write_start_tag(writer, "mc:Fallback", vec![], false);
+
+        // control
+        write_start_tag(writer, "control", attributes(), true);
+
+        write_end_tag(writer, "mc:Fallback");
+
+        write_end_tag(writer, "mc:AlternateContent");
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Address {
     sheet_name: Box<str>,
     range:      Range,
@@ -23,6 +23,7 @@ use crate::{
 };
 
 #[derive(Default, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Alignment {
     horizontal:    EnumValue<HorizontalAlignmentValues>,
     vertical:      EnumValue<VerticalAlignmentValues>,
@@ -1,4 +1,5 @@
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Anchor {
     left_column:   u32,
     left_offset:   u32,
@@ -1,9 +1,38 @@
-use super::Range;
-use crate::traits::AdjustmentCoordinate;
+// autoFilter
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    FilterColumn,
+    Range,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    traits::AdjustmentCoordinate,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AutoFilter {
-    range: Range,
+    range:         Range,
+    filter_column: Vec<FilterColumn>,
 }
 
 impl AutoFilter {
@@ -37,6 +66,84 @@ impl AutoFilter {
         range.set_range(value.into());
         self.range = range;
     }
+
+    #[inline]
+    #[must_use]
+    pub fn filter_column(&self) -> &[FilterColumn] {
+        &self.filter_column
+    }
+
+    #[inline]
+    pub fn filter_column_mut(&mut self) -> &mut Vec<FilterColumn> {
+        &mut self.filter_column
+    }
+
+    /// Add the criteria of a column.
+    /// Criteria for the same column are replaced.
+    #[inline]
+    pub fn add_filter_column(&mut self, value: FilterColumn) -> &mut Self {
+        self.filter_column.retain(|v| v.col_id() != value.col_id());
+        self.filter_column.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.set_range(v);
+        }
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    let mut obj = FilterColumn::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.filter_column.push(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    let mut obj = FilterColumn::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.filter_column.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"autoFilter" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "autoFilter"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // autoFilter
+        write_start_tag(
+            writer,
+            "autoFilter",
+            vec![("ref", &self.range.range()).into()],
+            self.filter_column.is_empty(),
+        );
+
+        if !self.filter_column.is_empty() {
+            // filterColumn
+            for filter_column in &self.filter_column {
+                filter_column.write_to(writer);
+            }
+
+            write_end_tag(writer, "autoFilter");
+        }
+    }
 }
 impl AdjustmentCoordinate for AutoFilter {
     #[inline]
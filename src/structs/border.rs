@@ -16,9 +16,11 @@ use super::{
     EnumValue,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -28,6 +30,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Border {
     color: Option<Box<Color>>,
     style: EnumValue<BorderStyleValues>,
@@ -130,18 +133,18 @@ impl Border {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, style, "style");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"color" {
-                    self.color.clone().unwrap_or_default().set_attributes(reader, e, true);
+                    self.color.clone().unwrap_or_default().set_attributes(reader, e, true)?;
                 }
             },
             Event::End(ref e) => {
@@ -152,14 +155,11 @@ impl Border {
                     b"bottom"   |
                     b"diagonal" |
                     b"vertical" |
-                    b"horizontal" => return,
+                    b"horizontal" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "left,right,top,bottom,diagonal,vertical,horizontal"
-            )
+            Event::Eof => return Err(unexpected_eof(reader, "left,right,top,bottom,diagonal,vertical,horizontal"))
         );
     }
 
@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use super::EnumTrait;
 #[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BorderStyleValues {
     DashDot,
     DashDotDot,
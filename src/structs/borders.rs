@@ -15,9 +15,11 @@ use super::{
     Border,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -37,6 +39,7 @@ pub(crate) enum BordersIndex {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Borders {
     data:          Box<[Border; 7]>,
     diagonal_down: BooleanValue,
@@ -537,12 +540,12 @@ impl Borders {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, diagonal_up, "diagonalUp");
         set_string_from_xml!(self, e, diagonal_down, "diagonalDown");
         
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -551,35 +554,35 @@ impl Borders {
                 let is_empty = matches!(n, Event::Empty(_));
                 match e.name().into_inner() {
                     b"left" => {
-                        self.data[BordersIndex::Left as usize].set_attributes(reader, e, is_empty);
+                        self.data[BordersIndex::Left as usize].set_attributes(reader, e, is_empty)?;
                     }
                     b"right" => {
-                        self.data[BordersIndex::Right as usize].set_attributes(reader, e, is_empty);
+                        self.data[BordersIndex::Right as usize].set_attributes(reader, e, is_empty)?;
                     }
                     b"top" => {
-                        self.data[BordersIndex::Top as usize].set_attributes(reader, e, is_empty);
+                        self.data[BordersIndex::Top as usize].set_attributes(reader, e, is_empty)?;
                     }
                     b"bottom" => {
-                        self.data[BordersIndex::Bottom as usize].set_attributes(reader, e, is_empty);
+                        self.data[BordersIndex::Bottom as usize].set_attributes(reader, e, is_empty)?;
                     }
                     b"diagonal" => {
-                        self.data[BordersIndex::Diagonal as usize].set_attributes(reader, e, is_empty);
+                        self.data[BordersIndex::Diagonal as usize].set_attributes(reader, e, is_empty)?;
                     }
                     b"vertical" => {
-                        self.data[BordersIndex::Vertical as usize].set_attributes(reader, e, is_empty);
+                        self.data[BordersIndex::Vertical as usize].set_attributes(reader, e, is_empty)?;
                     }
                     b"horizontal" => {
-                        self.data[BordersIndex::Horizontal as usize].set_attributes(reader, e, is_empty);
+                        self.data[BordersIndex::Horizontal as usize].set_attributes(reader, e, is_empty)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"border" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "border")
+            Event::Eof => return Err(unexpected_eof(reader, "border"))
         );
     }
 
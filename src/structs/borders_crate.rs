@@ -15,7 +15,11 @@ use super::{
     Style,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct BordersCrate {
     borders: Vec<Borders>,
 }
@@ -80,23 +85,23 @@ impl BordersCrate {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"border" {
                     let mut obj = Borders::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_borders(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"borders" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "borders")
+            Event::Eof => return Err(unexpected_eof(reader, "borders"))
         );
     }
 
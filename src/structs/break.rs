@@ -20,6 +20,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Break {
     id:                UInt32Value,
     max:               UInt32Value,
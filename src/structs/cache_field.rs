@@ -11,9 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -28,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CacheField {
     name:             StringValue,
     number_format_id: UInt32Value,
@@ -117,7 +120,7 @@ impl CacheField {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, number_format_id, "numFmtId");
 
@@ -127,16 +130,16 @@ impl CacheField {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"sharedItems" {
                     let mut obj = SharedItems::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_shared_items(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheField" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheField")
+            Event::Eof => return Err(unexpected_eof(reader, "cacheField"))
         );
     }
 
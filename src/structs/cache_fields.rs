@@ -10,7 +10,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::CacheField,
     writer::driver::{
         write_end_tag,
@@ -19,6 +23,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CacheFields {
     list: Vec<CacheField>,
 }
@@ -63,22 +68,22 @@ impl CacheFields {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"cacheField" {
                     let mut obj = CacheField::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheFields")
+            Event::Eof => return Err(unexpected_eof(reader, "cacheFields"))
         );
     }
 
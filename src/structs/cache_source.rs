@@ -11,9 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -28,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CacheSource {
     r#type:           EnumValue<SourceValues>,
     worksheet_source: Option<WorksheetSource>,
@@ -107,11 +110,11 @@ impl CacheSource {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -125,10 +128,10 @@ impl CacheSource {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheSource" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheSource")
+            Event::Eof => return Err(unexpected_eof(reader, "cacheSource"))
         );
     }
 
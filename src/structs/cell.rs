@@ -15,8 +15,10 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::{
         coordinate::CellCoordinates,
+        date::iso_8601_to_excel,
         formula::{
             FormulaToken,
             adjustment_formula_coordinate,
@@ -26,8 +28,11 @@ use crate::{
         number_format::to_formatted_string,
     },
     reader::driver::{
+        check_coordinate,
         get_attribute,
+        invalid_xml,
         set_string_from_xml,
+        unexpected_eof,
     },
     structs::{
         CellFormula,
@@ -57,6 +62,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell {
     coordinate:            Coordinate,
     pub(crate) cell_value: Box<CellValue>,
@@ -125,6 +131,28 @@ impl Cell {
         self
     }
 
+    /// Apply a named cell style of the workbook to the cell.
+    /// # Arguments
+    /// * `name` - Name of the style. ex) `"Good"`, `"Heading 1"`
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut book = new_file();
+    /// let mut style = Style::default();
+    /// style.font_mut().set_bold(true);
+    /// book.add_named_style("Brand Heading", &style);
+    /// book.sheet_mut(0)
+    ///     .unwrap()
+    ///     .cell_mut("A1")
+    ///     .set_named_style("Brand Heading");
+    /// ```
+    #[inline]
+    pub fn set_named_style<S: Into<String>>(&mut self, name: S) -> &mut Self {
+        self.style.set_named_style(name);
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn coordinate(&self) -> &Coordinate {
@@ -575,12 +603,12 @@ impl Cell {
         stylesheet: &Stylesheet,
         empty_flag: bool,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut type_value: String = String::new();
         let mut cell_reference: String = String::new();
 
         if let Some(v) = get_attribute(e, b"r") {
-            cell_reference = v;
+            cell_reference = check_coordinate(reader, e, "r", v)?;
             self.coordinate.set_coordinate(&cell_reference);
         }
 
@@ -598,7 +626,7 @@ impl Cell {
         set_string_from_xml!(self, e, cell_meta_index, "cm");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
@@ -607,7 +635,7 @@ impl Cell {
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"f" => {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                     b"t" => {
@@ -619,14 +647,20 @@ impl Cell {
                             }
                         }
                         let mut buf = Vec::new();
-                        let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                        let text = reader
+                            .read_text_into(e.name(), &mut buf)
+                            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                        let text = crate::helper::utils::unescape_xml_text(&text);
                         self.set_value_string_crate(text);
                         reader.config_mut().trim_text(true);
 
                     }
                     b"v" => {
                         let mut buf = Vec::new();
-                        let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                        let text = reader
+                            .read_text_into(e.name(), &mut buf)
+                            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                        let text = crate::helper::utils::unescape_xml_text(&text);
                         match type_value.as_str() {
                             "str" => {
                                 self.set_value_string_crate(text);
@@ -650,6 +684,15 @@ impl Cell {
                             "" | "n" => {
                                 self.set_value_crate(text);
                             }
+                            "d" => match iso_8601_to_excel(&text) {
+                                Some(v) => {
+                                    self.set_value_crate(v.to_string());
+                                    self.set_date_format_if_general(v);
+                                }
+                                None => {
+                                    self.set_value_string_crate(text);
+                                }
+                            },
                             _ => {}
                         }
                     }
@@ -658,19 +701,41 @@ impl Cell {
                 Ok(Event::Empty(ref e)) => {
                     if e.name().into_inner() == b"f" {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                 }
-                Ok(Event::End(ref e)) => if e.name().into_inner() == b"c" { return },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "c"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::End(ref e)) => if e.name().into_inner() == b"c" { return Ok(()) },
+                Ok(Event::Eof) => return Err(unexpected_eof(reader, "c")),
+                Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
                 _ => (),
             }
             buf.clear();
         }
     }
 
+    /// Keep a value read from an ISO 8601 date shown as a date, a time or a
+    /// date with time, unless the cell has a number format.
+    fn set_date_format_if_general(&mut self, value: f64) {
+        let is_general = self
+            .style()
+            .number_format()
+            .is_none_or(|v| v.format_code() == NumberingFormat::FORMAT_GENERAL);
+        if !is_general {
+            return;
+        }
+        let format_code = if value < 1.0 {
+            NumberingFormat::FORMAT_DATE_TIME4
+        } else if value.fract() == 0.0 {
+            NumberingFormat::FORMAT_DATE_YYYYMMDD
+        } else {
+            "yyyy-mm-dd h:mm:ss"
+        };
+        self.style_mut()
+            .number_format_mut()
+            .set_format_code(format_code);
+    }
+
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
@@ -849,7 +914,7 @@ mod tests {
             &Stylesheet::default(),
             false,
             &mut formula_shared_list,
-        );
+        ).unwrap();
 
         assert_eq!(cell.value(), "0050");
         assert_eq!(cell.formatted_value(), "0050");
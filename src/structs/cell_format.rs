@@ -17,9 +17,11 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -29,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct CellFormat {
     number_format_id:    UInt32Value,
     font_id:             UInt32Value,
@@ -325,11 +328,12 @@ impl CellFormat {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, number_format_id, "numFmtId");
         set_string_from_xml!(self, e, font_id, "fontId");
         set_string_from_xml!(self, e, fill_id, "fillId");
         set_string_from_xml!(self, e, border_id, "borderId");
+        set_string_from_xml!(self, e, format_id, "xfId");
         set_string_from_xml!(self, e, apply_number_format, "applyNumberFormat");
         set_string_from_xml!(self, e, apply_border, "applyBorder");
         set_string_from_xml!(self, e, apply_font, "applyFont");
@@ -338,7 +342,7 @@ impl CellFormat {
         set_string_from_xml!(self, e, apply_protection, "applyProtection");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -360,10 +364,10 @@ impl CellFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xf")
+            Event::Eof => return Err(unexpected_eof(reader, "xf"))
         );
     }
 
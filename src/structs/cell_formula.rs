@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::{
         coordinate::index_from_coordinate,
         formula::{
@@ -23,6 +24,7 @@ use crate::{
     },
     reader::driver::{
         get_attribute,
+        invalid_xml,
         set_string_from_xml,
     },
     structs::{
@@ -41,6 +43,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CellFormula {
     bx:             BooleanValue,
     data_table_2d:  BooleanValue,
@@ -273,6 +276,19 @@ impl CellFormula {
         self
     }
 
+    /// (This method is crate only.)
+    /// Rewrite the text of the formula.
+    pub(crate) fn map_text<F: Fn(&str) -> String>(&mut self, f: F) {
+        if self.text.has_value() {
+            let value = f(self.text.value_str());
+            self.text.set_value(value);
+        }
+        if self.text_view.has_value() {
+            let value = f(self.text_view.value_str());
+            self.text_view.set_value(value);
+        }
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
@@ -281,7 +297,7 @@ impl CellFormula {
         is_empty: bool,
         cell_reference_str: &str,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, bx, "bx");
         set_string_from_xml!(self, e, data_table_2d, "dt2D");
         set_string_from_xml!(self, e, data_table_row, "dtr");
@@ -296,7 +312,8 @@ impl CellFormula {
         if !is_empty {
             let mut buf = Vec::new();
             let text = reader
-                    .read_text_into(e.name(), &mut buf).unwrap();
+                    .read_text_into(e.name(), &mut buf)
+                    .map_err(|e| invalid_xml(reader.error_position(), &e))?;
             self.text.set_value(crate::helper::utils::unescape_xml_text(&text));
         }
 
@@ -307,10 +324,10 @@ impl CellFormula {
                     let parent_cell = index_from_coordinate(parent_cell_reference_str);
                     let self_cell = index_from_coordinate(cell_reference_str);
                     let (Some(parent_col_num), Some(parent_row_num), ..) = parent_cell else {
-                        return;
+                        return Ok(());
                     };
                     let (Some(self_col_num), Some(self_row_num), ..) = self_cell else {
-                        return;
+                        return Ok(());
                     };
 
                     // Shared-formula sibling rebasing: translate every
@@ -345,6 +362,7 @@ impl CellFormula {
                 }
             }
         }
+        Ok(())
     }
 
     #[inline]
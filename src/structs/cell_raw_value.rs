@@ -7,6 +7,7 @@ use super::{
 use crate::CellErrorType;
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CellRawValue {
     String(Box<str>),
     RichText(RichText),
@@ -20,6 +20,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CellStyle {
     name:       StringValue,
     builtin_id: UInt32Value,
@@ -84,6 +85,12 @@ impl CellStyle {
         self
     }
 
+    /// (This method is crate only.)
+    #[inline]
+    pub(crate) fn has_builtin_id(&self) -> bool {
+        self.builtin_id.has_value()
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
@@ -103,7 +110,9 @@ impl CellStyle {
         let format_id = self.format_id.value_string();
         attributes.push(("xfId", &format_id).into());
         let builtin_id = self.builtin_id.value_string();
-        attributes.push(("builtinId", &builtin_id).into());
+        if self.builtin_id.has_value() {
+            attributes.push(("builtinId", &builtin_id).into());
+        }
         write_start_tag(writer, "cellStyle", attributes, true);
     }
 }
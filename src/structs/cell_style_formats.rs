@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::CellFormat;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct CellStyleFormats {
     cell_format: Vec<CellFormat>,
 }
@@ -58,23 +63,23 @@ impl CellStyleFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"xf" {
                     let mut obj = CellFormat::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_cell_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cellStyleXfs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cellStyleXfs")
+            Event::Eof => return Err(unexpected_eof(reader, "cellStyleXfs"))
         );
     }
 
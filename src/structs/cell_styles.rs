@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::CellStyle;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CellStyles {
     cell_style: Vec<CellStyle>,
 }
@@ -57,7 +62,7 @@ impl CellStyles {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -69,10 +74,10 @@ impl CellStyles {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cellStyles" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cellStyles")
+            Event::Eof => return Err(unexpected_eof(reader, "cellStyles"))
         );
     }
 
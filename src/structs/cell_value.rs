@@ -18,6 +18,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CellValue {
     pub(crate) raw_value: CellRawValue,
     pub(crate) formula:   Option<Box<CellFormula>>,
@@ -248,6 +249,11 @@ impl CellValue {
         self.formula_obj()
     }
 
+    #[inline]
+    pub(crate) fn formula_obj_mut(&mut self) -> Option<&mut CellFormula> {
+        self.formula.as_deref_mut()
+    }
+
     #[inline]
     pub fn set_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let mut obj = CellFormula::default();
@@ -594,6 +594,27 @@ impl Cells {
                 .collect();
     }
 }
+/// The cells are a sequence sorted by row and column, the indexes are
+/// rebuilt from their coordinates.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Cells {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_cells_sorted_by_row_column())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Cells {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut cells = Self::default();
+        for cell in <Vec<Cell> as serde::Deserialize>::deserialize(deserializer)? {
+            cells.add(cell);
+        }
+        Ok(cells)
+    }
+}
+
 impl AdjustmentCoordinate for Cells {
     #[inline]
     fn adjustment_insert_coordinate(
@@ -60,7 +60,7 @@ use crate::{
                 MarkerType,
                 TwoCellAnchor,
             }, Paragraph, Run, RunProperties
-        }, ChartType
+        }, ChartType, Workbook
     }, traits::{
         AdjustmentCoordinate,
         AdjustmentCoordinateWithSheet,
@@ -131,6 +131,7 @@ use crate::{
 /// chart.set_grouping(umya_spreadsheet::drawing::charts::GroupingValues::Standard);
 /// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chart {
     two_cell_anchor:  Box<TwoCellAnchor>,
     default_language: String,
@@ -327,6 +328,42 @@ impl Chart {
         self.chart_space_mut()
     }
 
+    /// Refresh the cached series data from the worksheet cells.
+    /// Values, format codes and point counts are copied from the cells
+    /// referenced by each series, including references to other sheets.
+    /// References to sheets that do not exist keep their current cache.
+    /// # Arguments
+    /// * `wb` - Workbook that owns the referenced cells.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.sheet_mut(0).unwrap().cell_mut("A1").set_value_number(42);
+    ///
+    /// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// from_marker.set_coordinate("C1");
+    /// to_marker.set_coordinate("D11");
+    /// let mut chart = umya_spreadsheet::structs::Chart::default();
+    /// chart.new_chart(
+    ///     &umya_spreadsheet::structs::ChartType::LineChart,
+    ///     from_marker,
+    ///     to_marker,
+    ///     vec!["Sheet1!$A$1:$A$10"],
+    /// );
+    /// chart.refresh_cache(&book);
+    /// ```
+    pub fn refresh_cache(&mut self, wb: &Workbook) -> &mut Self {
+        if let Some(v) = self.two_cell_anchor.graphic_frame_mut() {
+            v.graphic_mut()
+                .graphic_data_mut()
+                .chart_space_mut()
+                .chart_mut()
+                .plot_area_mut()
+                .refresh_cache(wb);
+        }
+        self
+    }
+
     #[allow(clippy::needless_pass_by_value)]
     pub fn new_chart(
         &mut self,
@@ -0,0 +1,351 @@
+use super::{
+    Chart,
+    ChartStyle,
+    ChartType,
+    drawing::{
+        RgbColorModelHex,
+        SolidFill,
+        Theme,
+        charts::{
+            LegendPositionValues,
+            MajorGridlines,
+            MajorUnit,
+            MaxAxisValue,
+            MinAxisValue,
+            PlotArea,
+            ValueAxis,
+        },
+        spreadsheet::MarkerType,
+    },
+};
+
+/// Builds a styled [`Chart`].
+///
+/// The builder collects the chart settings and produces the same `Chart`
+/// that [`Chart::new_chart`] creates, with the requested titles, axis
+/// scaling, number format, gridlines, legend and series colors applied.
+/// Axis settings are ignored for chart types without axes.
+///
+/// Series colors are taken from [`set_series_colors`](Self::set_series_colors)
+/// when given, otherwise from the [`ChartStyle`] preset using the theme set
+/// with [`set_theme`](Self::set_theme) (or the default Office theme).
+/// Pie and doughnut charts keep their per-point colors.
+///
+/// ## Example
+/// ```rust
+/// use umya_spreadsheet::*;
+///
+/// let mut book = new_file();
+///
+/// let chart = ChartBuilder::new(ChartType::BarChart, "C1", "J15")
+///     .add_series("Sheet1!$A$1:$A$10")
+///     .add_series("Sheet1!$B$1:$B$10")
+///     .set_series_titles(vec!["2023", "2024"])
+///     .set_title("Sales")
+///     .set_vertical_title("Amount")
+///     .set_horizontal_title("Month")
+///     .set_min(0.0)
+///     .set_max(1000.0)
+///     .set_major_unit(250.0)
+///     .set_number_format("#,##0")
+///     .set_legend_position(drawing::charts::LegendPositionValues::Bottom)
+///     .set_theme(book.theme())
+///     .set_style(ChartStyle::Accent1)
+///     .build();
+///
+/// book.sheet_by_name_mut("Sheet1").unwrap().add_chart(chart);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ChartBuilder {
+    chart_type:       ChartType,
+    from_coordinate:  String,
+    to_coordinate:    String,
+    series:           Vec<String>,
+    series_titles:    Vec<String>,
+    title:            Option<String>,
+    vertical_title:   Option<String>,
+    horizontal_title: Option<String>,
+    min:              Option<f64>,
+    max:              Option<f64>,
+    major_unit:       Option<f64>,
+    number_format:    Option<String>,
+    major_gridlines:  Option<bool>,
+    legend_position:  Option<LegendPositionValues>,
+    legend_visible:   bool,
+    style:            Option<ChartStyle>,
+    series_colors:    Vec<String>,
+    theme:            Option<Theme>,
+}
+
+impl ChartBuilder {
+    /// Create a new builder.
+    /// # Arguments
+    /// * `chart_type` - Chart type.
+    /// * `from_coordinate` - Top left cell of the chart. ex) "C1"
+    /// * `to_coordinate` - Bottom right cell of the chart. ex) "J15"
+    #[must_use]
+    pub fn new(chart_type: ChartType, from_coordinate: &str, to_coordinate: &str) -> Self {
+        Self {
+            chart_type,
+            from_coordinate: from_coordinate.to_string(),
+            to_coordinate: to_coordinate.to_string(),
+            legend_visible: true,
+            ..Default::default()
+        }
+    }
+
+    /// Add a series.
+    /// # Arguments
+    /// * `range` - Range of the series values. ex) "Sheet1!$A$1:$A$10"
+    #[inline]
+    pub fn add_series<S: Into<String>>(&mut self, range: S) -> &mut Self {
+        self.series.push(range.into());
+        self
+    }
+
+    #[inline]
+    pub fn set_series_titles<S: Into<String>>(&mut self, value: Vec<S>) -> &mut Self {
+        self.series_titles = value.into_iter().map(Into::into).collect();
+        self
+    }
+
+    #[inline]
+    pub fn set_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn set_vertical_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.vertical_title = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn set_horizontal_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.horizontal_title = Some(value.into());
+        self
+    }
+
+    /// Set the minimum of the value axis.
+    #[inline]
+    pub fn set_min(&mut self, value: f64) -> &mut Self {
+        self.min = Some(value);
+        self
+    }
+
+    /// Set the maximum of the value axis.
+    #[inline]
+    pub fn set_max(&mut self, value: f64) -> &mut Self {
+        self.max = Some(value);
+        self
+    }
+
+    /// Set the distance between major tick marks of the value axis.
+    #[inline]
+    pub fn set_major_unit(&mut self, value: f64) -> &mut Self {
+        self.major_unit = Some(value);
+        self
+    }
+
+    /// Set the number format of the value axis labels.
+    /// # Arguments
+    /// * `value` - Format code. ex) "#,##0.00"
+    #[inline]
+    pub fn set_number_format<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.number_format = Some(value.into());
+        self
+    }
+
+    /// Show or hide the major gridlines of the value axis.
+    #[inline]
+    pub fn set_major_gridlines(&mut self, value: bool) -> &mut Self {
+        self.major_gridlines = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn set_legend_position(&mut self, value: LegendPositionValues) -> &mut Self {
+        self.legend_position = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn set_legend_visible(&mut self, value: bool) -> &mut Self {
+        self.legend_visible = value;
+        self
+    }
+
+    #[inline]
+    pub fn set_style(&mut self, value: ChartStyle) -> &mut Self {
+        self.style = Some(value);
+        self
+    }
+
+    /// Set the series colors. Overrides the colors of the style preset.
+    /// # Arguments
+    /// * `value` - RGB hex strings. ex) vec!["4472C4", "ED7D31"]
+    #[inline]
+    pub fn set_series_colors<S: Into<String>>(&mut self, value: Vec<S>) -> &mut Self {
+        self.series_colors = value.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the theme the style preset takes its accent colors from.
+    #[inline]
+    pub fn set_theme(&mut self, value: &Theme) -> &mut Self {
+        self.theme = Some(value.clone());
+        self
+    }
+
+    /// Build the chart.
+    #[must_use]
+    pub fn build(&self) -> Chart {
+        let mut from_marker = MarkerType::default();
+        let mut to_marker = MarkerType::default();
+        from_marker.set_coordinate(&self.from_coordinate);
+        to_marker.set_coordinate(&self.to_coordinate);
+
+        let mut chart = Chart::default();
+        chart.new_chart(
+            &self.chart_type,
+            from_marker,
+            to_marker,
+            self.series.iter().map(String::as_str).collect(),
+        );
+
+        if !self.series_titles.is_empty() {
+            chart.set_series_title(self.series_titles.clone());
+        }
+        if let Some(v) = &self.title {
+            chart.set_title(v);
+        }
+        if let Some(v) = &self.vertical_title {
+            chart.set_vertical_title(v);
+        }
+        if let Some(v) = &self.horizontal_title {
+            chart.set_horizontal_title(v);
+        }
+
+        if let Some(value_axis) = Self::vertical_value_axis_mut(chart.plot_area_mut()) {
+            if let Some(v) = self.min {
+                let mut obj = MinAxisValue::default();
+                obj.set_val(v);
+                value_axis.scaling_mut().set_min_axis_value(obj);
+            }
+            if let Some(v) = self.max {
+                let mut obj = MaxAxisValue::default();
+                obj.set_val(v);
+                value_axis.scaling_mut().set_max_axis_value(obj);
+            }
+            if let Some(v) = self.major_unit {
+                let mut obj = MajorUnit::default();
+                obj.set_val(v);
+                value_axis.set_major_unit(obj);
+            }
+            if let Some(v) = &self.number_format {
+                value_axis
+                    .numbering_format_mut()
+                    .set_format_code(v)
+                    .set_source_linked(false);
+            }
+            match self.major_gridlines {
+                Some(true) => {
+                    value_axis.set_major_gridlines(MajorGridlines::default());
+                }
+                Some(false) => {
+                    value_axis.remove_major_gridlines();
+                }
+                None => {}
+            }
+        }
+
+        if let Some(v) = &self.legend_position {
+            chart
+                .chart_space_mut()
+                .chart_mut()
+                .legend_mut()
+                .legend_position_mut()
+                .set_val(v.clone());
+        }
+        if !self.legend_visible {
+            chart.chart_space_mut().chart_mut().set_legend_present(false);
+        }
+
+        if let Some(v) = &self.style {
+            let chart_space = chart.chart_space_mut();
+            let mut style = chart_space.style().cloned().unwrap_or_default();
+            style.set_val(v.id().to_string());
+            chart_space.set_style(style);
+        }
+
+        let colors = self.resolve_colors();
+        if !colors.is_empty() {
+            self.apply_series_colors(&mut chart, &colors);
+        }
+
+        chart
+    }
+
+    fn resolve_colors(&self) -> Vec<String> {
+        if !self.series_colors.is_empty() {
+            return self.series_colors.clone();
+        }
+        if self.style.is_none() && self.theme.is_none() {
+            return Vec::new();
+        }
+        let style = self.style.clone().unwrap_or_default();
+        match &self.theme {
+            Some(v) => style.colors(v),
+            None => style.colors(&Theme::default_value()),
+        }
+    }
+
+    fn apply_series_colors(&self, chart: &mut Chart, colors: &[String]) {
+        let is_line = match self.chart_type {
+            ChartType::LineChart
+            | ChartType::Line3DChart
+            | ChartType::ScatterChart
+            | ChartType::RadarChart => true,
+            ChartType::PieChart
+            | ChartType::Pie3DChart
+            | ChartType::DoughnutChart
+            | ChartType::OfPieChart => return,
+            _ => false,
+        };
+
+        for (series, color) in chart
+            .area_chart_series_list_mut()
+            .area_chart_series_mut()
+            .iter_mut()
+            .zip(colors.iter().cycle())
+        {
+            let mut rgb_color_model_hex = RgbColorModelHex::default();
+            rgb_color_model_hex.set_val(color);
+            let mut solid_fill = SolidFill::default();
+            solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+
+            let mut shape_properties = series.shape_properties().cloned().unwrap_or_default();
+            if is_line {
+                let mut outline = shape_properties.outline().cloned().unwrap_or_default();
+                if outline.width() == 0 {
+                    outline.set_width(28575);
+                }
+                outline.set_solid_fill(solid_fill);
+                shape_properties.set_outline(outline);
+            } else {
+                shape_properties.set_solid_fill(solid_fill);
+            }
+            series.set_shape_properties(shape_properties);
+        }
+    }
+
+    fn vertical_value_axis_mut(plot_area: &mut PlotArea) -> Option<&mut ValueAxis> {
+        let axis_list = plot_area.value_axis_mut();
+        match axis_list.len() {
+            1 => axis_list.get_mut(0),
+            2 => axis_list.get_mut(1),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use super::drawing::Theme;
+
+/// Chart style presets.
+///
+/// A preset selects the chart style id written to the chart part and the
+/// colors given to the series. Accent colors are taken from the theme.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChartStyle {
+    /// Style 1. Shades of gray.
+    Grayscale,
+    /// Style 2. One theme accent color per series.
+    #[default]
+    Colorful,
+    /// Style 3. Shades of theme accent 1.
+    Accent1,
+    /// Style 4. Shades of theme accent 2.
+    Accent2,
+    /// Style 5. Shades of theme accent 3.
+    Accent3,
+    /// Style 6. Shades of theme accent 4.
+    Accent4,
+    /// Style 7. Shades of theme accent 5.
+    Accent5,
+    /// Style 8. Shades of theme accent 6.
+    Accent6,
+}
+
+impl ChartStyle {
+    const GRAYSCALE: [&'static str; 6] = [
+        "595959", "7F7F7F", "A5A5A5", "404040", "BFBFBF", "262626",
+    ];
+
+    // Luminance adjustments used for the monochrome presets.
+    // Negative values darken the base color, positive values lighten it.
+    const SHADES: [f64; 6] = [0.0, -0.25, 0.4, -0.5, 0.6, 0.8];
+
+    /// Get the chart style id of the preset.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> u32 {
+        match self {
+            Self::Grayscale => 1,
+            Self::Colorful => 2,
+            Self::Accent1 => 3,
+            Self::Accent2 => 4,
+            Self::Accent3 => 5,
+            Self::Accent4 => 6,
+            Self::Accent5 => 7,
+            Self::Accent6 => 8,
+        }
+    }
+
+    /// Get the series colors of the preset as RGB hex strings.
+    /// # Arguments
+    /// * `theme` - Theme providing the accent colors.
+    #[must_use]
+    pub fn colors(&self, theme: &Theme) -> Vec<String> {
+        let scheme = theme.theme_elements().color_scheme();
+        let base = match self {
+            Self::Grayscale => {
+                return Self::GRAYSCALE.iter().map(|v| (*v).to_string()).collect();
+            }
+            Self::Colorful => {
+                return vec![
+                    scheme.accent1().val(),
+                    scheme.accent2().val(),
+                    scheme.accent3().val(),
+                    scheme.accent4().val(),
+                    scheme.accent5().val(),
+                    scheme.accent6().val(),
+                ];
+            }
+            Self::Accent1 => scheme.accent1().val(),
+            Self::Accent2 => scheme.accent2().val(),
+            Self::Accent3 => scheme.accent3().val(),
+            Self::Accent4 => scheme.accent4().val(),
+            Self::Accent5 => scheme.accent5().val(),
+            Self::Accent6 => scheme.accent6().val(),
+        };
+        Self::SHADES
+            .iter()
+            .map(|shade| Self::adjust_luminance(&base, *shade))
+            .collect()
+    }
+
+    fn adjust_luminance(hex: &str, amount: f64) -> String {
+        let Ok(rgb) = u32::from_str_radix(hex, 16) else {
+            return hex.to_string();
+        };
+        let adjust = |shift: u32| {
+            let channel = f64::from((rgb >> shift) & 0xFF);
+            let value = if amount < 0.0 {
+                channel * (1.0 + amount)
+            } else {
+                channel + (255.0 - channel) * amount
+            };
+            let value: u8 = num_traits::cast(value.round().clamp(0.0, 255.0)).unwrap_or(0);
+            value
+        };
+        format!("{:02X}{:02X}{:02X}", adjust(16), adjust(8), adjust(0))
+    }
+}
@@ -0,0 +1,472 @@
+// chartsheet
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    Chart,
+    ChartsheetView,
+    ChartsheetViews,
+    Color,
+    HeaderFooter,
+    PageMargins,
+    PageSetup,
+    StringValue,
+    drawing::spreadsheet::{
+        Extent,
+        GraphicFrame,
+        Position,
+    },
+    raw::{
+        RawFile,
+        RawRelationships,
+    },
+};
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        DRAWINGML_MAIN_NS,
+        REL_OFC_NS,
+        SHEET_DRAWING_NS,
+        SHEET_MAIN_NS,
+    },
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// A Chart Sheet Object.
+/// A sheet tab whose whole content is a single chart.
+///
+/// Chart sheets live in the workbook's sheet list alongside ordinary
+/// worksheets, see [`Workbook::add_chartsheet`](crate::structs::Workbook::add_chartsheet).
+///
+/// ## Example
+/// ```rust
+/// use umya_spreadsheet::*;
+///
+/// let mut book = new_file();
+///
+/// let mut from_marker = drawing::spreadsheet::MarkerType::default();
+/// let mut to_marker = drawing::spreadsheet::MarkerType::default();
+/// from_marker.set_coordinate("A1");
+/// to_marker.set_coordinate("B2");
+/// let mut chart = Chart::default();
+/// chart.new_chart(
+///     &ChartType::BarChart,
+///     from_marker,
+///     to_marker,
+///     vec!["Sheet1!$A$1:$A$10"],
+/// );
+///
+/// let chartsheet = book.add_chartsheet("Chart1", chart).unwrap();
+/// chartsheet.chart_mut().set_title("Sales");
+/// chartsheet
+///     .page_setup_mut()
+///     .set_orientation(OrientationValues::Landscape);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chartsheet {
+    code_name:     StringValue,
+    tab_color:     Option<Color>,
+    sheet_views:   ChartsheetViews,
+    page_margins:  PageMargins,
+    page_setup:    PageSetup,
+    header_footer: HeaderFooter,
+    position:      Position,
+    extent:        Extent,
+    chart:         Chart,
+}
+
+impl Default for Chartsheet {
+    #[inline]
+    fn default() -> Self {
+        let mut sheet_view = ChartsheetView::default();
+        sheet_view.set_workbook_view_id(0).set_zoom_to_fit(true);
+        let mut sheet_views = ChartsheetViews::default();
+        sheet_views.add_sheet_view_list_mut(sheet_view);
+
+        let mut page_margins = PageMargins::default();
+        page_margins
+            .set_left(0.7)
+            .set_right(0.7)
+            .set_top(0.75)
+            .set_bottom(0.75)
+            .set_header(0.3)
+            .set_footer(0.3);
+
+        let mut extent = Extent::default();
+        extent.set_cx(8_666_049).set_cy(6_293_304);
+
+        Self {
+            code_name: StringValue::default(),
+            tab_color: None,
+            sheet_views,
+            page_margins,
+            page_setup: PageSetup::default(),
+            header_footer: HeaderFooter::default(),
+            position: Position::default(),
+            extent,
+            chart: Chart::default(),
+        }
+    }
+}
+
+impl Chartsheet {
+    /// Create a new chart sheet displaying the given chart.
+    /// # Arguments
+    /// * `chart` - Chart. The anchor markers of the chart are ignored.
+    #[inline]
+    #[must_use]
+    pub fn new(chart: Chart) -> Self {
+        let mut obj = Self::default();
+        obj.set_chart(chart);
+        obj
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn chart(&self) -> &Chart {
+        &self.chart
+    }
+
+    #[inline]
+    pub fn chart_mut(&mut self) -> &mut Chart {
+        &mut self.chart
+    }
+
+    #[inline]
+    pub fn set_chart(&mut self, value: Chart) -> &mut Self {
+        self.chart = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn code_name(&self) -> Option<&str> {
+        self.code_name.value()
+    }
+
+    #[inline]
+    pub fn set_code_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.code_name.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn tab_color(&self) -> Option<&Color> {
+        self.tab_color.as_ref()
+    }
+
+    #[inline]
+    pub fn tab_color_mut(&mut self) -> &mut Color {
+        self.tab_color.get_or_insert_with(Color::default)
+    }
+
+    #[inline]
+    pub fn set_tab_color(&mut self, value: Color) -> &mut Self {
+        self.tab_color = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_tab_color(&mut self) -> &mut Self {
+        self.tab_color = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sheet_views(&self) -> &ChartsheetViews {
+        &self.sheet_views
+    }
+
+    #[inline]
+    pub fn sheet_views_mut(&mut self) -> &mut ChartsheetViews {
+        &mut self.sheet_views
+    }
+
+    #[inline]
+    pub fn set_sheet_views(&mut self, value: ChartsheetViews) -> &mut Self {
+        self.sheet_views = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn page_margins(&self) -> &PageMargins {
+        &self.page_margins
+    }
+
+    #[inline]
+    pub fn page_margins_mut(&mut self) -> &mut PageMargins {
+        &mut self.page_margins
+    }
+
+    #[inline]
+    pub fn set_page_margins(&mut self, value: PageMargins) -> &mut Self {
+        self.page_margins = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn page_setup(&self) -> &PageSetup {
+        &self.page_setup
+    }
+
+    #[inline]
+    pub fn page_setup_mut(&mut self) -> &mut PageSetup {
+        &mut self.page_setup
+    }
+
+    #[inline]
+    pub fn set_page_setup(&mut self, value: PageSetup) -> &mut Self {
+        self.page_setup = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn header_footer(&self) -> &HeaderFooter {
+        &self.header_footer
+    }
+
+    #[inline]
+    pub fn header_footer_mut(&mut self) -> &mut HeaderFooter {
+        &mut self.header_footer
+    }
+
+    #[inline]
+    pub fn set_header_footer(&mut self, value: HeaderFooter) -> &mut Self {
+        self.header_footer = value;
+        self
+    }
+
+    /// Get the position of the chart on the sheet (EMU).
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    #[inline]
+    pub fn position_mut(&mut self) -> &mut Position {
+        &mut self.position
+    }
+
+    /// Get the size of the chart on the sheet (EMU).
+    #[inline]
+    #[must_use]
+    pub fn extent(&self) -> &Extent {
+        &self.extent
+    }
+
+    #[inline]
+    pub fn extent_mut(&mut self) -> &mut Extent {
+        &mut self.extent
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        relationships: Option<&RawRelationships>,
+        drawing_relationships: Option<&RawRelationships>,
+    ) -> Result<(), XlsxError> {
+        self.sheet_views = ChartsheetViews::default();
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"sheetPr" => {
+                        set_string_from_xml!(self, e, code_name, "codeName");
+                    }
+                    b"sheetViews" => {
+                        self.sheet_views.set_attributes(reader, e)?;
+                    }
+                    b"headerFooter" => {
+                        self.header_footer.set_attributes(reader, e, false)?;
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"sheetPr" => {
+                        set_string_from_xml!(self, e, code_name, "codeName");
+                    }
+                    b"tabColor" => {
+                        self.tab_color_mut().set_attributes(reader, e, true)?;
+                    }
+                    b"pageMargins" => {
+                        self.page_margins.set_attributes(reader, e)?;
+                    }
+                    b"pageSetup" => {
+                        self.page_setup.set_attributes(reader, e, relationships)?;
+                    }
+                    b"drawing" => {
+                        if let (Some(r_id), Some(relationships)) =
+                            (get_attribute(e, b"r:id"), relationships)
+                        {
+                            let drawing_file = relationships.relationship_by_rid(&r_id)?.raw_file();
+                            self.set_drawing(drawing_file, drawing_relationships)?;
+                        }
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"chartsheet" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "chartsheet"))
+        );
+    }
+
+    fn set_drawing(
+        &mut self,
+        drawing_file: &RawFile,
+        drawing_relationships: Option<&RawRelationships>,
+    ) -> Result<(), XlsxError> {
+        let data = Cursor::new(drawing_file.file_data());
+        let mut reader = Reader::from_reader(data);
+        reader.config_mut().trim_text(true);
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if matches!(e.name().into_inner(), b"xdr:graphicFrame" | b"graphicFrame") {
+                    let mut obj = GraphicFrame::default();
+                    obj.set_attributes(&mut reader, e, drawing_relationships)?;
+                    self.chart.two_cell_anchor_mut().set_graphic_frame(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"xdr:pos" | b"pos" => {
+                        self.position.set_attributes(&mut reader, e);
+                    }
+                    b"xdr:ext" | b"ext" => {
+                        self.extent.set_attributes(&mut reader, e);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Eof => break
+        );
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, has_macros: bool) {
+        // chartsheet
+        write_start_tag(
+            writer,
+            "chartsheet",
+            vec![
+                ("xmlns", SHEET_MAIN_NS).into(),
+                ("xmlns:r", REL_OFC_NS).into(),
+            ],
+            false,
+        );
+
+        // sheetPr
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if let Some(v) = self.code_name.value() {
+            attributes.push(("codeName", v).into());
+        } else if has_macros {
+            attributes.push(("codeName", "Chart").into());
+        }
+        match &self.tab_color {
+            Some(v) => {
+                write_start_tag(writer, "sheetPr", attributes, false);
+                v.write_to_tab_color(writer);
+                write_end_tag(writer, "sheetPr");
+            }
+            None => {
+                if !attributes.is_empty() {
+                    write_start_tag(writer, "sheetPr", attributes, true);
+                }
+            }
+        }
+
+        // sheetViews
+        self.sheet_views.write_to(writer);
+
+        // pageMargins
+        self.page_margins.write_to(writer);
+
+        // pageSetup
+        let mut r_id = 1;
+        self.page_setup.write_to(writer, &mut r_id);
+
+        // headerFooter
+        self.header_footer.write_to(writer);
+
+        // drawing
+        write_start_tag(
+            writer,
+            "drawing",
+            vec![("r:id", format!("rId{r_id}").as_str()).into()],
+            true,
+        );
+
+        write_end_tag(writer, "chartsheet");
+    }
+
+    pub(crate) fn write_drawing_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
+        // xdr:wsDr
+        write_start_tag(
+            writer,
+            "xdr:wsDr",
+            vec![
+                ("xmlns:xdr", SHEET_DRAWING_NS).into(),
+                ("xmlns:a", DRAWINGML_MAIN_NS).into(),
+            ],
+            false,
+        );
+
+        // xdr:absoluteAnchor
+        write_start_tag(writer, "xdr:absoluteAnchor", vec![], false);
+
+        // xdr:pos
+        self.position.write_to(writer);
+
+        // xdr:ext
+        self.extent.write_to(writer);
+
+        // xdr:graphicFrame
+        if let Some(v) = self.chart.two_cell_anchor().graphic_frame() {
+            v.write_to(writer, rel_list);
+        }
+
+        // xdr:clientData
+        write_start_tag(writer, "xdr:clientData", vec![], true);
+
+        write_end_tag(writer, "xdr:absoluteAnchor");
+
+        write_end_tag(writer, "xdr:wsDr");
+    }
+}
@@ -0,0 +1,129 @@
+// sheetView (chartsheet)
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    UInt32Value,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChartsheetView {
+    tab_selected:     BooleanValue,
+    zoom_scale:       UInt32Value,
+    workbook_view_id: UInt32Value,
+    zoom_to_fit:      BooleanValue,
+}
+
+impl ChartsheetView {
+    #[inline]
+    #[must_use]
+    pub fn tab_selected(&self) -> bool {
+        self.tab_selected.value()
+    }
+
+    #[inline]
+    pub fn set_tab_selected(&mut self, value: bool) -> &mut Self {
+        self.tab_selected.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zoom_scale(&self) -> u32 {
+        self.zoom_scale.value()
+    }
+
+    #[inline]
+    pub fn set_zoom_scale(&mut self, value: u32) -> &mut Self {
+        self.zoom_scale.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn workbook_view_id(&self) -> u32 {
+        self.workbook_view_id.value()
+    }
+
+    #[inline]
+    pub fn set_workbook_view_id(&mut self, value: u32) -> &mut Self {
+        self.workbook_view_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zoom_to_fit(&self) -> bool {
+        self.zoom_to_fit.value()
+    }
+
+    #[inline]
+    pub fn set_zoom_to_fit(&mut self, value: bool) -> &mut Self {
+        self.zoom_to_fit.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, tab_selected, "tabSelected");
+        set_string_from_xml!(self, e, zoom_scale, "zoomScale");
+        set_string_from_xml!(self, e, workbook_view_id, "workbookViewId");
+        set_string_from_xml!(self, e, zoom_to_fit, "zoomToFit");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sheetView" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "sheetView"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // sheetView
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.tab_selected.value() {
+            attributes.push(("tabSelected", self.tab_selected.value_string()).into());
+        }
+        let zoom_scale = self.zoom_scale.value_string();
+        if self.zoom_scale.has_value() {
+            attributes.push(("zoomScale", &zoom_scale).into());
+        }
+        let workbook_view_id = self.workbook_view_id.value_string();
+        attributes.push(("workbookViewId", &workbook_view_id).into());
+        if self.zoom_to_fit.value() {
+            attributes.push(("zoomToFit", self.zoom_to_fit.value_string()).into());
+        }
+        write_start_tag(writer, "sheetView", attributes, true);
+    }
+}
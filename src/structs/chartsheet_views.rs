@@ -0,0 +1,88 @@
+// sheetViews (chartsheet)
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::ChartsheetView;
+use crate::{
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChartsheetViews {
+    sheet_view_list: Vec<ChartsheetView>,
+}
+
+impl ChartsheetViews {
+    #[inline]
+    #[must_use]
+    pub fn sheet_view_list(&self) -> &[ChartsheetView] {
+        &self.sheet_view_list
+    }
+
+    #[inline]
+    pub fn sheet_view_list_mut(&mut self) -> &mut Vec<ChartsheetView> {
+        &mut self.sheet_view_list
+    }
+
+    #[inline]
+    pub fn add_sheet_view_list_mut(&mut self, value: ChartsheetView) -> &mut Self {
+        self.sheet_view_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
+                let is_empty = matches!(n, Event::Empty(_));
+                if e.name().into_inner() == b"sheetView" {
+                    let mut obj = ChartsheetView::default();
+                    obj.set_attributes(reader, e, is_empty)?;
+                    self.add_sheet_view_list_mut(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sheetViews" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "sheetViews"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // sheetViews
+        write_start_tag(writer, "sheetViews", vec![], false);
+
+        // sheetView
+        if self.sheet_view_list.is_empty() {
+            ChartsheetView::default().write_to(writer);
+        }
+        for sheet_view in &self.sheet_view_list {
+            sheet_view.write_to(writer);
+        }
+
+        write_end_tag(writer, "sheetViews");
+    }
+}
@@ -16,8 +16,13 @@ use quick_xml::{
 use rgb::Argb;
 
 use crate::{
+    XlsxError,
     helper::color::calc_tint,
-    reader::driver::get_attribute_value,
+    reader::driver::{
+        get_attribute_value,
+        invalid_xml,
+        unexpected_eof,
+    },
     structs::drawing::Theme,
     writer::driver::write_start_tag,
 };
@@ -170,6 +175,7 @@ static COLOR_STR_TO_INDEX: phf::Map<&'static str, u32> = phf_map! {
 };
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     indexed:     Option<u32>,
     theme_index: Option<u32>,
@@ -472,6 +478,21 @@ impl Color {
             || self.tint.is_some()
     }
 
+    #[inline]
+    pub(crate) fn has_theme_index(&self) -> bool {
+        self.theme_index.is_some()
+    }
+
+    #[inline]
+    pub(crate) fn has_indexed(&self) -> bool {
+        self.indexed.is_some()
+    }
+
+    #[inline]
+    pub(crate) fn has_tint(&self) -> bool {
+        self.tint.is_some()
+    }
+
     #[inline]
     pub(crate) fn hash_code(&self) -> String {
         crate::helper::utils::md5_hash(format!(
@@ -500,7 +521,7 @@ impl Color {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         for attr in e.attributes().with_checks(false).flatten() {
             match attr.key.0 {
                 b"indexed" => {
@@ -534,21 +555,18 @@ impl Color {
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"color" | b"fgColor" | b"bgColor" | b"tabColor" => return,
+                    b"color" | b"fgColor" | b"bgColor" | b"tabColor" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!(
-                    "Error: Could not find {} end element",
-                    "color,fgColor,bgColor,tabColor"
-                ),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(unexpected_eof(reader, "color,fgColor,bgColor,tabColor")),
+                Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
                 _ => (),
             }
             buf.clear();
@@ -579,7 +597,7 @@ impl Color {
         self.write_to(writer, "tabColor");
     }
 
-    fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: &str) {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: &str) {
         let mut attributes: crate::structs::AttrCollection = Vec::new();
 
         if let Some(theme_index) = self.theme_index {
@@ -14,7 +14,11 @@ use super::{
     ConditionalFormatValueObject,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -22,6 +26,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorScale {
     cfvo_collection:  Vec<ConditionalFormatValueObject>,
     color_collection: Vec<Color>,
@@ -82,7 +87,7 @@ impl ColorScale {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -90,12 +95,12 @@ impl ColorScale {
                 match e.name().into_inner() {
                     b"cfvo" => {
                         let mut obj = ConditionalFormatValueObject::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.cfvo_collection.push(obj);
                     }
                     b"color" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.color_collection.push(obj);
                     }
                     _ => (),
@@ -103,10 +108,10 @@ impl ColorScale {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colorScale" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colorScale")
+            Event::Eof => return Err(unexpected_eof(reader, "colorScale"))
         );
     }
 
@@ -35,6 +35,7 @@ use crate::{
 /// worksheet.column_dimension_mut("A").set_width(60f64);
 /// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Column {
     col_num:             UInt32Value,
     pub(crate) width:    DoubleValue,
@@ -248,7 +249,7 @@ impl Column {
         set_string_from_xml!(self, e, best_fit, "bestFit");
 
         if let Some(v) = get_attribute(e, b"style") {
-            let style = stylesheet.style(v.parse::<usize>().unwrap());
+            let style = stylesheet.style(v.parse::<usize>().unwrap_or_default());
             self.set_style(style);
         }
     }
@@ -11,7 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::Break,
     writer::driver::{
         write_end_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnBreaks {
     break_list: Vec<Break>,
 }
@@ -64,7 +69,7 @@ impl ColumnBreaks {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -76,10 +81,10 @@ impl ColumnBreaks {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colBreaks" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colBreaks")
+            Event::Eof => return Err(unexpected_eof(reader, "colBreaks"))
         );
     }
 
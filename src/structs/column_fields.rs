@@ -11,7 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::Field,
     writer::driver::{
         write_end_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnFields {
     list: Vec<Field>,
 }
@@ -59,7 +64,7 @@ impl ColumnFields {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -71,10 +76,10 @@ impl ColumnFields {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colFields")
+            Event::Eof => return Err(unexpected_eof(reader, "colFields"))
         );
     }
 
@@ -0,0 +1,63 @@
+use crate::structs::{
+    RecordColumn,
+    Style,
+};
+
+/// The columns of a worksheet that JSON records are written to.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::{
+///     ColumnSpec,
+///     RecordColumn,
+///     Style,
+/// };
+///
+/// let mut spec = ColumnSpec::default();
+/// let mut column = RecordColumn::default();
+/// column.set_key("price").set_header("Price").set_number_format("#,##0.00");
+/// spec.add_column(column);
+/// let mut style = Style::default();
+/// style.font_mut().set_bold(true);
+/// spec.set_header_style(style);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnSpec {
+    columns:      Vec<RecordColumn>,
+    header_style: Option<Box<Style>>,
+}
+
+impl ColumnSpec {
+    /// The columns in the order they are written. When there are none, a
+    /// column is written for each key of the records.
+    #[inline]
+    #[must_use]
+    pub fn columns(&self) -> &[RecordColumn] {
+        &self.columns
+    }
+
+    #[inline]
+    pub fn add_column(&mut self, value: RecordColumn) -> &mut Self {
+        self.columns.push(value);
+        self
+    }
+
+    /// The style of the header cells.
+    #[inline]
+    #[must_use]
+    pub fn header_style(&self) -> Option<&Style> {
+        self.header_style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_header_style(&mut self, value: Style) -> &mut Self {
+        self.header_style = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_header_style(&mut self) -> &mut Self {
+        self.header_style = None;
+        self
+    }
+}
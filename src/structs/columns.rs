@@ -11,8 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        invalid_element,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -29,6 +32,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Columns {
     column: Vec<Column>,
 }
@@ -116,15 +120,19 @@ impl Columns {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         stylesheet: &Stylesheet,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"col" {
                     let mut obj = Column::default();
                     obj.set_attributes(reader, e, stylesheet);
-                    let min = get_attribute(e, b"min").unwrap().parse::<u32>().unwrap();
-                    let max = get_attribute(e, b"max").unwrap().parse::<u32>().unwrap();
+                    let min = required_attribute(reader, e, "min")?
+                        .parse::<u32>()
+                        .map_err(|v| invalid_element(reader, "col", v.to_string()))?;
+                    let max = required_attribute(reader, e, "max")?
+                        .parse::<u32>()
+                        .map_err(|v| invalid_element(reader, "col", v.to_string()))?;
                     for i in min..=max {
                         obj.set_col_num(i);
                         self.set_column(obj.clone());
@@ -133,10 +141,10 @@ impl Columns {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cols" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cols")
+            Event::Eof => return Err(unexpected_eof(reader, "cols"))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 use super::{
     Coordinate,
     CommentText,
+    RichText,
     vml::{
         Fill as VmlFill,
         Path,
@@ -28,9 +29,15 @@ use super::{
     },
 };
 use crate::{
+    XlsxError,
     helper::coordinate::CellCoordinates, reader::driver::{
+        check_coordinate,
         get_attribute,
+        invalid_attribute,
+        parse_attribute,
+        required_attribute,
         set_string_from_xml,
+        unexpected_eof,
     }, structs::vml::Shape, traits::AdjustmentCoordinate, writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -38,6 +45,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Comment {
     coordinate: Coordinate,
     author:     Box<str>,
@@ -126,6 +134,24 @@ impl Comment {
         self
     }
 
+    /// Replace the comment text with rich text.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::{
+    ///     Comment,
+    ///     RichText,
+    /// };
+    ///
+    /// let mut comment = Comment::default();
+    /// comment.set_rich_text(RichText::builder().text("Note").bold().build());
+    /// assert_eq!(comment.text().rich_text().unwrap().text(), "Note");
+    /// ```
+    #[inline]
+    pub fn set_rich_text(&mut self, value: RichText) -> &mut Self {
+        self.text.remove_text().set_rich_text(value);
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn anchor(&self) -> &Anchor {
@@ -280,15 +306,15 @@ impl Comment {
         reader: &mut Reader<R>,
         e: &BytesStart,
         authors: &[String],
-    ) {
-        let coordinate = get_attribute(e, b"ref").unwrap();
+    ) -> Result<(), XlsxError> {
+        let coordinate = required_attribute(reader, e, "ref")?;
+        let coordinate = check_coordinate(reader, e, "ref", coordinate)?;
         self.coordinate_mut().set_coordinate(coordinate);
 
-        let author_id = get_attribute(e, b"authorId")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-        let author = authors.get(author_id).unwrap();
+        let author_id: usize = parse_attribute(reader, e, "authorId")?;
+        let author = authors
+            .get(author_id)
+            .ok_or_else(|| invalid_attribute(reader, e, "authorId", &author_id.to_string()))?;
         self.set_author(author);
 
         set_string_from_xml!(self, e, id, "id");
@@ -297,15 +323,15 @@ impl Comment {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    self.text_mut().set_attributes(reader, e);
+                    self.text_mut().set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"comment" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "comment")
+            Event::Eof => return Err(unexpected_eof(reader, "comment"))
         );
     }
 
@@ -4,13 +4,16 @@ use super::RichText;
 use super::Text;
 use super::TextElement;
 use crate::writer::driver::{write_end_tag, write_start_tag};
+use crate::reader::driver::unexpected_eof;
 use crate::xml_read_loop;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use crate::XlsxError;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommentText {
     text: Option<Text>,
     rich_text: Option<RichText>,
@@ -107,8 +110,9 @@ impl CommentText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut vec_text_element: Vec<TextElement> = Vec::new();
+        let mut phonetic_runs: Vec<PhoneticRun> = Vec::new();
 
         xml_read_loop!(
             reader,
@@ -116,31 +120,37 @@ impl CommentText {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         let _ = self.set_text(obj);
                     }
                     b"r" => {
                         let mut obj = TextElement::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         vec_text_element.push(obj);
                     }
                     b"rPh" => {
-                        PhoneticRun::set_attributes(reader, e);
+                        let mut obj = PhoneticRun::default();
+                        obj.set_attributes(reader, e)?;
+                        phonetic_runs.push(obj);
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    if !vec_text_element.is_empty() {
-                        let mut obj = RichText::default();
-                        obj.set_rich_text_elements(vec_text_element);
+                    let from_text = vec_text_element.is_empty();
+                    if let Some(obj) =
+                        RichText::from_parts(self.text.as_ref(), vec_text_element, phonetic_runs)
+                    {
+                        if from_text {
+                            self.text = None;
+                        }
                         self.set_rich_text(obj);
                     }
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "text")
+            Event::Eof => return Err(unexpected_eof(reader, "text"))
         );
     }
 
@@ -0,0 +1,142 @@
+use super::{
+    Color,
+    ConditionalFormatValues,
+    ConditionalFormattingIcon,
+    Style,
+};
+
+/// A conditional formatting rule that applies to a cell.
+///
+/// Returned by
+/// [`Worksheet::conditional_format_matches`](crate::Worksheet::conditional_format_matches).
+/// Depending on the rule type it carries the differential style of the rule,
+/// the interpolated color of a color scale, the bar length of a data bar or
+/// the icon of an icon set. An expression the evaluator does not support is
+/// returned as an unsupported match without a style.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConditionalFormatMatch {
+    coordinate:       String,
+    rule_type:        ConditionalFormatValues,
+    priority:         i32,
+    style:            Option<Style>,
+    color:            Option<Color>,
+    data_bar_percent: Option<f64>,
+    icon:             Option<ConditionalFormattingIcon>,
+    icon_index:       Option<usize>,
+    unsupported:      bool,
+}
+
+impl ConditionalFormatMatch {
+    pub(crate) fn new(
+        coordinate: String,
+        rule_type: ConditionalFormatValues,
+        priority: i32,
+    ) -> Self {
+        Self {
+            coordinate,
+            rule_type,
+            priority,
+            ..Default::default()
+        }
+    }
+
+    /// Get the coordinate of the cell. ex) "A1"
+    #[inline]
+    #[must_use]
+    pub fn coordinate(&self) -> &str {
+        &self.coordinate
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rule_type(&self) -> &ConditionalFormatValues {
+        &self.rule_type
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Get the differential style of the rule.
+    #[inline]
+    #[must_use]
+    pub fn style(&self) -> Option<&Style> {
+        self.style.as_ref()
+    }
+
+    #[inline]
+    pub(crate) fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Some(value);
+        self
+    }
+
+    /// Get the fill color of a color scale.
+    #[inline]
+    #[must_use]
+    pub fn color(&self) -> Option<&Color> {
+        self.color.as_ref()
+    }
+
+    #[inline]
+    pub(crate) fn set_color(&mut self, value: Color) -> &mut Self {
+        self.color = Some(value);
+        self
+    }
+
+    /// Get the length of a data bar as a percentage of the cell width.
+    /// The length lies between the minimum and maximum length of the bar.
+    #[inline]
+    #[must_use]
+    pub fn data_bar_percent(&self) -> Option<f64> {
+        self.data_bar_percent
+    }
+
+    #[inline]
+    pub(crate) fn set_data_bar_percent(&mut self, value: f64) -> &mut Self {
+        self.data_bar_percent = Some(value);
+        self
+    }
+
+    /// Get the icon of an icon set.
+    /// Custom icons are returned in place of the icons of the set and follow
+    /// the icon order as well.
+    #[inline]
+    #[must_use]
+    pub fn icon(&self) -> Option<&ConditionalFormattingIcon> {
+        self.icon.as_ref()
+    }
+
+    /// Get the zero-based index of the icon of an icon set.
+    /// This is the threshold the value reached, counted in reverse when the
+    /// icon order is reversed.
+    #[inline]
+    #[must_use]
+    pub fn icon_index(&self) -> Option<usize> {
+        self.icon_index
+    }
+
+    #[inline]
+    pub(crate) fn set_icon(&mut self, value: ConditionalFormattingIcon, index: usize) -> &mut Self {
+        self.icon = Some(value);
+        self.icon_index = Some(index);
+        self
+    }
+
+    /// Get whether the rule could not be evaluated.
+    /// Expressions using functions or references the evaluator does not
+    /// support are reported this way instead of as a non-matching rule.
+    #[inline]
+    #[must_use]
+    pub fn is_unsupported(&self) -> bool {
+        self.unsupported
+    }
+
+    #[inline]
+    pub(crate) fn set_unsupported(&mut self, value: bool) -> &mut Self {
+        self.unsupported = value;
+        self
+    }
+}
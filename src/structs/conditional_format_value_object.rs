@@ -10,23 +10,34 @@ use quick_xml::{
 };
 
 use super::{
+    BooleanValue,
     ConditionalFormatValueObjectValues,
     EnumValue,
     StringValue,
 };
 use crate::{
+    XlsxError,
+    helper::utils::unescape_xml_text,
     reader::driver::{
         get_attribute,
+        invalid_xml,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
-    writer::driver::write_start_tag,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+        write_text_node,
+    },
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionalFormatValueObject {
     r#type: EnumValue<ConditionalFormatValueObjectValues>,
     val:    StringValue,
+    gte:    BooleanValue,
 }
 
 impl ConditionalFormatValueObject {
@@ -61,34 +72,89 @@ impl ConditionalFormatValueObject {
         self
     }
 
+    /// Get whether the threshold of an icon set includes its value
+    /// (greater than or equal) instead of excluding it (greater than).
+    /// Defaults to `true` when not set.
+    #[inline]
+    #[must_use]
+    pub fn gte(&self) -> bool {
+        !self.gte.has_value() || self.gte.value()
+    }
+
+    #[inline]
+    pub fn set_gte(&mut self, value: bool) -> &mut Self {
+        self.gte.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, gte, "gte");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cfvo" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cfvo")
+            Event::Eof => return Err(unexpected_eof(reader, "cfvo"))
+        );
+    }
+
+    /// Read an `x14:cfvo`, whose value is held in an `xm:f` element.
+    pub(crate) fn set_x14_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, gte, "gte");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"xm:f" {
+                    let mut buf = Vec::new();
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    self.val.set_value(unescape_xml_text(&text));
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:cfvo" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "x14:cfvo"))
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // cfvo
         let mut attributes: crate::structs::AttrCollection = Vec::new();
-        let ctype = self.r#type.value_string();
+        // The automatic minimum and maximum are unknown outside the extension.
+        let ctype = match self.r#type.value() {
+            ConditionalFormatValueObjectValues::AutoMin => "min",
+            ConditionalFormatValueObjectValues::AutoMax => "max",
+            _ => self.r#type.value_string(),
+        };
         if self.r#type.has_value() {
             attributes.push(("type", ctype).into());
         }
@@ -96,7 +162,34 @@ impl ConditionalFormatValueObject {
         if self.val.has_value() {
             attributes.push(("val", val).into());
         }
+        if self.gte.has_value() {
+            attributes.push(("gte", self.gte.value_string()).into());
+        }
 
         write_start_tag(writer, "cfvo", attributes, true);
     }
+
+    pub(crate) fn write_x14_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:cfvo
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.r#type.has_value() {
+            attributes.push(("type", self.r#type.value_string()).into());
+        }
+        if self.gte.has_value() {
+            attributes.push(("gte", self.gte.value_string()).into());
+        }
+
+        if !self.val.has_value() {
+            write_start_tag(writer, "x14:cfvo", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "x14:cfvo", attributes, false);
+
+        // xm:f
+        write_start_tag(writer, "xm:f", vec![], false);
+        write_text_node(writer, self.val.value_str());
+        write_end_tag(writer, "xm:f");
+
+        write_end_tag(writer, "x14:cfvo");
+    }
 }
@@ -2,7 +2,10 @@ use std::str::FromStr;
 
 use super::EnumTrait;
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConditionalFormatValueObjectValues {
+    AutoMax,
+    AutoMin,
     Formula,
     Max,
     Min,
@@ -20,6 +23,8 @@ impl EnumTrait for ConditionalFormatValueObjectValues {
     #[inline]
     fn value_string(&self) -> &str {
         match &self {
+            Self::AutoMax => "autoMax",
+            Self::AutoMin => "autoMin",
             Self::Formula => "formula",
             Self::Max => "max",
             Self::Min => "min",
@@ -35,6 +40,8 @@ impl FromStr for ConditionalFormatValueObjectValues {
     #[inline]
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
+            "autoMax" => Ok(Self::AutoMax),
+            "autoMin" => Ok(Self::AutoMin),
             "formula" => Ok(Self::Formula),
             "max" => Ok(Self::Max),
             "min" => Ok(Self::Min),
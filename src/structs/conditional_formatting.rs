@@ -15,18 +15,27 @@ use super::{
     SequenceOfReferences,
 };
 use crate::{
+    XlsxError,
+    helper::{
+        const_str::EXCEL_MAIN_NS,
+        utils::unescape_xml_text,
+    },
     reader::driver::{
         get_attribute,
+        invalid_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     traits::AdjustmentCoordinate,
     writer::driver::{
         write_end_tag,
         write_start_tag,
+        write_text_node,
     },
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionalFormatting {
     sequence_of_references: SequenceOfReferences,
     conditional_collection: Vec<ConditionalFormattingRule>,
@@ -107,7 +116,7 @@ impl ConditionalFormatting {
         reader: &mut Reader<R>,
         e: &BytesStart,
         differential_formats: &DifferentialFormats,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"sqref") {
             self.sequence_of_references.set_sqref(v);
         }
@@ -118,24 +127,81 @@ impl ConditionalFormatting {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"cfRule" {
                     let mut obj = ConditionalFormattingRule::default();
-                    obj.set_attributes(reader, e, differential_formats, is_empty);
+                    obj.set_attributes(reader, e, differential_formats, is_empty)?;
                     self.conditional_collection.push(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"conditionalFormatting" {
-                    return
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "conditionalFormatting"))
+        );
+    }
+
+    /// Read an `x14:conditionalFormatting`.
+    pub(crate) fn set_x14_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
+                let is_empty = matches!(n, Event::Empty(_));
+                match e.name().into_inner() {
+                    b"x14:cfRule" => {
+                        let mut obj = ConditionalFormattingRule::default();
+                        obj.set_x14_attributes(reader, e, is_empty)?;
+                        self.conditional_collection.push(obj);
+                    }
+                    b"xm:sqref" if !is_empty => {
+                        let mut buf = Vec::new();
+                        let text = reader
+                            .read_text_into(e.name(), &mut buf)
+                            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                        self.sequence_of_references.set_sqref(unescape_xml_text(&text));
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:conditionalFormatting" {
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "conditionalFormatting")
+            Event::Eof => return Err(unexpected_eof(reader, "x14:conditionalFormatting"))
         );
     }
 
+    /// Whether any rule is written to the Excel 2010 extension.
+    #[inline]
+    pub(crate) fn has_x14(&self) -> bool {
+        self.conditional_collection
+            .iter()
+            .any(ConditionalFormattingRule::has_x14)
+    }
+
+    /// # Arguments
+    /// * `x14_index` - Number of rules written to the Excel 2010 extension so
+    ///   far in the worksheet. Used to make the ids of the rules.
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
         differential_formats: &mut DifferentialFormats,
+        x14_index: &mut usize,
     ) {
+        let is_x14_only = !self.conditional_collection.is_empty()
+            && self
+                .conditional_collection
+                .iter()
+                .all(ConditionalFormattingRule::is_x14_only);
+        if is_x14_only {
+            *x14_index += self.conditional_collection.len();
+            return;
+        }
+
         let is_inner = !self.conditional_collection.is_empty();
 
         // conditionalFormatting
@@ -149,12 +215,50 @@ impl ConditionalFormatting {
         if is_inner {
             // cfRule
             for v in &self.conditional_collection {
-                v.write_to(writer, differential_formats);
+                let x14_id = v.has_x14().then(|| {
+                    *x14_index += 1;
+                    v.x14_id(*x14_index - 1)
+                });
+                if !v.is_x14_only() {
+                    v.write_to(writer, differential_formats, x14_id.as_deref());
+                }
             }
 
             write_end_tag(writer, "conditionalFormatting");
         }
     }
+
+    /// Write the rules kept in the Excel 2010 extension.
+    /// # Arguments
+    /// * `x14_index` - Same as [`write_to`](Self::write_to).
+    pub(crate) fn write_x14_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, x14_index: &mut usize) {
+        if !self.has_x14() {
+            return;
+        }
+
+        // x14:conditionalFormatting
+        write_start_tag(
+            writer,
+            "x14:conditionalFormatting",
+            vec![("xmlns:xm", EXCEL_MAIN_NS).into()],
+            false,
+        );
+
+        // x14:cfRule
+        for v in &self.conditional_collection {
+            if v.has_x14() {
+                v.write_x14_to(writer, &v.x14_id(*x14_index));
+                *x14_index += 1;
+            }
+        }
+
+        // xm:sqref
+        write_start_tag(writer, "xm:sqref", vec![], false);
+        write_text_node(writer, self.sequence_of_references.get_sqref());
+        write_end_tag(writer, "xm:sqref");
+
+        write_end_tag(writer, "x14:conditionalFormatting");
+    }
 }
 impl AdjustmentCoordinate for ConditionalFormatting {
     #[inline]
@@ -171,6 +275,14 @@ impl AdjustmentCoordinate for ConditionalFormatting {
             root_row_num,
             offset_row_num,
         );
+        for rule in &mut self.conditional_collection {
+            rule.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     #[inline]
@@ -181,12 +293,19 @@ impl AdjustmentCoordinate for ConditionalFormatting {
         root_row_num: u32,
         offset_row_num: u32,
     ) {
-        self.sequence_of_references.adjustment_remove_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        );
+        self.sequence_of_references
+            .range_collection_mut()
+            .retain_mut(|range| {
+                range.shrink_for_remove(root_col_num, offset_col_num, root_row_num, offset_row_num)
+            });
+        for rule in &mut self.conditional_collection {
+            rule.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     #[inline]
@@ -197,11 +316,15 @@ impl AdjustmentCoordinate for ConditionalFormatting {
         root_row_num: u32,
         offset_row_num: u32,
     ) -> bool {
-        self.sequence_of_references.is_remove_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        )
+        let range_collection = self.sequence_of_references.range_collection();
+        !range_collection.is_empty()
+            && range_collection.iter().all(|range| {
+                !range.clone().shrink_for_remove(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                )
+            })
     }
 }
@@ -0,0 +1,87 @@
+// x14:cfIcon
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    EnumValue,
+    IconSetValues,
+    UInt32Value,
+};
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+/// A custom icon of an icon set threshold.
+/// The icon is picked from any icon set by its position in that set.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConditionalFormattingIcon {
+    icon_set: EnumValue<IconSetValues>,
+    icon_id:  UInt32Value,
+}
+
+impl ConditionalFormattingIcon {
+    /// Create a new icon.
+    /// # Arguments
+    /// * `icon_set` - Icon set the icon is taken from.
+    /// * `icon_id` - Zero-based position of the icon in the set.
+    #[must_use]
+    pub fn new(icon_set: IconSetValues, icon_id: u32) -> Self {
+        let mut obj = Self::default();
+        obj.set_icon_set(icon_set).set_icon_id(icon_id);
+        obj
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn icon_set(&self) -> &IconSetValues {
+        self.icon_set.value()
+    }
+
+    #[inline]
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn icon_id(&self) -> u32 {
+        self.icon_id.value()
+    }
+
+    #[inline]
+    pub fn set_icon_id(&mut self, value: u32) -> &mut Self {
+        self.icon_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, icon_id, "iconId");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:cfIcon
+        let icon_id = self.icon_id.value_string();
+        write_start_tag(
+            writer,
+            "x14:cfIcon",
+            vec![
+                ("iconSet", self.icon_set.value_string()).into(),
+                ("iconId", icon_id.as_str()).into(),
+            ],
+            true,
+        );
+    }
+}
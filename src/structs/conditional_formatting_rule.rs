@@ -26,18 +26,27 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
+    helper::{
+        const_str::SHEET_MS_MAIN_NS,
+        utils::unescape_xml_text,
+    },
     reader::driver::{
         get_attribute,
+        invalid_xml,
         set_string_from_xml,
         xml_read_loop,
     },
+    traits::AdjustmentCoordinate,
     writer::driver::{
         write_end_tag,
         write_start_tag,
+        write_text_node,
     },
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionalFormattingRule {
     r#type:        EnumValue<ConditionalFormatValues>,
     operator:      EnumValue<ConditionalFormattingOperatorValues>,
@@ -56,6 +65,8 @@ pub struct ConditionalFormattingRule {
     data_bar:      Option<DataBar>,
     icon_set:      Option<IconSet>,
     formula:       Option<Box<Formula>>,
+    formula2:      Option<Box<Formula>>,
+    id:            StringValue,
 }
 
 impl ConditionalFormattingRule {
@@ -223,10 +234,12 @@ impl ConditionalFormattingRule {
         self
     }
 
+    /// Get whether the rule matches values above the average.
+    /// Defaults to `true` when not set.
     #[inline]
     #[must_use]
     pub fn above_average(&self) -> bool {
-        self.above_average.value()
+        !self.above_average.has_value() || self.above_average.value()
     }
 
     #[inline]
@@ -405,13 +418,106 @@ impl ConditionalFormattingRule {
         self
     }
 
+    /// Get the second formula.
+    /// Used as the upper bound of the `between` and `notBetween` operators.
+    #[inline]
+    #[must_use]
+    pub fn formula2(&self) -> Option<&Formula> {
+        self.formula2.as_deref()
+    }
+
+    #[inline]
+    pub fn set_formula2(&mut self, value: Formula) -> &mut Self {
+        self.formula2 = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula2(&mut self) -> &mut Self {
+        self.formula2 = None;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Replace the sheet name of the references to a renamed sheet.
+    pub(crate) fn rename_sheet_reference(&mut self, old_name: &str, new_name: &str) {
+        for formula in [&mut self.formula, &mut self.formula2].into_iter().flatten() {
+            formula.rename_sheet_reference(old_name, new_name);
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Turn the references to a removed sheet into `#REF!`.
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        for formula in [&mut self.formula, &mut self.formula2].into_iter().flatten() {
+            formula.remove_sheet_reference(sheet_name);
+        }
+    }
+
+    /// Get the id linking the rule to its Excel 2010 extension.
+    /// ex) "{8E4F6C2A-3B0D-4E5F-9A1B-2C3D4E5F6A7B}"
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        self.id.value_str()
+    }
+
+    /// Set the id linking the rule to its Excel 2010 extension.
+    /// An id is generated on writing when the rule needs one and has none.
+    #[inline]
+    pub fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    /// Whether the rule is written to the Excel 2010 extension.
+    pub(crate) fn has_x14(&self) -> bool {
+        self.data_bar
+            .as_ref()
+            .is_some_and(|v| v.has_extension() || self.id.has_value())
+            || self
+                .icon_set
+                .as_ref()
+                .is_some_and(IconSet::is_x14_only)
+    }
+
+    /// Whether the rule is written to the Excel 2010 extension only.
+    #[inline]
+    pub(crate) fn is_x14_only(&self) -> bool {
+        self.icon_set
+            .as_ref()
+            .is_some_and(IconSet::is_x14_only)
+    }
+
+    /// Get the id of the rule, or one made from `index` when it has none.
+    pub(crate) fn x14_id(&self, index: usize) -> String {
+        if self.id.has_value() {
+            return self.id.value_str().to_string();
+        }
+        format!("{{00000000-0000-4000-8000-{:012X}}}", index + 1)
+    }
+
+    /// Take over the extension data of the same rule.
+    pub(crate) fn merge_x14(&mut self, x14: Self) {
+        if let Some(v) = x14.icon_set {
+            self.icon_set = Some(v);
+        }
+        let Some(x14_bar) = x14.data_bar else {
+            return;
+        };
+        match &mut self.data_bar {
+            Some(data_bar) => data_bar.merge_x14(x14_bar),
+            None => self.data_bar = Some(x14_bar),
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         differential_formats: &DifferentialFormats,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, operator, "operator");
 
@@ -433,42 +539,53 @@ impl ConditionalFormattingRule {
         set_string_from_xml!(self, e, equal_average, "equalAverage");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
+                    b"x14:id" => {
+                        let mut buf = Vec::new();
+                        let text = reader
+                            .read_text_into(e.name(), &mut buf)
+                            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                        self.id.set_value(unescape_xml_text(&text));
+                    }
                     b"colorScale" => {
                         let mut obj = ColorScale::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.color_scale = Some(obj);
                     }
                     b"dataBar" => {
                         let mut obj = DataBar::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.data_bar = Some(obj);
                     }
                     b"iconSet" => {
                         let mut obj = IconSet::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.icon_set = Some(obj);
                     }
                     b"formula" => {
                         let mut obj = Formula::default();
-                        obj.set_attributes(reader, e);
-                        self.formula = Some(Box::new(obj));
+                        obj.set_attributes(reader, e)?;
+                        if self.formula.is_none() {
+                            self.formula = Some(Box::new(obj));
+                        } else {
+                            self.formula2 = Some(Box::new(obj));
+                        }
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cfRule" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => return
+            Event::Eof => return Ok(())
         );
     }
 
@@ -476,11 +593,14 @@ impl ConditionalFormattingRule {
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
         differential_formats: &mut DifferentialFormats,
+        x14_id: Option<&str>,
     ) {
         let is_inner = self.color_scale.is_some()
             || self.data_bar.is_some()
             || self.icon_set.is_some()
-            || self.formula.is_some();
+            || self.formula.is_some()
+            || self.formula2.is_some()
+            || x14_id.is_some();
 
         // cfRule
         let mut attributes: crate::structs::AttrCollection = Vec::new();
@@ -569,8 +689,130 @@ impl ConditionalFormattingRule {
             if let Some(v) = &self.formula {
                 v.write_to(writer);
             }
+            if let Some(v) = &self.formula2 {
+                v.write_to(writer);
+            }
+
+            // extLst
+            if let Some(id) = x14_id {
+                write_start_tag(writer, "extLst", vec![], false);
+                write_start_tag(
+                    writer,
+                    "ext",
+                    vec![
+                        ("uri", "{B025F937-C7B1-47D3-B67F-A62EFF666E3E}").into(),
+                        ("xmlns:x14", SHEET_MS_MAIN_NS).into(),
+                    ],
+                    false,
+                );
+                write_start_tag(writer, "x14:id", vec![], false);
+                write_text_node(writer, id);
+                write_end_tag(writer, "x14:id");
+                write_end_tag(writer, "ext");
+                write_end_tag(writer, "extLst");
+            }
 
             write_end_tag(writer, "cfRule");
         }
     }
+
+    /// Read an `x14:cfRule`.
+    pub(crate) fn set_x14_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, priority, "priority");
+        set_string_from_xml!(self, e, id, "id");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"x14:dataBar" => {
+                        let mut obj = DataBar::default();
+                        obj.set_x14_attributes(reader, e)?;
+                        self.data_bar = Some(obj);
+                    }
+                    b"x14:iconSet" => {
+                        let mut obj = IconSet::default();
+                        obj.set_x14_attributes(reader, e)?;
+                        self.icon_set = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:cfRule" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Ok(())
+        );
+    }
+
+    pub(crate) fn write_x14_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, id: &str) {
+        // x14:cfRule
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("type", self.r#type.value_string()).into());
+        let priority = self.priority.value_string();
+        if self.is_x14_only() && self.priority.has_value() {
+            attributes.push(("priority", &priority).into());
+        }
+        attributes.push(("id", id).into());
+        write_start_tag(writer, "x14:cfRule", attributes, false);
+
+        // x14:dataBar
+        if let Some(v) = &self.data_bar {
+            v.write_x14_to(writer);
+        }
+
+        // x14:iconSet
+        if let Some(v) = &self.icon_set {
+            v.write_x14_to(writer);
+        }
+
+        write_end_tag(writer, "x14:cfRule");
+    }
+}
+impl AdjustmentCoordinate for ConditionalFormattingRule {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        for formula in [&mut self.formula, &mut self.formula2].into_iter().flatten() {
+            formula.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        for formula in [&mut self.formula, &mut self.formula2].into_iter().flatten() {
+            formula.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
 }
@@ -0,0 +1,383 @@
+// controlPr
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    MediaObject,
+    ObjectAnchor,
+    StringValue,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    structs::raw::RawRelationships,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// Properties shared by form controls and `ActiveX` controls.
+/// Holds the anchor of the control and the macro assigned to it.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ControlProperties {
+    locked:           BooleanValue,
+    default_size:     BooleanValue,
+    print:            BooleanValue,
+    disabled:         BooleanValue,
+    recalc_always:    BooleanValue,
+    ui_object:        BooleanValue,
+    auto_fill:        BooleanValue,
+    auto_line:        BooleanValue,
+    auto_pict:        BooleanValue,
+    macro_name:       StringValue,
+    alternative_text: StringValue,
+    linked_cell:      StringValue,
+    list_fill_range:  StringValue,
+    image:            Option<MediaObject>,
+    object_anchor:    ObjectAnchor,
+}
+
+impl ControlProperties {
+    #[inline]
+    #[must_use]
+    pub fn locked(&self) -> bool {
+        self.locked.value()
+    }
+
+    #[inline]
+    pub fn set_locked(&mut self, value: bool) -> &mut Self {
+        self.locked.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn default_size(&self) -> bool {
+        self.default_size.value()
+    }
+
+    #[inline]
+    pub fn set_default_size(&mut self, value: bool) -> &mut Self {
+        self.default_size.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn print(&self) -> bool {
+        self.print.value()
+    }
+
+    #[inline]
+    pub fn set_print(&mut self, value: bool) -> &mut Self {
+        self.print.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn disabled(&self) -> bool {
+        self.disabled.value()
+    }
+
+    #[inline]
+    pub fn set_disabled(&mut self, value: bool) -> &mut Self {
+        self.disabled.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn recalc_always(&self) -> bool {
+        self.recalc_always.value()
+    }
+
+    #[inline]
+    pub fn set_recalc_always(&mut self, value: bool) -> &mut Self {
+        self.recalc_always.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ui_object(&self) -> bool {
+        self.ui_object.value()
+    }
+
+    #[inline]
+    pub fn set_ui_object(&mut self, value: bool) -> &mut Self {
+        self.ui_object.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn auto_fill(&self) -> bool {
+        self.auto_fill.value()
+    }
+
+    #[inline]
+    pub fn set_auto_fill(&mut self, value: bool) -> &mut Self {
+        self.auto_fill.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn auto_line(&self) -> bool {
+        self.auto_line.value()
+    }
+
+    #[inline]
+    pub fn set_auto_line(&mut self, value: bool) -> &mut Self {
+        self.auto_line.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn auto_pict(&self) -> bool {
+        self.auto_pict.value()
+    }
+
+    #[inline]
+    pub fn set_auto_pict(&mut self, value: bool) -> &mut Self {
+        self.auto_pict.set_value(value);
+        self
+    }
+
+    /// Get the name of the macro run when the control is clicked. ex) "[0]!Macro1"
+    #[inline]
+    #[must_use]
+    pub fn macro_name(&self) -> &str {
+        self.macro_name.value_str()
+    }
+
+    #[inline]
+    pub fn set_macro_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.macro_name.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_macro_name(&mut self) -> &mut Self {
+        self.macro_name.remove_value();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn alternative_text(&self) -> &str {
+        self.alternative_text.value_str()
+    }
+
+    #[inline]
+    pub fn set_alternative_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.alternative_text.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_alternative_text(&mut self) -> &mut Self {
+        self.alternative_text.remove_value();
+        self
+    }
+
+    /// Get the cell linked to the value of an `ActiveX` control.
+    #[inline]
+    #[must_use]
+    pub fn linked_cell(&self) -> &str {
+        self.linked_cell.value_str()
+    }
+
+    #[inline]
+    pub fn set_linked_cell<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.linked_cell.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_linked_cell(&mut self) -> &mut Self {
+        self.linked_cell.remove_value();
+        self
+    }
+
+    /// Get the range that fills the list of an `ActiveX` control.
+    #[inline]
+    #[must_use]
+    pub fn list_fill_range(&self) -> &str {
+        self.list_fill_range.value_str()
+    }
+
+    #[inline]
+    pub fn set_list_fill_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.list_fill_range.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_list_fill_range(&mut self) -> &mut Self {
+        self.list_fill_range.remove_value();
+        self
+    }
+
+    /// Get the image displayed in place of the control.
+    #[inline]
+    #[must_use]
+    pub fn image(&self) -> Option<&MediaObject> {
+        self.image.as_ref()
+    }
+
+    #[inline]
+    pub fn image_mut(&mut self) -> Option<&mut MediaObject> {
+        self.image.as_mut()
+    }
+
+    #[inline]
+    pub fn set_image(&mut self, value: MediaObject) -> &mut Self {
+        self.image = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_image(&mut self) -> &mut Self {
+        self.image = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn object_anchor(&self) -> &ObjectAnchor {
+        &self.object_anchor
+    }
+
+    #[inline]
+    pub fn object_anchor_mut(&mut self) -> &mut ObjectAnchor {
+        &mut self.object_anchor
+    }
+
+    #[inline]
+    pub fn set_object_anchor(&mut self, value: ObjectAnchor) -> &mut Self {
+        self.object_anchor = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        relationships: &RawRelationships,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, locked, "locked");
+        set_string_from_xml!(self, e, default_size, "defaultSize");
+        set_string_from_xml!(self, e, print, "print");
+        set_string_from_xml!(self, e, disabled, "disabled");
+        set_string_from_xml!(self, e, recalc_always, "recalcAlways");
+        set_string_from_xml!(self, e, ui_object, "uiObject");
+        set_string_from_xml!(self, e, auto_fill, "autoFill");
+        set_string_from_xml!(self, e, auto_line, "autoLine");
+        set_string_from_xml!(self, e, auto_pict, "autoPict");
+        set_string_from_xml!(self, e, macro_name, "macro");
+        set_string_from_xml!(self, e, alternative_text, "altText");
+        set_string_from_xml!(self, e, linked_cell, "linkedCell");
+        set_string_from_xml!(self, e, list_fill_range, "listFillRange");
+
+        if let Some(r_id) = get_attribute(e, b"r:id") {
+            let attached_file = relationships.relationship_by_rid(&r_id)?.raw_file();
+            let mut obj = MediaObject::default();
+            obj.set_image_name(attached_file.file_name());
+            obj.set_image_data(attached_file.file_data());
+            self.set_image(obj);
+        }
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"anchor" {
+                    self.object_anchor.set_attributes(reader, e)?;
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"controlPr" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "controlPr"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: usize) {
+        // controlPr
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.locked.has_value() {
+            attributes.push(("locked", self.locked.value_string()).into());
+        }
+        if self.default_size.has_value() {
+            attributes.push(("defaultSize", self.default_size.value_string()).into());
+        }
+        if self.print.has_value() {
+            attributes.push(("print", self.print.value_string()).into());
+        }
+        if self.disabled.has_value() {
+            attributes.push(("disabled", self.disabled.value_string()).into());
+        }
+        if self.recalc_always.has_value() {
+            attributes.push(("recalcAlways", self.recalc_always.value_string()).into());
+        }
+        if self.ui_object.has_value() {
+            attributes.push(("uiObject", self.ui_object.value_string()).into());
+        }
+        if self.auto_fill.has_value() {
+            attributes.push(("autoFill", self.auto_fill.value_string()).into());
+        }
+        if self.auto_line.has_value() {
+            attributes.push(("autoLine", self.auto_line.value_string()).into());
+        }
+        if self.auto_pict.has_value() {
+            attributes.push(("autoPict", self.auto_pict.value_string()).into());
+        }
+        if self.macro_name.has_value() {
+            attributes.push(("macro", self.macro_name.value_str()).into());
+        }
+        if self.alternative_text.has_value() {
+            attributes.push(("altText", self.alternative_text.value_str()).into());
+        }
+        if self.linked_cell.has_value() {
+            attributes.push(("linkedCell", self.linked_cell.value_str()).into());
+        }
+        if self.list_fill_range.has_value() {
+            attributes.push(("listFillRange", self.list_fill_range.value_str()).into());
+        }
+        let r_id_str = format!("rId{r_id}");
+        if self.image.is_some() {
+            attributes.push(("r:id", &r_id_str).into());
+        }
+        write_start_tag(writer, "controlPr", attributes, false);
+
+        // anchor
+        self.object_anchor.write_to(writer);
+
+        write_end_tag(writer, "controlPr");
+    }
+}
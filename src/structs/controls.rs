@@ -0,0 +1,194 @@
+// controls
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    ActiveXControl,
+    FormControl,
+};
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        ACTIVEX_BIN_NS,
+        ACTIVEX_NS,
+        CTRL_PROP_NS,
+    },
+    reader::driver::{
+        invalid_xml,
+        required_attribute,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    structs::raw::RawWorksheet,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Controls {
+    form_control:     Vec<FormControl>,
+    active_x_control: Vec<ActiveXControl>,
+}
+
+impl Controls {
+    #[inline]
+    #[must_use]
+    pub fn form_control(&self) -> &[FormControl] {
+        &self.form_control
+    }
+
+    #[inline]
+    pub fn form_control_mut(&mut self) -> &mut Vec<FormControl> {
+        &mut self.form_control
+    }
+
+    #[inline]
+    pub fn add_form_control(&mut self, value: FormControl) -> &mut Self {
+        self.form_control.push(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn active_x_control(&self) -> &[ActiveXControl] {
+        &self.active_x_control
+    }
+
+    #[inline]
+    pub fn active_x_control_mut(&mut self) -> &mut Vec<ActiveXControl> {
+        &mut self.active_x_control
+    }
+
+    #[inline]
+    pub fn add_active_x_control(&mut self, value: ActiveXControl) -> &mut Self {
+        self.active_x_control.push(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.form_control.is_empty() && self.active_x_control.is_empty()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        raw_worksheet: &RawWorksheet,
+    ) -> Result<(), XlsxError> {
+        let mut is_fallback = false;
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"mc:Fallback" => is_fallback = true,
+                    b"control" if !is_fallback => {
+                        self.add_control(reader, e, raw_worksheet, false)?;
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"control" && !is_fallback {
+                    self.add_control(reader, e, raw_worksheet, true)?;
+                }
+            },
+            Event::End(ref e) => {
+                match e.name().into_inner() {
+                    b"mc:Fallback" => is_fallback = false,
+                    b"controls" => return Ok(()),
+                    _ => (),
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "controls"))
+        );
+    }
+
+    fn add_control<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        raw_worksheet: &RawWorksheet,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        let relationships = raw_worksheet.required_worksheet_relationships()?;
+        let r_id = required_attribute(reader, e, "r:id")?;
+        let relationship = relationships.relationship_by_rid(&r_id)?;
+
+        match relationship.get_type() {
+            CTRL_PROP_NS => {
+                let mut obj = FormControl::default();
+                obj.set_attributes(reader, e, relationships, empty_flag)?;
+                obj.set_form_control_properties(relationship.raw_file().file_data())?;
+                self.add_form_control(obj);
+            }
+            ACTIVEX_NS => {
+                let mut obj = ActiveXControl::default();
+                obj.set_attributes(reader, e, relationships, empty_flag)?;
+                obj.set_control_data(relationship.raw_file().file_data());
+                let binary = raw_worksheet
+                    .relationships_of(relationship.raw_file())
+                    .and_then(|v| {
+                        v.relationship_list()
+                            .iter()
+                            .find(|v| v.get_type() == ACTIVEX_BIN_NS)
+                    });
+                if let Some(v) = binary {
+                    obj.set_binary_data(v.raw_file().file_data(), v.id());
+                }
+                self.add_active_x_control(obj);
+            }
+            _ => {
+                if !empty_flag {
+                    let mut buf = Vec::new();
+                    reader
+                        .read_to_end_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        r_id: usize,
+        shape_id: usize,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+
+        // controls
+        write_start_tag(writer, "controls", vec![], false);
+
+        let mut r = r_id;
+        let mut s = shape_id;
+        for obj in &self.form_control {
+            obj.write_to(writer, r, s);
+            r += 1 + usize::from(obj.control_properties().image().is_some());
+            s += 1;
+        }
+        for obj in &self.active_x_control {
+            obj.write_to(writer, r, s);
+            r += 1 + usize::from(obj.control_properties().image().is_some());
+            s += 1;
+        }
+
+        write_end_tag(writer, "controls");
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate {
     column: ColumnReference,
     row:    RowReference,
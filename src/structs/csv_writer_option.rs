@@ -2,6 +2,7 @@ use super::EnumValue;
 use crate::structs::CsvEncodeValues;
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CsvWriterOption {
     pub(crate) csv_encode_values: EnumValue<CsvEncodeValues>,
     pub(crate) wrap_with_char:    Box<str>,
@@ -0,0 +1,84 @@
+// customFilter
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    EnumValue,
+    FilterOperatorValues,
+    StringValue,
+};
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomFilter {
+    operator: EnumValue<FilterOperatorValues>,
+    val:      StringValue,
+}
+
+impl CustomFilter {
+    #[inline]
+    #[must_use]
+    pub fn new<S: Into<String>>(operator: FilterOperatorValues, val: S) -> Self {
+        let mut obj = Self::default();
+        obj.set_operator(operator).set_val(val);
+        obj
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn operator(&self) -> &FilterOperatorValues {
+        self.operator.value()
+    }
+
+    #[inline]
+    pub fn set_operator(&mut self, value: FilterOperatorValues) -> &mut Self {
+        self.operator.set_value(value);
+        self
+    }
+
+    /// Get the value to compare with. `*` and `?` are wildcards.
+    #[inline]
+    #[must_use]
+    pub fn val(&self) -> &str {
+        self.val.value_str()
+    }
+
+    #[inline]
+    pub fn set_val<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, operator, "operator");
+        set_string_from_xml!(self, e, val, "val");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customFilter
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.operator.has_value() {
+            attributes.push(("operator", self.operator.value_string()).into());
+        }
+        attributes.push(("val", self.val.value_str()).into());
+        write_start_tag(writer, "customFilter", attributes, true);
+    }
+}
@@ -0,0 +1,115 @@
+// customFilters
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    CustomFilter,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// One or two conditions on the values of a column.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomFilters {
+    and:           BooleanValue,
+    custom_filter: Vec<CustomFilter>,
+}
+
+impl CustomFilters {
+    /// Whether both conditions must be met. Otherwise either one is enough.
+    #[inline]
+    #[must_use]
+    pub fn and(&self) -> bool {
+        self.and.value()
+    }
+
+    #[inline]
+    pub fn set_and(&mut self, value: bool) -> &mut Self {
+        self.and.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn custom_filter(&self) -> &[CustomFilter] {
+        &self.custom_filter
+    }
+
+    #[inline]
+    pub fn custom_filter_mut(&mut self) -> &mut Vec<CustomFilter> {
+        &mut self.custom_filter
+    }
+
+    #[inline]
+    pub fn add_custom_filter(&mut self, value: CustomFilter) -> &mut Self {
+        self.custom_filter.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, and, "and");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"customFilter" {
+                    let mut obj = CustomFilter::default();
+                    obj.set_attributes(reader, e);
+                    self.custom_filter.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customFilters" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "customFilters"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customFilters
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.and.value() {
+            attributes.push(("and", self.and.value_string()).into());
+        }
+        write_start_tag(writer, "customFilters", attributes, false);
+
+        // customFilter
+        for custom_filter in &self.custom_filter {
+            custom_filter.write_to(writer);
+        }
+
+        write_end_tag(writer, "customFilters");
+    }
+}
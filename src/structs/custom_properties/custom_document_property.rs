@@ -13,9 +13,13 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        invalid_element,
+        invalid_xml,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -30,6 +34,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CustomDocumentProperty {
     name:                           StringValue,
     link_target:                    StringValue,
@@ -156,12 +161,12 @@ impl CustomDocumentProperty {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, link_target, "linkTarget");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -171,28 +176,39 @@ impl CustomDocumentProperty {
                     b"vt:lpwstr" =>{
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap()
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?
                         );
                         self.set_value_string(text);
                     }
                     b"vt:filetime" =>{
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap()
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?
                         );
                         self.set_value_date_manual(text);
                     }
                     b"vt:i4"=> {
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap()
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?
+                        );
+                        self.set_value_number(
+                            text.parse::<i32>()
+                                .map_err(|v| invalid_element(reader, "vt:i4", v.to_string()))?,
                         );
-                        self.set_value_number(text.parse::<i32>().unwrap());
                     }
                     b"vt:bool"=> {
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap()
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?
                         );
                         self.set_value_bool(matches!(text.as_str(), "true" | "1"));
                     }
@@ -200,9 +216,9 @@ impl CustomDocumentProperty {
                 }
             },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"property" {return}
+                if e.name().into_inner() == b"property" {return Ok(())}
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "property")
+            Event::Eof => return Err(unexpected_eof(reader, "property"))
         );
     }
 
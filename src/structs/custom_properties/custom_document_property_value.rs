@@ -1,6 +1,7 @@
 use std::fmt;
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CustomDocumentPropertyValue {
     String(Box<str>),
     Date(Box<str>),
@@ -10,11 +10,15 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         CUSTOM_PROPS_NS,
         VTYPES_NS,
     },
-    reader::driver::xml_read_loop,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::custom_properties::CustomDocumentProperty,
     writer::driver::{
         write_end_tag,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties {
     custom_document_property_list: Vec<CustomDocumentProperty>,
 }
@@ -80,23 +85,23 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"property" {
                     let mut obj = CustomDocumentProperty::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.add_custom_document_property_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"Properties" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "Properties")
+            Event::Eof => return Err(unexpected_eof(reader, "Properties"))
         );
     }
 
@@ -10,21 +10,51 @@ use quick_xml::{
 };
 
 use super::{
+    BooleanValue,
     Color,
     ConditionalFormatValueObject,
+    ConditionalFormatValueObjectValues,
+    DataBarAxisPositionValues,
+    DataBarDirectionValues,
+    EnumValue,
+    UInt32Value,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
 };
 
+/// A Data Bar of a conditional formatting rule.
+///
+/// Bar length limits, borders, gradient or solid fill, negative bar colors
+/// and the axis are kept in the Excel 2010 extension of the rule, which is
+/// written alongside the rule whenever one of them is set.
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataBar {
-    cfvo_collection:  Vec<ConditionalFormatValueObject>,
-    color_collection: Vec<Color>,
+    cfvo_collection:                            Vec<ConditionalFormatValueObject>,
+    color_collection:                           Vec<Color>,
+    min_length:                                 UInt32Value,
+    max_length:                                 UInt32Value,
+    show_value:                                 BooleanValue,
+    border:                                     BooleanValue,
+    gradient:                                   BooleanValue,
+    direction:                                  EnumValue<DataBarDirectionValues>,
+    negative_bar_color_same_as_positive:        BooleanValue,
+    negative_bar_border_color_same_as_positive: BooleanValue,
+    axis_position:                              EnumValue<DataBarAxisPositionValues>,
+    border_color:                               Option<Color>,
+    negative_fill_color:                        Option<Color>,
+    negative_border_color:                      Option<Color>,
+    axis_color:                                 Option<Color>,
 }
 
 impl DataBar {
@@ -78,11 +108,261 @@ impl DataBar {
         self
     }
 
+    /// Get the shortest bar length in percent of the cell width.
+    #[inline]
+    #[must_use]
+    pub fn min_length(&self) -> u32 {
+        if self.min_length.has_value() {
+            self.min_length.value()
+        } else {
+            10
+        }
+    }
+
+    #[inline]
+    pub fn set_min_length(&mut self, value: u32) -> &mut Self {
+        self.min_length.set_value(value);
+        self
+    }
+
+    /// Get the longest bar length in percent of the cell width.
+    #[inline]
+    #[must_use]
+    pub fn max_length(&self) -> u32 {
+        if self.max_length.has_value() {
+            self.max_length.value()
+        } else {
+            90
+        }
+    }
+
+    #[inline]
+    pub fn set_max_length(&mut self, value: u32) -> &mut Self {
+        self.max_length.set_value(value);
+        self
+    }
+
+    /// Get whether the cell value is shown next to the bar.
+    #[inline]
+    #[must_use]
+    pub fn show_value(&self) -> bool {
+        !self.show_value.has_value() || self.show_value.value()
+    }
+
+    #[inline]
+    pub fn set_show_value(&mut self, value: bool) -> &mut Self {
+        self.show_value.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn border(&self) -> bool {
+        self.border.value()
+    }
+
+    #[inline]
+    pub fn set_border(&mut self, value: bool) -> &mut Self {
+        self.border.set_value(value);
+        self
+    }
+
+    /// Get whether the bar has a gradient fill. A solid fill is used when
+    /// `false`.
+    #[inline]
+    #[must_use]
+    pub fn gradient(&self) -> bool {
+        !self.gradient.has_value() || self.gradient.value()
+    }
+
+    #[inline]
+    pub fn set_gradient(&mut self, value: bool) -> &mut Self {
+        self.gradient.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn direction(&self) -> &DataBarDirectionValues {
+        self.direction.value()
+    }
+
+    #[inline]
+    pub fn set_direction(&mut self, value: DataBarDirectionValues) -> &mut Self {
+        self.direction.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn negative_bar_color_same_as_positive(&self) -> bool {
+        self.negative_bar_color_same_as_positive.value()
+    }
+
+    #[inline]
+    pub fn set_negative_bar_color_same_as_positive(&mut self, value: bool) -> &mut Self {
+        self.negative_bar_color_same_as_positive.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn negative_bar_border_color_same_as_positive(&self) -> bool {
+        !self.negative_bar_border_color_same_as_positive.has_value()
+            || self.negative_bar_border_color_same_as_positive.value()
+    }
+
+    #[inline]
+    pub fn set_negative_bar_border_color_same_as_positive(&mut self, value: bool) -> &mut Self {
+        self.negative_bar_border_color_same_as_positive
+            .set_value(value);
+        self
+    }
+
+    /// Get where the axis between negative and positive bars is drawn.
+    #[inline]
+    #[must_use]
+    pub fn axis_position(&self) -> &DataBarAxisPositionValues {
+        self.axis_position.value()
+    }
+
+    #[inline]
+    pub fn set_axis_position(&mut self, value: DataBarAxisPositionValues) -> &mut Self {
+        self.axis_position.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn border_color(&self) -> Option<&Color> {
+        self.border_color.as_ref()
+    }
+
+    /// Set the border color. Turns the border on.
+    #[inline]
+    pub fn set_border_color(&mut self, value: Color) -> &mut Self {
+        self.border_color = Some(value);
+        self.border.set_value(true);
+        self
+    }
+
+    #[inline]
+    pub fn remove_border_color(&mut self) -> &mut Self {
+        self.border_color = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn negative_fill_color(&self) -> Option<&Color> {
+        self.negative_fill_color.as_ref()
+    }
+
+    #[inline]
+    pub fn set_negative_fill_color(&mut self, value: Color) -> &mut Self {
+        self.negative_fill_color = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_negative_fill_color(&mut self) -> &mut Self {
+        self.negative_fill_color = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn negative_border_color(&self) -> Option<&Color> {
+        self.negative_border_color.as_ref()
+    }
+
+    #[inline]
+    pub fn set_negative_border_color(&mut self, value: Color) -> &mut Self {
+        self.negative_border_color = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_negative_border_color(&mut self) -> &mut Self {
+        self.negative_border_color = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn axis_color(&self) -> Option<&Color> {
+        self.axis_color.as_ref()
+    }
+
+    #[inline]
+    pub fn set_axis_color(&mut self, value: Color) -> &mut Self {
+        self.axis_color = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_axis_color(&mut self) -> &mut Self {
+        self.axis_color = None;
+        self
+    }
+
+    /// Take over the settings read from the Excel 2010 extension.
+    pub(crate) fn merge_x14(&mut self, x14: Self) {
+        if x14.min_length.has_value() {
+            self.min_length = x14.min_length;
+        }
+        if x14.max_length.has_value() {
+            self.max_length = x14.max_length;
+        }
+        if !x14.cfvo_collection.is_empty() {
+            self.cfvo_collection = x14.cfvo_collection;
+        }
+        if self.color_collection.is_empty() {
+            self.color_collection = x14.color_collection;
+        }
+        self.border = x14.border;
+        self.gradient = x14.gradient;
+        self.direction = x14.direction;
+        self.negative_bar_color_same_as_positive = x14.negative_bar_color_same_as_positive;
+        self.negative_bar_border_color_same_as_positive =
+            x14.negative_bar_border_color_same_as_positive;
+        self.axis_position = x14.axis_position;
+        self.border_color = x14.border_color;
+        self.negative_fill_color = x14.negative_fill_color;
+        self.negative_border_color = x14.negative_border_color;
+        self.axis_color = x14.axis_color;
+    }
+
+    /// Whether the bar needs the Excel 2010 extension.
+    pub(crate) fn has_extension(&self) -> bool {
+        self.border.has_value()
+            || self.gradient.has_value()
+            || self.direction.has_value()
+            || self.negative_bar_color_same_as_positive.has_value()
+            || self.negative_bar_border_color_same_as_positive.has_value()
+            || self.axis_position.has_value()
+            || self.border_color.is_some()
+            || self.negative_fill_color.is_some()
+            || self.negative_border_color.is_some()
+            || self.axis_color.is_some()
+            || self.cfvo_collection.iter().any(|v| {
+                matches!(
+                    v.get_type(),
+                    ConditionalFormatValueObjectValues::AutoMin
+                        | ConditionalFormatValueObjectValues::AutoMax
+                )
+            })
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, min_length, "minLength");
+        set_string_from_xml!(self, e, max_length, "maxLength");
+        set_string_from_xml!(self, e, show_value, "showValue");
+
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -90,12 +370,12 @@ impl DataBar {
                 match e.name().into_inner() {
                     b"cfvo" => {
                         let mut obj = ConditionalFormatValueObject::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.cfvo_collection.push(obj);
                     }
                     b"color" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.color_collection.push(obj);
                     }
                     _ => (),
@@ -103,16 +383,84 @@ impl DataBar {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dataBar" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => return
+            Event::Eof => return Ok(())
+        );
+    }
+
+    /// Read an `x14:dataBar`.
+    pub(crate) fn set_x14_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, min_length, "minLength");
+        set_string_from_xml!(self, e, max_length, "maxLength");
+        set_string_from_xml!(self, e, border, "border");
+        set_string_from_xml!(self, e, gradient, "gradient");
+        set_string_from_xml!(self, e, direction, "direction");
+        set_string_from_xml!(
+            self,
+            e,
+            negative_bar_color_same_as_positive,
+            "negativeBarColorSameAsPositive"
+        );
+        set_string_from_xml!(
+            self,
+            e,
+            negative_bar_border_color_same_as_positive,
+            "negativeBarBorderColorSameAsPositive"
+        );
+        set_string_from_xml!(self, e, axis_position, "axisPosition");
+
+        xml_read_loop!(
+            reader,
+            ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
+                let is_empty = matches!(n, Event::Empty(_));
+                let name = e.name().into_inner();
+                if name == b"x14:cfvo" {
+                    let mut obj = ConditionalFormatValueObject::default();
+                    obj.set_x14_attributes(reader, e, is_empty)?;
+                    self.cfvo_collection.push(obj);
+                } else if name.starts_with(b"x14:") {
+                    let mut obj = Color::default();
+                    obj.set_attributes(reader, e, true)?;
+                    match name {
+                        b"x14:fillColor" => self.color_collection.push(obj),
+                        b"x14:borderColor" => self.border_color = Some(obj),
+                        b"x14:negativeFillColor" => self.negative_fill_color = Some(obj),
+                        b"x14:negativeBorderColor" => self.negative_border_color = Some(obj),
+                        b"x14:axisColor" => self.axis_color = Some(obj),
+                        _ => (),
+                    }
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:dataBar" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Ok(())
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // dataBar
-        write_start_tag(writer, "dataBar", vec![], false);
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        let min_length = self.min_length.value_string();
+        if self.min_length.has_value() {
+            attributes.push(("minLength", min_length.as_str()).into());
+        }
+        let max_length = self.max_length.value_string();
+        if self.max_length.has_value() {
+            attributes.push(("maxLength", max_length.as_str()).into());
+        }
+        if self.show_value.has_value() {
+            attributes.push(("showValue", self.show_value.value_string()).into());
+        }
+        write_start_tag(writer, "dataBar", attributes, false);
 
         // cfvo
         for v in &self.cfvo_collection {
@@ -126,9 +474,82 @@ impl DataBar {
 
         write_end_tag(writer, "dataBar");
     }
+
+    pub(crate) fn write_x14_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:dataBar
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        let min_length = self.min_length.value_string();
+        if self.min_length.has_value() {
+            attributes.push(("minLength", min_length.as_str()).into());
+        }
+        let max_length = self.max_length.value_string();
+        if self.max_length.has_value() {
+            attributes.push(("maxLength", max_length.as_str()).into());
+        }
+        if self.border.has_value() {
+            attributes.push(("border", self.border.value_string()).into());
+        }
+        if self.gradient.has_value() {
+            attributes.push(("gradient", self.gradient.value_string()).into());
+        }
+        if self.direction.has_value() {
+            attributes.push(("direction", self.direction.value_string()).into());
+        }
+        if self.negative_bar_color_same_as_positive.has_value() {
+            attributes.push(
+                (
+                    "negativeBarColorSameAsPositive",
+                    self.negative_bar_color_same_as_positive.value_string(),
+                )
+                    .into(),
+            );
+        }
+        if self.negative_bar_border_color_same_as_positive.has_value() {
+            attributes.push(
+                (
+                    "negativeBarBorderColorSameAsPositive",
+                    self.negative_bar_border_color_same_as_positive
+                        .value_string(),
+                )
+                    .into(),
+            );
+        }
+        if self.axis_position.has_value() {
+            attributes.push(("axisPosition", self.axis_position.value_string()).into());
+        }
+        write_start_tag(writer, "x14:dataBar", attributes, false);
+
+        // x14:cfvo
+        for v in &self.cfvo_collection {
+            v.write_x14_to(writer);
+        }
+
+        // x14:borderColor
+        if let Some(v) = &self.border_color {
+            v.write_to(writer, "x14:borderColor");
+        }
+
+        // x14:negativeFillColor
+        if let Some(v) = &self.negative_fill_color {
+            v.write_to(writer, "x14:negativeFillColor");
+        }
+
+        // x14:negativeBorderColor
+        if let Some(v) = &self.negative_border_color {
+            v.write_to(writer, "x14:negativeBorderColor");
+        }
+
+        // x14:axisColor
+        if let Some(v) = &self.axis_color {
+            v.write_to(writer, "x14:axisColor");
+        }
+
+        write_end_tag(writer, "x14:dataBar");
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::unnested_or_patterns)]
 mod tests {
     use super::*;
 
@@ -141,7 +562,7 @@ mod tests {
                     if e.name().into_inner() == b"dataBar" =>
                 {
                     let mut obj = DataBar::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e).unwrap();
                     return obj;
                 }
                 Ok(Event::Eof) => panic!("dataBar element not found"),
@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataBarAxisPositionValues {
+    Automatic,
+    Middle,
+    None,
+}
+impl Default for DataBarAxisPositionValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Automatic
+    }
+}
+impl EnumTrait for DataBarAxisPositionValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Automatic => "automatic",
+            Self::Middle => "middle",
+            Self::None => "none",
+        }
+    }
+}
+impl FromStr for DataBarAxisPositionValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "automatic" => Ok(Self::Automatic),
+            "middle" => Ok(Self::Middle),
+            "none" => Ok(Self::None),
+            _ => Err(()),
+        }
+    }
+}
@@ -21,6 +21,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataField {
     name:        StringValue,
     fie_id:      UInt32Value,
@@ -11,7 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::DataField,
     writer::driver::{
         write_end_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataFields {
     list: Vec<DataField>,
 }
@@ -60,7 +65,7 @@ impl DataFields {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -72,10 +77,10 @@ impl DataFields {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dataFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dataFields")
+            Event::Eof => return Err(unexpected_eof(reader, "dataFields"))
         );
     }
 
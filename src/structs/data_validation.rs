@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use super::{
+    Address,
     BooleanValue,
     DataValidationOperatorValues,
     DataValidationValues,
@@ -21,7 +22,13 @@ use super::{
 use crate::{
     Formula1,
     Formula2,
+    XlsxError,
+    helper::formula::{
+        remove_sheet_in_formula,
+        rename_sheet_in_formula,
+    },
     reader::driver::{
+        unexpected_eof,
         xml_read_loop,
         get_attribute,
     },
@@ -32,6 +39,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataValidation {
     r#type:                 EnumValue<DataValidationValues>,
     operator:               EnumValue<DataValidationOperatorValues>,
@@ -78,6 +86,11 @@ impl DataValidation {
         self
     }
 
+    #[inline]
+    pub(crate) fn has_operator(&self) -> bool {
+        self.operator.has_value()
+    }
+
     #[inline]
     #[must_use]
     pub fn allow_blank(&self) -> bool {
@@ -279,12 +292,155 @@ impl DataValidation {
         self
     }
 
+    /// (This method is crate only.)
+    /// Replace the sheet name of the references to a renamed sheet.
+    pub(crate) fn rename_sheet_reference(&mut self, old_name: &str, new_name: &str) {
+        self.map_formulas(|v| rename_sheet_in_formula(v, old_name, new_name));
+    }
+
+    /// (This method is crate only.)
+    /// Turn the references to a removed sheet into `#REF!`.
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        self.map_formulas(|v| remove_sheet_in_formula(v, sheet_name));
+    }
+
+    fn map_formulas<F: Fn(&str) -> String>(&mut self, f: F) {
+        let value = f(self.formula1());
+        if value != self.formula1() {
+            self.set_formula1(value);
+        }
+        let value = f(self.formula2());
+        if value != self.formula2() {
+            self.set_formula2(value);
+        }
+    }
+
+    fn with_type(r#type: DataValidationValues, formula1: String) -> Self {
+        let mut result = Self::default();
+        result
+            .set_type(r#type)
+            .set_allow_blank(true)
+            .set_show_error_message(true)
+            .set_formula1(formula1);
+        result
+    }
+
+    fn with_operator<S: AsRef<str>>(
+        r#type: DataValidationValues,
+        operator: DataValidationOperatorValues,
+        value: S,
+    ) -> Self {
+        let value = value.as_ref();
+        let mut result = Self::with_type(r#type, value.strip_prefix('=').unwrap_or(value).into());
+        result.set_operator(operator);
+        result
+    }
+
+    /// Build a list rule from literal values.
+    /// ex) `DataValidation::list(["Yes", "No"])`
+    #[must_use]
+    pub fn list<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let values = values
+            .into_iter()
+            .map(|v| v.as_ref().replace('"', "\"\""))
+            .collect::<Vec<_>>();
+        Self::with_type(DataValidationValues::List, format!("\"{}\"", values.join(",")))
+    }
+
+    /// Build a list rule taking its values from a range.
+    /// The reference is made absolute, so every cell of the validated range
+    /// uses the same source.
+    /// ex) `DataValidation::list_from_range("Lookups!A1:A20")`
+    #[must_use]
+    pub fn list_from_range<S: AsRef<str>>(range: S) -> Self {
+        let mut address = Address::default();
+        address.set_address(range.as_ref());
+        let range = address.range_mut();
+        if let Some(v) = range.coordinate_start_col_mut() {
+            v.set_is_lock(true);
+        }
+        if let Some(v) = range.coordinate_start_row_mut() {
+            v.set_is_lock(true);
+        }
+        if let Some(v) = range.coordinate_end_col_mut() {
+            v.set_is_lock(true);
+        }
+        if let Some(v) = range.coordinate_end_row_mut() {
+            v.set_is_lock(true);
+        }
+        Self::with_type(DataValidationValues::List, address.address_ptn2())
+    }
+
+    /// Build a list rule taking its values from a defined name.
+    /// ex) `DataValidation::list_from_name("Countries")`
+    #[must_use]
+    pub fn list_from_name<S: Into<String>>(name: S) -> Self {
+        Self::with_type(DataValidationValues::List, name.into())
+    }
+
+    /// Build a rule allowing whole numbers.
+    /// For `Between` and `NotBetween`, set the upper bound with
+    /// [`set_formula2`](Self::set_formula2).
+    /// # Arguments
+    /// * `operator` - Comparison operator.
+    /// * `value` - Value or formula to compare with. ex) `"100"`, `"$B$1"`
+    #[must_use]
+    pub fn whole_number<S: AsRef<str>>(operator: DataValidationOperatorValues, value: S) -> Self {
+        Self::with_operator(DataValidationValues::Whole, operator, value)
+    }
+
+    /// Build a rule allowing decimal numbers.
+    /// For `Between` and `NotBetween`, set the upper bound with
+    /// [`set_formula2`](Self::set_formula2).
+    #[must_use]
+    pub fn decimal<S: AsRef<str>>(operator: DataValidationOperatorValues, value: S) -> Self {
+        Self::with_operator(DataValidationValues::Decimal, operator, value)
+    }
+
+    /// Build a rule allowing dates.
+    /// The value is a serial date, a `DATE()` call or a reference.
+    /// ex) `"DATE(2024,1,31)"`
+    #[must_use]
+    pub fn date<S: AsRef<str>>(operator: DataValidationOperatorValues, value: S) -> Self {
+        Self::with_operator(DataValidationValues::Date, operator, value)
+    }
+
+    /// Build a rule allowing times.
+    /// The value is a fraction of a day, a `TIME()` call or a reference.
+    /// ex) `"TIME(18,0,0)"`
+    #[must_use]
+    pub fn time<S: AsRef<str>>(operator: DataValidationOperatorValues, value: S) -> Self {
+        Self::with_operator(DataValidationValues::Time, operator, value)
+    }
+
+    /// Build a rule limiting the length of the text.
+    #[must_use]
+    pub fn text_length<S: AsRef<str>>(operator: DataValidationOperatorValues, value: S) -> Self {
+        Self::with_operator(DataValidationValues::TextLength, operator, value)
+    }
+
+    /// Build a rule allowing values for which a formula is true.
+    /// Relative references are relative to the top-left cell of the range.
+    /// ex) `"=B2>A2"`
+    #[must_use]
+    pub fn custom<S: AsRef<str>>(formula: S) -> Self {
+        let formula = formula.as_ref();
+        Self::with_type(
+            DataValidationValues::Custom,
+            formula.strip_prefix('=').unwrap_or(formula).into(),
+        )
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"type") {
             self.r#type.set_value_string(v);
         }
@@ -326,7 +482,7 @@ impl DataValidation {
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -335,12 +491,12 @@ impl DataValidation {
                 match e.name().into_inner() {
                     b"formula1" => {
                         let mut obj = Formula1::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         *self.formula1 = obj;
                     }
                     b"formula2" => {
                         let mut obj = Formula2::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         *self.formula2 = obj;
                     }
                     _ => (),
@@ -348,10 +504,10 @@ impl DataValidation {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dataValidation" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dataValidation")
+            Event::Eof => return Err(unexpected_eof(reader, "dataValidation"))
         );
     }
 
@@ -450,7 +606,7 @@ mod tests {
             panic!("expected Start dataValidation");
         };
         let mut dv = DataValidation::default();
-        dv.set_attributes(&mut reader, &e, false);
+        dv.set_attributes(&mut reader, &e, false).unwrap();
         assert_eq!(
             dv.formula1(),
             "\"0–3 years ago,4–5 years ago,6–7 years ago\"",
@@ -474,7 +630,7 @@ mod tests {
             panic!("expected Start dataValidation");
         };
         let mut dv = DataValidation::default();
-        dv.set_attributes(&mut reader, &e, false);
+        dv.set_attributes(&mut reader, &e, false).unwrap();
         assert_eq!(dv.formula1(), "\"petrol,diesel,LPG,electric\"");
     }
 }
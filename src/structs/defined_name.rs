@@ -9,13 +9,29 @@ use quick_xml::{
 use super::{
     Address,
     BooleanValue,
+    DefinedNameValue,
     StringValue,
     UInt32Value,
 };
 use crate::{
-    helper::address::is_address,
+    CellErrorType,
+    XlsxError,
+    helper::{
+        address::{
+            is_address,
+            is_line_address,
+        },
+        coordinate::column_index_from_string,
+        formula::{
+            is_same_sheet_name,
+            remove_sheet_in_formula,
+            rename_sheet_in_formula,
+        },
+        utils::compile_regex,
+    },
     reader::driver::{
         get_attribute,
+        invalid_xml,
         set_string_from_xml,
     },
     traits::AdjustmentCoordinateWithSheet,
@@ -27,6 +43,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DefinedName {
     name:           StringValue,
     address:        Vec<Address>,
@@ -35,6 +52,10 @@ pub struct DefinedName {
     hidden:         BooleanValue,
 }
 impl DefinedName {
+    pub(crate) const FILTER_DATABASE: &'static str = "_xlnm._FilterDatabase";
+    pub(crate) const PRINT_AREA: &'static str = "_xlnm.Print_Area";
+    pub(crate) const PRINT_TITLES: &'static str = "_xlnm.Print_Titles";
+
     #[inline]
     #[must_use]
     pub fn name(&self) -> &str {
@@ -73,9 +94,11 @@ impl DefinedName {
     }
 
     pub fn set_address<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.address.clear();
+        self.string_value.remove_value();
         let list = Self::split_str(value);
         for v in &list {
-            if is_address(v) {
+            if is_address(v) || is_line_address(v) {
                 self.add_address(v);
             } else {
                 self.set_string_value(v);
@@ -91,6 +114,89 @@ impl DefinedName {
         self
     }
 
+    /// Get what the name refers to: cell ranges, a constant or a formula.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::{
+    ///     DefinedName,
+    ///     DefinedNameValue,
+    /// };
+    ///
+    /// let mut defined_name = DefinedName::default();
+    /// defined_name.set_name("TaxRate").set_address("0.08");
+    /// assert!(matches!(defined_name.resolve(), DefinedNameValue::Constant(v) if &*v == "0.08"));
+    ///
+    /// defined_name.set_address("Sheet1!$A$1:$A$4");
+    /// assert!(matches!(defined_name.resolve(), DefinedNameValue::Range(v) if v.len() == 1));
+    /// ```
+    #[must_use]
+    pub fn resolve(&self) -> DefinedNameValue {
+        if !self.string_value.has_value() {
+            return DefinedNameValue::Range(self.address.clone());
+        }
+        let value = self.string_value.value_str();
+        let value = value.strip_prefix('=').unwrap_or(value);
+        if Self::is_constant(value) {
+            DefinedNameValue::Constant(value.into())
+        } else {
+            DefinedNameValue::Formula(value.into())
+        }
+    }
+
+    fn is_constant(value: &str) -> bool {
+        value.parse::<f64>().is_ok()
+            || (value.len() >= 2 && value.starts_with('"') && value.ends_with('"'))
+            || (value.starts_with('{') && value.ends_with('}'))
+            || value.eq_ignore_ascii_case("TRUE")
+            || value.eq_ignore_ascii_case("FALSE")
+            || value.parse::<CellErrorType>().is_ok()
+    }
+
+    /// Check a name against the rules of Excel.
+    /// A name starts with a letter, `_` or `\`, continues with letters,
+    /// digits, `_`, `.`, `\` or `?`, is at most 255 characters long and
+    /// must not look like a cell reference such as `A1` or `R1C1`.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::DefinedName;
+    ///
+    /// assert!(DefinedName::is_valid_name("Sales_2024"));
+    /// assert!(DefinedName::is_valid_name("_xlnm.Print_Area"));
+    /// assert!(!DefinedName::is_valid_name("A1"));
+    /// assert!(!DefinedName::is_valid_name("R2C3"));
+    /// assert!(!DefinedName::is_valid_name("1st"));
+    /// assert!(!DefinedName::is_valid_name("My Name"));
+    /// ```
+    #[must_use]
+    pub fn is_valid_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(c) if c.is_alphabetic() || c == '_' || c == '\\' => {}
+            _ => return false,
+        }
+        if name.chars().count() > 255
+            || !chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '\\' | '?'))
+        {
+            return false;
+        }
+        if name.eq_ignore_ascii_case("R") || name.eq_ignore_ascii_case("C") {
+            return false;
+        }
+        let r1c1 = compile_regex!(r"^[Rr]([0-9]+)?([Cc]([0-9]+)?)?$|^[Cc][0-9]+$");
+        if r1c1.is_match(name).unwrap() {
+            return false;
+        }
+        let a1 = compile_regex!(r"^([A-Za-z]{1,3})([0-9]{1,7})$");
+        if let Some(caps) = a1.captures(name).unwrap() {
+            let col = column_index_from_string(caps[1].to_uppercase());
+            let row = caps[2].parse::<u32>().unwrap();
+            if col <= 16384 && (1..=1_048_576).contains(&row) {
+                return false;
+            }
+        }
+        true
+    }
+
     #[inline]
     pub(crate) fn address_obj(&self) -> &[Address] {
         &self.address
@@ -109,6 +215,15 @@ impl DefinedName {
         self
     }
 
+    /// (This method is crate only.)
+    /// Rewrite an address that is not a plain range, such as a formula.
+    pub(crate) fn map_string_value<F: Fn(&str) -> String>(&mut self, f: F) {
+        if self.string_value.has_value() {
+            let value = f(self.string_value.value_str());
+            self.string_value.set_value(value);
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn has_local_sheet_id(&self) -> bool {
@@ -197,27 +312,77 @@ impl DefinedName {
         result
     }
 
-    pub(crate) fn set_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        let value = value.into();
+    /// (This method is crate only.)
+    /// Follow the rename of the sheet the name is stored on.
+    /// Addresses without a sheet name get the new name.
+    pub(crate) fn set_sheet_name(&mut self, old_name: &str, new_name: &str) -> &mut Self {
         for address in &mut self.address {
-            address.set_sheet_name(value.clone());
+            if address.sheet_name().is_empty() {
+                address.set_sheet_name(new_name);
+            }
         }
+        self.rename_sheet_reference(old_name, new_name);
         self
     }
 
+    /// (This method is crate only.)
+    /// Replace the sheet name of the references to a renamed sheet.
+    pub(crate) fn rename_sheet_reference(&mut self, old_name: &str, new_name: &str) {
+        if old_name.is_empty() {
+            return;
+        }
+        for address in &mut self.address {
+            if is_same_sheet_name(address.sheet_name(), old_name) {
+                address.set_sheet_name(new_name);
+            }
+        }
+        self.map_string_value(|v| rename_sheet_in_formula(v, old_name, new_name));
+    }
+
+    /// (This method is crate only.)
+    /// Turn the references to a removed sheet into `#REF!`.
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        if self.string_value.has_value() {
+            self.map_string_value(|v| remove_sheet_in_formula(v, sheet_name));
+            return;
+        }
+        if !self
+            .address
+            .iter()
+            .any(|v| is_same_sheet_name(v.sheet_name(), sheet_name))
+        {
+            return;
+        }
+        let value = self
+            .address
+            .iter()
+            .map(|v| {
+                if is_same_sheet_name(v.sheet_name(), sheet_name) {
+                    format!("#REF!{}", v.range().range())
+                } else {
+                    v.address_ptn2()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+        self.set_string_value(value);
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, local_sheet_id, "localSheetId");
         set_string_from_xml!(self, e, hidden, "hidden");
 
         let mut buf = Vec::new();
         let text = reader
-                .read_text_into(e.name(), &mut buf).unwrap();
+                .read_text_into(e.name(), &mut buf)
+                .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_address(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -0,0 +1,13 @@
+use super::Address;
+
+/// What a defined name refers to.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefinedNameValue {
+    /// One or more cell ranges. ex) `Sheet1!$A$1:$B$4`
+    Range(Vec<Address>),
+    /// A constant. ex) `0.08`, `"Tokyo"`, `TRUE`, `#N/A`
+    Constant(Box<str>),
+    /// Any other formula. ex) `OFFSET(Sheet1!$A$1,0,0,COUNTA(Sheet1!$A:$A),1)`
+    Formula(Box<str>),
+}
@@ -0,0 +1,228 @@
+use super::{
+    DefinedName,
+    DefinedNameValue,
+    Workbook,
+    XlsxError,
+};
+
+/// The defined names of a workbook, scoped to the workbook or to a sheet.
+///
+/// Names are not case sensitive. Looked up from a sheet, a name scoped to the
+/// sheet is found before a workbook name.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// book.names_mut().add("Rate", "0.08", None).unwrap();
+/// book.names_mut().add("Rate", "0.1", Some("Sheet1")).unwrap();
+///
+/// assert_eq!(book.names().get("rate", None).unwrap().address(), "0.08");
+/// assert_eq!(
+///     book.names().get("rate", Some("Sheet1")).unwrap().address(),
+///     "0.1"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DefinedNames<'a> {
+    workbook: &'a Workbook,
+}
+
+impl<'a> DefinedNames<'a> {
+    #[inline]
+    pub(crate) fn new(workbook: &'a Workbook) -> Self {
+        Self { workbook }
+    }
+
+    /// Get a defined name by its name.
+    /// # Arguments
+    /// * `name` - Name.
+    /// * `sheet_name` - Sheet the name is looked up from. `None` looks up
+    ///   workbook names only.
+    #[must_use]
+    pub fn get(&self, name: &str, sheet_name: Option<&str>) -> Option<&'a DefinedName> {
+        let (sheet_index, index) = self.find(name, sheet_name)?;
+        match sheet_index {
+            Some(v) => self.workbook.sheet_collection_no_check()[v]
+                .defined_names()
+                .get(index),
+            None => self.workbook.defined_names().get(index),
+        }
+    }
+
+    /// Get what a defined name refers to: cell ranges, a constant or a
+    /// formula.
+    /// # Arguments
+    /// * `name` - Name.
+    /// * `sheet_name` - Sheet the name is looked up from.
+    #[inline]
+    #[must_use]
+    pub fn resolve(&self, name: &str, sheet_name: Option<&str>) -> Option<DefinedNameValue> {
+        self.get(name, sheet_name).map(DefinedName::resolve)
+    }
+
+    /// Get all defined names, the workbook names first.
+    pub fn iter(&self) -> impl Iterator<Item = &'a DefinedName> {
+        self.workbook.defined_names().iter().chain(
+            self.workbook
+                .sheet_collection_no_check()
+                .iter()
+                .flat_map(|v| v.defined_names().iter()),
+        )
+    }
+
+    /// Find a defined name, the sheet scope first.
+    /// Returns the index of the sheet storing it (`None` for the workbook)
+    /// and its index in that list.
+    fn find(self, name: &str, sheet_name: Option<&str>) -> Option<(Option<usize>, usize)> {
+        if let Some(sheet_index) = sheet_name.and_then(|v| self.sheet_index(v)) {
+            if let Some(v) = self.find_in_scope(name, Some(sheet_index)) {
+                return Some(v);
+            }
+        }
+        self.find_in_scope(name, None)
+    }
+
+    /// Find a defined name scoped to a sheet, or a workbook name when
+    /// `sheet_index` is `None`. Workbook names may be stored on a sheet.
+    fn find_in_scope(
+        self,
+        name: &str,
+        sheet_index: Option<usize>,
+    ) -> Option<(Option<usize>, usize)> {
+        let is_match = |v: &DefinedName| {
+            v.name().eq_ignore_ascii_case(name) && v.has_local_sheet_id() == sheet_index.is_some()
+        };
+        let sheets = self.workbook.sheet_collection_no_check();
+        if let Some(sheet_index) = sheet_index {
+            return sheets[sheet_index]
+                .defined_names()
+                .iter()
+                .position(is_match)
+                .map(|v| (Some(sheet_index), v));
+        }
+        if let Some(v) = self.workbook.defined_names().iter().position(is_match) {
+            return Some((None, v));
+        }
+        sheets.iter().enumerate().find_map(|(i, sheet)| {
+            sheet
+                .defined_names()
+                .iter()
+                .position(is_match)
+                .map(|v| (Some(i), v))
+        })
+    }
+
+    #[inline]
+    fn sheet_index(self, sheet_name: &str) -> Option<usize> {
+        self.workbook
+            .sheet_collection_no_check()
+            .iter()
+            .position(|v| v.name() == sheet_name)
+    }
+}
+
+/// The defined names of a workbook in mutable.
+/// See [`DefinedNames`].
+#[derive(Debug)]
+pub struct DefinedNamesMut<'a> {
+    workbook: &'a mut Workbook,
+}
+
+impl<'a> DefinedNamesMut<'a> {
+    #[inline]
+    pub(crate) fn new(workbook: &'a mut Workbook) -> Self {
+        Self { workbook }
+    }
+
+    /// Get a defined name by its name.
+    /// # Arguments
+    /// * `name` - Name.
+    /// * `sheet_name` - Sheet the name is looked up from. `None` looks up
+    ///   workbook names only.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, name: &str, sheet_name: Option<&str>) -> Option<&DefinedName> {
+        DefinedNames::new(self.workbook).get(name, sheet_name)
+    }
+
+    /// Get a defined name by its name in mutable.
+    /// # Arguments
+    /// * `name` - Name.
+    /// * `sheet_name` - Sheet the name is looked up from. `None` looks up
+    ///   workbook names only.
+    pub fn get_mut(&mut self, name: &str, sheet_name: Option<&str>) -> Option<&mut DefinedName> {
+        let (sheet_index, index) = DefinedNames::new(self.workbook).find(name, sheet_name)?;
+        self.list_mut(sheet_index).get_mut(index)
+    }
+
+    /// Add a defined name.
+    /// # Arguments
+    /// * `name` - Name. ex) "`TaxRate`"
+    /// * `address` - Range, constant or formula. ex) "Sheet1!$A$1:$A$4"
+    /// * `sheet_name` - Sheet the name is scoped to. `None` makes a workbook
+    ///   name.
+    /// # Errors
+    /// `XlsxError::InvalidName` if the name breaks the rules of Excel,
+    /// `XlsxError::NameDuplicate` if the scope already has the name and
+    /// `XlsxError::NotFound` if the sheet does not exist.
+    pub fn add<S: Into<String>>(
+        &mut self,
+        name: S,
+        address: S,
+        sheet_name: Option<&str>,
+    ) -> Result<&mut DefinedName, XlsxError> {
+        let name = name.into();
+        if !DefinedName::is_valid_name(&name) {
+            return Err(XlsxError::InvalidName());
+        }
+        let sheet_index = self.scope(sheet_name)?;
+        let names = DefinedNames::new(self.workbook);
+        if names.find_in_scope(&name, sheet_index).is_some() {
+            return Err(XlsxError::NameDuplicate());
+        }
+
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(name).set_address(address);
+        if let Some(v) = sheet_index {
+            defined_name.set_local_sheet_id(num_traits::cast(v).unwrap());
+        }
+        let list = self.list_mut(sheet_index);
+        list.push(defined_name);
+        Ok(list.last_mut().unwrap())
+    }
+
+    /// Remove a defined name.
+    /// # Arguments
+    /// * `name` - Name.
+    /// * `sheet_name` - Sheet the name is scoped to. `None` removes a workbook
+    ///   name.
+    /// # Errors
+    /// `XlsxError::NotFound` if the scope does not have the name.
+    pub fn remove(
+        &mut self,
+        name: &str,
+        sheet_name: Option<&str>,
+    ) -> Result<DefinedName, XlsxError> {
+        let sheet_index = self.scope(sheet_name)?;
+        let (sheet_index, index) = DefinedNames::new(self.workbook)
+            .find_in_scope(name, sheet_index)
+            .ok_or(XlsxError::NotFound())?;
+        Ok(self.list_mut(sheet_index).remove(index))
+    }
+
+    fn scope(&self, sheet_name: Option<&str>) -> Result<Option<usize>, XlsxError> {
+        match sheet_name {
+            Some(v) => DefinedNames::new(self.workbook)
+                .sheet_index(v)
+                .map(Some)
+                .ok_or(XlsxError::NotFound()),
+            None => Ok(None),
+        }
+    }
+
+    fn list_mut(&mut self, sheet_index: Option<usize>) -> &mut Vec<DefinedName> {
+        match sheet_index {
+            Some(v) => self.workbook.sheet_collection_no_check_mut()[v].defined_names_mut(),
+            None => self.workbook.defined_names_mut(),
+        }
+    }
+}
@@ -18,7 +18,11 @@ use super::{
     Style,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -26,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct DifferentialFormat {
     font:      Option<Box<Font>>,
     fill:      Option<Fill>,
@@ -217,24 +222,24 @@ impl DifferentialFormat {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"font" => {
                         let mut obj = Font::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_font(obj);
                     }
                     b"fill" => {
                         let mut obj = Fill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_fill(obj);
                     }
                     b"border" => {
                         let mut obj = Borders::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_borders(obj);
                     }
                     b"alignment" => {
@@ -247,10 +252,10 @@ impl DifferentialFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxf")
+            Event::Eof => return Err(unexpected_eof(reader, "dxf"))
         );
     }
 
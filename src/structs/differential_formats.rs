@@ -15,7 +15,11 @@ use super::{
     Style,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct DifferentialFormats {
     differential_format: Vec<DifferentialFormat>,
 }
@@ -89,22 +94,22 @@ impl DifferentialFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"dxf" {
                     let mut obj = DifferentialFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_differential_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxfs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxfs")
+            Event::Eof => return Err(unexpected_eof(reader, "dxfs"))
         );
     }
 
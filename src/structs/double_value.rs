@@ -1,4 +1,5 @@
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DoubleValue {
     value: Option<f64>,
 }
@@ -177,6 +177,9 @@ pub use self::shape_auto_fit::*;
 mod text_alignment_type_values;
 pub use self::text_alignment_type_values::*;
 
+mod text_anchoring_type_values;
+pub use self::text_anchoring_type_values::*;
+
 mod text_caps_values;
 pub use self::text_caps_values::*;
 
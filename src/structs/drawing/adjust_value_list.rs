@@ -12,8 +12,10 @@ use quick_xml::{
 
 use super::shape_guide::ShapeGuide;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -23,6 +25,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdjustValueList {
     shape_guide_collection: Vec<ShapeGuide>,
 }
@@ -66,23 +69,23 @@ impl AdjustValueList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:gd" {
                     let mut shape_guide = ShapeGuide::default();
-                    shape_guide.set_name(get_attribute(e, b"name").unwrap());
-                    shape_guide.set_fmla(get_attribute(e, b"fmla").unwrap());
+                    shape_guide.set_name(required_attribute(reader, e, "name")?);
+                    shape_guide.set_fmla(required_attribute(reader, e, "fmla")?);
                     self.add_shape_guide_collection(shape_guide);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:avLst")
+            Event::Eof => return Err(unexpected_eof(reader, "a:avLst"))
         );
     }
 
@@ -8,11 +8,13 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::required_attribute,
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Alpha {
     val: Box<str>,
 }
@@ -38,10 +40,11 @@ impl Alpha {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(required_attribute(reader, e, "val")?);
+        Ok(())
     }
 
     #[inline]
@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use super::super::super::EnumTrait;
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BevelPresetValues {
     Angle,
     ArtDeco,
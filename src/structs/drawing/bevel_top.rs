@@ -23,6 +23,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BevelTop {
     width:  Int64Value,
     height: Int64Value,
@@ -11,12 +11,15 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWING_MAIN_NS,
         REL_OFC_NS,
     },
     reader::driver::{
         get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -30,6 +33,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Blip {
     image:  MediaObject,
     cstate: Box<str>,
@@ -91,30 +95,30 @@ impl Blip {
         e: &BytesStart,
         drawing_relationships: &RawRelationships,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"cstate") {
             self.set_cstate(v);
         }
 
-        let picture_id = get_attribute(e, b"r:embed").unwrap();
-        let relationship = drawing_relationships.relationship_by_rid(&picture_id);
+        let picture_id = required_attribute(reader, e, "r:embed")?;
+        let relationship = drawing_relationships.relationship_by_rid(&picture_id)?;
         self.image_mut()
             .set_image_name(relationship.raw_file().file_name());
         self.image_mut()
             .set_image_data(relationship.raw_file().file_data());
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:blip" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:blip")
+            Event::Eof => return Err(unexpected_eof(reader, "a:blip"))
         );
     }
 
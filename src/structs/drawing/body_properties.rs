@@ -21,8 +21,10 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute_value,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -32,6 +34,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BodyProperties {
     vert_overflow:         StringValue,
     horz_overflow:         StringValue,
@@ -270,12 +273,18 @@ impl BodyProperties {
         self
     }
 
+    #[inline]
+    pub fn remove_shape_auto_fit(&mut self) -> &mut BodyProperties {
+        self.shape_auto_fit = None;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         for attr in e.attributes().with_checks(false).flatten() {
             let key = attr.key.into_inner();
             let value = get_attribute_value(&attr).unwrap();
@@ -318,7 +327,7 @@ impl BodyProperties {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -332,10 +341,10 @@ impl BodyProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:bodyPr" {
-                     return
+                     return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:bodyPr")
+            Event::Eof => return Err(unexpected_eof(reader, "a:bodyPr"))
         );
     }
 
@@ -16,9 +16,11 @@ use super::{
     Rotation,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -28,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Camera {
     preset:   EnumValue<PresetCameraValues>,
     rotation: Option<Box<Rotation>>,
@@ -88,11 +91,11 @@ impl Camera {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset, "prst");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -106,10 +109,10 @@ impl Camera {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:camera" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:camera")
+            Event::Eof => return Err(unexpected_eof(reader, "a:camera"))
         );
     }
 
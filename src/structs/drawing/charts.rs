@@ -438,3 +438,12 @@ pub use self::time_unit_values::*;
 
 mod base_time_unit;
 pub use self::base_time_unit::*;
+
+mod max_axis_value;
+pub use self::max_axis_value::*;
+
+mod min_axis_value;
+pub use self::min_axis_value::*;
+
+mod major_unit;
+pub use self::major_unit::*;
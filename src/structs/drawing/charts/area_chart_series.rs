@@ -28,16 +28,19 @@ use super::{
     YValues,
 };
 use crate::{
+    XlsxError,
     drawing::charts::ChartText,
     structs::Workbook,
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AreaChartSeries {
     index:              Index,
     order:              Order,
@@ -460,11 +463,36 @@ impl AreaChartSeries {
         self.formula_mut()
     }
 
+    /// Fill the caches of every cell reference in this series.
+    pub fn refresh_cache(&mut self, wb: &Workbook) -> &mut Self {
+        if let Some(v) = &mut self.category_axis_data {
+            if let Some(h) = v.string_reference_mut() {
+                h.refresh_cache(wb);
+            }
+            if let Some(h) = v.number_reference_mut() {
+                h.refresh_cache(wb);
+            }
+        }
+        if let Some(v) = &mut self.values {
+            v.number_reference_mut().refresh_cache(wb);
+        }
+        if let Some(v) = &mut self.x_values {
+            v.number_reference_mut().refresh_cache(wb);
+        }
+        if let Some(v) = &mut self.y_values {
+            v.number_reference_mut().refresh_cache(wb);
+        }
+        if let Some(v) = &mut self.bubble_size {
+            v.number_reference_mut().refresh_cache(wb);
+        }
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -472,73 +500,73 @@ impl AreaChartSeries {
                 match e.name().into_inner() {
                     b"c:tx" => {
                         let mut obj = ChartText::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_chart_text(obj);
                     }
                     b"c:marker" => {
                         let mut obj = Marker::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_marker(obj);
                     }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:cat" => {
                     let mut obj = CategoryAxisData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_category_axis_data(obj);
                 }
                 b"c:val" => {
                     let mut obj = Values::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_values(obj);
                 }
                 b"c:xVal" => {
                     let mut obj = XValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_x_values(obj);
                 }
                 b"c:yVal" => {
                     let mut obj = YValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_y_values(obj);
                 }
                 b"c:bubbleSize" => {
                     let mut obj = BubbleSize::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_size(obj);
                 }
                 b"c:dLbls" => {
                     let mut obj = DataLabels::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_data_labels(obj);
                 }
                 b"c:idx" => {
-                    self.index.set_attributes(reader, e);
+                    self.index.set_attributes(reader, e)?;
                 }
                 b"c:order" => {
-                    self.order.set_attributes(reader, e);
+                    self.order.set_attributes(reader, e)?;
                 }
                 b"c:explosion" => {
                     let mut obj = Explosion::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_explosion(obj);
                 }
                 b"c:invertIfNegative" => {
                     let mut obj = InvertIfNegative::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_invert_if_negative(obj);
                 }
                 b"c:bubble3D" => {
                     let mut obj = Bubble3D::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_3d(obj);
                 }
                 b"c:smooth" => {
                     let mut obj = Smooth::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_smooth(obj);
                 }
                 _ => (),
@@ -546,10 +574,10 @@ impl AreaChartSeries {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:ser" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:ser"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:ser")),
         );
     }
 
@@ -1,6 +1,7 @@
 use super::AreaChartSeries;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AreaChartSeriesList {
     area_chart_series: Vec<AreaChartSeries>,
 }
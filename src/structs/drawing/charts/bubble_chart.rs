@@ -20,7 +20,11 @@ use super::{
     VaryColors,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::Workbook,
     writer::driver::{
         write_end_tag,
@@ -29,6 +33,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BubbleChart {
     vary_colors:            VaryColors,
     area_chart_series_list: AreaChartSeriesList,
@@ -198,19 +203,19 @@ impl BubbleChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -218,17 +223,17 @@ impl BubbleChart {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:bubbleScale" => {
-                        self.bubble_scale.set_attributes(reader, e);
+                        self.bubble_scale.set_attributes(reader, e)?;
                     }
                     b"c:showNegBubbles" => {
-                        self.show_negative_bubbles.set_attributes(reader, e);
+                        self.show_negative_bubbles.set_attributes(reader, e)?;
                     }
                     b"c:axId" => {
                         let mut obj = AxisId::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_axis_id(obj);
                     }
                     _ => (),
@@ -236,10 +241,10 @@ impl BubbleChart {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:bubbleChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:bubbleChart")
+            Event::Eof => return Err(unexpected_eof(reader, "c:bubbleChart"))
         );
     }
 
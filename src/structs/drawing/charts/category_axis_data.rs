@@ -15,8 +15,12 @@ use super::{
     StringReference,
 };
 use crate::{
+    XlsxError,
     drawing::charts::NumberReference,
-    reader::driver::xml_read_loop,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::Workbook,
     writer::driver::{
         write_end_tag,
@@ -25,6 +29,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CategoryAxisData {
     string_reference: Option<StringReference>,
     string_literal:   Option<StringLiteral>,
@@ -126,24 +131,24 @@ impl CategoryAxisData {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:strRef" => {
                         let mut obj = StringReference::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_reference(obj);
                     }
                     b"c:strLit" => {
                         let mut obj = StringLiteral::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_literal(obj);
                     }
                     b"c:numRef" => {
                         let mut obj = NumberReference::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_number_reference(obj);
                     }
                     _ => (),
@@ -151,10 +156,10 @@ impl CategoryAxisData {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:cat" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:cat")
+            Event::Eof => return Err(unexpected_eof(reader, "c:cat"))
         );
     }
 
@@ -19,6 +19,7 @@ use super::{
     ShapeProperties,
 };
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWING_CHART_2015_NS,
         DRAWINGML_CHART_NS,
@@ -34,10 +35,12 @@ use crate::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChartSpace {
     date1904:         Option<Date1904>,
     editing_language: EditingLanguage,
@@ -228,26 +231,26 @@ impl ChartSpace {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"mc:AlternateContent" => {
                     let mut obj = Style::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_style(obj);
                 }
                 b"c:chart" => {
-                    self.chart.set_attributes(reader, e);
+                    self.chart.set_attributes(reader, e)?;
                 }
                 b"c:printSettings" => {
                     let mut obj = PrintSettings::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_print_settings(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
@@ -255,28 +258,28 @@ impl ChartSpace {
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:date1904" => {
                     let mut obj = Date1904::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_date1904(obj);
                 }
                 b"c:lang" => {
-                    self.editing_language.set_attributes(reader, e);
+                    self.editing_language.set_attributes(reader, e)?;
                 }
                 b"c:roundedCorners" => {
-                    self.rounded_corners.set_attributes(reader, e);
+                    self.rounded_corners.set_attributes(reader, e)?;
                 }
                 b"c:style" => {
                     let mut obj = Style::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_style(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:chartSpace" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:chartSpace"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:chartSpace")),
         );
     }
 
@@ -21,7 +21,11 @@ use super::{
     TextProperties,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -29,6 +33,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataLabels {
     show_legend_key:    ShowLegendKey,
     show_value:         ShowValue,
@@ -245,39 +250,39 @@ impl DataLabels {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:txPr" {
                     let mut obj = TextProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_properties(obj);
                 }
             },
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:showLegendKey" => {
-                        self.show_legend_key.set_attributes(reader, e);
+                        self.show_legend_key.set_attributes(reader, e)?;
                     }
                     b"c:showVal" => {
-                        self.show_value.set_attributes(reader, e);
+                        self.show_value.set_attributes(reader, e)?;
                     }
                     b"c:showCatName" => {
-                        self.show_category_name.set_attributes(reader, e);
+                        self.show_category_name.set_attributes(reader, e)?;
                     }
                     b"c:showSerName" => {
-                        self.show_series_name.set_attributes(reader, e);
+                        self.show_series_name.set_attributes(reader, e)?;
                     }
                     b"c:showPercent" => {
-                        self.show_percent.set_attributes(reader, e);
+                        self.show_percent.set_attributes(reader, e)?;
                     }
                     b"c:showBubbleSize" => {
-                        self.show_bubble_size.set_attributes(reader, e);
+                        self.show_bubble_size.set_attributes(reader, e)?;
                     }
                     b"c:showLeaderLines" => {
                         let mut obj = ShowLeaderLines::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_show_leader_lines(obj);
                     }
                     _ => (),
@@ -285,10 +290,10 @@ impl DataLabels {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:dLbls" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:dLbls")
+            Event::Eof => return Err(unexpected_eof(reader, "c:dLbls"))
         );
     }
 
@@ -12,8 +12,11 @@ use crate::writer::driver::{
     write_start_tag,
     write_text_node,
 };
+use crate::reader::driver::invalid_xml;
+use crate::XlsxError;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FormatCode {
     text: Box<str>,
 }
@@ -39,10 +42,13 @@ impl FormatCode {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_text(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
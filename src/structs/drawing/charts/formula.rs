@@ -10,7 +10,16 @@ use quick_xml::{
 use super::super::super::Address;
 use super::super::super::StringValue;
 use crate::{
-    helper::address::is_address,
+    XlsxError,
+    helper::{
+        address::is_address,
+        formula::{
+            is_same_sheet_name,
+            remove_sheet_in_formula,
+            rename_sheet_in_formula,
+        },
+    },
+    reader::driver::invalid_xml,
     traits::AdjustmentCoordinateWithSheet,
     writer::driver::{
         write_end_tag,
@@ -20,6 +29,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Formula {
     address:      Address,
     string_value: StringValue,
@@ -86,14 +96,40 @@ impl Formula {
         self
     }
 
+    /// (This method is crate only.)
+    /// Replace the sheet name of the references to a renamed sheet.
+    pub(crate) fn rename_sheet_reference(&mut self, old_name: &str, new_name: &str) {
+        if self.string_value.has_value() {
+            let value = rename_sheet_in_formula(self.string_value.value_str(), old_name, new_name);
+            self.string_value.set_value(value);
+        } else if is_same_sheet_name(self.address.sheet_name(), old_name) {
+            self.address.set_sheet_name(new_name);
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Turn the references to a removed sheet into `#REF!`.
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        if self.string_value.has_value() {
+            let value = remove_sheet_in_formula(self.string_value.value_str(), sheet_name);
+            self.string_value.set_value(value);
+        } else if is_same_sheet_name(self.address.sheet_name(), sheet_name) {
+            let value = format!("#REF!{}", self.address.range().range());
+            self.set_string_value(value);
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_address_str(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
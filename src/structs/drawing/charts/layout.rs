@@ -12,14 +12,17 @@ use quick_xml::{
 
 use super::ManualLayout;
 use crate::{
+    XlsxError,
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Layout {
     manual_layout: Option<ManualLayout>,
 }
@@ -60,9 +63,9 @@ impl Layout {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -70,16 +73,16 @@ impl Layout {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:manualLayout" {
                     let mut obj = ManualLayout::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_manual_layout(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:layout" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:layout"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:layout")),
         );
     }
 
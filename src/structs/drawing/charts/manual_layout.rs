@@ -22,14 +22,17 @@ use super::{
     WidthMode,
 };
 use crate::{
+    XlsxError,
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManualLayout {
     height:        Option<Height>,
     height_mode:   Option<HeightMode>,
@@ -285,63 +288,63 @@ impl ManualLayout {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => match e.name().0 {
                 b"c:h" => {
                     let mut obj = Height::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_height(obj);
                 }
                 b"c:hMode" => {
                     let mut obj = HeightMode::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_height_mode(obj);
                 }
                 b"c:layoutTarget" => {
                     let mut obj = LayoutTarget::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_layout_target(obj);
                 }
                 b"c:x" => {
                     let mut obj = Left::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_left(obj);
                 }
                 b"c:xMode" => {
                     let mut obj = LeftMode::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_left_mode(obj);
                 }
                 b"c:y" => {
                     let mut obj = Top::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_top(obj);
                 }
                 b"c:yMode" => {
                     let mut obj = TopMode::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_top_mode(obj);
                 }
                 b"c:w" => {
                     let mut obj = Width::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_width(obj);
                 }
                 b"c:wMode" => {
                     let mut obj = WidthMode::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_width_mode(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:manualLayout" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:manualLayout"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:manualLayout")),
         );
     }
 
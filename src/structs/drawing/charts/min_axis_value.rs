@@ -0,0 +1,51 @@
+// c:min
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::super::super::DoubleValue;
+use crate::{
+    XlsxError,
+    reader::driver::required_attribute,
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinAxisValue {
+    val: DoubleValue,
+}
+impl MinAxisValue {
+    #[must_use]
+    pub fn val(&self) -> f64 {
+        self.val.value()
+    }
+
+    pub fn set_val(&mut self, value: f64) -> &mut MinAxisValue {
+        self.val.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        self.val.set_value_string(required_attribute(reader, e, "val")?);
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:min
+        write_start_tag(
+            writer,
+            "c:min",
+            vec![("val", &self.val.value_string()).into()],
+            true,
+        );
+    }
+}
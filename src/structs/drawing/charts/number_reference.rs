@@ -13,15 +13,18 @@ use quick_xml::{
 use super::Formula;
 use super::NumberingCache;
 use crate::{
+    XlsxError,
     structs::Workbook,
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NumberReference {
     formula:         Formula,
     numbering_cache: NumberingCache,
@@ -78,28 +81,34 @@ impl NumberReference {
         self
     }
 
+    /// Fill the numbering cache from the referenced cells.
+    pub fn refresh_cache(&mut self, wb: &Workbook) -> &mut Self {
+        self.numbering_cache.refresh(self.formula.address(), wb);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:f" => {
-                    self.formula.set_attributes(reader, e);
+                    self.formula.set_attributes(reader, e)?;
                 }
                 b"c:numCache" => {
-                    self.numbering_cache.set_attributes(reader, e);
+                    self.numbering_cache.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:numRef" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numRef"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:numRef")),
         );
     }
 
@@ -111,8 +120,9 @@ impl NumberReference {
         self.formula.write_to(writer);
 
         // c:numCache
-        self.numbering_cache
-            .write_to(writer, self.formula().address(), wb);
+        let mut numbering_cache = self.numbering_cache.clone();
+        numbering_cache.refresh(self.formula.address(), wb);
+        numbering_cache.write_to(writer);
 
         write_end_tag(writer, "c:numRef");
     }
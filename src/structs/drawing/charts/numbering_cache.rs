@@ -10,23 +10,35 @@ use quick_xml::{
     },
 };
 
-use super::FormatCode;
+use super::{
+    FormatCode,
+    StringPoint,
+};
 use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
     structs::{
         Address,
+        UInt32Value,
         Workbook,
     },
     writer::driver::{
         write_end_tag,
         write_start_tag,
-        write_text_node,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NumberingCache {
-    format_code: FormatCode,
+    format_code:       FormatCode,
+    point_count:       UInt32Value,
+    string_point_list: Vec<StringPoint>,
 }
 
 impl NumberingCache {
@@ -55,35 +67,104 @@ impl NumberingCache {
         self
     }
 
+    /// Get the number of cells covered by the reference.
+    /// Blank and non-numeric cells are counted but have no point.
+    #[must_use]
+    pub fn point_count(&self) -> u32 {
+        self.point_count.value()
+    }
+
+    pub fn set_point_count(&mut self, value: u32) -> &mut NumberingCache {
+        self.point_count.set_value(value);
+        self
+    }
+
+    #[must_use]
+    pub fn string_point_list(&self) -> &[StringPoint] {
+        &self.string_point_list
+    }
+
+    pub fn string_point_list_mut(&mut self) -> &mut Vec<StringPoint> {
+        &mut self.string_point_list
+    }
+
+    pub fn add_string_point_list(&mut self, value: StringPoint) -> &mut NumberingCache {
+        self.string_point_list.push(value);
+        self
+    }
+
+    /// Fill the cache from the cells referenced by `address`.
+    /// The cache is left untouched when the sheet cannot be resolved.
+    /// # Return value
+    /// * `bool` - true if the cache was refreshed.
+    pub(crate) fn refresh(&mut self, address: &Address, wb: &Workbook) -> bool {
+        let Some(cell_list) = wb.cells_by_address_crate(address) else {
+            return false;
+        };
+
+        let numeric_cells: Vec<_> = (0u32..)
+            .zip(cell_list.iter())
+            .filter_map(|(index, cell)| {
+                cell.filter(|v| v.cell_value().value_number().is_some())
+                    .map(|v| (index, v))
+            })
+            .collect();
+
+        let format_code = numeric_cells
+            .first()
+            .map_or("General", |(_, cell)| cell_format_code(cell));
+        self.format_code.set_text(format_code);
+        self.point_count
+            .set_value(cell_list.len().try_into().unwrap());
+        self.string_point_list.clear();
+        for (index, cell) in numeric_cells {
+            let mut point = StringPoint::default();
+            point.set_index(index);
+            point.numeric_value_mut().set_text(cell.value());
+            let cell_format_code = cell_format_code(cell);
+            if cell_format_code != format_code {
+                point.set_format_code(cell_format_code);
+            }
+            self.string_point_list.push(point);
+        }
+        true
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
-                if e.name().0 == b"c:formatCode" {
-                    self.format_code.set_attributes(reader, e);
+                match e.name().0 {
+                    b"c:formatCode" => {
+                        self.format_code.set_attributes(reader, e)?;
+                    }
+                    b"c:pt" => {
+                        let mut obj = StringPoint::default();
+                        obj.set_attributes(reader, e)?;
+                        self.add_string_point_list(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"c:ptCount" {
+                    set_string_from_xml!(self, e, point_count, "val");
                 }
             },
             Event::End(ref e) => {
                if e.name().0 == b"c:numCache" {
-                   return;
+                   return Ok(());
                }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numCache"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:numCache")),
         );
     }
 
-    pub(crate) fn write_to(
-        &self,
-        writer: &mut Writer<Cursor<Vec<u8>>>,
-        address: &Address,
-        wb: &Workbook,
-    ) {
-        let cell_value_list = wb.cell_value_by_address_crate(address);
-        let coll_value_count = cell_value_list.len().to_string();
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // c:numCache
         write_start_tag(writer, "c:numCache", vec![], false);
 
@@ -91,25 +172,25 @@ impl NumberingCache {
         self.format_code.write_to(writer);
 
         // c:ptCount
+        let point_count = self.point_count.value_string();
         write_start_tag(
             writer,
             "c:ptCount",
-            vec![("val", &coll_value_count).into()],
+            vec![("val", &point_count).into()],
             true,
         );
 
-        for (idx, cell_value) in cell_value_list.into_iter().enumerate() {
-            // c:pt
-            write_start_tag(writer, "c:pt", vec![("idx", idx.to_string()).into()], false);
-
-            // c:v
-            write_start_tag(writer, "c:v", vec![], false);
-            write_text_node(writer, cell_value.value());
-            write_end_tag(writer, "c:v");
-
-            write_end_tag(writer, "c:pt");
+        // c:pt
+        for obj in &self.string_point_list {
+            obj.write_to(writer, obj.index());
         }
 
         write_end_tag(writer, "c:numCache");
     }
 }
+
+fn cell_format_code(cell: &crate::structs::Cell) -> &str {
+    cell.style()
+        .number_format()
+        .map_or("General", |v| v.format_code())
+}
@@ -12,11 +12,13 @@ use super::super::super::{
     StringValue,
 };
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::required_attribute,
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NumberingFormat {
     format_code:   StringValue,
     source_linked: BooleanValue,
@@ -56,13 +58,14 @@ impl NumberingFormat {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.format_code
-            .set_value_string(get_attribute(e, b"formatCode").unwrap());
+            .set_value_string(required_attribute(reader, e, "formatCode")?);
         self.source_linked
-            .set_value_string(get_attribute(e, b"sourceLinked").unwrap());
+            .set_value_string(required_attribute(reader, e, "sourceLinked")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
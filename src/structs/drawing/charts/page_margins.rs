@@ -9,11 +9,13 @@ use quick_xml::{
 
 use super::super::super::DoubleValue;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::required_attribute,
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageMargins {
     bottom: DoubleValue,
     left:   DoubleValue,
@@ -121,18 +123,19 @@ impl PageMargins {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.bottom
-            .set_value_string(get_attribute(e, b"b").unwrap());
-        self.left.set_value_string(get_attribute(e, b"l").unwrap());
-        self.right.set_value_string(get_attribute(e, b"r").unwrap());
-        self.top.set_value_string(get_attribute(e, b"t").unwrap());
+            .set_value_string(required_attribute(reader, e, "b")?);
+        self.left.set_value_string(required_attribute(reader, e, "l")?);
+        self.right.set_value_string(required_attribute(reader, e, "r")?);
+        self.top.set_value_string(required_attribute(reader, e, "t")?);
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .set_value_string(required_attribute(reader, e, "header")?);
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .set_value_string(required_attribute(reader, e, "footer")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -10,6 +10,7 @@ use quick_xml::{
 use crate::writer::driver::write_start_tag;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageSetup {}
 impl PageSetup {
     pub(crate) fn set_attributes<R: std::io::BufRead>(_reader: &mut Reader<R>, _e: &BytesStart) {}
@@ -34,6 +34,7 @@ use super::{
     ValueAxis,
 };
 use crate::{
+    XlsxError,
     drawing::charts::DateAxis,
     structs::Workbook,
     traits::AdjustmentCoordinateWithSheet,
@@ -41,10 +42,12 @@ use crate::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlotArea {
     layout:           Layout,
     line_chart:       Option<LineChart>,
@@ -677,6 +680,38 @@ impl PlotArea {
         self.formula_mut()
     }
 
+    /// Fill the caches of every series from the referenced cells.
+    pub fn refresh_cache(&mut self, wb: &Workbook) -> &mut Self {
+        macro_rules! refresh_series {
+            ($($field:expr),+ $(,)?) => {
+                $(
+                    if let Some(v) = $field {
+                        for ser in v.area_chart_series_list_mut().area_chart_series_mut() {
+                            ser.refresh_cache(wb);
+                        }
+                    }
+                )+
+            };
+        }
+
+        refresh_series!(
+            &mut self.line_chart,
+            &mut self.line_3d_chart,
+            &mut self.pie_chart,
+            &mut self.pie_3d_chart,
+            &mut self.doughnut_chart,
+            &mut self.scatter_chart,
+            &mut self.bar_chart,
+            &mut self.bar_3d_chart,
+            &mut self.radar_chart,
+            &mut self.bubble_chart,
+            &mut self.area_chart,
+            &mut self.area_3d_chart,
+            &mut self.of_pie_chart,
+        );
+        self
+    }
+
     pub(crate) fn is_support(&self) -> bool {
         self.line_chart.is_some()
             || self.line_3d_chart.is_some()
@@ -697,111 +732,111 @@ impl PlotArea {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:layout" => {
-                    self.layout.set_attributes(reader, e, false);
+                    self.layout.set_attributes(reader, e, false)?;
                 }
                 b"c:lineChart" => {
                     let mut obj = LineChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_chart(obj);
                 }
                 b"c:line3DChart" => {
                     let mut obj = Line3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_3d_chart(obj);
                 }
                 b"c:pieChart" => {
                     let mut obj = PieChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pie_chart(obj);
                 }
                 b"c:pie3DChart" => {
                     let mut obj = Pie3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pie_3d_chart(obj);
                 }
                 b"c:doughnutChart" => {
                     let mut obj = DoughnutChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_doughnut_chart(obj);
                 }
                 b"c:scatterChart" => {
                     let mut obj = ScatterChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_scatter_chart(obj);
                 }
                 b"c:barChart" => {
                     let mut obj = BarChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bar_chart(obj);
                 }
                 b"c:bar3DChart" => {
                     let mut obj = Bar3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bar_3d_chart(obj);
                 }
                 b"c:radarChart" => {
                     let mut obj = RadarChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_radar_chart(obj);
                 }
                 b"c:bubbleChart" => {
                     let mut obj = BubbleChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_chart(obj);
                 }
                 b"c:areaChart" => {
                     let mut obj = AreaChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_area_chart(obj);
                 }
                 b"c:area3DChart" => {
                     let mut obj = Area3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_area_3d_chart(obj);
                 }
                 b"c:ofPieChart" => {
                     let mut obj = OfPieChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_of_pie_chart(obj);
                 }
                 b"c:catAx" => {
                     let mut obj = CategoryAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_category_axis(obj);
                 }
                 b"c:dateAx" => {
                     let mut obj = DateAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_date_axis(obj);
                 }
                 b"c:valAx" => {
                     let mut obj = ValueAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_value_axis(obj);
                 }
                 b"c:serAx" => {
                     let mut obj = SeriesAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_series_axis(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:plotArea" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:plotArea"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:plotArea")),
         );
     }
 
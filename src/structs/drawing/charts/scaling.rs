@@ -10,9 +10,17 @@ use quick_xml::{
     },
 };
 
-use super::Orientation;
+use super::{
+    MaxAxisValue,
+    MinAxisValue,
+    Orientation,
+};
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,8 +28,11 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scaling {
-    orientation: Orientation,
+    orientation:    Orientation,
+    max_axis_value: Option<MaxAxisValue>,
+    min_axis_value: Option<MinAxisValue>,
 }
 
 impl Scaling {
@@ -50,24 +61,75 @@ impl Scaling {
         self
     }
 
+    #[must_use]
+    pub fn max_axis_value(&self) -> Option<&MaxAxisValue> {
+        self.max_axis_value.as_ref()
+    }
+
+    pub fn max_axis_value_mut(&mut self) -> Option<&mut MaxAxisValue> {
+        self.max_axis_value.as_mut()
+    }
+
+    pub fn set_max_axis_value(&mut self, value: MaxAxisValue) -> &mut Self {
+        self.max_axis_value = Some(value);
+        self
+    }
+
+    pub fn remove_max_axis_value(&mut self) -> &mut Self {
+        self.max_axis_value = None;
+        self
+    }
+
+    #[must_use]
+    pub fn min_axis_value(&self) -> Option<&MinAxisValue> {
+        self.min_axis_value.as_ref()
+    }
+
+    pub fn min_axis_value_mut(&mut self) -> Option<&mut MinAxisValue> {
+        self.min_axis_value.as_mut()
+    }
+
+    pub fn set_min_axis_value(&mut self, value: MinAxisValue) -> &mut Self {
+        self.min_axis_value = Some(value);
+        self
+    }
+
+    pub fn remove_min_axis_value(&mut self) -> &mut Self {
+        self.min_axis_value = None;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
-                if e.name().0 == b"c:orientation" {
-                    self.orientation.set_attributes(reader, e);
+                match e.name().0 {
+                    b"c:orientation" => {
+                        self.orientation.set_attributes(reader, e)?;
+                    }
+                    b"c:max" => {
+                        let mut obj = MaxAxisValue::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_max_axis_value(obj);
+                    }
+                    b"c:min" => {
+                        let mut obj = MinAxisValue::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_min_axis_value(obj);
+                    }
+                    _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:scaling" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:scaling")
+            Event::Eof => return Err(unexpected_eof(reader, "c:scaling"))
         );
     }
 
@@ -78,6 +140,16 @@ impl Scaling {
         // c:orientation
         self.orientation.write_to(writer);
 
+        // c:max
+        if let Some(v) = &self.max_axis_value {
+            v.write_to(writer);
+        }
+
+        // c:min
+        if let Some(v) = &self.min_axis_value {
+            v.write_to(writer);
+        }
+
         write_end_tag(writer, "c:scaling");
     }
 }
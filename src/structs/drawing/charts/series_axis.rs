@@ -25,7 +25,11 @@ use super::{
 };
 use crate::{
     Workbook,
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -33,6 +37,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SeriesAxis {
     axis_id:             AxisId,
     scaling:             Scaling,
@@ -327,22 +332,22 @@ impl SeriesAxis {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                     b"c:scaling" => {
-                        self.scaling.set_attributes(reader, e);
+                        self.scaling.set_attributes(reader, e)?;
                     }
                     b"c:title" => {
                         let mut obj = Title::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_title(obj);
                     }
                     b"c:majorGridlines" => {
                         let mut obj = MajorGridlines::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_major_gridlines(obj);
                     }
                     _ => (),
@@ -351,43 +356,43 @@ impl SeriesAxis {
             Event::Empty(ref e) => {
                 match e.name().0 {
                     b"c:axId" => {
-                        self.axis_id.set_attributes(reader, e);
+                        self.axis_id.set_attributes(reader, e)?;
                     }
                     b"c:delete" => {
-                        self.delete.set_attributes(reader, e);
+                        self.delete.set_attributes(reader, e)?;
                     }
                     b"c:axPos" => {
-                        self.axis_position.set_attributes(reader, e);
+                        self.axis_position.set_attributes(reader, e)?;
                     }
                     b"c:majorGridlines" => {
                         let mut obj = MajorGridlines::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_major_gridlines(obj);
                     }
                     b"c:majorTickMark" => {
-                        self.major_tick_mark.set_attributes(reader, e);
+                        self.major_tick_mark.set_attributes(reader, e)?;
                     }
                     b"c:minorTickMark" => {
-                        self.minor_tick_mark.set_attributes(reader, e);
+                        self.minor_tick_mark.set_attributes(reader, e)?;
                     }
                     b"c:tickLblPos" => {
-                        self.tick_label_position.set_attributes(reader, e);
+                        self.tick_label_position.set_attributes(reader, e)?;
                     }
                     b"c:crossAx" => {
-                        self.crossing_axis.set_attributes(reader, e);
+                        self.crossing_axis.set_attributes(reader, e)?;
                     }
                     b"c:crosses" => {
-                        self.crosses.set_attributes(reader, e);
+                        self.crosses.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:serAx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:serAx")
+            Event::Eof => return Err(unexpected_eof(reader, "c:serAx"))
         );
     }
 
@@ -8,6 +8,8 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
     structs::StringValue,
     writer::driver::{
         write_end_tag,
@@ -17,6 +19,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SeriesText {
     value: StringValue,
 }
@@ -42,10 +45,13 @@ impl SeriesText {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_value(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
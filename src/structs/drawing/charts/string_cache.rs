@@ -10,64 +10,141 @@ use quick_xml::{
     },
 };
 
+use super::StringPoint;
 use crate::{
-    CellValue,
+    XlsxError,
     drawing::charts::Formula,
-    reader::driver::xml_read_loop,
-    structs::Workbook,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    structs::{
+        UInt32Value,
+        Workbook,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
-        write_text_node,
     },
 };
 
 #[derive(Clone, Default, Debug)]
-pub struct StringCache {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StringCache {
+    point_count:       UInt32Value,
+    string_point_list: Vec<StringPoint>,
+}
 
 impl StringCache {
-    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) {
+    /// Get the number of cells covered by the reference.
+    /// Blank cells are counted but have no point.
+    #[must_use]
+    pub fn point_count(&self) -> u32 {
+        self.point_count.value()
+    }
+
+    pub fn set_point_count(&mut self, value: u32) -> &mut Self {
+        self.point_count.set_value(value);
+        self
+    }
+
+    #[must_use]
+    pub fn string_point_list(&self) -> &[StringPoint] {
+        &self.string_point_list
+    }
+
+    pub fn string_point_list_mut(&mut self) -> &mut Vec<StringPoint> {
+        &mut self.string_point_list
+    }
+
+    pub fn add_string_point_list(&mut self, value: StringPoint) -> &mut Self {
+        self.string_point_list.push(value);
+        self
+    }
+
+    /// Fill the cache from the cells referenced by `formula`.
+    /// Cells are cached with their formatted value.
+    /// The cache is left untouched when the sheet cannot be resolved.
+    /// # Return value
+    /// * `bool` - true if the cache was refreshed.
+    pub(crate) fn refresh(&mut self, formula: &Formula, wb: &Workbook) -> bool {
+        if formula.has_string_value() {
+            let mut point = StringPoint::default();
+            point.numeric_value_mut().set_text(formula.address_str());
+            self.point_count.set_value(1);
+            self.string_point_list = vec![point];
+            return true;
+        }
+
+        let Some(cell_list) = wb.cells_by_address_crate(formula.address()) else {
+            return false;
+        };
+
+        self.point_count
+            .set_value(cell_list.len().try_into().unwrap());
+        self.string_point_list.clear();
+        for (index, cell) in (0u32..).zip(cell_list) {
+            let Some(cell) = cell else {
+                continue;
+            };
+            let value = cell.formatted_value();
+            if value.is_empty() {
+                continue;
+            }
+            let mut point = StringPoint::default();
+            point.set_index(index);
+            point.numeric_value_mut().set_text(value);
+            self.string_point_list.push(point);
+        }
+        true
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"c:pt" {
+                    let mut obj = StringPoint::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_string_point_list(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"c:ptCount" {
+                    set_string_from_xml!(self, e, point_count, "val");
+                }
+            },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strCache" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strCache")
+            Event::Eof => return Err(unexpected_eof(reader, "c:strCache"))
         );
     }
 
-    pub(crate) fn write_to(writer: &mut Writer<Cursor<Vec<u8>>>, formula: &Formula, wb: &Workbook) {
-        let mut cell = CellValue::default();
-        let cell_value_list = if formula.has_string_value() {
-            cell.set_value(formula.address_str());
-            vec![&cell]
-        } else {
-            wb.cell_value_by_address_crate(formula.address())
-        };
-        let coll_value_count = cell_value_list.len().to_string();
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // c:strCache
         write_start_tag(writer, "c:strCache", vec![], false);
 
         // c:ptCount
+        let point_count = self.point_count.value_string();
         write_start_tag(
             writer,
             "c:ptCount",
-            vec![("val", &coll_value_count).into()],
+            vec![("val", &point_count).into()],
             true,
         );
 
-        for (idx, cell_value) in cell_value_list.into_iter().enumerate() {
-            // c:pt
-            write_start_tag(writer, "c:pt", vec![("idx", idx.to_string()).into()], false);
-
-            // c:v
-            write_start_tag(writer, "c:v", vec![], false);
-            write_text_node(writer, cell_value.value());
-            write_end_tag(writer, "c:v");
-
-            write_end_tag(writer, "c:pt");
+        // c:pt
+        for obj in &self.string_point_list {
+            obj.write_to(writer, obj.index());
         }
 
         write_end_tag(writer, "c:strCache");
@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::StringPoint;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringLiteral {
     string_point_list: Vec<StringPoint>,
 }
@@ -54,22 +59,22 @@ impl StringLiteral {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:pt" {
                     let mut obj = StringPoint::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_string_point_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strLit" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strLit")
+            Event::Eof => return Err(unexpected_eof(reader, "c:strLit"))
         );
     }
 
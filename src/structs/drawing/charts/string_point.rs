@@ -12,19 +12,54 @@ use quick_xml::{
 // c:pt
 use super::NumericValue;
 use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    structs::{
+        StringValue,
+        UInt32Value,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringPoint {
+    index:         UInt32Value,
+    format_code:   StringValue,
     numeric_value: NumericValue,
 }
 
 impl StringPoint {
+    #[must_use]
+    pub fn index(&self) -> u32 {
+        self.index.value()
+    }
+
+    pub fn set_index(&mut self, value: u32) -> &mut Self {
+        self.index.set_value(value);
+        self
+    }
+
+    /// Get the number format of this point.
+    /// Only set when it differs from the format code of the cache.
+    #[must_use]
+    pub fn format_code(&self) -> Option<&str> {
+        self.format_code.value()
+    }
+
+    pub fn set_format_code<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.format_code.set_value(value);
+        self
+    }
+
     #[must_use]
     pub fn numeric_value(&self) -> &NumericValue {
         &self.numeric_value
@@ -53,28 +88,36 @@ impl StringPoint {
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, index, "idx");
+        set_string_from_xml!(self, e, format_code, "formatCode");
+
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:v" {
-                    self.numeric_value.set_attributes(reader, e);
+                    self.numeric_value.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:pt" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:pt"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:pt")),
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, index: u32) {
         // c:pt
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
         let index_str = index.to_string();
-        write_start_tag(writer, "c:pt", vec![("idx", &index_str).into()], false);
+        attributes.push(("idx", &index_str).into());
+        if let Some(v) = self.format_code.value() {
+            attributes.push(("formatCode", v).into());
+        }
+        write_start_tag(writer, "c:pt", attributes, false);
 
         // c:v
         self.numeric_value.write_to(writer);
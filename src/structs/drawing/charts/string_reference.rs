@@ -13,15 +13,18 @@ use quick_xml::{
 use super::Formula;
 use super::StringCache;
 use crate::{
+    XlsxError,
     structs::Workbook,
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringReference {
     formula:      Formula,
     string_cache: StringCache,
@@ -78,28 +81,34 @@ impl StringReference {
         self
     }
 
+    /// Fill the string cache from the referenced cells.
+    pub fn refresh_cache(&mut self, wb: &Workbook) -> &mut Self {
+        self.string_cache.refresh(&self.formula, wb);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:f" => {
-                    self.formula.set_attributes(reader, e);
+                    self.formula.set_attributes(reader, e)?;
                 }
                 b"c:strCache" => {
-                    StringCache::set_attributes(reader, e);
+                    self.string_cache.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strRef" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strRef"),
+            Event::Eof => return Err(unexpected_eof(reader, "c:strRef")),
         );
     }
 
@@ -111,7 +120,9 @@ impl StringReference {
         self.formula.write_to(writer);
 
         // c:strCache
-        StringCache::write_to(writer, self.formula(), wb);
+        let mut string_cache = self.string_cache.clone();
+        string_cache.refresh(&self.formula, wb);
+        string_cache.write_to(writer);
 
         write_end_tag(writer, "c:strRef");
     }
@@ -19,6 +19,7 @@ use super::{
     Delete,
     MajorGridlines,
     MajorTickMark,
+    MajorUnit,
     MinorTickMark,
     NumberingFormat,
     Scaling,
@@ -29,7 +30,11 @@ use super::{
 };
 use crate::{
     Workbook,
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -37,6 +42,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValueAxis {
     axis_id:             AxisId,
     scaling:             Scaling,
@@ -51,6 +57,7 @@ pub struct ValueAxis {
     crossing_axis:       CrossingAxis,
     crosses:             Crosses,
     cross_between:       CrossBetween,
+    major_unit:          Option<MajorUnit>,
     shape_properties:    Option<ShapeProperties>,
     text_properties:     Option<TextProperties>,
 }
@@ -181,6 +188,11 @@ impl ValueAxis {
         self
     }
 
+    pub fn remove_major_gridlines(&mut self) -> &mut Self {
+        self.major_gridlines = None;
+        self
+    }
+
     #[must_use]
     pub fn title(&self) -> Option<&Title> {
         self.title.as_ref()
@@ -381,6 +393,25 @@ impl ValueAxis {
         self
     }
 
+    #[must_use]
+    pub fn major_unit(&self) -> Option<&MajorUnit> {
+        self.major_unit.as_ref()
+    }
+
+    pub fn major_unit_mut(&mut self) -> Option<&mut MajorUnit> {
+        self.major_unit.as_mut()
+    }
+
+    pub fn set_major_unit(&mut self, value: MajorUnit) -> &mut Self {
+        self.major_unit = Some(value);
+        self
+    }
+
+    pub fn remove_major_unit(&mut self) -> &mut Self {
+        self.major_unit = None;
+        self
+    }
+
     #[must_use]
     pub fn shape_properties(&self) -> Option<&ShapeProperties> {
         self.shape_properties.as_ref()
@@ -435,79 +466,84 @@ impl ValueAxis {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:scaling" => {
-                    self.scaling.set_attributes(reader, e);
+                    self.scaling.set_attributes(reader, e)?;
                 }
                 b"c:title" => {
                     let mut obj = Title::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_title(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:txPr" => {
                     let mut obj = TextProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_properties(obj);
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_major_gridlines(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().0 {
                 b"c:axId" => {
-                    self.axis_id.set_attributes(reader, e);
+                    self.axis_id.set_attributes(reader, e)?;
                 }
                 b"c:delete" => {
-                    self.delete.set_attributes(reader, e);
+                    self.delete.set_attributes(reader, e)?;
                 }
                 b"c:axPos" => {
-                    self.axis_position.set_attributes(reader, e);
+                    self.axis_position.set_attributes(reader, e)?;
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_major_gridlines(obj);
                 }
                 b"c:numFmt" => {
-                    self.numbering_format.set_attributes(reader, e);
+                    self.numbering_format.set_attributes(reader, e)?;
                 }
                 b"c:majorTickMark" => {
-                    self.major_tick_mark.set_attributes(reader, e);
+                    self.major_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:minorTickMark" => {
-                    self.minor_tick_mark.set_attributes(reader, e);
+                    self.minor_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:tickLblPos" => {
-                    self.tick_label_position.set_attributes(reader, e);
+                    self.tick_label_position.set_attributes(reader, e)?;
                 }
                 b"c:crossAx" => {
-                    self.crossing_axis.set_attributes(reader, e);
+                    self.crossing_axis.set_attributes(reader, e)?;
                 }
                 b"c:crosses" => {
-                    self.crosses.set_attributes(reader, e);
+                    self.crosses.set_attributes(reader, e)?;
                 }
                 b"c:crossBetween" => {
-                    self.cross_between.set_attributes(reader, e);
+                    self.cross_between.set_attributes(reader, e)?;
+                }
+                b"c:majorUnit" => {
+                    let mut obj = MajorUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_major_unit(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:valAx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:valAx")
+            Event::Eof => return Err(unexpected_eof(reader, "c:valAx"))
         );
     }
 
@@ -568,6 +604,11 @@ impl ValueAxis {
         // c:crossBetween
         self.cross_between.write_to(writer);
 
+        // c:majorUnit
+        if let Some(v) = &self.major_unit {
+            v.write_to(writer);
+        }
+
         write_end_tag(writer, "c:valAx");
     }
 }
@@ -14,7 +14,11 @@ use super::{
     SystemColor,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -22,6 +26,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color2Type {
     rgb_color_model_hex: Option<Box<RgbColorModelHex>>,
     system_color:        Option<Box<SystemColor>>,
@@ -111,7 +116,7 @@ impl Color2Type {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -119,12 +124,12 @@ impl Color2Type {
                 match e.name().into_inner() {
                 b"a:srgbClr" => {
                     let mut obj = RgbColorModelHex::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.rgb_color_model_hex = Some(Box::new(obj));
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.system_color = Some(Box::new(obj));
                 }
                 _ => (),
@@ -143,11 +148,11 @@ impl Color2Type {
                     b"a:folHlink" |
                     b"a:hlink"    |
                     b"a:lt1"      |
-                    b"a:lt2"      => return,
+                    b"a:lt2"      => return Ok(()),
                     _             => (),
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "Color2Type")
+            Event::Eof => return Err(unexpected_eof(reader, "Color2Type"))
         );
     }
 
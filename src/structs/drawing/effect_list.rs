@@ -16,7 +16,11 @@ use super::{
     SoftEdge,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -24,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EffectList {
     glow:         Option<Box<Glow>>,
     outer_shadow: Option<Box<OuterShadow>>,
@@ -123,9 +128,9 @@ impl EffectList {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -133,7 +138,7 @@ impl EffectList {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:softEdge" {
                     let mut obj = SoftEdge::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_soft_edge(obj);
                 }
             },
@@ -141,12 +146,12 @@ impl EffectList {
                 match e.name().into_inner() {
                     b"a:glow" => {
                         let mut obj = Glow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_glow(obj);
                     }
                     b"a:outerShdw" => {
                         let mut obj = OuterShadow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_outer_shadow(obj);
                     }
                     _ => (),
@@ -154,10 +159,10 @@ impl EffectList {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectLst")
+            Event::Eof => return Err(unexpected_eof(reader, "a:effectLst"))
         );
     }
 
@@ -18,7 +18,11 @@ use super::{
     Shape3DType,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -26,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EffectStyle {
     effect_list:   Option<Box<EffectList>>,
     scene_3d_type: Option<Box<Scene3DType>>,
@@ -127,7 +132,7 @@ impl EffectStyle {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -135,17 +140,17 @@ impl EffectStyle {
                 match e.name().into_inner() {
                 b"a:effectLst" => {
                     let mut obj = EffectList::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.effect_list = Some(Box::new(obj));
                 }
                 b"a:scene3d" => {
                     let mut obj = Scene3DType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.scene_3d_type = Some(Box::new(obj));
                 }
                 b"a:sp3d" => {
                     let mut obj = Shape3DType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.shape_3d_type = Some(Box::new(obj));
                 }
                 _ => (),
@@ -153,10 +158,10 @@ impl EffectStyle {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectStyle" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectStyle")
+            Event::Eof => return Err(unexpected_eof(reader, "a:effectStyle"))
         );
     }
 
@@ -10,21 +10,23 @@ use quick_xml::{
     },
 };
 
-use crate::reader::driver::xml_read_loop;
+use crate::reader::driver::{unexpected_eof, xml_read_loop};
+use crate::XlsxError;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExtensionList {}
 impl ExtensionList {
     #[inline]
-    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) {
+    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:extLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:extLst")
+            Event::Eof => return Err(unexpected_eof(reader, "a:extLst"))
         );
     }
 
@@ -9,11 +9,13 @@ use quick_xml::{
 
 use super::super::super::Int64Value;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::required_attribute,
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Extents {
     cx: Int64Value,
     cy: Int64Value,
@@ -60,11 +62,12 @@ impl Extents {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.cx.set_value_string(get_attribute(e, b"cx").unwrap());
-        self.cy.set_value_string(get_attribute(e, b"cy").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.cx.set_value_string(required_attribute(reader, e, "cx")?);
+        self.cy.set_value_string(required_attribute(reader, e, "cy")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
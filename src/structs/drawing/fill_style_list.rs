@@ -14,7 +14,11 @@ use super::{
     SolidFill,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -22,6 +26,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FillStyleList {
     solid_fill:               Vec<SolidFill>,
     gradient_fill_collection: Vec<GradientFill>,
@@ -107,19 +112,19 @@ impl FillStyleList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:solidFill" => {
                         let mut obj = SolidFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.solid_fill.push(obj);
                     }
                     b"a:gradFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.gradient_fill_collection.push(obj);
                     }
                     _ => (),
@@ -127,10 +132,10 @@ impl FillStyleList {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fillStyleLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fillStyleLst")
+            Event::Eof => return Err(unexpected_eof(reader, "fillStyleLst"))
         );
     }
 
@@ -19,8 +19,14 @@ use crate::writer::driver::{
     write_end_tag,
     write_start_tag,
 };
+use crate::reader::driver::{
+    invalid_xml,
+    unexpected_eof,
+};
+use crate::XlsxError;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FontCollectionType {
     latin_font:             TextFontType,
     east_asian_font:        TextFontType,
@@ -187,55 +193,50 @@ impl FontCollectionType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"a:latin" => {
-                        self.latin_font.set_attributes(reader, e, true);
+                        self.latin_font.set_attributes(reader, e, true)?;
                     }
                     b"a:ea" => {
-                        self.east_asian_font.set_attributes(reader, e, true);
+                        self.east_asian_font.set_attributes(reader, e, true)?;
                     }
                     b"a:cs" => {
-                        self.complex_script_font.set_attributes(reader, e, true);
+                        self.complex_script_font.set_attributes(reader, e, true)?;
                     }
                     b"a:font" => {
                         let mut obj = SupplementalFont::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.add_supplemental_font_list(obj);
                     }
                     _ => (),
                 },
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"a:latin" => {
-                        self.latin_font.set_attributes(reader, e, false);
+                        self.latin_font.set_attributes(reader, e, false)?;
                     }
                     b"a:ea" => {
-                        self.east_asian_font.set_attributes(reader, e, false);
+                        self.east_asian_font.set_attributes(reader, e, false)?;
                     }
                     b"a:cs" => {
-                        self.complex_script_font.set_attributes(reader, e, false);
+                        self.complex_script_font.set_attributes(reader, e, false)?;
                     }
                     b"a:font" => {
                         let mut obj = SupplementalFont::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.add_supplemental_font_list(obj);
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"a:majorFont" | b"a:minorFont" => return,
+                    b"a:majorFont" | b"a:minorFont" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => {
-                    panic!(
-                        "Error: Could not find {} end element",
-                        "a:majorFont, a:minorFont"
-                    )
-                }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(unexpected_eof(reader, "a:majorFont, a:minorFont")),
+                Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
                 _ => (),
             }
             buf.clear();
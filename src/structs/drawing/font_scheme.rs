@@ -15,8 +15,10 @@ use super::{
     FontCollectionType,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -26,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FontScheme {
     name:       StringValue,
     major_font: FontCollectionType,
@@ -116,7 +119,7 @@ impl FontScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"name") {
             self.name.set_value(v);
         }
@@ -126,20 +129,20 @@ impl FontScheme {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:majorFont" => {
-                    self.major_font.set_attributes(reader, e);
+                    self.major_font.set_attributes(reader, e)?;
                 }
                 b"a:minorFont" => {
-                    self.minor_font.set_attributes(reader, e);
+                    self.minor_font.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fontScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fontScheme")
+            Event::Eof => return Err(unexpected_eof(reader, "a:fontScheme"))
         );
     }
 
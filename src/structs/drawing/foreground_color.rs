@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::SchemeColor;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForegroundColor {
     scheme_color: SchemeColor,
 }
@@ -59,21 +64,21 @@ impl ForegroundColor {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"a:schemeClr" {
-                    self.scheme_color.set_attributes(reader, e, is_empty);
+                    self.scheme_color.set_attributes(reader, e, is_empty)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fgClr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fgClr")
+            Event::Eof => return Err(unexpected_eof(reader, "a:fgClr"))
         );
     }
 
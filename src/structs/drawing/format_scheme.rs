@@ -18,8 +18,10 @@ use super::{
     LineStyleList,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -29,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FormatScheme {
     name:                       StringValue,
     fill_style_list:            FillStyleList,
@@ -177,7 +180,7 @@ impl FormatScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"name") {
             self.name.set_value(v);
         }
@@ -188,22 +191,22 @@ impl FormatScheme {
                 match e.name().into_inner() {
                     b"a:fillStyleLst" => {
                         let mut obj = FillStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.fill_style_list = obj;
                     }
                     b"a:lnStyleLst" => {
                         let mut obj = LineStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.line_style_list = obj;
                     }
                     b"a:effectStyleLst" => {
                         let mut obj = EffectStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.effect_style_list = obj;
                     }
                     b"a:bgFillStyleLst" => {
                         let mut obj = BackgroundFillStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.background_fill_style_list = obj;
                     }
                     _ => (),
@@ -211,10 +214,10 @@ impl FormatScheme {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fmtScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fmtScheme")
+            Event::Eof => return Err(unexpected_eof(reader, "a:fmtScheme"))
         );
     }
 
@@ -15,8 +15,10 @@ use super::{
     SchemeColor,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -26,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Glow {
     radius:       Int64Value,
     scheme_color: Option<Box<SchemeColor>>,
@@ -73,25 +76,25 @@ impl Glow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.radius
-            .set_value_string(get_attribute(e, b"rad").unwrap());
+            .set_value_string(required_attribute(reader, e, "rad")?);
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut obj = SchemeColor::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_scheme_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:glow" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:glow")
+            Event::Eof => return Err(unexpected_eof(reader, "a:glow"))
         );
     }
 
@@ -21,9 +21,11 @@ use super::{
     TileRectangle,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -33,6 +35,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradientFill {
     flip:                 EnumValue<TileFlipValues>,
     rotate_with_shape:    BooleanValue,
@@ -174,7 +177,7 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, flip, "flip");
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
@@ -185,26 +188,26 @@ impl GradientFill {
                 match e.name().into_inner() {
                 b"a:lin" => {
                     let mut obj = LinearGradientFill::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_linear_gradient_fill(obj);
                 }
                 b"a:tileRect" => {
                     let obj = TileRectangle::default();
-                    TileRectangle::set_attributes(reader, e, is_empty);
+                    TileRectangle::set_attributes(reader, e, is_empty)?;
                     self.set_tile_rectangle(obj);
                 }
                 b"a:gsLst" => {
-                    self.gradient_stop_list.set_attributes(reader, e);
+                    self.gradient_stop_list.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gradFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gradFill")
+            Event::Eof => return Err(unexpected_eof(reader, "a:gradFill"))
         );
     }
 
@@ -15,8 +15,10 @@ use super::{
     SchemeColor,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -26,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradientStop {
     position:            i32,
     scheme_color:        Option<Box<SchemeColor>>,
@@ -116,9 +119,9 @@ impl GradientStop {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"pos") {
-            self.set_position(v.parse::<i32>().unwrap());
+            self.set_position(v.parse::<i32>().unwrap_or_default());
         }
 
         xml_read_loop!(
@@ -128,12 +131,12 @@ impl GradientStop {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -141,10 +144,10 @@ impl GradientStop {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gs")
+            Event::Eof => return Err(unexpected_eof(reader, "a:gs"))
         );
     }
 
@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::GraphicData;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::raw::RawRelationships,
     traits::AdjustmentCoordinateWithSheet,
     writer::driver::{
@@ -22,6 +26,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Graphic {
     graphic_data: GraphicData,
 }
@@ -62,21 +67,21 @@ impl Graphic {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
                     self.graphic_data
-                        .set_attributes(reader, e, drawing_relationships);
+                        .set_attributes(reader, e, drawing_relationships)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphic" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphic")
+            Event::Eof => return Err(unexpected_eof(reader, "a:graphic"))
         );
     }
 
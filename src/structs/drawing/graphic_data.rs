@@ -12,13 +12,15 @@ use quick_xml::{
 
 use super::charts::ChartSpace;
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWINGML_CHART_NS,
         REL_OFC_NS,
     },
     reader::{
         driver::{
-            get_attribute,
+            required_attribute,
+            unexpected_eof,
             xml_read_loop,
         },
         xlsx::chart,
@@ -32,6 +34,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphicData {
     chart_space: ChartSpace,
 }
@@ -72,24 +75,23 @@ impl GraphicData {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"c:chart" {
-                    let chart_id = get_attribute(e, b"r:id").unwrap();
-                    let relationship = drawing_relationships
-                        .unwrap()
-                        .relationship_by_rid(&chart_id);
-                    chart::read(relationship.raw_file(), &mut self.chart_space);
+                    let chart_id = required_attribute(reader, e, "r:id")?;
+                    let relationship =
+                        RawRelationships::find_by_rid(drawing_relationships, &chart_id)?;
+                    chart::read(relationship.raw_file(), &mut self.chart_space)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphicData")
+            Event::Eof => return Err(unexpected_eof(reader, "a:graphicData"))
         );
     }
 
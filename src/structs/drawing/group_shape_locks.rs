@@ -17,6 +17,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupShapeLocks {
     no_change_aspect: BooleanValue,
     no_grouping:      BooleanValue,
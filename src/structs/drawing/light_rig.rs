@@ -17,9 +17,11 @@ use super::{
     Rotation,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -29,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightRig {
     rig:        EnumValue<LightRigValues>,
     definition: EnumValue<LightRigDirectionValues>,
@@ -109,12 +112,12 @@ impl LightRig {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rig, "rig");
         set_string_from_xml!(self, e, definition, "dir");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -128,10 +131,10 @@ impl LightRig {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lightRig" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lightRig")
+            Event::Eof => return Err(unexpected_eof(reader, "a:lightRig"))
         );
     }
 
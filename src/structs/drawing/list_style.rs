@@ -18,7 +18,11 @@ use super::{
     TextParagraphPropertiesType,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -26,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListStyle {
     effect_list:                    Option<Box<EffectList>>,
     text_paragraph_properties_type: HashMap<Box<str>, Box<TextParagraphPropertiesType>>,
@@ -456,7 +461,7 @@ impl ListStyle {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
@@ -467,52 +472,52 @@ impl ListStyle {
                     }
                     b"a:defPPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_default_paragraph_properties(obj);
                     }
                     b"a:lvl1pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level1_paragraph_properties(obj);
                     }
                     b"a:lvl2pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level2_paragraph_properties(obj);
                     }
                     b"a:lvl3pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level3_paragraph_properties(obj);
                     }
                     b"a:lvl4pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level4_paragraph_properties(obj);
                     }
                     b"a:lvl5pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level5_paragraph_properties(obj);
                     }
                     b"a:lvl6pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level6_paragraph_properties(obj);
                     }
                     b"a:lvl7pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level7_paragraph_properties(obj);
                     }
                     b"a:lvl8pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level8_paragraph_properties(obj);
                     }
                     b"a:lvl9pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level9_paragraph_properties(obj);
                     }
                     _ => (),
@@ -520,10 +525,10 @@ impl ListStyle {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lstStyle" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lstStyle")
+            Event::Eof => return Err(unexpected_eof(reader, "a:lstStyle"))
         );
     }
 
@@ -17,8 +17,10 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -28,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OuterShadow {
     blur_radius:         StringValue,
     alignment:           StringValue,
@@ -267,7 +270,7 @@ impl OuterShadow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"blurRad") {
             self.set_blur_radius(v);
         }
@@ -297,17 +300,17 @@ impl OuterShadow {
                 match e.name().into_inner() {
                     b"a:prstClr" => {
                         let mut obj = PresetColor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_preset_color(obj);
                     }
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -315,10 +318,10 @@ impl OuterShadow {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:outerShdw" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:outerShdw")
+            Event::Eof => return Err(unexpected_eof(reader, "a:outerShdw"))
         );
     }
 
@@ -23,10 +23,12 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     drawing::SystemColor,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -40,6 +42,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Outline {
     width:              UInt32Value,
     cap_type:           StringValue,
@@ -405,9 +408,9 @@ impl Outline {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"w") {
-            self.set_width(v.parse::<u32>().unwrap());
+            self.set_width(v.parse::<u32>().unwrap_or_default());
         }
 
         if let Some(v) = get_attribute(e, b"cap") {
@@ -427,47 +430,47 @@ impl Outline {
                 match e.name().into_inner() {
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:gradFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     b"a:tailEnd" => {
                         let mut obj = TailEnd::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_tail_end(obj);
                     }
                     b"a:noFill" => {
                         let obj = NoFill::default();
-                        NoFill::set_attributes(reader, e, is_empty);
+                        NoFill::set_attributes(reader, e, is_empty)?;
                         self.set_no_fill(obj);
                     }
                     b"a:bevel" => {
                         let obj = Bevel::default();
-                        Bevel::set_attributes(reader, e, is_empty);
+                        Bevel::set_attributes(reader, e, is_empty)?;
                         self.set_bevel(obj);
                     }
                     b"a:miter" => {
                         let mut obj = Miter::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_miter(obj);
                     }
                     b"a:prstDash" => {
                         let mut obj = PresetDash::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_preset_dash(obj);
                     }
                     b"a:round" => {
                         let obj = Round::default();
-                        Round::set_attributes(reader, e, is_empty);
+                        Round::set_attributes(reader, e, is_empty)?;
                         self.set_round(obj);
                     }
                     b"a:sysClr" => {
                         let mut obj = SystemColor::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_system_color(obj);
                     }
                     _ => (),
@@ -475,10 +478,10 @@ impl Outline {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:ln" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:ln")
+            Event::Eof => return Err(unexpected_eof(reader, "a:ln"))
         );
     }
 
@@ -16,7 +16,11 @@ use super::{
     RunProperties,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -24,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Paragraph {
     paragraph_properties:    ParagraphProperties,
     run:                     Vec<Run>,
@@ -118,23 +123,23 @@ impl Paragraph {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
                 let is_empty = matches!(n, Event::Empty(_));
                 match e.name().into_inner() {
                     b"a:pPr" => {
-                        self.paragraph_properties.set_attributes(reader, e, is_empty);
+                        self.paragraph_properties.set_attributes(reader, e, is_empty)?;
                     }
                     b"a:r" => {
                         let mut run = Run::default();
-                        run.set_attributes(reader, e);
+                        run.set_attributes(reader, e)?;
                         self.add_run(run);
                     }
                     b"a:endParaRPr" => {
                         let mut run_properties = RunProperties::default();
-                        run_properties.set_attributes(reader, e, is_empty);
+                        run_properties.set_attributes(reader, e, is_empty)?;
                         self.set_end_para_run_properties(run_properties);
                     }
                     _ => (),
@@ -142,10 +147,10 @@ impl Paragraph {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:p" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:p")
+            Event::Eof => return Err(unexpected_eof(reader, "a:p"))
         );
     }
 
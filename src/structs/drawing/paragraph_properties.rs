@@ -18,9 +18,11 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -30,6 +32,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParagraphProperties {
     right_to_left:          StringValue,
     alignment:              EnumValue<TextAlignmentTypeValues>,
@@ -141,14 +144,14 @@ impl ParagraphProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"rtl") {
             self.set_right_to_left(v);
         }
         set_string_from_xml!(self, e, alignment, "algn");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -158,12 +161,12 @@ impl ParagraphProperties {
                 match e.name().into_inner() {
                 b"a:defRPr" => {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_default_run_properties(obj);
                 }
                 b"a:lnSpc" => {
                     let mut obj = LineSpacing::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_spacing(obj);
                 }
                 _ => (),
@@ -171,10 +174,10 @@ impl ParagraphProperties {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:pPr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:pPr")
+            Event::Eof => return Err(unexpected_eof(reader, "a:pPr"))
         );
     }
 
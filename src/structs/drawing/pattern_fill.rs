@@ -15,8 +15,10 @@ use super::{
     ForegroundColor,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -26,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PatternFill {
     preset:           Box<str>,
     foreground_color: ForegroundColor,
@@ -127,7 +130,7 @@ impl PatternFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"prst") {
             self.set_preset(v);
         }
@@ -137,20 +140,20 @@ impl PatternFill {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:fgClr" => {
-                        self.foreground_color.set_attributes(reader, e);
+                        self.foreground_color.set_attributes(reader, e)?;
                     },
                     b"a:bgClr" => {
-                        self.background_color.set_attributes(reader, e);
+                        self.background_color.set_attributes(reader, e)?;
                     },
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:pattFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:pattFill")
+            Event::Eof => return Err(unexpected_eof(reader, "a:pattFill"))
         );
     }
 
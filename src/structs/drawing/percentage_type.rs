@@ -11,15 +11,18 @@ use quick_xml::{
 
 use super::super::Int32Value;
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PercentageType {
     val: Int32Value,
 }
@@ -50,33 +53,33 @@ impl PercentageType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, val, "val");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lum" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:lumMod" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:lumOff" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:sat" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:satMod" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lum,a:lumMod,a:lumOff,a:sat,a:satMod")
+            Event::Eof => return Err(unexpected_eof(reader, "a:lum,a:lumMod,a:lumOff,a:sat,a:satMod"))
         );
     }
 
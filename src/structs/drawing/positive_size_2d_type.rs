@@ -12,16 +12,19 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
     },
     structs::Int64Value,
     writer::driver::write_start_tag,
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositiveSize2DType {
     cx: Int64Value,
     cy: Int64Value,
@@ -70,26 +73,23 @@ impl PositiveSize2DType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, cx, "cx");
         set_string_from_xml!(self, e, cy, "cy");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"a:chExt" | b"a:ext" => return,
+                    b"a:chExt" | b"a:ext" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "a:ext,a:chExt"
-            )
+            Event::Eof => return Err(unexpected_eof(reader, "a:ext,a:chExt"))
         );
     }
 
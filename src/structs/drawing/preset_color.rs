@@ -12,8 +12,10 @@ use quick_xml::{
 
 use super::alpha::Alpha;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -23,6 +25,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PresetColor {
     val:   Box<str>,
     alpha: Option<Alpha>,
@@ -80,24 +83,24 @@ impl PresetColor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(required_attribute(reader, e, "val")?);
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:alpha" {
                     let mut alpha = Alpha::default();
-                    alpha.set_attributes(reader, e);
+                    alpha.set_attributes(reader, e)?;
                     self.set_alpha(alpha);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstClr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstClr")
+            Event::Eof => return Err(unexpected_eof(reader, "a:prstClr"))
         );
     }
 
@@ -15,14 +15,17 @@ use super::{
     PresetLineDashValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PresetDash {
     val: EnumValue<PresetLineDashValues>,
 }
@@ -52,21 +55,21 @@ impl PresetDash {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.val.set_value_string(required_attribute(reader, e, "val")?);
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstDash" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstDash")
+            Event::Eof => return Err(unexpected_eof(reader, "a:prstDash"))
         );
     }
 
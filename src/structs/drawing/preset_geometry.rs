@@ -12,8 +12,10 @@ use quick_xml::{
 
 use super::adjust_value_list::AdjustValueList;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -23,6 +25,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PresetGeometry {
     geometry:          Box<str>,
     adjust_value_list: AdjustValueList,
@@ -271,22 +274,22 @@ impl PresetGeometry {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_geometry(get_attribute(e, b"prst").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_geometry(required_attribute(reader, e, "prst")?);
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    self.adjust_value_list_mut().set_attributes(reader, e);
+                    self.adjust_value_list_mut().set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstGeom" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstGeom")
+            Event::Eof => return Err(unexpected_eof(reader, "a:prstGeom"))
         );
     }
 
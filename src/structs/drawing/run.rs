@@ -11,7 +11,12 @@ use quick_xml::{
 
 use super::run_properties::RunProperties;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        invalid_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +25,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Run {
     text:           Box<str>,
     run_properties: RunProperties,
@@ -77,20 +83,22 @@ impl Run {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
                 let is_empty = matches!(n, Event::Empty(_));
                 match e.name().into_inner(){
                     b"a:rPr"=> {
-                        self.run_properties.set_attributes(reader, e, is_empty);
+                        self.run_properties.set_attributes(reader, e, is_empty)?;
                     }
                     b"a:t"=> {
                         if !is_empty {
                             let mut buf = Vec::new();
                             let text = crate::helper::utils::unescape_xml_text(
-                                &reader.read_text_into(e.name(), &mut buf).unwrap()
+                                &reader
+                                    .read_text_into(e.name(), &mut buf)
+                                    .map_err(|e| invalid_xml(reader.error_position(), &e))?
                             );
                             self.set_text(text);
                         }
@@ -100,10 +108,10 @@ impl Run {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"a:r" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:r")
+            Event::Eof => return Err(unexpected_eof(reader, "a:r"))
         );
     }
 
@@ -23,8 +23,10 @@ use super::{
     TextFontType,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::StringValue,
@@ -35,6 +37,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunProperties {
     text:                 Box<str>,
     kumimoji:             StringValue,
@@ -492,7 +495,7 @@ impl RunProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"kumimoji") {
             self.set_kumimoji(v);
         }
@@ -522,7 +525,7 @@ impl RunProperties {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -532,42 +535,42 @@ impl RunProperties {
                 match e.name().into_inner() {
                 b"a:solidFill" => {
                     let mut obj = SolidFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_solid_fill(obj);
                 }
                 b"a:ln" => {
                     let mut obj = Outline::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_outline(obj);
                 }
                 b"a:gradFill" => {
                     let mut obj = GradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_gradient_fill(obj);
                 }
                 b"a:effectLst" => {
                     let mut effect_list = EffectList::default();
-                    effect_list.set_attributes(reader, e, is_empty);
+                    effect_list.set_attributes(reader, e, is_empty)?;
                     self.set_effect_list(effect_list);
                 }
                 b"a:latin" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_latin_font(obj);
                 }
                 b"a:ea" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_east_asian_font(obj);
                 }
                 b"a:cs" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_complex_script_font(obj);
                 }
                 b"a:noFill" => {
                     let obj = NoFill::default();
-                    NoFill::set_attributes(reader, e, is_empty);
+                    NoFill::set_attributes(reader, e, is_empty)?;
                     self.set_no_fill(obj);
                 }
                 _ => (),
@@ -575,14 +578,11 @@ impl RunProperties {
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"a:rPr" | b"a:endParaRPr" | b"a:defRPr" => return,
+                    b"a:rPr" | b"a:endParaRPr" | b"a:defRPr" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "a:rPr, a:endParaRPr, a:defRPr"
-            )
+            Event::Eof => return Err(unexpected_eof(reader, "a:rPr, a:endParaRPr, a:defRPr"))
         );
     }
 
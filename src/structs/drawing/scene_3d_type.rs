@@ -15,7 +15,11 @@ use super::{
     LightRig,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scene3DType {
     camera:    Option<Camera>,
     light_rig: Option<LightRig>,
@@ -71,7 +76,7 @@ impl Scene3DType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -79,12 +84,12 @@ impl Scene3DType {
                 match e.name().into_inner() {
                     b"a:camera" => {
                         let mut obj = Camera::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_camera(obj);
                     }
                     b"a:lightRig" => {
                         let mut obj = LightRig::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_light_rig(obj);
                     }
                     _ => (),
@@ -92,10 +97,10 @@ impl Scene3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:scene3d" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:scene3d")
+            Event::Eof => return Err(unexpected_eof(reader, "a:scene3d"))
         );
     }
 
@@ -17,9 +17,11 @@ use super::{
     PresetMaterialTypeValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -29,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shape3DType {
     preset_material: EnumValue<PresetMaterialTypeValues>,
     bevel_top:       Option<Box<BevelTop>>,
@@ -117,7 +120,7 @@ impl Shape3DType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset_material, "prstMaterial");
 
         xml_read_loop!(
@@ -139,10 +142,10 @@ impl Shape3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:sp3d" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:sp3d")
+            Event::Eof => return Err(unexpected_eof(reader, "a:sp3d"))
         );
     }
 
@@ -6,6 +6,7 @@ use quick_xml::Writer;
 use crate::writer::driver::write_start_tag;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeGuide {
     name: Box<str>,
     fmla: Box<str>,
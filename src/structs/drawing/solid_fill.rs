@@ -15,8 +15,12 @@ use super::{
     scheme_color::SchemeColor,
 };
 use crate::{
+    XlsxError,
     drawing::SystemColor,
-    reader::driver::xml_read_loop,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -24,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SolidFill {
     scheme_color:        Option<Box<SchemeColor>>,
     rgb_color_model_hex: Option<Box<RgbColorModelHex>>,
@@ -122,7 +127,7 @@ impl SolidFill {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -130,17 +135,17 @@ impl SolidFill {
                 match e.name().into_inner() {
                 b"a:schemeClr" => {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, is_empty);
+                    scheme_color.set_attributes(reader, e, is_empty)?;
                     self.set_scheme_color(scheme_color);
                 }
                 b"a:srgbClr" => {
                     let mut rgb_color_model_hex = RgbColorModelHex::default();
-                    rgb_color_model_hex.set_attributes(reader, e, is_empty);
+                    rgb_color_model_hex.set_attributes(reader, e, is_empty)?;
                     self.set_rgb_color_model_hex(rgb_color_model_hex);
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_system_color(obj);
                 }
                 _ => (),
@@ -148,10 +153,10 @@ impl SolidFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:solidFill" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:solidFill")
+            Event::Eof => return Err(unexpected_eof(reader, "a:solidFill"))
         );
     }
 
@@ -17,9 +17,11 @@ use super::super::{
     Stretch,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::raw::RawRelationships,
@@ -30,6 +32,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlipFill {
     rotate_with_shape: BooleanValue,
     blip:              Blip,
@@ -152,7 +155,7 @@ impl BlipFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
         xml_read_loop!(
@@ -161,10 +164,10 @@ impl BlipFill {
                 match e.name().into_inner() {
                     b"a:blip" => {
                         self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), false);
+                            .set_attributes(reader, e, drawing_relationships.unwrap(), false)?;
                         }
                     b"a:stretch" => {
-                        self.stretch.set_attributes(reader, e);
+                        self.stretch.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -173,7 +176,7 @@ impl BlipFill {
                 match e.name().into_inner() {
                     b"a:blip" => {
                         self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), true);
+                            .set_attributes(reader, e, drawing_relationships.unwrap(), true)?;
                         }
                     b"a:srcRect" => {
                         let mut source_rectangle = SourceRectangle::default();
@@ -185,10 +188,10 @@ impl BlipFill {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:blipFill" | b"blipFill") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "blipFill")
+            Event::Eof => return Err(unexpected_eof(reader, "blipFill"))
         );
     }
 
@@ -17,7 +17,11 @@ use super::{
     ShapeStyle,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::raw::RawRelationships,
     writer::driver::{
         write_end_tag,
@@ -26,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConnectionShape {
     anchor:                                 Anchor,
     non_visual_connection_shape_properties: NonVisualConnectionShapeProperties,
@@ -162,35 +167,56 @@ impl ConnectionShape {
         self.shape_style = value;
     }
 
+    /// Get the id of the connector in the drawing.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> u32 {
+        self.non_visual_connection_shape_properties
+            .non_visual_drawing_properties()
+            .id()
+    }
+
+    /// Set the line color and width (EMU).
+    /// # Arguments
+    /// * `color` - RGB hex string. ex) "2F528F"
+    /// * `width` - Width in EMU. ex) 12700 (1pt)
+    #[inline]
+    pub fn set_outline<S: Into<String>>(&mut self, color: S, width: u32) -> &mut Self {
+        self.shape_properties
+            .set_outline_color(color)
+            .set_outline_width(width);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvCxnSpPr" => {
                         self.non_visual_connection_shape_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                         }
                     b"xdr:spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     b"xdr:style" => {
-                        self.shape_style.set_attributes(reader, e);
+                        self.shape_style.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cxnSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cxnSp")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:cxnSp"))
         );
     }
 
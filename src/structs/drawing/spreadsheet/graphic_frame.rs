@@ -19,9 +19,11 @@ use super::{
     Transform,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::raw::RawRelationships,
@@ -33,6 +35,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphicFrame {
     r#macro:                             StringValue,
     non_visual_graphic_frame_properties: NonVisualGraphicFrameProperties,
@@ -165,7 +168,7 @@ impl GraphicFrame {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#macro, "macro");
 
         xml_read_loop!(
@@ -174,24 +177,24 @@ impl GraphicFrame {
                 match e.name().into_inner() {
                     b"xdr:nvGraphicFramePr" => {
                         self.non_visual_graphic_frame_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                         }
                     b"xdr:xfrm" => {
-                        self.transform.set_attributes(reader, e);
+                        self.transform.set_attributes(reader, e)?;
                     }
                     b"a:graphic" => {
                         self.graphic
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"xdr:graphicFrame" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:graphicFrame")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:graphicFrame"))
         );
     }
 
@@ -17,7 +17,11 @@ use super::{
     Shape,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::raw::RawRelationships,
     writer::driver::{
         write_end_tag,
@@ -26,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupShape {
     non_visual_group_shape_properties: NonVisualGroupShapeProperties,
     group_shape_properties:            GroupShapeProperties,
@@ -157,25 +162,25 @@ impl GroupShape {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvGrpSpPr" => {
-                        self.non_visual_group_shape_properties.set_attributes(reader, e);
+                        self.non_visual_group_shape_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:grpSpPr" => {
-                        self.group_shape_properties.set_attributes(reader, e);
+                        self.group_shape_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_picture_collection(obj);
                     }
                     b"xdr:sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_shape_collection(obj);
                     }
                     _ => (),
@@ -183,10 +188,10 @@ impl GroupShape {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:grpSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSp")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:grpSp"))
         );
     }
 
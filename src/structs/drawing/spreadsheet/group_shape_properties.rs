@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::super::Transform2D;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupShapeProperties {
     transform2d: Option<Transform2D>,
 }
@@ -59,22 +64,22 @@ impl GroupShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"a:xfrm" {
                     let mut obj = Transform2D::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_transform2d(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"xdr:grpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSpPr")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:grpSpPr"))
         );
     }
 
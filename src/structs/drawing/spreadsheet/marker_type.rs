@@ -18,6 +18,12 @@ use crate::{
         index_from_coordinate,
         is_remove_coordinate,
     },
+    XlsxError,
+    reader::driver::{
+        invalid_element,
+        invalid_xml,
+        unexpected_eof,
+    },
     traits::AdjustmentCoordinate,
     writer::driver::{
         write_end_tag,
@@ -27,6 +33,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MarkerType {
     col:     u32,
     col_off: i32,
@@ -146,7 +153,7 @@ impl MarkerType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
@@ -154,41 +161,55 @@ impl MarkerType {
                     b"xdr:col" | b"col" => {
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap(),
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?,
                         );
-                        self.col = text.parse::<u32>().unwrap();
+                        self.col = text
+                            .parse::<u32>()
+                            .map_err(|e| invalid_element(reader, "xdr:col", e.to_string()))?;
                     }
                     b"xdr:colOff" | b"colOff" => {
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap(),
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?,
                         );
-                        self.col_off = text.parse::<i32>().unwrap();
+                        self.col_off = text
+                            .parse::<i32>()
+                            .map_err(|e| invalid_element(reader, "xdr:colOff", e.to_string()))?;
                     }
                     b"xdr:row" | b"row" => {
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap(),
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?,
                         );
-                        self.row = text.parse::<u32>().unwrap();
+                        self.row = text
+                            .parse::<u32>()
+                            .map_err(|e| invalid_element(reader, "xdr:row", e.to_string()))?;
                     }
                     b"xdr:rowOff" | b"rowOff" => {
                         let mut buf = Vec::new();
                         let text = crate::helper::utils::unescape_xml_text(
-                            &reader.read_text_into(e.name(), &mut buf).unwrap(),
+                            &reader
+                                .read_text_into(e.name(), &mut buf)
+                                .map_err(|e| invalid_xml(reader.error_position(), &e))?,
                         );
-                        self.row_off = text.parse::<i32>().unwrap();
+                        self.row_off = text
+                            .parse::<i32>()
+                            .map_err(|e| invalid_element(reader, "xdr:rowOff", e.to_string()))?;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"xdr:from" | b"from" | b"xdr:to" | b"to" => return,
+                    b"xdr:from" | b"from" | b"xdr:to" | b"to" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => {
-                    panic!("Error: Could not find {} end element", "xdr:from,xdr:to")
-                }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(unexpected_eof(reader, "xdr:from,xdr:to")),
+                Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
                 _ => (),
             }
             buf.clear();
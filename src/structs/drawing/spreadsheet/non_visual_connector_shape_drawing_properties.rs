@@ -15,7 +15,11 @@ use super::super::{
     StartConnection,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonVisualConnectorShapeDrawingProperties {
     start_connection: Option<Box<StartConnection>>,
     end_connection:   Option<Box<EndConnection>>,
@@ -79,19 +84,19 @@ impl NonVisualConnectorShapeDrawingProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:stCxn" => {
                         let mut connection_type = StartConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_start_connection(connection_type);
                     }
                     b"a:endCxn" => {
                         let mut connection_type = EndConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_end_connection(connection_type);
                     }
                     _ => (),
@@ -99,10 +104,10 @@ impl NonVisualConnectorShapeDrawingProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvCxnSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvCxnSpPr")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:cNvCxnSpPr"))
         );
     }
 
@@ -16,9 +16,12 @@ use super::super::super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        required_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -28,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonVisualDrawingProperties {
     id:     UInt32Value,
     name:   StringValue,
@@ -97,24 +101,24 @@ impl NonVisualDrawingProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id.set_value_string(required_attribute(reader, e, "id")?);
         self.name
-            .set_value_string(get_attribute(e, b"name").unwrap());
+            .set_value_string(required_attribute(reader, e, "name")?);
         set_string_from_xml!(self, e, hidden, "hidden");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:cNvPr" | b"cNvPr") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cNvPr")
+            Event::Eof => return Err(unexpected_eof(reader, "cNvPr"))
         );
     }
 
@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::super::GroupShapeLocks;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonVisualGroupShapeDrawingProperties {
     group_shape_locks: Option<GroupShapeLocks>,
 }
@@ -60,9 +65,9 @@ impl NonVisualGroupShapeDrawingProperties {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -76,10 +81,10 @@ impl NonVisualGroupShapeDrawingProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvGrpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvGrpSpPr")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:cNvGrpSpPr"))
         );
     }
 
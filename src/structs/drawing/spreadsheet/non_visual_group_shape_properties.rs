@@ -15,7 +15,11 @@ use super::{
     NonVisualGroupShapeDrawingProperties,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonVisualGroupShapeProperties {
     non_visual_drawing_properties:             NonVisualDrawingProperties,
     non_visual_group_shape_drawing_properties: NonVisualGroupShapeDrawingProperties,
@@ -113,18 +118,18 @@ impl NonVisualGroupShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner(){
                     b"xdr:cNvPr" =>{
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                     }
                     b"a:cNvGrpSpPr"=> {
                         self.non_visual_group_shape_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                     }
                     _=>()
                 }
@@ -133,21 +138,21 @@ impl NonVisualGroupShapeProperties {
                 match e.name().into_inner() {
                     b"xdr:cNvPr" =>{
                         self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                     }
                     b"a:cNvGrpSpPr" =>{
                         self.non_visual_group_shape_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                     }
                     _ => ()
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvGrpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvGrpSpPr")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:nvGrpSpPr"))
         );
     }
 
@@ -12,9 +12,11 @@ use quick_xml::{
 
 use super::super::PictureLocks;
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::BooleanValue,
@@ -25,6 +27,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonVisualPictureDrawingProperties {
     prefer_relative_resize: BooleanValue,
     picture_locks:          Option<PictureLocks>,
@@ -83,11 +86,11 @@ impl NonVisualPictureDrawingProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, prefer_relative_resize, "preferRelativeResize");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -101,10 +104,10 @@ impl NonVisualPictureDrawingProperties {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:cNvPicPr" | b"cNvPicPr") {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cNvPicPr")
+            Event::Eof => return Err(unexpected_eof(reader, "cNvPicPr"))
         );
     }
 
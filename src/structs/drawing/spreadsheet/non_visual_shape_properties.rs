@@ -12,7 +12,14 @@ use quick_xml::{
 
 use super::NonVisualDrawingProperties;
 use crate::{
-    reader::driver::xml_read_loop,
+    BooleanValue,
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,8 +27,10 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonVisualShapeProperties {
     non_visual_drawing_properties: NonVisualDrawingProperties,
+    text_box:                      BooleanValue,
 }
 
 impl NonVisualShapeProperties {
@@ -54,31 +63,56 @@ impl NonVisualShapeProperties {
         self.non_visual_drawing_properties = value;
     }
 
+    /// Whether the shape is a text box.
+    #[inline]
+    #[must_use]
+    pub fn text_box(&self) -> bool {
+        self.text_box.value()
+    }
+
+    #[inline]
+    pub fn set_text_box(&mut self, value: bool) -> &mut Self {
+        self.text_box.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
-                if e.name().into_inner() == b"xdr:cNvPr" {
-                    self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                match e.name().into_inner() {
+                    b"xdr:cNvPr" => {
+                        self.non_visual_drawing_properties
+                            .set_attributes(reader, e, true)?;
+                    }
+                    b"xdr:cNvSpPr" => {
+                        set_string_from_xml!(self, e, text_box, "txBox");
+                    }
+                    _ => (),
                 }
             },
             Event::Start(ref e) => {
-                if e.name().into_inner() == b"xdr:cNvPr" {
-                    self.non_visual_drawing_properties
-                        .set_attributes(reader, e, false);
+                match e.name().into_inner() {
+                    b"xdr:cNvPr" => {
+                        self.non_visual_drawing_properties
+                            .set_attributes(reader, e, false)?;
+                    }
+                    b"xdr:cNvSpPr" => {
+                        set_string_from_xml!(self, e, text_box, "txBox");
+                    }
+                    _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvSpPr")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:nvSpPr"))
         );
     }
 
@@ -90,7 +124,11 @@ impl NonVisualShapeProperties {
         self.non_visual_drawing_properties.write_to(writer, ole_id);
 
         // xdr:cNvSpPr
-        write_start_tag(writer, "xdr:cNvSpPr", vec![], true);
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.text_box.has_value() {
+            attributes.push(("txBox", self.text_box.value_string()).into());
+        }
+        write_start_tag(writer, "xdr:cNvSpPr", attributes, true);
 
         write_end_tag(writer, "xdr:nvSpPr");
     }
@@ -18,7 +18,11 @@ use super::{
     Shape,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::raw::RawRelationships,
     traits::AdjustmentCoordinate,
     writer::driver::{
@@ -28,6 +32,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OneCellAnchor {
     from_marker: MarkerType,
     extent:      Extent,
@@ -192,32 +197,47 @@ impl OneCellAnchor {
         self.picture.is_some() || self.group_shape.is_some()
     }
 
+    pub(crate) fn max_object_id(&self) -> u32 {
+        [
+            self.shape.as_ref().map(|v| v.id()),
+            self.picture.as_ref().map(|v| {
+                v.non_visual_picture_properties()
+                    .non_visual_drawing_properties()
+                    .id()
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(0)
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:from" | b"from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"xdr:grpSp" | b"grpSp" => {
                         let mut obj = GroupShape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_group_shape(obj);
                     }
                     b"xdr:sp" | b"sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_shape(obj);
                     }
                     b"xdr:pic" | b"pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_picture(obj);
                     }
                     _ => (),
@@ -230,10 +250,10 @@ impl OneCellAnchor {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:oneCellAnchor" | b"oneCellAnchor") {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oneCellAnchor")
+            Event::Eof => return Err(unexpected_eof(reader, "oneCellAnchor"))
         );
     }
 
@@ -16,7 +16,11 @@ use super::{
     ShapeProperties,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::raw::RawRelationships,
     writer::driver::{
         write_end_tag,
@@ -25,6 +29,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Picture {
     non_visual_picture_properties: NonVisualPictureProperties,
     blip_fill:                     BlipFill,
@@ -124,30 +129,30 @@ impl Picture {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvPicPr" | b"nvPicPr" => {
-                        self.non_visual_picture_properties.set_attributes(reader, e);
+                        self.non_visual_picture_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:blipFill" | b"blipFill" => {
                         self.blip_fill
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     b"xdr:spPr" | b"spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:pic" | b"pic") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pic")
+            Event::Eof => return Err(unexpected_eof(reader, "pic"))
         );
     }
 
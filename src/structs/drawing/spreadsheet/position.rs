@@ -0,0 +1,74 @@
+// xdr:pos
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::super::super::Int64Value;
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    x: Int64Value,
+    y: Int64Value,
+}
+
+impl Position {
+    #[inline]
+    #[must_use]
+    pub fn x(&self) -> i64 {
+        self.x.value()
+    }
+
+    #[inline]
+    pub fn set_x(&mut self, value: i64) -> &mut Position {
+        self.x.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn y(&self) -> i64 {
+        self.y.value()
+    }
+
+    #[inline]
+    pub fn set_y(&mut self, value: i64) -> &mut Position {
+        self.y.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, x, "x");
+        set_string_from_xml!(self, e, y, "y");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // xdr:pos
+        write_start_tag(
+            writer,
+            "xdr:pos",
+            vec![
+                ("x", self.x.value_string()).into(),
+                ("y", self.y.value_string()).into(),
+            ],
+            true,
+        );
+    }
+}
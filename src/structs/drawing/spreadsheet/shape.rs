@@ -11,20 +11,38 @@ use quick_xml::{
 };
 
 use super::{
-    super::super::Anchor,
+    super::{
+        super::Anchor,
+        Paragraph,
+        Run,
+        RunProperties,
+        SolidFill,
+        RgbColorModelHex,
+        ShapeAutoFit,
+        TextAlignmentTypeValues,
+        TextAnchoringTypeValues,
+        TextFontType,
+        TextWrappingValues,
+        Theme,
+    },
     NonVisualShapeProperties,
     ShapeProperties,
     ShapeStyle,
     TextBody,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
+        EnumTrait,
+        RichText,
         StringValue,
+        TextElement,
         raw::RawRelationships,
     },
     writer::driver::{
@@ -34,6 +52,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shape {
     anchor:                      Anchor,
     non_visual_shape_properties: NonVisualShapeProperties,
@@ -197,12 +216,167 @@ impl Shape {
         self
     }
 
+    /// Get the id of the shape in the drawing.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> u32 {
+        self.non_visual_shape_properties
+            .non_visual_drawing_properties()
+            .id()
+    }
+
+    /// Fill the shape with a solid color.
+    /// # Arguments
+    /// * `value` - RGB hex string. ex) "4472C4"
+    #[inline]
+    pub fn set_fill_color<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.shape_properties.set_fill_color(value);
+        self
+    }
+
+    /// Set the outline color and width (EMU).
+    /// # Arguments
+    /// * `color` - RGB hex string. ex) "2F528F"
+    /// * `width` - Width in EMU. ex) 12700 (1pt)
+    #[inline]
+    pub fn set_outline<S: Into<String>>(&mut self, color: S, width: u32) -> &mut Self {
+        self.shape_properties
+            .set_outline_color(color)
+            .set_outline_width(width);
+        self
+    }
+
+    /// Rotate the shape clockwise.
+    /// # Arguments
+    /// * `value` - Angle in degrees.
+    #[inline]
+    pub fn set_rotation(&mut self, value: f64) -> &mut Self {
+        self.shape_properties.set_rotation(value);
+        self
+    }
+
+    /// Replace the text of the shape.
+    /// Line breaks in the text start a new paragraph.
+    /// Font name, size, bold, italic and color of each element are kept.
+    pub fn set_text(&mut self, value: &RichText) -> &mut Self {
+        let alignment = match &self.text_body {
+            Some(v) => v
+                .paragraph()
+                .first()
+                .map(|p| p.paragraph_properties().alignment().clone()),
+            None if !self.non_visual_shape_properties.text_box() => {
+                Some(TextAlignmentTypeValues::Center)
+            }
+            None => None,
+        };
+        let text_body = self.text_body_or_default();
+        text_body.paragraph_mut().clear();
+
+        let mut paragraph = Paragraph::default();
+        for element in value.rich_text_elements() {
+            for (i, line) in element.text().split('\n').enumerate() {
+                if i > 0 {
+                    text_body.add_paragraph(std::mem::take(&mut paragraph));
+                }
+                if line.is_empty() {
+                    continue;
+                }
+                let mut run = Run::default();
+                run.set_text(line);
+                run.set_run_properties(Self::make_run_properties(element));
+                paragraph.add_run(run);
+            }
+        }
+        text_body.add_paragraph(paragraph);
+
+        if let Some(v) = alignment {
+            self.set_text_alignment(v);
+        }
+        self
+    }
+
+    /// Set the horizontal alignment of every paragraph.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn set_text_alignment(&mut self, value: TextAlignmentTypeValues) -> &mut Self {
+        for paragraph in self.text_body_or_default().paragraph_mut() {
+            paragraph
+                .paragraph_properties_mut()
+                .set_alignment(value.clone());
+        }
+        self
+    }
+
+    /// Set the vertical alignment of the text.
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn set_vertical_text_alignment(&mut self, value: TextAnchoringTypeValues) -> &mut Self {
+        self.text_body_or_default()
+            .body_properties_mut()
+            .set_anchor(value.value_string());
+        self
+    }
+
+    /// Resize the shape to fit its text.
+    #[inline]
+    pub fn set_auto_fit(&mut self, value: bool) -> &mut Self {
+        let body_properties = self.text_body_or_default().body_properties_mut();
+        if value {
+            body_properties.set_shape_auto_fit(ShapeAutoFit::default());
+        } else {
+            body_properties.remove_shape_auto_fit();
+        }
+        self
+    }
+
+    fn text_body_or_default(&mut self) -> &mut TextBody {
+        let is_text_box = self.non_visual_shape_properties.text_box();
+        self.text_body.get_or_insert_with(|| {
+            let mut obj = TextBody::default();
+            obj.body_properties_mut()
+                .set_vert_overflow("clip")
+                .set_horz_overflow("clip")
+                .set_wrap(TextWrappingValues::Square)
+                .set_rtl_col("0")
+                .set_anchor(if is_text_box { "t" } else { "ctr" });
+            Box::new(obj)
+        })
+    }
+
+    fn make_run_properties(element: &TextElement) -> RunProperties {
+        let mut obj = RunProperties::default();
+        obj.set_language("en-US");
+        let Some(font) = element.font() else {
+            return obj;
+        };
+        obj.set_sz((font.size() * 100.0).round().to_string());
+        if font.bold() {
+            obj.set_bold("1");
+        }
+        if font.italic() {
+            obj.set_italic("1");
+        }
+        if font.color().has_value() {
+            let argb = font.color().argb_with_theme(&Theme::default_value());
+            let mut rgb_color_model_hex = RgbColorModelHex::default();
+            rgb_color_model_hex.set_val(&argb[argb.len().saturating_sub(6)..]);
+            let mut solid_fill = SolidFill::default();
+            solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+            obj.set_solid_fill(solid_fill);
+        }
+        if !font.name().is_empty() {
+            let mut latin_font = TextFontType::default();
+            latin_font.set_typeface(font.name());
+            obj.set_latin_font(latin_font);
+        }
+        obj
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#macro, "macro");
 
         xml_read_loop!(
@@ -210,19 +384,19 @@ impl Shape {
                 Event::Start(ref e) => {
                     match e.name().into_inner() {
                         b"xdr:nvSpPr" => {
-                            self.non_visual_shape_properties.set_attributes(reader, e);
+                            self.non_visual_shape_properties.set_attributes(reader, e)?;
                         }
                         b"xdr:spPr" => {
-                            self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                            self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                         }
                         b"xdr:style" => {
                             let mut obj = ShapeStyle::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_shape_style(obj);
                         }
                         b"xdr:txBody" => {
                             let mut obj = TextBody::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_text_body(obj);
                         }
                         _ => (),
@@ -230,10 +404,10 @@ impl Shape {
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"xdr:sp" {
-                        return;
+                        return Ok(());
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "xdr:sp")
+                Event::Eof => return Err(unexpected_eof(reader, "xdr:sp"))
         );
     }
 
@@ -17,11 +17,13 @@ use super::super::{
     NoFill,
     Outline,
     PresetGeometry,
+    RgbColorModelHex,
     SolidFill,
     Transform2D,
 };
 use crate::{
     EnumValue,
+    XlsxError,
     drawing::{
         BlackWhiteModeValues,
         GradientFill,
@@ -29,6 +31,7 @@ use crate::{
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::raw::RawRelationships,
@@ -39,6 +42,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeProperties {
     transform2d:      Option<Box<Transform2D>>,
     preset_geometry:  PresetGeometry,
@@ -341,12 +345,58 @@ impl ShapeProperties {
         self
     }
 
+    /// Fill the shape with a solid color.
+    /// # Arguments
+    /// * `value` - RGB hex string. ex) "4472C4"
+    pub fn set_fill_color<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut rgb_color_model_hex = RgbColorModelHex::default();
+        rgb_color_model_hex.set_val(value);
+        let mut solid_fill = SolidFill::default();
+        solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+        self.no_fill = None;
+        self.gradient_fill = None;
+        self.set_solid_fill(solid_fill)
+    }
+
+    /// Set the color of the shape outline.
+    /// # Arguments
+    /// * `value` - RGB hex string. ex) "2F528F"
+    pub fn set_outline_color<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut rgb_color_model_hex = RgbColorModelHex::default();
+        rgb_color_model_hex.set_val(value);
+        let mut solid_fill = SolidFill::default();
+        solid_fill.set_rgb_color_model_hex(rgb_color_model_hex);
+        self.outline
+            .get_or_insert_with(Box::default)
+            .set_solid_fill(solid_fill);
+        self
+    }
+
+    /// Set the width of the shape outline (EMU).
+    #[inline]
+    pub fn set_outline_width(&mut self, value: u32) -> &mut Self {
+        self.outline.get_or_insert_with(Box::default).set_width(value);
+        self
+    }
+
+    /// Rotate the shape clockwise.
+    /// # Arguments
+    /// * `value` - Angle in degrees.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn set_rotation(&mut self, value: f64) -> &mut Self {
+        let rot = (value * 60000.0).round() as i64;
+        self.transform2d
+            .get_or_insert_with(Box::default)
+            .set_rot(rot.to_string());
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, black_white_mode, "bwMode");
 
         xml_read_loop!(
@@ -355,40 +405,40 @@ impl ShapeProperties {
                 match e.name().into_inner() {
                     b"a:xfrm" => {
                         let mut obj = Transform2D::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_transform2d(obj);
                     }
                     b"a:prstGeom" => {
-                        self.preset_geometry.set_attributes(reader, e);
+                        self.preset_geometry.set_attributes(reader, e)?;
                     }
                     b"a:blipFill" => {
                         let mut obj = BlipFill::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_blip_fill(obj);
                     }
                     b"a:gradFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     b"a:ln" => {
                         let mut outline = Outline::default();
-                        outline.set_attributes(reader, e);
+                        outline.set_attributes(reader, e)?;
                         self.set_outline(outline);
                     }
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:effectLst" => {
                         let mut effect_list = EffectList::default();
-                        effect_list.set_attributes(reader, e, false);
+                        effect_list.set_attributes(reader, e, false)?;
                         self.set_effect_list(effect_list);
                     }
                     b"a:extLst" => {
                         let obj = ExtensionList::default();
-                        ExtensionList::set_attributes(reader, e);
+                        ExtensionList::set_attributes(reader, e)?;
                         self.set_extension_list(obj);
                     }
                     _ => (),
@@ -397,16 +447,16 @@ impl ShapeProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:noFill" {
                     let obj = NoFill::default();
-                    NoFill::set_attributes(reader, e, true);
+                    NoFill::set_attributes(reader, e, true)?;
                     self.set_no_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:spPr" | b"spPr") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "spPr")
+            Event::Eof => return Err(unexpected_eof(reader, "spPr"))
         );
     }
 
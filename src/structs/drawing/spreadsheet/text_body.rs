@@ -13,9 +13,18 @@ use super::super::{
     BodyProperties,
     ListStyle,
     Paragraph,
+    RgbColorModelHex,
+    Run,
+    SolidFill,
+    TextFontType,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    structs::RichText,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +32,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextBody {
     body_properties: BodyProperties,
     list_style:      ListStyle,
@@ -117,28 +127,92 @@ impl TextBody {
         self.paragraph.push(value);
     }
 
+    /// Replace the paragraphs with rich text.
+    /// Each line of the text becomes a paragraph.
+    /// Bold, italic, strikethrough, size, color and font name are kept.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::{
+    ///     RichText,
+    ///     drawing::spreadsheet::TextBody,
+    /// };
+    ///
+    /// let mut text_body = TextBody::default();
+    /// text_body.set_rich_text(&RichText::builder().text("A\nB").bold().build());
+    /// assert_eq!(text_body.paragraph().len(), 2);
+    /// assert_eq!(text_body.paragraph()[1].run()[0].run_properties().bold(), "1");
+    /// ```
+    pub fn set_rich_text(&mut self, value: &RichText) -> &mut Self {
+        self.paragraph.clear();
+        let mut paragraph = Paragraph::default();
+        for element in value.rich_text_elements() {
+            for (i, line) in element.text().split('\n').enumerate() {
+                if i > 0 {
+                    self.paragraph.push(std::mem::take(&mut paragraph));
+                }
+                if line.is_empty() {
+                    continue;
+                }
+                let mut run = Run::default();
+                run.set_text(line);
+                if let Some(font) = element.font() {
+                    let run_properties = run.run_properties_mut();
+                    if font.bold() {
+                        run_properties.set_bold("1");
+                    }
+                    if font.italic() {
+                        run_properties.set_italic("1");
+                    }
+                    if font.strikethrough() {
+                        run_properties.set_strike("sngStrike");
+                    }
+                    if font.size() > 0.0 {
+                        let sz: u32 = num_traits::cast((font.size() * 100.0).round()).unwrap();
+                        run_properties.set_sz(sz.to_string());
+                    }
+                    let argb = font.color().argb_str();
+                    if argb != "00000000" {
+                        let mut color = RgbColorModelHex::default();
+                        color.set_val(&argb[2..]);
+                        let mut solid_fill = SolidFill::default();
+                        solid_fill.set_rgb_color_model_hex(color);
+                        run_properties.set_solid_fill(solid_fill);
+                    }
+                    if !font.name().is_empty() {
+                        let mut latin_font = TextFontType::default();
+                        latin_font.set_typeface(font.name());
+                        run_properties.set_latin_font(latin_font);
+                    }
+                }
+                paragraph.add_run(run);
+            }
+        }
+        self.paragraph.push(paragraph);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:p" => {
                         let mut paragraph = Paragraph::default();
-                        paragraph.set_attributes(reader, e);
+                        paragraph.set_attributes(reader, e)?;
                         self.add_paragraph(paragraph);
                     }
                     b"a:bodyPr" => {
                         let mut body_properties = BodyProperties::default();
-                        body_properties.set_attributes(reader, e, false);
+                        body_properties.set_attributes(reader, e, false)?;
                         self.set_body_properties(body_properties);
                     }
                     b"a:lstStyle" => {
                         let mut obj = ListStyle::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_list_style(obj);
                     }
                     _ => (),
@@ -147,16 +221,16 @@ impl TextBody {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:bodyPr" {
                     let mut body_properties = BodyProperties::default();
-                    body_properties.set_attributes(reader, e, true);
+                    body_properties.set_attributes(reader, e, true)?;
                     self.set_body_properties(body_properties);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:txBody" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:txBody")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:txBody"))
         );
     }
 
@@ -21,6 +21,7 @@ use super::{
     Shape,
 };
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWING_MAIN_NS,
         MC_NS,
@@ -28,6 +29,7 @@ use crate::{
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -45,6 +47,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TwoCellAnchor {
     edit_as:              EnumValue<EditAsValues>,
     from_marker:          MarkerType,
@@ -330,12 +333,33 @@ impl TwoCellAnchor {
         self.picture.is_some() || self.group_shape.is_some()
     }
 
+    pub(crate) fn max_object_id(&self) -> u32 {
+        [
+            self.graphic_frame.as_ref().map(|v| {
+                v.non_visual_graphic_frame_properties()
+                    .non_visual_drawing_properties()
+                    .id()
+            }),
+            self.shape.as_ref().map(|v| v.id()),
+            self.connection_shape.as_ref().map(|v| v.id()),
+            self.picture.as_ref().map(|v| {
+                v.non_visual_picture_properties()
+                    .non_visual_drawing_properties()
+                    .id()
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(0)
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, edit_as, "editAs");
 
         xml_read_loop!(
@@ -343,34 +367,34 @@ impl TwoCellAnchor {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"xdr:from" | b"from" => {
-                    self.from_marker.set_attributes(reader, e);
+                    self.from_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:to" | b"to" => {
-                    self.to_marker.set_attributes(reader, e);
+                    self.to_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:grpSp" | b"grpSp" => {
                     let mut obj = GroupShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_group_shape(obj);
                 }
                 b"xdr:graphicFrame" | b"graphicFrame" => {
                     let mut obj = GraphicFrame::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_graphic_frame(obj);
                 }
                 b"xdr:sp" | b"sp" => {
                     let mut obj = Shape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_shape(obj);
                 }
                 b"xdr:cxnSp" | b"cxnSp" => {
                     let mut obj = ConnectionShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_connection_shape(obj);
                 }
                 b"xdr:pic" | b"pic" => {
                     let mut obj = Picture::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_picture(obj);
                 }
                 _ => (),
@@ -378,10 +402,10 @@ impl TwoCellAnchor {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:twoCellAnchor" | b"twoCellAnchor") {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "twoCellAnchor")
+            Event::Eof => return Err(unexpected_eof(reader, "twoCellAnchor"))
         );
     }
 
@@ -11,23 +11,38 @@ use quick_xml::{
 };
 
 use super::{
+    super::{
+        EndConnection,
+        PositiveFixedPercentageType,
+        PresetGeometry,
+        SchemeColor,
+        SchemeColorValues,
+        StartConnection,
+        StyleMatrixReferenceType,
+    },
     ConnectionShape,
     GraphicFrame,
     OneCellAnchor,
     Picture,
     Shape,
+    ShapeStyle,
     TwoCellAnchor,
 };
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWINGML_MAIN_NS,
         SHEET_DRAWING_NS,
     },
-    reader::driver::xml_read_loop,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::{
         Chart,
         Image,
         OleObjects,
+        RichText,
         raw::RawRelationships,
     },
     traits::{
@@ -41,6 +56,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorksheetDrawing {
     image_collection:           Vec<Image>,
     chart_collection:           Vec<Chart>,
@@ -281,6 +297,163 @@ impl WorksheetDrawing {
         self
     }
 
+    /// Add a shape drawn with the theme accent color.
+    /// # Arguments
+    /// * `geometry` - Preset geometry of the shape.
+    /// * `anchor` - Anchor giving the position of the shape.
+    pub fn add_shape(&mut self, geometry: PresetGeometry, mut anchor: TwoCellAnchor) -> &mut Shape {
+        let id = self.next_object_id();
+        let mut shape = Shape::default();
+        shape
+            .non_visual_shape_properties_mut()
+            .non_visual_drawing_properties_mut()
+            .set_id(id)
+            .set_name(format!("Shape {}", id - 1));
+        shape.shape_properties_mut().set_geometry(geometry);
+        shape.set_shape_style(Self::make_shape_style(
+            ("2", Some(50000)),
+            "1",
+            SchemeColorValues::Light1,
+        ));
+        anchor.set_shape(shape);
+        self.two_cell_anchor_collection.push(anchor);
+        self.two_cell_anchor_collection
+            .last_mut()
+            .and_then(TwoCellAnchor::shape_mut)
+            .unwrap()
+    }
+
+    /// Add a text box.
+    /// # Arguments
+    /// * `anchor` - Anchor giving the position of the text box.
+    /// * `text` - Text of the text box.
+    pub fn add_text_box(&mut self, mut anchor: TwoCellAnchor, text: &RichText) -> &mut Shape {
+        let id = self.next_object_id();
+        let mut shape = Shape::default();
+        shape
+            .non_visual_shape_properties_mut()
+            .set_text_box(true)
+            .non_visual_drawing_properties_mut()
+            .set_id(id)
+            .set_name(format!("TextBox {}", id - 1));
+        shape
+            .shape_properties_mut()
+            .geometry_mut()
+            .set_geometry(PresetGeometry::GEOMETRY_RECT);
+        shape
+            .set_fill_color("FFFFFF")
+            .set_outline("BFBFBF", 9525)
+            .set_text(text);
+        anchor.set_shape(shape);
+        self.two_cell_anchor_collection.push(anchor);
+        self.two_cell_anchor_collection
+            .last_mut()
+            .and_then(TwoCellAnchor::shape_mut)
+            .unwrap()
+    }
+
+    /// Add a straight connector between two shapes.
+    /// The connector runs from the right side of the start shape
+    /// to the left side of the end shape.
+    /// # Arguments
+    /// * `anchor` - Anchor giving the position of the connector.
+    /// * `start_shape_id` - Id of the start shape. see [`Shape::id`].
+    /// * `end_shape_id` - Id of the end shape. see [`Shape::id`].
+    pub fn add_connector(
+        &mut self,
+        mut anchor: TwoCellAnchor,
+        start_shape_id: u32,
+        end_shape_id: u32,
+    ) -> &mut ConnectionShape {
+        let id = self.next_object_id();
+        let mut connection_shape = ConnectionShape::default();
+        let properties = connection_shape.non_visual_connection_shape_properties_mut();
+        properties
+            .non_visual_drawing_properties_mut()
+            .set_id(id)
+            .set_name(format!("Connector {}", id - 1));
+        let mut start_connection = StartConnection::default();
+        start_connection.set_id(start_shape_id);
+        start_connection.set_index(3);
+        let mut end_connection = EndConnection::default();
+        end_connection.set_id(end_shape_id);
+        end_connection.set_index(1);
+        let connector_properties = properties.non_visual_connector_shape_drawing_properties_mut();
+        connector_properties.set_start_connection(start_connection);
+        connector_properties.set_end_connection(end_connection);
+        connection_shape
+            .shape_properties_mut()
+            .geometry_mut()
+            .set_geometry(PresetGeometry::GEOMETRY_STRAIGHTCONNECTOR1);
+        connection_shape.set_shape_style(Self::make_shape_style(
+            ("1", None),
+            "0",
+            SchemeColorValues::Text1,
+        ));
+        anchor.set_connection_shape(connection_shape);
+        self.two_cell_anchor_collection.push(anchor);
+        self.two_cell_anchor_collection
+            .last_mut()
+            .and_then(TwoCellAnchor::connection_shape_mut)
+            .unwrap()
+    }
+
+    fn next_object_id(&self) -> u32 {
+        let charts = self
+            .chart_collection
+            .iter()
+            .map(|v| v.two_cell_anchor().max_object_id());
+        let images = self.image_collection.iter().map(|v| {
+            v.two_cell_anchor()
+                .map(TwoCellAnchor::max_object_id)
+                .or_else(|| v.one_cell_anchor().map(OneCellAnchor::max_object_id))
+                .unwrap_or(0)
+        });
+        let two_cell_anchors = self
+            .two_cell_anchor_collection
+            .iter()
+            .map(TwoCellAnchor::max_object_id);
+        let one_cell_anchors = self
+            .one_cell_anchor_collection
+            .iter()
+            .map(OneCellAnchor::max_object_id);
+        charts
+            .chain(images)
+            .chain(two_cell_anchors)
+            .chain(one_cell_anchors)
+            .max()
+            .unwrap_or(0)
+            .max(1)
+            + 1
+    }
+
+    fn make_shape_style(
+        line: (&str, Option<i32>),
+        fill: &str,
+        font_color: SchemeColorValues,
+    ) -> ShapeStyle {
+        let make_reference = |index: &str, color: SchemeColorValues, shade: Option<i32>| {
+            let mut scheme_color = SchemeColor::default();
+            scheme_color.set_val(color);
+            if let Some(v) = shade {
+                let mut obj = PositiveFixedPercentageType::default();
+                obj.set_val(v);
+                scheme_color.set_shade(obj);
+            }
+            let mut obj = StyleMatrixReferenceType::default();
+            obj.set_index(index);
+            obj.set_scheme_color(scheme_color);
+            obj
+        };
+
+        let mut obj = ShapeStyle::default();
+        obj.set_line_reference(make_reference(line.0, SchemeColorValues::Accent1, line.1));
+        obj.set_fill_reference(make_reference(fill, SchemeColorValues::Accent1, None));
+        obj.set_effect_reference(make_reference("0", SchemeColorValues::Accent1, None));
+        obj.set_font_reference(make_reference("minor", font_color, None));
+        obj
+    }
+
     #[inline]
     #[must_use]
     pub fn has_drawing_object(&self) -> bool {
@@ -437,7 +610,7 @@ impl WorksheetDrawing {
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
         ole_objects: &mut OleObjects,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut ole_index = 0;
         let mut is_alternate_content = false;
 
@@ -453,7 +626,7 @@ impl WorksheetDrawing {
                             continue;
                         }
                         let mut obj = OneCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         if obj.is_image() {
                             let mut image = Image::default();
                             image.set_one_cell_anchor(obj);
@@ -472,12 +645,12 @@ impl WorksheetDrawing {
                                 reader,
                                 e,
                                 drawing_relationships,
-                            );
+                            )?;
                             ole_index += 1;
                             continue;
                         }
                         let mut obj = TwoCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         if obj.is_support() {
                             if obj.is_chart() {
                                 let mut chart = Chart::default();
@@ -501,12 +674,12 @@ impl WorksheetDrawing {
                     b"mc:AlternateContent" => {
                         is_alternate_content = false;
                     }
-                    b"xdr:wsDr" | b"wsDr" => return,
+                    b"xdr:wsDr" | b"wsDr" => return Ok(()),
                     _ => (),
                 }
             },
 
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:wsDr")
+            Event::Eof => return Err(unexpected_eof(reader, "xdr:wsDr"))
         );
     }
 
@@ -9,11 +9,13 @@ use quick_xml::{
 
 use super::super::super::UInt32Value;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::required_attribute,
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StartConnection {
     id:    UInt32Value,
     index: UInt32Value,
@@ -58,12 +60,13 @@ impl StartConnection {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id.set_value_string(required_attribute(reader, e, "id")?);
         self.index
-            .set_value_string(get_attribute(e, b"idx").unwrap());
+            .set_value_string(required_attribute(reader, e, "idx")?);
+        Ok(())
     }
 
     #[inline]
@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::fill_rectangle::FillRectangle;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stretch {
     fill_rectangle: Option<Box<FillRectangle>>,
 }
@@ -58,7 +63,7 @@ impl Stretch {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -70,10 +75,10 @@ impl Stretch {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:stretch" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:stretch")
+            Event::Eof => return Err(unexpected_eof(reader, "a:stretch"))
         );
     }
 
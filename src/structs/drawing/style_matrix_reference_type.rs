@@ -12,8 +12,10 @@ use quick_xml::{
 
 use super::SchemeColor;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -23,6 +25,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StyleMatrixReferenceType {
     index:        Box<str>,
     scheme_color: Option<Box<SchemeColor>>,
@@ -70,11 +73,11 @@ impl StyleMatrixReferenceType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        self.set_index(get_attribute(e, b"idx").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_index(required_attribute(reader, e, "idx")?);
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -83,7 +86,7 @@ impl StyleMatrixReferenceType {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, is_empty);
+                    scheme_color.set_attributes(reader, e, is_empty)?;
                     self.set_scheme_color(scheme_color);
                 }
             },
@@ -92,11 +95,11 @@ impl StyleMatrixReferenceType {
                     b"a:lnRef"     |
                     b"a:fillRef"   |
                     b"a:effectRef" |
-                    b"a:fontRef" => return,
+                    b"a:fontRef" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lnRef")
+            Event::Eof => return Err(unexpected_eof(reader, "a:lnRef"))
         );
     }
 
@@ -11,8 +11,10 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::StringValue,
@@ -20,6 +22,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TailEnd {
     t_type: StringValue,
     width:  StringValue,
@@ -82,7 +85,7 @@ impl TailEnd {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"type") {
             self.set_type(v);
         }
@@ -96,17 +99,17 @@ impl TailEnd {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:tailEnd" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:tailEnd")
+            Event::Eof => return Err(unexpected_eof(reader, "a:tailEnd"))
         );
     }
 
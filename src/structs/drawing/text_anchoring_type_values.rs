@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use super::super::super::EnumTrait;
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextAnchoringTypeValues {
+    Bottom,
+    Center,
+    Distributed,
+    Justified,
+    Top,
+}
+impl Default for TextAnchoringTypeValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Top
+    }
+}
+impl EnumTrait for TextAnchoringTypeValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Bottom => "b",
+            Self::Center => "ctr",
+            Self::Distributed => "dist",
+            Self::Justified => "just",
+            Self::Top => "t",
+        }
+    }
+}
+impl FromStr for TextAnchoringTypeValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "b" => Ok(Self::Bottom),
+            "ctr" => Ok(Self::Center),
+            "dist" => Ok(Self::Distributed),
+            "just" => Ok(Self::Justified),
+            "t" => Ok(Self::Top),
+            _ => Err(()),
+        }
+    }
+}
@@ -22,9 +22,11 @@ use super::{
     TextFontAlignmentValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -34,6 +36,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextParagraphPropertiesType {
     right_to_left:          BooleanValue,
     alignment:              EnumValue<TextAlignmentTypeValues>,
@@ -194,7 +197,7 @@ impl TextParagraphPropertiesType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, right_to_left, "rtl");
         set_string_from_xml!(self, e, alignment, "algn");
         set_string_from_xml!(self, e, font_alignment, "fontAlgn");
@@ -206,17 +209,17 @@ impl TextParagraphPropertiesType {
                 match e.name().into_inner() {
                 b"a:spcBef" => {
                     let mut obj = SpaceBefore::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_before(obj);
                 }
                 b"a:spcAft" => {
                     let mut obj = SpaceAfter::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_after(obj);
                 }
                 b"a:defRPr" => {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_default_run_properties(obj);
                 }
                 _ => (),
@@ -233,11 +236,11 @@ impl TextParagraphPropertiesType {
                     b"a:lvl6pPr" |
                     b"a:lvl7pPr" |
                     b"a:lvl8pPr" |
-                    b"a:lvl9pPr" => return,
+                    b"a:lvl9pPr" => return Ok(()),
                     _ =>()
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lvl1pPr-lvl9pPr")
+            Event::Eof => return Err(unexpected_eof(reader, "a:lvl1pPr-lvl9pPr"))
         );
     }
 
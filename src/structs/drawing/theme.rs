@@ -33,9 +33,11 @@ use super::{
     ThemeElements,
 };
 use crate::{
+    XlsxError,
     helper::const_str::DRAWINGML_MAIN_NS,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -45,6 +47,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Theme {
     name:           StringValue,
     theme_elements: ThemeElements,
@@ -576,7 +579,7 @@ impl Theme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"name") {
             self.name.set_value(v);
         }
@@ -585,15 +588,15 @@ impl Theme {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    self.theme_elements.set_attributes(reader, e);
+                    self.theme_elements.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:theme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:theme")
+            Event::Eof => return Err(unexpected_eof(reader, "a:theme"))
         );
     }
 
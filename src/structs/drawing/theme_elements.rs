@@ -16,7 +16,11 @@ use super::{
     FormatScheme,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -24,6 +28,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThemeElements {
     color_scheme:  ColorScheme,
     font_scheme:   FontScheme,
@@ -125,29 +130,29 @@ impl ThemeElements {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:clrScheme" => {
-                        self.color_scheme.set_attributes(reader, e);
+                        self.color_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fontScheme" => {
-                        self.font_scheme.set_attributes(reader, e);
+                        self.font_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fmtScheme" => {
-                        self.format_scheme.set_attributes(reader, e);
+                        self.format_scheme.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:themeElements")
+            Event::Eof => return Err(unexpected_eof(reader, "a:themeElements"))
         );
     }
 
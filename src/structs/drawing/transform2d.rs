@@ -12,8 +12,10 @@ use quick_xml::{
 
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::drawing::{
@@ -27,6 +29,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform2D {
     offset:        Point2DType,
     extents:       PositiveSize2DType,
@@ -212,7 +215,7 @@ impl Transform2D {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"rot") {
             self.set_rot(v);
         }
@@ -231,19 +234,19 @@ impl Transform2D {
                 let is_empty = matches!(n, Event::Empty(_));
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e, is_empty);
+                        self.offset.set_attributes(reader, e, is_empty)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e, is_empty);
+                        self.extents.set_attributes(reader, e, is_empty)?;
                     }
                     b"a:chOff" => {
                         let mut obj = Point2DType::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_child_offset(obj);
                     }
                     b"a:chExt" => {
                         let mut obj = PositiveSize2DType::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_child_extents(obj);
                     }
                     _ => (),
@@ -251,10 +254,10 @@ impl Transform2D {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:xfrm" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:xfrm")
+            Event::Eof => return Err(unexpected_eof(reader, "a:xfrm"))
         );
     }
 
@@ -0,0 +1,99 @@
+// dynamicFilter
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    DoubleValue,
+    DynamicFilterValues,
+    EnumValue,
+};
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+/// A filter whose result depends on the data or the current date,
+/// such as above average or this month.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicFilter {
+    r#type:  EnumValue<DynamicFilterValues>,
+    val:     DoubleValue,
+    max_val: DoubleValue,
+}
+
+impl DynamicFilter {
+    #[inline]
+    #[must_use]
+    pub fn get_type(&self) -> &DynamicFilterValues {
+        self.r#type.value()
+    }
+
+    #[inline]
+    pub fn set_type(&mut self, value: DynamicFilterValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    /// Get the average, or the start of the date range, saved by the
+    /// application.
+    #[inline]
+    #[must_use]
+    pub fn val(&self) -> Option<f64> {
+        self.val.has_value().then(|| self.val.value())
+    }
+
+    #[inline]
+    pub fn set_val(&mut self, value: f64) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    /// Get the end of the date range saved by the application.
+    #[inline]
+    #[must_use]
+    pub fn max_val(&self) -> Option<f64> {
+        self.max_val.has_value().then(|| self.max_val.value())
+    }
+
+    #[inline]
+    pub fn set_max_val(&mut self, value: f64) -> &mut Self {
+        self.max_val.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, max_val, "maxVal");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // dynamicFilter
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("type", self.r#type.value_string()).into());
+        let val = self.val.value_string();
+        if self.val.has_value() {
+            attributes.push(("val", &val).into());
+        }
+        let max_val = self.max_val.value_string();
+        if self.max_val.has_value() {
+            attributes.push(("maxVal", &max_val).into());
+        }
+        write_start_tag(writer, "dynamicFilter", attributes, true);
+    }
+}
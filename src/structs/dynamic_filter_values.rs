@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DynamicFilterValues {
+    Null,
+    AboveAverage,
+    BelowAverage,
+    Tomorrow,
+    Today,
+    Yesterday,
+    NextWeek,
+    ThisWeek,
+    LastWeek,
+    NextMonth,
+    ThisMonth,
+    LastMonth,
+    NextQuarter,
+    ThisQuarter,
+    LastQuarter,
+    NextYear,
+    ThisYear,
+    LastYear,
+    YearToDate,
+    Q1,
+    Q2,
+    Q3,
+    Q4,
+    M1,
+    M2,
+    M3,
+    M4,
+    M5,
+    M6,
+    M7,
+    M8,
+    M9,
+    M10,
+    M11,
+    M12,
+}
+impl Default for DynamicFilterValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Null
+    }
+}
+impl EnumTrait for DynamicFilterValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Null => "null",
+            Self::AboveAverage => "aboveAverage",
+            Self::BelowAverage => "belowAverage",
+            Self::Tomorrow => "tomorrow",
+            Self::Today => "today",
+            Self::Yesterday => "yesterday",
+            Self::NextWeek => "nextWeek",
+            Self::ThisWeek => "thisWeek",
+            Self::LastWeek => "lastWeek",
+            Self::NextMonth => "nextMonth",
+            Self::ThisMonth => "thisMonth",
+            Self::LastMonth => "lastMonth",
+            Self::NextQuarter => "nextQuarter",
+            Self::ThisQuarter => "thisQuarter",
+            Self::LastQuarter => "lastQuarter",
+            Self::NextYear => "nextYear",
+            Self::ThisYear => "thisYear",
+            Self::LastYear => "lastYear",
+            Self::YearToDate => "yearToDate",
+            Self::Q1 => "Q1",
+            Self::Q2 => "Q2",
+            Self::Q3 => "Q3",
+            Self::Q4 => "Q4",
+            Self::M1 => "M1",
+            Self::M2 => "M2",
+            Self::M3 => "M3",
+            Self::M4 => "M4",
+            Self::M5 => "M5",
+            Self::M6 => "M6",
+            Self::M7 => "M7",
+            Self::M8 => "M8",
+            Self::M9 => "M9",
+            Self::M10 => "M10",
+            Self::M11 => "M11",
+            Self::M12 => "M12",
+        }
+    }
+}
+impl FromStr for DynamicFilterValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "null" => Ok(Self::Null),
+            "aboveAverage" => Ok(Self::AboveAverage),
+            "belowAverage" => Ok(Self::BelowAverage),
+            "tomorrow" => Ok(Self::Tomorrow),
+            "today" => Ok(Self::Today),
+            "yesterday" => Ok(Self::Yesterday),
+            "nextWeek" => Ok(Self::NextWeek),
+            "thisWeek" => Ok(Self::ThisWeek),
+            "lastWeek" => Ok(Self::LastWeek),
+            "nextMonth" => Ok(Self::NextMonth),
+            "thisMonth" => Ok(Self::ThisMonth),
+            "lastMonth" => Ok(Self::LastMonth),
+            "nextQuarter" => Ok(Self::NextQuarter),
+            "thisQuarter" => Ok(Self::ThisQuarter),
+            "lastQuarter" => Ok(Self::LastQuarter),
+            "nextYear" => Ok(Self::NextYear),
+            "thisYear" => Ok(Self::ThisYear),
+            "lastYear" => Ok(Self::LastYear),
+            "yearToDate" => Ok(Self::YearToDate),
+            "Q1" => Ok(Self::Q1),
+            "Q2" => Ok(Self::Q2),
+            "Q3" => Ok(Self::Q3),
+            "Q4" => Ok(Self::Q4),
+            "M1" => Ok(Self::M1),
+            "M2" => Ok(Self::M2),
+            "M3" => Ok(Self::M3),
+            "M4" => Ok(Self::M4),
+            "M5" => Ok(Self::M5),
+            "M6" => Ok(Self::M6),
+            "M7" => Ok(Self::M7),
+            "M8" => Ok(Self::M8),
+            "M9" => Ok(Self::M9),
+            "M10" => Ok(Self::M10),
+            "M11" => Ok(Self::M11),
+            "M12" => Ok(Self::M12),
+            _ => Err(()),
+        }
+    }
+}
@@ -16,9 +16,12 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        required_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -32,6 +35,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmbeddedObjectProperties {
     prog_id:       StringValue,
     shape_id:      UInt32Value,
@@ -182,9 +186,9 @@ impl EmbeddedObjectProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
-        let r_id = get_attribute(e, b"r:id").unwrap();
-        let attached_file = relationships.relationship_by_rid(&r_id).raw_file();
+    ) -> Result<(), XlsxError> {
+        let r_id = required_attribute(reader, e, "r:id")?;
+        let attached_file = relationships.relationship_by_rid(&r_id)?.raw_file();
 
         self.image_mut()
             .set_image_name(attached_file.file_name());
@@ -198,15 +202,15 @@ impl EmbeddedObjectProperties {
             reader,
                 Event::Start(ref e) => {
                     if e.name().into_inner() == b"anchor" {
-                        self.object_anchor.set_attributes(reader, e);
+                        self.object_anchor.set_attributes(reader, e)?;
                     }
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"objectPr" {
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "objectPr")
+                Event::Eof => return Err(unexpected_eof(reader, "objectPr"))
         );
     }
 
@@ -3,6 +3,7 @@ use std::str::FromStr;
 use super::EnumTrait;
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumValue<T: EnumTrait + FromStr> {
     value:         Option<T>,
     value_default: T,
@@ -9,6 +9,7 @@ use crate::from_err;
 /// An enum to represent all different errors that can appear as
 /// a value in a worksheet cell
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CellErrorType {
     /// Division by 0 error
     Div0,
@@ -62,7 +63,59 @@ impl FromStr for CellErrorType {
     }
 }
 
+/// Where a malformed element was found while reading a file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct XmlLocation {
+    part:     String,
+    position: u64,
+    element:  String,
+}
+
+impl XmlLocation {
+    #[inline]
+    pub(crate) fn new<S: Into<String>>(position: u64, element: S) -> Self {
+        Self {
+            part: String::new(),
+            position,
+            element: element.into(),
+        }
+    }
+
+    /// Path of the part in the package. ex) "xl/worksheets/sheet1.xml"
+    #[inline]
+    #[must_use]
+    pub fn part(&self) -> &str {
+        &self.part
+    }
+
+    /// Byte offset in the part.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Name of the element. Empty when unknown.
+    #[inline]
+    #[must_use]
+    pub fn element(&self) -> &str {
+        &self.element
+    }
+}
+
+impl fmt::Display for XmlLocation {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.part, self.position)?;
+        if !self.element.is_empty() {
+            write!(f, " in <{}>", self.element)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum XlsxError {
     /// IO error
     Io(std::io::Error),
@@ -80,6 +133,56 @@ pub enum XlsxError {
     NotDeserialized(),
     /// name duplicate error
     NameDuplicate(),
+    /// invalid name error
+    InvalidName(),
+    /// An element is not closed before the end of the part.
+    UnexpectedEof(XmlLocation),
+    /// A required attribute is missing.
+    MissingAttribute(XmlLocation, String),
+    /// The XML is not well-formed.
+    InvalidXml(XmlLocation, String),
+    /// An element has content that can not be read.
+    InvalidElement(XmlLocation, String),
+    /// A part referenced in the package does not exist.
+    MissingPart(String),
+    /// A record of a binary workbook can not be read.
+    InvalidRecord(String),
+    /// A value can not be serialized or deserialized.
+    Serde(String),
+    /// A cell can not be converted, with its coordinate.
+    InvalidCell(String, String),
+    /// A range is reversed or out of the bounds of a sheet.
+    InvalidRange(String),
+}
+
+impl XlsxError {
+    /// Get the location of a malformed element.
+    #[inline]
+    #[must_use]
+    pub fn location(&self) -> Option<&XmlLocation> {
+        match self {
+            Self::UnexpectedEof(v)
+            | Self::MissingAttribute(v, _)
+            | Self::InvalidXml(v, _)
+            | Self::InvalidElement(v, _) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Set the part name of the location if it is not set yet.
+    pub(crate) fn in_part(mut self, part: &str) -> Self {
+        if let Self::UnexpectedEof(v)
+        | Self::MissingAttribute(v, _)
+        | Self::InvalidXml(v, _)
+        | Self::InvalidElement(v, _) = &mut self
+        {
+            if v.part.is_empty() {
+                v.part = part.into();
+            }
+        }
+        self
+    }
 }
 
 from_err!(std::io::Error, XlsxError, Io);
@@ -95,7 +198,17 @@ impl fmt::Display for XlsxError {
             NotFound,
             NotDeserialized,
             NameDuplicate,
+            InvalidName,
+            InvalidCell,
+            InvalidElement,
+            InvalidRange,
+            InvalidRecord,
+            InvalidXml,
             Io,
+            MissingAttribute,
+            MissingPart,
+            Serde,
+            UnexpectedEof,
             Uft8,
             Xml,
             Zip,
@@ -109,7 +222,33 @@ impl fmt::Display for XlsxError {
             NotFound() => write!(f, "Not Found"),
             NotDeserialized() => write!(f, "Not Deserialized: This Worksheet is Not Deserialized. Please exec to read_sheet(&mut self, index: usize)"),
             NameDuplicate() => write!(f, "Name Duplicate"),
+            InvalidName() => write!(f, "Invalid Name"),
+            UnexpectedEof(l) => write!(f, "Unexpected end of part: {l}"),
+            MissingAttribute(l, a) => write!(f, "Missing attribute '{a}': {l}"),
+            InvalidXml(l, s) => write!(f, "Invalid XML: {l}: {s}"),
+            InvalidElement(l, s) => write!(f, "Invalid element: {l}: {s}"),
+            MissingPart(p) => write!(f, "Missing part: {p}"),
+            InvalidRecord(s) => write!(f, "Invalid record: {s}"),
+            Serde(s) => write!(f, "Serde error: {s}"),
+            InvalidCell(c, s) => write!(f, "Invalid cell {c}: {s}"),
+            InvalidRange(r) => write!(f, "Invalid range: {r}"),
         }
     }
 }
 impl std::error::Error for XlsxError {}
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for XlsxError {
+    #[inline]
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Serde(msg.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::ser::Error for XlsxError {
+    #[inline]
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Serde(msg.to_string())
+    }
+}
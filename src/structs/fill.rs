@@ -15,7 +15,11 @@ use super::{
     PatternValues,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fill {
     pattern_fill:  Option<Box<PatternFill>>,
     gradient_fill: Option<Box<GradientFill>>,
@@ -180,7 +185,7 @@ impl Fill {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
@@ -188,12 +193,12 @@ impl Fill {
                 match e.name().into_inner() {
                     b"patternFill" => {
                         let mut obj = PatternFill::default();
-                        obj.set_attributes(reader, e, is_empty);
+                        obj.set_attributes(reader, e, is_empty)?;
                         self.set_pattern_fill(obj);
                     }
                     b"gradientFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     _ => (),
@@ -201,10 +206,10 @@ impl Fill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fill")
+            Event::Eof => return Err(unexpected_eof(reader, "fill"))
         );
     }
 
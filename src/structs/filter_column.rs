@@ -0,0 +1,298 @@
+// filterColumn
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    CustomFilters,
+    DynamicFilter,
+    Filters,
+    Top10,
+    UInt32Value,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// The filter criteria of one column of an auto filter.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FilterColumn {
+    col_id:         UInt32Value,
+    hidden_button:  BooleanValue,
+    show_button:    BooleanValue,
+    filters:        Option<Box<Filters>>,
+    custom_filters: Option<Box<CustomFilters>>,
+    top10:          Option<Box<Top10>>,
+    dynamic_filter: Option<Box<DynamicFilter>>,
+}
+
+impl FilterColumn {
+    /// Create the criteria of a column.
+    /// # Arguments
+    /// * `col_id` - Index of the column within the filtered range, from 0.
+    #[inline]
+    #[must_use]
+    pub fn new(col_id: u32) -> Self {
+        let mut obj = Self::default();
+        obj.col_id.set_value(col_id);
+        obj
+    }
+
+    /// Get the index of the column within the filtered range, from 0.
+    #[inline]
+    #[must_use]
+    pub fn col_id(&self) -> u32 {
+        self.col_id.value()
+    }
+
+    #[inline]
+    pub fn set_col_id(&mut self, value: u32) -> &mut Self {
+        self.col_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn hidden_button(&self) -> bool {
+        self.hidden_button.value()
+    }
+
+    #[inline]
+    pub fn set_hidden_button(&mut self, value: bool) -> &mut Self {
+        self.hidden_button.set_value(value);
+        self
+    }
+
+    /// Whether the filter button is shown. Defaults to `true`.
+    #[inline]
+    #[must_use]
+    pub fn show_button(&self) -> bool {
+        !self.show_button.has_value() || self.show_button.value()
+    }
+
+    #[inline]
+    pub fn set_show_button(&mut self, value: bool) -> &mut Self {
+        self.show_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn filters(&self) -> Option<&Filters> {
+        self.filters.as_deref()
+    }
+
+    #[inline]
+    pub fn filters_mut(&mut self) -> Option<&mut Filters> {
+        self.filters.as_deref_mut()
+    }
+
+    #[inline]
+    pub fn set_filters(&mut self, value: Filters) -> &mut Self {
+        self.filters = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_filters(&mut self) -> &mut Self {
+        self.filters = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn custom_filters(&self) -> Option<&CustomFilters> {
+        self.custom_filters.as_deref()
+    }
+
+    #[inline]
+    pub fn custom_filters_mut(&mut self) -> Option<&mut CustomFilters> {
+        self.custom_filters.as_deref_mut()
+    }
+
+    #[inline]
+    pub fn set_custom_filters(&mut self, value: CustomFilters) -> &mut Self {
+        self.custom_filters = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_custom_filters(&mut self) -> &mut Self {
+        self.custom_filters = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn top10(&self) -> Option<&Top10> {
+        self.top10.as_deref()
+    }
+
+    #[inline]
+    pub fn top10_mut(&mut self) -> Option<&mut Top10> {
+        self.top10.as_deref_mut()
+    }
+
+    #[inline]
+    pub fn set_top10(&mut self, value: Top10) -> &mut Self {
+        self.top10 = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_top10(&mut self) -> &mut Self {
+        self.top10 = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn dynamic_filter(&self) -> Option<&DynamicFilter> {
+        self.dynamic_filter.as_deref()
+    }
+
+    #[inline]
+    pub fn dynamic_filter_mut(&mut self) -> Option<&mut DynamicFilter> {
+        self.dynamic_filter.as_deref_mut()
+    }
+
+    #[inline]
+    pub fn set_dynamic_filter(&mut self, value: DynamicFilter) -> &mut Self {
+        self.dynamic_filter = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_dynamic_filter(&mut self) -> &mut Self {
+        self.dynamic_filter = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, col_id, "colId");
+        set_string_from_xml!(self, e, hidden_button, "hiddenButton");
+        set_string_from_xml!(self, e, show_button, "showButton");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"filters" => {
+                        let mut obj = Filters::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.set_filters(obj);
+                    }
+                    b"customFilters" => {
+                        let mut obj = CustomFilters::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.set_custom_filters(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"filters" => {
+                        let mut obj = Filters::default();
+                        obj.set_attributes(reader, e, true)?;
+                        self.set_filters(obj);
+                    }
+                    b"customFilters" => {
+                        let mut obj = CustomFilters::default();
+                        obj.set_attributes(reader, e, true)?;
+                        self.set_custom_filters(obj);
+                    }
+                    b"top10" => {
+                        let mut obj = Top10::default();
+                        obj.set_attributes(reader, e);
+                        self.set_top10(obj);
+                    }
+                    b"dynamicFilter" => {
+                        let mut obj = DynamicFilter::default();
+                        obj.set_attributes(reader, e);
+                        self.set_dynamic_filter(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "filterColumn"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // filterColumn
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        let col_id = self.col_id.value_string();
+        attributes.push(("colId", &col_id).into());
+        if self.hidden_button.value() {
+            attributes.push(("hiddenButton", "1").into());
+        }
+        if !self.show_button() {
+            attributes.push(("showButton", "0").into());
+        }
+        let is_empty = self.filters.is_none()
+            && self.custom_filters.is_none()
+            && self.top10.is_none()
+            && self.dynamic_filter.is_none();
+        write_start_tag(writer, "filterColumn", attributes, is_empty);
+
+        if is_empty {
+            return;
+        }
+
+        // filters
+        if let Some(v) = &self.filters {
+            v.write_to(writer);
+        }
+
+        // customFilters
+        if let Some(v) = &self.custom_filters {
+            v.write_to(writer);
+        }
+
+        // top10
+        if let Some(v) = &self.top10 {
+            v.write_to(writer);
+        }
+
+        // dynamicFilter
+        if let Some(v) = &self.dynamic_filter {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "filterColumn");
+    }
+}
@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FilterOperatorValues {
+    Equal,
+    LessThan,
+    LessThanOrEqual,
+    NotEqual,
+    GreaterThanOrEqual,
+    GreaterThan,
+}
+impl Default for FilterOperatorValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Equal
+    }
+}
+impl EnumTrait for FilterOperatorValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Equal => "equal",
+            Self::LessThan => "lessThan",
+            Self::LessThanOrEqual => "lessThanOrEqual",
+            Self::NotEqual => "notEqual",
+            Self::GreaterThanOrEqual => "greaterThanOrEqual",
+            Self::GreaterThan => "greaterThan",
+        }
+    }
+}
+impl FromStr for FilterOperatorValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "equal" => Ok(Self::Equal),
+            "lessThan" => Ok(Self::LessThan),
+            "lessThanOrEqual" => Ok(Self::LessThanOrEqual),
+            "notEqual" => Ok(Self::NotEqual),
+            "greaterThanOrEqual" => Ok(Self::GreaterThanOrEqual),
+            "greaterThan" => Ok(Self::GreaterThan),
+            _ => Err(()),
+        }
+    }
+}
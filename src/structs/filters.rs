@@ -0,0 +1,114 @@
+// filters
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::BooleanValue;
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// The values of a column that are shown.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Filters {
+    blank:  BooleanValue,
+    values: Vec<String>,
+}
+
+impl Filters {
+    /// Whether blank cells are shown.
+    #[inline]
+    #[must_use]
+    pub fn blank(&self) -> bool {
+        self.blank.value()
+    }
+
+    #[inline]
+    pub fn set_blank(&mut self, value: bool) -> &mut Self {
+        self.blank.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut Vec<String> {
+        &mut self.values
+    }
+
+    #[inline]
+    pub fn add_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.values.push(value.into());
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, blank, "blank");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"filter" {
+                    if let Some(v) = get_attribute(e, b"val") {
+                        self.values.push(v);
+                    }
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filters" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "filters"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // filters
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.blank.value() {
+            attributes.push(("blank", self.blank.value_string()).into());
+        }
+        write_start_tag(writer, "filters", attributes, self.values.is_empty());
+
+        if !self.values.is_empty() {
+            // filter
+            for value in &self.values {
+                write_start_tag(writer, "filter", vec![("val", value).into()], true);
+            }
+
+            write_end_tag(writer, "filters");
+        }
+    }
+}
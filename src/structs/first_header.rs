@@ -0,0 +1,100 @@
+// firstHeader
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
+    structs::{
+        HeaderFooterSection,
+        StringValue,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+        write_text_node,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirstHeader {
+    value: StringValue,
+}
+
+impl FirstHeader {
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &str {
+        self.value.value_str()
+    }
+
+    #[inline]
+    #[must_use]
+    #[deprecated(since = "3.0.0", note = "Use value()")]
+    pub fn get_value(&self) -> &str {
+        self.value()
+    }
+
+    #[inline]
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    /// Get the value split into its left, center and right parts.
+    #[inline]
+    #[must_use]
+    pub fn section(&self) -> HeaderFooterSection {
+        HeaderFooterSection::parse(self.value())
+    }
+
+    #[inline]
+    pub fn set_section(&mut self, value: &HeaderFooterSection) -> &mut Self {
+        self.set_value(value.to_string())
+    }
+
+    #[inline]
+    pub(crate) fn hash_code(&self) -> String {
+        crate::helper::utils::md5_hash(self.value())
+    }
+
+    #[inline]
+    #[deprecated(since = "3.0.0", note = "Use hash_code()")]
+    pub(crate) fn get_hash_code(&self) -> String {
+        self.hash_code()
+    }
+
+    #[inline]
+    pub(crate) fn has_param(&self) -> bool {
+        self.value.has_value()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        let mut buf = Vec::new();
+        let text = reader
+                    .read_text_into(e.name(), &mut buf)
+                    .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+        self.set_value(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if self.has_param() {
+            // firstHeader
+            write_start_tag(writer, "firstHeader", vec![], false);
+            write_text_node(writer, self.value.value_str());
+            write_end_tag(writer, "firstHeader");
+        }
+    }
+}
@@ -28,12 +28,18 @@ use super::{
     UnderlineValues,
     VerticalTextAlignment,
 };
+use crate::reader::driver::{
+    invalid_xml,
+    unexpected_eof,
+};
 use crate::writer::driver::{
     write_end_tag,
     write_start_tag,
 };
+use crate::XlsxError;
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Font {
     font_name:               FontName,
     font_size:               FontSize,
@@ -610,6 +616,25 @@ impl Font {
         def
     }
 
+    /// Apply the properties set in the font of a differential format.
+    pub(crate) fn apply_differential(&mut self, value: &Self) {
+        if value.font_bold.val.has_value() {
+            self.font_bold = value.font_bold.clone();
+        }
+        if value.font_italic.val.has_value() {
+            self.font_italic = value.font_italic.clone();
+        }
+        if value.font_strike.val.has_value() {
+            self.font_strike = value.font_strike.clone();
+        }
+        if value.font_underline.val.has_value() {
+            self.font_underline = value.font_underline.clone();
+        }
+        if value.color.has_value() {
+            self.color = value.color.clone();
+        }
+    }
+
     pub(crate) fn hash_code(&self) -> String {
         crate::helper::utils::md5_hash(format!(
             "{}{}{}{}{}{}{}{}{}{}{}",
@@ -636,16 +661,16 @@ impl Font {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"rFont" | b"name" => {
-                        self.font_name.set_attributes(reader, e);
+                        self.font_name.set_attributes(reader, e)?;
                     }
                     b"sz" => {
-                        self.font_size.set_attributes(reader, e);
+                        self.font_size.set_attributes(reader, e)?;
                     }
                     b"family" => {
                         self.font_family_numbering.set_attributes(reader, e);
@@ -663,13 +688,13 @@ impl Font {
                         self.font_strike.set_attributes(reader, e);
                     }
                     b"color" => {
-                        self.color.set_attributes(reader, e, true);
+                        self.color.set_attributes(reader, e, true)?;
                     }
                     b"charset" => {
                         self.font_char_set.set_attributes(reader, e);
                     }
                     b"scheme" => {
-                        self.font_scheme.set_attributes(reader, e);
+                        self.font_scheme.set_attributes(reader, e)?;
                     }
                     b"vertAlign" => {
                         self.vertical_text_alignment.set_attributes(reader, e);
@@ -677,11 +702,11 @@ impl Font {
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"font" | b"rPr" => return,
+                    b"font" | b"rPr" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "font, rPr"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(unexpected_eof(reader, "font, rPr")),
+                Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
                 _ => (),
             }
             buf.clear();
@@ -17,6 +17,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FontFamilyNumbering {
     pub(crate) val: Int32Value,
 }
@@ -3,6 +3,7 @@ use std::str::FromStr;
 use super::EnumTrait;
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontSchemeValues {
     Major,
     Minor,
@@ -11,7 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::{
         Font,
         Style,
@@ -23,6 +27,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Fonts {
     font: Vec<Font>,
 }
@@ -80,7 +85,7 @@ impl Fonts {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -92,16 +97,16 @@ impl Fonts {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"font" {
                     let mut obj = Font::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_font(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fonts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fonts")
+            Event::Eof => return Err(unexpected_eof(reader, "fonts"))
         );
     }
 
@@ -0,0 +1,907 @@
+// control (form control)
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    escape,
+    events::{
+        BytesDecl,
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    CheckedValues,
+    ControlProperties,
+    EnumValue,
+    FormControlTypeValues,
+    StringValue,
+    UInt32Value,
+};
+use crate::{
+    XlsxError,
+    helper::{
+        const_str::{
+            MC_NS,
+            SHEET_MS_MAIN_NS,
+        },
+        coordinate::index_from_coordinate,
+    },
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    structs::{
+        raw::RawRelationships,
+        vml::{
+            Shape,
+            office::InsetMarginValues,
+            spreadsheet::{
+                Anchor,
+                AutoFill,
+            },
+        },
+    },
+    writer::driver::{
+        write_end_tag,
+        write_new_line,
+        write_start_tag,
+    },
+};
+
+// EMU per pixel, used to convert between the sheet anchor and the VML anchor.
+const EMU_PER_PIXEL: usize = 9525;
+
+/// A Form Control Object.
+/// Checkboxes, drop-downs, buttons, spinners and the other legacy form
+/// controls placed on a worksheet.
+///
+/// A control is linked to the sheet through its linked cell (the cell that
+/// receives the value of the control), its input range (the items of a list
+/// or drop-down) and the macro run when it is clicked.
+///
+/// ## Example
+/// ```rust
+/// use umya_spreadsheet::*;
+///
+/// let mut book = new_file();
+/// let sheet = book.sheet_mut(0).unwrap();
+///
+/// sheet
+///     .add_checkbox("B2", "D3", "Include tax")
+///     .set_linked_cell("$A$2")
+///     .set_checked(CheckedValues::Checked);
+/// sheet
+///     .add_dropdown("B5", "D6", "$F$1:$F$3")
+///     .set_linked_cell("$A$5");
+/// sheet.add_button("B8", "D9", "Recalculate", "[0]!Recalculate");
+/// ```
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormControl {
+    name:               StringValue,
+    object_type:        EnumValue<FormControlTypeValues>,
+    text:               StringValue,
+    linked_cell:        StringValue,
+    input_range:        StringValue,
+    checked:            EnumValue<CheckedValues>,
+    value:              UInt32Value,
+    minimum:            UInt32Value,
+    maximum:            UInt32Value,
+    increment:          UInt32Value,
+    page_increment:     UInt32Value,
+    drop_lines:         UInt32Value,
+    selected:           UInt32Value,
+    drop_style:         StringValue,
+    no_three_d:         BooleanValue,
+    lock_text:          BooleanValue,
+    horizontal:         BooleanValue,
+    items:              Vec<String>,
+    control_properties: ControlProperties,
+    shape:              Shape,
+    shape_id:           u32,
+}
+
+impl FormControl {
+    /// Create a new form control.
+    /// # Arguments
+    /// * `object_type` - Type of the control.
+    /// * `from_coordinate` - Top left cell of the control. ex) "B2"
+    /// * `to_coordinate` - Bottom right cell of the control. ex) "D3"
+    #[must_use]
+    pub fn new(
+        object_type: FormControlTypeValues,
+        from_coordinate: &str,
+        to_coordinate: &str,
+    ) -> Self {
+        let mut obj = Self::default();
+        obj.set_anchor(from_coordinate, to_coordinate);
+        obj.control_properties
+            .set_default_size(false)
+            .set_auto_fill(false)
+            .set_auto_line(false)
+            .set_auto_pict(false);
+        obj.control_properties
+            .object_anchor_mut()
+            .set_move_with_cells(true);
+
+        let mut auto_fill = AutoFill::default();
+        auto_fill.set_value(false);
+        obj.shape
+            .set_style("position:absolute;z-index:1;mso-wrap-style:tight")
+            .set_inset_mode(InsetMarginValues::Auto)
+            .set_stroke_color("windowText [64]");
+        obj.shape.client_data_mut().set_auto_fill(auto_fill);
+
+        match object_type {
+            FormControlTypeValues::Button => {
+                obj.set_lock_text(true);
+                obj.shape.set_fill_color("buttonFace [67]");
+            }
+            FormControlTypeValues::Drop => {
+                obj.set_drop_lines(8).set_no_three_d(true);
+                obj.shape
+                    .set_filled(false)
+                    .set_fill_color("window [65]")
+                    .set_stroked(false);
+            }
+            _ => {
+                obj.set_lock_text(true).set_no_three_d(true);
+                obj.shape
+                    .set_filled(false)
+                    .set_fill_color("window [65]")
+                    .set_stroked(false);
+            }
+        }
+        obj.object_type.set_value(object_type);
+        obj
+    }
+
+    /// Get the name of the control. ex) "Check Box 1"
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn object_type(&self) -> &FormControlTypeValues {
+        self.object_type.value()
+    }
+
+    #[inline]
+    pub fn set_object_type(&mut self, value: FormControlTypeValues) -> &mut Self {
+        self.object_type.set_value(value);
+        self
+    }
+
+    /// Get the caption of the control.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        self.text.value_str()
+    }
+
+    #[inline]
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.text.set_value(value);
+        self
+    }
+
+    /// Get the cell that receives the value of the control. ex) "$A$1"
+    #[inline]
+    #[must_use]
+    pub fn linked_cell(&self) -> &str {
+        self.linked_cell.value_str()
+    }
+
+    #[inline]
+    pub fn set_linked_cell<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.linked_cell.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_linked_cell(&mut self) -> &mut Self {
+        self.linked_cell.remove_value();
+        self
+    }
+
+    /// Get the range holding the items of a list box or drop-down. ex)
+    /// "$F$1:$F$3"
+    #[inline]
+    #[must_use]
+    pub fn input_range(&self) -> &str {
+        self.input_range.value_str()
+    }
+
+    #[inline]
+    pub fn set_input_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.input_range.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_input_range(&mut self) -> &mut Self {
+        self.input_range.remove_value();
+        self
+    }
+
+    /// Get the macro run when the control is clicked. ex) "[0]!Macro1"
+    #[inline]
+    #[must_use]
+    pub fn macro_name(&self) -> &str {
+        self.control_properties.macro_name()
+    }
+
+    #[inline]
+    pub fn set_macro_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.control_properties.set_macro_name(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_macro_name(&mut self) -> &mut Self {
+        self.control_properties.remove_macro_name();
+        self
+    }
+
+    /// Get the state of a checkbox or option button.
+    #[inline]
+    #[must_use]
+    pub fn checked(&self) -> &CheckedValues {
+        self.checked.value()
+    }
+
+    #[inline]
+    pub fn set_checked(&mut self, value: CheckedValues) -> &mut Self {
+        self.checked.set_value(value);
+        self
+    }
+
+    /// Get the current value of a scroll bar or spinner.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        self.value.value()
+    }
+
+    #[inline]
+    pub fn set_value(&mut self, value: u32) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn minimum(&self) -> u32 {
+        self.minimum.value()
+    }
+
+    #[inline]
+    pub fn set_minimum(&mut self, value: u32) -> &mut Self {
+        self.minimum.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn maximum(&self) -> u32 {
+        self.maximum.value()
+    }
+
+    #[inline]
+    pub fn set_maximum(&mut self, value: u32) -> &mut Self {
+        self.maximum.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn increment(&self) -> u32 {
+        self.increment.value()
+    }
+
+    #[inline]
+    pub fn set_increment(&mut self, value: u32) -> &mut Self {
+        self.increment.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn page_increment(&self) -> u32 {
+        self.page_increment.value()
+    }
+
+    #[inline]
+    pub fn set_page_increment(&mut self, value: u32) -> &mut Self {
+        self.page_increment.set_value(value);
+        self
+    }
+
+    /// Get the number of lines shown in the list of a drop-down.
+    #[inline]
+    #[must_use]
+    pub fn drop_lines(&self) -> u32 {
+        self.drop_lines.value()
+    }
+
+    #[inline]
+    pub fn set_drop_lines(&mut self, value: u32) -> &mut Self {
+        self.drop_lines.set_value(value);
+        self
+    }
+
+    /// Get the selected item (1-based) of a list box or drop-down.
+    #[inline]
+    #[must_use]
+    pub fn selected(&self) -> u32 {
+        self.selected.value()
+    }
+
+    #[inline]
+    pub fn set_selected(&mut self, value: u32) -> &mut Self {
+        self.selected.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn drop_style(&self) -> &str {
+        self.drop_style.value_str()
+    }
+
+    #[inline]
+    pub fn set_drop_style<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.drop_style.set_value(value);
+        self
+    }
+
+    /// Get whether the control is drawn flat instead of with 3-D shading.
+    #[inline]
+    #[must_use]
+    pub fn no_three_d(&self) -> bool {
+        self.no_three_d.value()
+    }
+
+    #[inline]
+    pub fn set_no_three_d(&mut self, value: bool) -> &mut Self {
+        self.no_three_d.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn lock_text(&self) -> bool {
+        self.lock_text.value()
+    }
+
+    #[inline]
+    pub fn set_lock_text(&mut self, value: bool) -> &mut Self {
+        self.lock_text.set_value(value);
+        self
+    }
+
+    /// Get whether a scroll bar is horizontal.
+    #[inline]
+    #[must_use]
+    pub fn horizontal(&self) -> bool {
+        self.horizontal.value()
+    }
+
+    #[inline]
+    pub fn set_horizontal(&mut self, value: bool) -> &mut Self {
+        self.horizontal.set_value(value);
+        self
+    }
+
+    /// Get the items of a list box or drop-down stored in the control itself.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    #[inline]
+    pub fn items_mut(&mut self) -> &mut Vec<String> {
+        &mut self.items
+    }
+
+    #[inline]
+    pub fn add_item<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.items.push(value.into());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn control_properties(&self) -> &ControlProperties {
+        &self.control_properties
+    }
+
+    #[inline]
+    pub fn control_properties_mut(&mut self) -> &mut ControlProperties {
+        &mut self.control_properties
+    }
+
+    #[inline]
+    pub fn set_control_properties(&mut self, value: ControlProperties) -> &mut Self {
+        self.control_properties = value;
+        self
+    }
+
+    /// Set the cells the control is placed over.
+    /// # Arguments
+    /// * `from_coordinate` - Top left cell. ex) "B2"
+    /// * `to_coordinate` - Bottom right cell. ex) "D3"
+    pub fn set_anchor(&mut self, from_coordinate: &str, to_coordinate: &str) -> &mut Self {
+        let (from_col, from_row, ..) = index_from_coordinate(from_coordinate);
+        let (to_col, to_row, ..) = index_from_coordinate(to_coordinate);
+        let anchor = self.control_properties.object_anchor_mut();
+        anchor
+            .from_marker_mut()
+            .set_col(from_col.unwrap() as usize - 1)
+            .set_col_off(0)
+            .set_row(from_row.unwrap() as usize - 1)
+            .set_row_off(0);
+        anchor
+            .to_marker_mut()
+            .set_col(to_col.unwrap() as usize - 1)
+            .set_col_off(0)
+            .set_row(to_row.unwrap() as usize - 1)
+            .set_row_off(0);
+        self.shape.client_data_mut().set_anchor(Anchor::default());
+        self
+    }
+
+    /// Get the VML shape drawn for the control.
+    #[inline]
+    #[must_use]
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    #[inline]
+    pub fn shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    #[inline]
+    pub(crate) fn shape_id(&self) -> u32 {
+        self.shape_id
+    }
+
+    #[inline]
+    pub(crate) fn set_shape_id(&mut self, value: u32) -> &mut Self {
+        self.shape_id = value;
+        self
+    }
+
+    /// Whether the control shows a caption in its VML text box.
+    #[inline]
+    fn has_caption(&self) -> bool {
+        matches!(
+            self.object_type(),
+            FormControlTypeValues::Button
+                | FormControlTypeValues::CheckBox
+                | FormControlTypeValues::Dialog
+                | FormControlTypeValues::GroupBox
+                | FormControlTypeValues::Label
+                | FormControlTypeValues::Radio
+        )
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        relationships: &RawRelationships,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        if let Some(v) = get_attribute(e, b"shapeId") {
+            self.shape_id = v.parse::<u32>().unwrap_or_default();
+        }
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"controlPr" {
+                    self.control_properties.set_attributes(reader, e, relationships, false)?;
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"controlPr" {
+                    self.control_properties.set_attributes(reader, e, relationships, true)?;
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"control" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "control"))
+        );
+    }
+
+    /// Read the `formControlPr` part of the control.
+    pub(crate) fn set_form_control_properties(&mut self, data: &[u8]) -> Result<(), XlsxError> {
+        let mut reader = Reader::from_reader(data);
+        reader.config_mut().trim_text(true);
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"formControlPr" {
+                    self.set_form_control_properties_attributes(e);
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"formControlPr" => {
+                        self.set_form_control_properties_attributes(e);
+                    }
+                    b"item" => {
+                        if let Some(v) = get_attribute(e, b"val") {
+                            self.items.push(v);
+                        }
+                    }
+                    _ => (),
+                }
+            },
+            Event::Eof => break
+        );
+        Ok(())
+    }
+
+    fn set_form_control_properties_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, object_type, "objectType");
+        set_string_from_xml!(self, e, linked_cell, "fmlaLink");
+        set_string_from_xml!(self, e, input_range, "fmlaRange");
+        set_string_from_xml!(self, e, checked, "checked");
+        set_string_from_xml!(self, e, value, "val");
+        set_string_from_xml!(self, e, minimum, "min");
+        set_string_from_xml!(self, e, maximum, "max");
+        set_string_from_xml!(self, e, increment, "inc");
+        set_string_from_xml!(self, e, page_increment, "page");
+        set_string_from_xml!(self, e, drop_lines, "dropLines");
+        set_string_from_xml!(self, e, selected, "sel");
+        set_string_from_xml!(self, e, drop_style, "dropStyle");
+        set_string_from_xml!(self, e, no_three_d, "noThreeD");
+        set_string_from_xml!(self, e, lock_text, "lockText");
+        set_string_from_xml!(self, e, horizontal, "horiz");
+    }
+
+    /// Take over the VML shape of the control.
+    /// # Arguments
+    /// * `shape` - VML shape.
+    /// * `is_legacy` - The control has no `formControlPr` part, so its
+    ///   settings are read from the shape.
+    pub(crate) fn set_vml_shape(&mut self, shape: Shape, is_legacy: bool) {
+        if let Some(v) = shape.text_box() {
+            self.text.set_value(Self::plain_text(v.innder()));
+        }
+
+        if is_legacy {
+            let client_data = shape.client_data();
+            if let Some(v) = FormControlTypeValues::from_vml_object_type(client_data.object_type())
+            {
+                self.object_type.set_value(v);
+            }
+
+            let anchor = client_data.anchor();
+            self.control_properties
+                .object_anchor_mut()
+                .from_marker_mut()
+                .set_col(anchor.left_column() as usize)
+                .set_col_off(anchor.left_offset() as usize * EMU_PER_PIXEL)
+                .set_row(anchor.top_row() as usize)
+                .set_row_off(anchor.top_offset() as usize * EMU_PER_PIXEL);
+            self.control_properties
+                .object_anchor_mut()
+                .to_marker_mut()
+                .set_col(anchor.right_column() as usize)
+                .set_col_off(anchor.right_offset() as usize * EMU_PER_PIXEL)
+                .set_row(anchor.bottom_row() as usize)
+                .set_row_off(anchor.bottom_offset() as usize * EMU_PER_PIXEL);
+
+            for (name, text) in client_data.form_values() {
+                match &**name {
+                    "FmlaMacro" => {
+                        self.control_properties.set_macro_name(&**text);
+                    }
+                    "FmlaLink" => {
+                        self.linked_cell.set_value(&**text);
+                    }
+                    "FmlaRange" => {
+                        self.input_range.set_value(&**text);
+                    }
+                    "Checked" => {
+                        self.checked.set_value(match &**text {
+                            "2" => CheckedValues::Mixed,
+                            "0" => CheckedValues::Unchecked,
+                            _ => CheckedValues::Checked,
+                        });
+                    }
+                    "Val" => {
+                        self.value.set_value_string(&**text);
+                    }
+                    "Min" => {
+                        self.minimum.set_value_string(&**text);
+                    }
+                    "Max" => {
+                        self.maximum.set_value_string(&**text);
+                    }
+                    "Inc" => {
+                        self.increment.set_value_string(&**text);
+                    }
+                    "Page" => {
+                        self.page_increment.set_value_string(&**text);
+                    }
+                    "DropLines" => {
+                        self.drop_lines.set_value_string(&**text);
+                    }
+                    "Sel" => {
+                        self.selected.set_value_string(&**text);
+                    }
+                    "NoThreeD" => {
+                        self.no_three_d.set_value(true);
+                    }
+                    "Horiz" => {
+                        self.horizontal.set_value(true);
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        self.shape = shape;
+    }
+
+    fn plain_text(html: &str) -> String {
+        let mut text = String::new();
+        let mut in_tag = false;
+        for c in html.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                _ if !in_tag => text.push(c),
+                _ => (),
+            }
+        }
+        text.trim().to_string()
+    }
+
+    pub(crate) fn write_vml_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        id: usize,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
+        let mut shape = self.shape.clone();
+        if shape.get_type().is_empty() {
+            shape.set_type("#_x0000_t201");
+        }
+
+        if self.has_caption() && self.text.has_value() {
+            let is_changed = shape
+                .text_box()
+                .is_none_or(|v| Self::plain_text(v.innder()) != self.text());
+            if is_changed {
+                let align = if *self.object_type() == FormControlTypeValues::Button {
+                    "center"
+                } else {
+                    "left"
+                };
+                let mut text_box = shape.text_box().cloned().unwrap_or_default();
+                text_box.set_innder(format!(
+                    "<div style='text-align:{align}'><font face=\"Segoe UI\" size=\"160\" \
+                     color=\"auto\">{}</font></div>",
+                    escape::escape(self.text())
+                ));
+                shape.set_text_box(text_box);
+            }
+        }
+
+        // The anchor read from the drawing is kept; it is rebuilt for new or
+        // moved controls only.
+        let vml_anchor = shape.client_data().anchor();
+        if vml_anchor.right_column() == 0 && vml_anchor.bottom_row() == 0 {
+            let from = self.control_properties.object_anchor().from_marker();
+            let to = self.control_properties.object_anchor().to_marker();
+            let mut anchor = Anchor::default();
+            anchor
+                .set_left_column(num_traits::cast(from.col()).unwrap())
+                .set_left_offset(num_traits::cast(from.col_off() / EMU_PER_PIXEL).unwrap())
+                .set_top_row(num_traits::cast(from.row()).unwrap())
+                .set_top_offset(num_traits::cast(from.row_off() / EMU_PER_PIXEL).unwrap())
+                .set_right_column(num_traits::cast(to.col()).unwrap())
+                .set_right_offset(num_traits::cast(to.col_off() / EMU_PER_PIXEL).unwrap())
+                .set_bottom_row(num_traits::cast(to.row()).unwrap())
+                .set_bottom_offset(num_traits::cast(to.row_off() / EMU_PER_PIXEL).unwrap());
+            shape.client_data_mut().set_anchor(anchor);
+        }
+
+        let client_data = shape.client_data_mut();
+        client_data.set_object_type(self.object_type().vml_object_type());
+        let form_values = self.vml_form_values(client_data.form_values());
+        client_data.set_form_values(form_values);
+
+        shape.write_to(writer, id, rel_list);
+    }
+
+    // Merge the typed settings into the form control elements of the shape.
+    fn vml_form_values(&self, current: &[(Box<str>, Box<str>)]) -> Vec<(Box<str>, Box<str>)> {
+        const TYPED: &[&str] = &[
+            "FmlaMacro", "FmlaLink", "FmlaRange", "Checked", "Val", "Min", "Max", "Inc", "Page",
+            "DropLines", "Sel", "NoThreeD", "Horiz",
+        ];
+        let mut list: Vec<(Box<str>, Box<str>)> = current
+            .iter()
+            .filter(|(name, _)| !TYPED.contains(&&**name))
+            .cloned()
+            .collect();
+        let mut push = |name: &str, text: &str| list.push((name.into(), text.into()));
+
+        if !self.macro_name().is_empty() {
+            push("FmlaMacro", self.macro_name());
+        }
+        if self.linked_cell.has_value() {
+            push("FmlaLink", self.linked_cell());
+        }
+        if self.input_range.has_value() {
+            push("FmlaRange", self.input_range());
+        }
+        match self.checked() {
+            CheckedValues::Checked => push("Checked", "1"),
+            CheckedValues::Mixed => push("Checked", "2"),
+            CheckedValues::Unchecked => (),
+        }
+        for (name, value) in [
+            ("Val", &self.value),
+            ("Min", &self.minimum),
+            ("Max", &self.maximum),
+            ("Inc", &self.increment),
+            ("Page", &self.page_increment),
+            ("DropLines", &self.drop_lines),
+            ("Sel", &self.selected),
+        ] {
+            if value.has_value() {
+                push(name, &value.value_string());
+            }
+        }
+        if self.no_three_d() {
+            push("NoThreeD", "");
+        }
+        if self.horizontal() {
+            push("Horiz", "");
+        }
+        list
+    }
+
+    pub(crate) fn write_form_control_properties(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // XML header
+        writer
+            .write_event(Event::Decl(BytesDecl::new(
+                "1.0",
+                Some("UTF-8"),
+                Some("yes"),
+            )))
+            .unwrap();
+        write_new_line(writer);
+
+        // formControlPr
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("xmlns", SHEET_MS_MAIN_NS).into());
+        attributes.push(("objectType", self.object_type.value_string()).into());
+        if self.checked.has_value() {
+            attributes.push(("checked", self.checked.value_string()).into());
+        }
+        if self.drop_style.has_value() {
+            attributes.push(("dropStyle", self.drop_style.value_str()).into());
+        }
+        if self.linked_cell.has_value() {
+            attributes.push(("fmlaLink", self.linked_cell.value_str()).into());
+        }
+        if self.input_range.has_value() {
+            attributes.push(("fmlaRange", self.input_range.value_str()).into());
+        }
+        if self.horizontal.has_value() {
+            attributes.push(("horiz", self.horizontal.value_string()).into());
+        }
+        if self.lock_text.has_value() {
+            attributes.push(("lockText", self.lock_text.value_string()).into());
+        }
+        if self.no_three_d.has_value() {
+            attributes.push(("noThreeD", self.no_three_d.value_string()).into());
+        }
+        let numbers = [
+            ("dropLines", &self.drop_lines),
+            ("inc", &self.increment),
+            ("max", &self.maximum),
+            ("min", &self.minimum),
+            ("page", &self.page_increment),
+            ("sel", &self.selected),
+            ("val", &self.value),
+        ]
+        .map(|(name, value)| (name, value.has_value().then(|| value.value_string())));
+        for (name, value) in &numbers {
+            if let Some(v) = value {
+                attributes.push((*name, v.as_str()).into());
+            }
+        }
+
+        if self.items.is_empty() {
+            write_start_tag(writer, "formControlPr", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "formControlPr", attributes, false);
+
+        // itemLst
+        write_start_tag(writer, "itemLst", vec![], false);
+        for item in &self.items {
+            write_start_tag(writer, "item", vec![("val", item.as_str()).into()], true);
+        }
+        write_end_tag(writer, "itemLst");
+
+        write_end_tag(writer, "formControlPr");
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        r_id: usize,
+        shape_id: usize,
+    ) {
+        // mc:AlternateContent
+        write_start_tag(
+            writer,
+            "mc:AlternateContent",
+            vec![("xmlns:mc", MC_NS).into()],
+            false,
+        );
+
+        // mc:Choice
+        write_start_tag(writer, "mc:Choice", vec![("Requires", "x14").into()], false);
+
+        // control
+        let shape_id_str = shape_id.to_string();
+        let r_id_str = format!("rId{r_id}");
+        write_start_tag(
+            writer,
+            "control",
+            vec![
+                ("shapeId", shape_id_str.as_str()).into(),
+                ("r:id", r_id_str.as_str()).into(),
+                ("name", self.name.value_str()).into(),
+            ],
+            false,
+        );
+
+        // controlPr
+        self.control_properties.write_to(writer, r_id + 1);
+
+        write_end_tag(writer, "control");
+
+        write_end_tag(writer, "mc:Choice");
+
+        write_end_tag(writer, "mc:AlternateContent");
+    }
+}
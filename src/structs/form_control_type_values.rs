@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use super::{
+    EnumTrait,
+    vml::spreadsheet::ObjectValues,
+};
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FormControlTypeValues {
+    Button,
+    CheckBox,
+    Dialog,
+    Drop,
+    EditBox,
+    GroupBox,
+    Label,
+    List,
+    Radio,
+    Scroll,
+    Spin,
+}
+impl Default for FormControlTypeValues {
+    #[inline]
+    fn default() -> Self {
+        Self::CheckBox
+    }
+}
+impl EnumTrait for FormControlTypeValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Button => "Button",
+            Self::CheckBox => "CheckBox",
+            Self::Dialog => "Dialog",
+            Self::Drop => "Drop",
+            Self::EditBox => "EditBox",
+            Self::GroupBox => "GBox",
+            Self::Label => "Label",
+            Self::List => "List",
+            Self::Radio => "Radio",
+            Self::Scroll => "Scroll",
+            Self::Spin => "Spin",
+        }
+    }
+}
+impl FromStr for FormControlTypeValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Button" => Ok(Self::Button),
+            "CheckBox" => Ok(Self::CheckBox),
+            "Dialog" => Ok(Self::Dialog),
+            "Drop" => Ok(Self::Drop),
+            "EditBox" => Ok(Self::EditBox),
+            "GBox" => Ok(Self::GroupBox),
+            "Label" => Ok(Self::Label),
+            "List" => Ok(Self::List),
+            "Radio" => Ok(Self::Radio),
+            "Scroll" => Ok(Self::Scroll),
+            "Spin" => Ok(Self::Spin),
+            _ => Err(()),
+        }
+    }
+}
+impl FormControlTypeValues {
+    /// Object type used by the VML shape of the control.
+    #[inline]
+    pub(crate) fn vml_object_type(&self) -> ObjectValues {
+        match &self {
+            Self::Button => ObjectValues::Button,
+            Self::CheckBox => ObjectValues::Checkbox,
+            Self::Dialog => ObjectValues::Dialog,
+            Self::Drop => ObjectValues::Drop,
+            Self::EditBox => ObjectValues::Edit,
+            Self::GroupBox => ObjectValues::GroupBox,
+            Self::Label => ObjectValues::Label,
+            Self::List => ObjectValues::List,
+            Self::Radio => ObjectValues::Radio,
+            Self::Scroll => ObjectValues::Scroll,
+            Self::Spin => ObjectValues::Spin,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_vml_object_type(value: &ObjectValues) -> Option<Self> {
+        match value {
+            ObjectValues::Button => Some(Self::Button),
+            ObjectValues::Checkbox => Some(Self::CheckBox),
+            ObjectValues::Dialog => Some(Self::Dialog),
+            ObjectValues::Drop => Some(Self::Drop),
+            ObjectValues::Edit => Some(Self::EditBox),
+            ObjectValues::GroupBox => Some(Self::GroupBox),
+            ObjectValues::Label => Some(Self::Label),
+            ObjectValues::List => Some(Self::List),
+            ObjectValues::Radio => Some(Self::Radio),
+            ObjectValues::Scroll => Some(Self::Scroll),
+            ObjectValues::Spin => Some(Self::Spin),
+            _ => None,
+        }
+    }
+}
@@ -12,7 +12,20 @@ use super::{
     StringValue,
 };
 use crate::{
-    helper::address::is_address,
+    XlsxError,
+    helper::{
+        address::is_address,
+        formula::{
+            adjustment_insert_formula_coordinate,
+            adjustment_remove_formula_coordinate,
+            is_same_sheet_name,
+            parse_to_tokens,
+            remove_sheet_in_formula,
+            rename_sheet_in_formula,
+        },
+    },
+    reader::driver::invalid_xml,
+    traits::AdjustmentCoordinate,
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -21,6 +34,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Formula {
     address:      Address,
     string_value: StringValue,
@@ -92,15 +106,40 @@ impl Formula {
         self
     }
 
+    /// (This method is crate only.)
+    /// Replace the sheet name of the references to a renamed sheet.
+    pub(crate) fn rename_sheet_reference(&mut self, old_name: &str, new_name: &str) {
+        if self.string_value.has_value() {
+            let value = rename_sheet_in_formula(self.string_value.value_str(), old_name, new_name);
+            self.string_value.set_value(value);
+        } else if is_same_sheet_name(self.address.sheet_name(), old_name) {
+            self.address.set_sheet_name(new_name);
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Turn the references to a removed sheet into `#REF!`.
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        if self.string_value.has_value() {
+            let value = remove_sheet_in_formula(self.string_value.value_str(), sheet_name);
+            self.string_value.set_value(value);
+        } else if is_same_sheet_name(self.address.sheet_name(), sheet_name) {
+            let value = format!("#REF!{}", self.address.range().range());
+            self.set_string_value(value);
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         let text = reader
-                    .read_text_into(e.name(), &mut buf).unwrap();
+                    .read_text_into(e.name(), &mut buf)
+                    .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_address_str(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     #[inline]
@@ -111,3 +150,44 @@ impl Formula {
         write_end_tag(writer, "formula");
     }
 }
+impl AdjustmentCoordinate for Formula {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        let formula = adjustment_insert_formula_coordinate(
+            &mut parse_to_tokens(format!("={}", self.address_str())),
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+            "",
+            "",
+            false,
+        );
+        self.set_address_str(formula);
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        let formula = adjustment_remove_formula_coordinate(
+            &mut parse_to_tokens(format!("={}", self.address_str())),
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+            "",
+            "",
+            false,
+        );
+        self.set_address_str(formula);
+    }
+}
@@ -8,6 +8,8 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -16,6 +18,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Formula2 {
     text: String,
 }
@@ -44,11 +47,13 @@ impl Formula2 {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         let text = reader
-                    .read_text_into(e.name(), &mut buf).unwrap();
+                    .read_text_into(e.name(), &mut buf)
+                    .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_text(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     #[inline]
@@ -11,7 +11,13 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        invalid_element,
+        invalid_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -20,6 +26,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FromMarker {
     col:     usize,
     col_off: usize,
@@ -136,38 +143,58 @@ impl FromMarker {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"xdr:col" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
-                    self.col = text.parse::<usize>().unwrap();
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
+                    self.col = text
+                        .parse::<usize>()
+                        .map_err(|v| invalid_element(reader, "xdr:col", v.to_string()))?;
                 }
                 b"xdr:colOff" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
-                    self.col_off = text.parse::<usize>().unwrap();
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
+                    self.col_off = text
+                        .parse::<usize>()
+                        .map_err(|v| invalid_element(reader, "xdr:colOff", v.to_string()))?;
                 }
                 b"xdr:row" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
-                    self.row = text.parse::<usize>().unwrap();
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
+                    self.row = text
+                        .parse::<usize>()
+                        .map_err(|v| invalid_element(reader, "xdr:row", v.to_string()))?;
                 }
                 b"xdr:rowOff" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
-                    self.row_off = text.parse::<usize>().unwrap();
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
+                    self.row_off = text
+                        .parse::<usize>()
+                        .map_err(|v| invalid_element(reader, "xdr:rowOff", v.to_string()))?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"from" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "from")
+            Event::Eof => return Err(unexpected_eof(reader, "from"))
         );
     }
 
@@ -18,9 +18,11 @@ use super::{
     GradientStop,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -30,6 +32,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradientFill {
     degree:        DoubleValue,
     gradient_stop: Vec<GradientStop>,
@@ -102,7 +105,7 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, degree, "degree");
 
         xml_read_loop!(
@@ -110,16 +113,16 @@ impl GradientFill {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"stop" {
                     let mut obj = GradientStop::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_gradient_stop(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"gradientFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "gradientFill")
+            Event::Eof => return Err(unexpected_eof(reader, "gradientFill"))
         );
     }
 
@@ -11,8 +11,24 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::{
+        BooleanValue,
+        EvenFooter,
+        EvenHeader,
+        FirstFooter,
+        FirstHeader,
+        HeaderFooterField,
+        HeaderFooterImage,
+        HeaderFooterImageSection,
+        HeaderFooterSection,
+        MediaObject,
         OddFooter,
         OddHeader,
     },
@@ -23,9 +39,19 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HeaderFooter {
-    odd_header: OddHeader,
-    odd_footer: OddFooter,
+    odd_header:         OddHeader,
+    odd_footer:         OddFooter,
+    even_header:        EvenHeader,
+    even_footer:        EvenFooter,
+    first_header:       FirstHeader,
+    first_footer:       FirstFooter,
+    different_odd_even: BooleanValue,
+    different_first:    BooleanValue,
+    scale_with_doc:     BooleanValue,
+    align_with_margins: BooleanValue,
+    images:             Vec<HeaderFooterImage>,
 }
 
 impl HeaderFooter {
@@ -89,42 +115,308 @@ impl HeaderFooter {
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn even_header(&self) -> &EvenHeader {
+        &self.even_header
+    }
+
+    #[inline]
+    pub fn even_header_mut(&mut self) -> &mut EvenHeader {
+        &mut self.even_header
+    }
+
+    #[inline]
+    pub fn set_even_header(&mut self, value: EvenHeader) -> &mut Self {
+        self.even_header = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn even_footer(&self) -> &EvenFooter {
+        &self.even_footer
+    }
+
+    #[inline]
+    pub fn even_footer_mut(&mut self) -> &mut EvenFooter {
+        &mut self.even_footer
+    }
+
+    #[inline]
+    pub fn set_even_footer(&mut self, value: EvenFooter) -> &mut Self {
+        self.even_footer = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn first_header(&self) -> &FirstHeader {
+        &self.first_header
+    }
+
+    #[inline]
+    pub fn first_header_mut(&mut self) -> &mut FirstHeader {
+        &mut self.first_header
+    }
+
+    #[inline]
+    pub fn set_first_header(&mut self, value: FirstHeader) -> &mut Self {
+        self.first_header = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn first_footer(&self) -> &FirstFooter {
+        &self.first_footer
+    }
+
+    #[inline]
+    pub fn first_footer_mut(&mut self) -> &mut FirstFooter {
+        &mut self.first_footer
+    }
+
+    #[inline]
+    pub fn set_first_footer(&mut self, value: FirstFooter) -> &mut Self {
+        self.first_footer = value;
+        self
+    }
+
+    /// Whether the even pages use the even header and footer.
+    #[inline]
+    #[must_use]
+    pub fn different_odd_even(&self) -> bool {
+        self.different_odd_even.value()
+    }
+
+    #[inline]
+    pub fn set_different_odd_even(&mut self, value: bool) -> &mut Self {
+        self.different_odd_even.set_value(value);
+        self
+    }
+
+    /// Whether the first page uses the first header and footer.
+    #[inline]
+    #[must_use]
+    pub fn different_first(&self) -> bool {
+        self.different_first.value()
+    }
+
+    #[inline]
+    pub fn set_different_first(&mut self, value: bool) -> &mut Self {
+        self.different_first.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn scale_with_doc(&self) -> bool {
+        self.scale_with_doc.value()
+    }
+
+    #[inline]
+    pub fn set_scale_with_doc(&mut self, value: bool) -> &mut Self {
+        self.scale_with_doc.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn align_with_margins(&self) -> bool {
+        self.align_with_margins.value()
+    }
+
+    #[inline]
+    pub fn set_align_with_margins(&mut self, value: bool) -> &mut Self {
+        self.align_with_margins.set_value(value);
+        self
+    }
+
+    /// Get the pictures of the header and footer.
+    #[inline]
+    #[must_use]
+    pub fn images(&self) -> &[HeaderFooterImage] {
+        &self.images
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn image(&self, section: HeaderFooterImageSection) -> Option<&HeaderFooterImage> {
+        self.images.iter().find(|v| v.id() == section.vml_id())
+    }
+
+    #[inline]
+    pub fn image_mut(
+        &mut self,
+        section: HeaderFooterImageSection,
+    ) -> Option<&mut HeaderFooterImage> {
+        self.images.iter_mut().find(|v| v.id() == section.vml_id())
+    }
+
+    /// Set the picture of a part of the odd page header or footer.
+    /// The `&G` code is added to the part, and the picture is written to the
+    /// VML drawing of the header and footer.
+    /// The size is taken from the image data.
+    /// # Arguments
+    /// * `section` - Place of the picture.
+    /// * `image` - Image with its file name. ex) `make_media_object("logo.png")`
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::{
+    ///     helper::binary::make_media_object,
+    ///     structs::HeaderFooterImageSection,
+    /// };
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let image = make_media_object("./images/sample1.png");
+    /// let header_footer = book.sheet_mut(0).unwrap().header_footer_mut();
+    /// header_footer.set_image(HeaderFooterImageSection::LeftHeader, image);
+    /// assert_eq!(header_footer.odd_header().value(), "&L&G");
+    /// ```
+    pub fn set_image(
+        &mut self,
+        section: HeaderFooterImageSection,
+        image: MediaObject,
+    ) -> &mut Self {
+        let (width, height) = imagesize::blob_size(image.image_data()).map_or((0.0, 0.0), |v| {
+            let width: f64 = num_traits::cast(v.width).unwrap();
+            let height: f64 = num_traits::cast(v.height).unwrap();
+            // pixels to points
+            (width * 0.75, height * 0.75)
+        });
+        self.update_section(section, |fields| {
+            if !fields.contains(&HeaderFooterField::Picture) {
+                fields.push(HeaderFooterField::Picture);
+            }
+        });
+        self.images.retain(|v| v.id() != section.vml_id());
+        let mut obj = HeaderFooterImage::default();
+        obj.set_id(section.vml_id())
+            .set_image(image)
+            .set_width(width)
+            .set_height(height);
+        self.images.push(obj);
+        self
+    }
+
+    /// Remove the picture and its `&G` code.
+    pub fn remove_image(&mut self, section: HeaderFooterImageSection) -> &mut Self {
+        self.update_section(section, |fields| {
+            fields.retain(|v| v != &HeaderFooterField::Picture);
+        });
+        self.images.retain(|v| v.id() != section.vml_id());
+        self
+    }
+
+    fn update_section<F: FnOnce(&mut Vec<HeaderFooterField>)>(
+        &mut self,
+        section: HeaderFooterImageSection,
+        f: F,
+    ) {
+        let mut value: HeaderFooterSection = if section.is_header() {
+            self.odd_header.section()
+        } else {
+            self.odd_footer.section()
+        };
+        f(value.fields_mut(section.position()));
+        if section.is_header() {
+            self.odd_header.set_section(&value);
+        } else {
+            self.odd_footer.set_section(&value);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add_image_crate(&mut self, value: HeaderFooterImage) {
+        self.images.push(value);
+    }
+
     #[inline]
     pub(crate) fn has_param(&self) -> bool {
-        self.odd_header.has_param() || self.odd_footer.has_param()
+        self.odd_header.has_param()
+            || self.odd_footer.has_param()
+            || self.even_header.has_param()
+            || self.even_footer.has_param()
+            || self.first_header.has_param()
+            || self.first_footer.has_param()
+            || self.different_odd_even.has_value()
+            || self.different_first.has_value()
+            || self.scale_with_doc.has_value()
+            || self.align_with_margins.has_value()
     }
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, different_odd_even, "differentOddEven");
+        set_string_from_xml!(self, e, different_first, "differentFirst");
+        set_string_from_xml!(self, e, scale_with_doc, "scaleWithDoc");
+        set_string_from_xml!(self, e, align_with_margins, "alignWithMargins");
+        if empty_flag {
+            return Ok(());
+        }
+
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"oddHeader" => {
-                    self.odd_header.set_attributes(reader, e);
+                    self.odd_header.set_attributes(reader, e)?;
                 }
                 b"oddFooter" => {
-                    self.odd_footer.set_attributes(reader, e);
+                    self.odd_footer.set_attributes(reader, e)?;
+                }
+                b"evenHeader" => {
+                    self.even_header.set_attributes(reader, e)?;
+                }
+                b"evenFooter" => {
+                    self.even_footer.set_attributes(reader, e)?;
+                }
+                b"firstHeader" => {
+                    self.first_header.set_attributes(reader, e)?;
+                }
+                b"firstFooter" => {
+                    self.first_footer.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"headerFooter" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "headerFooter")
+            Event::Eof => return Err(unexpected_eof(reader, "headerFooter"))
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         if self.has_param() {
             // headerFooter
-            write_start_tag(writer, "headerFooter", vec![], false);
+            let mut attributes: crate::structs::AttrCollection = Vec::new();
+            if self.different_odd_even.has_value() {
+                attributes.push((
+                    "differentOddEven",
+                    self.different_odd_even.value_string(),
+                ).into());
+            }
+            if self.different_first.has_value() {
+                attributes.push(("differentFirst", self.different_first.value_string()).into());
+            }
+            if self.scale_with_doc.has_value() {
+                attributes.push(("scaleWithDoc", self.scale_with_doc.value_string()).into());
+            }
+            if self.align_with_margins.has_value() {
+                attributes.push((
+                    "alignWithMargins",
+                    self.align_with_margins.value_string(),
+                ).into());
+            }
+            write_start_tag(writer, "headerFooter", attributes, false);
 
             // oddHeader
             self.odd_header().write_to(writer);
@@ -132,6 +424,18 @@ impl HeaderFooter {
             // oddFooter
             self.odd_footer().write_to(writer);
 
+            // evenHeader
+            self.even_header().write_to(writer);
+
+            // evenFooter
+            self.even_footer().write_to(writer);
+
+            // firstHeader
+            self.first_header().write_to(writer);
+
+            // firstFooter
+            self.first_footer().write_to(writer);
+
             write_end_tag(writer, "headerFooter");
         }
     }
@@ -0,0 +1,102 @@
+/// A piece of a header or footer section.
+/// Each variant but `Text` is an Excel `&` code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeaderFooterField {
+    /// Literal text. `&` is escaped on writing.
+    Text(Box<str>),
+    /// `&P`
+    PageNumber,
+    /// `&N`
+    PageCount,
+    /// `&D`
+    Date,
+    /// `&T`
+    Time,
+    /// `&Z`
+    FilePath,
+    /// `&F`
+    FileName,
+    /// `&A`
+    SheetName,
+    /// `&G`, the picture set with `HeaderFooter::set_image`.
+    Picture,
+    /// `&"name,style"` ex) `&"Arial,Bold"`
+    /// The style is `None` when the code has no comma. ex) `&"Arial"`
+    Font {
+        name:  Box<str>,
+        style: Option<Box<str>>,
+    },
+    /// `&nn` ex) `&12`
+    FontSize(u32),
+    /// `&K` with a RGB color or a theme color. ex) `FF0000`, `01+000`
+    Color(Box<str>),
+    /// `&B`
+    Bold,
+    /// `&I`
+    Italic,
+    /// `&U`
+    Underline,
+    /// `&E`
+    DoubleUnderline,
+    /// `&S`
+    Strikethrough,
+    /// `&X`
+    Superscript,
+    /// `&Y`
+    Subscript,
+    /// An `&` code that is not known, kept as written. ex) `&Q`
+    Unknown(Box<str>),
+}
+
+impl HeaderFooterField {
+    pub(crate) fn code(&self) -> String {
+        match self {
+            Self::Text(v) => v.replace('&', "&&"),
+            Self::PageNumber => "&P".into(),
+            Self::PageCount => "&N".into(),
+            Self::Date => "&D".into(),
+            Self::Time => "&T".into(),
+            Self::FilePath => "&Z".into(),
+            Self::FileName => "&F".into(),
+            Self::SheetName => "&A".into(),
+            Self::Picture => "&G".into(),
+            Self::Font {
+                name,
+                style: Some(style),
+            } => format!("&\"{name},{style}\""),
+            Self::Font { name, style: None } => format!("&\"{name}\""),
+            Self::FontSize(v) => format!("&{v}"),
+            Self::Color(v) => format!("&K{v}"),
+            Self::Bold => "&B".into(),
+            Self::Italic => "&I".into(),
+            Self::Underline => "&U".into(),
+            Self::DoubleUnderline => "&E".into(),
+            Self::Strikethrough => "&S".into(),
+            Self::Superscript => "&X".into(),
+            Self::Subscript => "&Y".into(),
+            Self::Unknown(v) => v.to_string(),
+        }
+    }
+
+    pub(crate) fn from_code(code: char) -> Option<Self> {
+        Some(match code {
+            'P' => Self::PageNumber,
+            'N' => Self::PageCount,
+            'D' => Self::Date,
+            'T' => Self::Time,
+            'Z' => Self::FilePath,
+            'F' => Self::FileName,
+            'A' => Self::SheetName,
+            'G' => Self::Picture,
+            'B' => Self::Bold,
+            'I' => Self::Italic,
+            'U' => Self::Underline,
+            'E' => Self::DoubleUnderline,
+            'S' => Self::Strikethrough,
+            'X' => Self::Superscript,
+            'Y' => Self::Subscript,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,126 @@
+use super::{
+    DoubleValue,
+    HeaderFooterImageSection,
+    MediaObject,
+    StringValue,
+};
+
+/// A picture shown by the `&G` code of a header or footer.
+/// It is stored in the VML drawing of the `legacyDrawingHF` part.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeaderFooterImage {
+    id:     StringValue,
+    image:  MediaObject,
+    width:  DoubleValue,
+    height: DoubleValue,
+}
+impl HeaderFooterImage {
+    #[inline]
+    #[must_use]
+    pub fn image(&self) -> &MediaObject {
+        &self.image
+    }
+
+    #[inline]
+    pub fn image_mut(&mut self) -> &mut MediaObject {
+        &mut self.image
+    }
+
+    #[inline]
+    pub fn set_image(&mut self, value: MediaObject) -> &mut Self {
+        self.image = value;
+        self
+    }
+
+    /// Width in points.
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> f64 {
+        self.width.value()
+    }
+
+    #[inline]
+    pub fn set_width(&mut self, value: f64) -> &mut Self {
+        self.width.set_value(value);
+        self
+    }
+
+    /// Height in points.
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> f64 {
+        self.height.value()
+    }
+
+    #[inline]
+    pub fn set_height(&mut self, value: f64) -> &mut Self {
+        self.height.set_value(value);
+        self
+    }
+
+    /// Get the place of the picture.
+    /// `None` for the pictures of even or first pages read from a file.
+    #[must_use]
+    pub fn section(&self) -> Option<HeaderFooterImageSection> {
+        [
+            HeaderFooterImageSection::LeftHeader,
+            HeaderFooterImageSection::CenterHeader,
+            HeaderFooterImageSection::RightHeader,
+            HeaderFooterImageSection::LeftFooter,
+            HeaderFooterImageSection::CenterFooter,
+            HeaderFooterImageSection::RightFooter,
+        ]
+        .into_iter()
+        .find(|v| v.vml_id() == self.id())
+    }
+
+    #[inline]
+    pub(crate) fn id(&self) -> &str {
+        self.id.value_str()
+    }
+
+    #[inline]
+    pub(crate) fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Whether the VML shape id is one of a header or footer picture.
+    /// ex) "CH", "LFEVEN", "RHFIRST"
+    pub(crate) fn is_header_footer_id(id: &str) -> bool {
+        let id = id
+            .strip_suffix("EVEN")
+            .or_else(|| id.strip_suffix("FIRST"))
+            .unwrap_or(id);
+        matches!(id, "LH" | "CH" | "RH" | "LF" | "CF" | "RF")
+    }
+
+    /// (This method is crate only.)
+    /// Read the size from a VML style. ex) "width:60pt;height:30pt"
+    pub(crate) fn set_style(&mut self, style: &str) -> &mut Self {
+        for (key, value) in style.split(';').filter_map(|v| v.split_once(':')) {
+            let Some(value) = value.trim().strip_suffix("pt") else {
+                continue;
+            };
+            let Ok(value) = value.parse::<f64>() else {
+                continue;
+            };
+            match key.trim() {
+                "width" => self.set_width(value),
+                "height" => self.set_height(value),
+                _ => continue,
+            };
+        }
+        self
+    }
+
+    pub(crate) fn style(&self) -> String {
+        format!(
+            "position:absolute;margin-left:0;margin-top:0;width:{}pt;height:{}pt;z-index:1",
+            self.width.value_string(),
+            self.height.value_string()
+        )
+    }
+}
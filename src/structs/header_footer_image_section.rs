@@ -0,0 +1,46 @@
+use super::HeaderFooterPosition;
+
+/// The place of a header or footer picture.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeaderFooterImageSection {
+    LeftHeader,
+    CenterHeader,
+    RightHeader,
+    LeftFooter,
+    CenterFooter,
+    RightFooter,
+}
+impl HeaderFooterImageSection {
+    /// Id of the VML shape. ex) "CH" is the center header.
+    #[inline]
+    pub(crate) fn vml_id(self) -> &'static str {
+        match self {
+            Self::LeftHeader => "LH",
+            Self::CenterHeader => "CH",
+            Self::RightHeader => "RH",
+            Self::LeftFooter => "LF",
+            Self::CenterFooter => "CF",
+            Self::RightFooter => "RF",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn position(self) -> HeaderFooterPosition {
+        match self {
+            Self::LeftHeader | Self::LeftFooter => HeaderFooterPosition::Left,
+            Self::CenterHeader | Self::CenterFooter => HeaderFooterPosition::Center,
+            Self::RightHeader | Self::RightFooter => HeaderFooterPosition::Right,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_header(self) -> bool {
+        matches!(
+            self,
+            Self::LeftHeader | Self::CenterHeader | Self::RightHeader
+        )
+    }
+}
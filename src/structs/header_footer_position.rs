@@ -0,0 +1,29 @@
+/// The left, center or right part of a header or footer.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeaderFooterPosition {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+impl HeaderFooterPosition {
+    #[inline]
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Self::Left => "&L",
+            Self::Center => "&C",
+            Self::Right => "&R",
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_code(code: char) -> Option<Self> {
+        match code {
+            'L' => Some(Self::Left),
+            'C' => Some(Self::Center),
+            'R' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt;
+
+use super::{
+    HeaderFooterField,
+    HeaderFooterPosition,
+};
+
+/// The content of a header or footer, split into its left, center and right
+/// parts.
+/// `to_string()` gives the string with Excel's `&` codes.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::{
+///     HeaderFooterPosition,
+///     HeaderFooterSection,
+/// };
+///
+/// let mut section = HeaderFooterSection::default();
+/// section
+///     .add_font(HeaderFooterPosition::Left, "Arial", "Bold")
+///     .add_text(HeaderFooterPosition::Left, "Invoice")
+///     .add_text(HeaderFooterPosition::Right, "Page ")
+///     .add_page_number(HeaderFooterPosition::Right)
+///     .add_text(HeaderFooterPosition::Right, " of ")
+///     .add_page_count(HeaderFooterPosition::Right);
+/// assert_eq!(
+///     section.to_string(),
+///     "&L&\"Arial,Bold\"Invoice&RPage &P of &N"
+/// );
+///
+/// let section = HeaderFooterSection::parse("&CQ&&A &D");
+/// assert_eq!(section.center().len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeaderFooterSection {
+    left:   Vec<HeaderFooterField>,
+    center: Vec<HeaderFooterField>,
+    right:  Vec<HeaderFooterField>,
+}
+impl HeaderFooterSection {
+    /// Parse a header or footer string.
+    /// Text before the first `&L`, `&C` or `&R` belongs to the center part.
+    /// Codes are case sensitive. Unknown codes are kept as written, so a
+    /// string written by Excel is given back by `to_string()`.
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        let mut result = Self::default();
+        let mut position = HeaderFooterPosition::Center;
+        let mut text = String::new();
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                text.push(c);
+                continue;
+            }
+            let Some(&code) = chars.peek() else {
+                result.push_text(position, &mut text);
+                result.add(position, HeaderFooterField::Unknown("&".into()));
+                break;
+            };
+            if code == '&' {
+                chars.next();
+                text.push('&');
+                continue;
+            }
+            let field = if code == '"' {
+                chars.next();
+                let mut font = String::new();
+                let mut is_closed = false;
+                for v in chars.by_ref() {
+                    if v == '"' {
+                        is_closed = true;
+                        break;
+                    }
+                    font.push(v);
+                }
+                if is_closed {
+                    let (name, style) = match font.split_once(',') {
+                        Some((name, style)) => (name, Some(style.into())),
+                        None => (font.as_str(), None),
+                    };
+                    HeaderFooterField::Font {
+                        name: name.into(),
+                        style,
+                    }
+                } else {
+                    HeaderFooterField::Unknown(format!("&\"{font}").into())
+                }
+            } else if code.is_ascii_digit() {
+                let mut size = String::new();
+                while let Some(v) = chars.next_if(char::is_ascii_digit) {
+                    size.push(v);
+                }
+                match size.parse::<u32>() {
+                    Ok(v) if v.to_string() == size => HeaderFooterField::FontSize(v),
+                    _ => HeaderFooterField::Unknown(format!("&{size}").into()),
+                }
+            } else if code == 'K' {
+                chars.next();
+                let color: String = chars.by_ref().take(6).collect();
+                HeaderFooterField::Color(color.into())
+            } else if let Some(v) = HeaderFooterPosition::from_code(code) {
+                chars.next();
+                result.push_text(position, &mut text);
+                position = v;
+                continue;
+            } else {
+                chars.next();
+                HeaderFooterField::from_code(code)
+                    .unwrap_or_else(|| HeaderFooterField::Unknown(format!("&{code}").into()))
+            };
+            result.push_text(position, &mut text);
+            result.add(position, field);
+        }
+        result.push_text(position, &mut text);
+        result
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn left(&self) -> &[HeaderFooterField] {
+        &self.left
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> &[HeaderFooterField] {
+        &self.center
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn right(&self) -> &[HeaderFooterField] {
+        &self.right
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn fields(&self, position: HeaderFooterPosition) -> &[HeaderFooterField] {
+        match position {
+            HeaderFooterPosition::Left => &self.left,
+            HeaderFooterPosition::Center => &self.center,
+            HeaderFooterPosition::Right => &self.right,
+        }
+    }
+
+    #[inline]
+    pub fn fields_mut(&mut self, position: HeaderFooterPosition) -> &mut Vec<HeaderFooterField> {
+        match position {
+            HeaderFooterPosition::Left => &mut self.left,
+            HeaderFooterPosition::Center => &mut self.center,
+            HeaderFooterPosition::Right => &mut self.right,
+        }
+    }
+
+    #[inline]
+    pub fn add(&mut self, position: HeaderFooterPosition, field: HeaderFooterField) -> &mut Self {
+        self.fields_mut(position).push(field);
+        self
+    }
+
+    #[inline]
+    pub fn add_text<S: Into<String>>(
+        &mut self,
+        position: HeaderFooterPosition,
+        text: S,
+    ) -> &mut Self {
+        self.add(position, HeaderFooterField::Text(text.into().into()))
+    }
+
+    #[inline]
+    pub fn add_page_number(&mut self, position: HeaderFooterPosition) -> &mut Self {
+        self.add(position, HeaderFooterField::PageNumber)
+    }
+
+    #[inline]
+    pub fn add_page_count(&mut self, position: HeaderFooterPosition) -> &mut Self {
+        self.add(position, HeaderFooterField::PageCount)
+    }
+
+    #[inline]
+    pub fn add_date(&mut self, position: HeaderFooterPosition) -> &mut Self {
+        self.add(position, HeaderFooterField::Date)
+    }
+
+    #[inline]
+    pub fn add_time(&mut self, position: HeaderFooterPosition) -> &mut Self {
+        self.add(position, HeaderFooterField::Time)
+    }
+
+    #[inline]
+    pub fn add_file_name(&mut self, position: HeaderFooterPosition) -> &mut Self {
+        self.add(position, HeaderFooterField::FileName)
+    }
+
+    #[inline]
+    pub fn add_sheet_name(&mut self, position: HeaderFooterPosition) -> &mut Self {
+        self.add(position, HeaderFooterField::SheetName)
+    }
+
+    /// Add a font change.
+    /// # Arguments
+    /// * `name` - Font name. ex) "Arial"
+    /// * `style` - Font style. ex) "Bold", "Bold Italic", "Regular"
+    #[inline]
+    pub fn add_font<S: Into<String>>(
+        &mut self,
+        position: HeaderFooterPosition,
+        name: S,
+        style: S,
+    ) -> &mut Self {
+        self.add(
+            position,
+            HeaderFooterField::Font {
+                name:  name.into().into(),
+                style: Some(style.into().into()),
+            },
+        )
+    }
+
+    /// Add a font size change.
+    /// Text added after it should not start with a digit, which would be
+    /// read as a part of the size. ex) `&12` then `3` is written as `&123`.
+    #[inline]
+    pub fn add_font_size(&mut self, position: HeaderFooterPosition, size: u32) -> &mut Self {
+        self.add(position, HeaderFooterField::FontSize(size))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.center.is_empty() && self.right.is_empty()
+    }
+
+    fn push_text(&mut self, position: HeaderFooterPosition, text: &mut String) {
+        if !text.is_empty() {
+            self.add_text(position, std::mem::take(text));
+        }
+    }
+}
+impl fmt::Display for HeaderFooterSection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for position in [
+            HeaderFooterPosition::Left,
+            HeaderFooterPosition::Center,
+            HeaderFooterPosition::Right,
+        ] {
+            let fields = self.fields(position);
+            if fields.is_empty() {
+                continue;
+            }
+            write!(f, "{}", position.code())?;
+            for field in fields {
+                write!(f, "{}", field.code())?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hyperlink {
     url:      Box<str>,
     tooltip:  Box<str>,
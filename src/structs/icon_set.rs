@@ -10,24 +10,95 @@ use quick_xml::{
 };
 
 use super::{
+    BooleanValue,
     Color,
     ConditionalFormatValueObject,
+    ConditionalFormattingIcon,
+    EnumValue,
+    IconSetValues,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
 };
 
+/// An Icon Set of a conditional formatting rule.
+///
+/// Icon sets added in Excel 2010 (`3Stars`, `3Triangles`, `5Boxes`,
+/// `NoIcons`) and sets with custom icons are written to the Excel 2010
+/// extension of the worksheet only.
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IconSet {
+    icon_set:         EnumValue<IconSetValues>,
+    show_value:       BooleanValue,
+    percent:          BooleanValue,
+    reverse:          BooleanValue,
     cfvo_collection:  Vec<ConditionalFormatValueObject>,
     color_collection: Vec<Color>,
+    custom_icons:     Vec<ConditionalFormattingIcon>,
 }
 
 impl IconSet {
+    #[inline]
+    #[must_use]
+    pub fn icon_set(&self) -> &IconSetValues {
+        self.icon_set.value()
+    }
+
+    #[inline]
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    /// Get whether the cell value is shown next to the icon.
+    #[inline]
+    #[must_use]
+    pub fn show_value(&self) -> bool {
+        !self.show_value.has_value() || self.show_value.value()
+    }
+
+    #[inline]
+    pub fn set_show_value(&mut self, value: bool) -> &mut Self {
+        self.show_value.set_value(value);
+        self
+    }
+
+    /// Get whether the thresholds are percentages.
+    #[inline]
+    #[must_use]
+    pub fn percent(&self) -> bool {
+        !self.percent.has_value() || self.percent.value()
+    }
+
+    #[inline]
+    pub fn set_percent(&mut self, value: bool) -> &mut Self {
+        self.percent.set_value(value);
+        self
+    }
+
+    /// Get whether the icons are shown in reverse order.
+    #[inline]
+    #[must_use]
+    pub fn reverse(&self) -> bool {
+        self.reverse.value()
+    }
+
+    #[inline]
+    pub fn set_reverse(&mut self, value: bool) -> &mut Self {
+        self.reverse.set_value(value);
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn cfvo_collection(&self) -> &[ConditionalFormatValueObject] {
@@ -81,40 +152,133 @@ impl IconSet {
         self
     }
 
+    /// Get the custom icons, one per threshold.
+    #[inline]
+    #[must_use]
+    pub fn custom_icons(&self) -> &[ConditionalFormattingIcon] {
+        &self.custom_icons
+    }
+
+    #[inline]
+    pub fn set_custom_icons(
+        &mut self,
+        value: impl Into<Vec<ConditionalFormattingIcon>>,
+    ) -> &mut Self {
+        self.custom_icons = value.into();
+        self
+    }
+
+    #[inline]
+    pub fn add_custom_icon(&mut self, value: ConditionalFormattingIcon) -> &mut Self {
+        self.custom_icons.push(value);
+        self
+    }
+
+    /// Whether the set can only be written to the Excel 2010 extension.
+    #[inline]
+    pub(crate) fn is_x14_only(&self) -> bool {
+        !self.custom_icons.is_empty() || self.icon_set.value().is_x14()
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        self.set_set_attributes(e);
+
         xml_read_loop!(
             reader,
-                Event::Empty(ref e) => {
-                    match e.name().into_inner() {
-                        b"cfvo" => {
-                            let mut obj = ConditionalFormatValueObject::default();
-                            obj.set_attributes(reader, e, true);
-                            self.cfvo_collection.push(obj);
-                        }
-                        b"color" => {
-                            let mut obj = Color::default();
-                            obj.set_attributes(reader, e, true);
-                            self.color_collection.push(obj);
-                        }
-                        _ => (),
+            ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
+                let is_empty = matches!(n, Event::Empty(_));
+                match e.name().into_inner() {
+                    b"cfvo" => {
+                        let mut obj = ConditionalFormatValueObject::default();
+                        obj.set_attributes(reader, e, is_empty)?;
+                        self.cfvo_collection.push(obj);
                     }
-                },
-                Event::End(ref e) => {
-                    if e.name().into_inner() == b"iconSet" {
-                        return
+                    b"color" => {
+                        let mut obj = Color::default();
+                        obj.set_attributes(reader, e, is_empty)?;
+                        self.color_collection.push(obj);
                     }
-                },
-                Event::Eof => return
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"iconSet" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Ok(())
         );
     }
 
+    /// Read an `x14:iconSet`.
+    pub(crate) fn set_x14_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        self.set_set_attributes(e);
+
+        xml_read_loop!(
+            reader,
+            ref n @ (Event::Empty(ref e) | Event::Start(ref e)) => {
+                let is_empty = matches!(n, Event::Empty(_));
+                match e.name().into_inner() {
+                    b"x14:cfvo" => {
+                        let mut obj = ConditionalFormatValueObject::default();
+                        obj.set_x14_attributes(reader, e, is_empty)?;
+                        self.cfvo_collection.push(obj);
+                    }
+                    b"x14:cfIcon" => {
+                        let mut obj = ConditionalFormattingIcon::default();
+                        obj.set_attributes(e);
+                        self.custom_icons.push(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:iconSet" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Ok(())
+        );
+    }
+
+    fn set_set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, show_value, "showValue");
+        set_string_from_xml!(self, e, percent, "percent");
+        set_string_from_xml!(self, e, reverse, "reverse");
+    }
+
+    fn make_attributes(&self, is_x14: bool) -> crate::structs::AttrCollection<'_> {
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.icon_set.has_value() {
+            attributes.push(("iconSet", self.icon_set.value_string()).into());
+        }
+        if self.show_value.has_value() {
+            attributes.push(("showValue", self.show_value.value_string()).into());
+        }
+        if self.percent.has_value() {
+            attributes.push(("percent", self.percent.value_string()).into());
+        }
+        if self.reverse.has_value() {
+            attributes.push(("reverse", self.reverse.value_string()).into());
+        }
+        if is_x14 && !self.custom_icons.is_empty() {
+            attributes.push(("custom", "1").into());
+        }
+        attributes
+    }
+
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // dataBar
-        write_start_tag(writer, "dataBar", vec![], false);
+        // iconSet
+        write_start_tag(writer, "iconSet", self.make_attributes(false), false);
 
         // cfvo
         for v in &self.cfvo_collection {
@@ -126,6 +290,23 @@ impl IconSet {
             v.write_to_color(writer);
         }
 
-        write_end_tag(writer, "dataBar");
+        write_end_tag(writer, "iconSet");
+    }
+
+    pub(crate) fn write_x14_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:iconSet
+        write_start_tag(writer, "x14:iconSet", self.make_attributes(true), false);
+
+        // x14:cfvo
+        for v in &self.cfvo_collection {
+            v.write_x14_to(writer);
+        }
+
+        // x14:cfIcon
+        for v in &self.custom_icons {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "x14:iconSet");
     }
 }
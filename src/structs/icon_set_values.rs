@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconSetValues {
+    ThreeArrows,
+    ThreeArrowsGray,
+    ThreeFlags,
+    ThreeTrafficLights1,
+    ThreeTrafficLights2,
+    ThreeSigns,
+    ThreeSymbols,
+    ThreeSymbols2,
+    ThreeStars,
+    ThreeTriangles,
+    FourArrows,
+    FourArrowsGray,
+    FourRedToBlack,
+    FourRating,
+    FourTrafficLights,
+    FiveArrows,
+    FiveArrowsGray,
+    FiveRating,
+    FiveQuarters,
+    FiveBoxes,
+    NoIcons,
+}
+impl Default for IconSetValues {
+    #[inline]
+    fn default() -> Self {
+        Self::ThreeTrafficLights1
+    }
+}
+impl EnumTrait for IconSetValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::ThreeArrows => "3Arrows",
+            Self::ThreeArrowsGray => "3ArrowsGray",
+            Self::ThreeFlags => "3Flags",
+            Self::ThreeTrafficLights1 => "3TrafficLights1",
+            Self::ThreeTrafficLights2 => "3TrafficLights2",
+            Self::ThreeSigns => "3Signs",
+            Self::ThreeSymbols => "3Symbols",
+            Self::ThreeSymbols2 => "3Symbols2",
+            Self::ThreeStars => "3Stars",
+            Self::ThreeTriangles => "3Triangles",
+            Self::FourArrows => "4Arrows",
+            Self::FourArrowsGray => "4ArrowsGray",
+            Self::FourRedToBlack => "4RedToBlack",
+            Self::FourRating => "4Rating",
+            Self::FourTrafficLights => "4TrafficLights",
+            Self::FiveArrows => "5Arrows",
+            Self::FiveArrowsGray => "5ArrowsGray",
+            Self::FiveRating => "5Rating",
+            Self::FiveQuarters => "5Quarters",
+            Self::FiveBoxes => "5Boxes",
+            Self::NoIcons => "NoIcons",
+        }
+    }
+}
+impl FromStr for IconSetValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "3Arrows" => Ok(Self::ThreeArrows),
+            "3ArrowsGray" => Ok(Self::ThreeArrowsGray),
+            "3Flags" => Ok(Self::ThreeFlags),
+            "3TrafficLights1" => Ok(Self::ThreeTrafficLights1),
+            "3TrafficLights2" => Ok(Self::ThreeTrafficLights2),
+            "3Signs" => Ok(Self::ThreeSigns),
+            "3Symbols" => Ok(Self::ThreeSymbols),
+            "3Symbols2" => Ok(Self::ThreeSymbols2),
+            "3Stars" => Ok(Self::ThreeStars),
+            "3Triangles" => Ok(Self::ThreeTriangles),
+            "4Arrows" => Ok(Self::FourArrows),
+            "4ArrowsGray" => Ok(Self::FourArrowsGray),
+            "4RedToBlack" => Ok(Self::FourRedToBlack),
+            "4Rating" => Ok(Self::FourRating),
+            "4TrafficLights" => Ok(Self::FourTrafficLights),
+            "5Arrows" => Ok(Self::FiveArrows),
+            "5ArrowsGray" => Ok(Self::FiveArrowsGray),
+            "5Rating" => Ok(Self::FiveRating),
+            "5Quarters" => Ok(Self::FiveQuarters),
+            "5Boxes" => Ok(Self::FiveBoxes),
+            "NoIcons" => Ok(Self::NoIcons),
+            _ => Err(()),
+        }
+    }
+}
+impl IconSetValues {
+    /// Get the number of icons in the set.
+    #[must_use]
+    pub fn icon_count(&self) -> usize {
+        match self {
+            Self::NoIcons => 0,
+            Self::ThreeArrows
+            | Self::ThreeArrowsGray
+            | Self::ThreeFlags
+            | Self::ThreeTrafficLights1
+            | Self::ThreeTrafficLights2
+            | Self::ThreeSigns
+            | Self::ThreeSymbols
+            | Self::ThreeSymbols2
+            | Self::ThreeStars
+            | Self::ThreeTriangles => 3,
+            Self::FourArrows
+            | Self::FourArrowsGray
+            | Self::FourRedToBlack
+            | Self::FourRating
+            | Self::FourTrafficLights => 4,
+            Self::FiveArrows
+            | Self::FiveArrowsGray
+            | Self::FiveRating
+            | Self::FiveQuarters
+            | Self::FiveBoxes => 5,
+        }
+    }
+
+    /// Whether the set exists only in the Excel 2010 extension.
+    #[inline]
+    pub(crate) fn is_x14(&self) -> bool {
+        matches!(
+            self,
+            Self::ThreeStars | Self::ThreeTriangles | Self::FiveBoxes | Self::NoIcons
+        )
+    }
+}
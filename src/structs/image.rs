@@ -33,6 +33,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Image {
     two_cell_anchor: Option<Box<TwoCellAnchor>>,
     one_cell_anchor: Option<Box<OneCellAnchor>>,
@@ -1,4 +1,5 @@
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Int16Value {
     #[allow(dead_code)]
     value: Option<i16>,
@@ -34,7 +35,7 @@ impl Int16Value {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int16Value {
-        self.set_value(value.into().parse::<i16>().unwrap())
+        self.set_value(value.into().parse::<i16>().unwrap_or_default())
     }
 
     #[inline]
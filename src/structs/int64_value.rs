@@ -1,4 +1,5 @@
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Int64Value {
     value: Option<i64>,
 }
@@ -33,7 +34,7 @@ impl Int64Value {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int64Value {
-        self.set_value(value.into().parse::<i64>().unwrap())
+        self.set_value(value.into().parse::<i64>().unwrap_or_default())
     }
 
     #[inline]
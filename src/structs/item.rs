@@ -21,6 +21,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Item {
     index:                 UInt32Value,
     item_type:             EnumValue<ItemValues>,
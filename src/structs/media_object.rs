@@ -1,4 +1,5 @@
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaObject {
     title: Box<str>,
     name:  Box<str>,
@@ -12,8 +12,10 @@ use quick_xml::{
 
 use super::Range;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -23,6 +25,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct MergeCells {
     range: Vec<Range>,
 }
@@ -94,20 +97,20 @@ impl MergeCells {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"mergeCell" {
-                    self.add_range(get_attribute(e, b"ref").unwrap());
+                    self.add_range(required_attribute(reader, e, "ref")?);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mergeCells" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mergeCells")
+            Event::Eof => return Err(unexpected_eof(reader, "mergeCells"))
         );
     }
 
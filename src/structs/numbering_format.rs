@@ -9,11 +9,17 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::{
+        invalid_attribute,
+        parse_attribute,
+        required_attribute,
+    },
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NumberingFormat {
     number_format_id: u32,
     format_code:      Box<str>,
@@ -178,18 +184,16 @@ impl NumberingFormat {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.number_format_id = get_attribute(e, b"numFmtId")
-            .unwrap()
-            .parse::<u32>()
-            .unwrap();
-        self.format_code = escape::unescape(get_attribute(e, b"formatCode").unwrap().as_str())
-            .unwrap()
-            .to_string()
-            .into_boxed_str();
+    ) -> Result<(), XlsxError> {
+        self.number_format_id = parse_attribute(reader, e, "numFmtId")?;
+        let format_code = required_attribute(reader, e, "formatCode")?;
+        self.format_code = escape::unescape(&format_code)
+            .map_err(|_| invalid_attribute(reader, e, "formatCode", &format_code))?
+            .into();
         self.is_build_in = false;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, number_format_id: u32) {
@@ -18,7 +18,11 @@ use super::{
     Style,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -26,6 +30,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct NumberingFormats {
     numbering_format: HashMap<u32, NumberingFormat>,
 }
@@ -112,22 +117,22 @@ impl NumberingFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"numFmt" {
                     let mut obj = NumberingFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_numbering_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"numFmts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "numFmts")
+            Event::Eof => return Err(unexpected_eof(reader, "numFmts"))
         );
     }
 
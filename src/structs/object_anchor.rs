@@ -15,9 +15,11 @@ use super::{
     ToMarker,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -27,6 +29,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectAnchor {
     move_with_cells: BooleanValue,
     from_marker:     FromMarker,
@@ -141,7 +144,7 @@ impl ObjectAnchor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, move_with_cells, "moveWithCells");
 
         xml_read_loop!(
@@ -149,20 +152,20 @@ impl ObjectAnchor {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"from" | b"xdr:from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"to" | b"xdr:to" => {
-                        self.to_marker.set_attributes(reader, e);
+                        self.to_marker.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"anchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "anchor")
+            Event::Eof => return Err(unexpected_eof(reader, "anchor"))
         );
     }
 
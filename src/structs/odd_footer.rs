@@ -8,7 +8,12 @@ use quick_xml::{
 };
 
 use crate::{
-    structs::StringValue,
+    XlsxError,
+    reader::driver::invalid_xml,
+    structs::{
+        HeaderFooterSection,
+        StringValue,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -17,6 +22,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OddFooter {
     value: StringValue,
 }
@@ -41,6 +47,18 @@ impl OddFooter {
         self
     }
 
+    /// Get the value split into its left, center and right parts.
+    #[inline]
+    #[must_use]
+    pub fn section(&self) -> HeaderFooterSection {
+        HeaderFooterSection::parse(self.value())
+    }
+
+    #[inline]
+    pub fn set_section(&mut self, value: &HeaderFooterSection) -> &mut Self {
+        self.set_value(value.to_string())
+    }
+
     #[inline]
     pub(crate) fn hash_code(&self) -> String {
         crate::helper::utils::md5_hash(self.value())
@@ -61,11 +79,13 @@ impl OddFooter {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         let text = reader
-            .read_text_into(e.name(), &mut buf).unwrap();
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_value(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     #[inline]
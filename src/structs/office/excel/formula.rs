@@ -11,6 +11,8 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
     structs::Address,
     writer::driver::{
         write_end_tag,
@@ -20,6 +22,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Formula {
     value: Address,
 }
@@ -58,12 +61,15 @@ impl Formula {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         let mut obj = Address::default();
         obj.set_address(crate::helper::utils::unescape_xml_text(&text));
         self.value = obj;
+        Ok(())
     }
 
     #[inline]
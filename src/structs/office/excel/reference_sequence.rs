@@ -11,6 +11,8 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
     structs::Range,
     writer::driver::{
         write_end_tag,
@@ -20,6 +22,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferenceSequence {
     value: Vec<Range>,
 }
@@ -96,10 +99,13 @@ impl ReferenceSequence {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.set_sqref(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     #[inline]
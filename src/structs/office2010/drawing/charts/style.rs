@@ -12,12 +12,15 @@ use quick_xml::{
 
 use crate::{
     StringValue,
+    XlsxError,
     helper::const_str::{
         DRAWING_CHART_NS,
         MC_NS,
     },
     reader::driver::{
         get_attribute,
+        required_attribute,
+        unexpected_eof,
         xml_read_loop,
     },
     set_string_from_xml,
@@ -28,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     include_alternate_content: bool,
     val:                       StringValue,
@@ -78,7 +82,7 @@ impl Style {
         reader: &mut Reader<R>,
         e: &BytesStart,
         include_alternate_content: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.include_alternate_content = include_alternate_content;
 
         if include_alternate_content {
@@ -86,19 +90,20 @@ impl Style {
                 reader,
                 Event::Empty(ref e) => {
                     if e.name().into_inner() == b"c:style" {
-                        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+                        self.val.set_value_string(required_attribute(reader, e, "val")?);
                     }
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"mc:AlternateContent" {
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "mc:AlternateContent")
+                Event::Eof => return Err(unexpected_eof(reader, "mc:AlternateContent"))
             );
         } else {
             set_string_from_xml!(self, e, val, "val");
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -123,7 +128,12 @@ impl Style {
             );
 
             // c14:style
-            write_start_tag(writer, "c14:style", vec![("val", "102").into()], true);
+            let c14_val = self
+                .val
+                .value_str()
+                .parse::<u32>()
+                .map_or_else(|_| "102".to_string(), |v| (v + 100).to_string());
+            write_start_tag(writer, "c14:style", vec![("val", c14_val).into()], true);
 
             write_end_tag(writer, "mc:Choice");
 
@@ -11,7 +11,13 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    helper::formula::is_same_sheet_name,
+    reader::driver::{
+        get_attribute,
+        invalid_xml,
+        unexpected_eof,
+    },
     structs::{
         BooleanValue,
         DataValidationOperatorValues,
@@ -31,6 +37,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataValidation {
     r#type:             EnumValue<DataValidationValues>,
     operator:           EnumValue<DataValidationOperatorValues>,
@@ -75,6 +82,11 @@ impl DataValidation {
         self
     }
 
+    #[inline]
+    pub(crate) fn has_operator(&self) -> bool {
+        self.operator.has_value()
+    }
+
     #[inline]
     #[must_use]
     pub fn allow_blank(&self) -> bool {
@@ -272,12 +284,39 @@ impl DataValidation {
         self
     }
 
+    /// (This method is crate only.)
+    /// Replace the sheet name of the references to a renamed sheet.
+    pub(crate) fn rename_sheet_reference(&mut self, old_name: &str, new_name: &str) {
+        let addresses = [
+            self.formula1.as_mut().map(|v| v.value_mut().value_mut()),
+            self.formula2.as_mut().map(|v| v.value_mut().value_mut()),
+        ];
+        for address in addresses.into_iter().flatten() {
+            if is_same_sheet_name(address.sheet_name(), old_name) {
+                address.set_sheet_name(new_name);
+            }
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Whether the formulas refer to the given sheet.
+    pub(crate) fn has_sheet_reference(&self, sheet_name: &str) -> bool {
+        let addresses = [
+            self.formula1.as_ref().map(|v| v.value().value()),
+            self.formula2.as_ref().map(|v| v.value().value()),
+        ];
+        addresses
+            .into_iter()
+            .flatten()
+            .any(|v| is_same_sheet_name(v.sheet_name(), sheet_name))
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"type") {
             self.r#type.set_value_string(v);
         }
@@ -307,7 +346,7 @@ impl DataValidation {
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
@@ -316,30 +355,28 @@ impl DataValidation {
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"x14:formula1" => {
                         let mut obj = DataValidationForumla1::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula1 = Some(Box::new(obj));
                     }
                     b"x14:formula2" => {
                         let mut obj = DataValidationForumla2::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula2 = Some(Box::new(obj));
                     }
                     b"xm:sqref" => {
                         let mut obj = ReferenceSequence::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.reference_sequence = obj;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => {
                     if e.name().into_inner() == b"x14:dataValidation" {
-                        return;
+                        return Ok(());
                     }
                 }
-                Ok(Event::Eof) => {
-                    panic!("Error: Could not find {} end element", "x14:dataValidation")
-                }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(unexpected_eof(reader, "x14:dataValidation")),
+                Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
                 _ => (),
             }
             buf.clear();
@@ -14,6 +14,11 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
+    reader::driver::{
+        invalid_xml,
+        unexpected_eof,
+    },
     structs::office::excel::Formula,
     writer::driver::{
         write_end_tag,
@@ -22,6 +27,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataValidationForumla2 {
     value: Formula,
 }
@@ -60,25 +66,25 @@ impl DataValidationForumla2 {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) => {
                     if e.name().into_inner() == b"xm:f" {
                         let mut obj = Formula::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.value = obj;
-                        return;
+                        return Ok(());
                     }
                 }
                 Ok(Event::End(ref e)) => {
                     if e.name().into_inner() == b"x14:formula2" {
-                        return;
+                        return Ok(());
                     }
                 }
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "x14:formula2"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(unexpected_eof(reader, "x14:formula2")),
+                Err(e) => return Err(invalid_xml(reader.error_position(), &e)),
                 _ => (),
             }
             buf.clear();
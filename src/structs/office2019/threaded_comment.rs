@@ -14,18 +14,21 @@ use crate::{
     Coordinate,
     DateTimeValue,
     StringValue,
+    XlsxError,
     office2019::threaded_comment_text::ThreadedCommentText,
-    reader::driver::get_attribute,
+    reader::driver::{check_coordinate, get_attribute, required_attribute},
     set_string_from_xml,
     traits::AdjustmentCoordinate,
     writer::driver::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThreadedComment {
     coordinate:            Coordinate,
     d_t:                   DateTimeValue,
@@ -100,8 +103,9 @@ impl ThreadedComment {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        let coordinate = get_attribute(e, b"ref").unwrap();
+    ) -> Result<(), XlsxError> {
+        let coordinate = required_attribute(reader, e, "ref")?;
+        let coordinate = check_coordinate(reader, e, "ref", coordinate)?;
         self.coordinate_mut().set_coordinate(coordinate);
 
         set_string_from_xml!(self, e, d_t, "dT");
@@ -111,15 +115,15 @@ impl ThreadedComment {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    self.threaded_comment_text.set_attributes(reader, e);
+                    self.threaded_comment_text.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"threadedComment" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "threadedComment")
+            Event::Eof => return Err(unexpected_eof(reader, "threadedComment"))
         );
     }
 
@@ -14,10 +14,13 @@ use super::{
     StringValue,
 };
 use crate::{
+    XlsxError,
     helper::const_str::MC_NS,
     reader::driver::{
         get_attribute,
+        required_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -32,6 +35,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OleObject {
     requires:                   StringValue,
     prog_id:                    StringValue,
@@ -234,7 +238,7 @@ impl OleObject {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut alternate_content: &str = "";
 
         xml_read_loop!(
@@ -251,18 +255,18 @@ impl OleObject {
                     b"oleObject" => {
                         if alternate_content == "Choice" {
                             self.prog_id
-                                .set_value_string(get_attribute(e, b"progId").unwrap());
+                                .set_value_string(required_attribute(reader, e, "progId")?);
 
-                            let r_id = get_attribute(e, b"r:id").unwrap();
+                            let r_id = required_attribute(reader, e, "r:id")?;
                             let attached_file =
-                                relationships.relationship_by_rid(&r_id).raw_file();
+                                relationships.relationship_by_rid(&r_id)?.raw_file();
                             self.set_object_extension(attached_file.extension());
                             self.set_object_data(attached_file.file_data());
                         }
                     }
                     b"objectPr" => {
                         let mut obj = EmbeddedObjectProperties::default();
-                        obj.set_attributes(reader, e, relationships);
+                        obj.set_attributes(reader, e, relationships)?;
                         self.set_embedded_object_properties(obj);
                     }
                     _ => (),
@@ -270,10 +274,10 @@ impl OleObject {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mc:AlternateContent")
+            Event::Eof => return Err(unexpected_eof(reader, "mc:AlternateContent"))
         );
     }
 
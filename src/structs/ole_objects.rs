@@ -12,7 +12,11 @@ use quick_xml::{
 
 use super::OleObject;
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::raw::RawRelationships,
     writer::driver::{
         write_end_tag,
@@ -21,6 +25,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OleObjects {
     ole_object: Vec<OleObject>,
 }
@@ -61,22 +66,22 @@ impl OleObjects {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
                     let mut obj = OleObject::default();
-                    obj.set_attributes(reader, e, relationships);
+                    obj.set_attributes(reader, e, relationships)?;
                     self.set_ole_object(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"oleObjects" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oleObjects")
+            Event::Eof => return Err(unexpected_eof(reader, "oleObjects"))
         );
     }
 
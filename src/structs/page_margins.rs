@@ -7,12 +7,14 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::required_attribute,
     structs::DoubleValue,
     writer::driver::write_start_tag,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageMargins {
     left:   DoubleValue,
     right:  DoubleValue,
@@ -138,20 +140,21 @@ impl PageMargins {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.left
-            .set_value_string(get_attribute(e, b"left").unwrap());
+            .set_value_string(required_attribute(reader, e, "left")?);
         self.right
-            .set_value_string(get_attribute(e, b"right").unwrap());
-        self.top.set_value_string(get_attribute(e, b"top").unwrap());
+            .set_value_string(required_attribute(reader, e, "right")?);
+        self.top.set_value_string(required_attribute(reader, e, "top")?);
         self.bottom
-            .set_value_string(get_attribute(e, b"bottom").unwrap());
+            .set_value_string(required_attribute(reader, e, "bottom")?);
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .set_value_string(required_attribute(reader, e, "header")?);
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .set_value_string(required_attribute(reader, e, "footer")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -7,13 +7,16 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
     },
     structs::{
+        BooleanValue,
         EnumValue,
         OrientationValues,
+        PaperSize,
         UInt32Value,
         raw::RawRelationships,
     },
@@ -21,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageSetup {
     paper_size:     UInt32Value,
     orientation:    EnumValue<OrientationValues>,
@@ -30,6 +34,7 @@ pub struct PageSetup {
     horizontal_dpi: UInt32Value,
     vertical_dpi:   UInt32Value,
     object_data:    Option<Vec<u8>>,
+    fit_to_page:    BooleanValue,
 }
 
 impl PageSetup {
@@ -52,6 +57,33 @@ impl PageSetup {
         self
     }
 
+    /// Get the paper size as `PaperSize`.
+    /// `None` when no paper size is set or the value is not a known size.
+    #[inline]
+    #[must_use]
+    pub fn paper(&self) -> Option<PaperSize> {
+        if !self.paper_size.has_value() {
+            return None;
+        }
+        PaperSize::try_from(self.paper_size.value()).ok()
+    }
+
+    /// Set the paper size.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut book = new_file();
+    /// let page_setup = book.sheet_mut(0).unwrap().page_setup_mut();
+    /// page_setup.set_paper(PaperSize::A4);
+    /// assert_eq!(page_setup.paper_size(), 9);
+    /// ```
+    #[inline]
+    pub fn set_paper(&mut self, value: PaperSize) -> &mut Self {
+        self.paper_size.set_value(u32::from(value));
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn orientation(&self) -> &OrientationValues {
@@ -128,6 +160,40 @@ impl PageSetup {
         self
     }
 
+    /// Whether the sheet is scaled to fit the page counts of
+    /// `fit_to_width` and `fit_to_height` instead of using `scale`.
+    #[inline]
+    #[must_use]
+    pub fn fit_to_page(&self) -> bool {
+        self.fit_to_page.value()
+    }
+
+    #[inline]
+    pub fn set_fit_to_page(&mut self, value: bool) -> &mut Self {
+        self.fit_to_page.set_value(value);
+        self
+    }
+
+    /// Scale the sheet to fit a number of pages.
+    /// # Arguments
+    /// * `width` - Pages across. 0 leaves the width automatic.
+    /// * `height` - Pages down. 0 leaves the height automatic.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let page_setup = book.sheet_mut(0).unwrap().page_setup_mut();
+    /// // one page wide, as many pages tall as needed
+    /// page_setup.fit_to_pages(1, 0);
+    /// assert!(page_setup.fit_to_page());
+    /// ```
+    #[inline]
+    pub fn fit_to_pages(&mut self, width: u32, height: u32) -> &mut Self {
+        self.fit_to_width.set_value(width);
+        self.fit_to_height.set_value(height);
+        self.fit_to_page.set_value(true);
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn horizontal_dpi(&self) -> u32 {
@@ -213,7 +279,7 @@ impl PageSetup {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, paper_size, "paperSize");
         set_string_from_xml!(self, e, orientation, "orientation");
         set_string_from_xml!(self, e, scale, "scale");
@@ -223,12 +289,10 @@ impl PageSetup {
         set_string_from_xml!(self, e, vertical_dpi, "verticalDpi");
 
         if let Some(r_id) = get_attribute(e, b"r:id") {
-            let attached_file = relationships
-                .unwrap()
-                .relationship_by_rid(&r_id)
-                .raw_file();
+            let attached_file = RawRelationships::find_by_rid(relationships, &r_id)?.raw_file();
             self.set_object_data(attached_file.file_data());
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut usize) {
@@ -22,6 +22,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pane {
     horizontal_split: DoubleValue,
     vertical_split:   DoubleValue,
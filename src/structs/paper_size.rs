@@ -0,0 +1,118 @@
+macro_rules! paper_size {
+    ($($(#[$doc:meta])* $name:ident = $value:literal,)+) => {
+        /// Paper size of the `paperSize` attribute of the page setup.
+        #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum PaperSize {
+            $($(#[$doc])* $name,)+
+        }
+        impl From<PaperSize> for u32 {
+            #[inline]
+            fn from(value: PaperSize) -> Self {
+                match value {
+                    $(PaperSize::$name => $value,)+
+                }
+            }
+        }
+        impl TryFrom<u32> for PaperSize {
+            type Error = ();
+
+            #[inline]
+            fn try_from(value: u32) -> Result<Self, Self::Error> {
+                match value {
+                    $($value => Ok(Self::$name),)+
+                    _ => Err(()),
+                }
+            }
+        }
+    };
+}
+
+paper_size! {
+    /// Letter (8.5 in. by 11 in.)
+    Letter = 1,
+    /// Letter small (8.5 in. by 11 in.)
+    LetterSmall = 2,
+    /// Tabloid (11 in. by 17 in.)
+    Tabloid = 3,
+    /// Ledger (17 in. by 11 in.)
+    Ledger = 4,
+    /// Legal (8.5 in. by 14 in.)
+    Legal = 5,
+    /// Statement (5.5 in. by 8.5 in.)
+    Statement = 6,
+    /// Executive (7.25 in. by 10.5 in.)
+    Executive = 7,
+    /// A3 (297 mm by 420 mm)
+    A3 = 8,
+    /// A4 (210 mm by 297 mm)
+    A4 = 9,
+    /// A4 small (210 mm by 297 mm)
+    A4Small = 10,
+    /// A5 (148 mm by 210 mm)
+    A5 = 11,
+    /// B4 (250 mm by 353 mm)
+    B4 = 12,
+    /// B5 (176 mm by 250 mm)
+    B5 = 13,
+    /// Folio (8.5 in. by 13 in.)
+    Folio = 14,
+    /// Quarto (215 mm by 275 mm)
+    Quarto = 15,
+    /// Standard (10 in. by 14 in.)
+    Standard10x14 = 16,
+    /// Standard (11 in. by 17 in.)
+    Standard11x17 = 17,
+    /// Note (8.5 in. by 11 in.)
+    Note = 18,
+    /// #9 envelope (3.875 in. by 8.875 in.)
+    Envelope9 = 19,
+    /// #10 envelope (4.125 in. by 9.5 in.)
+    Envelope10 = 20,
+    /// #11 envelope (4.5 in. by 10.375 in.)
+    Envelope11 = 21,
+    /// #12 envelope (4.75 in. by 11 in.)
+    Envelope12 = 22,
+    /// #14 envelope (5 in. by 11.5 in.)
+    Envelope14 = 23,
+    /// C paper (17 in. by 22 in.)
+    CSheet = 24,
+    /// D paper (22 in. by 34 in.)
+    DSheet = 25,
+    /// E paper (34 in. by 44 in.)
+    ESheet = 26,
+    /// DL envelope (110 mm by 220 mm)
+    EnvelopeDl = 27,
+    /// C5 envelope (162 mm by 229 mm)
+    EnvelopeC5 = 28,
+    /// C3 envelope (324 mm by 458 mm)
+    EnvelopeC3 = 29,
+    /// C4 envelope (229 mm by 324 mm)
+    EnvelopeC4 = 30,
+    /// C6 envelope (114 mm by 162 mm)
+    EnvelopeC6 = 31,
+    /// C65 envelope (114 mm by 229 mm)
+    EnvelopeC65 = 32,
+    /// B4 envelope (250 mm by 353 mm)
+    EnvelopeB4 = 33,
+    /// B5 envelope (176 mm by 250 mm)
+    EnvelopeB5 = 34,
+    /// B6 envelope (176 mm by 125 mm)
+    EnvelopeB6 = 35,
+    /// Italy envelope (110 mm by 230 mm)
+    EnvelopeItaly = 36,
+    /// Monarch envelope (3.875 in. by 7.5 in.)
+    EnvelopeMonarch = 37,
+    /// 6 3/4 envelope (3.625 in. by 6.5 in.)
+    EnvelopePersonal = 38,
+    /// US standard fanfold (14.875 in. by 11 in.)
+    FanfoldUs = 39,
+    /// German standard fanfold (8.5 in. by 12 in.)
+    FanfoldStdGerman = 40,
+    /// German legal fanfold (8.5 in. by 13 in.)
+    FanfoldLegalGerman = 41,
+    /// A2 (420 mm by 594 mm)
+    A2 = 66,
+    /// A6 (105 mm by 148 mm)
+    A6 = 70,
+}
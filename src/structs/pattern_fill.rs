@@ -16,9 +16,11 @@ use super::{
     PatternValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -28,6 +30,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PatternFill {
     pub(crate) pattern_type: EnumValue<PatternValues>,
     foreground_color:        Option<Box<Color>>,
@@ -178,11 +181,11 @@ impl PatternFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, pattern_type, "patternType");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -191,12 +194,12 @@ impl PatternFill {
                 match e.name().into_inner() {
                     b"fgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_foreground_color(obj);
                     }
                     b"bgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_background_color(obj);
                     }
                     _ => (),
@@ -204,10 +207,10 @@ impl PatternFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"patternFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "patternFill")
+            Event::Eof => return Err(unexpected_eof(reader, "patternFill"))
         );
     }
 
@@ -1,4 +1,4 @@
-// si
+// rPh
 use std::io::Cursor;
 
 use quick_xml::{
@@ -10,25 +10,122 @@ use quick_xml::{
     },
 };
 
-use crate::reader::driver::xml_read_loop;
+use super::{
+    Text,
+    UInt32Value,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
 
-#[derive(Default, Debug)]
-pub(crate) struct PhoneticRun {}
+/// Phonetic reading (furigana) of a part of the text.
+#[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhoneticRun {
+    text:       Text,
+    base_start: UInt32Value,
+    base_end:   UInt32Value,
+}
 
 impl PhoneticRun {
-    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) {
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        self.text.value()
+    }
+
+    #[inline]
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.text.set_value(value);
+        self
+    }
+
+    /// Index of the first character of the base text.
+    #[inline]
+    #[must_use]
+    pub fn base_start(&self) -> u32 {
+        self.base_start.value()
+    }
+
+    #[inline]
+    pub fn set_base_start(&mut self, value: u32) -> &mut Self {
+        self.base_start.set_value(value);
+        self
+    }
+
+    /// Index next to the last character of the base text.
+    #[inline]
+    #[must_use]
+    pub fn base_end(&self) -> u32 {
+        self.base_end.value()
+    }
+
+    #[inline]
+    pub fn set_base_end(&mut self, value: u32) -> &mut Self {
+        self.base_end.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn hash_code(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.base_start.value(),
+            self.base_end.value(),
+            self.text.hash_code()
+        )
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, base_start, "sb");
+        set_string_from_xml!(self, e, base_end, "eb");
+
         xml_read_loop!(
             reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"t" {
+                    let mut obj = Text::default();
+                    obj.set_attributes(reader, e)?;
+                    self.text = obj;
+                }
+            },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rPh" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rPh")
+            Event::Eof => return Err(unexpected_eof(reader, "rPh"))
         );
     }
 
-    pub(crate) fn write_to(_writer: &mut Writer<Cursor<Vec<u8>>>) {
-        unimplemented!();
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // rPh
+        let base_start = self.base_start.value_string();
+        let base_end = self.base_end.value_string();
+        write_start_tag(
+            writer,
+            "rPh",
+            vec![("sb", &base_start).into(), ("eb", &base_end).into()],
+            false,
+        );
+
+        // t
+        self.text.write_to(writer);
+
+        write_end_tag(writer, "rPh");
     }
 }
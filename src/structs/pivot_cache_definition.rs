@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         MC_NS,
         REL_OFC_NS,
@@ -20,6 +21,7 @@ use crate::{
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -37,6 +39,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PivotCacheDefinition {
     id:                      StringValue,
     refreshed_by:            StringValue,
@@ -280,7 +283,7 @@ impl PivotCacheDefinition {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, id, "r:id");
         set_string_from_xml!(self, e, refreshed_by, "refreshedBy");
         set_string_from_xml!(self, e, refreshed_date, "refreshedDate");
@@ -295,21 +298,21 @@ impl PivotCacheDefinition {
                 let is_empty = matches!(n, Event::Empty(_));
                 if e.name().into_inner() == b"cacheSource" {
                     let mut obj = CacheSource::default();
-                    obj.set_attributes(reader, e, is_empty);
+                    obj.set_attributes(reader, e, is_empty)?;
                     self.set_cache_source(obj);
                 }
                 if e.name().into_inner() == b"cacheFields" {
                     let mut obj = CacheFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_cache_fields(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotCacheDefinition" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotCacheDefinition")
+            Event::Eof => return Err(unexpected_eof(reader, "pivotCacheDefinition"))
         );
     }
 
@@ -8,9 +8,11 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     EnumValue, PivotTableAxisValues, reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
     }, structs::{
         BooleanValue,
         Items,
@@ -18,6 +20,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PivotField {
     data_field: BooleanValue,
     show_all:   BooleanValue,
@@ -99,28 +102,28 @@ impl PivotField {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, data_field, "dataField");
         set_string_from_xml!(self, e, show_all, "showAll");
         set_string_from_xml!(self, e, axis, "axis");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"items" {
-                    self.items.set_attributes(reader, e);
+                    self.items.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotField" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotField")
+            Event::Eof => return Err(unexpected_eof(reader, "pivotField"))
         );
     }
 
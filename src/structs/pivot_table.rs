@@ -4,6 +4,7 @@ use crate::structs::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PivotTable {
     pivot_table_definition: PivotTableDefinition,
     pivot_cache_definition: PivotCacheDefinition,
@@ -20,6 +20,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PivotTableStyle {
     name:                StringValue,
     show_row_headers:    BooleanValue,
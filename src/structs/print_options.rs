@@ -16,6 +16,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrintOptions {
     horizontal_centered: BooleanValue,
     vertical_centered:   BooleanValue,
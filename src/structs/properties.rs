@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         COREPROPS_NS,
         DCMITYPE_NS,
@@ -19,7 +20,10 @@ use crate::{
         XPROPS_NS,
         XSI_NS,
     },
-    reader::driver::xml_read_loop,
+    reader::driver::{
+        invalid_xml,
+        xml_read_loop,
+    },
     structs::{
         StringValue,
         Worksheet,
@@ -33,6 +37,7 @@ use crate::{
 };
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties {
     creator:           StringValue,
     last_modified_by:  StringValue,
@@ -356,78 +361,117 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"dc:title" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_title(text);
                 },
                 b"dc:subject" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_subject(text);
                 },
                 b"dc:creator" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_creator(text);
                 },
                 b"cp:keywords" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_keywords(text);
                 },
                 b"dc:description" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_description(text);
                 },
                 b"cp:lastModifiedBy" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_last_modified_by(text);
                 },
                 b"cp:revision" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_revision(text);
                 },
                 b"dcterms:created" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_created(text);
                 },
                 b"dcterms:modified" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_modified(text);
                 },
                 b"cp:category" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_category(text);
                 },
                 b"cp:version" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_version(text);
                 },
                 b"Manager" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_manager(text);
                 },
                 b"Company" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_company(text);
                 },
                 _ => {}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -435,23 +479,29 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"Manager" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_manager(text);
                 }
                 b"Company" => {
                     let mut buf = Vec::new();
-                    let text = crate::helper::utils::unescape_xml_text(&reader.read_text_into(e.name(), &mut buf).unwrap());
+                    let text = reader
+                        .read_text_into(e.name(), &mut buf)
+                        .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    let text = crate::helper::utils::unescape_xml_text(&text);
                     self.set_company(text);
                 }
                 _ =>{}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -460,10 +510,11 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut obj = CustomProperties::default();
-        obj.set_attributes(reader, e);
+        obj.set_attributes(reader, e)?;
         self.set_custom_properties(obj);
+        Ok(())
     }
 
     pub(crate) fn write_to_core(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -17,6 +17,7 @@ use crate::{
 };
 
 #[derive(Default, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Protection {
     locked: BooleanValue,
     hidden: BooleanValue,
@@ -57,6 +58,19 @@ impl Protection {
         self.hidden.set_value(value);
     }
 
+    /// Locked state, `true` when it is not set as in Excel.
+    /// (This method is crate only.)
+    #[inline]
+    pub(crate) fn is_locked(&self) -> bool {
+        !self.locked.has_value() || self.locked.value()
+    }
+
+    /// (This method is crate only.)
+    #[inline]
+    pub(crate) fn is_hidden(&self) -> bool {
+        self.hidden.value()
+    }
+
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn hash_code(&self) -> String {
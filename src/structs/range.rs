@@ -11,6 +11,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Range {
     start_col: Option<ColumnReference>,
     start_row: Option<RowReference>,
@@ -220,6 +221,75 @@ impl Range {
     pub(crate) fn get_coordinate_end(&self) -> String {
         self.coordinate_end()
     }
+
+    /// Make every coordinate of the range absolute.
+    pub(crate) fn lock_all(&mut self) -> &mut Self {
+        for col in [&mut self.start_col, &mut self.end_col].into_iter().flatten() {
+            col.set_is_lock(true);
+        }
+        for row in [&mut self.start_row, &mut self.end_row].into_iter().flatten() {
+            row.set_is_lock(true);
+        }
+        self
+    }
+
+    /// Shrink the range by removed columns and rows.
+    /// Unlike `adjustment_remove_coordinate`, a range that overlaps the removed
+    /// columns or rows keeps its remaining cells.
+    /// # Return value
+    /// * `bool` - `false` when every cell of the range is removed.
+    pub(crate) fn shrink_for_remove(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) -> bool {
+        let cols = self.start_col.as_ref().map(|start| {
+            let end = self.end_col.as_ref().map_or(start.num(), ColumnReference::num);
+            shrink_span(start.num(), end, root_col_num, offset_col_num)
+        });
+        let rows = self.start_row.as_ref().map(|start| {
+            let end = self.end_row.as_ref().map_or(start.num(), RowReference::num);
+            shrink_span(start.num(), end, root_row_num, offset_row_num)
+        });
+        if cols == Some(None) || rows == Some(None) {
+            return false;
+        }
+
+        if let Some(Some((start, end))) = cols {
+            if let Some(v) = &mut self.start_col {
+                v.set_num(start);
+            }
+            if let Some(v) = &mut self.end_col {
+                v.set_num(end);
+            }
+        }
+        if let Some(Some((start, end))) = rows {
+            if let Some(v) = &mut self.start_row {
+                v.set_num(start);
+            }
+            if let Some(v) = &mut self.end_row {
+                v.set_num(end);
+            }
+        }
+        true
+    }
+}
+
+/// Remove `offset_num` indices starting at `root_num` from the span
+/// `start..=end`. Returns `None` when nothing of the span is left.
+fn shrink_span(start: u32, end: u32, root_num: u32, offset_num: u32) -> Option<(u32, u32)> {
+    if root_num == 0 || offset_num == 0 || end < root_num {
+        return Some((start, end));
+    }
+    let last = root_num + offset_num - 1;
+    if start > last {
+        return Some((start - offset_num, end - offset_num));
+    }
+    let start = start.min(root_num);
+    let end = if end > last { end - offset_num } else { root_num - 1 };
+    (end >= start).then_some((start, end))
 }
 impl AdjustmentCoordinate for Range {
     #[inline]
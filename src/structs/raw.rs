@@ -11,3 +11,6 @@ pub(crate) use self::raw_relationships::*;
 
 mod raw_relationship;
 pub(crate) use self::raw_relationship::*;
+
+mod raw_passthrough;
+pub(crate) use self::raw_passthrough::*;
@@ -21,11 +21,37 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub(crate) struct RawFile {
     file_target: StringValue,
     file_data:   Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     source_file: Option<PathBuf>,
 }
+
+/// The data that is still in the source file is read, so that the
+/// deserialized file does not depend on it.
+#[cfg(feature = "serde")]
+impl serde::Serialize for RawFile {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{
+            Error,
+            SerializeStruct,
+        };
+
+        let mut raw_file = std::borrow::Cow::Borrowed(self);
+        if !self.has_file_data() && self.source_file.is_some() {
+            raw_file
+                .to_mut()
+                .load_file_data_from_source()
+                .map_err(S::Error::custom)?;
+        }
+        let mut state = serializer.serialize_struct("RawFile", 2)?;
+        state.serialize_field("file_target", &raw_file.file_target)?;
+        state.serialize_field("file_data", &raw_file.file_data)?;
+        state.end()
+    }
+}
 impl RawFile {
     #[inline]
     pub(crate) fn file_name(&self) -> &str {
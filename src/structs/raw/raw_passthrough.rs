@@ -0,0 +1,774 @@
+use std::{
+    io,
+    io::Cursor,
+    path::Path,
+};
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use crate::{
+    reader::driver::invalid_xml,
+    structs::{
+        WriterManager,
+        XlsxError,
+        raw::{
+            RawFile,
+            RawRelationship,
+            RawRelationships,
+        },
+    },
+    writer::driver::write_start_tag,
+};
+
+/// Elements of the workbook part in schema order.
+pub(crate) const WORKBOOK_ORDER: &[&str] = &[
+    "fileVersion",
+    "fileSharing",
+    "workbookPr",
+    "workbookProtection",
+    "bookViews",
+    "sheets",
+    "functionGroups",
+    "externalReferences",
+    "definedNames",
+    "calcPr",
+    "oleSize",
+    "customWorkbookViews",
+    "pivotCaches",
+    "smartTagPr",
+    "smartTagTypes",
+    "webPublishing",
+    "fileRecoveryPr",
+    "webPublishObjects",
+    "extLst",
+];
+
+/// Elements of the worksheet part in schema order.
+pub(crate) const WORKSHEET_ORDER: &[&str] = &[
+    "sheetPr",
+    "dimension",
+    "sheetViews",
+    "sheetFormatPr",
+    "cols",
+    "sheetData",
+    "sheetCalcPr",
+    "sheetProtection",
+    "protectedRanges",
+    "scenarios",
+    "autoFilter",
+    "sortState",
+    "dataConsolidate",
+    "customSheetViews",
+    "mergeCells",
+    "phoneticPr",
+    "conditionalFormatting",
+    "dataValidations",
+    "hyperlinks",
+    "printOptions",
+    "pageMargins",
+    "pageSetup",
+    "headerFooter",
+    "rowBreaks",
+    "colBreaks",
+    "customProperties",
+    "cellWatches",
+    "ignoredErrors",
+    "smartTags",
+    "drawing",
+    "legacyDrawing",
+    "legacyDrawingHF",
+    "drawingHF",
+    "picture",
+    "oleObjects",
+    "controls",
+    "webPublishItems",
+    "tableParts",
+    "extLst",
+];
+
+/// Elements of the styles part in schema order.
+pub(crate) const STYLESHEET_ORDER: &[&str] = &[
+    "numFmts",
+    "fonts",
+    "fills",
+    "borders",
+    "cellStyleXfs",
+    "cellXfs",
+    "cellStyles",
+    "dxfs",
+    "tableStyles",
+    "colors",
+    "extLst",
+];
+
+/// Attributes that refer to a style. The writer numbers the styles again, so
+/// a kept value could refer to another style.
+const STYLE_ATTRIBUTES: &[&str] = &[
+    "s", "xfId", "numFmtId", "fontId", "fillId", "borderId", "dxfId",
+];
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Name and occurrence of an element among the children of its parent.
+type ElementKey = (String, usize);
+
+/// Content of a written element that the crate does not write.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum NestedContent {
+    /// An attribute with its value as read.
+    Attribute(String, String),
+    /// An element, the element it follows and its XML.
+    Element(ElementKey, Option<ElementKey>, String),
+}
+
+/// Relationships, package parts and XML elements of a part that the crate
+/// does not model. They are kept as read and written back unchanged.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct RawPassthrough {
+    relationship_list:  Vec<RawRelationship>,
+    relationships_list: Vec<RawRelationships>,
+    namespace_list:     Vec<(String, String)>,
+    ignorable_list:     Vec<String>,
+    element_list:       Vec<(String, String)>,
+    extension_list:     Vec<String>,
+    nested_list:        Vec<(Vec<ElementKey>, NestedContent)>,
+}
+
+impl RawPassthrough {
+    #[inline]
+    pub(crate) fn add_relationship_list(&mut self, value: RawRelationship) -> &mut Self {
+        self.relationship_list.push(value);
+        self
+    }
+
+    /// Relationships of the kept parts, read recursively.
+    #[inline]
+    pub(crate) fn add_relationships_list(&mut self, value: RawRelationships) -> &mut Self {
+        self.relationships_list.push(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn has_relationships(&self, file_target: &str) -> bool {
+        self.relationships_list
+            .iter()
+            .any(|v| v.file_target() == file_target)
+    }
+
+    /// Whether a kept element refers to the relationship.
+    pub(crate) fn is_referenced(&self, r_id: &str) -> bool {
+        let double_quoted = format!("\"{r_id}\"");
+        let single_quoted = format!("'{r_id}'");
+        self.element_list
+            .iter()
+            .map(|(_, xml)| xml)
+            .chain(&self.extension_list)
+            .chain(self.nested_list.iter().map(|(_, v)| match v {
+                NestedContent::Attribute(_, xml) | NestedContent::Element(_, _, xml) => xml,
+            }))
+            .any(|xml| xml.contains(&double_quoted) || xml.contains(&single_quoted))
+    }
+
+    /// Read the relationships of a kept part and the parts they target.
+    pub(crate) fn read_part_relationships<R: io::Read + io::Seek>(
+        &mut self,
+        arv: &mut zip::read::ZipArchive<R>,
+        raw_file: &RawFile,
+        source_file: Option<&Path>,
+    ) -> Result<(), XlsxError> {
+        let base_path = raw_file.path();
+        let target = raw_file.make_rel_name();
+        let mut obj = RawRelationships::default();
+        if !obj.set_attributes(arv, &base_path, &target, source_file)? {
+            return Ok(());
+        }
+        if self.has_relationships(obj.file_target()) {
+            return Ok(());
+        }
+        let raw_files: Vec<RawFile> = obj
+            .relationship_list()
+            .iter()
+            .filter(|v| v.target_mode() != "External")
+            .map(|v| v.raw_file().clone())
+            .collect();
+        self.add_relationships_list(obj);
+        for raw_file in &raw_files {
+            self.read_part_relationships(arv, raw_file, source_file)?;
+        }
+        Ok(())
+    }
+
+    /// Keep the top level elements of a part that are not in `known`.
+    /// # Arguments
+    /// * `data` - the part.
+    /// * `order` - the elements of the part in schema order.
+    /// * `known` - the elements the crate reads and writes.
+    /// * `known_extensions` - the elements of the `ext` that the crate reads
+    ///   and writes. Only used when `extLst` is in `known`.
+    pub(crate) fn read_elements(
+        &mut self,
+        data: &[u8],
+        order: &[&str],
+        known: &[&str],
+        known_extensions: &[&str],
+    ) -> Result<(), XlsxError> {
+        let data = data.strip_prefix(UTF8_BOM).unwrap_or(data);
+        let mut reader = Reader::from_reader(data);
+        let mut depth = 0;
+        let mut anchor = String::new();
+        loop {
+            let start = to_usize(reader.buffer_position());
+            let event = reader
+                .read_event()
+                .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+            let (e, is_empty) = match event {
+                Event::Start(e) => (e, false),
+                Event::Empty(e) => (e, true),
+                Event::End(_) => {
+                    depth -= 1;
+                    continue;
+                }
+                Event::Eof => break,
+                _ => continue,
+            };
+            if depth == 0 {
+                self.read_namespaces(&e);
+                if !is_empty {
+                    depth += 1;
+                }
+                continue;
+            }
+            let name = local_name(&e);
+            if depth == 1 && name == "extLst" && known.contains(&"extLst") {
+                // the ext elements are kept one by one
+                if !is_empty {
+                    depth += 1;
+                }
+                anchor = name;
+                continue;
+            }
+            if !is_empty {
+                reader
+                    .read_to_end(e.name())
+                    .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+            }
+            let xml = &data[start..to_usize(reader.buffer_position())];
+            if depth == 2 {
+                if !contains_any(xml, known_extensions) {
+                    self.extension_list
+                        .push(String::from_utf8_lossy(xml).to_string());
+                }
+                continue;
+            }
+            let is_known = known.contains(&name.as_str())
+                || (!order.contains(&name.as_str()) && contains_any(xml, known));
+            if !is_known {
+                self.element_list
+                    .push((anchor.clone(), String::from_utf8_lossy(xml).to_string()));
+            }
+            if let Some(v) = order_name(order, &name, xml) {
+                anchor = v;
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep the attributes and elements in the top level elements of a part
+    /// that the crate does not write. Each is kept with the path of its
+    /// element and the element it follows.
+    /// # Arguments
+    /// * `data` - the part.
+    /// * `written` - the part as the crate writes it right after reading.
+    /// * `skip` - the top level elements whose content the crate writes from
+    ///   its own model, so that kept content could contradict it.
+    pub(crate) fn read_nested(
+        &mut self,
+        data: &[u8],
+        written: &[u8],
+        skip: &[&str],
+    ) -> Result<(), XlsxError> {
+        let data = data.strip_prefix(UTF8_BOM).unwrap_or(data);
+        // the ext elements are kept by `read_elements`
+        let descend = |name: &str| name != "extLst" && !skip.contains(&name);
+        let (Some(original), Some(written)) = (
+            XmlNode::parse(data, descend)?,
+            XmlNode::parse(written, descend)?,
+        ) else {
+            return Ok(());
+        };
+        for child in &original.children {
+            if !descend(&child.key.0) {
+                continue;
+            }
+            if let Some(v) = written.child(&child.key) {
+                self.read_nested_element(data, &mut vec![child.key.clone()], child, v);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_nested_element(
+        &mut self,
+        data: &[u8],
+        path: &mut Vec<ElementKey>,
+        original: &XmlNode,
+        written: &XmlNode,
+    ) {
+        for (key, value) in &original.attributes {
+            if written.attribute(key).is_none()
+                && !key.starts_with("xmlns")
+                && !STYLE_ATTRIBUTES.contains(&key.as_str())
+            {
+                self.nested_list.push((
+                    path.clone(),
+                    NestedContent::Attribute(key.clone(), value.clone()),
+                ));
+            }
+        }
+        let mut anchor = None;
+        for child in &original.children {
+            if let Some(v) = written.child(&child.key) {
+                path.push(child.key.clone());
+                self.read_nested_element(data, path, child, v);
+                path.pop();
+                anchor = Some(child.key.clone());
+            } else {
+                let xml = String::from_utf8_lossy(&data[child.start..child.end]).to_string();
+                self.nested_list.push((
+                    path.clone(),
+                    NestedContent::Element(child.key.clone(), anchor.clone(), xml),
+                ));
+            }
+        }
+    }
+
+    fn read_namespaces(&mut self, e: &BytesStart<'_>) {
+        for attr in e.attributes().with_checks(false).flatten() {
+            let key = String::from_utf8_lossy(attr.key.into_inner()).to_string();
+            let value = String::from_utf8_lossy(&attr.value).to_string();
+            if let Some(prefix) = key.strip_prefix("xmlns:") {
+                self.namespace_list.push((prefix.to_string(), value));
+            } else if key == "mc:Ignorable" {
+                self.ignorable_list = value.split_whitespace().map(ToOwned::to_owned).collect();
+            }
+        }
+    }
+
+    /// Write the kept relationships.
+    /// Returns `true` when one has been written.
+    pub(crate) fn write_relationships(&self, writer: &mut Writer<Cursor<Vec<u8>>>) -> bool {
+        for relationship in &self.relationship_list {
+            let r_id = preserved_r_id(relationship.id());
+            let mut attributes: crate::structs::AttrCollection = Vec::new();
+            attributes.push(("Id", &r_id).into());
+            attributes.push(("Type", relationship.get_type()).into());
+            attributes.push(("Target", relationship.target()).into());
+            if !relationship.target_mode().is_empty() {
+                attributes.push(("TargetMode", relationship.target_mode()).into());
+            }
+            write_start_tag(writer, "Relationship", attributes, true);
+        }
+        !self.relationship_list.is_empty()
+    }
+
+    /// Write the kept parts and their relationships.
+    /// Parts that have already been written are skipped.
+    pub(crate) fn write_parts<W: io::Seek + io::Write>(
+        &self,
+        writer_mng: &mut WriterManager<W>,
+    ) -> Result<(), XlsxError> {
+        for relationship in &self.relationship_list {
+            if relationship.target_mode() != "External" {
+                relationship.write_to_bin(writer_mng)?;
+            }
+        }
+        for relationships in &self.relationships_list {
+            relationships.write_to(writer_mng, None)?;
+        }
+        Ok(())
+    }
+
+    /// Put the kept elements back into a written part.
+    /// Each element follows the elements of the part that come before it in
+    /// `order`, and the `ext` elements are added to the `extLst`.
+    pub(crate) fn write_elements(
+        &self,
+        writer: Writer<Cursor<Vec<u8>>>,
+        order: &[&str],
+    ) -> Result<Writer<Cursor<Vec<u8>>>, XlsxError> {
+        if self.element_list.is_empty()
+            && self.extension_list.is_empty()
+            && self.nested_list.is_empty()
+        {
+            return Ok(writer);
+        }
+        let data = self.write_nested(writer.into_inner().into_inner())?;
+        let mut reader = Reader::from_reader(data.as_slice());
+        let mut depth = 0;
+        let mut root: Option<(usize, usize, Vec<u8>)> = None;
+        let mut children: Vec<(Option<usize>, usize)> = Vec::new();
+        let mut ext_lst_end: Option<usize> = None;
+        loop {
+            let start = to_usize(reader.buffer_position());
+            let event = reader
+                .read_event()
+                .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+            match event {
+                Event::Start(ref e) | Event::Empty(ref e) if depth == 0 => {
+                    let end = to_usize(reader.buffer_position());
+                    root = Some((start, end, self.make_root_tag(e)?));
+                    depth += 1;
+                }
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let name = local_name(e);
+                    if let Event::Start(_) = event {
+                        reader
+                            .read_to_end(e.name())
+                            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                    }
+                    let end = to_usize(reader.buffer_position());
+                    if name == "extLst" {
+                        // position of </extLst>
+                        ext_lst_end = data[..end].iter().rposition(|v| *v == b'<');
+                    }
+                    let index = order_name(order, &name, &data[start..end])
+                        .and_then(|v| order.iter().position(|o| *o == v))
+                        .or_else(|| children.last().and_then(|v| v.0));
+                    children.push((index, end));
+                }
+                Event::End(_) | Event::Eof => break,
+                _ => (),
+            }
+        }
+        let Some((root_start, root_end, root_tag)) = root else {
+            return Ok(Writer::new(Cursor::new(data)));
+        };
+
+        // after the last written element that comes before the anchor
+        let position_after = |anchor: &str| {
+            let anchor = order.iter().position(|v| *v == anchor);
+            children
+                .iter()
+                .take_while(|(index, _)| anchor.is_some() && *index <= anchor)
+                .last()
+                .map_or(root_end, |v| v.1)
+        };
+        let mut insertions: Vec<(usize, String)> = Vec::new();
+        for (anchor, xml) in &self.element_list {
+            insertions.push((position_after(anchor), self.remap_r_id(xml)));
+        }
+        if !self.extension_list.is_empty() {
+            let extensions: String = self
+                .extension_list
+                .iter()
+                .map(|v| self.remap_r_id(v))
+                .collect();
+            match ext_lst_end {
+                Some(position) => insertions.push((position, extensions)),
+                None => insertions.push((
+                    position_after("extLst"),
+                    format!("<extLst>{extensions}</extLst>"),
+                )),
+            }
+        }
+        insertions.sort_by_key(|v| v.0);
+
+        let mut result = Vec::with_capacity(data.len());
+        result.extend_from_slice(&data[..root_start]);
+        result.extend_from_slice(&root_tag);
+        let mut position = root_end;
+        for (insert_position, xml) in insertions {
+            result.extend_from_slice(&data[position..insert_position]);
+            result.extend_from_slice(xml.as_bytes());
+            position = insert_position;
+        }
+        result.extend_from_slice(&data[position..]);
+        Ok(Writer::new(Cursor::new(result)))
+    }
+
+    /// Put the kept nested content back into the written elements.
+    /// Content of an element that is not written any more is dropped, and an
+    /// element is not added when the crate writes one in its place.
+    fn write_nested(&self, data: Vec<u8>) -> Result<Vec<u8>, XlsxError> {
+        if self.nested_list.is_empty() {
+            return Ok(data);
+        }
+        let descend = |name: &str| self.nested_list.iter().any(|(path, _)| path[0].0 == name);
+        let Some(root) = XmlNode::parse(&data, descend)? else {
+            return Ok(data);
+        };
+        // (position, order at the position, end of the replaced bytes, text)
+        let mut edits: Vec<(usize, u8, usize, String)> = Vec::new();
+        let mut opened: Vec<&XmlNode> = Vec::new();
+        for (path, content) in &self.nested_list {
+            let Some(node) = root.find(path) else {
+                continue;
+            };
+            match content {
+                NestedContent::Attribute(key, value) => {
+                    if node.attribute(key).is_some() {
+                        continue;
+                    }
+                    let quote = if value.contains('"') { '\'' } else { '"' };
+                    let value = match self.relationship_list.iter().find(|v| v.id() == value) {
+                        Some(v) => preserved_r_id(v.id()),
+                        None => value.clone(),
+                    };
+                    let position = node.start_tag_end - if node.is_empty { 2 } else { 1 };
+                    edits.push((
+                        position,
+                        0,
+                        position,
+                        format!(" {key}={quote}{value}{quote}"),
+                    ));
+                }
+                NestedContent::Element(key, anchor, xml) => {
+                    if node.child(key).is_some() {
+                        continue;
+                    }
+                    let position = anchor
+                        .as_ref()
+                        .and_then(|v| node.child(v))
+                        .map_or(node.start_tag_end, |v| v.end);
+                    if node.is_empty && !opened.iter().any(|v| v.start == node.start) {
+                        // <name/> becomes <name>...</name>
+                        let position = node.start_tag_end - 2;
+                        edits.push((position, 1, node.start_tag_end, ">".into()));
+                        opened.push(node);
+                    }
+                    edits.push((position, 2, position, self.remap_r_id(xml)));
+                }
+            }
+        }
+        for node in opened {
+            let position = node.start_tag_end;
+            edits.push((position, 3, position, format!("</{}>", node.name)));
+        }
+        edits.sort_by_key(|v| (v.0, v.1));
+
+        let mut result = Vec::with_capacity(data.len());
+        let mut position = 0;
+        for (start, _, end, text) in edits {
+            result.extend_from_slice(&data[position..start]);
+            result.extend_from_slice(text.as_bytes());
+            position = end;
+        }
+        result.extend_from_slice(&data[position..]);
+        Ok(result)
+    }
+
+    /// The root start tag with the namespaces the kept elements use.
+    fn make_root_tag(&self, e: &BytesStart<'_>) -> Result<Vec<u8>, XlsxError> {
+        let mut ignorable: Vec<String> = Vec::new();
+        let mut tag = BytesStart::new(String::from_utf8_lossy(e.name().into_inner()));
+        for attr in e.attributes().with_checks(false).flatten() {
+            if attr.key.into_inner() == b"mc:Ignorable" {
+                let value = String::from_utf8_lossy(&attr.value).to_string();
+                ignorable.extend(value.split_whitespace().map(ToOwned::to_owned));
+            } else {
+                tag.push_attribute(attr);
+            }
+        }
+        for (prefix, uri) in &self.namespace_list {
+            let key = format!("xmlns:{prefix}");
+            if e.try_get_attribute(key.as_str()).ok().flatten().is_none() {
+                tag.push_attribute((key.as_str(), uri.as_str()));
+            }
+        }
+        for prefix in &self.ignorable_list {
+            if !ignorable.contains(prefix) {
+                ignorable.push(prefix.clone());
+            }
+        }
+        if !ignorable.is_empty() {
+            tag.push_attribute(("mc:Ignorable", ignorable.join(" ").as_str()));
+        }
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        writer.write_event(Event::Start(tag))?;
+        Ok(writer.into_inner().into_inner())
+    }
+
+    /// Kept elements refer to the kept relationships by their new id.
+    fn remap_r_id(&self, xml: &str) -> String {
+        let mut result = xml.to_string();
+        for relationship in &self.relationship_list {
+            let r_id = preserved_r_id(relationship.id());
+            if r_id != relationship.id() {
+                for quote in ['"', '\''] {
+                    result = result.replace(
+                        &format!("{quote}{}{quote}", relationship.id()),
+                        &format!("{quote}{r_id}{quote}"),
+                    );
+                }
+            }
+        }
+        result
+    }
+}
+
+/// An element of a part with the positions of its tags.
+struct XmlNode {
+    name:          String,
+    key:           ElementKey,
+    start:         usize,
+    start_tag_end: usize,
+    end:           usize,
+    is_empty:      bool,
+    attributes:    Vec<(String, String)>,
+    children:      Vec<XmlNode>,
+}
+
+impl XmlNode {
+    /// Read the root element of a part.
+    /// The content of a top level element is read only when `descend` is
+    /// `true` for its name.
+    fn parse<F: Fn(&str) -> bool>(data: &[u8], descend: F) -> Result<Option<Self>, XlsxError> {
+        let mut reader = Reader::from_reader(data);
+        let mut stack: Vec<Self> = Vec::new();
+        loop {
+            let start = to_usize(reader.buffer_position());
+            let event = reader
+                .read_event()
+                .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+            let end = to_usize(reader.buffer_position());
+            let node = match event {
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let name = local_name(e);
+                    let occurrence = stack
+                        .last()
+                        .map_or(0, |v| v.children.iter().filter(|c| c.key.0 == name).count());
+                    let node = Self {
+                        name: String::from_utf8_lossy(e.name().into_inner()).to_string(),
+                        key: (name, occurrence),
+                        start,
+                        start_tag_end: end,
+                        end,
+                        is_empty: matches!(event, Event::Empty(_)),
+                        attributes: e
+                            .attributes()
+                            .with_checks(false)
+                            .flatten()
+                            .map(|v| {
+                                (
+                                    String::from_utf8_lossy(v.key.into_inner()).to_string(),
+                                    String::from_utf8_lossy(&v.value).to_string(),
+                                )
+                            })
+                            .collect(),
+                        children: Vec::new(),
+                    };
+                    if node.is_empty {
+                        node
+                    } else if stack.len() == 1 && !descend(&node.key.0) {
+                        reader
+                            .read_to_end(e.name())
+                            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
+                        Self {
+                            end: to_usize(reader.buffer_position()),
+                            ..node
+                        }
+                    } else {
+                        stack.push(node);
+                        continue;
+                    }
+                }
+                Event::End(_) => match stack.pop() {
+                    Some(mut node) => {
+                        node.end = end;
+                        node
+                    }
+                    None => return Ok(None),
+                },
+                Event::Eof => return Ok(None),
+                _ => continue,
+            };
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => return Ok(Some(node)),
+            }
+        }
+    }
+
+    fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn child(&self, key: &ElementKey) -> Option<&Self> {
+        self.children.iter().find(|v| &v.key == key)
+    }
+
+    fn find(&self, path: &[ElementKey]) -> Option<&Self> {
+        path.iter().try_fold(self, |node, key| node.child(key))
+    }
+}
+
+/// Id of a kept relationship. The writer numbers the relationships it makes
+/// `rId1`, `rId2`, ..., so the kept ones are renamed out of the way.
+fn preserved_r_id(r_id: &str) -> String {
+    match r_id.strip_prefix("rId") {
+        Some(no) if !no.is_empty() && no.bytes().all(|v| v.is_ascii_digit()) => {
+            format!("rIdP{no}")
+        }
+        _ => r_id.to_string(),
+    }
+}
+
+/// Name in `order` of the element, or of the first element in it for markup
+/// compatibility wrappers such as `mc:AlternateContent`.
+fn order_name(order: &[&str], name: &str, xml: &[u8]) -> Option<String> {
+    if order.contains(&name) {
+        return Some(name.to_string());
+    }
+    let mut reader = Reader::from_reader(xml);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) => {
+                let name = local_name(e);
+                if order.contains(&name.as_str()) {
+                    return Some(name);
+                }
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => (),
+        }
+    }
+}
+
+/// Whether the element or one in it has one of the names.
+fn contains_any(xml: &[u8], names: &[&str]) -> bool {
+    let mut reader = Reader::from_reader(xml);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) => {
+                if names.contains(&local_name(e).as_str()) {
+                    return true;
+                }
+            }
+            Ok(Event::Eof) | Err(_) => return false,
+            _ => (),
+        }
+    }
+}
+
+/// Name without the namespace prefix, as some files use one for the main
+/// namespace.
+fn local_name(e: &BytesStart<'_>) -> String {
+    String::from_utf8_lossy(e.local_name().into_inner()).to_string()
+}
+
+#[inline]
+fn to_usize(value: u64) -> usize {
+    num_traits::cast(value).unwrap()
+}
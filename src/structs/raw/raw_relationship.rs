@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    helper::strict,
     reader::driver::get_attribute,
     structs::{
         StringValue,
@@ -22,6 +23,7 @@ use crate::{
 };
 
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct RawRelationship {
     id:          StringValue,
     r_type:      StringValue,
@@ -139,7 +141,7 @@ impl RawRelationship {
             return;
         };
         self.set_id(id);
-        self.set_type(type_val);
+        self.set_type(strict::to_transitional(&type_val));
         self.set_target(target);
         if let Some(v) = get_attribute(e, b"TargetMode") {
             self.set_target_mode(v);
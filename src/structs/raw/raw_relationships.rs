@@ -34,6 +34,7 @@ use crate::{
 };
 
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct RawRelationships {
     file_target:       StringValue,
     relationship_list: Vec<RawRelationship>,
@@ -94,23 +95,37 @@ impl RawRelationships {
         self.relationship_list_mut()
     }
 
-    pub(crate) fn relationship_by_rid(&self, r_id: &str) -> &RawRelationship {
+    pub(crate) fn relationship_by_rid(&self, r_id: &str) -> Result<&RawRelationship, XlsxError> {
         self.relationship_list()
             .iter()
             .find(|relationship| relationship.id() == r_id)
-            .unwrap_or_else(|| panic!("Not found relationship with ID: {r_id}."))
+            .ok_or_else(|| {
+                XlsxError::MissingPart(format!("relationship {r_id} in {}", self.file_target()))
+            })
+    }
+
+    /// Find a relationship of a part that may have no relationships file.
+    pub(crate) fn find_by_rid<'a>(
+        relationships: Option<&'a Self>,
+        r_id: &str,
+    ) -> Result<&'a RawRelationship, XlsxError> {
+        relationships
+            .ok_or_else(|| XlsxError::MissingPart(format!("relationship {r_id}")))?
+            .relationship_by_rid(r_id)
     }
 
     #[deprecated(since = "3.0.0", note = "Use relationship_by_rid()")]
-    pub(crate) fn get_relationship_by_rid(&self, r_id: &str) -> &RawRelationship {
+    pub(crate) fn get_relationship_by_rid(&self, r_id: &str) -> Result<&RawRelationship, XlsxError> {
         self.relationship_by_rid(r_id)
     }
 
-    pub(crate) fn relationship_by_type(&self, rtype: &str) -> &RawRelationship {
+    pub(crate) fn relationship_by_type(&self, rtype: &str) -> Result<&RawRelationship, XlsxError> {
         self.relationship_list()
             .iter()
             .find(|relationship| relationship.get_type() == rtype)
-            .unwrap_or_else(|| panic!("Not found relationship with Type: {rtype}."))
+            .ok_or_else(|| {
+                XlsxError::MissingPart(format!("relationship {rtype} in {}", self.file_target()))
+            })
     }
 
     #[inline]
@@ -125,18 +140,30 @@ impl RawRelationships {
         base_path: &str,
         target: &str,
         source_file: Option<&Path>,
-    ) -> bool {
+    ) -> Result<bool, XlsxError> {
+        let path_str = join_paths(base_path, target);
         let data = {
-            let path_str = join_paths(base_path, target);
             let Ok(file_path) = zip_by_name(arv, &path_str) else {
-                return false;
+                return Ok(false);
             };
-            self.set_file_target(path_str);
+            self.set_file_target(path_str.clone());
             let mut r = io::BufReader::new(file_path);
             let mut buf = Vec::new();
-            r.read_to_end(&mut buf).unwrap();
+            r.read_to_end(&mut buf)?;
             io::Cursor::new(buf)
         };
+        self.read_relationships(data, arv, base_path, source_file)
+            .map_err(|e| e.in_part(&path_str))?;
+        Ok(true)
+    }
+
+    fn read_relationships<R: Read + io::Seek>(
+        &mut self,
+        data: io::Cursor<Vec<u8>>,
+        arv: &mut zip::read::ZipArchive<R>,
+        base_path: &str,
+        source_file: Option<&Path>,
+    ) -> Result<(), XlsxError> {
         let mut reader = Reader::from_reader(data);
         reader.config_mut().trim_text(true);
 
@@ -152,7 +179,7 @@ impl RawRelationships {
             Event::Eof => break
         );
 
-        true
+        Ok(())
     }
 
     pub(crate) fn write_to<W: io::Seek + io::Write>(
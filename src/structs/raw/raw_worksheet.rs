@@ -5,6 +5,7 @@ use std::{
 
 use crate::{
     helper::const_str::{
+        PKG_CHARTSHEET_RELS,
         PKG_DRAWINGS_RELS,
         PKG_PIVOT_CACHE_RELS,
         PKG_PIVOT_TABLE_RELS,
@@ -12,6 +13,7 @@ use crate::{
         PKG_SHEET_RELS,
         PKG_VML_DRAWING_RELS,
     },
+    reader::driver::join_paths,
     structs::{
         WriterManager,
         XlsxError,
@@ -85,12 +87,31 @@ impl RawWorksheet {
             .find(|&relationships| relationships.file_target().starts_with(PKG_SHEET_RELS))
     }
 
+    /// Get the relationships of a worksheet that must have them.
+    pub(crate) fn required_worksheet_relationships(&self) -> Result<&RawRelationships, XlsxError> {
+        self.worksheet_relationships().ok_or_else(|| {
+            XlsxError::MissingPart(format!(
+                "relationships of {}",
+                self.worksheet_file().file_target()
+            ))
+        })
+    }
+
     #[inline]
     #[deprecated(since = "3.0.0", note = "Use worksheet_relationships()")]
     pub(crate) fn get_worksheet_relationships(&self) -> Option<&RawRelationships> {
         self.worksheet_relationships()
     }
 
+    #[inline]
+    pub(crate) fn chartsheet_relationships(&self) -> Option<&RawRelationships> {
+        self.relationships_list().iter().find(|&relationships| {
+            relationships
+                .file_target()
+                .starts_with(PKG_CHARTSHEET_RELS)
+        })
+    }
+
     pub(crate) fn drawing_relationships(&self) -> Option<&RawRelationships> {
         self.relationships_list()
             .iter()
@@ -131,16 +152,24 @@ impl RawWorksheet {
         })
     }
 
+    /// Relationships of a part that is itself the target of a relationship.
+    pub(crate) fn relationships_of(&self, raw_file: &RawFile) -> Option<&RawRelationships> {
+        let target = join_paths(&raw_file.path(), &raw_file.make_rel_name());
+        self.relationships_list()
+            .iter()
+            .find(|&relationships| relationships.file_target() == target)
+    }
+
     pub(crate) fn read<R: io::Read + io::Seek>(
         &mut self,
         arv: &mut zip::read::ZipArchive<R>,
         target: &str,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.worksheet_file_mut().set_attributes(arv, "xl", target);
 
         let base_path = self.worksheet_file().path();
         let target = self.worksheet_file().make_rel_name();
-        self.read_rawrelationships(arv, &base_path, &target, None);
+        self.read_rawrelationships(arv, &base_path, &target, None)
     }
 
     pub(crate) fn read_lazy<R: io::Read + io::Seek, P: AsRef<Path>>(
@@ -148,7 +177,7 @@ impl RawWorksheet {
         arv: &mut zip::read::ZipArchive<R>,
         target: &str,
         source_file: P,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.worksheet_file_mut().set_attributes_from_source(
             arv,
             "xl",
@@ -158,7 +187,7 @@ impl RawWorksheet {
 
         let base_path = self.worksheet_file().path();
         let target = self.worksheet_file().make_rel_name();
-        self.read_rawrelationships(arv, &base_path, &target, Some(source_file.as_ref()));
+        self.read_rawrelationships(arv, &base_path, &target, Some(source_file.as_ref()))
     }
 
     pub(crate) fn read_rawrelationships<R: io::Read + io::Seek>(
@@ -167,16 +196,17 @@ impl RawWorksheet {
         base_path: &str,
         target: &str,
         source_file: Option<&Path>,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut obj = RawRelationships::default();
-        if obj.set_attributes(arv, base_path, target, source_file) {
+        if obj.set_attributes(arv, base_path, target, source_file)? {
             for relationship in obj.relationship_list() {
                 let rels_base_path = relationship.raw_file().path();
                 let rels_target = relationship.raw_file().make_rel_name();
-                self.read_rawrelationships(arv, &rels_base_path, &rels_target, source_file);
+                self.read_rawrelationships(arv, &rels_base_path, &rels_target, source_file)?;
             }
             self.set_relationships(obj);
         }
+        Ok(())
     }
 
     pub(crate) fn load_file_data_from_source(&mut self) -> Result<(), XlsxError> {
@@ -193,6 +223,39 @@ impl RawWorksheet {
         Ok(())
     }
 
+    /// Whether the parts of the worksheet may refer to a sheet.
+    /// This is a text search for the end of the name followed by `!`, so it
+    /// can also find a name that is not a reference. Parts that can not be
+    /// loaded count as a reference.
+    pub(crate) fn mentions(&mut self, sheet_name: &str) -> bool {
+        // the name may be escaped in XML, so search the part after the last
+        // character that can be
+        let needle = sheet_name
+            .rsplit(['&', '<', '>', '"', '\''])
+            .next()
+            .unwrap_or_default()
+            .as_bytes();
+        if needle.is_empty() || self.load_file_data_from_source().is_err() {
+            return true;
+        }
+        let contains = |data: &[u8]| {
+            data.windows(needle.len()).enumerate().any(|(i, v)| {
+                let rest = &data[i + needle.len()..];
+                v.eq_ignore_ascii_case(needle)
+                    && [&b"!"[..], b"'!", b"&apos;!", b"&#39;!"]
+                        .iter()
+                        .any(|v| rest.starts_with(v))
+            })
+        };
+        contains(self.worksheet_file().file_data())
+            || self.relationships_list().iter().any(|relationships| {
+                relationships
+                    .relationship_list()
+                    .iter()
+                    .any(|v| contains(v.raw_file().file_data()))
+            })
+    }
+
     pub(crate) fn write<W: io::Seek + io::Write>(
         &self,
         sheet_no: i32,
@@ -0,0 +1,100 @@
+use crate::XlsxError;
+
+/// Options for reading a file.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::ReadOptions;
+///
+/// let mut options = ReadOptions::default();
+/// options.set_strict(false);
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let (book, warnings) =
+///     umya_spreadsheet::reader::xlsx::read_with_options(path, &options).unwrap();
+/// assert!(warnings.is_empty());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadOptions {
+    strict:           bool,
+    preserve_unknown: bool,
+}
+
+impl Default for ReadOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            strict:           true,
+            preserve_unknown: false,
+        }
+    }
+}
+
+impl ReadOptions {
+    /// Fail on the first element or part that can not be read.
+    /// When `false`, those are skipped and their errors are returned as
+    /// warnings. Default is `true`.
+    #[inline]
+    #[must_use]
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    #[inline]
+    pub fn set_strict(&mut self, value: bool) -> &mut Self {
+        self.strict = value;
+        self
+    }
+
+    /// Keep what is not supported, so that writing the workbook does not lose
+    /// it. Default is `false`.
+    ///
+    /// Kept are the package parts and relationships, with the parts they
+    /// refer to, and the elements and attributes of the workbook part, the
+    /// styles part and the worksheets, including the `ext` elements of their
+    /// `extLst` and the content nested in a supported element, such as a
+    /// child of `sheetView`. Nested content is put back where it was read, so
+    /// it follows its position rather than the element it belonged to when
+    /// elements are added or removed before it. Cells are written from their
+    /// values only, and the unsupported content of the other parts, such as
+    /// drawings and charts, is still lost.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::ReadOptions;
+    ///
+    /// let mut options = ReadOptions::default();
+    /// options.set_preserve_unknown(true);
+    /// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    /// let (book, _) = umya_spreadsheet::reader::xlsx::read_with_options(path, &options).unwrap();
+    /// let path = std::path::Path::new("./tests/result_files/preserve_unknown.xlsx");
+    /// umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn preserve_unknown(&self) -> bool {
+        self.preserve_unknown
+    }
+
+    #[inline]
+    pub fn set_preserve_unknown(&mut self, value: bool) -> &mut Self {
+        self.preserve_unknown = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Keep the error as a warning in lenient mode.
+    #[inline]
+    pub(crate) fn recover<T>(
+        &self,
+        result: Result<T, XlsxError>,
+        warnings: &mut Vec<XlsxError>,
+    ) -> Result<Option<T>, XlsxError> {
+        match result {
+            Ok(v) => Ok(Some(v)),
+            Err(e) if self.strict => Err(e),
+            Err(e) => {
+                warnings.push(e);
+                Ok(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/// A column of a [`ColumnSpec`](crate::structs::ColumnSpec).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordColumn {
+    key:           Box<str>,
+    header:        Option<Box<str>>,
+    number_format: Option<Box<str>>,
+}
+
+impl RecordColumn {
+    /// The key of the records the column is read from.
+    #[inline]
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    #[inline]
+    pub fn set_key<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.key = value.into().into_boxed_str();
+        self
+    }
+
+    /// The text of the header cell. Default is the key.
+    #[inline]
+    #[must_use]
+    pub fn header(&self) -> &str {
+        self.header.as_deref().unwrap_or(&self.key)
+    }
+
+    #[inline]
+    pub fn set_header<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.header = Some(value.into().into_boxed_str());
+        self
+    }
+
+    /// The format code of the cells of the column, such as `#,##0.00` or
+    /// `yyyy-mm-dd`. With a date or time format, the ISO 8601 strings of
+    /// the records are written as dates.
+    #[inline]
+    #[must_use]
+    pub fn number_format(&self) -> Option<&str> {
+        self.number_format.as_deref()
+    }
+
+    #[inline]
+    pub fn set_number_format<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.number_format = Some(value.into().into_boxed_str());
+        self
+    }
+}
@@ -0,0 +1,31 @@
+/// Options for converting the rows of a worksheet to JSON records.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::RecordsOption;
+///
+/// let mut option = RecordsOption::default();
+/// option.set_date_as_iso(true);
+/// assert!(option.date_as_iso());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordsOption {
+    date_as_iso: bool,
+}
+
+impl RecordsOption {
+    /// Write the numbers formatted as a date or a time as ISO 8601 strings,
+    /// such as `2024-01-15`, `12:30:00` or `2024-01-15T12:30:00`, instead of
+    /// their serial numbers. Default is `false`.
+    #[inline]
+    #[must_use]
+    pub fn date_as_iso(&self) -> bool {
+        self.date_as_iso
+    }
+
+    #[inline]
+    pub fn set_date_as_iso(&mut self, value: bool) -> &mut Self {
+        self.date_as_iso = value;
+        self
+    }
+}
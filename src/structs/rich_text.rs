@@ -6,11 +6,19 @@ use std::{
 
 use quick_xml::Writer;
 
-use super::TextElement;
+use super::{
+    PhoneticRun,
+    RichTextBuilder,
+    Text,
+    TextElement,
+};
+use crate::helper::html::richtext_to_html;
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RichText {
     rich_text_elements: Vec<TextElement>,
+    phonetic_runs:      Vec<PhoneticRun>,
 }
 
 impl RichText {
@@ -76,11 +84,89 @@ impl RichText {
         self
     }
 
+    /// Get the phonetic readings (furigana) of the text.
+    #[inline]
+    #[must_use]
+    pub fn phonetic_runs(&self) -> &[PhoneticRun] {
+        &self.phonetic_runs
+    }
+
+    #[inline]
+    pub fn phonetic_runs_mut(&mut self) -> &mut Vec<PhoneticRun> {
+        &mut self.phonetic_runs
+    }
+
+    #[inline]
+    pub fn add_phonetic_run(&mut self, value: PhoneticRun) -> &mut Self {
+        self.phonetic_runs.push(value);
+        self
+    }
+
+    /// Start building a rich text run by run.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::RichText;
+    ///
+    /// let rich_text = RichText::builder()
+    ///     .text("Total: ")
+    ///     .bold()
+    ///     .color("FF0000")
+    ///     .text("42")
+    ///     .build();
+    /// assert_eq!(rich_text.text(), "Total: 42");
+    /// assert!(rich_text.rich_text_elements()[0].font().unwrap().bold());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn builder() -> RichTextBuilder {
+        RichTextBuilder::default()
+    }
+
+    /// Convert to HTML that `helper::html::html_to_richtext` reads back.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::RichText;
+    ///
+    /// let rich_text = RichText::builder().text("A&B").bold().text("\nC").build();
+    /// assert_eq!(rich_text.to_html(), "<b>A&amp;B</b><br>C");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        richtext_to_html(self)
+    }
+
+    /// Convert to Markdown.
+    /// Bold, italic and strikethrough use the Markdown syntax, underline,
+    /// superscript and subscript use HTML tags. Fonts and colors are dropped.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::RichText;
+    ///
+    /// let rich_text = RichText::builder()
+    ///     .text("Total: ")
+    ///     .text("42")
+    ///     .bold()
+    ///     .build();
+    /// assert_eq!(rich_text.to_markdown(), "Total: **42**");
+    /// ```
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut result = String::new();
+        for element in &self.rich_text_elements {
+            result.push_str(&element.to_markdown());
+        }
+        result
+    }
+
     pub(crate) fn hash_code(&self) -> String {
         let mut value = String::new();
         for ele in &self.rich_text_elements {
             write!(value, "{}", ele.hash_code()).unwrap();
         }
+        for phonetic_run in &self.phonetic_runs {
+            write!(value, "{}", phonetic_run.hash_code()).unwrap();
+        }
         crate::helper::utils::md5_hash(&value)
     }
 
@@ -89,11 +175,48 @@ impl RichText {
         self.hash_code()
     }
 
-    #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // r
-        for obj in &self.rich_text_elements {
+        match self.rich_text_elements.as_slice() {
+            // plain text kept only for its phonetic runs
+            [element] if !self.phonetic_runs.is_empty() && element.run_properties().is_none() => {
+                // t
+                let mut obj = Text::default();
+                obj.set_value(element.text());
+                obj.write_to(writer);
+            }
+            elements => {
+                // r
+                for obj in elements {
+                    obj.write_to(writer);
+                }
+            }
+        }
+
+        // rPh
+        for obj in &self.phonetic_runs {
             obj.write_to(writer);
         }
     }
+
+    /// (This method is crate only.)
+    /// Build the value of a `si` or comment `text` element that has phonetic
+    /// runs, so the runs are kept for plain text as well.
+    pub(crate) fn from_parts(
+        text: Option<&Text>,
+        elements: Vec<TextElement>,
+        phonetic_runs: Vec<PhoneticRun>,
+    ) -> Option<Self> {
+        let mut result = Self::default();
+        result.set_rich_text_elements(elements);
+        if !phonetic_runs.is_empty() && result.rich_text_elements.is_empty() {
+            if let Some(v) = text {
+                result.set_text(v.value());
+            }
+        }
+        if result.rich_text_elements.is_empty() {
+            return None;
+        }
+        result.phonetic_runs = phonetic_runs;
+        Some(result)
+    }
 }
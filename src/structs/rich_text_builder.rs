@@ -0,0 +1,176 @@
+use super::{
+    Font,
+    PhoneticRun,
+    RichText,
+    TextElement,
+    UnderlineValues,
+    VerticalAlignmentRunValues,
+};
+
+/// Builds a `RichText` run by run.
+/// `text()` starts a new run and the formatting methods apply to the run
+/// added last.
+/// The result can be set to a cell, a comment or a shape text body.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::RichText;
+///
+/// let rich_text = RichText::builder()
+///     .text("Total: ")
+///     .bold()
+///     .color("FF0000")
+///     .text("42")
+///     .size(14.0)
+///     .build();
+///
+/// let mut book = umya_spreadsheet::new_file();
+/// let sheet = book.sheet_by_name_mut("Sheet1").unwrap();
+/// sheet.cell_mut("A1").set_rich_text(rich_text.clone());
+/// sheet.add_comments({
+///     let mut comment = umya_spreadsheet::structs::Comment::default();
+///     comment.new_comment("A1").set_rich_text(rich_text);
+///     comment
+/// });
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct RichTextBuilder {
+    rich_text: RichText,
+}
+
+impl RichTextBuilder {
+    /// Add a run with the default formatting.
+    #[inline]
+    #[must_use]
+    pub fn text<S: Into<String>>(mut self, value: S) -> Self {
+        let mut obj = TextElement::default();
+        obj.set_text(value);
+        self.rich_text.add_rich_text_elements(obj);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn bold(self) -> Self {
+        self.with_font(|font| {
+            font.set_bold(true);
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn italic(self) -> Self {
+        self.with_font(|font| {
+            font.set_italic(true);
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn underline(self) -> Self {
+        self.with_font(|font| {
+            font.font_underline_mut().set_val(UnderlineValues::Single);
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn strikethrough(self) -> Self {
+        self.with_font(|font| {
+            font.set_strikethrough(true);
+        })
+    }
+
+    /// Set the font color.
+    /// # Arguments
+    /// * `argb` - RGB or ARGB hex string. ex) "FF0000", "FFFF0000"
+    #[inline]
+    #[must_use]
+    pub fn color<S: AsRef<str>>(self, argb: S) -> Self {
+        self.with_font(|font| {
+            font.color_mut().set_argb_str(argb);
+        })
+    }
+
+    /// Set the font size in points.
+    #[inline]
+    #[must_use]
+    pub fn size(self, value: f64) -> Self {
+        self.with_font(|font| {
+            font.set_size(value);
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn font_name<S: Into<String>>(self, value: S) -> Self {
+        self.with_font(|font| {
+            font.set_name(value);
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn superscript(self) -> Self {
+        self.with_font(|font| {
+            font.vertical_text_alignment_mut()
+                .set_val(VerticalAlignmentRunValues::Superscript);
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn subscript(self) -> Self {
+        self.with_font(|font| {
+            font.vertical_text_alignment_mut()
+                .set_val(VerticalAlignmentRunValues::Subscript);
+        })
+    }
+
+    /// Add a phonetic reading (furigana) over the run added last.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::RichText;
+    ///
+    /// let rich_text = RichText::builder()
+    ///     .text("東京")
+    ///     .phonetic("トウキョウ")
+    ///     .text("都")
+    ///     .build();
+    /// let phonetic_run = &rich_text.phonetic_runs()[0];
+    /// assert_eq!(phonetic_run.text(), "トウキョウ");
+    /// assert_eq!(phonetic_run.base_start(), 0);
+    /// assert_eq!(phonetic_run.base_end(), 2);
+    /// ```
+    #[must_use]
+    pub fn phonetic<S: Into<String>>(mut self, value: S) -> Self {
+        let elements = self.rich_text.rich_text_elements();
+        let Some((last, before)) = elements.split_last() else {
+            return self;
+        };
+        let start: usize = before.iter().map(|v| v.text().chars().count()).sum();
+        let end = start + last.text().chars().count();
+
+        let mut obj = PhoneticRun::default();
+        obj.set_text(value)
+            .set_base_start(num_traits::cast(start).unwrap())
+            .set_base_end(num_traits::cast(end).unwrap());
+        self.rich_text.add_phonetic_run(obj);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn build(self) -> RichText {
+        self.rich_text
+    }
+
+    fn with_font<F: FnOnce(&mut Font)>(mut self, f: F) -> Self {
+        if let Some(element) = self.rich_text.rich_text_elements_mut().last_mut() {
+            if element.run_properties().is_none() {
+                element.set_run_properties(Font::default());
+            }
+            f(element.font_mut());
+        }
+        self
+    }
+}
@@ -23,10 +23,12 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     helper::formula::FormulaToken,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     traits::AdjustmentValue,
@@ -34,6 +36,7 @@ use crate::{
 };
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Row {
     row_num:       UInt32Value,
     height:        DoubleValue,
@@ -218,7 +221,7 @@ impl Row {
         stylesheet: &Stylesheet,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, row_num, "r");
         set_string_from_xml!(self, e, height, "ht");
         set_string_from_xml!(self, e, thick_bot, "thickBot");
@@ -232,12 +235,12 @@ impl Row {
         }
 
         if let Some(v) = get_attribute(e, b"s") {
-            let style = stylesheet.style(v.parse::<usize>().unwrap());
+            let style = stylesheet.style(v.parse::<usize>().unwrap_or_default());
             self.set_style(style);
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -252,7 +255,7 @@ impl Row {
                         stylesheet,
                         true,
                         formula_shared_list
-                    );
+                    )?;
                     cells.set_fast(obj);
                 }
             },
@@ -266,16 +269,16 @@ impl Row {
                         stylesheet,
                         false,
                         formula_shared_list
-                    );
+                    )?;
                     cells.set_fast(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"row" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "row")
+            Event::Eof => return Err(unexpected_eof(reader, "row"))
         );
     }
 
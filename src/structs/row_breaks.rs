@@ -11,7 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     structs::Break,
     writer::driver::{
         write_end_tag,
@@ -20,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RowBreaks {
     break_list: Vec<Break>,
 }
@@ -64,7 +69,7 @@ impl RowBreaks {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"brk" {
@@ -75,10 +80,10 @@ impl RowBreaks {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rowBreaks" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rowBreaks")
+            Event::Eof => return Err(unexpected_eof(reader, "rowBreaks"))
         );
     }
 
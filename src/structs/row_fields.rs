@@ -11,13 +11,15 @@ use quick_xml::{
 };
 
 use crate::{
-    Field, reader::driver::xml_read_loop, writer::driver::{
+    XlsxError,
+    Field, reader::driver::{unexpected_eof, xml_read_loop}, writer::driver::{
         write_end_tag,
         write_start_tag,
     }
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RowFields {
     list: Vec<Field>,
 }
@@ -57,7 +59,7 @@ impl RowFields {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -69,10 +71,10 @@ impl RowFields {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rowFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rowFields")
+            Event::Eof => return Err(unexpected_eof(reader, "rowFields"))
         );
     }
 
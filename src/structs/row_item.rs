@@ -11,9 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -29,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RowItem {
     index:                 UInt32Value,
     item_type:             EnumValue<ItemValues>,
@@ -129,13 +132,13 @@ impl RowItem {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, index, "i");
         set_string_from_xml!(self, e, item_type, "t");
         set_string_from_xml!(self, e, repeated_item_count, "r");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -149,10 +152,10 @@ impl RowItem {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"i" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "i")
+            Event::Eof => return Err(unexpected_eof(reader, "i"))
         );
     }
 
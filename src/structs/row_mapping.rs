@@ -0,0 +1,87 @@
+use crate::structs::RecordColumn;
+
+/// How the rows of a worksheet map to the fields of a struct.
+///
+/// A header is the name of the field unless a column maps it to another
+/// key. The number format of a column is used when the rows are written.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::structs::{
+///     RecordColumn,
+///     RowMapping,
+/// };
+///
+/// let mut mapping = RowMapping::default();
+/// mapping.set_header_row(2);
+/// let mut column = RecordColumn::default();
+/// column.set_key("unit_price").set_header("Unit Price");
+/// mapping.add_column(column);
+/// assert_eq!(mapping.field("Unit Price"), "unit_price");
+/// assert_eq!(mapping.header("unit_price"), "Unit Price");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RowMapping {
+    header_row: u32,
+    columns:    Vec<RecordColumn>,
+}
+
+impl Default for RowMapping {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            header_row: 1,
+            columns:    Vec::new(),
+        }
+    }
+}
+
+impl RowMapping {
+    /// The row of the headers, the rows below it are the values.
+    /// Default is `1`.
+    #[inline]
+    #[must_use]
+    pub fn header_row(&self) -> u32 {
+        self.header_row
+    }
+
+    #[inline]
+    pub fn set_header_row(&mut self, value: u32) -> &mut Self {
+        self.header_row = value;
+        self
+    }
+
+    /// The columns whose header is not the name of the field, or that
+    /// have a number format. The key of a column is the name of the field.
+    #[inline]
+    #[must_use]
+    pub fn columns(&self) -> &[RecordColumn] {
+        &self.columns
+    }
+
+    #[inline]
+    pub fn add_column(&mut self, value: RecordColumn) -> &mut Self {
+        self.columns.push(value);
+        self
+    }
+
+    /// The name of the field of a header.
+    #[must_use]
+    pub fn field<'a>(&'a self, header: &'a str) -> &'a str {
+        self.columns
+            .iter()
+            .find(|v| v.header() == header)
+            .map_or(header, RecordColumn::key)
+    }
+
+    /// The header of a field.
+    #[must_use]
+    pub fn header<'a>(&'a self, field: &'a str) -> &'a str {
+        self.column(field).map_or(field, RecordColumn::header)
+    }
+
+    #[inline]
+    pub(crate) fn column(&self, field: &str) -> Option<&RecordColumn> {
+        self.columns.iter().find(|v| v.key() == field)
+    }
+}
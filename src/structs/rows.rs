@@ -6,6 +6,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Rows {
     rows: HashMap<u32, Box<Row>>,
 }
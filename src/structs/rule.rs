@@ -0,0 +1,407 @@
+use super::{
+    Color,
+    ColorScale,
+    ConditionalFormatValueObject,
+    ConditionalFormatValueObjectValues,
+    ConditionalFormatValues,
+    ConditionalFormattingOperatorValues,
+    ConditionalFormattingRule,
+    DataBar,
+    Formula,
+    IconSet,
+    IconSetValues,
+    Style,
+    TimePeriodValues,
+};
+
+/// A conditional formatting rule to add with
+/// [`Worksheet::add_conditional_format`](crate::Worksheet::add_conditional_format).
+///
+/// Formulas that depend on the formatted range, such as those of the text
+/// rules, are generated from the top-left cell of the range when the rule is
+/// added. The priority of the rule is assigned by the worksheet.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+///
+/// let mut book = new_file();
+/// let sheet = book.sheet_mut(0).unwrap();
+///
+/// let mut style = Style::default();
+/// style.font_mut().set_bold(true);
+/// sheet.add_conditional_format(
+///     "A1:A10",
+///     Rule::cell_is(ConditionalFormattingOperatorValues::GreaterThan, "100").style(style),
+/// );
+/// sheet.add_conditional_format("B1:B10", Rule::duplicates());
+/// sheet.add_conditional_format("A1:D10", Rule::formula("=MOD(ROW(),2)=0"));
+/// ```
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rule {
+    rule: ConditionalFormattingRule,
+}
+
+impl Rule {
+    fn new(rule_type: ConditionalFormatValues) -> Self {
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(rule_type);
+        Self { rule }
+    }
+
+    fn with_formula(mut self, value: &str) -> Self {
+        let mut formula = Formula::default();
+        formula.set_string_value(value.strip_prefix('=').unwrap_or(value));
+        self.rule.set_formula(formula);
+        self
+    }
+
+    fn with_formula2(mut self, value: &str) -> Self {
+        let mut formula = Formula::default();
+        formula.set_string_value(value.strip_prefix('=').unwrap_or(value));
+        self.rule.set_formula2(formula);
+        self
+    }
+
+    fn with_text(
+        rule_type: ConditionalFormatValues,
+        operator: ConditionalFormattingOperatorValues,
+        text: &str,
+    ) -> Self {
+        let mut result = Self::new(rule_type);
+        result.rule.set_operator(operator);
+        result.rule.set_text(text);
+        result
+    }
+
+    fn with_top10(rank: u32, bottom: bool, percent: bool) -> Self {
+        let mut result = Self::new(ConditionalFormatValues::Top10);
+        result.rule.set_rank(rank);
+        if bottom {
+            result.rule.set_bottom(true);
+        }
+        if percent {
+            result.rule.set_percent(true);
+        }
+        result
+    }
+
+    fn cfvo(
+        r#type: ConditionalFormatValueObjectValues,
+        val: Option<&str>,
+    ) -> ConditionalFormatValueObject {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(r#type);
+        if let Some(v) = val {
+            cfvo.set_val(v);
+        }
+        cfvo
+    }
+
+    /// Compare the cell value with a value or formula.
+    /// # Arguments
+    /// * `operator` - Comparison operator. Use [`Rule::between`] and
+    ///   [`Rule::not_between`] for the operators taking two values.
+    /// * `formula` - Value or formula to compare with. ex) `"100"`, `"$B$1"`
+    #[must_use]
+    pub fn cell_is<S: AsRef<str>>(
+        operator: ConditionalFormattingOperatorValues,
+        formula: S,
+    ) -> Self {
+        let mut result = Self::new(ConditionalFormatValues::CellIs);
+        result.rule.set_operator(operator);
+        result.with_formula(formula.as_ref())
+    }
+
+    /// Match cell values between two values, both inclusive.
+    #[must_use]
+    pub fn between<S: AsRef<str>, T: AsRef<str>>(from: S, to: T) -> Self {
+        Self::cell_is(ConditionalFormattingOperatorValues::Between, from)
+            .with_formula2(to.as_ref())
+    }
+
+    /// Match cell values outside of two values.
+    #[must_use]
+    pub fn not_between<S: AsRef<str>, T: AsRef<str>>(from: S, to: T) -> Self {
+        Self::cell_is(ConditionalFormattingOperatorValues::NotBetween, from)
+            .with_formula2(to.as_ref())
+    }
+
+    /// Match cells for which a formula is true.
+    /// Relative references are relative to the top-left cell of the range.
+    /// ex) `"=MOD(ROW(),2)=0"`, `"=$B1>$C1"`
+    #[must_use]
+    pub fn formula<S: AsRef<str>>(formula: S) -> Self {
+        Self::new(ConditionalFormatValues::Expression).with_formula(formula.as_ref())
+    }
+
+    /// Match the `rank` highest values.
+    #[must_use]
+    pub fn top_n(rank: u32) -> Self {
+        Self::with_top10(rank, false, false)
+    }
+
+    /// Match the `rank` lowest values.
+    #[must_use]
+    pub fn bottom_n(rank: u32) -> Self {
+        Self::with_top10(rank, true, false)
+    }
+
+    /// Match the highest `percent` percent of the values.
+    #[must_use]
+    pub fn top_percent(percent: u32) -> Self {
+        Self::with_top10(percent, false, true)
+    }
+
+    /// Match the lowest `percent` percent of the values.
+    #[must_use]
+    pub fn bottom_percent(percent: u32) -> Self {
+        Self::with_top10(percent, true, true)
+    }
+
+    /// Match values above the average of the range.
+    #[must_use]
+    pub fn above_average() -> Self {
+        Self::new(ConditionalFormatValues::AboveAverage)
+    }
+
+    /// Match values below the average of the range.
+    #[must_use]
+    pub fn below_average() -> Self {
+        let mut result = Self::new(ConditionalFormatValues::AboveAverage);
+        result.rule.set_above_average(false);
+        result
+    }
+
+    /// Match values occurring more than once in the range.
+    #[must_use]
+    pub fn duplicates() -> Self {
+        Self::new(ConditionalFormatValues::DuplicateValues)
+    }
+
+    /// Match values occurring once in the range.
+    #[must_use]
+    pub fn unique() -> Self {
+        Self::new(ConditionalFormatValues::UniqueValues)
+    }
+
+    /// Match cells containing a text, ignoring case.
+    #[must_use]
+    pub fn contains_text<S: AsRef<str>>(text: S) -> Self {
+        Self::with_text(
+            ConditionalFormatValues::ContainsText,
+            ConditionalFormattingOperatorValues::ContainsText,
+            text.as_ref(),
+        )
+    }
+
+    /// Match cells not containing a text, ignoring case.
+    #[must_use]
+    pub fn not_contains_text<S: AsRef<str>>(text: S) -> Self {
+        Self::with_text(
+            ConditionalFormatValues::NotContainsText,
+            ConditionalFormattingOperatorValues::NotContains,
+            text.as_ref(),
+        )
+    }
+
+    /// Match cells beginning with a text, ignoring case.
+    #[must_use]
+    pub fn begins_with<S: AsRef<str>>(text: S) -> Self {
+        Self::with_text(
+            ConditionalFormatValues::BeginsWith,
+            ConditionalFormattingOperatorValues::BeginsWith,
+            text.as_ref(),
+        )
+    }
+
+    /// Match cells ending with a text, ignoring case.
+    #[must_use]
+    pub fn ends_with<S: AsRef<str>>(text: S) -> Self {
+        Self::with_text(
+            ConditionalFormatValues::EndsWith,
+            ConditionalFormattingOperatorValues::EndsWith,
+            text.as_ref(),
+        )
+    }
+
+    /// Match empty cells and cells containing only spaces.
+    #[must_use]
+    pub fn blanks() -> Self {
+        Self::new(ConditionalFormatValues::ContainsBlanks)
+    }
+
+    /// Match cells that are not blank.
+    #[must_use]
+    pub fn no_blanks() -> Self {
+        Self::new(ConditionalFormatValues::NotContainsBlanks)
+    }
+
+    /// Match cells containing an error value.
+    #[must_use]
+    pub fn errors() -> Self {
+        Self::new(ConditionalFormatValues::ContainsErrors)
+    }
+
+    /// Match cells not containing an error value.
+    #[must_use]
+    pub fn no_errors() -> Self {
+        Self::new(ConditionalFormatValues::NotContainsErrors)
+    }
+
+    /// Match dates within a period relative to today.
+    #[must_use]
+    pub fn time_period(value: TimePeriodValues) -> Self {
+        let mut result = Self::new(ConditionalFormatValues::TimePeriod);
+        result.rule.set_time_period(value);
+        result
+    }
+
+    /// Color the cells on a scale between the lowest and highest value.
+    #[must_use]
+    pub fn color_scale_2(min: Color, max: Color) -> Self {
+        let mut color_scale = ColorScale::default();
+        color_scale
+            .add_cfvo_collection(Self::cfvo(ConditionalFormatValueObjectValues::Min, None))
+            .add_cfvo_collection(Self::cfvo(ConditionalFormatValueObjectValues::Max, None))
+            .add_color_collection(min)
+            .add_color_collection(max);
+        let mut result = Self::new(ConditionalFormatValues::ColorScale);
+        result.rule.set_color_scale(color_scale);
+        result
+    }
+
+    /// Color the cells on a scale between the lowest value, the median and
+    /// the highest value.
+    #[must_use]
+    pub fn color_scale_3(min: Color, mid: Color, max: Color) -> Self {
+        let mut color_scale = ColorScale::default();
+        color_scale
+            .add_cfvo_collection(Self::cfvo(ConditionalFormatValueObjectValues::Min, None))
+            .add_cfvo_collection(Self::cfvo(
+                ConditionalFormatValueObjectValues::Percentile,
+                Some("50"),
+            ))
+            .add_cfvo_collection(Self::cfvo(ConditionalFormatValueObjectValues::Max, None))
+            .add_color_collection(min)
+            .add_color_collection(mid)
+            .add_color_collection(max);
+        let mut result = Self::new(ConditionalFormatValues::ColorScale);
+        result.rule.set_color_scale(color_scale);
+        result
+    }
+
+    /// Draw a bar of a color in the cells, scaled between the lowest and
+    /// highest value.
+    #[must_use]
+    pub fn data_bar(color: Color) -> Self {
+        let mut data_bar = DataBar::default();
+        data_bar
+            .add_cfvo_collection(Self::cfvo(ConditionalFormatValueObjectValues::Min, None))
+            .add_cfvo_collection(Self::cfvo(ConditionalFormatValueObjectValues::Max, None))
+            .add_color_collection(color);
+        let mut result = Self::new(ConditionalFormatValues::DataBar);
+        result.rule.set_data_bar(data_bar);
+        result
+    }
+
+    /// Show an icon of a set in the cells.
+    /// The thresholds divide the range of values into equal percentages.
+    #[must_use]
+    pub fn icon_set(value: IconSetValues) -> Self {
+        let count = u32::try_from(value.icon_count().max(1)).unwrap_or(1);
+        let mut icon_set = IconSet::default();
+        icon_set.set_icon_set(value);
+        for i in 0..count {
+            let threshold = (100 * i + count / 2) / count;
+            icon_set.add_cfvo_collection(Self::cfvo(
+                ConditionalFormatValueObjectValues::Percent,
+                Some(&threshold.to_string()),
+            ));
+        }
+        let mut result = Self::new(ConditionalFormatValues::IconSet);
+        result.rule.set_icon_set(icon_set);
+        result
+    }
+
+    /// Set the differential style applied to matching cells.
+    #[must_use]
+    pub fn style(mut self, value: Style) -> Self {
+        self.rule.set_style(value);
+        self
+    }
+
+    /// Set whether rules of lower priority are skipped for matching cells.
+    #[must_use]
+    pub fn stop_if_true(mut self, value: bool) -> Self {
+        self.rule.set_stop_if_true(value);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Build the rule for a range starting at `top_left`. ex) "A1"
+    pub(crate) fn into_rule(self, top_left: &str, priority: i32) -> ConditionalFormattingRule {
+        let text = self.rule.text().replace('"', "\"\"");
+        let formula = match self.rule.get_type() {
+            ConditionalFormatValues::ContainsText => {
+                Some(format!("NOT(ISERROR(SEARCH(\"{text}\",{top_left})))"))
+            }
+            ConditionalFormatValues::NotContainsText => {
+                Some(format!("ISERROR(SEARCH(\"{text}\",{top_left}))"))
+            }
+            ConditionalFormatValues::BeginsWith => {
+                Some(format!("LEFT({top_left},LEN(\"{text}\"))=\"{text}\""))
+            }
+            ConditionalFormatValues::EndsWith => {
+                Some(format!("RIGHT({top_left},LEN(\"{text}\"))=\"{text}\""))
+            }
+            ConditionalFormatValues::ContainsBlanks => Some(format!("LEN(TRIM({top_left}))=0")),
+            ConditionalFormatValues::NotContainsBlanks => {
+                Some(format!("LEN(TRIM({top_left}))>0"))
+            }
+            ConditionalFormatValues::ContainsErrors => Some(format!("ISERROR({top_left})")),
+            ConditionalFormatValues::NotContainsErrors => {
+                Some(format!("NOT(ISERROR({top_left}))"))
+            }
+            ConditionalFormatValues::TimePeriod => {
+                Some(Self::time_period_formula(self.rule.time_period(), top_left))
+            }
+            _ => None,
+        };
+        let mut result = match formula {
+            Some(v) => self.with_formula(&v),
+            None => self,
+        };
+        result.rule.set_priority(priority);
+        result.rule
+    }
+
+    fn time_period_formula(value: &TimePeriodValues, cell: &str) -> String {
+        match value {
+            TimePeriodValues::Today => format!("FLOOR({cell},1)=TODAY()"),
+            TimePeriodValues::Yesterday => format!("FLOOR({cell},1)=TODAY()-1"),
+            TimePeriodValues::Tomorrow => format!("FLOOR({cell},1)=TODAY()+1"),
+            TimePeriodValues::Last7Days => {
+                format!("AND(TODAY()-FLOOR({cell},1)<=6,FLOOR({cell},1)<=TODAY())")
+            }
+            TimePeriodValues::ThisWeek => format!(
+                "AND(TODAY()-ROUNDDOWN({cell},0)<=WEEKDAY(TODAY())-1,ROUNDDOWN({cell},0)-TODAY()<=7-WEEKDAY(TODAY()))"
+            ),
+            TimePeriodValues::LastWeek => format!(
+                "AND(TODAY()-ROUNDDOWN({cell},0)>=(WEEKDAY(TODAY())),TODAY()-ROUNDDOWN({cell},0)<(WEEKDAY(TODAY())+7))"
+            ),
+            TimePeriodValues::NextWeek => format!(
+                "AND(ROUNDDOWN({cell},0)-TODAY()>(7-WEEKDAY(TODAY())),ROUNDDOWN({cell},0)-TODAY()<(15-WEEKDAY(TODAY())))"
+            ),
+            TimePeriodValues::ThisMonth => {
+                format!("AND(MONTH({cell})=MONTH(TODAY()),YEAR({cell})=YEAR(TODAY()))")
+            }
+            TimePeriodValues::LastMonth => format!(
+                "AND(MONTH({cell})=MONTH(EDATE(TODAY(),0-1)),YEAR({cell})=YEAR(EDATE(TODAY(),0-1)))"
+            ),
+            TimePeriodValues::NextMonth => format!(
+                "AND(MONTH({cell})=MONTH(EDATE(TODAY(),0+1)),YEAR({cell})=YEAR(EDATE(TODAY(),0+1)))"
+            ),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SByteValue {
     value: Option<i8>,
 }
@@ -33,7 +34,7 @@ impl SByteValue {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut SByteValue {
-        self.set_value(value.into().parse::<i8>().unwrap())
+        self.set_value(value.into().parse::<i8>().unwrap_or_default())
     }
 
     #[inline]
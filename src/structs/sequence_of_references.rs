@@ -2,6 +2,7 @@ use super::Range;
 use crate::traits::AdjustmentCoordinate;
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SequenceOfReferences {
     range_collection: Vec<Range>,
 }
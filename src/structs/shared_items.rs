@@ -16,6 +16,7 @@ use quick_xml::{
 
 use crate::{
     CellErrorType,
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -28,10 +29,12 @@ use crate::{
         write_end_tag,
         write_start_tag,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SharedItems {
     contains_semi_mixed_types: BooleanValue,
     contains_string:           BooleanValue,
@@ -43,6 +46,7 @@ pub struct SharedItems {
 }
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SharedItemValue {
     Bool(bool),
     Date(Box<str>),
@@ -203,7 +207,7 @@ impl SharedItems {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, contains_semi_mixed_types, "containsSemiMixedTypes");
         set_string_from_xml!(self, e, contains_string, "containsString");
         set_string_from_xml!(self, e, contains_number, "containsNumber");
@@ -212,7 +216,7 @@ impl SharedItems {
         set_string_from_xml!(self, e, max_value, "maxValue");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -262,10 +266,10 @@ impl SharedItems {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sharedItems" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sharedItems")
+            Event::Eof => return Err(unexpected_eof(reader, "sharedItems"))
         );
     }
 
@@ -23,7 +23,11 @@ use super::{
     TextElement,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -31,6 +35,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct SharedStringItem {
     text:      Option<Text>,
     rich_text: Option<RichText>,
@@ -131,8 +136,9 @@ impl SharedStringItem {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut vec_text_element: Vec<TextElement> = Vec::new();
+        let mut phonetic_runs: Vec<PhoneticRun> = Vec::new();
 
         xml_read_loop!(
             reader,
@@ -140,31 +146,37 @@ impl SharedStringItem {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_text(obj);
                     }
                     b"r" => {
                         let mut obj = TextElement::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         vec_text_element.push(obj);
                     }
                     b"rPh" => {
-                        PhoneticRun::set_attributes(reader, e);
+                        let mut obj = PhoneticRun::default();
+                        obj.set_attributes(reader, e)?;
+                        phonetic_runs.push(obj);
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"si" {
-                    if !vec_text_element.is_empty() {
-                        let mut obj = RichText::default();
-                        obj.set_rich_text_elements(vec_text_element);
+                    let from_text = vec_text_element.is_empty();
+                    if let Some(obj) =
+                        RichText::from_parts(self.text.as_ref(), vec_text_element, phonetic_runs)
+                    {
+                        if from_text {
+                            self.text = None;
+                        }
                         self.set_rich_text(obj);
                     }
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "si")
+            Event::Eof => return Err(unexpected_eof(reader, "si"))
         );
     }
 
@@ -18,8 +18,12 @@ use super::{
     SharedStringItem,
 };
 use crate::{
+    XlsxError,
     helper::const_str::SHEET_MAIN_NS,
-    reader::driver::xml_read_loop,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -27,6 +31,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct SharedStringTable {
     shared_string_item: Vec<SharedStringItem>,
     map:                HashMap<u64, usize>,
@@ -97,14 +102,14 @@ impl SharedStringTable {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut n: usize = 0;
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"si" {
                     let mut shared_string_item = SharedStringItem::default();
-                    shared_string_item.set_attributes(reader, e);
+                    shared_string_item.set_attributes(reader, e)?;
 
                     let hash_code = shared_string_item.hash_u64();
                     self.map.insert(hash_code, n);
@@ -115,10 +120,10 @@ impl SharedStringTable {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sst")
+            Event::Eof => return Err(unexpected_eof(reader, "sst"))
         );
     }
 
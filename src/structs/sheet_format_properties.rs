@@ -22,6 +22,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SheetFormatProperties {
     base_column_width:    UInt32Value,
     custom_height:        BooleanValue,
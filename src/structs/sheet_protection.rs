@@ -22,6 +22,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SheetProtection {
     algorithm_name:        StringValue,
     hash_value:            StringValue,
@@ -20,9 +20,11 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     writer::driver::{
@@ -32,6 +34,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SheetView {
     show_grid_lines:              BooleanValue,
     tab_selected:                 BooleanValue,
@@ -283,7 +286,7 @@ impl SheetView {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, show_grid_lines, "showGridLines");
         set_string_from_xml!(self, e, tab_selected, "tabSelected");
         set_string_from_xml!(self, e, workbook_view_id, "workbookViewId");
@@ -305,7 +308,7 @@ impl SheetView {
         set_string_from_xml!(self, e, top_left_cell, "topLeftCell");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -327,10 +330,10 @@ impl SheetView {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sheetView" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sheetView")
+            Event::Eof => return Err(unexpected_eof(reader, "sheetView"))
         );
     }
 
@@ -1,12 +1,15 @@
 use crate::structs::{
     Alignment,
+    BorderStyleValues,
     Borders,
     Color,
     Fill,
     Font,
     NumberingFormat,
+    PatternFill,
     PatternValues,
     Protection,
+    StringValue,
     UInt32Value,
 };
 
@@ -76,6 +79,7 @@ use crate::structs::{
 ///     .set_argb(Color::COLOR_RED);
 /// ```
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     font:             Option<Box<Font>>,
     fill:             Option<Box<Fill>>,
@@ -83,6 +87,7 @@ pub struct Style {
     alignment:        Option<Alignment>,
     numbering_format: Option<Box<NumberingFormat>>,
     format_id:        UInt32Value,
+    named_style:      StringValue,
     protection:       Option<Protection>,
 }
 impl Style {
@@ -393,6 +398,33 @@ impl Style {
         self
     }
 
+    /// Get the name of the named cell style the style is based on.
+    /// ex) `"Good"`, `"Heading 1"`
+    #[inline]
+    #[must_use]
+    pub fn named_style(&self) -> Option<&str> {
+        self.named_style
+            .has_value()
+            .then(|| self.named_style.value_str())
+    }
+
+    /// Base the style on a named cell style of the workbook.
+    /// Parts of the style that are not set are taken from the named style
+    /// when the workbook is written.
+    /// See [`Workbook::add_named_style`](crate::Workbook::add_named_style).
+    #[inline]
+    pub fn set_named_style<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.named_style.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_named_style(&mut self) -> &mut Self {
+        self.named_style.remove_value();
+        self.format_id.remove_value();
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn protection(&self) -> Option<&Protection> {
@@ -436,6 +468,57 @@ impl Style {
         self
     }
 
+    /// Apply a differential format, such as the style of a conditional
+    /// formatting rule, on top of this style.
+    pub(crate) fn apply_differential(&mut self, value: &Self) {
+        if let Some(v) = value.font() {
+            self.font_mut().apply_differential(v);
+        }
+        if let Some(v) = value.fill() {
+            match v.pattern_fill() {
+                // A solid fill of a differential format keeps its color in bgColor.
+                Some(pattern_fill)
+                    if matches!(
+                        pattern_fill.pattern_type(),
+                        PatternValues::None | PatternValues::Solid
+                    ) =>
+                {
+                    let color = pattern_fill
+                        .background_color()
+                        .filter(|v| v.has_value())
+                        .or_else(|| pattern_fill.foreground_color());
+                    if let Some(color) = color {
+                        let mut fill = PatternFill::default();
+                        fill.set_pattern_type(PatternValues::Solid)
+                            .set_foreground_color(color.clone());
+                        self.fill_mut().set_pattern_fill(fill);
+                    }
+                }
+                _ => {
+                    self.set_fill(v.clone());
+                }
+            }
+        }
+        if let Some(v) = value.borders() {
+            let borders = self.borders_mut();
+            if v.left().style() != &BorderStyleValues::None {
+                borders.set_left(v.left().clone());
+            }
+            if v.right().style() != &BorderStyleValues::None {
+                borders.set_right(v.right().clone());
+            }
+            if v.top().style() != &BorderStyleValues::None {
+                borders.set_top(v.top().clone());
+            }
+            if v.bottom().style() != &BorderStyleValues::None {
+                borders.set_bottom(v.bottom().clone());
+            }
+        }
+        if let Some(v) = value.numbering_format() {
+            self.set_numbering_format(v.clone());
+        }
+    }
+
     #[inline]
     pub(crate) fn is_empty(&self) -> bool {
         !(self.font.is_some()
@@ -443,7 +526,8 @@ impl Style {
             || self.borders.is_some()
             || self.alignment.is_some()
             || self.numbering_format.is_some()
-            || self.protection.is_some())
+            || self.protection.is_some()
+            || self.named_style.has_value())
     }
 
     // When opened in software such as Excel, it is visually blank.
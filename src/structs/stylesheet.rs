@@ -20,17 +20,25 @@ use super::{
     DifferentialFormats,
     Fills,
     Fonts,
+    CellStyle,
     NumberingFormats,
     Style,
+    TableStyle,
+    TableStyles,
+    raw::RawPassthrough,
 };
 use crate::{
+    XlsxError,
     helper::const_str::{
         MC_NS,
         SHEET_MAIN_NS,
         SHEET_MS_MAIN_NS,
         SHEETML_AC_NS,
     },
-    reader::driver::xml_read_loop,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -38,6 +46,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Stylesheet {
     numbering_formats:    NumberingFormats,
     fonts:                Fonts,
@@ -48,7 +57,9 @@ pub(crate) struct Stylesheet {
     cell_styles:          CellStyles,
     differential_formats: DifferentialFormats,
     colors:               Colors,
+    table_styles:         TableStyles,
     maked_style_list:     Vec<Style>,
+    passthrough:          RawPassthrough,
 }
 
 impl Stylesheet {
@@ -321,6 +332,17 @@ impl Stylesheet {
         self.style(id)
     }
 
+    /// Elements of the styles part that are not supported.
+    #[inline]
+    pub(crate) fn passthrough(&self) -> &RawPassthrough {
+        &self.passthrough
+    }
+
+    #[inline]
+    pub(crate) fn passthrough_mut(&mut self) -> &mut RawPassthrough {
+        &mut self.passthrough
+    }
+
     pub(crate) fn make_style(&mut self) -> &mut Self {
         for cell_format in self.cell_formats.cell_format() {
             let def_cell_format = self
@@ -406,6 +428,16 @@ impl Stylesheet {
 
         // format_id
         style.set_format_id(cell_format.format_id());
+        if cell_format.format_id() != 0 {
+            if let Some(v) = self
+                .cell_styles
+                .cell_style()
+                .iter()
+                .find(|v| v.format_id() == cell_format.format_id())
+            {
+                style.set_named_style(v.name());
+            }
+        }
 
         // alignment
         let mut apply = true;
@@ -468,11 +500,36 @@ impl Stylesheet {
         }
         let mut cell_format = CellFormat::default();
 
-        let number_format_id = self.numbering_formats.set_style(style);
-        let font_id = self.fonts.set_style(style);
-        let fill_id = self.fills.set_style(style);
-        let border_id = self.borders.set_style(style);
-        let format_id = 0;
+        let mut number_format_id = self.numbering_formats.set_style(style);
+        let mut font_id = self.fonts.set_style(style);
+        let mut fill_id = self.fills.set_style(style);
+        let mut border_id = self.borders.set_style(style);
+        let format_id = match style.named_style() {
+            Some(name) => self.named_style_format_id(name).unwrap_or(0),
+            None => style.format_id(),
+        };
+        let format_id = self
+            .cell_style_formats
+            .cell_format()
+            .get(format_id as usize)
+            .map_or(0, |_| format_id);
+
+        // parts that are not set are taken from the named style.
+        if format_id != 0 {
+            let named = &self.cell_style_formats.cell_format()[format_id as usize];
+            if style.numbering_format().is_none() {
+                number_format_id = named.number_format_id();
+            }
+            if style.font().is_none() {
+                font_id = named.font_id();
+            }
+            if style.fill().is_none() {
+                fill_id = named.fill_id();
+            }
+            if style.borders().is_none() {
+                border_id = named.border_id();
+            }
+        }
 
         cell_format.set_number_format_id(number_format_id);
         cell_format.set_font_id(font_id);
@@ -511,6 +568,95 @@ impl Stylesheet {
         index
     }
 
+    /// Add a named cell style, or replace the one with the same name.
+    /// Returns the index of its format in `cellStyleXfs`.
+    pub(crate) fn add_named_style(&mut self, name: &str, style: &Style) -> u32 {
+        if self.cell_style_formats.cell_format().is_empty() {
+            self.cell_style_formats.set_cell_format(CellFormat::default());
+            let mut cell_style = CellStyle::default();
+            cell_style.set_name("Normal").set_format_id(0).set_builtin_id(0);
+            self.cell_styles.add_cell_style(cell_style);
+        }
+
+        let mut cell_format = CellFormat::default();
+        cell_format.set_number_format_id(self.numbering_formats.set_style(style));
+        cell_format.set_font_id(self.fonts.set_style(style));
+        cell_format.set_fill_id(self.fills.set_style(style));
+        cell_format.set_border_id(self.borders.set_style(style));
+        if style.numbering_format().is_none() {
+            cell_format.set_apply_number_format(false);
+        }
+        if style.font().is_none() {
+            cell_format.set_apply_font(false);
+        }
+        if style.fill().is_none() {
+            cell_format.set_apply_fill(false);
+        }
+        if style.borders().is_none() {
+            cell_format.set_apply_border(false);
+        }
+        match style.alignment() {
+            Some(v) => {
+                cell_format.set_alignment(v.clone());
+            }
+            None => {
+                cell_format.set_apply_alignment(false);
+            }
+        }
+        match style.protection() {
+            Some(v) => {
+                cell_format.set_protection(v.clone());
+            }
+            None => {
+                cell_format.set_apply_protection(false);
+            }
+        }
+
+        if let Some(format_id) = self.named_style_format_id(name) {
+            self.cell_style_formats.cell_format_mut()[format_id as usize] = cell_format;
+            return format_id;
+        }
+
+        let format_id = num_traits::cast(self.cell_style_formats.cell_format().len()).unwrap();
+        self.cell_style_formats.set_cell_format(cell_format);
+        let mut cell_style = CellStyle::default();
+        cell_style.set_name(name).set_format_id(format_id);
+        if let Some(v) = builtin_style_id(name) {
+            cell_style.set_builtin_id(v);
+        }
+        self.cell_styles.add_cell_style(cell_style);
+        format_id
+    }
+
+    /// Get the style of a named cell style.
+    pub(crate) fn named_style(&self, name: &str) -> Option<Style> {
+        let format_id = self.named_style_format_id(name)?;
+        let cell_format = self.cell_style_formats.cell_format().get(format_id as usize)?;
+        let mut style = Style::default();
+        self.style_by_cell_format(&mut style, &CellFormat::default(), cell_format);
+        Some(style)
+    }
+
+    fn named_style_format_id(&self, name: &str) -> Option<u32> {
+        self.cell_styles
+            .cell_style()
+            .iter()
+            .find(|v| v.name().eq_ignore_ascii_case(name))
+            .map(CellStyle::format_id)
+            .filter(|v| (*v as usize) < self.cell_style_formats.cell_format().len())
+    }
+
+    #[inline]
+    pub(crate) fn table_styles(&self) -> &[TableStyle] {
+        self.table_styles.table_style()
+    }
+
+    #[inline]
+    pub(crate) fn add_table_style(&mut self, value: TableStyle) -> &mut Self {
+        self.table_styles.add_table_style(value);
+        self
+    }
+
     pub(crate) fn set_defalut_value(&mut self) -> &mut Self {
         let style = Style::default_value();
         self.set_style(&style);
@@ -523,7 +669,7 @@ impl Stylesheet {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.numbering_formats.build_in_formats();
 
         xml_read_loop!(
@@ -531,41 +677,54 @@ impl Stylesheet {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"numFmts" => {
-                        self.numbering_formats.set_attributes(reader, e);
+                        self.numbering_formats.set_attributes(reader, e)?;
                     }
                     b"fonts" => {
-                        self.fonts.set_attributes(reader, e);
+                        self.fonts.set_attributes(reader, e)?;
                     }
                     b"fills" => {
-                        self.fills.set_attributes(reader, e);
+                        self.fills.set_attributes(reader, e)?;
                     }
                     b"borders" => {
-                        self.borders.set_attributes(reader, e);
+                        self.borders.set_attributes(reader, e)?;
                     }
                     b"cellStyleXfs" => {
-                        self.cell_style_formats.set_attributes(reader, e);
+                        self.cell_style_formats.set_attributes(reader, e)?;
                     }
                     b"cellXfs" => {
-                        self.cell_formats.set_attributes(reader, e);
+                        self.cell_formats.set_attributes(reader, e)?;
                     }
                     b"cellStyles" => {
-                        self.cell_styles.set_attributes(reader, e);
+                        self.cell_styles.set_attributes(reader, e)?;
                     }
                     b"dxfs" => {
-                        self.differential_formats.set_attributes(reader, e);
+                        self.differential_formats.set_attributes(reader, e)?;
                     }
                     b"colors" => {
-                        self.colors.set_attributes(reader, e);
+                        self.colors.set_attributes(reader, e)?;
+                    }
+                    b"tableStyles" => {
+                        self.table_styles.set_attributes(
+                            reader,
+                            e,
+                            &self.differential_formats,
+                            false,
+                        )?;
                     }
                     _ => (),
                 }
             },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"tableStyles" {
+                    self.table_styles.set_attributes(reader, e, &self.differential_formats, true)?;
+                }
+            },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"styleSheet" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "styleSheet")
+            Event::Eof => return Err(unexpected_eof(reader, "styleSheet"))
         );
     }
 
@@ -604,23 +763,21 @@ impl Stylesheet {
         // cellStyles
         self.cell_styles.write_to(writer);
 
+        // the elements of table styles are written as dxf.
+        let mut differential_formats = self.differential_formats.clone();
+        let mut table_styles = self.table_styles.clone();
+        for table_style in table_styles.table_style_mut() {
+            table_style.set_differential_formats(&mut differential_formats);
+        }
+
         // dxfs
-        self.differential_formats.write_to(writer);
+        differential_formats.write_to(writer);
 
         // colors
         self.colors.write_to(writer);
 
         // tableStyles
-        write_start_tag(
-            writer,
-            "tableStyles",
-            vec![
-                ("count", "0").into(),
-                ("defaultTableStyle", "TableStyleMedium2").into(),
-                ("defaultPivotStyle", "PivotStyleMedium9").into(),
-            ],
-            true,
-        );
+        table_styles.write_to(writer);
 
         // extLst
         write_start_tag(writer, "extLst", vec![], false);
@@ -651,3 +808,43 @@ impl Stylesheet {
         write_end_tag(writer, "styleSheet");
     }
 }
+
+fn builtin_style_id(name: &str) -> Option<u32> {
+    const ACCENTS: [&str; 4] = ["", "20% - ", "40% - ", "60% - "];
+    let id = match name {
+        "Normal" => 0,
+        "Comma" => 3,
+        "Currency" => 4,
+        "Percent" => 5,
+        "Comma [0]" => 6,
+        "Currency [0]" => 7,
+        "Hyperlink" => 8,
+        "Followed Hyperlink" => 9,
+        "Note" => 10,
+        "Warning Text" => 11,
+        "Title" => 15,
+        "Heading 1" => 16,
+        "Heading 2" => 17,
+        "Heading 3" => 18,
+        "Heading 4" => 19,
+        "Input" => 20,
+        "Output" => 21,
+        "Calculation" => 22,
+        "Check Cell" => 23,
+        "Linked Cell" => 24,
+        "Total" => 25,
+        "Good" => 26,
+        "Bad" => 27,
+        "Neutral" => 28,
+        "Explanatory Text" => 53,
+        _ => {
+            // Accent1 = 29, 20% - Accent1 = 30, ... 60% - Accent6 = 52
+            return (1..=6u32).find_map(|n| {
+                ACCENTS.iter().zip(0u32..).find_map(|(prefix, i)| {
+                    (name == format!("{prefix}Accent{n}")).then_some(29 + (n - 1) * 4 + i)
+                })
+            });
+        }
+    };
+    Some(id)
+}
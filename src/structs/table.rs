@@ -1,16 +1,26 @@
 use super::{
+    AutoFilter,
     BooleanValue,
     EnumValue,
+    Range,
     StringValue,
+    Style,
     TotalsRowFunctionValues,
     UInt32Value,
     coordinate::Coordinate,
 };
-use crate::helper::coordinate::CellCoordinates;
+use crate::{
+    helper::{
+        coordinate::CellCoordinates,
+        structured_reference::escape_column,
+    },
+    traits::AdjustmentCoordinate,
+};
 
 // use reader::driver::*;
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table {
     name:             Box<str>,
     area:             (Coordinate, Coordinate),
@@ -19,6 +29,10 @@ pub struct Table {
     style_info:       Option<Box<TableStyleInfo>>,
     totals_row_shown: BooleanValue,
     totals_row_count: UInt32Value,
+    auto_filter:      AutoFilter,
+    header_row_style: Option<Box<Style>>,
+    data_style:       Option<Box<Style>>,
+    totals_row_style: Option<Box<Style>>,
 }
 impl Table {
     #[inline]
@@ -37,6 +51,10 @@ impl Table {
             style_info:       None,
             totals_row_shown: BooleanValue::default(),
             totals_row_count: UInt32Value::default(),
+            auto_filter:      AutoFilter::default(),
+            header_row_style: None,
+            data_style:       None,
+            totals_row_style: None,
         }
     }
 
@@ -240,6 +258,171 @@ impl Table {
         self.totals_row_count.set_value_string(value);
     }
 
+    /// Get the filter criteria of the table.
+    /// The range of the filter follows the area of the table when written.
+    #[inline]
+    #[must_use]
+    pub fn auto_filter(&self) -> &AutoFilter {
+        &self.auto_filter
+    }
+
+    #[inline]
+    pub fn auto_filter_mut(&mut self) -> &mut AutoFilter {
+        &mut self.auto_filter
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn header_row_style(&self) -> Option<&Style> {
+        self.header_row_style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_header_row_style(&mut self, value: Style) {
+        self.header_row_style = Some(Box::new(value));
+    }
+
+    #[inline]
+    pub fn remove_header_row_style(&mut self) {
+        self.header_row_style = None;
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn data_style(&self) -> Option<&Style> {
+        self.data_style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_data_style(&mut self, value: Style) {
+        self.data_style = Some(Box::new(value));
+    }
+
+    #[inline]
+    pub fn remove_data_style(&mut self) {
+        self.data_style = None;
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn totals_row_style(&self) -> Option<&Style> {
+        self.totals_row_style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_totals_row_style(&mut self, value: Style) {
+        self.totals_row_style = Some(Box::new(value));
+    }
+
+    #[inline]
+    pub fn remove_totals_row_style(&mut self) {
+        self.totals_row_style = None;
+    }
+
+    /// Get the index of a column by its name. The name is not case sensitive.
+    #[inline]
+    #[must_use]
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|v| v.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Whether the table has a totals row.
+    #[inline]
+    #[must_use]
+    pub fn has_totals_row(&self) -> bool {
+        self.totals_row_count.value() > 0
+    }
+
+    /// Show or hide the totals row, growing or shrinking the area by a row.
+    /// When shown for the first time without any totals row settings, the
+    /// first column is labelled `Total` and the last column is summed.
+    ///
+    /// This only changes the table.
+    /// [`Worksheet::set_table_totals_row`](crate::Worksheet::set_table_totals_row)
+    /// also writes the cells of the totals row.
+    pub fn set_totals_row(&mut self, value: bool) -> &mut Self {
+        if value == self.has_totals_row() {
+            return self;
+        }
+        let end_row = self.area.1.row_num();
+        if value {
+            let is_unset = self
+                .columns
+                .iter()
+                .all(|v| !v.has_totals_row_label() && !v.has_totals_row_function());
+            if is_unset {
+                if let Some(v) = self.columns.first_mut() {
+                    v.set_totals_row_label("Total");
+                }
+                if self.columns.len() > 1 {
+                    if let Some(v) = self.columns.last_mut() {
+                        v.set_totals_row_function(TotalsRowFunctionValues::Sum);
+                    }
+                }
+            }
+            self.area.1.set_row_num(end_row + 1);
+            self.totals_row_count.set_value(1);
+        } else {
+            self.area.1.set_row_num(end_row - 1);
+            self.totals_row_count.remove_value();
+        }
+        self
+    }
+
+    /// Get the formula of the totals row cell of a column.
+    /// ex) `SUBTOTAL(109,Table1[Amount])`
+    /// # Arguments
+    /// * `index` - Index of the column, from 0.
+    #[must_use]
+    pub fn totals_row_formula(&self, index: usize) -> Option<String> {
+        let column = self.columns.get(index)?;
+        let function_num = match column.totals_row_function() {
+            TotalsRowFunctionValues::Average => 101,
+            TotalsRowFunctionValues::Count => 103,
+            TotalsRowFunctionValues::CountNumbers => 102,
+            TotalsRowFunctionValues::Maximum => 104,
+            TotalsRowFunctionValues::Minimum => 105,
+            TotalsRowFunctionValues::StandardDeviation => 107,
+            TotalsRowFunctionValues::Sum => 109,
+            TotalsRowFunctionValues::Variance => 110,
+            TotalsRowFunctionValues::Custom => {
+                return column.totals_row_formula().map(str::to_string);
+            }
+            TotalsRowFunctionValues::None => return None,
+        };
+        Some(format!(
+            "SUBTOTAL({function_num},{}[{}])",
+            self.name,
+            escape_column(column.name())
+        ))
+    }
+
+    /// (This method is crate only.)
+    /// Get the range of the table without its totals row.
+    pub(crate) fn auto_filter_range(&self) -> String {
+        let mut end = self.area.1.clone();
+        end.set_row_num(end.row_num() - self.totals_row_count.value());
+        format!("{}:{}", self.area.0, end)
+    }
+
+    #[inline]
+    pub(crate) fn columns_crate_mut(&mut self) -> &mut Vec<TableColumn> {
+        &mut self.columns
+    }
+
+    #[inline]
+    pub(crate) fn area_mut(&mut self) -> &mut (Coordinate, Coordinate) {
+        &mut self.area
+    }
+
+    #[inline]
+    pub(crate) fn set_name_crate(&mut self, name: &str) {
+        self.name = name.into();
+        self.display_name = name.into();
+    }
+
     #[inline]
     fn cell_coord_to_coord<T>(cc: T) -> Coordinate
     where
@@ -253,12 +436,75 @@ impl Table {
     }
 }
 
+impl AdjustmentCoordinate for Table {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        for coordinate in [&mut self.area.0, &mut self.area.1] {
+            coordinate.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        // columns of the table that are removed
+        let start_col = self.area.0.col_num();
+        if offset_col_num > 0 {
+            let mut index = start_col;
+            self.columns.retain(|_| {
+                let is_keep = index < root_col_num || index >= root_col_num + offset_col_num;
+                index += 1;
+                is_keep
+            });
+        }
+
+        let mut range = Range::default();
+        range.set_range(format!("{}:{}", self.area.0, self.area.1));
+        if range.shrink_for_remove(root_col_num, offset_col_num, root_row_num, offset_row_num) {
+            self.set_area((range.coordinate_start().as_str(), range.coordinate_end().as_str()));
+        }
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) -> bool {
+        let mut range = Range::default();
+        range.set_range(format!("{}:{}", self.area.0, self.area.1));
+        !range.shrink_for_remove(root_col_num, offset_col_num, root_row_num, offset_row_num)
+    }
+}
+
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableColumn {
     name:                      String,
     totals_row_label:          StringValue,
     totals_row_function:       EnumValue<TotalsRowFunctionValues>,
     calculated_column_formula: Option<String>,
+    totals_row_formula:        Option<String>,
+    header_row_style:          Option<Box<Style>>,
+    data_style:                Option<Box<Style>>,
+    totals_row_style:          Option<Box<Style>>,
 }
 impl TableColumn {
     #[inline]
@@ -269,6 +515,10 @@ impl TableColumn {
             totals_row_label:          StringValue::default(),
             totals_row_function:       EnumValue::default(),
             calculated_column_formula: None,
+            totals_row_formula:        None,
+            header_row_style:          None,
+            data_style:                None,
+            totals_row_style:          None,
         }
     }
 
@@ -291,7 +541,6 @@ impl TableColumn {
     }
 
     #[inline]
-    #[allow(dead_code)]
     pub(crate) fn has_totals_row_label(&self) -> bool {
         self.totals_row_label.has_value()
     }
@@ -331,7 +580,6 @@ impl TableColumn {
     }
 
     #[inline]
-    #[allow(dead_code)]
     pub(crate) fn has_totals_row_function(&self) -> bool {
         self.totals_row_function.has_value()
     }
@@ -383,13 +631,98 @@ impl TableColumn {
         self.calculated_column_formula()
     }
 
+    /// Set the formula filled into the cells of the column.
+    /// ex) `[@Qty]*[@Price]`
     #[inline]
-    pub(crate) fn set_calculated_column_formula(&mut self, value: String) {
+    pub fn set_calculated_column_formula(&mut self, value: String) {
         self.calculated_column_formula = Some(value);
     }
+
+    #[inline]
+    pub fn remove_calculated_column_formula(&mut self) {
+        self.calculated_column_formula = None;
+    }
+
+    /// Get the formula of the totals row cell when the totals row function
+    /// is `Custom`.
+    #[inline]
+    #[must_use]
+    pub fn totals_row_formula(&self) -> Option<&str> {
+        self.totals_row_formula.as_deref()
+    }
+
+    #[inline]
+    pub fn set_totals_row_formula(&mut self, value: String) {
+        self.totals_row_formula = Some(value);
+    }
+
+    #[inline]
+    pub fn remove_totals_row_formula(&mut self) {
+        self.totals_row_formula = None;
+    }
+
+    /// (This method is crate only.)
+    /// Rewrite the calculated column formula and the totals row formula.
+    pub(crate) fn map_formulas<F: Fn(&str) -> String>(&mut self, f: F) {
+        if let Some(v) = &mut self.calculated_column_formula {
+            *v = f(v);
+        }
+        if let Some(v) = &mut self.totals_row_formula {
+            *v = f(v);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn header_row_style(&self) -> Option<&Style> {
+        self.header_row_style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_header_row_style(&mut self, value: Style) {
+        self.header_row_style = Some(Box::new(value));
+    }
+
+    #[inline]
+    pub fn remove_header_row_style(&mut self) {
+        self.header_row_style = None;
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn data_style(&self) -> Option<&Style> {
+        self.data_style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_data_style(&mut self, value: Style) {
+        self.data_style = Some(Box::new(value));
+    }
+
+    #[inline]
+    pub fn remove_data_style(&mut self) {
+        self.data_style = None;
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn totals_row_style(&self) -> Option<&Style> {
+        self.totals_row_style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_totals_row_style(&mut self, value: Style) {
+        self.totals_row_style = Some(Box::new(value));
+    }
+
+    #[inline]
+    pub fn remove_totals_row_style(&mut self) {
+        self.totals_row_style = None;
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableStyleInfo {
     name:             String,
     show_first_col:   ShowColumn,
@@ -399,12 +732,14 @@ pub struct TableStyleInfo {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShowColumn {
     Show,
     Hide,
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShowStripes {
     Show,
     Hide,
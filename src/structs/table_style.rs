@@ -0,0 +1,192 @@
+// tableStyle
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    DifferentialFormats,
+    StringValue,
+    TableStyleElement,
+    TableStyleElementValues,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// A custom table style of the workbook.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+///
+/// let mut header = Style::default();
+/// header.font_mut().set_bold(true);
+///
+/// let mut element = TableStyleElement::default();
+/// element
+///     .set_type(TableStyleElementValues::HeaderRow)
+///     .set_style(header);
+///
+/// let mut table_style = TableStyle::default();
+/// table_style.set_name("Brand Table").add_element(element);
+///
+/// let mut book = new_file();
+/// book.add_table_style(table_style);
+/// ```
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableStyle {
+    name:     StringValue,
+    pivot:    BooleanValue,
+    table:    BooleanValue,
+    elements: Vec<TableStyleElement>,
+}
+
+impl TableStyle {
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    /// Whether the style can be applied to pivot tables. Defaults to `true`.
+    #[inline]
+    #[must_use]
+    pub fn pivot(&self) -> bool {
+        !self.pivot.has_value() || self.pivot.value()
+    }
+
+    #[inline]
+    pub fn set_pivot(&mut self, value: bool) -> &mut Self {
+        self.pivot.set_value(value);
+        self
+    }
+
+    /// Whether the style can be applied to tables. Defaults to `true`.
+    #[inline]
+    #[must_use]
+    pub fn table(&self) -> bool {
+        !self.table.has_value() || self.table.value()
+    }
+
+    #[inline]
+    pub fn set_table(&mut self, value: bool) -> &mut Self {
+        self.table.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn elements(&self) -> &[TableStyleElement] {
+        &self.elements
+    }
+
+    #[inline]
+    pub fn elements_mut(&mut self) -> &mut Vec<TableStyleElement> {
+        &mut self.elements
+    }
+
+    /// Get the element for a part of the table.
+    #[inline]
+    #[must_use]
+    pub fn element(&self, r#type: &TableStyleElementValues) -> Option<&TableStyleElement> {
+        self.elements.iter().find(|v| v.get_type() == r#type)
+    }
+
+    /// Add an element. An element for the same part of the table is replaced.
+    #[inline]
+    pub fn add_element(&mut self, value: TableStyleElement) -> &mut Self {
+        self.elements.retain(|v| v.get_type() != value.get_type());
+        self.elements.push(value);
+        self
+    }
+
+    pub(crate) fn set_differential_formats(
+        &mut self,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        for element in &mut self.elements {
+            element.set_differential_format(differential_formats);
+        }
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, pivot, "pivot");
+        set_string_from_xml!(self, e, table, "table");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"tableStyleElement" {
+                    let mut obj = TableStyleElement::default();
+                    obj.set_attributes(reader, e, differential_formats);
+                    self.elements.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"tableStyle" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "tableStyle"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // tableStyle
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("name", self.name.value_str()).into());
+        if !self.pivot() {
+            attributes.push(("pivot", "0").into());
+        }
+        if !self.table() {
+            attributes.push(("table", "0").into());
+        }
+        let count = self.elements.len().to_string();
+        attributes.push(("count", &count).into());
+        write_start_tag(writer, "tableStyle", attributes, self.elements.is_empty());
+
+        if !self.elements.is_empty() {
+            // tableStyleElement
+            for element in &self.elements {
+                element.write_to(writer);
+            }
+
+            write_end_tag(writer, "tableStyle");
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// tableStyleElement
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    DifferentialFormats,
+    EnumValue,
+    Style,
+    TableStyleElementValues,
+    UInt32Value,
+};
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+/// The formatting of one part of a table, such as its header row.
+/// The style is stored as a differential format.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableStyleElement {
+    r#type: EnumValue<TableStyleElementValues>,
+    size:   UInt32Value,
+    style:  Style,
+    dxf_id: UInt32Value,
+}
+
+impl TableStyleElement {
+    #[inline]
+    #[must_use]
+    pub fn get_type(&self) -> &TableStyleElementValues {
+        self.r#type.value()
+    }
+
+    #[inline]
+    pub fn set_type(&mut self, value: TableStyleElementValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    /// Get the number of rows or columns a stripe spans. Defaults to 1.
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> u32 {
+        if self.size.has_value() {
+            self.size.value()
+        } else {
+            1
+        }
+    }
+
+    #[inline]
+    pub fn set_size(&mut self, value: u32) -> &mut Self {
+        self.size.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
+
+    #[inline]
+    pub fn style_mut(&mut self) -> &mut Style {
+        &mut self.style
+    }
+
+    #[inline]
+    pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Register the style with the differential formats and keep its id.
+    #[inline]
+    pub(crate) fn set_differential_format(&mut self, differential_formats: &mut DifferentialFormats) {
+        self.dxf_id.set_value(differential_formats.set_style(&self.style));
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+    ) {
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, size, "size");
+        if let Some(v) = get_attribute(e, b"dxfId") {
+            let id = v.parse::<usize>().unwrap_or_default();
+            if id < differential_formats.differential_format().len() {
+                self.style = differential_formats.style(id);
+            }
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // tableStyleElement
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("type", self.r#type.value_string()).into());
+        let size = self.size.value_string();
+        if self.size.has_value() {
+            attributes.push(("size", &size).into());
+        }
+        let dxf_id = self.dxf_id.value_string();
+        if self.dxf_id.has_value() {
+            attributes.push(("dxfId", &dxf_id).into());
+        }
+        write_start_tag(writer, "tableStyleElement", attributes, true);
+    }
+}
@@ -0,0 +1,114 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TableStyleElementValues {
+    WholeTable,
+    HeaderRow,
+    TotalRow,
+    FirstColumn,
+    LastColumn,
+    FirstRowStripe,
+    SecondRowStripe,
+    FirstColumnStripe,
+    SecondColumnStripe,
+    FirstHeaderCell,
+    LastHeaderCell,
+    FirstTotalCell,
+    LastTotalCell,
+    FirstSubtotalColumn,
+    SecondSubtotalColumn,
+    ThirdSubtotalColumn,
+    FirstSubtotalRow,
+    SecondSubtotalRow,
+    ThirdSubtotalRow,
+    BlankRow,
+    FirstColumnSubheading,
+    SecondColumnSubheading,
+    ThirdColumnSubheading,
+    FirstRowSubheading,
+    SecondRowSubheading,
+    ThirdRowSubheading,
+    PageFieldLabels,
+    PageFieldValues,
+}
+impl Default for TableStyleElementValues {
+    #[inline]
+    fn default() -> Self {
+        Self::WholeTable
+    }
+}
+impl EnumTrait for TableStyleElementValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::WholeTable => "wholeTable",
+            Self::HeaderRow => "headerRow",
+            Self::TotalRow => "totalRow",
+            Self::FirstColumn => "firstColumn",
+            Self::LastColumn => "lastColumn",
+            Self::FirstRowStripe => "firstRowStripe",
+            Self::SecondRowStripe => "secondRowStripe",
+            Self::FirstColumnStripe => "firstColumnStripe",
+            Self::SecondColumnStripe => "secondColumnStripe",
+            Self::FirstHeaderCell => "firstHeaderCell",
+            Self::LastHeaderCell => "lastHeaderCell",
+            Self::FirstTotalCell => "firstTotalCell",
+            Self::LastTotalCell => "lastTotalCell",
+            Self::FirstSubtotalColumn => "firstSubtotalColumn",
+            Self::SecondSubtotalColumn => "secondSubtotalColumn",
+            Self::ThirdSubtotalColumn => "thirdSubtotalColumn",
+            Self::FirstSubtotalRow => "firstSubtotalRow",
+            Self::SecondSubtotalRow => "secondSubtotalRow",
+            Self::ThirdSubtotalRow => "thirdSubtotalRow",
+            Self::BlankRow => "blankRow",
+            Self::FirstColumnSubheading => "firstColumnSubheading",
+            Self::SecondColumnSubheading => "secondColumnSubheading",
+            Self::ThirdColumnSubheading => "thirdColumnSubheading",
+            Self::FirstRowSubheading => "firstRowSubheading",
+            Self::SecondRowSubheading => "secondRowSubheading",
+            Self::ThirdRowSubheading => "thirdRowSubheading",
+            Self::PageFieldLabels => "pageFieldLabels",
+            Self::PageFieldValues => "pageFieldValues",
+        }
+    }
+}
+impl FromStr for TableStyleElementValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "wholeTable" => Ok(Self::WholeTable),
+            "headerRow" => Ok(Self::HeaderRow),
+            "totalRow" => Ok(Self::TotalRow),
+            "firstColumn" => Ok(Self::FirstColumn),
+            "lastColumn" => Ok(Self::LastColumn),
+            "firstRowStripe" => Ok(Self::FirstRowStripe),
+            "secondRowStripe" => Ok(Self::SecondRowStripe),
+            "firstColumnStripe" => Ok(Self::FirstColumnStripe),
+            "secondColumnStripe" => Ok(Self::SecondColumnStripe),
+            "firstHeaderCell" => Ok(Self::FirstHeaderCell),
+            "lastHeaderCell" => Ok(Self::LastHeaderCell),
+            "firstTotalCell" => Ok(Self::FirstTotalCell),
+            "lastTotalCell" => Ok(Self::LastTotalCell),
+            "firstSubtotalColumn" => Ok(Self::FirstSubtotalColumn),
+            "secondSubtotalColumn" => Ok(Self::SecondSubtotalColumn),
+            "thirdSubtotalColumn" => Ok(Self::ThirdSubtotalColumn),
+            "firstSubtotalRow" => Ok(Self::FirstSubtotalRow),
+            "secondSubtotalRow" => Ok(Self::SecondSubtotalRow),
+            "thirdSubtotalRow" => Ok(Self::ThirdSubtotalRow),
+            "blankRow" => Ok(Self::BlankRow),
+            "firstColumnSubheading" => Ok(Self::FirstColumnSubheading),
+            "secondColumnSubheading" => Ok(Self::SecondColumnSubheading),
+            "thirdColumnSubheading" => Ok(Self::ThirdColumnSubheading),
+            "firstRowSubheading" => Ok(Self::FirstRowSubheading),
+            "secondRowSubheading" => Ok(Self::SecondRowSubheading),
+            "thirdRowSubheading" => Ok(Self::ThirdRowSubheading),
+            "pageFieldLabels" => Ok(Self::PageFieldLabels),
+            "pageFieldValues" => Ok(Self::PageFieldValues),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// tableStyles
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    DifferentialFormats,
+    StringValue,
+    TableStyle,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        unexpected_eof,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct TableStyles {
+    default_table_style: StringValue,
+    default_pivot_style: StringValue,
+    table_style:         Vec<TableStyle>,
+}
+
+impl TableStyles {
+    #[inline]
+    pub(crate) fn table_style(&self) -> &[TableStyle] {
+        &self.table_style
+    }
+
+    #[inline]
+    pub(crate) fn table_style_mut(&mut self) -> &mut Vec<TableStyle> {
+        &mut self.table_style
+    }
+
+    /// Add a table style. A style with the same name is replaced.
+    #[inline]
+    pub(crate) fn add_table_style(&mut self, value: TableStyle) -> &mut Self {
+        self.table_style.retain(|v| v.name() != value.name());
+        self.table_style.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, default_table_style, "defaultTableStyle");
+        set_string_from_xml!(self, e, default_pivot_style, "defaultPivotStyle");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"tableStyle" {
+                    let mut obj = TableStyle::default();
+                    obj.set_attributes(reader, e, differential_formats, false)?;
+                    self.table_style.push(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"tableStyle" {
+                    let mut obj = TableStyle::default();
+                    obj.set_attributes(reader, e, differential_formats, true)?;
+                    self.table_style.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"tableStyles" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(unexpected_eof(reader, "tableStyles"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // tableStyles
+        let count = self.table_style.len().to_string();
+        let default_table_style = self.default_table_style.value().unwrap_or("TableStyleMedium2");
+        let default_pivot_style = self.default_pivot_style.value().unwrap_or("PivotStyleMedium9");
+        write_start_tag(
+            writer,
+            "tableStyles",
+            vec![
+                ("count", &count).into(),
+                ("defaultTableStyle", default_table_style).into(),
+                ("defaultPivotStyle", default_pivot_style).into(),
+            ],
+            self.table_style.is_empty(),
+        );
+
+        if !self.table_style.is_empty() {
+            // tableStyle
+            for table_style in &self.table_style {
+                table_style.write_to(writer);
+            }
+
+            write_end_tag(writer, "tableStyles");
+        }
+    }
+}
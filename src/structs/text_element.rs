@@ -13,9 +13,15 @@ use quick_xml::{
 use super::{
     Font,
     Text,
+    UnderlineValues,
+    VerticalAlignmentRunValues,
 };
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        unexpected_eof,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -23,6 +29,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextElement {
     text:           Text,
     run_properties: Option<Box<Font>>,
@@ -125,6 +132,57 @@ impl TextElement {
         self.set_run_properties(value)
     }
 
+    /// (This method is crate only.)
+    /// Convert to Markdown. Line breaks become hard breaks.
+    pub(crate) fn to_markdown(&self) -> String {
+        let mut open = String::new();
+        let mut close = String::new();
+        if let Some(font) = self.font() {
+            let mut wrap = |start: &str, end: &str| {
+                open.push_str(start);
+                close.insert_str(0, end);
+            };
+            if font.bold() {
+                wrap("**", "**");
+            }
+            if font.italic() {
+                wrap("*", "*");
+            }
+            if font.strikethrough() {
+                wrap("~~", "~~");
+            }
+            if *font.font_underline().val() != UnderlineValues::None {
+                wrap("<u>", "</u>");
+            }
+            match font.vertical_text_alignment().val() {
+                VerticalAlignmentRunValues::Superscript => wrap("<sup>", "</sup>"),
+                VerticalAlignmentRunValues::Subscript => wrap("<sub>", "</sub>"),
+                VerticalAlignmentRunValues::Baseline => (),
+            }
+        }
+
+        let lines: Vec<String> = self
+            .text()
+            .split('\n')
+            .map(|line| {
+                let body = line.trim();
+                if body.is_empty() || open.is_empty() {
+                    return escape_markdown(line);
+                }
+                // Emphasis must not start or end with whitespace.
+                let start = line.len() - line.trim_start().len();
+                let end = line.trim_end().len();
+                format!(
+                    "{}{open}{}{close}{}",
+                    &line[..start],
+                    escape_markdown(body),
+                    &line[end..]
+                )
+            })
+            .collect();
+        lines.join("  \n")
+    }
+
     pub(crate) fn hash_code(&self) -> String {
         crate::helper::utils::md5_hash(format!(
             "{}{}",
@@ -149,19 +207,19 @@ impl TextElement {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.text = obj;
                     }
                     b"rPr" => {
                         let mut obj = Font::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_run_properties(obj);
                     }
                     _ => (),
@@ -169,10 +227,10 @@ impl TextElement {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"r" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "r")
+            Event::Eof => return Err(unexpected_eof(reader, "r"))
         );
     }
 
@@ -191,3 +249,14 @@ impl TextElement {
         write_end_tag(writer, "r");
     }
 }
+
+fn escape_markdown(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '*' | '_' | '~' | '`' | '[' | ']' | '<' | '>' | '#') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
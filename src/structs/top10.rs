@@ -0,0 +1,115 @@
+// top10
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    BooleanValue,
+    DoubleValue,
+};
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+/// Show the top or bottom items of a column.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Top10 {
+    top:        BooleanValue,
+    percent:    BooleanValue,
+    val:        DoubleValue,
+    filter_val: DoubleValue,
+}
+
+impl Top10 {
+    /// Whether the top items are shown. Defaults to `true`.
+    #[inline]
+    #[must_use]
+    pub fn top(&self) -> bool {
+        !self.top.has_value() || self.top.value()
+    }
+
+    #[inline]
+    pub fn set_top(&mut self, value: bool) -> &mut Self {
+        self.top.set_value(value);
+        self
+    }
+
+    /// Whether `val` is a percentage of the items.
+    #[inline]
+    #[must_use]
+    pub fn percent(&self) -> bool {
+        self.percent.value()
+    }
+
+    #[inline]
+    pub fn set_percent(&mut self, value: bool) -> &mut Self {
+        self.percent.set_value(value);
+        self
+    }
+
+    /// Get the number, or the percentage, of items shown.
+    #[inline]
+    #[must_use]
+    pub fn val(&self) -> f64 {
+        self.val.value()
+    }
+
+    #[inline]
+    pub fn set_val(&mut self, value: f64) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    /// Get the value of the last item shown, as saved by the application.
+    #[inline]
+    #[must_use]
+    pub fn filter_val(&self) -> Option<f64> {
+        self.filter_val.has_value().then(|| self.filter_val.value())
+    }
+
+    #[inline]
+    pub fn set_filter_val(&mut self, value: f64) -> &mut Self {
+        self.filter_val.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, top, "top");
+        set_string_from_xml!(self, e, percent, "percent");
+        set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, filter_val, "filterVal");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // top10
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if !self.top() {
+            attributes.push(("top", "0").into());
+        }
+        if self.percent.value() {
+            attributes.push(("percent", "1").into());
+        }
+        let val = self.val.value_string();
+        attributes.push(("val", &val).into());
+        let filter_val = self.filter_val.value_string();
+        if self.filter_val.has_value() {
+            attributes.push(("filterVal", &filter_val).into());
+        }
+        write_start_tag(writer, "top10", attributes, true);
+    }
+}
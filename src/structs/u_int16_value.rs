@@ -1,4 +1,5 @@
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UInt16Value {
     value: Option<u16>,
 }
@@ -33,7 +34,7 @@ impl UInt16Value {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut UInt16Value {
-        self.set_value(value.into().parse::<u16>().unwrap())
+        self.set_value(value.into().parse::<u16>().unwrap_or_default())
     }
 
     #[inline]
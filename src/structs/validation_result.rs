@@ -0,0 +1,114 @@
+use super::DataValidationValues;
+
+/// The outcome of checking a cell against the data validation of its
+/// worksheet.
+///
+/// Returned by [`Worksheet::validate_cell`](crate::Worksheet::validate_cell),
+/// [`Worksheet::validate_all`](crate::Worksheet::validate_all) and
+/// [`Workbook::validate_all`](crate::Workbook::validate_all).
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationResult {
+    sheet_name:      String,
+    coordinate:      String,
+    value:           String,
+    validation_type: Option<DataValidationValues>,
+    is_valid:        bool,
+    is_evaluated:    bool,
+    error_title:     String,
+    error_message:   String,
+}
+
+impl ValidationResult {
+    pub(crate) fn new(sheet_name: &str, coordinate: String, value: String) -> Self {
+        Self {
+            sheet_name: sheet_name.to_string(),
+            coordinate,
+            value,
+            is_valid: true,
+            is_evaluated: true,
+            ..Default::default()
+        }
+    }
+
+    /// Get the name of the worksheet.
+    #[inline]
+    #[must_use]
+    pub fn sheet_name(&self) -> &str {
+        &self.sheet_name
+    }
+
+    /// Get the coordinate of the cell. ex) "A1"
+    #[inline]
+    #[must_use]
+    pub fn coordinate(&self) -> &str {
+        &self.coordinate
+    }
+
+    /// Get the formatted value of the cell.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the type of the rule the cell was checked against.
+    /// `None` when no rule applies to the cell.
+    #[inline]
+    #[must_use]
+    pub fn validation_type(&self) -> Option<&DataValidationValues> {
+        self.validation_type.as_ref()
+    }
+
+    /// Whether the value of the cell satisfies the rule.
+    /// Cells without a rule, and rules that could not be evaluated, are
+    /// valid.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    /// Whether the rule could be evaluated.
+    /// Formulas using functions the evaluator does not support, and
+    /// references to other worksheets when checking a worksheet on its own,
+    /// are not evaluated.
+    #[inline]
+    #[must_use]
+    pub fn is_evaluated(&self) -> bool {
+        self.is_evaluated
+    }
+
+    /// Get the error title of the rule.
+    #[inline]
+    #[must_use]
+    pub fn error_title(&self) -> &str {
+        &self.error_title
+    }
+
+    /// Get the error message of the rule.
+    #[inline]
+    #[must_use]
+    pub fn error_message(&self) -> &str {
+        &self.error_message
+    }
+
+    pub(crate) fn set_rule(
+        &mut self,
+        validation_type: DataValidationValues,
+        error_title: &str,
+        error_message: &str,
+    ) -> &mut Self {
+        self.validation_type = Some(validation_type);
+        self.error_title = error_title.to_string();
+        self.error_message = error_message.to_string();
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_valid(&mut self, value: Option<bool>) -> &mut Self {
+        self.is_valid = value.unwrap_or(true);
+        self.is_evaluated = value.is_some();
+        self
+    }
+}
@@ -7,8 +7,10 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        required_attribute,
         set_string_from_xml,
     },
     structs::{
@@ -21,6 +23,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fill {
     color:      StringValue,
     color_2:    StringValue,
@@ -139,10 +142,10 @@ impl Fill {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, color, "color");
         set_string_from_xml!(self, e, color_2, "color2");
         set_string_from_xml!(self, e, on, "on");
@@ -150,14 +153,15 @@ impl Fill {
 
         if let Some(relid) = get_attribute(e, b"o:relid") {
             if let Some(rel) = drawing_relationships {
-                let relationship = rel.relationship_by_rid(&relid);
+                let relationship = rel.relationship_by_rid(&relid)?;
                 let mut obj = MediaObject::default();
-                obj.set_image_title(get_attribute(e, b"o:title").unwrap());
+                obj.set_image_title(required_attribute(reader, e, "o:title")?);
                 obj.set_image_name(relationship.raw_file().file_name());
                 obj.set_image_data(relationship.raw_file().file_data());
                 self.set_image(obj);
             }
         }
+        Ok(())
     }
 
     /// We allow the `unused_assignments` lint here, because the compiler is not
@@ -7,6 +7,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -20,6 +21,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageData {
     image: Option<MediaObject>,
     title: StringValue,
@@ -77,10 +79,10 @@ impl ImageData {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(relid) = get_attribute(e, b"o:relid") {
             if let Some(rel) = drawing_relationships {
-                let relationship = rel.relationship_by_rid(&relid);
+                let relationship = rel.relationship_by_rid(&relid)?;
                 let mut obj = MediaObject::default();
                 obj.set_image_name(relationship.raw_file().file_name());
                 obj.set_image_data(relationship.raw_file().file_data());
@@ -89,6 +91,7 @@ impl ImageData {
         }
 
         set_string_from_xml!(self, e, title, "o:title");
+        Ok(())
     }
 
     pub(crate) fn write_to(
@@ -20,9 +20,11 @@ use super::{
     spreadsheet::ClientData,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::{
@@ -40,6 +42,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shape {
     style:           StringValue,
     r_type:          StringValue,
@@ -395,14 +398,14 @@ impl Shape {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r_type, "type");
         set_string_from_xml!(self, e, style, "style");
         set_string_from_xml!(self, e, filled, "filled");
         set_string_from_xml!(self, e, fill_color, "fillcolor");
-        set_string_from_xml!(self, e, stroked, "stoked");
-        set_string_from_xml!(self, e, stroke_color, "stokecolor");
-        set_string_from_xml!(self, e, stroke_weight, "stokeweight");
+        set_string_from_xml!(self, e, stroked, "stroked");
+        set_string_from_xml!(self, e, stroke_color, "strokecolor");
+        set_string_from_xml!(self, e, stroke_weight, "strokeweight");
         set_string_from_xml!(self, e, inset_mode, "o:insetmode");
         set_string_from_xml!(self, e, optional_number, "o:spt");
         set_string_from_xml!(self, e, coordinate_size, "coordsize");
@@ -413,7 +416,7 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:fill" => {
                     let mut obj = Fill::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_fill(obj);
                 }
                 b"v:shadow" => {
@@ -433,7 +436,7 @@ impl Shape {
                 }
                 b"v:imagedata" => {
                     let mut obj = ImageData::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_image_data(obj);
                 }
                 _ => (),
@@ -443,12 +446,12 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:textbox" => {
                     let mut obj = TextBox::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_box(obj);
                 }
                 b"x:ClientData" => {
                     let mut obj = ClientData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_client_data(obj);
                 }
                 _ => (),
@@ -456,10 +459,10 @@ impl Shape {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"v:shape" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "v:shape")
+            Event::Eof => return Err(unexpected_eof(reader, "v:shape"))
         );
     }
 
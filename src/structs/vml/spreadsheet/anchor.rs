@@ -7,11 +7,13 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::coordinate::{
         adjustment_insert_coordinate,
         adjustment_remove_coordinate,
         is_remove_coordinate,
     },
+    reader::driver::invalid_xml,
     traits::AdjustmentCoordinate,
     writer::driver::{
         write_end_tag,
@@ -21,6 +23,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Anchor {
     left_column:   u32,
     left_offset:   u32,
@@ -248,10 +251,12 @@ impl Anchor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         let text = crate::helper::utils::unescape_xml_text(
-            &reader.read_text_into(e.name(), &mut buf).unwrap(),
+            &reader
+                .read_text_into(e.name(), &mut buf)
+                .map_err(|e| invalid_xml(reader.error_position(), &e))?,
         );
         let mut split_str = text.split(',');
         self.set_left_column(Self::number(split_str.next()));
@@ -262,6 +267,7 @@ impl Anchor {
         self.set_right_offset(Self::number(split_str.next()));
         self.set_bottom_row(Self::number(split_str.next()));
         self.set_bottom_offset(Self::number(split_str.next()));
+        Ok(())
     }
 
     #[inline]
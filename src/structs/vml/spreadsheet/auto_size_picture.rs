@@ -7,6 +7,8 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
     structs::TrueFalseBlankValue,
     writer::driver::{
         write_end_tag,
@@ -16,6 +18,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AutoSizePicture {
     value: TrueFalseBlankValue,
 }
@@ -46,14 +49,17 @@ impl AutoSizePicture {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.value
             .set_value_string(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     #[inline]
@@ -22,9 +22,12 @@ use super::{
     Visible,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        invalid_xml,
         set_string_from_xml,
+        unexpected_eof,
         xml_read_loop,
     },
     structs::EnumValue,
@@ -35,10 +38,12 @@ use crate::{
     writer::driver::{
         write_end_tag,
         write_start_tag,
+        write_text_node,
     },
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClientData {
     object_type:           EnumValue<ObjectValues>,
     move_with_cells:       Option<MoveWithCells>,
@@ -50,8 +55,19 @@ pub struct ClientData {
     visible:               Option<Visible>,
     clipboard_format:      Option<ClipboardFormat>,
     auto_size_picture:     Option<AutoSizePicture>,
+    form_values:           Vec<(Box<str>, Box<str>)>,
 }
 
+// Form control elements kept as name and text pairs.
+const FORM_VALUE_ELEMENTS: &[&str] = &[
+    "Accel", "Accel2", "AutoLine", "Cancel", "Checked", "Colored", "Default", "DefaultSize",
+    "Disabled", "Dismiss", "DropLines", "DropStyle", "Dx", "FirstButton", "FmlaGroup", "FmlaLink",
+    "FmlaMacro", "FmlaRange", "FmlaTxbx", "Help", "Horiz", "Inc", "JustLastX", "LCT", "ListItem",
+    "LockText", "Locked", "Max", "Min", "MultiLine", "MultiSel", "NoThreeD", "NoThreeD2", "Page",
+    "PrintObject", "SecretEdit", "Sel", "SelType", "TextHAlign", "TextVAlign", "VScroll", "VTEdit",
+    "Val", "ValidIds", "WidthMin",
+];
+
 impl ClientData {
     #[inline]
     #[must_use]
@@ -342,12 +358,30 @@ impl ClientData {
         self
     }
 
+    /// (This method is crate only.)
+    /// Form control elements as name (without prefix) and text pairs.
+    #[inline]
+    pub(crate) fn form_values(&self) -> &[(Box<str>, Box<str>)] {
+        &self.form_values
+    }
+
+    #[inline]
+    pub(crate) fn set_form_values(&mut self, value: Vec<(Box<str>, Box<str>)>) -> &mut Self {
+        self.form_values = value;
+        self
+    }
+
+    fn form_value_name(name: &[u8]) -> Option<&str> {
+        let name = std::str::from_utf8(name.strip_prefix(b"x:")?).ok()?;
+        FORM_VALUE_ELEMENTS.contains(&name).then_some(name)
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, object_type, "ObjectType");
 
         xml_read_loop!(
@@ -356,88 +390,103 @@ impl ClientData {
                 match e.name().into_inner() {
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_size_picture(obj);
                     }
-                    _ => (),
+                    name => {
+                        if let Some(name) = Self::form_value_name(name) {
+                            self.form_values.push((name.into(), "".into()));
+                        }
+                    }
                 }
             },
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"x:Anchor" => {
                         let mut obj = Anchor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_anchor(obj);
                     }
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Row" => {
                         let mut obj = CommentRowTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_row_target(obj);
                     }
                     b"x:Column" => {
                         let mut obj = CommentColumnTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_column_target(obj);
                     }
                     b"x:CF" => {
                         let mut obj = ClipboardFormat::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_clipboard_format(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_size_picture(obj);
                     }
-                    _ => (),
+                    name => {
+                        if let Some(name) = Self::form_value_name(name) {
+                            let name: Box<str> = name.into();
+                            let mut buf = Vec::new();
+                            let text = crate::helper::utils::unescape_xml_text(
+                                &reader
+                                    .read_text_into(e.name(), &mut buf)
+                                    .map_err(|e| invalid_xml(reader.error_position(), &e))?,
+                            );
+                            self.form_values.push((name, text.into()));
+                        }
+                    }
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"x:ClientData" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:ClientData")
+            Event::Eof => return Err(unexpected_eof(reader, "x:ClientData"))
         );
     }
 
@@ -464,6 +513,18 @@ impl ClientData {
         // x:Anchor
         self.anchor.write_to(writer);
 
+        // form control elements
+        for (name, text) in &self.form_values {
+            let tag_name = format!("x:{name}");
+            if text.is_empty() {
+                write_start_tag(writer, &tag_name, vec![], true);
+            } else {
+                write_start_tag(writer, &tag_name, vec![], false);
+                write_text_node(writer, &**text);
+                write_end_tag(writer, &tag_name);
+            }
+        }
+
         // x:AutoFill
         if let Some(v) = &self.auto_fill {
             v.write_to(writer);
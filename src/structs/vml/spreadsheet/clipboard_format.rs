@@ -8,6 +8,8 @@ use quick_xml::{
 
 use super::ClipboardFormatValues;
 use crate::{
+    XlsxError,
+    reader::driver::invalid_xml,
     structs::EnumValue,
     writer::driver::{
         write_end_tag,
@@ -17,6 +19,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClipboardFormat {
     value: EnumValue<ClipboardFormatValues>,
 }
@@ -46,11 +49,14 @@ impl ClipboardFormat {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.value
             .set_value_string(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     #[inline]
@@ -7,11 +7,13 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::coordinate::{
         adjustment_insert_coordinate,
         adjustment_remove_coordinate,
         is_remove_coordinate,
     },
+    reader::driver::invalid_xml,
     structs::UInt32Value,
     traits::AdjustmentValue,
     writer::driver::{
@@ -22,6 +24,7 @@ use crate::{
 };
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommentRowTarget {
     value: UInt32Value,
 }
@@ -69,11 +72,14 @@ impl CommentRowTarget {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
-        let text = reader.read_text_into(e.name(), &mut buf).unwrap();
+        let text = reader
+            .read_text_into(e.name(), &mut buf)
+            .map_err(|e| invalid_xml(reader.error_position(), &e))?;
         self.value
             .set_value_string(crate::helper::utils::unescape_xml_text(&text));
+        Ok(())
     }
 
     #[inline]
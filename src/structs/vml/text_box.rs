@@ -7,8 +7,10 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        invalid_xml,
         set_string_from_xml,
     },
     structs::StringValue,
@@ -20,6 +22,7 @@ use crate::{
 };
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextBox {
     style:  StringValue,
     innder: StringValue,
@@ -72,14 +75,17 @@ impl TextBox {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, style, "style");
         let mut buf = Vec::new();
         reader.config_mut().check_end_names = false;
         let text = crate::helper::utils::unescape_xml_text(
-            &reader.read_text_into(e.name(), &mut buf).unwrap(),
+            &reader
+                .read_text_into(e.name(), &mut buf)
+                .map_err(|e| invalid_xml(reader.error_position(), &e))?,
         );
         self.set_innder(text);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -9,20 +9,35 @@ use crate::{
     helper::{
         address::split_address,
         coordinate::column_index_from_string,
+        range::get_start_and_end_point,
+        structured_reference,
+    },
+    reader::xlsx::{
+        deserialize_worksheet,
+        raw_to_deserialize_by_worksheet,
     },
-    reader::xlsx::raw_to_deserialize_by_worksheet,
     structs::{
         Address,
+        Cell,
         CellValue,
         Cells,
+        Chart,
+        Chartsheet,
         DefinedName,
+        DefinedNames,
+        DefinedNamesMut,
         Properties,
+        ReadOptions,
         SharedStringTable,
+        Style,
         Stylesheet,
+        TableStyle,
+        ValidationResult,
         WorkbookProtection,
         WorkbookView,
         Worksheet,
         drawing::Theme,
+        raw::RawPassthrough,
     },
     traits::{
         AdjustmentCoordinate,
@@ -32,22 +47,29 @@ use crate::{
 
 /// A Workbook Object.
 /// The starting point of all struct.
+/// With the `serde` feature, the workbook implements `Serialize` and
+/// `Deserialize`. All worksheets must be deserialized to serialize it.
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Workbook {
-    properties:            Properties,
-    work_sheet_collection: Vec<Worksheet>,
-    macros_code:           Option<Vec<u8>>,
-    jsa_macros_code:       Option<Vec<u8>>,
-    code_name:             StringValue,
-    ribbon_xml_data:       StringValue,
-    theme:                 Theme,
-    stylesheet:            Stylesheet,
-    shared_string_table:   Arc<RwLock<SharedStringTable>>,
-    workbook_view:         WorkbookView,
-    backup_context_types:  Vec<(Box<str>, Box<str>)>,
-    pivot_caches:          Vec<(Box<str>, Box<str>, Box<str>)>,
-    workbook_protection:   Option<Box<WorkbookProtection>>,
-    defined_names:         Vec<DefinedName>,
+    properties:                   Properties,
+    work_sheet_collection:        Vec<Worksheet>,
+    macros_code:                  Option<Vec<u8>>,
+    jsa_macros_code:              Option<Vec<u8>>,
+    code_name:                    StringValue,
+    ribbon_xml_data:              StringValue,
+    theme:                        Theme,
+    stylesheet:                   Stylesheet,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    shared_string_table:          Arc<RwLock<SharedStringTable>>,
+    workbook_view:                WorkbookView,
+    backup_context_types:         Vec<(Box<str>, Box<str>)>,
+    backup_default_context_types: Vec<(Box<str>, Box<str>)>,
+    pivot_caches:                 Vec<(Box<str>, Box<str>, Box<str>)>,
+    workbook_protection:          Option<Box<WorkbookProtection>>,
+    defined_names:                Vec<DefinedName>,
+    package_passthrough:          RawPassthrough,
+    workbook_passthrough:         RawPassthrough,
 }
 
 impl Workbook {
@@ -193,12 +215,77 @@ impl Workbook {
             .cell_value_by_range(&address.range().range())
     }
 
+    /// (This method is crate only.)
+    /// Get the cells referenced by an address, in row order.
+    /// Returns `None` when the sheet cannot be resolved.
+    /// Whole-column and whole-row references stop at the last used cell.
+    pub(crate) fn cells_by_address_crate(&self, address: &Address) -> Option<Vec<Option<&Cell>>> {
+        let worksheet = self.sheet_by_name(address.sheet_name()).ok()?;
+        let (mut row_start, mut row_end, mut col_start, mut col_end) =
+            get_start_and_end_point(&address.range().range());
+        if row_start == 0 {
+            row_start = 1;
+            row_end = worksheet.highest_row();
+        }
+        if col_start == 0 {
+            col_start = 1;
+            col_end = worksheet.highest_column();
+        }
+        Some(
+            (row_start..=row_end)
+                .flat_map(|row| (col_start..=col_end).map(move |col| (col, row)))
+                .map(|coordinate| worksheet.cell(coordinate))
+                .collect(),
+        )
+    }
+
     #[inline]
     #[deprecated(since = "3.0.0", note = "Use cell_value_by_address_crate()")]
     pub(crate) fn get_cell_value_by_address_crate(&self, address: &Address) -> Vec<&CellValue> {
         self.cell_value_by_address_crate(address)
     }
 
+    /// Refresh the cached series data of every chart in the workbook.
+    /// See [`Chart::refresh_cache`].
+    /// Sheets that have not been deserialized yet are skipped.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.refresh_chart_caches();
+    /// ```
+    pub fn refresh_chart_caches(&mut self) -> &mut Self {
+        for index in 0..self.work_sheet_collection.len() {
+            let worksheet = &mut self.work_sheet_collection[index];
+            if !worksheet.is_deserialized() {
+                continue;
+            }
+            let mut chart_list = std::mem::take(
+                worksheet
+                    .worksheet_drawing_mut()
+                    .chart_collection_mut(),
+            );
+            let mut chartsheet_chart = worksheet
+                .chartsheet_mut()
+                .map(|v| std::mem::take(v.chart_mut()));
+
+            for chart in &mut chart_list {
+                chart.refresh_cache(self);
+            }
+            if let Some(chart) = &mut chartsheet_chart {
+                chart.refresh_cache(self);
+            }
+
+            let worksheet = &mut self.work_sheet_collection[index];
+            *worksheet
+                .worksheet_drawing_mut()
+                .chart_collection_mut() = chart_list;
+            if let (Some(v), Some(chart)) = (worksheet.chartsheet_mut(), chartsheet_chart) {
+                v.set_chart(chart);
+            }
+        }
+        self
+    }
+
     /// Get Theme.
     #[inline]
     #[must_use]
@@ -397,6 +484,173 @@ impl Workbook {
         self.code_name()
     }
 
+    /// Add a named cell style, or replace the one with the same name.
+    /// Apply it to a cell with [`Cell::set_named_style`].
+    /// # Arguments
+    /// * `name` - Name of the style. ex) `"Brand Heading"`
+    /// * `style` - Formatting of the style.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut book = new_file();
+    /// let mut style = Style::default();
+    /// style.font_mut().set_bold(true);
+    /// book.add_named_style("Brand Heading", &style);
+    /// assert!(book.named_style("Brand Heading").is_some());
+    /// ```
+    #[inline]
+    pub fn add_named_style<S: Into<String>>(&mut self, name: S, style: &Style) -> &mut Self {
+        self.stylesheet.add_named_style(&name.into(), style);
+        self
+    }
+
+    /// Get the formatting of a named cell style.
+    /// The name is not case sensitive.
+    #[inline]
+    #[must_use]
+    pub fn named_style(&self, name: &str) -> Option<Style> {
+        self.stylesheet.named_style(name)
+    }
+
+    /// Add a custom table style, or replace the one with the same name.
+    #[inline]
+    pub fn add_table_style(&mut self, value: TableStyle) -> &mut Self {
+        self.stylesheet.add_table_style(value);
+        self
+    }
+
+    /// Get the custom table styles.
+    #[inline]
+    #[must_use]
+    pub fn table_styles(&self) -> &[TableStyle] {
+        self.stylesheet.table_styles()
+    }
+
+    /// Rename a table.
+    /// The structured references to the table in formulas and defined names
+    /// are rewritten.
+    /// # Arguments
+    /// * `old_name` - Current name of the table. (not case sensitive)
+    /// * `new_name` - New name of the table.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut table = umya_spreadsheet::structs::Table::new("Sales", ("A1", "B3"));
+    /// table.add_column(umya_spreadsheet::structs::TableColumn::new("Qty"));
+    /// table.add_column(umya_spreadsheet::structs::TableColumn::new("Price"));
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet.add_table(table);
+    /// sheet.cell_mut("D1").set_formula("SUM(Sales[Qty])");
+    ///
+    /// book.rename_table("Sales", "Orders").unwrap();
+    /// let sheet = book.sheet(0).unwrap();
+    /// assert_eq!(sheet.cell("D1").unwrap().formula(), "SUM(Orders[Qty])");
+    /// ```
+    /// # Errors
+    /// `XlsxError::NotFound` if the table does not exist, and
+    /// `XlsxError::NameDuplicate` if another table already has the new name.
+    pub fn rename_table(&mut self, old_name: &str, new_name: &str) -> Result<&mut Self, XlsxError> {
+        let old_name = self.find_table_name(old_name)?;
+        if !old_name.eq_ignore_ascii_case(new_name) && self.find_table_name(new_name).is_ok() {
+            return Err(XlsxError::NameDuplicate());
+        }
+        for worksheet in &mut self.work_sheet_collection {
+            if let Some(table) = worksheet.table_mut(&old_name) {
+                table.set_name_crate(new_name);
+            }
+        }
+        self.rewrite_structured_references(new_name, |formula, _| {
+            structured_reference::rename_table(formula, &old_name, new_name)
+        });
+        Ok(self)
+    }
+
+    /// Rename a column of a table.
+    /// The header cell and the structured references to the column in
+    /// formulas and defined names are updated.
+    /// # Arguments
+    /// * `table_name` - Name of the table. (not case sensitive)
+    /// * `old_name` - Current name of the column. (not case sensitive)
+    /// * `new_name` - New name of the column.
+    /// # Errors
+    /// `XlsxError::NotFound` if the table or the column does not exist, and
+    /// `XlsxError::NameDuplicate` if another column already has the new name.
+    pub fn rename_table_column(
+        &mut self,
+        table_name: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<&mut Self, XlsxError> {
+        let table_name = self.find_table_name(table_name)?;
+        let worksheet = self
+            .work_sheet_collection
+            .iter_mut()
+            .find(|v| v.table(&table_name).is_some())
+            .ok_or(XlsxError::NotFound())?;
+        let table = worksheet.table_mut(&table_name).ok_or(XlsxError::NotFound())?;
+        let index = table.column_index(old_name).ok_or(XlsxError::NotFound())?;
+        if let Some(v) = table.column_index(new_name) {
+            if v != index {
+                return Err(XlsxError::NameDuplicate());
+            }
+        }
+        let old_name = table.columns()[index].name().to_string();
+        table.columns_crate_mut()[index].set_name(new_name.to_string());
+        let (start, _) = table.area();
+        let col: u32 = num_traits::cast(index).unwrap();
+        let header = (start.col_num() + col, start.row_num());
+        worksheet.cell_mut(header).set_value_string(new_name);
+        self.rewrite_structured_references(&table_name, |formula, in_table| {
+            structured_reference::rename_column(formula, &table_name, &old_name, new_name, in_table)
+        });
+        Ok(self)
+    }
+
+    /// Find the stored name of a table. The name is not case sensitive.
+    fn find_table_name(&mut self, name: &str) -> Result<String, XlsxError> {
+        self.sheet_collection_mut()
+            .iter()
+            .find_map(|v| v.table(name))
+            .map(|v| v.name().to_string())
+            .ok_or(XlsxError::NotFound())
+    }
+
+    /// Rewrite the formulas of cells, table columns and defined names.
+    /// `f` receives the formula and whether it is within the table named
+    /// `table_name`.
+    fn rewrite_structured_references<F>(&mut self, table_name: &str, f: F)
+    where
+        F: Fn(&str, bool) -> String,
+    {
+        let f = &f;
+        for worksheet in self.sheet_collection_mut() {
+            let area = worksheet.table(table_name).map(|v| v.area().clone());
+            for cell in worksheet.cells_mut() {
+                let coordinate = cell.coordinate();
+                let in_table = area.as_ref().is_some_and(|(start, end)| {
+                    (start.col_num()..=end.col_num()).contains(&coordinate.col_num())
+                        && (start.row_num()..=end.row_num()).contains(&coordinate.row_num())
+                });
+                if let Some(formula) = cell.cell_value_mut().formula_obj_mut() {
+                    formula.map_text(|v| f(v, in_table));
+                }
+            }
+            for table in worksheet.tables_mut() {
+                let in_table = table.name().eq_ignore_ascii_case(table_name);
+                for column in table.columns_crate_mut() {
+                    column.map_formulas(|v| f(v, in_table));
+                }
+            }
+            for defined_name in worksheet.defined_names_mut() {
+                defined_name.map_string_value(|v| f(v, false));
+            }
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.map_string_value(|v| f(v, false));
+        }
+    }
+
     /// (This method is crate only.)
     /// Get Stylesheet.
     #[inline]
@@ -522,23 +776,48 @@ impl Workbook {
     }
 
     /// deserialize by all worksheet.
+    /// A worksheet that can not be read stays not deserialized, and
+    /// `sheet_mut()` returns the error.
     #[inline]
     pub fn read_sheet_collection(&mut self) -> &mut Self {
         let shared_string_table = self.shared_string_table();
         let stylesheet = self.stylesheet().clone();
         for worksheet in &mut self.work_sheet_collection {
-            raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet);
+            raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet).ok();
         }
         self
     }
 
+    /// (This method is crate only.)
+    /// Deserialize all worksheets and stop at the first error.
+    pub(crate) fn read_sheet_collection_crate(
+        &mut self,
+        options: &ReadOptions,
+        warnings: &mut Vec<XlsxError>,
+    ) -> Result<(), XlsxError> {
+        let shared_string_table = self.shared_string_table();
+        let stylesheet = self.stylesheet().clone();
+        for worksheet in &mut self.work_sheet_collection {
+            deserialize_worksheet(
+                worksheet,
+                &shared_string_table,
+                &stylesheet,
+                options,
+                warnings,
+            )?;
+        }
+        Ok(())
+    }
+
     /// deserialize a worksheet.
+    /// A worksheet that can not be read stays not deserialized, and
+    /// `sheet_mut()` returns the error.
     #[inline]
     pub fn read_sheet(&mut self, index: usize) -> &mut Self {
         let shared_string_table = self.shared_string_table();
         let stylesheet = self.stylesheet().clone();
         let worksheet = self.work_sheet_collection.get_mut(index).unwrap();
-        raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet);
+        raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet).ok();
         self
     }
 
@@ -601,10 +880,12 @@ impl Workbook {
 
     pub fn lazy_read_sheet_cells(&self, index: usize) -> Result<Cells, XlsxError> {
         let shared_string_table = self.shared_string_table();
-        self.work_sheet_collection
+        let worksheet = self
+            .work_sheet_collection
             .get(index)
-            .map(|v| v.cells_stream(&shared_string_table.read().unwrap(), self.stylesheet()))
-            .ok_or(XlsxError::NotFound())
+            .ok_or(XlsxError::NotFound())?;
+        let cells = worksheet.cells_stream(&shared_string_table.read().unwrap(), self.stylesheet());
+        cells
     }
 
     #[deprecated(since = "3.0.0", note = "Use lazy_read_sheet_cells()")]
@@ -617,17 +898,15 @@ impl Workbook {
     /// * `index` - sheet index
     /// # Return value
     /// * `Result<&mut Worksheet, XlsxError>`.
-    #[allow(clippy::manual_inspect)]
     pub fn sheet_mut(&mut self, index: usize) -> Result<&mut Worksheet, XlsxError> {
         let shared_string_table = self.shared_string_table();
         let stylesheet = self.stylesheet().clone();
-        self.work_sheet_collection
+        let worksheet = self
+            .work_sheet_collection
             .get_mut(index)
-            .map(|v| {
-                raw_to_deserialize_by_worksheet(v, &shared_string_table, &stylesheet);
-                v
-            })
-            .ok_or(XlsxError::NotFound())
+            .ok_or(XlsxError::NotFound())?;
+        raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet)?;
+        Ok(worksheet)
     }
 
     #[deprecated(since = "3.0.0", note = "Use sheet_mut()")]
@@ -712,33 +991,62 @@ impl Workbook {
     }
 
     /// Remove Work Sheet.
+    /// References to the sheet in formulas, defined names, charts, data
+    /// validations and conditional formats of the other sheets become
+    /// `#REF!`. When a worksheet that refers to the sheet can not be read,
+    /// its error is returned and the sheet is not removed.
     /// # Arguments
     /// * `index` - sheet index
     /// # Return value
     /// * `Result<(), XlsxError>` - OK:removed worksheet. Err:Error.
-    #[inline]
     pub fn remove_sheet(&mut self, index: usize) -> Result<(), XlsxError> {
         if self.work_sheet_collection.len() <= index {
             return Err(XlsxError::NotFound());
         }
-        self.work_sheet_collection.remove(index);
+        let name = self.work_sheet_collection[index].name().to_string();
+        self.read_sheets_mentioning(&name)?;
+        let worksheet = self.work_sheet_collection.remove(index);
+
+        // workbook names stored on the removed sheet are kept
+        for defined_name in worksheet.defined_names() {
+            if !defined_name.has_local_sheet_id() {
+                self.defined_names.push(defined_name.clone());
+            }
+        }
+        for sheet in &mut self.work_sheet_collection {
+            sheet.remove_sheet_reference(worksheet.name());
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.remove_sheet_reference(worksheet.name());
+        }
+
+        // the sheet indexes after the removed sheet have moved
+        for (sheet_index, sheet) in self.work_sheet_collection.iter_mut().enumerate() {
+            for defined_name in sheet.defined_names_mut() {
+                if defined_name.has_local_sheet_id() {
+                    defined_name.set_local_sheet_id(num_traits::cast(sheet_index).unwrap());
+                }
+            }
+        }
         Ok(())
     }
 
     /// Remove Work Sheet.
+    /// References to the sheet in formulas, defined names, charts, data
+    /// validations and conditional formats of the other sheets become
+    /// `#REF!`. When a worksheet that refers to the sheet can not be read,
+    /// its error is returned and the sheet is not removed.
     /// # Arguments
     /// * `sheet_name` - sheet name
     /// # Return value
     /// * `Result<(), XlsxError>` - OK:removed worksheet. Err:Error.
     pub fn remove_sheet_by_name(&mut self, sheet_name: &str) -> Result<(), XlsxError> {
-        let cnt_before = self.work_sheet_collection.len();
-        self.work_sheet_collection
-            .retain(|x| x.name() != sheet_name);
-        let cnt_after = self.work_sheet_collection.len();
-        if cnt_before == cnt_after {
-            return Err(XlsxError::NotFound());
-        }
-        Ok(())
+        let index = self
+            .work_sheet_collection
+            .iter()
+            .position(|x| x.name() == sheet_name)
+            .ok_or(XlsxError::NotFound())?;
+        self.remove_sheet(index)
     }
 
     /// Add New Work Sheet.
@@ -757,6 +1065,28 @@ impl Workbook {
         Ok(Workbook::add_new_sheet_crate(self, sheet_id, v.clone()))
     }
 
+    /// Add New Chart Sheet.
+    /// The chart sheet is appended to the sheet list, so it keeps its tab
+    /// position relative to the worksheets around it.
+    /// # Arguments
+    /// * `sheet_title` - sheet title
+    /// * `chart` - chart displayed on the sheet
+    /// # Return value
+    /// * `Result<&mut Chartsheet, XlsxError>` - OK:added chart sheet.
+    ///   Err:Error.
+    pub fn add_chartsheet<S: Into<String>>(
+        &mut self,
+        sheet_title: S,
+        chart: Chart,
+    ) -> Result<&mut Chartsheet, XlsxError> {
+        let v = sheet_title.into();
+        Workbook::check_sheet_name(self, &v)?;
+        let sheet_id = (self.work_sheet_collection.len() + 1).to_string();
+        let worksheet = Workbook::add_new_sheet_crate(self, sheet_id, v);
+        worksheet.set_chartsheet(Chartsheet::new(chart));
+        Ok(worksheet.chartsheet_mut().unwrap())
+    }
+
     /// (This method is crate only.)
     /// Add New Work Sheet.
     /// # Arguments
@@ -778,11 +1108,25 @@ impl Workbook {
     }
 
     /// Set Sheet Name.
+    /// References to the sheet in formulas, defined names, charts, data
+    /// validations and conditional formats are renamed as well. When a
+    /// worksheet that refers to the sheet can not be read, its error is
+    /// returned and the sheet is not renamed.
     /// # Arguments
     /// * `index` - target sheet index
     /// * `sheet_name` - sheet name
     /// # Return value
     /// * `Result<(), XlsxError>` - OK:Success  Err:Error.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.new_sheet("Data").unwrap();
+    /// book.sheet_mut(0).unwrap().cell_mut("A1").set_formula("SUM(Data!A1:A3)");
+    ///
+    /// book.set_sheet_name(1, "Sales Data").unwrap();
+    /// let sheet = book.sheet(0).unwrap();
+    /// assert_eq!(sheet.cell("A1").unwrap().formula(), "SUM('Sales Data'!A1:A3)");
+    /// ```
     pub fn set_sheet_name<S: Into<String>>(
         &mut self,
         index: usize,
@@ -790,12 +1134,38 @@ impl Workbook {
     ) -> Result<(), XlsxError> {
         let sheet_name_str = sheet_name.into();
         Workbook::check_sheet_name(self, sheet_name_str.as_ref())?;
-        self.work_sheet_collection
-            .get_mut(index)
-            .map(|sheet| {
-                sheet.set_name(sheet_name_str);
-            })
-            .ok_or(XlsxError::NotFound())
+        let old_name = self
+            .work_sheet_collection
+            .get(index)
+            .ok_or(XlsxError::NotFound())?
+            .name()
+            .to_string();
+        self.read_sheets_mentioning(&old_name)?;
+        for sheet in &mut self.work_sheet_collection {
+            sheet.rename_sheet_reference(&old_name, &sheet_name_str);
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.rename_sheet_reference(&old_name, &sheet_name_str);
+        }
+        self.work_sheet_collection[index].set_name(sheet_name_str);
+        Ok(())
+    }
+
+    /// Deserialize the worksheets that may refer to a sheet, so that their
+    /// references can be updated. The other worksheets stay as they are.
+    /// A worksheet that can not be read keeps its raw data and its error is
+    /// returned.
+    fn read_sheets_mentioning(&mut self, sheet_name: &str) -> Result<(), XlsxError> {
+        let shared_string_table = self.shared_string_table();
+        let stylesheet = self.stylesheet().clone();
+        for worksheet in &mut self.work_sheet_collection {
+            if !worksheet.is_deserialized()
+                && worksheet.raw_data_of_worksheet_mut().mentions(sheet_name)
+            {
+                raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet)?;
+            }
+        }
+        Ok(())
     }
 
     /// (This method is crate only.)
@@ -877,6 +1247,50 @@ impl Workbook {
         self.backup_context_types()
     }
 
+    /// (This method is crate only.)
+    /// Content types by extension of the file read.
+    #[inline]
+    pub(crate) fn backup_default_context_types(&self) -> &[(Box<str>, Box<str>)] {
+        &self.backup_default_context_types
+    }
+
+    #[inline]
+    pub(crate) fn set_backup_default_context_types(
+        &mut self,
+        value: impl Into<Vec<(String, String)>>,
+    ) -> &mut Self {
+        self.backup_default_context_types = value
+            .into()
+            .into_iter()
+            .map(|(a, b)| (a.into_boxed_str(), b.into_boxed_str()))
+            .collect();
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Parts of the package relationships that are not supported.
+    #[inline]
+    pub(crate) fn package_passthrough(&self) -> &RawPassthrough {
+        &self.package_passthrough
+    }
+
+    #[inline]
+    pub(crate) fn package_passthrough_mut(&mut self) -> &mut RawPassthrough {
+        &mut self.package_passthrough
+    }
+
+    /// (This method is crate only.)
+    /// Parts and elements of the workbook part that are not supported.
+    #[inline]
+    pub(crate) fn workbook_passthrough(&self) -> &RawPassthrough {
+        &self.workbook_passthrough
+    }
+
+    #[inline]
+    pub(crate) fn workbook_passthrough_mut(&mut self) -> &mut RawPassthrough {
+        &mut self.workbook_passthrough
+    }
+
     #[inline]
     pub(crate) fn set_backup_context_types(
         &mut self,
@@ -1034,6 +1448,54 @@ impl Workbook {
         self.defined_names.push(value);
     }
 
+    /// Get the defined names of the workbook and its sheets.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.names_mut().add("Rate", "0.08", None).unwrap();
+    /// assert_eq!(book.names().get("rate", None).unwrap().address(), "0.08");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn names(&self) -> DefinedNames<'_> {
+        DefinedNames::new(self)
+    }
+
+    /// Get the defined names of the workbook and its sheets in mutable.
+    #[inline]
+    pub fn names_mut(&mut self) -> DefinedNamesMut<'_> {
+        DefinedNamesMut::new(self)
+    }
+
+    /// Get Work Sheet List in mutable.
+    /// No check deserialized.
+    #[inline]
+    pub(crate) fn sheet_collection_no_check_mut(&mut self) -> &mut [Worksheet] {
+        &mut self.work_sheet_collection
+    }
+
+    /// Check the cells of all worksheets against their data validation.
+    ///
+    /// List sources, references on other worksheets and defined names are
+    /// resolved across the workbook. Worksheets that are not deserialized are
+    /// skipped, and only cells present in a worksheet are checked.
+    /// # Return value
+    /// * `Vec<ValidationResult>` - Cells violating a rule, ordered by
+    ///   worksheet, row and column.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// assert!(book.validate_all().is_empty());
+    /// ```
+    #[must_use]
+    pub fn validate_all(&self) -> Vec<ValidationResult> {
+        self.work_sheet_collection
+            .iter()
+            .filter(|v| v.is_deserialized())
+            .flat_map(|v| v.validate_all_crate(Some(self)))
+            .collect()
+    }
+
     /// Has `ThreadedComments`.
     #[inline]
     #[must_use]
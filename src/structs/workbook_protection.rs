@@ -25,6 +25,7 @@ use crate::{
 };
 
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorkbookProtection {
     workbook_algorithm_name:  StringValue,
     workbook_hash_value:      StringValue,
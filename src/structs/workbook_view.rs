@@ -17,6 +17,7 @@ use crate::{
 };
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorkbookView {
     active_tab: UInt32Value,
     x_window: Int32Value,
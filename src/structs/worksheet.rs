@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+};
 use quick_xml::{
     escape,
     Reader,
@@ -8,13 +11,22 @@ use quick_xml::{
 use crate::{
     reader::driver::{
         get_attribute,
+        required_attribute,
         set_string_from_xml,
     },
+    reader::driver::unexpected_eof,
     xml_read_loop,
 };
 use crate::{
     StringValue,
+    XlsxError,
     helper::{
+        address::{
+            join_address,
+            split_address,
+        },
+        conditional_format::ConditionalFormatEvaluator,
+        data_validation::DataValidationEvaluator,
         const_str::PIVOT_CACHE_DEF_NS,
         coordinate::{
             CellCoordinates,
@@ -22,27 +34,41 @@ use crate::{
             coordinate_from_index,
             string_from_column_index,
         },
+        formula::{
+            remove_sheet_in_formula,
+            rename_sheet_in_formula,
+        },
         range::{
             get_coordinate_list,
             get_start_and_end_point,
+            range_bounds,
         },
     },
     reader::xlsx::worksheet::read_lite,
     structs::{
+        Address,
         AutoFilter,
         Cell,
         CellValue,
         Cells,
         Chart,
+        Chartsheet,
         Color,
         Column,
         ColumnBreaks,
+        ColumnReference,
         Columns,
         Comment,
+        ConditionalFormatMatch,
         ConditionalFormatting,
+        ConditionalFormattingRule,
+        Controls,
+        DataValidation,
         DataValidations,
         DefinedName,
         EnumValue,
+        FormControl,
+        FormControlTypeValues,
         HeaderFooter,
         Hyperlink,
         Image,
@@ -54,9 +80,13 @@ use crate::{
         PivotTable,
         PrintOptions,
         Range,
+        RichText,
         Row,
         RowBreaks,
+        RowReference,
         Rows,
+        Rule,
+        SequenceOfReferences,
         SharedStringTable,
         SheetFormatProperties,
         SheetProtection,
@@ -65,10 +95,24 @@ use crate::{
         Style,
         Stylesheet,
         Table,
-        drawing::spreadsheet::WorksheetDrawing,
+        ValidationResult,
+        Workbook,
+        drawing::{
+            PresetGeometry,
+            charts::Formula as ChartFormula,
+            spreadsheet::{
+                ConnectionShape,
+                Shape,
+                TwoCellAnchor,
+                WorksheetDrawing,
+            },
+        },
         office2010::excel::DataValidations as DataValidations2010,
         office2019::threaded_comment::ThreadedComment,
-        raw::RawWorksheet,
+        raw::{
+            RawPassthrough,
+            RawWorksheet,
+        },
     },
     traits::{
         AdjustmentCoordinate,
@@ -80,7 +124,16 @@ use crate::{
 
 /// A Worksheet Object.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Worksheet {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "not_deserialized"
+        )
+    )]
     raw_data_of_worksheet:             Option<RawWorksheet>,
     r_id:                              Box<str>,
     sheet_id:                          Box<str>,
@@ -104,6 +157,7 @@ pub struct Worksheet {
     tab_color:                         Option<Color>,
     code_name:                         StringValue,
     ole_objects:                       OleObjects,
+    controls:                          Controls,
     defined_names:                     Vec<DefinedName>,
     print_options:                     PrintOptions,
     column_breaks:                     ColumnBreaks,
@@ -114,6 +168,8 @@ pub struct Worksheet {
     data_validations_2010:             Option<DataValidations2010>,
     sheet_format_properties:           SheetFormatProperties,
     sheet_protection:                  Option<SheetProtection>,
+    chartsheet:                        Option<Box<Chartsheet>>,
+    passthrough:                       RawPassthrough,
 }
 
 impl Worksheet {
@@ -288,7 +344,7 @@ impl Worksheet {
         &self,
         shared_string_table: &SharedStringTable,
         stylesheet: &Stylesheet,
-    ) -> Cells {
+    ) -> Result<Cells, XlsxError> {
         assert!(!self.is_deserialized(), "This Worksheet is Deserialized.");
 
         read_lite(
@@ -303,7 +359,7 @@ impl Worksheet {
         &self,
         shared_string_table: &SharedStringTable,
         stylesheet: &Stylesheet,
-    ) -> Cells {
+    ) -> Result<Cells, XlsxError> {
         self.cells_stream(shared_string_table, stylesheet)
     }
 
@@ -715,6 +771,134 @@ impl Worksheet {
         self
     }
 
+    // ************************
+    // Records
+    // ************************
+    /// Get the rows of a range as JSON records, keyed by the values of the
+    /// header row. A column with an empty header is keyed by its letter.
+    /// Rows without values are skipped.
+    /// # Arguments
+    /// * `header_row` - Specify the row of the headers. ex) 1
+    /// * `range` - Specify the range. ex) "A1:D100", "A:D"
+    /// # Return value
+    /// * `Vec<serde_json::Map<String, serde_json::Value>>` - the records.
+    /// # Errors
+    /// `XlsxError::InvalidRange` if the range is malformed, reversed or out
+    /// of the sheet.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.cell_mut("A1").set_value("name");
+    /// worksheet.cell_mut("B1").set_value("price");
+    /// worksheet.cell_mut("A2").set_value("apple");
+    /// worksheet.cell_mut("B2").set_value_number(1.5);
+    /// let records = worksheet.to_records(1, "A:B").unwrap();
+    /// assert_eq!(records[0]["name"], "apple");
+    /// assert_eq!(records[0]["price"], 1.5);
+    /// assert!(worksheet.to_records(1, "B:A").is_err());
+    /// ```
+    #[cfg(feature = "json")]
+    #[inline]
+    pub fn to_records(
+        &self,
+        header_row: u32,
+        range: &str,
+    ) -> Result<Vec<serde_json::Map<String, serde_json::Value>>, XlsxError> {
+        self.to_records_with_option(header_row, range, &crate::structs::RecordsOption::default())
+    }
+
+    /// Get the rows of a range as JSON records with options.
+    /// # Arguments
+    /// * `header_row` - Specify the row of the headers. ex) 1
+    /// * `range` - Specify the range. ex) "A1:D100", "A:D"
+    /// * `option` - options.
+    /// # Return value
+    /// * `Vec<serde_json::Map<String, serde_json::Value>>` - the records.
+    /// # Errors
+    /// `XlsxError::InvalidRange` if the range is malformed, reversed or out
+    /// of the sheet.
+    #[cfg(feature = "json")]
+    #[inline]
+    pub fn to_records_with_option(
+        &self,
+        header_row: u32,
+        range: &str,
+        option: &crate::structs::RecordsOption,
+    ) -> Result<Vec<serde_json::Map<String, serde_json::Value>>, XlsxError> {
+        crate::helper::json::to_records(self, header_row, range, option)
+    }
+
+    /// Write JSON records from cell A1, the headers in the first row and a
+    /// row for each record below them.
+    /// # Arguments
+    /// * `records` - JSON objects.
+    /// * `spec` - the columns to write.
+    /// # Return value
+    /// * `Result` - Err when a record is not a JSON object.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::ColumnSpec;
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.sheet_mut(0).unwrap();
+    /// let records = vec![serde_json::json!({"name": "apple", "price": 1.5})];
+    /// worksheet
+    ///     .from_records(&records, &ColumnSpec::default())
+    ///     .unwrap();
+    /// assert_eq!(worksheet.value("A2"), "apple");
+    /// ```
+    #[cfg(feature = "json")]
+    #[inline]
+    pub fn from_records(
+        &mut self,
+        records: &[serde_json::Value],
+        spec: &crate::structs::ColumnSpec,
+    ) -> Result<(), XlsxError> {
+        crate::helper::json::from_records(self, records, spec)
+    }
+
+    /// Write values through `Serialize`, the headers in the header row of
+    /// `mapping` and a row for each value below them. A value is a struct
+    /// or a map, a column for each field.
+    /// # Arguments
+    /// * `rows` - the values to write.
+    /// * `mapping` - the header row, and the headers and number formats of
+    ///   the fields.
+    /// # Return value
+    /// * `Result` - Err with the row when a value can not be written.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::RowMapping;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Fruit {
+    ///     name:  String,
+    ///     price: f64,
+    /// }
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.sheet_mut(0).unwrap();
+    /// let rows = vec![Fruit {
+    ///     name:  "apple".to_string(),
+    ///     price: 1.5,
+    /// }];
+    /// worksheet
+    ///     .write_rows(&rows, &RowMapping::default())
+    ///     .unwrap();
+    /// assert_eq!(worksheet.value("A1"), "name");
+    /// assert_eq!(worksheet.value("B2"), "1.5");
+    /// ```
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn write_rows<T: serde::Serialize>(
+        &mut self,
+        rows: &[T],
+        mapping: &crate::structs::RowMapping,
+    ) -> Result<(), XlsxError> {
+        crate::helper::rows::write_rows(self, rows, mapping)
+    }
+
     // ************************
     // Comment
     // ************************
@@ -891,6 +1075,138 @@ impl Worksheet {
         self.conditional_formatting_collection.push(value);
     }
 
+    /// Add a conditional formatting rule to a range.
+    ///
+    /// The rule gets a priority below the rules already on the worksheet.
+    /// # Arguments
+    /// * `range` - Range of the cells. ex) `"A1:C10"`, `"A1:A10 C1:C10"`
+    /// * `rule` - Rule to add.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut book = new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.add_conditional_format("A1:A10", Rule::top_n(3));
+    /// ```
+    pub fn add_conditional_format(
+        &mut self,
+        range: &str,
+        rule: Rule,
+    ) -> &mut ConditionalFormatting {
+        let priority = self
+            .conditional_formatting_collection
+            .iter()
+            .flat_map(ConditionalFormatting::conditional_collection)
+            .map(ConditionalFormattingRule::priority)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let mut first_range = Range::default();
+        first_range.set_range(range.split_whitespace().next().unwrap_or(range));
+        let (col_start, row_start, ..) = range_bounds(&first_range);
+        let top_left = coordinate_from_index(col_start, row_start);
+
+        let mut sequence_of_references = SequenceOfReferences::default();
+        sequence_of_references.set_sqref(range);
+        let mut conditional_formatting = ConditionalFormatting::default();
+        conditional_formatting.set_sequence_of_references(sequence_of_references);
+        conditional_formatting.add_conditional_collection(rule.into_rule(&top_left, priority));
+        self.conditional_formatting_collection.push(conditional_formatting);
+        self.conditional_formatting_collection.last_mut().unwrap()
+    }
+
+    /// Get the style of the cell with its conditional formatting applied.
+    ///
+    /// Matching rules are applied in priority order, so the rule with the
+    /// highest priority wins where rules set the same property. A color scale
+    /// sets the fill color of the cell.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)`
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet(0).unwrap();
+    /// let style = worksheet.effective_style("A1");
+    /// ```
+    #[must_use]
+    pub fn effective_style<T>(&self, coordinate: T) -> Style
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let mut style = self.style((col, row)).clone();
+        let matches = ConditionalFormatEvaluator::new(self).matches(col, row);
+        for v in matches.iter().rev() {
+            if let Some(differential) = v.style() {
+                style.apply_differential(differential);
+            }
+            if let Some(color) = v.color() {
+                style.set_background_color_solid(color.argb_str());
+            }
+        }
+        style
+    }
+
+    /// Get the conditional formatting rules that apply to the cells of a
+    /// range.
+    ///
+    /// Rules of each cell are evaluated in priority order and evaluation
+    /// stops at a matching rule with `stopIfTrue`. Expressions may combine
+    /// operators, constants, cell references of the worksheet and common
+    /// logical, math and information functions. Other expressions are
+    /// returned as unsupported matches.
+    /// # Arguments
+    /// * `range` - Range of the cells. ex) `"A1:C10"`, `"A:A"` or `"1:3"`.
+    ///   Whole columns and rows stop at the last used row and column.
+    /// # Return value
+    /// * `Vec<ConditionalFormatMatch>` - Matches ordered by row, column and
+    ///   priority.
+    #[must_use]
+    pub fn conditional_format_matches(&self, range: &str) -> Vec<ConditionalFormatMatch> {
+        let mut target = Range::default();
+        target.set_range(range);
+        let (col_start, row_start, mut col_end, mut row_end) = range_bounds(&target);
+        let (highest_col, highest_row) = self.highest_column_and_row();
+        if target.coordinate_start_col().is_none() {
+            col_end = col_end.min(highest_col);
+        }
+        if target.coordinate_start_row().is_none() {
+            row_end = row_end.min(highest_row);
+        }
+
+        let mut evaluator = ConditionalFormatEvaluator::new(self);
+        let mut result = Vec::new();
+        for row in row_start..=row_end {
+            for col in col_start..=col_end {
+                result.extend(evaluator.matches(col, row));
+            }
+        }
+        result
+    }
+
+    /// (This method is crate only.)
+    /// Merge an `x14:conditionalFormatting` into the rules sharing its ids.
+    /// Rules with no counterpart are added as a new `ConditionalFormatting`.
+    pub(crate) fn merge_x14_conditional_formatting(&mut self, mut value: ConditionalFormatting) {
+        let mut rest = Vec::new();
+        for rule in std::mem::take(value.conditional_collection_mut()) {
+            let base = self
+                .conditional_formatting_collection
+                .iter_mut()
+                .flat_map(ConditionalFormatting::conditional_collection_mut)
+                .find(|v| !rule.id().is_empty() && v.id() == rule.id());
+            match base {
+                Some(v) => v.merge_x14(rule),
+                None => rest.push(rule),
+            }
+        }
+        if !rest.is_empty() {
+            value.set_conditional_collection(rest);
+            self.conditional_formatting_collection.push(value);
+        }
+    }
+
     // ************************
     // Hyperlink
     // ************************
@@ -1035,6 +1351,11 @@ impl Worksheet {
         self.auto_filter = Some(auto_filter);
     }
 
+    #[inline]
+    pub(crate) fn set_auto_filter_crate(&mut self, value: AutoFilter) {
+        self.auto_filter = Some(value);
+    }
+
     // Remove Auto Filter.
     #[inline]
     pub fn remove_auto_filter(&mut self) {
@@ -1762,10 +2083,10 @@ impl Worksheet {
     /// # Arguments
     /// * `sheet_name` - Sheet Name. [Caution] no duplicate other worksheet.
     pub fn set_name<S: Into<String>>(&mut self, sheet_name: S) -> &mut Self {
-        self.title = sheet_name.into().into_boxed_str();
+        let old_title = std::mem::replace(&mut self.title, sheet_name.into().into_boxed_str());
         let title = self.name().to_string();
         for defined_name in self.defined_names_mut() {
-            defined_name.set_sheet_name(&title);
+            defined_name.set_sheet_name(&old_title, &title);
         }
         self
     }
@@ -1974,6 +2295,113 @@ impl Worksheet {
         self
     }
 
+    /// Get Controls.
+    /// Form controls and `ActiveX` controls placed on the sheet.
+    #[inline]
+    #[must_use]
+    pub fn controls(&self) -> &Controls {
+        &self.controls
+    }
+
+    /// Get Controls in mutable.
+    #[inline]
+    pub fn controls_mut(&mut self) -> &mut Controls {
+        &mut self.controls
+    }
+
+    /// Set Controls.
+    /// # Arguments
+    /// * `value` - `Controls`.
+    #[inline]
+    pub fn set_controls(&mut self, value: Controls) -> &mut Self {
+        self.controls = value;
+        self
+    }
+
+    /// Add a form control.
+    /// A control without a name is named after its type. ex) "Check Box 1"
+    /// # Arguments
+    /// * `value` - `FormControl`.
+    pub fn add_form_control(&mut self, mut value: FormControl) -> &mut FormControl {
+        if value.name().is_empty() {
+            let count = self.controls.form_control().len()
+                + self.controls.active_x_control().len()
+                + 1;
+            let prefix = match value.object_type() {
+                FormControlTypeValues::Button => "Button",
+                FormControlTypeValues::CheckBox => "Check Box",
+                FormControlTypeValues::Dialog => "Dialog",
+                FormControlTypeValues::Drop => "Drop Down",
+                FormControlTypeValues::EditBox => "Edit Box",
+                FormControlTypeValues::GroupBox => "Group Box",
+                FormControlTypeValues::Label => "Label",
+                FormControlTypeValues::List => "List Box",
+                FormControlTypeValues::Radio => "Option Button",
+                FormControlTypeValues::Scroll => "Scroll Bar",
+                FormControlTypeValues::Spin => "Spinner",
+            };
+            value.set_name(format!("{prefix} {count}"));
+        }
+        self.controls.add_form_control(value);
+        self.controls.form_control_mut().last_mut().unwrap()
+    }
+
+    /// Add a checkbox.
+    /// # Arguments
+    /// * `from_coordinate` - Top left cell. ex) "B2"
+    /// * `to_coordinate` - Bottom right cell. ex) "D3"
+    /// * `text` - Caption of the checkbox.
+    #[inline]
+    pub fn add_checkbox(
+        &mut self,
+        from_coordinate: &str,
+        to_coordinate: &str,
+        text: &str,
+    ) -> &mut FormControl {
+        let mut obj =
+            FormControl::new(FormControlTypeValues::CheckBox, from_coordinate, to_coordinate);
+        obj.set_text(text);
+        self.add_form_control(obj)
+    }
+
+    /// Add a drop-down.
+    /// # Arguments
+    /// * `from_coordinate` - Top left cell. ex) "B5"
+    /// * `to_coordinate` - Bottom right cell. ex) "D6"
+    /// * `input_range` - Range holding the items. ex) "$F$1:$F$3"
+    #[inline]
+    pub fn add_dropdown(
+        &mut self,
+        from_coordinate: &str,
+        to_coordinate: &str,
+        input_range: &str,
+    ) -> &mut FormControl {
+        let mut obj = FormControl::new(FormControlTypeValues::Drop, from_coordinate, to_coordinate);
+        obj.set_input_range(input_range);
+        self.add_form_control(obj)
+    }
+
+    /// Add a button.
+    /// # Arguments
+    /// * `from_coordinate` - Top left cell. ex) "B8"
+    /// * `to_coordinate` - Bottom right cell. ex) "D9"
+    /// * `text` - Caption of the button.
+    /// * `macro_name` - Macro run when the button is clicked. ex)
+    ///   "[0]!Recalculate"
+    #[inline]
+    pub fn add_button(
+        &mut self,
+        from_coordinate: &str,
+        to_coordinate: &str,
+        text: &str,
+        macro_name: &str,
+    ) -> &mut FormControl {
+        let mut obj =
+            FormControl::new(FormControlTypeValues::Button, from_coordinate, to_coordinate);
+        obj.set_text(text).set_macro_name(macro_name);
+        self.add_form_control(obj)
+    }
+
     /// Get Defined Name (Vec).
     #[inline]
     #[must_use]
@@ -2020,15 +2448,205 @@ impl Worksheet {
     /// # Arguments
     /// * `name` - Name. ex) "`DefinedName01`"
     /// * `address` - Address. ex) "A1:A2"
-    #[inline]
-    pub fn add_defined_name<S: Into<String>>(&mut self, name: S, address: S) -> Result<(), &str> {
+    /// # Errors
+    /// `XlsxError::InvalidName` if the name breaks the rules of Excel and
+    /// `XlsxError::NameDuplicate` if the sheet already has it.
+    /// `Workbook::names_mut` also checks the names of the other sheets.
+    pub fn add_defined_name<S: Into<String>>(
+        &mut self,
+        name: S,
+        address: S,
+    ) -> Result<(), XlsxError> {
+        let name = name.into();
+        if !DefinedName::is_valid_name(&name) {
+            return Err(XlsxError::InvalidName());
+        }
+        if self
+            .defined_names
+            .iter()
+            .any(|v| v.name().eq_ignore_ascii_case(&name))
+        {
+            return Err(XlsxError::NameDuplicate());
+        }
         let mut defined_name = DefinedName::default();
-        defined_name.set_name(name.into());
+        defined_name.set_name(name);
         defined_name.set_address(address.into());
         self.add_defined_names(defined_name);
         Ok(())
     }
 
+    /// Get the print area of the sheet.
+    /// ex) "Sheet1!$A$1:$D$20"
+    #[must_use]
+    pub fn print_area(&self) -> Option<String> {
+        self.built_in_name(DefinedName::PRINT_AREA)
+            .map(DefinedName::address)
+    }
+
+    /// Set the print area of the sheet.
+    /// The coordinates are stored as absolute references.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:D20" or "A1:D20,F1:H20"
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.set_print_area("A1:D20");
+    /// assert_eq!(worksheet.print_area().unwrap(), "Sheet1!$A$1:$D$20");
+    /// ```
+    pub fn set_print_area<S: Into<String>>(&mut self, range: S) -> &mut Self {
+        let range = range.into();
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(DefinedName::PRINT_AREA);
+        for value in range.split(',') {
+            let (_, value) = split_address(value.trim());
+            let mut obj = Range::default();
+            obj.set_range(value).lock_all();
+            defined_name.add_address(join_address(self.name(), &obj.range()));
+        }
+        self.set_built_in_name(defined_name);
+        self
+    }
+
+    /// Remove the print area of the sheet.
+    #[inline]
+    pub fn remove_print_area(&mut self) -> &mut Self {
+        self.defined_names
+            .retain(|v| v.name() != DefinedName::PRINT_AREA);
+        self
+    }
+
+    /// Get the rows repeated at the top of each printed page.
+    #[must_use]
+    pub fn print_title_rows(&self) -> Option<RangeInclusive<u32>> {
+        self.built_in_name(DefinedName::PRINT_TITLES)?
+            .address_obj()
+            .iter()
+            .map(Address::range)
+            .filter(|v| v.coordinate_start_col().is_none())
+            .find_map(|v| {
+                let start = v.coordinate_start_row()?.num();
+                let end = v.coordinate_end_row().map_or(start, RowReference::num);
+                Some(start..=end)
+            })
+    }
+
+    /// Set the rows repeated at the top of each printed page.
+    /// # Arguments
+    /// * `rows` - Row numbers. ex) `1..=2`
+    /// # Errors
+    /// `XlsxError::InvalidRange` if the rows are reversed or out of the
+    /// sheet.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.set_print_title_rows(1..=2).unwrap();
+    /// assert_eq!(worksheet.print_title_rows(), Some(1..=2));
+    /// assert!(worksheet.set_print_title_rows(0..=0).is_err());
+    /// ```
+    pub fn set_print_title_rows(
+        &mut self,
+        rows: RangeInclusive<u32>,
+    ) -> Result<&mut Self, XlsxError> {
+        if !Self::is_valid_title_range(&rows, 1_048_576) {
+            return Err(XlsxError::InvalidRange(format!(
+                "rows {}..={}",
+                rows.start(),
+                rows.end()
+            )));
+        }
+        let columns = self.print_title_columns();
+        Ok(self.set_print_titles(Some(rows), columns))
+    }
+
+    /// Get the columns repeated at the left of each printed page.
+    #[must_use]
+    pub fn print_title_columns(&self) -> Option<RangeInclusive<u32>> {
+        self.built_in_name(DefinedName::PRINT_TITLES)?
+            .address_obj()
+            .iter()
+            .map(Address::range)
+            .filter(|v| v.coordinate_start_row().is_none())
+            .find_map(|v| {
+                let start = v.coordinate_start_col()?.num();
+                let end = v.coordinate_end_col().map_or(start, ColumnReference::num);
+                Some(start..=end)
+            })
+    }
+
+    /// Set the columns repeated at the left of each printed page.
+    /// # Arguments
+    /// * `columns` - Column numbers. ex) `1..=1` is column A.
+    /// # Errors
+    /// `XlsxError::InvalidRange` if the columns are reversed or out of the
+    /// sheet.
+    pub fn set_print_title_columns(
+        &mut self,
+        columns: RangeInclusive<u32>,
+    ) -> Result<&mut Self, XlsxError> {
+        if !Self::is_valid_title_range(&columns, 16_384) {
+            return Err(XlsxError::InvalidRange(format!(
+                "columns {}..={}",
+                columns.start(),
+                columns.end()
+            )));
+        }
+        let rows = self.print_title_rows();
+        Ok(self.set_print_titles(rows, Some(columns)))
+    }
+
+    /// Remove the rows and columns repeated on each printed page.
+    #[inline]
+    pub fn remove_print_titles(&mut self) -> &mut Self {
+        self.defined_names
+            .retain(|v| v.name() != DefinedName::PRINT_TITLES);
+        self
+    }
+
+    #[inline]
+    fn is_valid_title_range(range: &RangeInclusive<u32>, max: u32) -> bool {
+        1 <= *range.start() && range.start() <= range.end() && *range.end() <= max
+    }
+
+    fn set_print_titles(
+        &mut self,
+        rows: Option<RangeInclusive<u32>>,
+        columns: Option<RangeInclusive<u32>>,
+    ) -> &mut Self {
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(DefinedName::PRINT_TITLES);
+        if let Some(v) = columns {
+            let range = format!(
+                "${}:${}",
+                string_from_column_index(*v.start()),
+                string_from_column_index(*v.end())
+            );
+            defined_name.add_address(join_address(self.name(), &range));
+        }
+        if let Some(v) = rows {
+            let range = format!("${}:${}", v.start(), v.end());
+            defined_name.add_address(join_address(self.name(), &range));
+        }
+        self.set_built_in_name(defined_name);
+        self
+    }
+
+    fn built_in_name(&self, name: &str) -> Option<&DefinedName> {
+        self.defined_names
+            .iter()
+            .find(|v| v.has_local_sheet_id() && v.name() == name)
+    }
+
+    /// Replace the sheet-scoped name of the same name.
+    /// The local sheet id is fixed up with the index of the sheet on writing.
+    fn set_built_in_name(&mut self, mut defined_name: DefinedName) {
+        defined_name.set_local_sheet_id(0);
+        self.defined_names
+            .retain(|v| !(v.has_local_sheet_id() && v.name() == defined_name.name()));
+        self.defined_names.push(defined_name);
+    }
+
     /// Get Print Options.
     #[inline]
     #[must_use]
@@ -2169,6 +2787,170 @@ impl Worksheet {
         self.tables_mut()
     }
 
+    /// Get a table by its name. The name is not case sensitive.
+    #[inline]
+    #[must_use]
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|v| v.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get a table by its name in mutable. The name is not case sensitive.
+    #[inline]
+    pub fn table_mut(&mut self, name: &str) -> Option<&mut Table> {
+        self.tables
+            .iter_mut()
+            .find(|v| v.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Add a row of data to the end of a table and grow its area.
+    /// The values fill the columns without a calculated column formula, in
+    /// order. Columns with a calculated column formula get the formula.
+    /// When the table has a totals row, a row is inserted above it. When the
+    /// row below the table has cells in its columns, a row is inserted so
+    /// that they move down instead of being overwritten.
+    /// # Arguments
+    /// * `table_name` - Name of the table.
+    /// * `values` - Values of the row.
+    /// # Return value
+    /// * `Result<u32, XlsxError>` - Number of the added row.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut book = new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet.cell_mut("A1").set_value("Item");
+    /// sheet.cell_mut("B1").set_value("Qty");
+    /// let mut table = Table::new("Orders", ("A1", "B1"));
+    /// table.add_column(TableColumn::new("Item"));
+    /// table.add_column(TableColumn::new("Qty"));
+    /// sheet.add_table(table);
+    ///
+    /// let row = sheet.append_table_row("Orders", ["Apple", "3"]).unwrap();
+    /// assert_eq!(row, 2);
+    /// assert_eq!(sheet.value("B2"), "3");
+    /// ```
+    pub fn append_table_row<I, S>(&mut self, table_name: &str, values: I) -> Result<u32, XlsxError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let index = self.table_index(table_name)?;
+        let table = &self.tables[index];
+        let start_col = table.area().0.col_num();
+        let end_col = table.area().1.col_num();
+        let row = table.area().1.row_num() + 1 - table.totals_row_count();
+        if table.has_totals_row() {
+            self.insert_new_row(row, 1);
+        } else {
+            if self.has_cells_in_columns(row, start_col, end_col) {
+                self.insert_new_row(row, 1);
+            }
+            self.tables[index].area_mut().1.set_row_num(row);
+        }
+
+        let formulas: Vec<Option<String>> = self.tables[index]
+            .columns()
+            .iter()
+            .map(|v| v.calculated_column_formula().cloned())
+            .collect();
+        let mut values = values.into_iter();
+        for (col, formula) in (start_col..).zip(formulas) {
+            match formula {
+                Some(v) => {
+                    self.cell_mut((col, row)).set_formula(v);
+                }
+                None => {
+                    if let Some(v) = values.next() {
+                        self.cell_mut((col, row)).set_value(v);
+                    }
+                }
+            }
+        }
+        Ok(row)
+    }
+
+    /// Show or hide the totals row of a table.
+    /// When shown, the label or the `SUBTOTAL` formula of each column is
+    /// written to the row below the data. When that row has cells in the
+    /// columns of the table, a row is inserted so that they move down instead
+    /// of being overwritten. When hidden, the cells of the totals row are
+    /// removed.
+    /// # Arguments
+    /// * `table_name` - Name of the table.
+    /// * `value` - Whether the totals row is shown.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut book = new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet.cell_mut("A1").set_value("Item");
+    /// sheet.cell_mut("B1").set_value("Qty");
+    /// let mut table = Table::new("Orders", ("A1", "B3"));
+    /// table.add_column(TableColumn::new("Item"));
+    /// table.add_column(TableColumn::new("Qty"));
+    /// sheet.add_table(table);
+    ///
+    /// sheet.set_table_totals_row("Orders", true).unwrap();
+    /// assert_eq!(sheet.cell("A4").unwrap().value(), "Total");
+    /// assert_eq!(sheet.cell("B4").unwrap().formula(), "SUBTOTAL(109,Orders[Qty])");
+    /// ```
+    pub fn set_table_totals_row(&mut self, table_name: &str, value: bool) -> Result<(), XlsxError> {
+        let index = self.table_index(table_name)?;
+        let table = &self.tables[index];
+        if table.has_totals_row() == value {
+            return Ok(());
+        }
+        let start_col = table.area().0.col_num();
+        let end_col = table.area().1.col_num();
+        if !value {
+            let row = table.area().1.row_num();
+            for col in start_col..=end_col {
+                self.remove_cell((col, row));
+            }
+            self.tables[index].set_totals_row(false);
+            return Ok(());
+        }
+
+        let row = table.area().1.row_num() + 1;
+        if self.has_cells_in_columns(row, start_col, end_col) {
+            self.insert_new_row(row, 1);
+        }
+        self.tables[index].set_totals_row(true);
+        let table = &self.tables[index];
+        let row = table.area().1.row_num();
+        let cells: Vec<(Option<String>, Option<String>)> = (0..table.columns().len())
+            .map(|i| {
+                let label = table.columns()[i].totals_row_label().map(str::to_string);
+                (label, table.totals_row_formula(i))
+            })
+            .collect();
+        for (col, (label, formula)) in (start_col..).zip(cells) {
+            if let Some(v) = formula {
+                self.cell_mut((col, row)).set_formula(v);
+            } else if let Some(v) = label {
+                self.cell_mut((col, row)).set_value_string(v);
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn has_cells_in_columns(&self, row: u32, start_col: u32, end_col: u32) -> bool {
+        (start_col..=end_col).any(|col| self.cell((col, row)).is_some())
+    }
+
+    #[inline]
+    fn table_index(&self, name: &str) -> Result<usize, XlsxError> {
+        self.tables
+            .iter()
+            .position(|v| v.name().eq_ignore_ascii_case(name))
+            .ok_or(XlsxError::NotFound())
+    }
+
     #[inline]
     #[must_use]
     pub fn has_pivot_table(&self) -> bool {
@@ -2276,6 +3058,79 @@ impl Worksheet {
         self
     }
 
+    /// Add a data validation rule to a range.
+    /// # Arguments
+    /// * `range` - Range of the cells. ex) `"A1:A10"`, `"A1:A10 C1:C10"`
+    /// * `value` - Rule to add, such as one built with
+    ///   [`DataValidation::list_from_range`].
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut book = new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet
+    ///     .add_data_validation("B2:B20", DataValidation::list(["Yes", "No"]))
+    ///     .set_error_message("Answer Yes or No.");
+    /// ```
+    pub fn add_data_validation(
+        &mut self,
+        range: &str,
+        mut value: DataValidation,
+    ) -> &mut DataValidation {
+        value.sequence_of_references_mut().set_sqref(range);
+        let list = self
+            .data_validations
+            .get_or_insert_with(DataValidations::default)
+            .data_validation_list_mut();
+        list.push(value);
+        list.last_mut().unwrap()
+    }
+
+    /// Check the value of a cell against the data validation of the
+    /// worksheet.
+    ///
+    /// List sources and references on other worksheets can not be resolved
+    /// from a worksheet on its own. Use [`Workbook::validate_all`] to resolve
+    /// them.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)`
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet(0).unwrap();
+    /// assert!(worksheet.validate_cell("A1").is_valid());
+    /// ```
+    #[must_use]
+    pub fn validate_cell<T>(&self, coordinate: T) -> ValidationResult
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        DataValidationEvaluator::new(self, None).validate(col, row)
+    }
+
+    /// Check the cells of the worksheet against its data validation.
+    ///
+    /// Only cells present in the worksheet are checked.
+    /// # Return value
+    /// * `Vec<ValidationResult>` - Cells violating a rule, ordered by row
+    ///   and column.
+    #[must_use]
+    pub fn validate_all(&self) -> Vec<ValidationResult> {
+        self.validate_all_crate(None)
+    }
+
+    pub(crate) fn validate_all_crate(&self, workbook: Option<&Workbook>) -> Vec<ValidationResult> {
+        let evaluator = DataValidationEvaluator::new(self, workbook);
+        self.cells_crate()
+            .iter_coordinates_sorted_by_row_column()
+            .filter(|(col, row)| evaluator.applies_to(*col, *row))
+            .map(|(col, row)| evaluator.validate(col, row))
+            .filter(|v| !v.is_valid())
+            .collect()
+    }
+
     #[inline]
     #[must_use]
     pub fn sheet_format_properties(&self) -> &SheetFormatProperties {
@@ -2461,6 +3316,86 @@ impl Worksheet {
         self
     }
 
+    /// Add a shape.
+    /// The shape is drawn with the theme accent color until a fill or
+    /// outline is set on the returned `Shape`.
+    /// # Arguments
+    /// * `geometry` - Preset geometry of the shape.
+    /// * `anchor` - Anchor giving the position of the shape.
+    /// # Return value
+    /// * `&mut Shape` - The added shape.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::{
+    ///     drawing::{
+    ///         PresetGeometry,
+    ///         TextAlignmentTypeValues,
+    ///         spreadsheet::{
+    ///             MarkerType,
+    ///             TwoCellAnchor,
+    ///         },
+    ///     },
+    ///     *,
+    /// };
+    ///
+    /// let mut book = new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    ///
+    /// let mut from_marker = MarkerType::default();
+    /// let mut to_marker = MarkerType::default();
+    /// from_marker.set_coordinate("B2");
+    /// to_marker.set_coordinate("D6");
+    /// let mut anchor = TwoCellAnchor::default();
+    /// anchor.set_from_marker(from_marker).set_to_marker(to_marker);
+    ///
+    /// let mut geometry = PresetGeometry::default();
+    /// geometry.set_geometry(PresetGeometry::GEOMETRY_WEDGEROUNDRECTCALLOUT);
+    ///
+    /// let mut text = RichText::default();
+    /// text.set_text("Peak month");
+    /// sheet
+    ///     .add_shape(geometry, anchor)
+    ///     .set_fill_color("FFC000")
+    ///     .set_outline("BF9000", 12700)
+    ///     .set_rotation(5.0)
+    ///     .set_text(&text)
+    ///     .set_text_alignment(TextAlignmentTypeValues::Center)
+    ///     .set_auto_fit(true);
+    /// ```
+    #[inline]
+    pub fn add_shape(&mut self, geometry: PresetGeometry, anchor: TwoCellAnchor) -> &mut Shape {
+        self.worksheet_drawing_mut().add_shape(geometry, anchor)
+    }
+
+    /// Add a text box.
+    /// # Arguments
+    /// * `anchor` - Anchor giving the position of the text box.
+    /// * `text` - Text of the text box.
+    /// # Return value
+    /// * `&mut Shape` - The added text box.
+    #[inline]
+    pub fn add_text_box(&mut self, anchor: TwoCellAnchor, text: &RichText) -> &mut Shape {
+        self.worksheet_drawing_mut().add_text_box(anchor, text)
+    }
+
+    /// Add a straight connector between two shapes.
+    /// # Arguments
+    /// * `anchor` - Anchor giving the position of the connector.
+    /// * `start_shape_id` - Id of the start shape. see [`Shape::id`].
+    /// * `end_shape_id` - Id of the end shape. see [`Shape::id`].
+    /// # Return value
+    /// * `&mut ConnectionShape` - The added connector.
+    #[inline]
+    pub fn add_connector(
+        &mut self,
+        anchor: TwoCellAnchor,
+        start_shape_id: u32,
+        end_shape_id: u32,
+    ) -> &mut ConnectionShape {
+        self.worksheet_drawing_mut()
+            .add_connector(anchor, start_shape_id, end_shape_id)
+    }
+
     #[inline]
     pub fn chart<T>(&self, coordinate: T) -> Option<&Chart>
     where
@@ -2569,6 +3504,29 @@ impl Worksheet {
                 }
             }
         }
+        let control_images = self
+            .controls()
+            .form_control()
+            .iter()
+            .map(|v| (v.control_properties(), v.shape()))
+            .chain(
+                self.controls()
+                    .active_x_control()
+                    .iter()
+                    .map(|v| (v.control_properties(), v.shape())),
+            );
+        for (control_properties, shape) in control_images {
+            let image = control_properties.image().into_iter();
+            let fill = shape.fill().and_then(|v| v.image()).into_iter();
+            for media_object in image.chain(fill) {
+                let is_new = !list
+                    .iter()
+                    .any(|v| v.image_name() == media_object.image_name());
+                if is_new {
+                    list.push(media_object);
+                }
+            }
+        }
         for comment in self.comments() {
             if let Some(fill) = comment.shape().fill() {
                 if let Some(media_object) = fill.image() {
@@ -2581,6 +3539,15 @@ impl Worksheet {
                 }
             }
         }
+        for image in self.header_footer().images() {
+            let media_object = image.image();
+            let is_new = !list
+                .iter()
+                .any(|v| v.image_name() == media_object.image_name());
+            if is_new {
+                list.push(media_object);
+            }
+        }
 
         list
     }
@@ -2616,6 +3583,82 @@ impl Worksheet {
         !self.defined_names().is_empty()
     }
 
+    /// (This method is crate only.)
+    /// Replace the sheet name of the references to a renamed sheet in cell
+    /// formulas, defined names, charts, data validations and conditional
+    /// formats.
+    pub(crate) fn rename_sheet_reference(&mut self, old_name: &str, new_name: &str) {
+        for cell in self.cells_mut() {
+            if let Some(formula) = cell.cell_value_mut().formula_obj_mut() {
+                formula.map_text(|v| rename_sheet_in_formula(v, old_name, new_name));
+            }
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.rename_sheet_reference(old_name, new_name);
+        }
+        for formula in self.chart_formula_mut() {
+            formula.rename_sheet_reference(old_name, new_name);
+        }
+        if let Some(data_validations) = self.data_validations_mut() {
+            for data_validation in data_validations.data_validation_list_mut() {
+                data_validation.rename_sheet_reference(old_name, new_name);
+            }
+        }
+        if let Some(data_validations) = self.data_validations_2010_mut() {
+            for data_validation in data_validations.data_validation_list_mut() {
+                data_validation.rename_sheet_reference(old_name, new_name);
+            }
+        }
+        for conditional_formatting in &mut self.conditional_formatting_collection {
+            for rule in conditional_formatting.conditional_collection_mut() {
+                rule.rename_sheet_reference(old_name, new_name);
+            }
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Turn the references to a removed sheet into `#REF!`.
+    /// Excel 2010 data validations, which can only hold a range, are removed.
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        for cell in self.cells_mut() {
+            if let Some(formula) = cell.cell_value_mut().formula_obj_mut() {
+                formula.map_text(|v| remove_sheet_in_formula(v, sheet_name));
+            }
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.remove_sheet_reference(sheet_name);
+        }
+        for formula in self.chart_formula_mut() {
+            formula.remove_sheet_reference(sheet_name);
+        }
+        if let Some(data_validations) = self.data_validations_mut() {
+            for data_validation in data_validations.data_validation_list_mut() {
+                data_validation.remove_sheet_reference(sheet_name);
+            }
+        }
+        if let Some(data_validations) = self.data_validations_2010_mut() {
+            data_validations
+                .data_validation_list_mut()
+                .retain(|v| !v.has_sheet_reference(sheet_name));
+        }
+        for conditional_formatting in &mut self.conditional_formatting_collection {
+            for rule in conditional_formatting.conditional_collection_mut() {
+                rule.remove_sheet_reference(sheet_name);
+            }
+        }
+    }
+
+    fn chart_formula_mut(&mut self) -> Vec<&mut ChartFormula> {
+        let mut result: Vec<&mut ChartFormula> = Vec::new();
+        for chart in self.worksheet_drawing.chart_collection_mut() {
+            result.extend(chart.plot_area_mut().formula_mut());
+        }
+        if let Some(chartsheet) = self.chartsheet.as_mut() {
+            result.extend(chartsheet.chart_mut().plot_area_mut().formula_mut());
+        }
+        result
+    }
+
     #[inline]
     pub(crate) fn is_deserialized(&self) -> bool {
         self.raw_data_of_worksheet.is_none()
@@ -2628,6 +3671,13 @@ impl Worksheet {
             .expect("Not found at raw data of worksheet.")
     }
 
+    #[inline]
+    pub(crate) fn raw_data_of_worksheet_mut(&mut self) -> &mut RawWorksheet {
+        self.raw_data_of_worksheet
+            .as_mut()
+            .expect("Not found at raw data of worksheet.")
+    }
+
     #[inline]
     #[deprecated(since = "3.0.0", note = "Use raw_data_of_worksheet()")]
     pub(crate) fn get_raw_data_of_worksheet(&self) -> &RawWorksheet {
@@ -2653,6 +3703,18 @@ impl Worksheet {
         self
     }
 
+    /// (This method is crate only.)
+    /// Parts and elements of the worksheet that are not supported.
+    #[inline]
+    pub(crate) fn passthrough(&self) -> &RawPassthrough {
+        &self.passthrough
+    }
+
+    #[inline]
+    pub(crate) fn passthrough_mut(&mut self) -> &mut RawPassthrough {
+        &mut self.passthrough
+    }
+
     #[inline]
     #[must_use]
     pub fn sheet_protection(&self) -> Option<&SheetProtection> {
@@ -2690,6 +3752,50 @@ impl Worksheet {
         self
     }
 
+    /// Whether this sheet is a chart sheet.
+    /// A chart sheet has no cells. Writing a workbook fails when cells were
+    /// added to one of its chart sheets.
+    #[inline]
+    #[must_use]
+    pub fn is_chartsheet(&self) -> bool {
+        self.chartsheet.is_some()
+    }
+
+    /// Get the chart sheet content.
+    /// Returns `None` for ordinary worksheets.
+    #[inline]
+    #[must_use]
+    pub fn chartsheet(&self) -> Option<&Chartsheet> {
+        self.chartsheet.as_deref()
+    }
+
+    #[inline]
+    pub fn chartsheet_mut(&mut self) -> Option<&mut Chartsheet> {
+        self.chartsheet.as_deref_mut()
+    }
+
+    /// Turn this sheet into a chart sheet.
+    #[inline]
+    pub fn set_chartsheet(&mut self, value: Chartsheet) -> &mut Self {
+        self.chartsheet = Some(Box::new(value));
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Reject the cells of a chart sheet, which can not be written.
+    pub(crate) fn check_chartsheet_cells(&self) -> Result<(), XlsxError> {
+        if !self.is_chartsheet() {
+            return Ok(());
+        }
+        match self.cells.collection_sorted().first() {
+            Some(cell) => Err(XlsxError::InvalidCell(
+                cell.coordinate().to_string(),
+                format!("chart sheet '{}' can not have cells", self.name()),
+            )),
+            None => Ok(()),
+        }
+    }
+
     /// (This method is crate only.)
     /// Has Ole Objects.
     #[inline]
@@ -2697,11 +3803,23 @@ impl Worksheet {
         !self.ole_objects.ole_object().is_empty()
     }
 
+    /// (This method is crate only.)
+    /// Has Controls.
+    #[inline]
+    pub(crate) fn has_controls(&self) -> bool {
+        !self.controls.is_empty()
+    }
+
     /// (This method is crate only.)
     /// Has Legacy Drawing.
     #[inline]
     pub(crate) fn has_legacy_drawing(&self) -> bool {
-        self.has_comments() || self.has_ole_objects()
+        self.has_comments() || self.has_ole_objects() || self.has_controls()
+    }
+
+    #[inline]
+    pub(crate) fn has_legacy_drawing_header_footer(&self) -> bool {
+        !self.header_footer().images().is_empty()
     }
 
     /// Moving a section of the sheet
@@ -2883,27 +4001,27 @@ impl Worksheet {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        let r_id_value = get_attribute(e, b"r:id").unwrap();
+    ) -> Result<(), XlsxError> {
+        let r_id_value = required_attribute(reader, e, "r:id")?;
         self.set_r_id(r_id_value);
-        let sheet_id_value = get_attribute(e, b"sheetId").unwrap();
+        let sheet_id_value = required_attribute(reader, e, "sheetId")?;
         self.set_sheet_id(sheet_id_value);
-        let name_value = get_attribute(e, b"name").unwrap();
+        let name_value = required_attribute(reader, e, "name")?;
         self.set_name(escape::unescape(&name_value).unwrap());
         set_string_from_xml!(self, e, state, "state");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sheet" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sheet")
+            Event::Eof => return Err(unexpected_eof(reader, "sheet"))
         );
     }
 
@@ -2987,6 +4105,16 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // tables
+        for table in &mut self.tables {
+            table.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // auto filter
         if let Some(v) = self.auto_filter_mut() {
             v.adjustment_insert_coordinate(
@@ -3096,6 +4224,19 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // tables
+        self.tables.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        for table in &mut self.tables {
+            table.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // auto filter
         let is_remove = match self.auto_filter() {
             Some(v) => v.range().is_remove_coordinate(
@@ -3190,3 +4331,14 @@ impl AdjustmentCoordinateWithSheet for Worksheet {
             );
     }
 }
+
+/// A worksheet that is not deserialized can not be serialized, since its
+/// parts depend on the shared strings and the styles of the workbook.
+#[cfg(feature = "serde")]
+#[allow(clippy::ref_option)]
+fn not_deserialized<S: serde::Serializer>(
+    _: &Option<RawWorksheet>,
+    _: S,
+) -> Result<S::Ok, S::Error> {
+    Err(serde::ser::Error::custom(XlsxError::NotDeserialized()))
+}
@@ -10,15 +10,21 @@ use zip::result::ZipResult;
 
 use crate::{
     helper::const_str::{
+        ACTIVEX_BIN_TYPE,
+        ACTIVEX_TYPE,
         CHART_TYPE,
+        CHARTSHEET_TYPE,
         COMMENTS_TYPE,
         CORE_PROPS_TYPE,
+        CTRL_PROP_TYPE,
         CUSTOM_PROPS_TYPE,
         DRAWING_TYPE,
         OLE_OBJECT_TYPE,
         PIVOT_CACHE_DEF_TYPE,
         PIVOT_TABLE_TYPE,
+        PKG_ACTIVEX,
         PKG_CHARTS,
+        PKG_CTRL_PROPS,
         PKG_DRAWINGS,
         PKG_EMBEDDINGS,
         PKG_PRNTR_SETTINGS,
@@ -280,6 +286,33 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
         }
     }
 
+    pub(crate) fn add_file_at_ctrl_prop(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{PKG_CTRL_PROPS}/ctrlProp{index}.xml");
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
+    pub(crate) fn add_file_at_active_x(&mut self, writer: &[u8]) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{PKG_ACTIVEX}/activeX{index}.xml");
+            if !self.check_file_exist(&file_path) {
+                self.add_bin(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     #[inline]
     pub(crate) fn add_file_at_table(
         &mut self,
@@ -341,6 +374,11 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                 content_type = SHEET_TYPE;
             }
 
+            // Override chartsheet
+            if file.starts_with("/xl/chartsheets/sheet") {
+                content_type = CHARTSHEET_TYPE;
+            }
+
             // Override table
             if file.starts_with("/xl/tables/table") {
                 content_type = TABLE_TYPE;
@@ -391,6 +429,20 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                 content_type = OLE_OBJECT_TYPE;
             }
 
+            // Override form control properties
+            if file.starts_with("/xl/ctrlProps/ctrlProp") {
+                content_type = CTRL_PROP_TYPE;
+            }
+
+            // Override activeX
+            if file.starts_with("/xl/activeX/activeX") {
+                content_type = match Path::new(&file).extension().and_then(|v| v.to_str()) {
+                    Some("xml") => ACTIVEX_TYPE,
+                    Some("bin") => ACTIVEX_BIN_TYPE,
+                    _ => content_type,
+                };
+            }
+
             // Override xl/vbaProject.bin
             if file.starts_with("/xl/vbaProject.bin") {
                 content_type = VBA_TYPE;
@@ -2,5 +2,7 @@
 
 pub mod csv;
 pub(crate) mod driver;
+pub mod ods;
 pub mod streaming_writer;
+pub mod xlsb;
 pub mod xlsx;
@@ -0,0 +1,204 @@
+//! Writer of the `OpenDocument` spreadsheet.
+
+use std::{
+    fs,
+    io,
+    path::Path,
+};
+
+use quick_xml::{
+    Writer,
+    events::{
+        BytesDecl,
+        Event,
+    },
+};
+
+use super::driver::{
+    write_end_tag,
+    write_new_line,
+    write_start_tag,
+};
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        ODF_FO_NS,
+        ODF_MANIFEST_NS,
+        ODF_NUMBER_NS,
+        ODF_OFFICE_NS,
+        ODF_STYLE_NS,
+        ODF_TABLE_NS,
+        ODF_TEXT_NS,
+        ODS_TYPE,
+        PKG_ODS_CONTENT,
+        PKG_ODS_MANIFEST,
+        PKG_ODS_MIMETYPE,
+        PKG_ODS_STYLES,
+    },
+    structs::{
+        Workbook,
+        WriterManager,
+    },
+};
+
+pub(crate) mod content;
+pub(crate) mod formula;
+pub(crate) mod styles;
+
+fn write_zip_to_writer<W: io::Write + io::Seek>(wb: &Workbook, writer: W) -> Result<(), XlsxError> {
+    for sheet in wb.sheet_collection_no_check() {
+        if !sheet.is_deserialized() {
+            return Err(XlsxError::NotDeserialized());
+        }
+    }
+    let arv = zip::ZipWriter::new(writer);
+    let mut writer_manager = WriterManager::new(arv);
+
+    // The media type is the first file and is not compressed.
+    writer_manager.set_is_light(true);
+    writer_manager.add_bin(PKG_ODS_MIMETYPE, ODS_TYPE.as_bytes())?;
+    writer_manager.set_is_light(false);
+
+    let (content, pictures) = content::write(wb);
+    writer_manager.add_writer(PKG_ODS_CONTENT, content)?;
+    writer_manager.add_writer(PKG_ODS_STYLES, write_styles())?;
+    for picture in &pictures {
+        writer_manager.add_bin(&picture.path, &picture.data)?;
+    }
+    let mut files: Vec<(&str, &str)> = vec![
+        ("/", ODS_TYPE),
+        (PKG_ODS_CONTENT, "text/xml"),
+        (PKG_ODS_STYLES, "text/xml"),
+    ];
+    files.extend(
+        pictures
+            .iter()
+            .map(|v| (v.path.as_str(), v.media_type.as_str())),
+    );
+    writer_manager.add_writer(PKG_ODS_MANIFEST, write_manifest(&files))?;
+
+    writer_manager.finish()?;
+    Ok(())
+}
+
+pub(crate) fn new_xml_writer() -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    writer
+        .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
+        .unwrap();
+    write_new_line(&mut writer);
+    writer
+}
+
+fn write_manifest(files: &[(&str, &str)]) -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = new_xml_writer();
+    write_start_tag(
+        &mut writer,
+        "manifest:manifest",
+        vec![
+            ("xmlns:manifest", ODF_MANIFEST_NS).into(),
+            ("manifest:version", "1.3").into(),
+        ],
+        false,
+    );
+    for (index, (full_path, media_type)) in files.iter().enumerate() {
+        let mut attributes = vec![
+            ("manifest:full-path", *full_path).into(),
+            ("manifest:media-type", *media_type).into(),
+        ];
+        if index == 0 {
+            attributes.push(("manifest:version", "1.3").into());
+        }
+        write_start_tag(&mut writer, "manifest:file-entry", attributes, true);
+    }
+    write_end_tag(&mut writer, "manifest:manifest");
+    writer
+}
+
+/// The `Default` cell style the automatic styles inherit from, with the
+/// page the sheets are printed on.
+fn write_styles() -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = new_xml_writer();
+    write_start_tag(
+        &mut writer,
+        "office:document-styles",
+        vec![
+            ("xmlns:office", ODF_OFFICE_NS).into(),
+            ("xmlns:style", ODF_STYLE_NS).into(),
+            ("xmlns:text", ODF_TEXT_NS).into(),
+            ("xmlns:table", ODF_TABLE_NS).into(),
+            ("xmlns:fo", ODF_FO_NS).into(),
+            ("xmlns:number", ODF_NUMBER_NS).into(),
+            ("office:version", "1.3").into(),
+        ],
+        false,
+    );
+    write_start_tag(&mut writer, "office:styles", vec![], false);
+    write_start_tag(
+        &mut writer,
+        "style:style",
+        vec![
+            ("style:name", "Default").into(),
+            ("style:family", "table-cell").into(),
+        ],
+        true,
+    );
+    write_end_tag(&mut writer, "office:styles");
+    write_start_tag(&mut writer, "office:automatic-styles", vec![], false);
+    write_start_tag(
+        &mut writer,
+        "style:page-layout",
+        vec![("style:name", "Mpm1").into()],
+        true,
+    );
+    write_end_tag(&mut writer, "office:automatic-styles");
+    write_start_tag(&mut writer, "office:master-styles", vec![], false);
+    write_start_tag(
+        &mut writer,
+        "style:master-page",
+        vec![
+            ("style:name", "Default").into(),
+            ("style:page-layout-name", "Mpm1").into(),
+        ],
+        true,
+    );
+    write_end_tag(&mut writer, "office:master-styles");
+    write_end_tag(&mut writer, "office:document-styles");
+    writer
+}
+
+/// write spreadsheet file to arbitrary writer.
+/// # Arguments
+/// * `wb` - Workbook structs object.
+/// * `writer` - writer to write to.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn write_writer<W: io::Write>(wb: &Workbook, mut writer: W) -> Result<(), XlsxError> {
+    let mut cursor = io::Cursor::new(Vec::new());
+    write_zip_to_writer(wb, &mut cursor)?;
+    writer.write_all(&cursor.into_inner())?;
+    Ok(())
+}
+
+/// write spreadsheet file.
+/// Cell values, formulas, styles, merged cells, column widths, row heights,
+/// sheets, defined names and the images anchored to cells are written.
+/// Charts, comments, tables and the other parts of a workbook are not
+/// written.
+/// # Arguments
+/// * `wb` - Workbook structs object.
+/// * `path` - file path to save.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz.ods");
+/// let _unused = umya_spreadsheet::writer::ods::write(&book, path);
+/// ```
+pub fn write<P: AsRef<Path>>(wb: &Workbook, path: P) -> Result<(), XlsxError> {
+    let mut cursor = io::Cursor::new(Vec::new());
+    write_zip_to_writer(wb, &mut cursor)?;
+    fs::write(path, cursor.into_inner())?;
+    Ok(())
+}
@@ -0,0 +1,582 @@
+use std::{
+    collections::BTreeMap,
+    io::{
+        Cursor,
+        Write,
+    },
+};
+
+use quick_xml::Writer;
+
+use super::{
+    formula::{
+        range_address,
+        to_odf,
+    },
+    styles::{
+        AutomaticStyles,
+        ValueKind,
+    },
+};
+use crate::{
+    helper::{
+        const_str::{
+            ODF_DRAW_NS,
+            ODF_FO_NS,
+            ODF_NUMBER_NS,
+            ODF_OF_NS,
+            ODF_OFFICE_NS,
+            ODF_STYLE_NS,
+            ODF_SVG_NS,
+            ODF_TABLE_NS,
+            ODF_TEXT_NS,
+            ODF_XLINK_NS,
+        },
+        date::excel_to_date_time_jiff,
+    },
+    structs::{
+        Cell,
+        CellRawValue,
+        Column,
+        ColumnReference,
+        Image,
+        RichText,
+        RowReference,
+        Workbook,
+        Worksheet,
+    },
+    writer::{
+        driver::{
+            write_end_tag,
+            write_start_tag,
+            write_text_node,
+        },
+        xlsx::workbook::sheet_defined_names,
+    },
+};
+
+const MAX_COLUMN: u32 = 16384;
+const DEFAULT_COLUMN_WIDTH: f64 = 8.43;
+
+/// What is written at a position of a sheet.
+#[derive(Debug, Default)]
+struct Entry<'a> {
+    cell:    Option<&'a Cell>,
+    /// Columns and rows of a merged range starting here.
+    span:    Option<(u32, u32)>,
+    covered: bool,
+    images:  Vec<&'a Image>,
+}
+
+/// An image written to the package.
+#[derive(Debug)]
+pub(crate) struct Picture {
+    pub(crate) path:       String,
+    pub(crate) media_type: String,
+    pub(crate) data:       Vec<u8>,
+}
+
+/// Write `content.xml`, returns the images to add to the package.
+pub(crate) fn write(wb: &Workbook) -> (Writer<Cursor<Vec<u8>>>, Vec<Picture>) {
+    let mut styles = AutomaticStyles::new(wb.theme());
+    let mut pictures = Vec::new();
+
+    let mut body = Writer::new(Cursor::new(Vec::new()));
+    write_start_tag(&mut body, "office:body", vec![], false);
+    write_start_tag(&mut body, "office:spreadsheet", vec![], false);
+    for sheet in wb.sheet_collection_no_check() {
+        write_table(&mut body, sheet, &mut styles, &mut pictures);
+    }
+    write_named_expressions(&mut body, wb);
+    write_end_tag(&mut body, "office:spreadsheet");
+    write_end_tag(&mut body, "office:body");
+
+    let mut writer = super::new_xml_writer();
+    write_start_tag(
+        &mut writer,
+        "office:document-content",
+        vec![
+            ("xmlns:office", ODF_OFFICE_NS).into(),
+            ("xmlns:style", ODF_STYLE_NS).into(),
+            ("xmlns:text", ODF_TEXT_NS).into(),
+            ("xmlns:table", ODF_TABLE_NS).into(),
+            ("xmlns:draw", ODF_DRAW_NS).into(),
+            ("xmlns:fo", ODF_FO_NS).into(),
+            ("xmlns:xlink", ODF_XLINK_NS).into(),
+            ("xmlns:number", ODF_NUMBER_NS).into(),
+            ("xmlns:svg", ODF_SVG_NS).into(),
+            ("xmlns:of", ODF_OF_NS).into(),
+            ("office:version", "1.3").into(),
+        ],
+        false,
+    );
+    styles.write(&mut writer);
+    writer.get_mut().write_all(&body.into_inner().into_inner()).unwrap();
+    write_end_tag(&mut writer, "office:document-content");
+    (writer, pictures)
+}
+
+fn write_table(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    sheet: &Worksheet,
+    styles: &mut AutomaticStyles,
+    pictures: &mut Vec<Picture>,
+) {
+    let hidden = !matches!(sheet.sheet_state(), "" | "visible");
+    write_start_tag(
+        writer,
+        "table:table",
+        vec![
+            ("table:name", sheet.name()).into(),
+            ("table:style-name", AutomaticStyles::table_style(hidden)).into(),
+        ],
+        false,
+    );
+
+    let mut entries: BTreeMap<u32, BTreeMap<u32, Entry>> = BTreeMap::new();
+    for cell in sheet.cells_sorted() {
+        let coordinate = cell.coordinate();
+        let entry = entries
+            .entry(coordinate.row_num())
+            .or_default()
+            .entry(coordinate.col_num())
+            .or_default();
+        entry.cell = Some(cell);
+    }
+    for range in sheet.merge_cells() {
+        let first_row = range.coordinate_start_row().map_or(1, RowReference::num);
+        let last_row = range.coordinate_end_row().map_or(first_row, RowReference::num);
+        let first_col = range.coordinate_start_col().map_or(1, ColumnReference::num);
+        let last_col = range.coordinate_end_col().map_or(first_col, ColumnReference::num);
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                let entry = entries.entry(row).or_default().entry(col).or_default();
+                if row == first_row && col == first_col {
+                    entry.span = Some((last_col - first_col + 1, last_row - first_row + 1));
+                } else {
+                    entry.covered = true;
+                }
+            }
+        }
+    }
+    for image in sheet.image_collection() {
+        entries
+            .entry(image.row() + 1)
+            .or_default()
+            .entry(image.col() + 1)
+            .or_default()
+            .images
+            .push(image);
+    }
+
+    let last_col = entries
+        .values()
+        .filter_map(|v| v.keys().next_back().copied())
+        .chain(sheet.column_dimensions().iter().map(Column::col_num))
+        .max()
+        .unwrap_or(0)
+        .min(MAX_COLUMN);
+    write_columns(writer, sheet, last_col, styles);
+
+    let mut rows: BTreeMap<u32, Option<&BTreeMap<u32, Entry>>> =
+        entries.iter().map(|(k, v)| (*k, Some(v))).collect();
+    for row in sheet.row_dimensions() {
+        if row.custom_height() || row.hidden() {
+            rows.entry(row.row_num()).or_insert(None);
+        }
+    }
+    let mut next_row = 1;
+    for (row_num, row_entries) in rows {
+        if row_num > next_row {
+            write_empty_rows(writer, row_num - next_row);
+        }
+        next_row = row_num + 1;
+
+        let mut attributes = Vec::new();
+        if let Some(row) = sheet.row_dimensions_to_hashmap().get(&row_num) {
+            if row.custom_height() && row.height() > 0.0 {
+                attributes.push(("table:style-name", styles.row_style(row.height())).into());
+            }
+            if row.hidden() {
+                attributes.push(("table:visibility", "collapse").into());
+            }
+        }
+        write_start_tag(writer, "table:table-row", attributes, false);
+        let mut next_col = 1;
+        for (col, entry) in row_entries.into_iter().flatten() {
+            if *col > MAX_COLUMN {
+                break;
+            }
+            if *col > next_col {
+                write_empty_cells(writer, *col - next_col);
+            }
+            next_col = col + 1;
+            write_cell(writer, entry, styles, pictures);
+        }
+        if next_col == 1 {
+            write_empty_cells(writer, 1);
+        }
+        write_end_tag(writer, "table:table-row");
+    }
+    write_end_tag(writer, "table:table");
+}
+
+/// The columns up to `last_col` and a run of the default width for the
+/// rest of the sheet.
+fn write_columns(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    sheet: &Worksheet,
+    last_col: u32,
+    styles: &mut AutomaticStyles,
+) {
+    let default_width = match sheet.sheet_format_properties().default_column_width() {
+        v if v > 0.0 => v,
+        _ => DEFAULT_COLUMN_WIDTH,
+    };
+    let mut runs: Vec<(String, bool, u32)> = Vec::new();
+    for col in 1..=last_col {
+        let column = sheet.column_dimensions().iter().find(|v| v.col_num() == col);
+        let width = column.map_or(default_width, |v| {
+            if v.width() > 0.0 { v.width() } else { default_width }
+        });
+        let style = styles.column_style(width);
+        let hidden = column.is_some_and(Column::hidden);
+        match runs.last_mut() {
+            Some((s, h, count)) if *s == style && *h == hidden => *count += 1,
+            _ => runs.push((style, hidden, 1)),
+        }
+    }
+    if last_col < MAX_COLUMN {
+        runs.push((styles.column_style(default_width), false, MAX_COLUMN - last_col));
+    }
+    for (style, hidden, count) in runs {
+        let mut attributes = vec![("table:style-name", style).into()];
+        if count > 1 {
+            attributes.push(("table:number-columns-repeated", count.to_string()).into());
+        }
+        if hidden {
+            attributes.push(("table:visibility", "collapse").into());
+        }
+        attributes.push(("table:default-cell-style-name", "Default").into());
+        write_start_tag(writer, "table:table-column", attributes, true);
+    }
+}
+
+fn write_empty_rows(writer: &mut Writer<Cursor<Vec<u8>>>, count: u32) {
+    let mut attributes = Vec::new();
+    if count > 1 {
+        attributes.push(("table:number-rows-repeated", count.to_string()).into());
+    }
+    write_start_tag(writer, "table:table-row", attributes, false);
+    write_empty_cells(writer, 1);
+    write_end_tag(writer, "table:table-row");
+}
+
+fn write_empty_cells(writer: &mut Writer<Cursor<Vec<u8>>>, count: u32) {
+    let mut attributes = Vec::new();
+    if count > 1 {
+        attributes.push(("table:number-columns-repeated", count.to_string()).into());
+    }
+    write_start_tag(writer, "table:table-cell", attributes, true);
+}
+
+fn write_cell(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    entry: &Entry,
+    styles: &mut AutomaticStyles,
+    pictures: &mut Vec<Picture>,
+) {
+    if entry.covered {
+        write_start_tag(writer, "table:covered-table-cell", vec![], true);
+        return;
+    }
+    let mut attributes = Vec::new();
+    let mut kind = ValueKind::Number;
+    if let Some((name, v)) = entry.cell.and_then(|v| styles.cell_style(v.style())) {
+        attributes.push(("table:style-name", name).into());
+        kind = v;
+    }
+    if let Some((columns, rows)) = entry.span {
+        attributes.push(("table:number-columns-spanned", columns.to_string()).into());
+        attributes.push(("table:number-rows-spanned", rows.to_string()).into());
+    }
+
+    let mut paragraphs: Option<&RichText> = None;
+    let mut text = None;
+    if let Some(cell) = entry.cell {
+        let raw_value = cell.raw_value();
+        if cell.is_formula() || matches!(raw_value, CellRawValue::Error(_)) {
+            let formula = match raw_value {
+                CellRawValue::Error(v) => format!("of:={v}"),
+                _ => to_odf(cell.formula()),
+            };
+            attributes.push(("table:formula", formula).into());
+        }
+        match raw_value {
+            CellRawValue::Numeric(v) => {
+                let value_type = match kind {
+                    ValueKind::Date => {
+                        let date_time = excel_to_date_time_jiff(*v);
+                        attributes.push(("office:date-value", date_time.to_string()).into());
+                        "date"
+                    }
+                    ValueKind::Time => {
+                        attributes.push(("office:time-value", duration(*v)).into());
+                        "time"
+                    }
+                    ValueKind::Percentage => "percentage",
+                    ValueKind::Currency => "currency",
+                    ValueKind::Number | ValueKind::Text => "float",
+                };
+                attributes.insert(0, ("office:value-type", value_type).into());
+                if matches!(value_type, "float" | "percentage" | "currency") {
+                    attributes.push(("office:value", v.to_string()).into());
+                }
+                text = Some(cell.formatted_value());
+            }
+            CellRawValue::Bool(v) => {
+                attributes.insert(0, ("office:value-type", "boolean").into());
+                attributes.push(("office:boolean-value", v.to_string()).into());
+                text = Some(if *v { "TRUE" } else { "FALSE" }.to_string());
+            }
+            CellRawValue::Error(v) => {
+                attributes.insert(0, ("office:value-type", "string").into());
+                attributes.push(("office:string-value", String::new()).into());
+                text = Some(v.to_string());
+            }
+            CellRawValue::RichText(v) => {
+                attributes.insert(0, ("office:value-type", "string").into());
+                paragraphs = Some(v);
+            }
+            CellRawValue::String(_) | CellRawValue::Lazy(_) => {
+                attributes.insert(0, ("office:value-type", "string").into());
+                text = Some(cell.value().to_string());
+            }
+            CellRawValue::Empty => {}
+        }
+    }
+
+    let is_empty = text.is_none() && paragraphs.is_none() && entry.images.is_empty();
+    write_start_tag(writer, "table:table-cell", attributes, is_empty);
+    if is_empty {
+        return;
+    }
+    for image in &entry.images {
+        write_image(writer, image, pictures);
+    }
+    if let Some(v) = text {
+        for line in v.split('\n') {
+            write_start_tag(writer, "text:p", vec![], false);
+            write_text(writer, line);
+            write_end_tag(writer, "text:p");
+        }
+    }
+    if let Some(v) = paragraphs {
+        write_rich_text(writer, v, styles);
+    }
+    write_end_tag(writer, "table:table-cell");
+}
+
+/// The runs of a rich text, a paragraph for each line.
+fn write_rich_text(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    rich_text: &RichText,
+    styles: &mut AutomaticStyles,
+) {
+    write_start_tag(writer, "text:p", vec![], false);
+    for element in rich_text.rich_text_elements() {
+        let style = element.run_properties().map(|v| styles.text_style(v));
+        for (index, line) in element.text().split('\n').enumerate() {
+            if index > 0 {
+                write_end_tag(writer, "text:p");
+                write_start_tag(writer, "text:p", vec![], false);
+            }
+            if line.is_empty() {
+                continue;
+            }
+            match &style {
+                Some(v) => {
+                    write_start_tag(
+                        writer,
+                        "text:span",
+                        vec![("text:style-name", v.as_str()).into()],
+                        false,
+                    );
+                    write_text(writer, line);
+                    write_end_tag(writer, "text:span");
+                }
+                None => write_text(writer, line),
+            }
+        }
+    }
+    write_end_tag(writer, "text:p");
+}
+
+/// Text of a paragraph, the spaces that would be collapsed and the tabs are
+/// written as elements.
+fn write_text(writer: &mut Writer<Cursor<Vec<u8>>>, value: &str) {
+    let chars: Vec<char> = value.chars().collect();
+    let mut plain = String::new();
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            ' ' => {
+                let run = chars[index..].iter().take_while(|v| **v == ' ').count();
+                // A single space between words is kept as it is.
+                let is_inner = index > 0 && chars[index - 1] != '\t' && index + run < chars.len();
+                if is_inner {
+                    plain.push(' ');
+                }
+                let count = run - usize::from(is_inner);
+                if count > 0 {
+                    flush_text(writer, &mut plain);
+                    let attributes = if count > 1 {
+                        vec![("text:c", count.to_string()).into()]
+                    } else {
+                        vec![]
+                    };
+                    write_start_tag(writer, "text:s", attributes, true);
+                }
+                index += run;
+            }
+            '\t' => {
+                flush_text(writer, &mut plain);
+                write_start_tag(writer, "text:tab", vec![], true);
+                index += 1;
+            }
+            c => {
+                plain.push(c);
+                index += 1;
+            }
+        }
+    }
+    flush_text(writer, &mut plain);
+}
+
+fn flush_text(writer: &mut Writer<Cursor<Vec<u8>>>, plain: &mut String) {
+    if !plain.is_empty() {
+        write_text_node(writer, plain.as_str());
+        plain.clear();
+    }
+}
+
+fn write_image(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    image: &Image,
+    pictures: &mut Vec<Picture>,
+) {
+    let data = image.image_data();
+    if data.is_empty() {
+        return;
+    }
+    let (width, height) = match image.one_cell_anchor() {
+        Some(v) if v.extent().cx() > 0 => (
+            emu_to_cm(v.extent().cx()),
+            emu_to_cm(v.extent().cy()),
+        ),
+        _ => match imagesize::blob_size(data) {
+            Ok(v) => {
+                let width: f64 = num_traits::cast(v.width).unwrap();
+                let height: f64 = num_traits::cast(v.height).unwrap();
+                (width * 2.54 / 96.0, height * 2.54 / 96.0)
+            }
+            Err(_) => return,
+        },
+    };
+    let name = image.image_name();
+    let path = format!("Pictures/{}_{name}", pictures.len() + 1);
+    let extension = name.rsplit('.').next().unwrap_or_default().to_lowercase();
+    let media_type = match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg".to_string(),
+        "svg" => "image/svg+xml".to_string(),
+        "" => "image/png".to_string(),
+        v => format!("image/{v}"),
+    };
+
+    write_start_tag(
+        writer,
+        "draw:frame",
+        vec![
+            ("draw:z-index", pictures.len().to_string()).into(),
+            ("draw:name", name).into(),
+            ("svg:width", format!("{width:.4}cm")).into(),
+            ("svg:height", format!("{height:.4}cm")).into(),
+            ("svg:x", "0cm").into(),
+            ("svg:y", "0cm").into(),
+        ],
+        false,
+    );
+    write_start_tag(
+        writer,
+        "draw:image",
+        vec![
+            ("xlink:href", path.as_str()).into(),
+            ("xlink:type", "simple").into(),
+            ("xlink:show", "embed").into(),
+            ("xlink:actuate", "onLoad").into(),
+        ],
+        true,
+    );
+    write_end_tag(writer, "draw:frame");
+    pictures.push(Picture {
+        path,
+        media_type,
+        data: data.to_vec(),
+    });
+}
+
+fn emu_to_cm(value: i64) -> f64 {
+    let value: f64 = num_traits::cast(value).unwrap();
+    value / 360_000.0
+}
+
+/// A number of days as a duration such as `PT12H30M00S`.
+fn duration(value: f64) -> String {
+    let seconds: i64 = num_traits::cast((value.abs() * 86400.0).round()).unwrap_or(0);
+    let sign = if value < 0.0 { "-" } else { "" };
+    format!(
+        "{sign}PT{}H{:02}M{:02}S",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// The defined names, a name of a single range is a named range.
+fn write_named_expressions(writer: &mut Writer<Cursor<Vec<u8>>>, wb: &Workbook) {
+    let mut defined_names = wb.defined_names().to_vec();
+    defined_names.extend(sheet_defined_names(wb));
+    defined_names.retain(|v| !v.name().starts_with("_xlnm."));
+    if defined_names.is_empty() {
+        return;
+    }
+    write_start_tag(writer, "table:named-expressions", vec![], false);
+    for defined_name in &defined_names {
+        let address = defined_name.address();
+        match range_address(&address) {
+            Some(v) => {
+                let base = v.split(':').next().unwrap_or_default().to_string();
+                write_start_tag(
+                    writer,
+                    "table:named-range",
+                    vec![
+                        ("table:name", defined_name.name()).into(),
+                        ("table:base-cell-address", base).into(),
+                        ("table:cell-range-address", v).into(),
+                    ],
+                    true,
+                );
+            }
+            None => write_start_tag(
+                writer,
+                "table:named-expression",
+                vec![
+                    ("table:name", defined_name.name()).into(),
+                    ("table:expression", to_odf(&address)).into(),
+                ],
+                true,
+            ),
+        }
+    }
+    write_end_tag(writer, "table:named-expressions");
+}
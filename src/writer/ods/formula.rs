@@ -0,0 +1,146 @@
+use crate::helper::{
+    address::split_address,
+    coordinate::index_from_coordinate,
+    formula::{
+        FormulaTokenSubTypes,
+        FormulaTokenTypes,
+        parse_to_tokens,
+    },
+};
+
+const LAST_ROW: u32 = 1_048_576;
+const LAST_COL: u32 = 16_384;
+
+#[derive(Debug, PartialEq)]
+enum Group {
+    Paren,
+    Function,
+    Array,
+    ArrayRow,
+}
+
+/// Convert a formula in the A1 syntax of Excel to an `OpenFormula`
+/// expression, such as `of:=SUM([.A1:.B2];1)`.
+pub(crate) fn to_odf(formula: &str) -> String {
+    let formula = formula.strip_prefix('=').unwrap_or(formula);
+    let tokens = parse_to_tokens(format!("={formula}"));
+    let mut result = String::from("of:=");
+    let mut stack: Vec<Group> = Vec::new();
+    for token in &tokens {
+        let value = token.get_value();
+        let is_start = token.get_token_sub_type() == &FormulaTokenSubTypes::Start;
+        match token.get_token_type() {
+            FormulaTokenTypes::Function if is_start => match value {
+                "ARRAY" => {
+                    result.push('{');
+                    stack.push(Group::Array);
+                }
+                "ARRAYROW" => stack.push(Group::ArrayRow),
+                _ => {
+                    result.push_str(value);
+                    result.push('(');
+                    stack.push(Group::Function);
+                }
+            },
+            FormulaTokenTypes::Function | FormulaTokenTypes::Subexpression if !is_start => {
+                match stack.pop() {
+                    Some(Group::Array) => result.push('}'),
+                    Some(Group::ArrayRow) => {}
+                    _ => result.push(')'),
+                }
+            }
+            FormulaTokenTypes::Subexpression => {
+                result.push('(');
+                stack.push(Group::Paren);
+            }
+            // The tokenizer reports the separators of arguments as unions
+            // and the ones of unions as arguments.
+            FormulaTokenTypes::OperatorInfix | FormulaTokenTypes::Argument if value == "," => {
+                result.push(match stack.last() {
+                    Some(Group::Function | Group::ArrayRow) => ';',
+                    Some(Group::Array) => '|',
+                    _ => '~',
+                });
+            }
+            FormulaTokenTypes::OperatorInfix
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Intersection =>
+            {
+                result.push('!');
+            }
+            FormulaTokenTypes::Operand => match token.get_token_sub_type() {
+                FormulaTokenSubTypes::Text => {
+                    result.push('"');
+                    result.push_str(value);
+                    result.push('"');
+                }
+                FormulaTokenSubTypes::Number | FormulaTokenSubTypes::Error => {
+                    result.push_str(value);
+                }
+                FormulaTokenSubTypes::Logical => {
+                    result.push_str(&value.to_uppercase());
+                    result.push_str("()");
+                }
+                _ => result.push_str(&reference(value)),
+            },
+            FormulaTokenTypes::Whitespace | FormulaTokenTypes::Noop => {}
+            _ => result.push_str(value),
+        }
+    }
+    result
+}
+
+/// The address of a named range, such as `Sheet1.$A$1:.$B$2`, `None` when
+/// the value is not a single reference.
+pub(crate) fn range_address(value: &str) -> Option<String> {
+    if value.contains(',') {
+        return None;
+    }
+    let reference = reference(value);
+    reference
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .map(str::to_string)
+}
+
+/// A reference as `[.A1]`, `['My Sheet'.A1:.B2]` or `[Sheet1.A1:Sheet3.A1]`,
+/// a defined name is kept as it is.
+fn reference(value: &str) -> String {
+    let (sheet_name, address) = split_address(value);
+    let (first_address, last_address) = address.split_once(':').unwrap_or((address, address));
+    let is_area = address.contains(':');
+    if !is_reference(first_address, last_address, is_area) {
+        return value.to_string();
+    }
+    let (first_sheet, last_sheet) = sheet_name.split_once(':').unwrap_or((sheet_name, ""));
+    let first_sheet = odf_sheet(first_sheet);
+    if is_area || !last_sheet.is_empty() {
+        let last_sheet = odf_sheet(last_sheet);
+        format!("[{first_sheet}.{first_address}:{last_sheet}.{last_address}]")
+    } else {
+        format!("[{first_sheet}.{first_address}]")
+    }
+}
+
+fn is_reference(first: &str, last: &str, is_area: bool) -> bool {
+    let check = |value: &str| {
+        let (col, row, ..) = index_from_coordinate(value.to_uppercase());
+        let is_valid = col.is_none_or(|v| (1..=LAST_COL).contains(&v))
+            && row.is_none_or(|v| (1..=LAST_ROW).contains(&v));
+        (col.is_some(), row.is_some(), is_valid)
+    };
+    match (check(first), check(last)) {
+        ((true, true, true), (true, true, true)) => true,
+        // Whole columns and whole rows.
+        ((c1, r1, true), (c2, r2, true)) => is_area && c1 == c2 && r1 == r2 && c1 != r1,
+        _ => false,
+    }
+}
+
+/// A sheet name of a reference, quoted unless it is a bare identifier.
+fn odf_sheet(value: &str) -> String {
+    let value = value.replace("''", "'");
+    if value.is_empty() || value.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return value;
+    }
+    format!("'{}'", value.replace('\'', "''"))
+}
@@ -0,0 +1,754 @@
+use std::io::Cursor;
+
+use quick_xml::Writer;
+
+use crate::{
+    structs::{
+        Border,
+        Color,
+        Font,
+        HorizontalAlignmentValues,
+        NumberingFormat,
+        Style,
+        VerticalAlignmentRunValues,
+        VerticalAlignmentValues,
+        drawing::Theme,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+        write_text_node,
+    },
+};
+
+/// Attributes of the `style:*-properties` elements of a style.
+type Properties = Vec<(&'static str, Vec<(&'static str, String)>)>;
+
+/// The value type a cell is written with, from its data style.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum ValueKind {
+    #[default]
+    Number,
+    Percentage,
+    Currency,
+    Date,
+    Time,
+    Text,
+}
+
+impl ValueKind {
+    fn element(self) -> &'static str {
+        match self {
+            Self::Number => "number:number-style",
+            Self::Percentage => "number:percentage-style",
+            Self::Currency => "number:currency-style",
+            Self::Date => "number:date-style",
+            Self::Time => "number:time-style",
+            Self::Text => "number:text-style",
+        }
+    }
+}
+
+/// A child element of a data style.
+#[derive(Debug)]
+struct DataElement {
+    name:       &'static str,
+    attributes: Vec<(&'static str, String)>,
+    text:       Option<String>,
+}
+
+/// A `number:*-style` converted from a format code.
+#[derive(Debug, Default)]
+struct DataStyle {
+    kind:     ValueKind,
+    /// Whether the hours are not truncated, as in `[h]:mm`.
+    elapsed:  bool,
+    elements: Vec<DataElement>,
+}
+
+/// The automatic styles of `content.xml`, collected while the sheets are
+/// written.
+#[derive(Debug)]
+pub(crate) struct AutomaticStyles<'a> {
+    theme:       &'a Theme,
+    columns:     Vec<String>,
+    rows:        Vec<String>,
+    cells:       Vec<(Style, Properties, Option<usize>)>,
+    texts:       Vec<(Font, Properties)>,
+    data_styles: Vec<(String, DataStyle)>,
+}
+
+impl<'a> AutomaticStyles<'a> {
+    pub(crate) fn new(theme: &'a Theme) -> Self {
+        Self {
+            theme,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            cells: Vec::new(),
+            texts: Vec::new(),
+            data_styles: Vec::new(),
+        }
+    }
+
+    /// Name of the style of a column `width` characters wide.
+    pub(crate) fn column_style(&mut self, width: f64) -> String {
+        let inches = format!("{:.4}in", (width * 7.0 + 5.0) / 96.0);
+        format!("co{}", position_or_push(&mut self.columns, inches) + 1)
+    }
+
+    /// Name of the style of a row `height` points high.
+    pub(crate) fn row_style(&mut self, height: f64) -> String {
+        let points = format!("{height:.2}pt");
+        format!("ro{}", position_or_push(&mut self.rows, points) + 1)
+    }
+
+    /// Name of the style of a sheet.
+    pub(crate) fn table_style(hidden: bool) -> &'static str {
+        if hidden { "ta2" } else { "ta1" }
+    }
+
+    /// Name of the style of a cell with the value type of its data style,
+    /// `None` for the default style.
+    pub(crate) fn cell_style(&mut self, style: &Style) -> Option<(String, ValueKind)> {
+        if style == &Style::default() {
+            return None;
+        }
+        let index = if let Some(v) = self.cells.iter().position(|v| &v.0 == style) {
+            v
+        } else {
+            let properties = cell_properties(style, self.theme);
+            let data_style = style
+                .number_format()
+                .map(NumberingFormat::format_code)
+                .and_then(|v| self.data_style(v));
+            self.cells.push((style.clone(), properties, data_style));
+            self.cells.len() - 1
+        };
+        let kind = self.cells[index]
+            .2
+            .map(|v| self.data_styles[v].1.kind)
+            .unwrap_or_default();
+        Some((format!("ce{}", index + 1), kind))
+    }
+
+    /// Name of the style of a run of rich text.
+    pub(crate) fn text_style(&mut self, font: &Font) -> String {
+        let index = if let Some(v) = self.texts.iter().position(|v| &v.0 == font) {
+            v
+        } else {
+            let properties = vec![("style:text-properties", text_properties(font, self.theme))];
+            self.texts.push((font.clone(), properties));
+            self.texts.len() - 1
+        };
+        format!("T{}", index + 1)
+    }
+
+    fn data_style(&mut self, format_code: &str) -> Option<usize> {
+        if let Some(v) = self.data_styles.iter().position(|v| v.0 == format_code) {
+            return Some(v);
+        }
+        let data_style = DataStyle::new(format_code)?;
+        self.data_styles.push((format_code.to_string(), data_style));
+        Some(self.data_styles.len() - 1)
+    }
+
+    pub(crate) fn write(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        write_start_tag(writer, "office:automatic-styles", vec![], false);
+        for (index, (_, data_style)) in self.data_styles.iter().enumerate() {
+            data_style.write(writer, &format!("N{}", index + 1));
+        }
+        for (index, width) in self.columns.iter().enumerate() {
+            write_style(
+                writer,
+                &format!("co{}", index + 1),
+                "table-column",
+                &[],
+                &[(
+                    "style:table-column-properties",
+                    vec![
+                        ("fo:break-before", "auto".to_string()),
+                        ("style:column-width", width.clone()),
+                    ],
+                )],
+            );
+        }
+        for (index, height) in self.rows.iter().enumerate() {
+            write_style(
+                writer,
+                &format!("ro{}", index + 1),
+                "table-row",
+                &[],
+                &[(
+                    "style:table-row-properties",
+                    vec![
+                        ("style:row-height", height.clone()),
+                        ("fo:break-before", "auto".to_string()),
+                        ("style:use-optimal-row-height", "false".to_string()),
+                    ],
+                )],
+            );
+        }
+        for (name, display) in [("ta1", "true"), ("ta2", "false")] {
+            write_style(
+                writer,
+                name,
+                "table",
+                &[("style:master-page-name", "Default".to_string())],
+                &[(
+                    "style:table-properties",
+                    vec![
+                        ("table:display", display.to_string()),
+                        ("style:writing-mode", "lr-tb".to_string()),
+                    ],
+                )],
+            );
+        }
+        for (index, (_, properties, data_style)) in self.cells.iter().enumerate() {
+            let mut attributes = vec![("style:parent-style-name", "Default".to_string())];
+            if let Some(v) = data_style {
+                attributes.push(("style:data-style-name", format!("N{}", v + 1)));
+            }
+            write_style(
+                writer,
+                &format!("ce{}", index + 1),
+                "table-cell",
+                &attributes,
+                properties,
+            );
+        }
+        for (index, (_, properties)) in self.texts.iter().enumerate() {
+            write_style(writer, &format!("T{}", index + 1), "text", &[], properties);
+        }
+        write_end_tag(writer, "office:automatic-styles");
+    }
+}
+
+fn position_or_push(list: &mut Vec<String>, value: String) -> usize {
+    if let Some(v) = list.iter().position(|v| v == &value) {
+        return v;
+    }
+    list.push(value);
+    list.len() - 1
+}
+
+fn write_style(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    name: &str,
+    family: &str,
+    attributes: &[(&str, String)],
+    properties: &[(&str, Vec<(&str, String)>)],
+) {
+    let mut style_attributes = vec![("style:name", name).into(), ("style:family", family).into()];
+    style_attributes.extend(attributes.iter().map(|(k, v)| (*k, v).into()));
+    let properties: Vec<_> = properties.iter().filter(|v| !v.1.is_empty()).collect();
+    write_start_tag(writer, "style:style", style_attributes, properties.is_empty());
+    if properties.is_empty() {
+        return;
+    }
+    for (element, attributes) in properties {
+        write_start_tag(
+            writer,
+            *element,
+            attributes.iter().map(|(k, v)| (*k, v).into()).collect(),
+            true,
+        );
+    }
+    write_end_tag(writer, "style:style");
+}
+
+/// `#rrggbb` of a color, with the colors of the theme resolved.
+pub(crate) fn hex(color: &Color, theme: &Theme) -> Option<String> {
+    let argb = color.argb_with_theme(theme);
+    let rgb = argb.get(argb.len().checked_sub(6)?..)?;
+    rgb.chars()
+        .all(|c| c.is_ascii_hexdigit())
+        .then(|| format!("#{}", rgb.to_lowercase()))
+}
+
+fn cell_properties(style: &Style, theme: &Theme) -> Properties {
+    let mut cell = Vec::new();
+    let mut paragraph = Vec::new();
+    if let Some(v) = style.background_color().and_then(|v| hex(v, theme)) {
+        cell.push(("fo:background-color", v));
+    }
+    if let Some(borders) = style.borders() {
+        for (key, border) in [
+            ("fo:border-left", borders.left()),
+            ("fo:border-right", borders.right()),
+            ("fo:border-top", borders.top()),
+            ("fo:border-bottom", borders.bottom()),
+        ] {
+            if let Some(v) = border_value(border, theme) {
+                cell.push((key, v));
+            }
+        }
+        if let Some(v) = border_value(borders.diagonal(), theme) {
+            if borders.diagonal_up() {
+                cell.push(("style:diagonal-bl-tr", v.clone()));
+            }
+            if borders.diagonal_down() {
+                cell.push(("style:diagonal-tl-br", v));
+            }
+        }
+    }
+    if let Some(alignment) = style.alignment() {
+        let horizontal = match alignment.horizontal() {
+            HorizontalAlignmentValues::Left => Some("start"),
+            HorizontalAlignmentValues::Center | HorizontalAlignmentValues::CenterContinuous => {
+                Some("center")
+            }
+            HorizontalAlignmentValues::Right => Some("end"),
+            HorizontalAlignmentValues::Justify | HorizontalAlignmentValues::Distributed => {
+                Some("justify")
+            }
+            _ => None,
+        };
+        if let Some(v) = horizontal {
+            cell.push(("style:text-align-source", "fix".to_string()));
+            paragraph.push(("fo:text-align", v.to_string()));
+        }
+        let vertical = match alignment.vertical() {
+            VerticalAlignmentValues::Top => Some("top"),
+            VerticalAlignmentValues::Center => Some("middle"),
+            _ => None,
+        };
+        if let Some(v) = vertical {
+            cell.push(("style:vertical-align", v.to_string()));
+        }
+        if alignment.wrap_text() {
+            cell.push(("fo:wrap-option", "wrap".to_string()));
+        }
+        let angle = match alignment.text_rotation() {
+            v @ 1..=90 => Some(v),
+            v @ 91..=180 => Some(360 - (v - 90)),
+            _ => None,
+        };
+        if let Some(v) = angle {
+            cell.push(("style:rotation-angle", v.to_string()));
+        }
+    }
+    if let Some(protection) = style.protection() {
+        let value = match (protection.is_locked(), protection.is_hidden()) {
+            (true, true) => Some("hidden-and-protected"),
+            (false, true) => Some("formula-hidden"),
+            (false, false) => Some("none"),
+            (true, false) => None,
+        };
+        if let Some(v) = value {
+            cell.push(("style:cell-protect", v.to_string()));
+        }
+    }
+    let mut properties = vec![
+        ("style:table-cell-properties", cell),
+        ("style:paragraph-properties", paragraph),
+    ];
+    if let Some(v) = style.font() {
+        properties.push(("style:text-properties", text_properties(v, theme)));
+    }
+    properties
+}
+
+/// A border such as `0.74pt solid #000000`, `None` for no border.
+fn border_value(border: &Border, theme: &Theme) -> Option<String> {
+    let (width, line) = match border.border_style() {
+        Border::BORDER_THIN => ("0.74pt", "solid"),
+        Border::BORDER_MEDIUM => ("1.75pt", "solid"),
+        Border::BORDER_THICK => ("2.5pt", "solid"),
+        Border::BORDER_HAIR => ("0.05pt", "solid"),
+        Border::BORDER_DOTTED => ("0.74pt", "dotted"),
+        Border::BORDER_DASHED => ("0.74pt", "dashed"),
+        Border::BORDER_MEDIUMDASHED => ("1.75pt", "dashed"),
+        Border::BORDER_DASHDOT => ("0.74pt", "dash-dot"),
+        Border::BORDER_MEDIUMDASHDOT | Border::BORDER_SLANTDASHDOT => ("1.75pt", "dash-dot"),
+        Border::BORDER_DASHDOTDOT => ("0.74pt", "dash-dot-dot"),
+        Border::BORDER_MEDIUMDASHDOTDOT => ("1.75pt", "dash-dot-dot"),
+        Border::BORDER_DOUBLE => ("1.1pt", "double"),
+        _ => return None,
+    };
+    let color = border
+        .color()
+        .and_then(|v| hex(&v, theme))
+        .unwrap_or_else(|| "#000000".to_string());
+    Some(format!("{width} {line} {color}"))
+}
+
+fn text_properties(font: &Font, theme: &Theme) -> Vec<(&'static str, String)> {
+    let mut result = Vec::new();
+    if !font.name().is_empty() {
+        result.push(("fo:font-family", font.name().to_string()));
+    }
+    if font.size() > 0.0 {
+        result.push(("fo:font-size", format!("{}pt", font.size())));
+    }
+    if font.bold() {
+        result.push(("fo:font-weight", "bold".to_string()));
+    }
+    if font.italic() {
+        result.push(("fo:font-style", "italic".to_string()));
+    }
+    if let Some(v) = hex(font.color(), theme) {
+        result.push(("fo:color", v));
+    }
+    match font.underline() {
+        "" | "none" => {}
+        v => {
+            result.push(("style:text-underline-style", "solid".to_string()));
+            result.push(("style:text-underline-width", "auto".to_string()));
+            result.push(("style:text-underline-color", "font-color".to_string()));
+            if v.starts_with("double") {
+                result.push(("style:text-underline-type", "double".to_string()));
+            }
+        }
+    }
+    if font.strikethrough() {
+        result.push(("style:text-line-through-style", "solid".to_string()));
+    }
+    match font.vertical_text_alignment().val() {
+        VerticalAlignmentRunValues::Superscript => {
+            result.push(("style:text-position", "super 58%".to_string()));
+        }
+        VerticalAlignmentRunValues::Subscript => {
+            result.push(("style:text-position", "sub 58%".to_string()));
+        }
+        VerticalAlignmentRunValues::Baseline => {}
+    }
+    result
+}
+
+impl DataStyle {
+    /// Convert the first section of a format code, `None` for `General`.
+    fn new(format_code: &str) -> Option<Self> {
+        let chars: Vec<char> = first_section(format_code).chars().collect();
+        if chars.is_empty() || chars.iter().collect::<String>().eq_ignore_ascii_case("General") {
+            return None;
+        }
+        let mut result = Self::default();
+        let (mut has_date, mut has_time, mut has_number) = (false, false, false);
+        let (mut is_percentage, mut is_currency, mut is_text) = (false, false, false);
+        let mut index = 0;
+        while index < chars.len() {
+            let c = chars[index];
+            let run = chars[index..].iter().take_while(|v| v.eq_ignore_ascii_case(&c)).count();
+            match c {
+                '"' => {
+                    let end = chars[index + 1..]
+                        .iter()
+                        .position(|v| *v == '"')
+                        .map_or(chars.len(), |v| index + 1 + v);
+                    result.push_text(&chars[index + 1..end].iter().collect::<String>());
+                    index = end + 1;
+                    continue;
+                }
+                '\\' => {
+                    if let Some(v) = chars.get(index + 1) {
+                        result.push_text(&v.to_string());
+                    }
+                    index += 2;
+                    continue;
+                }
+                '_' => {
+                    result.push_text(" ");
+                    index += 2;
+                    continue;
+                }
+                '*' => {
+                    index += 2;
+                    continue;
+                }
+                '[' => {
+                    let end = chars[index..]
+                        .iter()
+                        .position(|v| *v == ']')
+                        .map_or(chars.len(), |v| index + v);
+                    let inner: String = chars[index + 1..end].iter().collect();
+                    if let Some(v) = inner.strip_prefix('$') {
+                        let symbol = v.split('-').next().unwrap_or_default();
+                        if !symbol.is_empty() {
+                            result.push("number:currency-symbol", vec![], Some(symbol));
+                            is_currency = true;
+                        }
+                    } else if let Some(v) = elapsed_element(&inner) {
+                        result.elapsed = true;
+                        has_time = true;
+                        result.push(v, long_attribute(inner.len() > 1), None);
+                    }
+                    index = end + 1;
+                    continue;
+                }
+                'y' | 'Y' => {
+                    has_date = true;
+                    result.push("number:year", long_attribute(run > 2), None);
+                }
+                'd' | 'D' => {
+                    has_date = true;
+                    let name = if run > 2 { "number:day-of-week" } else { "number:day" };
+                    result.push(name, long_attribute(run == 2 || run > 3), None);
+                }
+                'h' | 'H' => {
+                    has_time = true;
+                    result.push("number:hours", long_attribute(run > 1), None);
+                }
+                'm' | 'M' if is_minutes(&chars, index, run, &result) => {
+                    has_time = true;
+                    result.push("number:minutes", long_attribute(run > 1), None);
+                }
+                'm' | 'M' => {
+                    has_date = true;
+                    let mut attributes = long_attribute(run == 2 || run > 3);
+                    if run > 2 {
+                        attributes.push(("number:textual", "true".to_string()));
+                    }
+                    result.push("number:month", attributes, None);
+                }
+                's' | 'S' => {
+                    has_time = true;
+                    let mut attributes = long_attribute(run > 1);
+                    let decimals = if chars.get(index + run) == Some(&'.') {
+                        chars[index + run + 1..].iter().take_while(|v| **v == '0').count()
+                    } else {
+                        0
+                    };
+                    if decimals > 0 {
+                        attributes.push(("number:decimal-places", decimals.to_string()));
+                        index += decimals + 1;
+                    }
+                    result.push("number:seconds", attributes, None);
+                }
+                'A' | 'a' if ampm_length(&chars[index..]) > 0 => {
+                    has_time = true;
+                    result.push("number:am-pm", vec![], None);
+                    index += ampm_length(&chars[index..]);
+                    continue;
+                }
+                '@' => {
+                    is_text = true;
+                    result.push("number:text-content", vec![], None);
+                }
+                '0' | '#' | '?' | '.' | ',' => {
+                    let length = number_length(&chars[index..]);
+                    if length == 0 {
+                        result.push_text(&c.to_string());
+                        index += 1;
+                        continue;
+                    }
+                    let number: String = chars[index..index + length].iter().collect();
+                    result.push_number(number.trim_end());
+                    has_number = true;
+                    index += length;
+                    continue;
+                }
+                '%' => {
+                    is_percentage = true;
+                    result.push_text("%");
+                }
+                _ => result.push_text(&c.to_string()),
+            }
+            index += match c {
+                'y' | 'Y' | 'd' | 'D' | 'h' | 'H' | 'm' | 'M' | 's' | 'S' => run,
+                _ => 1,
+            };
+        }
+        result.kind = if has_date {
+            ValueKind::Date
+        } else if has_time {
+            ValueKind::Time
+        } else if is_percentage {
+            ValueKind::Percentage
+        } else if is_currency {
+            ValueKind::Currency
+        } else if is_text && !has_number {
+            ValueKind::Text
+        } else {
+            ValueKind::Number
+        };
+        if result.kind == ValueKind::Date || result.kind == ValueKind::Time {
+            result.elements.retain(|v| {
+                !matches!(
+                    v.name,
+                    "number:number" | "number:fraction" | "number:scientific-number"
+                )
+            });
+        }
+        (!result.elements.is_empty()).then_some(result)
+    }
+
+    fn push(
+        &mut self,
+        name: &'static str,
+        attributes: Vec<(&'static str, String)>,
+        text: Option<&str>,
+    ) {
+        self.elements.push(DataElement {
+            name,
+            attributes,
+            text: text.map(str::to_string),
+        });
+    }
+
+    fn push_text(&mut self, value: &str) {
+        if let Some(DataElement {
+            name: "number:text",
+            text: Some(v),
+            ..
+        }) = self.elements.last_mut()
+        {
+            v.push_str(value);
+            return;
+        }
+        self.push("number:text", vec![], Some(value));
+    }
+
+    /// A number such as `#,##0.00`, `0.00E+00` or `# ?/?`.
+    fn push_number(&mut self, value: &str) {
+        let count = |v: &str, digits: &str| v.chars().filter(|c| digits.contains(*c)).count();
+        if let Some((integer_numerator, denominator)) = value.split_once('/') {
+            let (integer, numerator) = match integer_numerator.rsplit_once(' ') {
+                Some((a, b)) => (Some(a), b),
+                None => (None, integer_numerator),
+            };
+            let mut attributes = Vec::new();
+            if let Some(v) = integer {
+                attributes.push(("number:min-integer-digits", count(v, "0").to_string()));
+            }
+            attributes.push(("number:min-numerator-digits", count(numerator, "0#?").to_string()));
+            if denominator.chars().all(|c| c.is_ascii_digit()) && !denominator.is_empty() {
+                attributes.push(("number:denominator-value", denominator.to_string()));
+            } else {
+                let digits = count(denominator, "0#?").to_string();
+                attributes.push(("number:min-denominator-digits", digits));
+            }
+            self.push("number:fraction", attributes, None);
+            return;
+        }
+        let upper = value.to_uppercase();
+        let (mantissa, exponent) = match upper.split_once('E') {
+            Some((a, b)) => (a, Some(b)),
+            None => (upper.as_str(), None),
+        };
+        let (integer, decimal) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let mut attributes = vec![
+            ("number:decimal-places", count(decimal, "0#?").to_string()),
+            ("number:min-integer-digits", count(integer, "0").to_string()),
+        ];
+        if let Some(v) = exponent {
+            attributes.push(("number:min-exponent-digits", count(v, "0").to_string()));
+            self.push("number:scientific-number", attributes, None);
+            return;
+        }
+        if integer.trim_end_matches(',').contains(',') {
+            attributes.push(("number:grouping", "true".to_string()));
+        }
+        self.push("number:number", attributes, None);
+    }
+
+    fn write(&self, writer: &mut Writer<Cursor<Vec<u8>>>, name: &str) {
+        let mut attributes = vec![("style:name", name).into()];
+        if self.elapsed {
+            attributes.push(("number:truncate-on-overflow", "false").into());
+        }
+        write_start_tag(writer, self.kind.element(), attributes, false);
+        for element in &self.elements {
+            let attributes = element.attributes.iter().map(|(k, v)| (*k, v).into()).collect();
+            match &element.text {
+                Some(v) => {
+                    write_start_tag(writer, element.name, attributes, false);
+                    write_text_node(writer, v.as_str());
+                    write_end_tag(writer, element.name);
+                }
+                None => write_start_tag(writer, element.name, attributes, true),
+            }
+        }
+        write_end_tag(writer, self.kind.element());
+    }
+}
+
+fn long_attribute(is_long: bool) -> Vec<(&'static str, String)> {
+    if is_long {
+        vec![("number:style", "long".to_string())]
+    } else {
+        Vec::new()
+    }
+}
+
+/// The section of a format code for positive numbers.
+fn first_section(format_code: &str) -> &str {
+    let mut in_quote = false;
+    for (index, c) in format_code.char_indices() {
+        match c {
+            '"' => in_quote = !in_quote,
+            ';' if !in_quote => return &format_code[..index],
+            _ => {}
+        }
+    }
+    format_code
+}
+
+/// The element of an elapsed time such as `[h]` or `[mm]`.
+fn elapsed_element(value: &str) -> Option<&'static str> {
+    let c = value.chars().next()?.to_ascii_lowercase();
+    if !value.chars().all(|v| v.eq_ignore_ascii_case(&c)) {
+        return None;
+    }
+    match c {
+        'h' => Some("number:hours"),
+        'm' => Some("number:minutes"),
+        's' => Some("number:seconds"),
+        _ => None,
+    }
+}
+
+/// `m` is minutes after hours or before seconds.
+fn is_minutes(chars: &[char], index: usize, run: usize, data_style: &DataStyle) -> bool {
+    let after_hours = data_style
+        .elements
+        .iter()
+        .rev()
+        .find(|v| v.name != "number:text")
+        .is_some_and(|v| v.name == "number:hours");
+    let before_seconds = chars[index + run..]
+        .iter()
+        .find(|v| v.is_ascii_alphabetic())
+        .is_some_and(|v| v.eq_ignore_ascii_case(&'s'));
+    run <= 2 && (after_hours || before_seconds)
+}
+
+fn ampm_length(chars: &[char]) -> usize {
+    let value: String = chars.iter().take(5).collect::<String>().to_uppercase();
+    if value.starts_with("AM/PM") {
+        5
+    } else if value.starts_with("A/P") {
+        3
+    } else {
+        0
+    }
+}
+
+/// Length of a number pattern, `0` when there is no digit placeholder.
+fn number_length(chars: &[char]) -> usize {
+    let mut length = 0;
+    while length < chars.len() {
+        let c = chars[length];
+        let previous = length.checked_sub(1).map(|v| chars[v].to_ascii_uppercase());
+        let is_part = match c {
+            '0' | '#' | '?' | '.' | ',' | '/' => true,
+            'E' | 'e' => matches!(chars.get(length + 1), Some('+' | '-')),
+            '+' | '-' => previous == Some('E'),
+            '1'..='9' => chars[..length].contains(&'/'),
+            // The space between the integer and the fraction.
+            ' ' => {
+                let mut rest = chars[length + 1..].iter().skip_while(|v| matches!(v, '0' | '#' | '?'));
+                matches!(chars.get(length + 1), Some('0' | '#' | '?')) && rest.next() == Some(&'/')
+            }
+            _ => false,
+        };
+        if !is_part {
+            break;
+        }
+        length += 1;
+    }
+    if chars[..length].iter().any(|v| matches!(v, '0' | '#' | '?')) {
+        length
+    } else {
+        0
+    }
+}
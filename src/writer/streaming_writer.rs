@@ -52,6 +52,7 @@ use crate::{
         chart,
         comment,
         content_types,
+        controls,
         doc_props_app,
         doc_props_core,
         doc_props_custom,
@@ -209,6 +210,16 @@ impl<W: io::Write + io::Seek> StreamingWriter<W> {
             &mut self.writer_manager,
         )?;
 
+        // Add vml drawing of the header and footer and its relationships
+        let (vml_drawing_hf_no, rel_list) =
+            vml_drawing::write_header_footer(&worksheet, &mut self.writer_manager)?;
+        vml_drawing_rels::write(
+            &worksheet,
+            &vml_drawing_hf_no,
+            &rel_list,
+            &mut self.writer_manager,
+        )?;
+
         // Add comments
         let comment_no = comment::write(&worksheet, &mut self.writer_manager)?;
 
@@ -219,6 +230,10 @@ impl<W: io::Write + io::Seek> StreamingWriter<W> {
         let (ole_object_no_list, excel_no_list) =
             embeddings::write(&worksheet, &mut self.writer_manager)?;
 
+        // Add form control properties and activeX controls
+        let (ctrl_prop_no_list, active_x_no_list) =
+            controls::write(&worksheet, &mut self.writer_manager)?;
+
         // Add Media
         media::write(&worksheet, &mut self.writer_manager)?;
 
@@ -231,7 +246,8 @@ impl<W: io::Write + io::Seek> StreamingWriter<W> {
             });
 
         // Add tables
-        let table_no_list = table::write(&worksheet, &mut self.writer_manager)?;
+        let table_no_list =
+            table::write(&worksheet, &mut self.stylesheet, &mut self.writer_manager)?;
 
         // Add pivot tables and caches
         let pivot_table_no_list = pivot_table::write(&worksheet, &mut self.writer_manager)?;
@@ -243,10 +259,13 @@ impl<W: io::Write + io::Seek> StreamingWriter<W> {
             &worksheet_no.to_string(),
             &drawing_no,
             &vml_drawing_no,
+            &vml_drawing_hf_no,
             &comment_no,
             &threaded_comment_no,
             &ole_object_no_list,
             &excel_no_list,
+            &ctrl_prop_no_list,
+            &active_x_no_list,
             &printer_settings_no,
             &table_no_list,
             &pivot_table_no_list,
@@ -263,6 +282,10 @@ impl<W: io::Write + io::Seek> StreamingWriter<W> {
         if !defined.is_empty() {
             stub.set_defined_names(defined.to_vec());
         }
+        // keeps the filter database name of the sheet
+        if let Some(auto_filter) = worksheet.auto_filter() {
+            stub.set_auto_filter_crate(auto_filter.clone());
+        }
         self.work_book.add_sheet(stub)?;
 
         Ok(())
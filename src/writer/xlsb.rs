@@ -0,0 +1,301 @@
+//! Writer of the Excel binary workbook (BIFF12).
+
+use std::{
+    fs,
+    io,
+    path::Path,
+};
+
+use quick_xml::{
+    Writer,
+    events::{
+        BytesDecl,
+        Event,
+    },
+};
+
+use self::{
+    formula::FormulaNames,
+    worksheet::SharedStrings,
+};
+use super::{
+    driver::{
+        write_end_tag,
+        write_new_line,
+        write_start_tag,
+    },
+    xlsx::{
+        doc_props_app,
+        doc_props_core,
+        theme,
+        workbook::sheet_defined_names,
+    },
+};
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        ARC_APP,
+        ARC_CORE,
+        CONTENT_TYPES,
+        CONTYPES_NS,
+        CORE_PROPS_TYPE,
+        COREPROPS_REL,
+        OFCDOC_NS,
+        PKG_SHARED_STRINGS_BIN,
+        PKG_STYLES_BIN,
+        PKG_THEME,
+        PKG_WORKBOOK_BIN,
+        PKG_WORKBOOK_BIN_RELS,
+        REL_NS,
+        REL_TYPE,
+        SHARED_STRINGS_BIN_TYPE,
+        SHARED_STRINGS_NS,
+        SHEET_BIN_TYPE,
+        STYLES_BIN_TYPE,
+        STYLES_NS,
+        THEME_NS,
+        THEME_TYPE,
+        WORKBOOK_BIN_TYPE,
+        WORKSHEET_NS,
+        XPROPS_REL,
+        XPROPS_TYPE,
+    },
+    structs::{
+        Workbook,
+        WriterManager,
+    },
+};
+
+pub(crate) mod formula;
+pub(crate) mod record;
+pub(crate) mod styles;
+pub(crate) mod workbook;
+pub(crate) mod worksheet;
+
+fn write_zip_to_writer<W: io::Write + io::Seek>(wb: &Workbook, writer: W) -> Result<(), XlsxError> {
+    let arv = zip::ZipWriter::new(writer);
+    let mut writer_manager = WriterManager::new(arv);
+
+    doc_props_app::write(wb, &mut writer_manager)?;
+    doc_props_core::write(wb, &mut writer_manager)?;
+    theme::write(wb.theme(), &mut writer_manager)?;
+
+    let mut defined_names = wb.defined_names().to_vec();
+    defined_names.extend(sheet_defined_names(wb));
+    let names = FormulaNames {
+        sheet_names: wb
+            .sheet_collection_no_check()
+            .iter()
+            .map(|v| v.name().to_string())
+            .collect(),
+        names:       defined_names
+            .iter()
+            .map(|v| {
+                let sheet_index = v.has_local_sheet_id().then(|| v.local_sheet_id());
+                (v.name().to_string(), sheet_index)
+            })
+            .collect(),
+    };
+
+    let mut shared_strings = SharedStrings::default();
+    let mut stylesheet = wb.stylesheet().clone();
+    let mut sheet_targets = Vec::new();
+    for (index, sheet) in wb.sheet_collection_no_check().iter().enumerate() {
+        if !sheet.is_deserialized() {
+            return Err(XlsxError::NotDeserialized());
+        }
+        sheet.check_chartsheet_cells()?;
+        let target = format!("worksheets/sheet{}.bin", index + 1);
+        let data = if sheet.is_chartsheet() {
+            // Chart sheets are written as empty sheets.
+            worksheet::write(
+                &crate::structs::Worksheet::default(),
+                num_traits::cast(index).unwrap(),
+                &mut shared_strings,
+                &mut stylesheet,
+                &names,
+            )
+        } else {
+            worksheet::write(
+                sheet,
+                num_traits::cast(index).unwrap(),
+                &mut shared_strings,
+                &mut stylesheet,
+                &names,
+            )
+        };
+        writer_manager.add_bin(&format!("xl/{target}"), &data)?;
+        sheet_targets.push(target);
+    }
+
+    if !shared_strings.is_empty() {
+        writer_manager.add_bin(PKG_SHARED_STRINGS_BIN, &shared_strings.write())?;
+    }
+    writer_manager.add_bin(PKG_STYLES_BIN, &styles::write(&stylesheet))?;
+    writer_manager.add_bin(PKG_WORKBOOK_BIN, &workbook::write(wb, &defined_names, &names))?;
+
+    write_rels(&mut writer_manager)?;
+    write_workbook_rels(&sheet_targets, !shared_strings.is_empty(), &mut writer_manager)?;
+    write_content_types(&sheet_targets, !shared_strings.is_empty(), &mut writer_manager)?;
+
+    writer_manager.finish()?;
+    Ok(())
+}
+
+fn new_xml_writer() -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    writer
+        .write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )))
+        .unwrap();
+    write_new_line(&mut writer);
+    writer
+}
+
+fn write_relationships<W: io::Seek + io::Write>(
+    target: &str,
+    relationships: &[(&str, &str)],
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = new_xml_writer();
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![("xmlns", REL_NS).into()],
+        false,
+    );
+    for (index, (rel_type, rel_target)) in relationships.iter().enumerate() {
+        let r_id = format!("rId{}", index + 1);
+        write_start_tag(
+            &mut writer,
+            "Relationship",
+            vec![
+                ("Id", r_id.as_str()).into(),
+                ("Type", *rel_type).into(),
+                ("Target", *rel_target).into(),
+            ],
+            true,
+        );
+    }
+    write_end_tag(&mut writer, "Relationships");
+    writer_mng.add_writer(target, writer)
+}
+
+fn write_rels<W: io::Seek + io::Write>(writer_mng: &mut WriterManager<W>) -> Result<(), XlsxError> {
+    let relationships = [
+        (OFCDOC_NS, PKG_WORKBOOK_BIN),
+        (COREPROPS_REL, ARC_CORE),
+        (XPROPS_REL, ARC_APP),
+    ];
+    write_relationships("_rels/.rels", &relationships, writer_mng)
+}
+
+/// The relationship of a sheet is `rId{n}` for the sheet `n`, as in
+/// `xl/workbook.bin`.
+fn write_workbook_rels<W: io::Seek + io::Write>(
+    sheet_targets: &[String],
+    has_shared_strings: bool,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut relationships: Vec<(&str, &str)> = sheet_targets
+        .iter()
+        .map(|v| (WORKSHEET_NS, v.as_str()))
+        .collect();
+    relationships.push((THEME_NS, "theme/theme1.xml"));
+    relationships.push((STYLES_NS, "styles.bin"));
+    if has_shared_strings {
+        relationships.push((SHARED_STRINGS_NS, "sharedStrings.bin"));
+    }
+    write_relationships(PKG_WORKBOOK_BIN_RELS, &relationships, writer_mng)
+}
+
+fn write_content_types<W: io::Seek + io::Write>(
+    sheet_targets: &[String],
+    has_shared_strings: bool,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = new_xml_writer();
+    write_start_tag(
+        &mut writer,
+        "Types",
+        vec![("xmlns", CONTYPES_NS).into()],
+        false,
+    );
+    for (extension, content_type) in [("rels", REL_TYPE), ("xml", "application/xml")] {
+        write_start_tag(
+            &mut writer,
+            "Default",
+            vec![
+                ("Extension", extension).into(),
+                ("ContentType", content_type).into(),
+            ],
+            true,
+        );
+    }
+    let mut overrides: Vec<(String, &str)> = vec![(
+        format!("/{PKG_WORKBOOK_BIN}"),
+        WORKBOOK_BIN_TYPE,
+    )];
+    for target in sheet_targets {
+        overrides.push((format!("/xl/{target}"), SHEET_BIN_TYPE));
+    }
+    overrides.push((format!("/{PKG_THEME}"), THEME_TYPE));
+    overrides.push((format!("/{PKG_STYLES_BIN}"), STYLES_BIN_TYPE));
+    if has_shared_strings {
+        overrides.push((format!("/{PKG_SHARED_STRINGS_BIN}"), SHARED_STRINGS_BIN_TYPE));
+    }
+    overrides.push((format!("/{ARC_CORE}"), CORE_PROPS_TYPE));
+    overrides.push((format!("/{ARC_APP}"), XPROPS_TYPE));
+    for (part_name, content_type) in &overrides {
+        write_start_tag(
+            &mut writer,
+            "Override",
+            vec![
+                ("PartName", part_name.as_str()).into(),
+                ("ContentType", *content_type).into(),
+            ],
+            true,
+        );
+    }
+    write_end_tag(&mut writer, "Types");
+    writer_mng.add_writer(CONTENT_TYPES, writer)
+}
+
+/// write spreadsheet file to arbitrary writer.
+/// # Arguments
+/// * `wb` - Workbook structs object.
+/// * `writer` - writer to write to.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn write_writer<W: io::Write>(wb: &Workbook, mut writer: W) -> Result<(), XlsxError> {
+    let mut cursor = io::Cursor::new(Vec::new());
+    write_zip_to_writer(wb, &mut cursor)?;
+    writer.write_all(&cursor.into_inner())?;
+    Ok(())
+}
+
+/// write spreadsheet file.
+/// Cell values, formulas, styles, merged cells, column widths, row heights,
+/// sheets and defined names are written. Drawings, comments, tables and the
+/// other parts of a workbook are not written. A formula that can not be
+/// written is replaced by its value.
+/// # Arguments
+/// * `wb` - Workbook structs object.
+/// * `path` - file path to save.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz.xlsb");
+/// let _unused = umya_spreadsheet::writer::xlsb::write(&book, path);
+/// ```
+pub fn write<P: AsRef<Path>>(wb: &Workbook, path: P) -> Result<(), XlsxError> {
+    let mut cursor = io::Cursor::new(Vec::new());
+    write_zip_to_writer(wb, &mut cursor)?;
+    fs::write(path, cursor.into_inner())?;
+    Ok(())
+}
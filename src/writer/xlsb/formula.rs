@@ -0,0 +1,352 @@
+use crate::{
+    helper::{
+        address::split_address,
+        coordinate::index_from_coordinate,
+        formula::{
+            FormulaToken,
+            FormulaTokenSubTypes,
+            FormulaTokenTypes,
+            parse_to_tokens,
+        },
+    },
+    reader::xls::formula::{
+        error_code,
+        function_by_name,
+    },
+};
+
+const LAST_ROW: u32 = 0xF_FFFF;
+const LAST_COL: u16 = 0x3FFF;
+
+/// Sheets and defined names a formula can refer to.
+#[derive(Debug, Default)]
+pub(crate) struct FormulaNames {
+    /// Names of the sheets, the index of a sheet is also its `XTI`.
+    pub(crate) sheet_names: Vec<String>,
+    /// Defined names in the order of their `BrtName` records, with the
+    /// sheet they are local to.
+    pub(crate) names:       Vec<(String, Option<u32>)>,
+}
+impl FormulaNames {
+    fn sheet_index(&self, name: &str) -> Option<u16> {
+        let name = name.replace("''", "'");
+        let index = self
+            .sheet_names
+            .iter()
+            .position(|v| v.eq_ignore_ascii_case(&name))?;
+        u16::try_from(index).ok()
+    }
+
+    /// 1-based index of a name, the name local to `sheet_index` first.
+    fn name_index(&self, name: &str, sheet_index: Option<u32>) -> Option<u32> {
+        let find = |scope: Option<u32>| {
+            self.names
+                .iter()
+                .position(|v| v.1 == scope && v.0.eq_ignore_ascii_case(name))
+        };
+        let index = sheet_index.and_then(|v| find(Some(v))).or_else(|| find(None))?;
+        u32::try_from(index + 1).ok()
+    }
+}
+
+#[derive(Debug)]
+enum Operator {
+    Token(u8, u8),
+    Paren,
+    Function {
+        name:      String,
+        count:     u8,
+        arg_start: usize,
+    },
+}
+
+/// Compile the text of a formula to `BIFF12` tokens, the references of a
+/// defined name keep the reference class.
+/// `None` is returned for a formula using anything the compiler does not
+/// support, such as array constants or functions of add-ins.
+pub(crate) fn compile(
+    formula: &str,
+    sheet_index: Option<u32>,
+    is_name: bool,
+    names: &FormulaNames,
+) -> Option<Vec<u8>> {
+    let tokens = parse_to_tokens(format!("={formula}"));
+    if tokens.is_empty() {
+        return None;
+    }
+    let mut output: Vec<u8> = Vec::new();
+    let mut stack: Vec<Operator> = Vec::new();
+    for token in &tokens {
+        let is_reference =
+            is_name || stack.iter().any(|v| matches!(v, Operator::Function { .. }));
+        match token.get_token_type() {
+            FormulaTokenTypes::Whitespace | FormulaTokenTypes::Noop => {}
+            FormulaTokenTypes::Operand => {
+                operand(token, &mut output, is_reference, sheet_index, names)?;
+            }
+            FormulaTokenTypes::OperatorPrefix => match token.get_value() {
+                "-" => push_operator(&mut stack, &mut output, 0x13, 6),
+                "+" => push_operator(&mut stack, &mut output, 0x12, 6),
+                _ => return None,
+            },
+            FormulaTokenTypes::OperatorPostfix => output.push(0x14),
+            // The tokenizer reports the separators of arguments as unions.
+            FormulaTokenTypes::OperatorInfix
+                if token.get_value() == "," && innermost_is_function(&stack) =>
+            {
+                next_argument(&mut stack, &mut output)?;
+            }
+            FormulaTokenTypes::OperatorInfix => {
+                let (ptg, precedence) = binary_operator(token.get_value())?;
+                push_operator(&mut stack, &mut output, ptg, precedence);
+            }
+            FormulaTokenTypes::Subexpression => {
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Start {
+                    stack.push(Operator::Paren);
+                } else {
+                    pop_operators(&mut stack, &mut output);
+                    if !matches!(stack.pop(), Some(Operator::Paren)) {
+                        return None;
+                    }
+                    output.push(0x15);
+                }
+            }
+            FormulaTokenTypes::Function => {
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Start {
+                    stack.push(Operator::Function {
+                        name:      token.get_value().to_string(),
+                        count:     0,
+                        arg_start: output.len(),
+                    });
+                } else {
+                    pop_operators(&mut stack, &mut output);
+                    let Some(Operator::Function {
+                        name,
+                        mut count,
+                        arg_start,
+                    }) = stack.pop()
+                    else {
+                        return None;
+                    };
+                    if output.len() > arg_start || count > 0 {
+                        if output.len() == arg_start {
+                            output.push(0x16);
+                        }
+                        count = count.checked_add(1)?;
+                    }
+                    function(&mut output, &name, count)?;
+                }
+            }
+            FormulaTokenTypes::Argument => next_argument(&mut stack, &mut output)?,
+            FormulaTokenTypes::Unknown => return None,
+        }
+    }
+    pop_operators(&mut stack, &mut output);
+    if !stack.is_empty() {
+        return None;
+    }
+    Some(output)
+}
+
+fn innermost_is_function(stack: &[Operator]) -> bool {
+    matches!(
+        stack.iter().rfind(|v| !matches!(v, Operator::Token(..))),
+        Some(Operator::Function { .. })
+    )
+}
+
+/// Close an argument of the innermost function, a missing one is `PtgMissArg`.
+fn next_argument(stack: &mut Vec<Operator>, output: &mut Vec<u8>) -> Option<()> {
+    pop_operators(stack, output);
+    let Some(Operator::Function {
+        count, arg_start, ..
+    }) = stack.last_mut()
+    else {
+        return None;
+    };
+    if output.len() == *arg_start {
+        output.push(0x16);
+    }
+    *count = count.checked_add(1)?;
+    *arg_start = output.len();
+    Some(())
+}
+
+fn binary_operator(value: &str) -> Option<(u8, u8)> {
+    Some(match value {
+        "^" => (0x07, 5),
+        "*" => (0x05, 4),
+        "/" => (0x06, 4),
+        "+" => (0x03, 3),
+        "-" => (0x04, 3),
+        "&" => (0x08, 2),
+        "<" => (0x09, 1),
+        "<=" => (0x0A, 1),
+        "=" => (0x0B, 1),
+        ">=" => (0x0C, 1),
+        ">" => (0x0D, 1),
+        "<>" => (0x0E, 1),
+        _ => return None,
+    })
+}
+
+/// Push an operator after the operators of the same or a higher precedence,
+/// all of them are left associative.
+fn push_operator(stack: &mut Vec<Operator>, output: &mut Vec<u8>, ptg: u8, precedence: u8) {
+    while let Some(Operator::Token(v, p)) = stack.last() {
+        if *p < precedence || (precedence == 6 && *p == 6) {
+            break;
+        }
+        output.push(*v);
+        stack.pop();
+    }
+    stack.push(Operator::Token(ptg, precedence));
+}
+
+/// Move the operators down to the innermost parenthesis or function.
+fn pop_operators(stack: &mut Vec<Operator>, output: &mut Vec<u8>) {
+    while let Some(Operator::Token(v, _)) = stack.last() {
+        output.push(*v);
+        stack.pop();
+    }
+}
+
+fn function(output: &mut Vec<u8>, name: &str, count: u8) -> Option<()> {
+    let (index, fixed_count) = function_by_name(name)?;
+    if i16::from(fixed_count) == i16::from(count) {
+        output.push(0x41);
+    } else {
+        output.push(0x42);
+        output.push(count);
+    }
+    output.extend_from_slice(&index.to_le_bytes());
+    Some(())
+}
+
+fn operand(
+    token: &FormulaToken,
+    output: &mut Vec<u8>,
+    is_reference: bool,
+    sheet_index: Option<u32>,
+    names: &FormulaNames,
+) -> Option<()> {
+    let value = token.get_value();
+    match token.get_token_sub_type() {
+        FormulaTokenSubTypes::Text => {
+            let chars: Vec<u16> = value.encode_utf16().collect();
+            output.push(0x17);
+            output.extend_from_slice(&u16::try_from(chars.len()).ok()?.to_le_bytes());
+            for v in chars {
+                output.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        FormulaTokenSubTypes::Number => {
+            let number: f64 = value.parse().ok()?;
+            if let Ok(v) = value.parse::<u16>() {
+                output.push(0x1E);
+                output.extend_from_slice(&v.to_le_bytes());
+            } else {
+                output.push(0x1F);
+                output.extend_from_slice(&number.to_le_bytes());
+            }
+        }
+        FormulaTokenSubTypes::Logical => {
+            output.push(0x1D);
+            output.push(u8::from(value.eq_ignore_ascii_case("TRUE")));
+        }
+        FormulaTokenSubTypes::Error => {
+            output.push(0x1C);
+            output.push(error_code(value));
+        }
+        _ => reference(value, output, is_reference, sheet_index, names)?,
+    }
+    Some(())
+}
+
+/// A cell, an area or a defined name, on another sheet or not.
+fn reference(
+    value: &str,
+    output: &mut Vec<u8>,
+    is_reference: bool,
+    sheet_index: Option<u32>,
+    names: &FormulaNames,
+) -> Option<()> {
+    // References in the arguments of a function keep the reference class,
+    // other ones are values.
+    let class = if is_reference { 0x20 } else { 0x40 };
+    let (sheet_name, address) = split_address(value);
+    let ixti = if sheet_name.is_empty() {
+        None
+    } else {
+        Some(names.sheet_index(sheet_name)?)
+    };
+    let Some(area) = parse_area(address) else {
+        if ixti.is_some() {
+            return None;
+        }
+        output.push(0x03 | class);
+        output.extend_from_slice(&names.name_index(address, sheet_index)?.to_le_bytes());
+        return Some(());
+    };
+    let (first, last) = area;
+    let is_cell = first == last && !address.contains(':');
+    let ptg = match (ixti.is_some(), is_cell) {
+        (false, true) => 0x04,
+        (false, false) => 0x05,
+        (true, true) => 0x1A,
+        (true, false) => 0x1B,
+    };
+    output.push(ptg | class);
+    if let Some(v) = ixti {
+        output.extend_from_slice(&v.to_le_bytes());
+    }
+    if is_cell {
+        output.extend_from_slice(&first.0.to_le_bytes());
+        output.extend_from_slice(&first.1.to_le_bytes());
+    } else {
+        output.extend_from_slice(&first.0.to_le_bytes());
+        output.extend_from_slice(&last.0.to_le_bytes());
+        output.extend_from_slice(&first.1.to_le_bytes());
+        output.extend_from_slice(&last.1.to_le_bytes());
+    }
+    Some(())
+}
+
+/// Rows and columns of both ends of a reference, the columns with their
+/// relative flags.
+fn parse_area(address: &str) -> Option<((u32, u16), (u32, u16))> {
+    let address = address.to_uppercase();
+    let (first, last) = address.split_once(':').unwrap_or((&address, &address));
+    let is_line = address.contains(':');
+    match (index_from_coordinate(first), index_from_coordinate(last)) {
+        ((Some(c1), Some(r1), cl1, rl1), (Some(c2), Some(r2), cl2, rl2)) => Some((
+            cell(r1, c1, rl1, cl1)?,
+            cell(r2, c2, rl2, cl2)?,
+        )),
+        // Whole columns.
+        ((Some(c1), None, cl1, _), (Some(c2), None, cl2, _)) if is_line => Some((
+            cell(1, c1, Some(true), cl1)?,
+            cell(LAST_ROW + 1, c2, Some(true), cl2)?,
+        )),
+        // Whole rows.
+        ((None, Some(r1), _, rl1), (None, Some(r2), _, rl2)) if is_line => Some((
+            cell(r1, 1, rl1, Some(true))?,
+            cell(r2, u32::from(LAST_COL) + 1, rl2, Some(true))?,
+        )),
+        _ => None,
+    }
+}
+
+/// Row and column of a cell, bits 14 and 15 of the column are set when the
+/// row and the column are relative.
+fn cell(row: u32, col: u32, row_lock: Option<bool>, col_lock: Option<bool>) -> Option<(u32, u16)> {
+    let row = row.checked_sub(1).filter(|v| *v <= LAST_ROW)?;
+    let mut col = u16::try_from(col.checked_sub(1)?).ok().filter(|v| *v <= LAST_COL)?;
+    if row_lock != Some(true) {
+        col |= 0x4000;
+    }
+    if col_lock != Some(true) {
+        col |= 0x8000;
+    }
+    Some((row, col))
+}
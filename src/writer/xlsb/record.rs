@@ -0,0 +1,88 @@
+/// Records of a binary part.
+/// The fields of a record are added with the typed methods and the record is
+/// closed with `end`.
+#[derive(Debug, Default)]
+pub(crate) struct RecordWriter {
+    stream: Vec<u8>,
+    data:   Vec<u8>,
+}
+impl RecordWriter {
+    #[inline]
+    pub(crate) fn u8(&mut self, value: u8) -> &mut Self {
+        self.data.push(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn u16(&mut self, value: u16) -> &mut Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    #[inline]
+    pub(crate) fn u32(&mut self, value: u32) -> &mut Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    #[inline]
+    pub(crate) fn f64(&mut self, value: f64) -> &mut Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    #[inline]
+    pub(crate) fn bytes(&mut self, value: &[u8]) -> &mut Self {
+        self.data.extend_from_slice(value);
+        self
+    }
+
+    /// `XLWideString`: the count of UTF-16 characters and the characters.
+    pub(crate) fn wide_string(&mut self, value: &str) -> &mut Self {
+        let chars: Vec<u16> = value.encode_utf16().collect();
+        self.u32(num_traits::cast(chars.len()).unwrap());
+        for v in chars {
+            self.u16(v);
+        }
+        self
+    }
+
+    /// `XLNullableWideString`, an empty string is written as null.
+    pub(crate) fn nullable_wide_string(&mut self, value: &str) -> &mut Self {
+        if value.is_empty() {
+            return self.u32(u32::MAX);
+        }
+        self.wide_string(value)
+    }
+
+    /// A formula: the size and the tokens, then the size of the data
+    /// following them.
+    pub(crate) fn formula(&mut self, rgce: &[u8]) -> &mut Self {
+        self.u32(num_traits::cast(rgce.len()).unwrap());
+        self.bytes(rgce);
+        self.u32(0)
+    }
+
+    /// Write the fields added so far as a record of `kind`.
+    pub(crate) fn end(&mut self, kind: u16) -> &mut Self {
+        let data = std::mem::take(&mut self.data);
+        write_varint(&mut self.stream, u32::from(kind));
+        write_varint(&mut self.stream, num_traits::cast(data.len()).unwrap());
+        self.stream.extend_from_slice(&data);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn into_inner(self) -> Vec<u8> {
+        self.stream
+    }
+}
+
+fn write_varint(stream: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte: u8 = num_traits::cast(value & 0x7F).unwrap();
+        value >>= 7;
+        if value == 0 {
+            stream.push(byte);
+            break;
+        }
+        stream.push(byte | 0x80);
+    }
+}
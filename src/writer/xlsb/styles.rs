@@ -0,0 +1,306 @@
+use super::record::RecordWriter;
+use crate::{
+    reader::{
+        xls::style::{
+            BORDER_STYLES,
+            PATTERN_TYPES,
+        },
+        xlsb::{
+            record,
+            styles::{
+                GRADIENT_FILL,
+                HORIZONTAL_ALIGNMENTS,
+                VERTICAL_ALIGNMENTS,
+            },
+        },
+    },
+    structs::{
+        Border,
+        Borders,
+        CellFormat,
+        Color,
+        Fill,
+        Font,
+        FontSchemeValues,
+        GradientStop,
+        Stylesheet,
+        UnderlineValues,
+        VerticalAlignmentRunValues,
+    },
+};
+
+/// Records of `xl/styles.bin`.
+pub(crate) fn write(stylesheet: &Stylesheet) -> Vec<u8> {
+    let mut writer = RecordWriter::default();
+    writer.end(record::BEGIN_STYLE_SHEET);
+
+    let mut formats: Vec<_> = stylesheet
+        .numbering_formats()
+        .numbering_format()
+        .values()
+        .filter(|v| !v.is_build_in())
+        .collect();
+    formats.sort_by_key(|v| v.number_format_id());
+    writer.u32(count(formats.len())).end(record::BEGIN_FMTS);
+    for format in formats {
+        writer
+            .u16(num_traits::cast(format.number_format_id()).unwrap())
+            .wide_string(format.format_code())
+            .end(record::FMT);
+    }
+    writer.end(record::END_FMTS);
+
+    let fonts = stylesheet.fonts().font();
+    writer.u32(count(fonts.len())).end(record::BEGIN_FONTS);
+    for font in fonts {
+        write_font(&mut writer, font);
+    }
+    writer.end(record::END_FONTS);
+
+    let fills = stylesheet.fills().fill();
+    writer.u32(count(fills.len())).end(record::BEGIN_FILLS);
+    for fill in fills {
+        write_fill(&mut writer, fill);
+    }
+    writer.end(record::END_FILLS);
+
+    let borders = stylesheet.borders().borders();
+    writer.u32(count(borders.len())).end(record::BEGIN_BORDERS);
+    for border in borders {
+        write_borders(&mut writer, border);
+    }
+    writer.end(record::END_BORDERS);
+
+    let cell_formats = stylesheet.cell_style_formats().cell_format();
+    writer
+        .u32(count(cell_formats.len()))
+        .end(record::BEGIN_CELL_STYLE_XFS);
+    for cell_format in cell_formats {
+        write_xf(&mut writer, cell_format, true);
+    }
+    writer.end(record::END_CELL_STYLE_XFS);
+
+    let cell_formats = stylesheet.cell_formats().cell_format();
+    writer.u32(count(cell_formats.len())).end(record::BEGIN_CELL_XFS);
+    for cell_format in cell_formats {
+        write_xf(&mut writer, cell_format, false);
+    }
+    writer.end(record::END_CELL_XFS);
+
+    let cell_styles = stylesheet.cell_styles().cell_style();
+    writer.u32(count(cell_styles.len())).end(record::BEGIN_STYLES);
+    for cell_style in cell_styles {
+        let is_builtin = cell_style.has_builtin_id() && cell_style.builtin_id() <= 0xFF;
+        writer
+            .u32(cell_style.format_id())
+            .u16(u16::from(is_builtin))
+            .u8(if is_builtin {
+                num_traits::cast(cell_style.builtin_id()).unwrap()
+            } else {
+                0
+            })
+            .u8(0xFF)
+            .wide_string(cell_style.name())
+            .end(record::STYLE);
+    }
+    writer.end(record::END_STYLES);
+
+    writer.end(record::END_STYLE_SHEET);
+    writer.into_inner()
+}
+
+#[inline]
+fn count(len: usize) -> u32 {
+    num_traits::cast(len).unwrap()
+}
+
+/// `BrtColor`, an unset color is automatic.
+pub(crate) fn write_color(writer: &mut RecordWriter, color: Option<&Color>) {
+    let Some(color) = color.filter(|v| v.has_value()) else {
+        writer.u8(0x00).u8(0).u16(0).bytes(&[0, 0, 0, 0]);
+        return;
+    };
+    let (kind, index) = if color.has_theme_index() {
+        (3, color.theme_index())
+    } else if color.has_indexed() {
+        (1, color.indexed())
+    } else {
+        (2, 0)
+    };
+    let argb = color.argb();
+    let tint: i16 = num_traits::cast((color.tint() * 32767.0).round()).unwrap_or(0);
+    writer
+        .u8((kind << 1) | 0x01)
+        .u8(num_traits::cast(index).unwrap_or(0))
+        .u16(u16::from_le_bytes(tint.to_le_bytes()))
+        .bytes(&[argb.r, argb.g, argb.b, argb.a]);
+}
+
+fn write_font(writer: &mut RecordWriter, font: &Font) {
+    let mut options = 0;
+    if font.italic() {
+        options |= 0x02;
+    }
+    if font.strikethrough() {
+        options |= 0x08;
+    }
+    let script = match font.vertical_text_alignment().val() {
+        VerticalAlignmentRunValues::Superscript => 1,
+        VerticalAlignmentRunValues::Subscript => 2,
+        VerticalAlignmentRunValues::Baseline => 0,
+    };
+    let underline = match font.font_underline().val() {
+        UnderlineValues::Single => 0x01,
+        UnderlineValues::Double => 0x02,
+        UnderlineValues::SingleAccounting => 0x21,
+        UnderlineValues::DoubleAccounting => 0x22,
+        UnderlineValues::None => 0x00,
+    };
+    let scheme = match font.font_scheme().val() {
+        FontSchemeValues::Major => 1,
+        FontSchemeValues::Minor => 2,
+        FontSchemeValues::None => 0,
+    };
+    writer
+        .u16(num_traits::cast((font.size() * 20.0).round()).unwrap_or(220))
+        .u16(options)
+        .u16(if font.bold() { 700 } else { 400 })
+        .u16(script)
+        .u8(underline)
+        .u8(num_traits::cast(font.family()).unwrap_or(0))
+        .u8(num_traits::cast(font.charset()).unwrap_or(0))
+        .u8(0);
+    write_color(writer, Some(font.color()));
+    writer
+        .u8(scheme)
+        .wide_string(font.name())
+        .end(record::FONT);
+}
+
+fn write_fill(writer: &mut RecordWriter, fill: &Fill) {
+    if let Some(gradient_fill) = fill.gradient_fill() {
+        let stops = gradient_fill.gradient_stop();
+        writer.u32(GRADIENT_FILL);
+        write_color(writer, stops.first().map(GradientStop::color));
+        write_color(writer, stops.last().map(GradientStop::color));
+        writer
+            .u32(0)
+            .f64(gradient_fill.degree())
+            .bytes(&[0; 32])
+            .u32(count(stops.len()));
+        for stop in stops {
+            write_color(writer, Some(stop.color()));
+            writer.f64(stop.position());
+        }
+        writer.end(record::FILL);
+        return;
+    }
+    let pattern_fill = fill.pattern_fill();
+    let pattern = pattern_fill
+        .and_then(|v| PATTERN_TYPES.iter().position(|p| p == v.pattern_type()))
+        .unwrap_or(0);
+    writer.u32(count(pattern));
+    write_color(writer, pattern_fill.and_then(|v| v.foreground_color()));
+    write_color(writer, pattern_fill.and_then(|v| v.background_color()));
+    writer
+        .u32(0)
+        .f64(0.0)
+        .bytes(&[0; 32])
+        .u32(0)
+        .end(record::FILL);
+}
+
+fn write_borders(writer: &mut RecordWriter, borders: &Borders) {
+    let mut options = 0;
+    if borders.diagonal_down() {
+        options |= 0x01;
+    }
+    if borders.diagonal_up() {
+        options |= 0x02;
+    }
+    writer.u8(options);
+    let list: [&Border; 5] = [
+        borders.top(),
+        borders.bottom(),
+        borders.left(),
+        borders.right(),
+        borders.diagonal(),
+    ];
+    for border in list {
+        let line = BORDER_STYLES
+            .iter()
+            .position(|v| *v == border.border_style())
+            .unwrap_or(0);
+        writer.u8(num_traits::cast(line).unwrap()).u8(0);
+        write_color(writer, border.color().as_ref());
+    }
+    writer.end(record::BORDER);
+}
+
+fn write_xf(writer: &mut RecordWriter, cell_format: &CellFormat, is_style_xf: bool) {
+    let mut options: u16 = 2 << 3;
+    let mut rotation = 0;
+    let mut indent = 0;
+    if let Some(alignment) = cell_format.alignment() {
+        let horizontal = HORIZONTAL_ALIGNMENTS
+            .iter()
+            .position(|v| v == alignment.horizontal())
+            .unwrap_or(0);
+        let vertical = VERTICAL_ALIGNMENTS
+            .iter()
+            .position(|v| v == alignment.vertical())
+            .unwrap_or(2);
+        options = num_traits::cast::<usize, u16>(horizontal | (vertical << 3)).unwrap();
+        if alignment.wrap_text() {
+            options |= 0x40;
+        }
+        rotation = num_traits::cast(alignment.text_rotation()).unwrap_or(0);
+        indent = num_traits::cast(alignment.indent()).unwrap_or(0);
+    }
+    match cell_format.protection() {
+        Some(v) => {
+            if v.is_locked() {
+                options |= 0x1000;
+            }
+            if v.is_hidden() {
+                options |= 0x2000;
+            }
+        }
+        None => options |= 0x1000,
+    }
+
+    let mut applied = 0;
+    if !is_style_xf {
+        let flags = [
+            cell_format.apply_number_format(),
+            cell_format.apply_font(),
+            cell_format.apply_alignment(),
+            cell_format.apply_border(),
+            cell_format.apply_fill(),
+            cell_format.apply_protection(),
+        ];
+        for (index, flag) in flags.into_iter().enumerate() {
+            if flag {
+                applied |= 1 << index;
+            }
+        }
+    }
+
+    let parent = if is_style_xf {
+        0xFFFF
+    } else {
+        num_traits::cast(cell_format.format_id()).unwrap()
+    };
+    writer
+        .u16(parent)
+        .u16(num_traits::cast(cell_format.number_format_id()).unwrap())
+        .u16(num_traits::cast(cell_format.font_id()).unwrap())
+        .u16(num_traits::cast(cell_format.fill_id()).unwrap())
+        .u16(num_traits::cast(cell_format.border_id()).unwrap())
+        .u8(rotation)
+        .u8(indent)
+        .u16(options)
+        .u8(applied)
+        .u8(0)
+        .end(record::XF);
+}
@@ -0,0 +1,94 @@
+use super::{
+    formula::{
+        FormulaNames,
+        compile,
+    },
+    record::RecordWriter,
+};
+use crate::{
+    reader::xlsb::record,
+    structs::{
+        DefinedName,
+        Workbook,
+    },
+};
+
+/// `#REF!` for the defined names that can not be compiled.
+const REF_ERROR: [u8; 2] = [0x1C, 0x17];
+
+/// Records of `xl/workbook.bin`, the sheet of index `n` has the relationship
+/// `rId{n + 1}`.
+pub(crate) fn write(wb: &Workbook, defined_names: &[DefinedName], names: &FormulaNames) -> Vec<u8> {
+    let mut writer = RecordWriter::default();
+    writer.end(record::BEGIN_BOOK);
+    writer.u32(0).u32(0).wide_string("").end(record::WB_PROP);
+
+    writer.end(record::BEGIN_BOOK_VIEWS);
+    writer
+        .u32(0)
+        .u32(0)
+        .u32(0x4000)
+        .u32(0x2000)
+        .u32(600)
+        .u32(0)
+        .u32(wb.workbook_view().active_tab())
+        .u8(0x78)
+        .end(record::BOOK_VIEW);
+    writer.end(record::END_BOOK_VIEWS);
+
+    writer.end(record::BEGIN_BUNDLE_SHS);
+    for (index, worksheet) in wb.sheet_collection_no_check().iter().enumerate() {
+        let state = match worksheet.sheet_state() {
+            "hidden" => 1,
+            "veryHidden" => 2,
+            _ => 0,
+        };
+        let index: u32 = num_traits::cast(index).unwrap();
+        writer
+            .u32(state)
+            .u32(index + 1)
+            .nullable_wide_string(&format!("rId{}", index + 1))
+            .wide_string(worksheet.name())
+            .end(record::BUNDLE_SH);
+    }
+    writer.end(record::END_BUNDLE_SHS);
+
+    // One reference to each sheet of this workbook, in the order of the
+    // sheets.
+    writer.end(record::BEGIN_EXTERNALS);
+    writer.end(record::SUP_SELF);
+    writer.u32(num_traits::cast(names.sheet_names.len()).unwrap());
+    for index in 0..names.sheet_names.len() {
+        let index: u32 = num_traits::cast(index).unwrap();
+        writer.u32(0).u32(index).u32(index);
+    }
+    writer.end(record::EXTERN_SHEET);
+    writer.end(record::END_EXTERNALS);
+
+    for defined_name in defined_names {
+        let is_builtin = defined_name.name().starts_with("_xlnm.");
+        let mut options = 0;
+        if defined_name.hidden() {
+            options |= 0x01;
+        }
+        if is_builtin {
+            options |= 0x20;
+        }
+        let sheet_index = defined_name
+            .has_local_sheet_id()
+            .then(|| defined_name.local_sheet_id());
+        let rgce = compile(&defined_name.address(), sheet_index, true, names)
+            .unwrap_or_else(|| REF_ERROR.to_vec());
+        writer
+            .u32(options)
+            .u8(0)
+            .u32(sheet_index.unwrap_or(u32::MAX))
+            .wide_string(defined_name.name())
+            .formula(&rgce)
+            .nullable_wide_string("")
+            .end(record::NAME);
+    }
+
+    writer.end(record::END_BOOK);
+    writer.into_inner()
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use super::{
+    formula::{
+        FormulaNames,
+        compile,
+    },
+    record::RecordWriter,
+};
+use crate::{
+    reader::{
+        xls::formula::error_code,
+        xlsb::record,
+    },
+    structs::{
+        Cell,
+        CellRawValue,
+        ColumnReference,
+        RowReference,
+        Stylesheet,
+        Worksheet,
+    },
+};
+
+/// Strings of the cells, written to `xl/sharedStrings.bin`.
+#[derive(Debug, Default)]
+pub(crate) struct SharedStrings {
+    list:  Vec<String>,
+    map:   HashMap<String, u32>,
+    count: u32,
+}
+impl SharedStrings {
+    fn index(&mut self, value: &str) -> u32 {
+        self.count += 1;
+        if let Some(v) = self.map.get(value) {
+            return *v;
+        }
+        let index = num_traits::cast(self.list.len()).unwrap();
+        self.list.push(value.to_string());
+        self.map.insert(value.to_string(), index);
+        index
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub(crate) fn write(&self) -> Vec<u8> {
+        let mut writer = RecordWriter::default();
+        writer
+            .u32(self.count)
+            .u32(num_traits::cast(self.list.len()).unwrap())
+            .end(record::BEGIN_SST);
+        for value in &self.list {
+            writer.u8(0).wide_string(value).end(record::SST_ITEM);
+        }
+        writer.end(record::END_SST);
+        writer.into_inner()
+    }
+}
+
+/// Records of a worksheet part.
+pub(crate) fn write(
+    worksheet: &Worksheet,
+    sheet_index: u32,
+    shared_strings: &mut SharedStrings,
+    stylesheet: &mut Stylesheet,
+    names: &FormulaNames,
+) -> Vec<u8> {
+    let mut writer = RecordWriter::default();
+    writer.end(record::BEGIN_SHEET);
+
+    let (last_col, last_row) = worksheet.highest_column_and_row();
+    writer
+        .u32(0)
+        .u32(last_row.saturating_sub(1))
+        .u32(0)
+        .u32(last_col.saturating_sub(1))
+        .end(record::WS_DIM);
+
+    let mut column_dimensions = worksheet.column_dimensions_crate().clone();
+    column_dimensions
+        .calculation_auto_width(worksheet.cells_crate(), worksheet.merge_cells_crate());
+    let columns = column_dimensions.column_collection();
+    if !columns.is_empty() {
+        writer.end(record::BEGIN_COL_INFOS);
+        for column in columns {
+            let col = column.col_num() - 1;
+            let xf = if column.has_style() {
+                stylesheet.set_style(column.style())
+            } else {
+                0
+            };
+            let mut options = 0x02;
+            if column.hidden() {
+                options |= 0x01;
+            }
+            writer
+                .u32(col)
+                .u32(col)
+                .u32(num_traits::cast((column.width() * 256.0).round()).unwrap_or(0))
+                .u32(xf)
+                .u16(options)
+                .end(record::COL_INFO);
+        }
+        writer.end(record::END_COL_INFOS);
+    }
+
+    writer.end(record::BEGIN_SHEET_DATA);
+    let mut row_dimensions = worksheet.row_dimensions();
+    row_dimensions.sort_by_key(|a| a.row_num());
+    let cells = worksheet.cells_sorted();
+    let mut cells_iter = cells.iter().peekable();
+    for row in row_dimensions {
+        let xf = if row.has_style() {
+            stylesheet.set_style(row.style())
+        } else {
+            0
+        };
+        let mut options = 0;
+        if row.hidden() {
+            options |= 0x10;
+        }
+        if row.custom_height() {
+            options |= 0x20;
+        }
+        if xf != 0 {
+            options |= 0x40;
+        }
+        let height = if row.height() > 0.0 { row.height() } else { 15.0 };
+        writer
+            .u32(row.row_num() - 1)
+            .u32(xf)
+            .u16(num_traits::cast((height * 20.0).round()).unwrap_or(300))
+            .u8(0)
+            .u8(options)
+            .u8(0)
+            .u32(0)
+            .end(record::ROW_HDR);
+        while let Some(cell) = cells_iter.peek() {
+            if row.row_num() != cell.coordinate().row_num() {
+                break;
+            }
+            let cell = cells_iter.next().unwrap();
+            write_cell(&mut writer, cell, sheet_index, shared_strings, stylesheet, names);
+        }
+    }
+    writer.end(record::END_SHEET_DATA);
+
+    let merge_cells = worksheet.merge_cells();
+    if !merge_cells.is_empty() {
+        writer
+            .u32(num_traits::cast(merge_cells.len()).unwrap())
+            .end(record::BEGIN_MERGE_CELLS);
+        for range in merge_cells {
+            let first_row = range.coordinate_start_row().map_or(1, RowReference::num);
+            let last_row = range.coordinate_end_row().map_or(first_row, RowReference::num);
+            let first_col = range.coordinate_start_col().map_or(1, ColumnReference::num);
+            let last_col = range.coordinate_end_col().map_or(first_col, ColumnReference::num);
+            writer
+                .u32(first_row - 1)
+                .u32(last_row - 1)
+                .u32(first_col - 1)
+                .u32(last_col - 1)
+                .end(record::MERGE_CELL);
+        }
+        writer.end(record::END_MERGE_CELLS);
+    }
+
+    writer.end(record::END_SHEET);
+    writer.into_inner()
+}
+
+/// A cell, a formula that can not be compiled is written as its cached
+/// value.
+fn write_cell(
+    writer: &mut RecordWriter,
+    cell: &Cell,
+    sheet_index: u32,
+    shared_strings: &mut SharedStrings,
+    stylesheet: &mut Stylesheet,
+    names: &FormulaNames,
+) {
+    if cell.cell_value().is_empty() && cell.style().is_empty() {
+        return;
+    }
+    let xf = stylesheet.set_style(cell.style());
+    let raw_value = cell.raw_value();
+    let rgce = if cell.is_formula() {
+        compile(cell.formula(), Some(sheet_index), false, names)
+    } else {
+        None
+    };
+    writer.u32(cell.coordinate().col_num() - 1).u32(xf);
+    if let Some(rgce) = rgce {
+        let kind = match raw_value {
+            CellRawValue::String(_) | CellRawValue::RichText(_) | CellRawValue::Lazy(_) => {
+                writer.wide_string(&cell.value());
+                record::FMLA_STRING
+            }
+            CellRawValue::Bool(v) => {
+                writer.u8(u8::from(*v));
+                record::FMLA_BOOL
+            }
+            CellRawValue::Error(v) => {
+                writer.u8(error_code(&v.to_string()));
+                record::FMLA_ERROR
+            }
+            CellRawValue::Numeric(v) => {
+                writer.f64(*v);
+                record::FMLA_NUM
+            }
+            CellRawValue::Empty => {
+                writer.f64(0.0);
+                record::FMLA_NUM
+            }
+        };
+        writer.u16(0).formula(&rgce).end(kind);
+        return;
+    }
+    let kind = match raw_value {
+        CellRawValue::String(_) | CellRawValue::RichText(_) | CellRawValue::Lazy(_) => {
+            writer.u32(shared_strings.index(&cell.value()));
+            record::CELL_ISST
+        }
+        CellRawValue::Bool(v) => {
+            writer.u8(u8::from(*v));
+            record::CELL_BOOL
+        }
+        CellRawValue::Error(v) => {
+            writer.u8(error_code(&v.to_string()));
+            record::CELL_ERROR
+        }
+        CellRawValue::Numeric(v) => {
+            writer.f64(*v);
+            record::CELL_REAL
+        }
+        CellRawValue::Empty => record::CELL_BLANK,
+    };
+    writer.end(kind);
+}
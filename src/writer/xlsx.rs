@@ -1,9 +1,15 @@
+#[cfg(feature = "async")]
+use std::sync::Mutex;
 use std::{
     fs,
     fs::File,
     io,
     io::Read,
     path::Path,
+    sync::{
+        Arc,
+        RwLock,
+    },
 };
 
 use super::driver;
@@ -11,14 +17,18 @@ use crate::{
     XlsxError,
     helper::crypt::encrypt,
     structs::{
+        SharedStringTable,
+        Stylesheet,
         Workbook,
         WriterManager,
     },
 };
 
 pub(crate) mod chart;
+pub(crate) mod chartsheet;
 pub(crate) mod comment;
 pub(crate) mod content_types;
+pub(crate) mod controls;
 pub(crate) mod doc_props_app;
 pub(crate) mod doc_props_core;
 pub(crate) mod doc_props_custom;
@@ -33,6 +43,7 @@ pub(crate) mod pivot_table;
 pub(crate) mod printer_settings;
 pub(crate) mod rels;
 pub(crate) mod shared_strings;
+pub(crate) mod strict;
 pub(crate) mod styles;
 pub(crate) mod table;
 pub(crate) mod theme;
@@ -45,149 +56,217 @@ pub(crate) mod workbook_rels;
 pub(crate) mod worksheet;
 pub(crate) mod worksheet_rels;
 
-fn write_zip_to_writer<W: io::Write + io::Seek>(
-    wb: &Workbook,
-    writer: W,
-    is_light: bool,
-) -> Result<(), XlsxError> {
-    let arv = zip::ZipWriter::new(writer);
-    let mut writer_manager = WriterManager::new(arv);
-    {
+/// A stage of the package, written one after another.
+#[derive(Clone, Copy)]
+enum Stage {
+    DocProps,
+    Worksheet(usize),
+    WorksheetObjects(usize),
+    Workbook,
+}
+
+/// Writes the parts of a workbook to a zip package a stage at a time.
+struct PackageWriter<'a, W: io::Write + io::Seek> {
+    wb:                  &'a Workbook,
+    writer_manager:      WriterManager<W>,
+    shared_string_table: Arc<RwLock<SharedStringTable>>,
+    stylesheet:          Stylesheet,
+}
+
+impl<'a, W: io::Write + io::Seek> PackageWriter<'a, W> {
+    fn new(wb: &'a Workbook, arv: zip::ZipWriter<W>, is_light: bool) -> Self {
+        let mut writer_manager = WriterManager::new(arv);
         writer_manager.set_is_light(is_light);
+        Self {
+            wb,
+            writer_manager,
+            shared_string_table: wb.shared_string_table(),
+            stylesheet: wb.stylesheet().clone(),
+        }
+    }
+
+    fn stages(&self) -> Vec<Stage> {
+        let count = self.wb.sheet_collection_no_check().len();
+        let mut stages = vec![Stage::DocProps];
+        stages.extend((0..count).map(Stage::Worksheet));
+        stages.extend((0..count).map(Stage::WorksheetObjects));
+        stages.push(Stage::Workbook);
+        stages
+    }
+
+    fn write(&mut self, stage: Stage) -> Result<(), XlsxError> {
+        match stage {
+            Stage::DocProps => self.write_doc_props(),
+            Stage::Worksheet(index) => self.write_worksheet(index),
+            Stage::WorksheetObjects(index) => self.write_worksheet_objects(index),
+            Stage::Workbook => self.write_workbook(),
+        }
+    }
+
+    fn finish(self) -> Result<W, XlsxError> {
+        Ok(self.writer_manager.finish()?)
+    }
+
+    fn write_doc_props(&mut self) -> Result<(), XlsxError> {
+        let wb = self.wb;
+        let writer_manager = &mut self.writer_manager;
 
         // Add docProps
-        doc_props_app::write(wb, &mut writer_manager)?;
-        doc_props_core::write(wb, &mut writer_manager)?;
-        doc_props_custom::write(wb, &mut writer_manager)?;
-        vba_project_bin::write(wb, &mut writer_manager)?;
-        jsa_project_bin::write(wb, &mut writer_manager)?;
-        rels::write(wb, &mut writer_manager)?;
-        theme::write(wb.theme(), &mut writer_manager)?;
-        person::write(wb, &mut writer_manager)?;
-
-        let shared_string_table = wb.shared_string_table();
-        let mut stylesheet = wb.stylesheet().clone();
-
-        // Process each worksheet
-        wb.sheet_collection_no_check()
-            .iter()
-            .enumerate()
-            .try_for_each(|(index, worksheet)| {
-                let worksheet_no = index + 1;
-                if worksheet.is_deserialized() {
-                    worksheet::write(
-                        worksheet_no.try_into().unwrap(),
-                        worksheet,
-                        &shared_string_table,
-                        &mut stylesheet,
-                        wb.has_macros(),
-                        &mut writer_manager,
-                    )
-                } else {
-                    worksheet
-                        .raw_data_of_worksheet()
-                        .write(worksheet_no.try_into().unwrap(), &mut writer_manager)
-                }
-            })?;
-
-        // Process objects associated with worksheets
-        wb.sheet_collection_no_check()
+        doc_props_app::write(wb, writer_manager)?;
+        doc_props_core::write(wb, writer_manager)?;
+        doc_props_custom::write(wb, writer_manager)?;
+        vba_project_bin::write(wb, writer_manager)?;
+        jsa_project_bin::write(wb, writer_manager)?;
+        rels::write(wb, writer_manager)?;
+        theme::write(wb.theme(), writer_manager)?;
+        person::write(wb, writer_manager)
+    }
+
+    fn write_worksheet(&mut self, index: usize) -> Result<(), XlsxError> {
+        let wb = self.wb;
+        let worksheet = &wb.sheet_collection_no_check()[index];
+        let worksheet_no = index + 1;
+        if let Some(v) = worksheet.chartsheet() {
+            worksheet.check_chartsheet_cells()?;
+            chartsheet::write(
+                worksheet_no.try_into().unwrap(),
+                worksheet,
+                v,
+                wb,
+                &mut self.writer_manager,
+            )
+        } else if worksheet.is_deserialized() {
+            worksheet::write(
+                worksheet_no.try_into().unwrap(),
+                worksheet,
+                &self.shared_string_table,
+                &mut self.stylesheet,
+                wb.has_macros(),
+                &mut self.writer_manager,
+            )
+        } else {
+            worksheet
+                .raw_data_of_worksheet()
+                .write(worksheet_no.try_into().unwrap(), &mut self.writer_manager)
+        }
+    }
+
+    /// Process objects associated with a worksheet
+    fn write_worksheet_objects(&mut self, index: usize) -> Result<(), XlsxError> {
+        let wb = self.wb;
+        let worksheet = &wb.sheet_collection_no_check()[index];
+        let worksheet_no = index + 1;
+        if !worksheet.is_deserialized() || worksheet.is_chartsheet() {
+            return Ok(());
+        }
+        let writer_manager = &mut self.writer_manager;
+
+        // Add charts
+        let chart_no_list: Result<Vec<String>, XlsxError> = worksheet
+            .worksheet_drawing()
+            .chart_collection()
             .iter()
-            .enumerate()
-            .try_for_each(|(index, worksheet)| {
-                let worksheet_no = index + 1;
-                if !worksheet.is_deserialized() {
-                    return Ok(());
-                }
-
-                // Add charts
-                let chart_no_list: Result<Vec<String>, XlsxError> = worksheet
-                    .worksheet_drawing()
-                    .chart_collection()
-                    .iter()
-                    .map(|chart| chart::write(chart.chart_space(), wb, &mut writer_manager))
-                    .collect();
-
-                let chart_no_list = chart_no_list?;
-
-                // Add drawing and its relationships
-                let (drawing_no, rel_list) = drawing::write(worksheet, &mut writer_manager)?;
-                drawing_rels::write(
-                    worksheet,
-                    &drawing_no,
-                    &chart_no_list,
-                    &rel_list,
-                    &mut writer_manager,
-                )?;
-
-                // Add vml drawing and its relationships
-                let (vml_drawing_no, rel_list) =
-                    vml_drawing::write(worksheet, &mut writer_manager)?;
-                vml_drawing_rels::write(
-                    worksheet,
-                    &vml_drawing_no,
-                    &rel_list,
-                    &mut writer_manager,
-                )?;
-
-                // Add comments
-                let comment_no = comment::write(worksheet, &mut writer_manager)?;
-
-                // Add threaded_comment
-                let threaded_comment_no = threaded_comment::write(worksheet, &mut writer_manager)?;
-
-                // Add ole_object and excel
-                let (ole_object_no_list, excel_no_list) =
-                    embeddings::write(worksheet, &mut writer_manager)?;
-
-                // Add Media
-                media::write(worksheet, &mut writer_manager)?;
-
-                // Add printer settings
-                let printer_settings_no = worksheet
-                    .page_setup()
-                    .object_data()
-                    .map_or_else(String::new, |_| {
-                        printer_settings::write(worksheet, &mut writer_manager).unwrap_or_default()
-                    });
-
-                // Add tables
-                let table_no_list = table::write(worksheet, &mut writer_manager)?;
-
-                // Add pivot tables and caches
-                let pivot_table_no_list = pivot_table::write(worksheet, &mut writer_manager)?;
-                let pivot_cache_no_list = pivot_cache::write(worksheet, &mut writer_manager)?;
-
-                // Add worksheet relationships
-                worksheet_rels::write(
-                    worksheet,
-                    &worksheet_no.to_string(),
-                    &drawing_no,
-                    &vml_drawing_no,
-                    &comment_no,
-                    &threaded_comment_no,
-                    &ole_object_no_list,
-                    &excel_no_list,
-                    &printer_settings_no,
-                    &table_no_list,
-                    &pivot_table_no_list,
-                    &pivot_cache_no_list,
-                    &mut writer_manager,
-                )
-            })?;
+            .map(|chart| chart::write(chart.chart_space(), wb, writer_manager))
+            .collect();
+
+        let chart_no_list = chart_no_list?;
+
+        // Add drawing and its relationships
+        let (drawing_no, rel_list) = drawing::write(worksheet, writer_manager)?;
+        drawing_rels::write(
+            worksheet,
+            &drawing_no,
+            &chart_no_list,
+            &rel_list,
+            writer_manager,
+        )?;
+
+        // Add vml drawing and its relationships
+        let (vml_drawing_no, rel_list) = vml_drawing::write(worksheet, writer_manager)?;
+        vml_drawing_rels::write(worksheet, &vml_drawing_no, &rel_list, writer_manager)?;
+
+        // Add vml drawing of the header and footer and its relationships
+        let (vml_drawing_hf_no, rel_list) =
+            vml_drawing::write_header_footer(worksheet, writer_manager)?;
+        vml_drawing_rels::write(worksheet, &vml_drawing_hf_no, &rel_list, writer_manager)?;
+
+        // Add comments
+        let comment_no = comment::write(worksheet, writer_manager)?;
+
+        // Add threaded_comment
+        let threaded_comment_no = threaded_comment::write(worksheet, writer_manager)?;
+
+        // Add ole_object and excel
+        let (ole_object_no_list, excel_no_list) = embeddings::write(worksheet, writer_manager)?;
+
+        // Add form control properties and activeX controls
+        let (ctrl_prop_no_list, active_x_no_list) = controls::write(worksheet, writer_manager)?;
+
+        // Add Media
+        media::write(worksheet, writer_manager)?;
+
+        // Add printer settings
+        let printer_settings_no = worksheet
+            .page_setup()
+            .object_data()
+            .map_or_else(String::new, |_| {
+                printer_settings::write(worksheet, writer_manager).unwrap_or_default()
+            });
+
+        // Add tables
+        let table_no_list = table::write(worksheet, &mut self.stylesheet, writer_manager)?;
+
+        // Add pivot tables and caches
+        let pivot_table_no_list = pivot_table::write(worksheet, writer_manager)?;
+        let pivot_cache_no_list = pivot_cache::write(worksheet, writer_manager)?;
+
+        // Add worksheet relationships
+        worksheet_rels::write(
+            worksheet,
+            &worksheet_no.to_string(),
+            &drawing_no,
+            &vml_drawing_no,
+            &vml_drawing_hf_no,
+            &comment_no,
+            &threaded_comment_no,
+            &ole_object_no_list,
+            &excel_no_list,
+            &ctrl_prop_no_list,
+            &active_x_no_list,
+            &printer_settings_no,
+            &table_no_list,
+            &pivot_table_no_list,
+            &pivot_cache_no_list,
+            writer_manager,
+        )
+    }
+
+    fn write_workbook(&mut self) -> Result<(), XlsxError> {
+        let wb = self.wb;
+        let writer_manager = &mut self.writer_manager;
 
         // Finalize file list and add remaining components
         writer_manager.file_list_sort();
-        shared_strings::write(&shared_string_table, &mut writer_manager)?;
-        styles::write(&stylesheet, &mut writer_manager)?;
-        workbook::write(wb, &mut writer_manager)?;
+        shared_strings::write(&self.shared_string_table, writer_manager)?;
+        styles::write(&self.stylesheet, writer_manager)?;
+        workbook::write(wb, writer_manager)?;
 
-        let has_shared_string_table = shared_string_table.read().unwrap().has_value();
-        workbook_rels::write(wb, has_shared_string_table, &mut writer_manager)?;
-        content_types::write(wb, &mut writer_manager)?;
+        let has_shared_string_table = self.shared_string_table.read().unwrap().has_value();
+        workbook_rels::write(wb, has_shared_string_table, writer_manager)?;
+        content_types::write(wb, writer_manager)
     }
+}
 
-    writer_manager.finish()?;
+fn write_zip_to_writer<W: io::Write + io::Seek>(
+    wb: &Workbook,
+    writer: W,
+    is_light: bool,
+) -> Result<(), XlsxError> {
+    let mut package = PackageWriter::new(wb, zip::ZipWriter::new(writer), is_light);
+    for stage in package.stages() {
+        package.write(stage)?;
+    }
+    package.finish()?;
     Ok(())
 }
 
@@ -223,6 +302,69 @@ pub fn write_writer_light<W: io::Write>(wb: &Workbook, mut writer: W) -> Result<
     Ok(())
 }
 
+/// write spreadsheet file to an asynchronous writer.
+///
+/// The writer does not have to seek, so it can be the body of an upload.
+/// The package is made a stage at a time on the calling task: the document
+/// properties, each worksheet, the parts of each worksheet and then the
+/// workbook. The entries of a stage are written to the writer before the
+/// next stage is made, so only the central directory is kept until the end
+/// of the package.
+/// # Arguments
+/// * `wb` - Workbook structs object.
+/// * `writer` - writer to write to.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let book = umya_spreadsheet::new_file();
+/// let mut data = Vec::new();
+/// umya_spreadsheet::writer::xlsx::write_async(&book, &mut data)
+///     .await
+///     .unwrap();
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub async fn write_async<W>(wb: &Workbook, mut writer: W) -> Result<(), XlsxError>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let buffer = Mutex::new(Vec::new());
+    let arv = zip::ZipWriter::new_stream(StageBuffer(&buffer));
+    let mut package = PackageWriter::new(wb, arv, false);
+    for stage in package.stages() {
+        package.write(stage)?;
+        let data = std::mem::take(&mut *buffer.lock().unwrap());
+        writer.write_all(&data).await?;
+    }
+    package.finish()?;
+    writer.write_all(&buffer.into_inner().unwrap()).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// The entries written by a stage of [`write_async`], until they are sent to
+/// its writer.
+#[cfg(feature = "async")]
+struct StageBuffer<'a>(&'a Mutex<Vec<u8>>);
+
+#[cfg(feature = "async")]
+impl io::Write for StageBuffer<'_> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 /// write spreadsheet file.
 /// # Arguments
 /// * `wb` - Workbook structs object.
@@ -277,6 +419,37 @@ pub fn write_light<P: AsRef<Path>>(wb: &Workbook, path: P) -> Result<(), XlsxErr
     Ok(())
 }
 
+/// write spreadsheet file in the Strict Open XML conformance (ISO/IEC 29500 Strict).
+/// # Arguments
+/// * `wb` - Workbook structs object.
+/// * `writer` - writer to write to.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+#[inline]
+pub fn write_writer_strict<W: io::Write>(wb: &Workbook, mut writer: W) -> Result<(), XlsxError> {
+    let buffer = strict::convert(&make_buffer(wb, false)?)?;
+    writer.write_all(&buffer)?;
+    Ok(())
+}
+
+/// write spreadsheet file in the Strict Open XML conformance (ISO/IEC 29500 Strict).
+/// # Arguments
+/// * `wb` - Workbook structs object.
+/// * `path` - file path to save.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz_strict.xlsx");
+/// let _unused = umya_spreadsheet::writer::xlsx::write_strict(&book, path);
+/// ```
+pub fn write_strict<P: AsRef<Path>>(wb: &Workbook, path: P) -> Result<(), XlsxError> {
+    let buffer = strict::convert(&make_buffer(wb, false)?)?;
+    fs::write(path, buffer)?;
+    Ok(())
+}
+
 /// write spreadsheet file with password.
 /// # Arguments
 /// * `wb` - Workbook structs object.
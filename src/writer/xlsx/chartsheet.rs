@@ -0,0 +1,148 @@
+use std::io;
+
+use quick_xml::{
+    Writer,
+    events::{
+        BytesDecl,
+        Event,
+    },
+};
+
+use super::{
+    XlsxError,
+    chart,
+    driver::{
+        write_end_tag,
+        write_new_line,
+        write_start_tag,
+    },
+    drawing_rels,
+};
+use crate::{
+    helper::const_str::{
+        DRAWINGS_NS,
+        PKG_CHARTSHEET,
+        PKG_CHARTSHEET_RELS,
+        PRINTER_SETTINGS_NS,
+        REL_NS,
+    },
+    structs::{
+        Chartsheet,
+        Workbook,
+        Worksheet,
+        WriterManager,
+    },
+};
+
+/// Writes a chart sheet together with its drawing, chart and relationships.
+pub(crate) fn write<W: io::Seek + io::Write>(
+    sheet_no: i32,
+    worksheet: &Worksheet,
+    chartsheet: &Chartsheet,
+    wb: &Workbook,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    // Add chart
+    let chart_no = chart::write(chartsheet.chart().chart_space(), wb, writer_mng)?;
+
+    // Add drawing and its relationships
+    let mut rel_list: Vec<(String, String)> = Vec::new();
+    let mut writer = new_writer();
+    chartsheet.write_drawing_to(&mut writer, &mut rel_list);
+    let drawing_no = writer_mng.add_file_at_drawing(writer)?.to_string();
+    drawing_rels::write(
+        worksheet,
+        &drawing_no,
+        &[chart_no],
+        &rel_list,
+        writer_mng,
+    )?;
+
+    // Add printer settings
+    let printer_settings_no = match chartsheet.page_setup().object_data() {
+        Some(v) => writer_mng.add_file_at_printer_settings(v)?.to_string(),
+        None => String::new(),
+    };
+
+    // Add chartsheet relationships
+    write_rels(sheet_no, &drawing_no, &printer_settings_no, writer_mng)?;
+
+    // Add chartsheet
+    let mut writer = new_writer();
+    chartsheet.write_to(&mut writer, wb.has_macros());
+    let target = format!("{PKG_CHARTSHEET}{sheet_no}.xml");
+    writer_mng.add_writer(&target, writer)
+}
+
+fn write_rels<W: io::Seek + io::Write>(
+    sheet_no: i32,
+    drawing_no: &str,
+    printer_settings_no: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = new_writer();
+
+    // relationships
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![("xmlns", REL_NS).into()],
+        false,
+    );
+
+    let mut r_id = 1;
+    if !printer_settings_no.is_empty() {
+        write_relationship(
+            &mut writer,
+            r_id,
+            PRINTER_SETTINGS_NS,
+            &format!("../printerSettings/printerSettings{printer_settings_no}.bin"),
+        );
+        r_id += 1;
+    }
+
+    write_relationship(
+        &mut writer,
+        r_id,
+        DRAWINGS_NS,
+        &format!("../drawings/drawing{drawing_no}.xml"),
+    );
+
+    write_end_tag(&mut writer, "Relationships");
+
+    let file_path = format!("{PKG_CHARTSHEET_RELS}{sheet_no}.xml.rels");
+    writer_mng.add_writer(&file_path, writer)
+}
+
+fn write_relationship(
+    writer: &mut Writer<io::Cursor<Vec<u8>>>,
+    r_id: i32,
+    p_type: &str,
+    p_target: &str,
+) {
+    let r_id_str = format!("rId{r_id}");
+    write_start_tag(
+        writer,
+        "Relationship",
+        vec![
+            ("Id", r_id_str.as_str()).into(),
+            ("Type", p_type).into(),
+            ("Target", p_target).into(),
+        ],
+        true,
+    );
+}
+
+fn new_writer() -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer
+        .write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )))
+        .unwrap();
+    write_new_line(&mut writer);
+    writer
+}
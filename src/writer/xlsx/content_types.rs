@@ -97,6 +97,25 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         }
     }
 
+    // Write the other content types of the file read
+    for (extension, content_type) in wb.backup_default_context_types() {
+        let is_written = default_content_types
+            .iter()
+            .chain(&optional_extensions)
+            .any(|v| v.0.eq_ignore_ascii_case(extension));
+        if !is_written && writer_mng.has_extension(extension) {
+            write_start_tag(
+                &mut writer,
+                "Default",
+                vec![
+                    ("Extension", &**extension).into(),
+                    ("ContentType", &**content_type).into(),
+                ],
+                true,
+            );
+        }
+    }
+
     // Override
     for (part_name, content_type) in writer_mng.make_context_type_override(wb) {
         write_start_tag(
@@ -0,0 +1,102 @@
+use std::io;
+
+use quick_xml::{
+    Writer,
+    events::{
+        BytesDecl,
+        Event,
+    },
+};
+
+use super::{
+    XlsxError,
+    driver::{
+        write_end_tag,
+        write_new_line,
+        write_start_tag,
+    },
+};
+use crate::{
+    helper::const_str::{
+        ACTIVEX_BIN_NS,
+        PKG_ACTIVEX,
+        REL_NS,
+    },
+    structs::{
+        ActiveXControl,
+        Worksheet,
+        WriterManager,
+    },
+};
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(Vec<String>, Vec<String>), XlsxError> {
+    let mut ctrl_prop_no_list: Vec<String> = Vec::new();
+    let mut active_x_no_list: Vec<String> = Vec::new();
+
+    for form_control in worksheet.controls().form_control() {
+        let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+        form_control.write_form_control_properties(&mut writer);
+        let file_no = writer_mng.add_file_at_ctrl_prop(writer)?;
+        ctrl_prop_no_list.push(file_no.to_string());
+    }
+
+    for active_x_control in worksheet.controls().active_x_control() {
+        let file_no = writer_mng.add_file_at_active_x(active_x_control.control_data())?;
+        if let Some(data) = active_x_control.binary_data() {
+            writer_mng.add_bin(&format!("{PKG_ACTIVEX}/activeX{file_no}.bin"), data)?;
+            write_binary_rels(active_x_control, file_no, writer_mng)?;
+        }
+        active_x_no_list.push(file_no.to_string());
+    }
+
+    Ok((ctrl_prop_no_list, active_x_no_list))
+}
+
+fn write_binary_rels<W: io::Seek + io::Write>(
+    active_x_control: &ActiveXControl,
+    file_no: i32,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer
+        .write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )))
+        .unwrap();
+    write_new_line(&mut writer);
+
+    // relationships
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![("xmlns", REL_NS).into()],
+        false,
+    );
+
+    let r_id = match active_x_control.binary_relationship_id() {
+        "" => "rId1",
+        v => v,
+    };
+    let target = format!("activeX{file_no}.bin");
+    write_start_tag(
+        &mut writer,
+        "Relationship",
+        vec![
+            ("Id", r_id).into(),
+            ("Type", ACTIVEX_BIN_NS).into(),
+            ("Target", target.as_str()).into(),
+        ],
+        true,
+    );
+
+    write_end_tag(&mut writer, "Relationships");
+
+    let file_path = format!("{PKG_ACTIVEX}/_rels/activeX{file_no}.xml.rels");
+    writer_mng.add_writer(&file_path, writer)
+}
@@ -88,6 +88,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
     }
 
+    // relationships of the parts that are not supported
+    wb.package_passthrough().write_relationships(&mut writer);
+
     write_end_tag(&mut writer, "Relationships");
 
     let target = "_rels/.rels";
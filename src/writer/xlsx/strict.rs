@@ -0,0 +1,95 @@
+use std::io::{
+    self,
+    Read,
+    Write,
+};
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use crate::{
+    XlsxError,
+    helper::{
+        const_str::PKG_WORKBOOK,
+        strict::to_strict,
+    },
+};
+
+/// Rewrites a package written with the Transitional conformance to the
+/// Strict one.
+pub(crate) fn convert(buffer: &[u8]) -> Result<Vec<u8>, XlsxError> {
+    let mut arv = zip::ZipArchive::new(io::Cursor::new(buffer))?;
+    let mut result = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+    for i in 0..arv.len() {
+        let mut file = arv.by_index(i)?;
+        let name = file.name().to_string();
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        let zip_opt =
+            zip::write::SimpleFileOptions::default().compression_method(file.compression());
+        // `_rels/.rels` has no file stem, so it has no extension for `Path`.
+        let is_xml = name
+            .rsplit_once('.')
+            .is_some_and(|(_, v)| v.eq_ignore_ascii_case("xml") || v.eq_ignore_ascii_case("rels"));
+        if is_xml {
+            data = convert_part(&data, name == PKG_WORKBOOK)?;
+        }
+        result.start_file(name, zip_opt)?;
+        result.write_all(&data)?;
+    }
+    Ok(result.finish()?.into_inner())
+}
+
+fn convert_part(data: &[u8], is_workbook: bool) -> Result<Vec<u8>, XlsxError> {
+    let mut reader = Reader::from_reader(data);
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    let mut is_root = true;
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                let tag = convert_tag(&e, is_workbook && is_root);
+                is_root = false;
+                writer.write_event(Event::Start(tag))?;
+            }
+            Event::Empty(e) => {
+                let tag = convert_tag(&e, is_workbook && is_root);
+                is_root = false;
+                writer.write_event(Event::Empty(tag))?;
+            }
+            Event::Eof => break,
+            event => writer.write_event(event)?,
+        }
+    }
+    Ok(writer.into_inner().into_inner())
+}
+
+fn convert_tag(e: &BytesStart, is_workbook_root: bool) -> BytesStart<'static> {
+    let name = String::from_utf8_lossy(e.name().into_inner()).into_owned();
+    let mut tag = BytesStart::new(name);
+    for attribute in e.attributes().with_checks(false).flatten() {
+        let key = attribute.key.into_inner();
+        let is_namespace = key == b"xmlns"
+            || key.starts_with(b"xmlns:")
+            || key == b"Type"
+            || key == b"uri";
+        if is_workbook_root && key == b"conformance" {
+            continue;
+        }
+        if is_namespace {
+            let value = to_strict(&String::from_utf8_lossy(&attribute.value));
+            tag.push_attribute((key, value.as_bytes()));
+        } else {
+            tag.push_attribute((key, attribute.value.as_ref()));
+        }
+    }
+    if is_workbook_root {
+        tag.push_attribute(("conformance", "strict"));
+    }
+    tag
+}
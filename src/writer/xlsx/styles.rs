@@ -17,6 +17,7 @@ use crate::{
     structs::{
         Stylesheet,
         WriterManager,
+        raw::STYLESHEET_ORDER,
     },
 };
 
@@ -24,6 +25,14 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     stylesheet: &Stylesheet,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
+    let writer = stylesheet
+        .passthrough()
+        .write_elements(make_part(stylesheet), STYLESHEET_ORDER)?;
+    writer_mng.add_writer(PKG_STYLES, writer)
+}
+
+/// Writes the styles part without the content kept by the passthrough.
+pub(crate) fn make_part(stylesheet: &Stylesheet) -> Writer<io::Cursor<Vec<u8>>> {
     let mut writer = Writer::new(io::Cursor::new(Vec::new()));
     // XML header
     writer
@@ -36,6 +45,5 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_new_line(&mut writer);
 
     stylesheet.write_to(&mut writer);
-
-    writer_mng.add_writer(PKG_STYLES, writer)
+    writer
 }
@@ -20,6 +20,9 @@ use super::{
 use crate::{
     helper::const_str::SHEET_MAIN_NS,
     structs::{
+        DifferentialFormats,
+        Style,
+        Stylesheet,
         Worksheet,
         WriterManager,
     },
@@ -27,8 +30,10 @@ use crate::{
 
 pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
+    stylesheet: &mut Stylesheet,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<Vec<String>, XlsxError> {
+    let differential_formats = stylesheet.differential_formats_mut();
     let mut table_no_list = Vec::<String>::new();
     for table in worksheet.tables() {
         let mut writer = Writer::new(io::Cursor::new(Vec::new()));
@@ -65,10 +70,24 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         if table.has_totals_row_count() {
             attributes.push(("totalsRowCount", totals_row_count_str).into());
         }
+        let header_row_dxf_id = dxf_id(table.header_row_style(), differential_formats);
+        if let Some(v) = &header_row_dxf_id {
+            attributes.push(("headerRowDxfId", v).into());
+        }
+        let data_dxf_id = dxf_id(table.data_style(), differential_formats);
+        if let Some(v) = &data_dxf_id {
+            attributes.push(("dataDxfId", v).into());
+        }
+        let totals_row_dxf_id = dxf_id(table.totals_row_style(), differential_formats);
+        if let Some(v) = &totals_row_dxf_id {
+            attributes.push(("totalsRowDxfId", v).into());
+        }
         write_start_tag(&mut writer, "table", attributes, false);
 
         // autoFilter
-        write_start_tag(&mut writer, "autoFilter", vec![("ref", &area).into()], true);
+        let mut auto_filter = table.auto_filter().clone();
+        auto_filter.set_range(table.auto_filter_range());
+        auto_filter.write_to(&mut writer);
 
         // tableColumns
         let cols = table.columns();
@@ -86,19 +105,39 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             let col_id_str = col_id.to_string();
             attributes.push(("id", &col_id_str).into());
             attributes.push(("name", col.name()).into());
-            attributes.push(("totalsRowLabel", col.totals_row_label_str()).into());
-            attributes.push(("totalsRowFunction", col.totals_row_function_str()).into());
-            match col.calculated_column_formula() {
-                Some(v) => {
-                    write_start_tag(&mut writer, "tableColumn", attributes, false);
+            if col.has_totals_row_label() {
+                attributes.push(("totalsRowLabel", col.totals_row_label_str()).into());
+            }
+            if col.has_totals_row_function() {
+                attributes.push(("totalsRowFunction", col.totals_row_function_str()).into());
+            }
+            let header_row_dxf_id = dxf_id(col.header_row_style(), differential_formats);
+            if let Some(v) = &header_row_dxf_id {
+                attributes.push(("headerRowDxfId", v).into());
+            }
+            let data_dxf_id = dxf_id(col.data_style(), differential_formats);
+            if let Some(v) = &data_dxf_id {
+                attributes.push(("dataDxfId", v).into());
+            }
+            let totals_row_dxf_id = dxf_id(col.totals_row_style(), differential_formats);
+            if let Some(v) = &totals_row_dxf_id {
+                attributes.push(("totalsRowDxfId", v).into());
+            }
+            let is_empty =
+                col.calculated_column_formula().is_none() && col.totals_row_formula().is_none();
+            write_start_tag(&mut writer, "tableColumn", attributes, is_empty);
+            if !is_empty {
+                if let Some(v) = col.calculated_column_formula() {
                     write_start_tag(&mut writer, "calculatedColumnFormula", vec![], false);
                     write_text_node(&mut writer, v);
                     write_end_tag(&mut writer, "calculatedColumnFormula");
-                    write_end_tag(&mut writer, "tableColumn");
                 }
-                None => {
-                    write_start_tag(&mut writer, "tableColumn", attributes, true);
+                if let Some(v) = col.totals_row_formula() {
+                    write_start_tag(&mut writer, "totalsRowFormula", vec![], false);
+                    write_text_node(&mut writer, v);
+                    write_end_tag(&mut writer, "totalsRowFormula");
                 }
+                write_end_tag(&mut writer, "tableColumn");
             }
 
             col_id += 1;
@@ -145,3 +184,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     }
     Ok(table_no_list)
 }
+
+fn dxf_id(style: Option<&Style>, differential_formats: &mut DifferentialFormats) -> Option<String> {
+    style.map(|v| differential_formats.set_style(v).to_string())
+}
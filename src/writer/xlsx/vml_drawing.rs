@@ -63,8 +63,8 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     let mut id = 1000 + 25;
 
-    // ole_object
-    if worksheet.has_ole_objects() {
+    // ole_object (activeX controls share the picture shape type)
+    if worksheet.has_ole_objects() || !worksheet.controls().active_x_control().is_empty() {
         // v:shapetype
         write_start_tag(
             &mut writer,
@@ -179,6 +179,69 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         }
     }
 
+    // form control, activeX control
+    if worksheet.has_controls() {
+        if !worksheet.controls().form_control().is_empty() {
+            // v:shapetype
+            write_start_tag(
+                &mut writer,
+                "v:shapetype",
+                vec![
+                    ("id", "_x0000_t201").into(),
+                    ("coordsize", "21600,21600").into(),
+                    ("o:spt", "201").into(),
+                    ("path", "m,l,21600r21600,l21600,xe").into(),
+                ],
+                false,
+            );
+
+            // v:stroke
+            write_start_tag(
+                &mut writer,
+                "v:stroke",
+                vec![("joinstyle", "miter").into()],
+                true,
+            );
+
+            // v:path
+            write_start_tag(
+                &mut writer,
+                "v:path",
+                vec![
+                    ("shadowok", "f").into(),
+                    ("o:extrusionok", "f").into(),
+                    ("strokeok", "f").into(),
+                    ("fillok", "f").into(),
+                    ("o:connecttype", "rect").into(),
+                ],
+                true,
+            );
+
+            // o:lock
+            write_start_tag(
+                &mut writer,
+                "o:lock",
+                vec![("v:ext", "edit").into(), ("shapetype", "t").into()],
+                true,
+            );
+
+            write_end_tag(&mut writer, "v:shapetype");
+        }
+
+        for form_control in worksheet.controls().form_control() {
+            // v:shape
+            form_control.write_vml_to(&mut writer, id, &mut rel_list);
+            id += 1;
+        }
+        for active_x_control in worksheet.controls().active_x_control() {
+            // v:shape
+            active_x_control
+                .shape()
+                .write_to(&mut writer, id, &mut rel_list);
+            id += 1;
+        }
+    }
+
     // comment
     if worksheet.has_comments() {
         // v:shapetype
@@ -227,3 +290,154 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let file_no = writer_mng.add_file_at_vml_drawing(writer)?;
     Ok((file_no.to_string(), rel_list))
 }
+
+/// Write the VML drawing with the pictures of the header and footer.
+pub(crate) fn write_header_footer<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(String, Vec<(String, String)>), XlsxError> {
+    let mut rel_list: Vec<(String, String)> = Vec::new();
+    if !worksheet.has_legacy_drawing_header_footer() {
+        return Ok((String::new(), rel_list));
+    }
+
+    // The shape ids must not overlap those of the other VML drawing.
+    let id_map: u32 = if worksheet.has_legacy_drawing() { 2 } else { 1 };
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // xml
+    write_start_tag(
+        &mut writer,
+        "xml",
+        vec![
+            ("xmlns:v", VML_NS).into(),
+            ("xmlns:o", OFFICE_NS).into(),
+            ("xmlns:x", EXCEL_NS).into(),
+        ],
+        false,
+    );
+
+    // o:shapelayout
+    write_start_tag(
+        &mut writer,
+        "o:shapelayout",
+        vec![("v:ext", "edit").into()],
+        false,
+    );
+
+    // o:idmap
+    write_start_tag(
+        &mut writer,
+        "o:idmap",
+        vec![("v:ext", "edit").into(), ("data", &id_map.to_string()).into()],
+        true,
+    );
+
+    write_end_tag(&mut writer, "o:shapelayout");
+
+    // v:shapetype
+    write_start_tag(
+        &mut writer,
+        "v:shapetype",
+        vec![
+            ("id", "_x0000_t75").into(),
+            ("coordsize", "21600,21600").into(),
+            ("o:spt", "75").into(),
+            ("o:preferrelative", "t").into(),
+            ("path", "m@4@5l@4@11@9@11@9@5xe").into(),
+            ("filled", "f").into(),
+            ("stroked", "f").into(),
+        ],
+        false,
+    );
+
+    // v:stroke
+    write_start_tag(
+        &mut writer,
+        "v:stroke",
+        vec![("joinstyle", "miter").into()],
+        true,
+    );
+
+    // v:formulas
+    write_start_tag(&mut writer, "v:formulas", vec![], false);
+    for eqn in [
+        "if lineDrawn pixelLineWidth 0",
+        "sum @0 1 0",
+        "sum 0 0 @1",
+        "prod @2 1 2",
+        "prod @3 21600 pixelWidth",
+        "prod @3 21600 pixelHeight",
+        "sum @0 0 1",
+        "prod @6 1 2",
+        "prod @7 21600 pixelWidth",
+        "sum @8 21600 0",
+        "prod @7 21600 pixelHeight",
+        "sum @10 21600 0",
+    ] {
+        write_start_tag(&mut writer, "v:f", vec![("eqn", eqn).into()], true);
+    }
+    write_end_tag(&mut writer, "v:formulas");
+
+    // v:path
+    write_start_tag(
+        &mut writer,
+        "v:path",
+        vec![
+            ("o:extrusionok", "f").into(),
+            ("gradientshapeok", "t").into(),
+            ("o:connecttype", "rect").into(),
+        ],
+        true,
+    );
+
+    // o:lock
+    write_start_tag(
+        &mut writer,
+        "o:lock",
+        vec![("v:ext", "edit").into(), ("aspectratio", "t").into()],
+        true,
+    );
+
+    write_end_tag(&mut writer, "v:shapetype");
+
+    for (id, image) in (id_map * 1024 + 1..).zip(worksheet.header_footer().images()) {
+        // v:shape
+        let spid = format!("_x0000_s{id}");
+        write_start_tag(
+            &mut writer,
+            "v:shape",
+            vec![
+                ("id", image.id()).into(),
+                ("o:spid", &spid).into(),
+                ("type", "#_x0000_t75").into(),
+                ("style", &image.style()).into(),
+            ],
+            false,
+        );
+
+        // v:imagedata
+        let r_id_str = format!("rId{}", image.image().rid(&mut rel_list));
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("o:relid", &r_id_str).into());
+        if !image.image().image_title().is_empty() {
+            attributes.push(("o:title", image.image().image_title()).into());
+        }
+        write_start_tag(&mut writer, "v:imagedata", attributes, true);
+
+        // o:lock
+        write_start_tag(
+            &mut writer,
+            "o:lock",
+            vec![("v:ext", "edit").into(), ("rotation", "t").into()],
+            true,
+        );
+
+        write_end_tag(&mut writer, "v:shape");
+    }
+
+    write_end_tag(&mut writer, "xml");
+
+    let file_no = writer_mng.add_file_at_vml_drawing(writer)?;
+    Ok((file_no.to_string(), rel_list))
+}
@@ -17,14 +17,19 @@ use super::{
     },
 };
 use crate::{
-    helper::const_str::{
-        PKG_WORKBOOK,
-        REL_OFC_NS,
-        SHEET_MAIN_NS,
+    helper::{
+        address::join_address,
+        const_str::{
+            PKG_WORKBOOK,
+            REL_OFC_NS,
+            SHEET_MAIN_NS,
+        },
     },
     structs::{
+        DefinedName,
         Workbook,
         WriterManager,
+        raw::WORKBOOK_ORDER,
     },
 };
 
@@ -32,6 +37,14 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     wb: &Workbook,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
+    let writer = wb
+        .workbook_passthrough()
+        .write_elements(make_part(wb), WORKBOOK_ORDER)?;
+    writer_mng.add_writer(PKG_WORKBOOK, writer)
+}
+
+/// Writes the workbook part without the content kept by the passthrough.
+pub(crate) fn make_part(wb: &Workbook) -> Writer<io::Cursor<Vec<u8>>> {
     let mut writer = Writer::new(io::Cursor::new(Vec::new()));
     // XML header
     writer
@@ -112,16 +125,15 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_end_tag(&mut writer, "sheets");
 
     // definedNames
-    if wb.has_defined_names() {
+    let sheet_defined_names = sheet_defined_names(wb);
+    if !wb.defined_names().is_empty() || !sheet_defined_names.is_empty() {
         write_start_tag(&mut writer, "definedNames", vec![], false);
 
         for defined_name in wb.defined_names() {
             defined_name.write_to(&mut writer);
         }
-        for sheet in wb.sheet_collection_no_check() {
-            for defined_name in sheet.defined_names() {
-                defined_name.write_to(&mut writer);
-            }
+        for defined_name in &sheet_defined_names {
+            defined_name.write_to(&mut writer);
         }
 
         write_end_tag(&mut writer, "definedNames");
@@ -160,6 +172,41 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     }
 
     write_end_tag(&mut writer, "workbook");
+    writer
+}
 
-    writer_mng.add_writer(PKG_WORKBOOK, writer)
+/// Names stored on the sheets.
+/// Sheet-scoped names get the current index of their sheet, and the filter
+/// database name is rebuilt from the auto filter of a loaded sheet.
+pub(crate) fn sheet_defined_names(wb: &Workbook) -> Vec<DefinedName> {
+    let mut result: Vec<DefinedName> = Vec::new();
+    for (index, sheet) in wb.sheet_collection_no_check().iter().enumerate() {
+        let index: u32 = num_traits::cast(index).unwrap();
+        for defined_name in sheet.defined_names() {
+            if !defined_name.has_local_sheet_id() {
+                result.push(defined_name.clone());
+                continue;
+            }
+            if sheet.is_deserialized() && defined_name.name() == DefinedName::FILTER_DATABASE {
+                continue;
+            }
+            let mut obj = defined_name.clone();
+            obj.set_local_sheet_id(index);
+            result.push(obj);
+        }
+        if !sheet.is_deserialized() {
+            continue;
+        }
+        if let Some(auto_filter) = sheet.auto_filter() {
+            let mut range = auto_filter.range().clone();
+            range.lock_all();
+            let mut obj = DefinedName::default();
+            obj.set_name(DefinedName::FILTER_DATABASE)
+                .add_address(join_address(sheet.name(), &range.range()));
+            obj.set_local_sheet_id(index);
+            obj.set_hidden(true);
+            result.push(obj);
+        }
+    }
+    result
 }
@@ -19,6 +19,7 @@ use super::{
 };
 use crate::{
     helper::const_str::{
+        CHARTSHEET_NS,
         JSA_PROJECT_NS,
         PERSION_NS,
         PIVOT_CACHE_DEF_NS,
@@ -61,9 +62,14 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let mut index = 1;
 
     // relationships worksheet
-    for _ in wb.sheet_collection_no_check() {
-        let path_str = format!("worksheets/sheet{index}.xml");
-        write_relationship(&mut writer, &index.to_string(), WORKSHEET_NS, &path_str, "");
+    for worksheet in wb.sheet_collection_no_check() {
+        if worksheet.is_chartsheet() {
+            let path_str = format!("chartsheets/sheet{index}.xml");
+            write_relationship(&mut writer, &index.to_string(), CHARTSHEET_NS, &path_str, "");
+        } else {
+            let path_str = format!("worksheets/sheet{index}.xml");
+            write_relationship(&mut writer, &index.to_string(), WORKSHEET_NS, &path_str, "");
+        }
         index += 1;
     }
 
@@ -140,6 +146,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
     }
 
+    // relationships of the parts that are not supported
+    wb.workbook_passthrough().write_relationships(&mut writer);
+
     write_end_tag(&mut writer, root_tag_name);
     make_file_from_writer(
         PKG_WORKBOOK_RELS,
@@ -148,6 +157,10 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         None,
         is_light,
     )?;
+
+    // parts that are not supported
+    wb.package_passthrough().write_parts(writer_mng)?;
+    wb.workbook_passthrough().write_parts(writer_mng)?;
     Ok(())
 }
 
@@ -33,10 +33,12 @@ use crate::{
     },
     structs::{
         Cell,
+        ConditionalFormatting,
         SharedStringTable,
         Stylesheet,
         Worksheet,
         WriterManager,
+        raw::WORKSHEET_ORDER,
     },
 };
 
@@ -64,6 +66,21 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     has_macros: bool,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
+    let writer = make_part(worksheet, shared_string_table, stylesheet, has_macros);
+    let writer = worksheet
+        .passthrough()
+        .write_elements(writer, WORKSHEET_ORDER)?;
+    let target = format!("{PKG_SHEET}{sheet_no}.xml");
+    writer_mng.add_writer(&target, writer)
+}
+
+/// Writes the worksheet part without the content kept by the passthrough.
+pub(crate) fn make_part(
+    worksheet: &Worksheet,
+    shared_string_table: &RwLock<SharedStringTable>,
+    stylesheet: &mut Stylesheet,
+    has_macros: bool,
+) -> InternalWriter {
     let mut writer = Writer::new(io::Cursor::new(Vec::new()));
 
     write_worksheet_header(&mut writer);
@@ -74,9 +91,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_worksheet_extensions(&mut writer, worksheet);
 
     write_end_tag(&mut writer, "worksheet");
-
-    let target = format!("{PKG_SHEET}{sheet_no}.xml");
-    writer_mng.add_writer(&target, writer)
+    writer
 }
 
 /// Writes the XML header and worksheet opening tag with required namespace
@@ -134,18 +149,23 @@ fn write_worksheet_properties(
         attributes.push(("codeName", code_name).into());
     }
 
-    match worksheet.tab_color() {
-        Some(v) => {
-            write_start_tag(writer, "sheetPr", attributes, false);
-            v.write_to_tab_color(writer);
-            write_end_tag(writer, "sheetPr");
-        }
-        None => {
-            if !attributes.is_empty() {
-                write_start_tag(writer, "sheetPr", attributes, true);
-            }
+    let fit_to_page = worksheet.page_setup().fit_to_page();
+    if worksheet.tab_color().is_none() && !fit_to_page {
+        if !attributes.is_empty() {
+            write_start_tag(writer, "sheetPr", attributes, true);
         }
+        return;
+    }
+
+    write_start_tag(writer, "sheetPr", attributes, false);
+    if let Some(v) = worksheet.tab_color() {
+        v.write_to_tab_color(writer);
     }
+    if fit_to_page {
+        // pageSetUpPr
+        write_start_tag(writer, "pageSetUpPr", vec![("fitToPage", "1").into()], true);
+    }
+    write_end_tag(writer, "sheetPr");
 }
 
 /// Writes the worksheet dimension (used range) and sheet view settings.
@@ -271,19 +291,19 @@ fn write_worksheet_features(
     }
 
     if let Some(v) = worksheet.auto_filter() {
-        write_start_tag(
-            writer,
-            "autoFilter",
-            vec![("ref", &v.range().range()).into()],
-            true,
-        );
+        v.write_to(writer);
     }
 
     worksheet.merge_cells_crate().write_to(writer);
     write_start_tag(writer, "phoneticPr", vec![("fontId", "1").into()], true);
 
+    let mut x14_index = 0;
     for conditional_formatting in worksheet.conditional_formatting_collection() {
-        conditional_formatting.write_to(writer, stylesheet.differential_formats_mut());
+        conditional_formatting.write_to(
+            writer,
+            stylesheet.differential_formats_mut(),
+            &mut x14_index,
+        );
     }
 
     if let Some(v) = worksheet.data_validations() {
@@ -304,8 +324,15 @@ fn write_worksheet_extensions(writer: &mut InternalWriter, worksheet: &Worksheet
     r_id = write_drawings(writer, worksheet, r_id);
     write_tables_and_objects(writer, worksheet, r_id);
 
-    if worksheet.data_validations_2010().is_some() {
+    let has_x14_conditional_formatting = worksheet
+        .conditional_formatting_collection()
+        .iter()
+        .any(ConditionalFormatting::has_x14);
+    if has_x14_conditional_formatting || worksheet.data_validations_2010().is_some() {
         write_start_tag(writer, "extLst", vec![], false);
+        if has_x14_conditional_formatting {
+            write_x14_conditional_formattings(writer, worksheet);
+        }
         if let Some(v) = worksheet.data_validations_2010() {
             v.write_to(writer);
         }
@@ -313,6 +340,35 @@ fn write_worksheet_extensions(writer: &mut InternalWriter, worksheet: &Worksheet
     }
 }
 
+/// Writes the Excel 2010 conditional formatting extension.
+///
+/// # Arguments
+///
+/// * `writer` - The XML writer to write to
+/// * `worksheet` - The worksheet containing the conditional formatting
+fn write_x14_conditional_formattings(writer: &mut InternalWriter, worksheet: &Worksheet) {
+    // ext
+    write_start_tag(
+        writer,
+        "ext",
+        vec![
+            ("uri", "{78C0D931-6437-407d-A8EE-F0AAD8539E65}").into(),
+            ("xmlns:x14", SHEET_MS_MAIN_NS).into(),
+        ],
+        false,
+    );
+
+    // x14:conditionalFormattings
+    write_start_tag(writer, "x14:conditionalFormattings", vec![], false);
+    let mut x14_index = 0;
+    for conditional_formatting in worksheet.conditional_formatting_collection() {
+        conditional_formatting.write_x14_to(writer, &mut x14_index);
+    }
+    write_end_tag(writer, "x14:conditionalFormattings");
+
+    write_end_tag(writer, "ext");
+}
+
 /// Writes rows and their contained cells to the worksheet.
 ///
 /// # Arguments
@@ -489,17 +545,41 @@ fn write_drawings(writer: &mut InternalWriter, worksheet: &Worksheet, mut r_id:
         r_id += 1;
     }
 
+    if worksheet.has_legacy_drawing_header_footer() {
+        let r_id_str = format!("rId{r_id}");
+        write_start_tag(
+            writer,
+            "legacyDrawingHF",
+            vec![("r:id", &r_id_str).into()],
+            true,
+        );
+        r_id += 1;
+    }
+
     r_id
 }
 
-/// Writes tables and OLE objects.
+/// Writes OLE objects, controls and tables.
 ///
 /// # Arguments
 ///
 /// * `writer` - The XML writer to write to
 /// * `worksheet` - The worksheet containing tables and objects
 /// * `r_id` - The current relationship ID
-fn write_tables_and_objects(writer: &mut InternalWriter, worksheet: &Worksheet, mut r_id: i32) {
+fn write_tables_and_objects(writer: &mut InternalWriter, worksheet: &Worksheet, r_id: i32) {
+    // Table and pivot table relationships come before those of the objects.
+    let r_id: usize = num_traits::cast(r_id).unwrap();
+    let ole_r_id = r_id + worksheet.tables().len() + worksheet.pivot_tables().len();
+    let ole_id = 1000 + 25;
+    let ole_count = worksheet.ole_objects().ole_object().len();
+    worksheet
+        .ole_objects()
+        .write_to(writer, ole_r_id, ole_id);
+
+    worksheet
+        .controls()
+        .write_to(writer, ole_r_id + ole_count * 2, ole_id + ole_count);
+
     if worksheet.has_table() {
         let tables = worksheet.tables();
         write_start_tag(
@@ -508,18 +588,12 @@ fn write_tables_and_objects(writer: &mut InternalWriter, worksheet: &Worksheet,
             vec![("count", &tables.len().to_string()).into()],
             false,
         );
-        for _table in worksheet.tables() {
+        for (r_id, _table) in (r_id..).zip(worksheet.tables()) {
             let r_id_str = format!("rId{r_id}");
             write_start_tag(writer, "tablePart", vec![("r:id", &r_id_str).into()], true);
-            r_id += 1;
         }
         write_end_tag(writer, "tableParts");
     }
-
-    let ole_id = 1000 + 25;
-    worksheet
-        .ole_objects()
-        .write_to(writer, num_traits::cast(r_id).unwrap(), ole_id);
 }
 
 #[cfg(test)]
@@ -18,7 +18,9 @@ use super::{
 };
 use crate::{
     helper::const_str::{
+        ACTIVEX_NS,
         COMMENTS_NS,
+        CTRL_PROP_NS,
         DRAWINGS_NS,
         HYPERLINK_NS,
         IMAGE_NS,
@@ -44,10 +46,13 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet_no: &str,
     drawing_no: &str,
     vml_drawing_no: &str,
+    vml_drawing_hf_no: &str,
     comment_no: &str,
     threaded_comment_no: &str,
     ole_object_no_list: &[String],
     excel_no_list: &[String],
+    ctrl_prop_no_list: &[String],
+    active_x_no_list: &[String],
     printer_settings_no: &str,
     table_no_list: &[String],
     pivot_table_no_list: &[String],
@@ -128,6 +133,18 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         r_id += 1;
     }
 
+    // Write vmlDrawing relationship of the header and footer
+    if worksheet.has_legacy_drawing_header_footer() {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id.to_string(),
+            VML_DRAWING_NS,
+            format!("../drawings/vmlDrawing{vml_drawing_hf_no}.vml").as_str(),
+            "",
+        );
+        r_id += 1;
+    }
+
     // write table relationships
     for table_no in table_no_list {
         is_write = write_relationship(
@@ -197,6 +214,49 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         r_id += 1;
     }
 
+    // Write controls
+    let controls = worksheet
+        .controls()
+        .form_control()
+        .iter()
+        .zip(ctrl_prop_no_list)
+        .map(|(v, no)| {
+            (
+                CTRL_PROP_NS,
+                format!("../ctrlProps/ctrlProp{no}.xml"),
+                v.control_properties(),
+            )
+        })
+        .chain(
+            worksheet
+                .controls()
+                .active_x_control()
+                .iter()
+                .zip(active_x_no_list)
+                .map(|(v, no)| {
+                    (
+                        ACTIVEX_NS,
+                        format!("../activeX/activeX{no}.xml"),
+                        v.control_properties(),
+                    )
+                }),
+        );
+    for (p_type, target, control_properties) in controls {
+        is_write = write_relationship(&mut writer, &r_id.to_string(), p_type, &target, "");
+        r_id += 1;
+
+        if let Some(image) = control_properties.image() {
+            write_relationship(
+                &mut writer,
+                &r_id.to_string(),
+                IMAGE_NS,
+                format!("../media/{}", image.image_name()).as_str(),
+                "",
+            );
+            r_id += 1;
+        }
+    }
+
     // Write comments relationship
     if worksheet.has_comments() {
         is_write = write_relationship(
@@ -220,13 +280,18 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
     }
 
+    // Write relationships of the parts that are not supported
+    if worksheet.passthrough().write_relationships(&mut writer) {
+        is_write = true;
+    }
+
     write_end_tag(&mut writer, "Relationships");
 
     if is_write {
         let file_path = format!("{PKG_SHEET_RELS}{worksheet_no}.xml.rels");
         writer_mng.add_writer(&file_path, writer)?;
     }
-    Ok(())
+    worksheet.passthrough().write_parts(writer_mng)
 }
 
 fn write_relationship(
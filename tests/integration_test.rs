@@ -2956,3 +2956,3011 @@ fn issue_343() {
     let out = std::path::Path::new("./tests/result_files/issue_343.xlsx");
     umya_spreadsheet::writer::xlsx::write(&book, out).unwrap();
 }
+
+#[test]
+fn chartsheet_roundtrip_keeps_tab_order() {
+    use std::io::Read;
+
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    for row in 1..=5u32 {
+        book.sheet_by_name_mut("Sheet1")
+            .unwrap()
+            .cell_mut((1, row))
+            .set_value_number(row);
+    }
+
+    let mut from_marker = drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("A1");
+    to_marker.set_coordinate("B2");
+    let mut chart = Chart::default();
+    chart.new_chart(
+        &ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$5"],
+    );
+    let chartsheet = book.add_chartsheet("Chart1", chart).unwrap();
+    chartsheet.chart_mut().set_title("Sales");
+    chartsheet
+        .page_setup_mut()
+        .set_orientation(OrientationValues::Landscape);
+    book.new_sheet("Sheet2").unwrap();
+
+    assert!(book.add_chartsheet("Sheet2", Chart::default()).is_err());
+
+    let out_path = std::path::Path::new("./tests/result_files/chartsheet.xlsx");
+    writer::xlsx::write(&book, out_path).unwrap();
+
+    let mut zip = zip::ZipArchive::new(std::fs::File::open(out_path).unwrap()).unwrap();
+    let mut workbook_rels = String::new();
+    zip.by_name("xl/_rels/workbook.xml.rels")
+        .unwrap()
+        .read_to_string(&mut workbook_rels)
+        .unwrap();
+    assert!(workbook_rels.contains("chartsheets/sheet2.xml"));
+    assert!(workbook_rels.contains("worksheets/sheet3.xml"));
+    let mut content_types = String::new();
+    zip.by_name("[Content_Types].xml")
+        .unwrap()
+        .read_to_string(&mut content_types)
+        .unwrap();
+    assert!(content_types.contains("/xl/chartsheets/sheet2.xml"));
+    assert!(zip.by_name("xl/chartsheets/_rels/sheet2.xml.rels").is_ok());
+
+    let book = reader::xlsx::read(out_path).unwrap();
+    let names: Vec<&str> = book.sheet_collection().iter().map(Worksheet::name).collect();
+    assert_eq!(names, vec!["Sheet1", "Chart1", "Sheet2"]);
+
+    let sheet = book.sheet_by_name("Chart1").unwrap();
+    assert!(sheet.is_chartsheet());
+    let chartsheet = sheet.chartsheet().unwrap();
+    assert!(matches!(
+        chartsheet.page_setup().orientation(),
+        OrientationValues::Landscape
+    ));
+    assert!(
+        chartsheet
+            .chart()
+            .chart_space()
+            .chart()
+            .plot_area()
+            .line_chart()
+            .is_some()
+    );
+    assert!(!book.sheet_by_name("Sheet1").unwrap().is_chartsheet());
+
+    let out_path = std::path::Path::new("./tests/result_files/chartsheet_roundtrip.xlsx");
+    writer::xlsx::write(&book, out_path).unwrap();
+
+    // A chart sheet has no cells, so the cells written to it are rejected.
+    let mut book = book;
+    book.sheet_by_name_mut("Chart1")
+        .unwrap()
+        .cell_mut("A1")
+        .set_value("lost");
+    let error = writer::xlsx::write_writer(&book, std::io::Cursor::new(Vec::new())).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid cell A1: chart sheet 'Chart1' can not have cells"
+    );
+    assert!(writer::xlsb::write_writer(&book, std::io::Cursor::new(Vec::new())).is_err());
+}
+
+#[test]
+fn chart_cache_refresh_from_cells() {
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    {
+        let sheet = book.sheet_by_name_mut("Sheet1").unwrap();
+        sheet.cell_mut("A1").set_value_number(1.5);
+        sheet.cell_mut("A2").set_value_number(2.25);
+        sheet.cell_mut("A4").set_value_number(4);
+        sheet
+            .style_mut("A4")
+            .number_format_mut()
+            .set_format_code("0.00");
+        for coordinate in ["A1", "A2"] {
+            sheet
+                .style_mut(coordinate)
+                .number_format_mut()
+                .set_format_code("#,##0.0");
+        }
+    }
+    book.new_sheet("Sheet2").unwrap();
+
+    let mut from_marker = drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("H10");
+    let mut chart = Chart::default();
+    chart.new_chart(
+        &ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$4"],
+    );
+    book.sheet_by_name_mut("Sheet2").unwrap().add_chart(chart);
+
+    book.refresh_chart_caches();
+
+    let sheet = book.sheet_by_name("Sheet2").unwrap();
+    let cache = sheet.chart_collection()[0]
+        .chart_space()
+        .chart()
+        .plot_area()
+        .line_chart()
+        .unwrap()
+        .area_chart_series_list()
+        .area_chart_series()[0]
+        .values()
+        .unwrap()
+        .number_reference()
+        .numbering_cache();
+    assert_eq!(cache.format_code().text(), "#,##0.0");
+    assert_eq!(cache.point_count(), 4);
+    let points: Vec<(u32, &str, Option<&str>)> = cache
+        .string_point_list()
+        .iter()
+        .map(|v| (v.index(), v.numeric_value().text(), v.format_code()))
+        .collect();
+    assert_eq!(
+        points,
+        vec![(0, "1.5", None), (1, "2.25", None), (3, "4", Some("0.00"))]
+    );
+
+    let path = std::path::Path::new("./tests/result_files/chart_cache_refresh.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let sheet = book.sheet_by_name("Sheet2").unwrap();
+    let cache = sheet.chart_collection()[0]
+        .chart_space()
+        .chart()
+        .plot_area()
+        .line_chart()
+        .unwrap()
+        .area_chart_series_list()
+        .area_chart_series()[0]
+        .values()
+        .unwrap()
+        .number_reference()
+        .numbering_cache()
+        .clone();
+    assert_eq!(cache.point_count(), 4);
+    assert_eq!(cache.string_point_list().len(), 3);
+    assert_eq!(cache.string_point_list()[2].format_code(), Some("0.00"));
+}
+
+#[test]
+fn chart_builder_applies_style() {
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let chart = ChartBuilder::new(ChartType::BarChart, "C1", "J15")
+        .add_series("Sheet1!$A$1:$A$4")
+        .add_series("Sheet1!$B$1:$B$4")
+        .set_title("Sales")
+        .set_min(0.0)
+        .set_max(100.0)
+        .set_major_unit(25.0)
+        .set_number_format("0.0%")
+        .set_major_gridlines(false)
+        .set_legend_position(drawing::charts::LegendPositionValues::Bottom)
+        .set_style(ChartStyle::Accent3)
+        .build();
+    book.sheet_by_name_mut("Sheet1").unwrap().add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chart_builder.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let chart_xml = zip_entry_to_string(&std::fs::read(path).unwrap(), "xl/charts/chart1.xml");
+    assert!(chart_xml.contains("<c:style val=\"5\"/>"));
+    assert!(!chart_xml.contains("<c:style val=\"2\"/>"));
+    let book = reader::xlsx::read(path).unwrap();
+
+    let chart_space = book.sheet_by_name("Sheet1").unwrap().chart_collection()[0].chart_space();
+    assert_eq!(chart_space.style().unwrap().val(), "5");
+    assert!(matches!(
+        chart_space.chart().legend().legend_position().val(),
+        drawing::charts::LegendPositionValues::Bottom
+    ));
+
+    let plot_area = chart_space.chart().plot_area();
+    let value_axis = &plot_area.value_axis()[0];
+    assert_eq!(value_axis.scaling().min_axis_value().unwrap().val(), 0.0);
+    assert_eq!(value_axis.scaling().max_axis_value().unwrap().val(), 100.0);
+    assert_eq!(value_axis.major_unit().unwrap().val(), 25.0);
+    assert_eq!(value_axis.numbering_format().format_code(), "0.0%");
+    assert!(!value_axis.numbering_format().source_linked());
+    assert!(value_axis.major_gridlines().is_none());
+
+    let colors: Vec<&str> = plot_area
+        .bar_chart()
+        .unwrap()
+        .area_chart_series_list()
+        .area_chart_series()
+        .iter()
+        .map(|v| {
+            v.shape_properties()
+                .unwrap()
+                .solid_fill()
+                .unwrap()
+                .rgb_color_model_hex()
+                .unwrap()
+                .val()
+        })
+        .collect();
+    assert_eq!(colors, vec!["A5A5A5", "7C7C7C"]);
+}
+
+#[test]
+fn shapes_text_boxes_and_connectors_roundtrip() {
+    use umya_spreadsheet::{
+        drawing::{
+            PresetGeometry,
+            TextAlignmentTypeValues,
+            spreadsheet::{
+                MarkerType,
+                TwoCellAnchor,
+            },
+        },
+        *,
+    };
+
+    let make_anchor = |from: &str, to: &str| {
+        let mut from_marker = MarkerType::default();
+        let mut to_marker = MarkerType::default();
+        from_marker.set_coordinate(from);
+        to_marker.set_coordinate(to);
+        let mut anchor = TwoCellAnchor::default();
+        anchor.set_from_marker(from_marker).set_to_marker(to_marker);
+        anchor
+    };
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+
+    let mut geometry = PresetGeometry::default();
+    geometry.set_geometry(PresetGeometry::GEOMETRY_ELLIPSE);
+    let mut label = RichText::default();
+    label.set_text("Start\nhere");
+    let start_id = sheet
+        .add_shape(geometry, make_anchor("B2", "C4"))
+        .set_fill_color("FF0000")
+        .set_outline("000000", 12700)
+        .set_rotation(45.0)
+        .set_text(&label)
+        .set_text_alignment(TextAlignmentTypeValues::Right)
+        .set_auto_fit(true)
+        .id();
+
+    let mut note = RichText::default();
+    note.set_text("Note");
+    let end_id = sheet.add_text_box(make_anchor("F2", "H4"), &note).id();
+    assert_ne!(start_id, end_id);
+
+    sheet.add_connector(make_anchor("C3", "F3"), start_id, end_id);
+
+    let path = std::path::Path::new("./tests/result_files/shapes.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let drawing = book.sheet(0).unwrap().worksheet_drawing();
+
+    let shapes = drawing.shape_collection();
+    assert_eq!(shapes.len(), 2);
+
+    let shape = shapes[0];
+    assert_eq!(shape.id(), start_id);
+    assert!(!shape.non_visual_shape_properties().text_box());
+    assert_eq!(shape.shape_properties().geometry().geometry(), "ellipse");
+    assert_eq!(
+        shape
+            .shape_properties()
+            .solid_fill()
+            .unwrap()
+            .rgb_color_model_hex()
+            .unwrap()
+            .val(),
+        "FF0000"
+    );
+    assert_eq!(shape.shape_properties().outline().unwrap().width(), 12700);
+    assert_eq!(
+        shape.shape_properties().transform2d().unwrap().rot(),
+        Some("2700000")
+    );
+    let text_body = shape.text_body().unwrap();
+    assert!(text_body.body_properties().shape_auto_fit().is_some());
+    assert_eq!(text_body.paragraph().len(), 2);
+    assert_eq!(text_body.paragraph()[1].run()[0].text(), "here");
+    assert!(matches!(
+        text_body.paragraph()[0].paragraph_properties().alignment(),
+        TextAlignmentTypeValues::Right
+    ));
+
+    let text_box = shapes[1];
+    assert_eq!(text_box.id(), end_id);
+    assert!(text_box.non_visual_shape_properties().text_box());
+    assert_eq!(text_box.text_body().unwrap().paragraph()[0].run()[0].text(), "Note");
+
+    let connectors = drawing.connection_shape_collection();
+    assert_eq!(connectors.len(), 1);
+    let connector_properties = connectors[0]
+        .non_visual_connection_shape_properties()
+        .non_visual_connector_shape_drawing_properties();
+    assert_eq!(connector_properties.start_connection().unwrap().id(), start_id);
+    assert_eq!(connector_properties.end_connection().unwrap().id(), end_id);
+}
+
+#[test]
+fn form_controls_roundtrip() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet
+        .add_checkbox("B2", "D3", "Include tax")
+        .set_linked_cell("$A$2")
+        .set_checked(CheckedValues::Checked);
+    sheet
+        .add_dropdown("B5", "D6", "$F$1:$F$3")
+        .set_linked_cell("$A$5")
+        .set_selected(2);
+    sheet.add_button("B8", "D9", "Recalculate", "[0]!Recalculate");
+
+    let path = std::path::Path::new("./tests/result_files/form_controls.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let controls = book.sheet(0).unwrap().controls().form_control();
+    assert_eq!(controls.len(), 3);
+
+    let checkbox = &controls[0];
+    assert_eq!(checkbox.name(), "Check Box 1");
+    assert_eq!(checkbox.object_type(), &FormControlTypeValues::CheckBox);
+    assert_eq!(checkbox.text(), "Include tax");
+    assert_eq!(checkbox.linked_cell(), "$A$2");
+    assert_eq!(checkbox.checked(), &CheckedValues::Checked);
+
+    let dropdown = &controls[1];
+    assert_eq!(dropdown.object_type(), &FormControlTypeValues::Drop);
+    assert_eq!(dropdown.input_range(), "$F$1:$F$3");
+    assert_eq!(dropdown.selected(), 2);
+    assert_eq!(
+        dropdown.control_properties().object_anchor().from_marker().row(),
+        4
+    );
+
+    let button = &controls[2];
+    assert_eq!(button.name(), "Button 3");
+    assert_eq!(button.text(), "Recalculate");
+    assert_eq!(button.macro_name(), "[0]!Recalculate");
+
+    // Controls read from a file are written back.
+    let path = std::path::Path::new("./tests/test_files/issue_189.xlsx");
+    let book = reader::xlsx::read(path).unwrap();
+    let count = book
+        .sheet_collection()
+        .iter()
+        .map(|v| v.controls().form_control().len())
+        .sum::<usize>();
+    assert!(count > 0);
+    let path = std::path::Path::new("./tests/result_files/form_controls_189.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    assert_eq!(
+        book.sheet_collection()
+            .iter()
+            .map(|v| v.controls().form_control().len())
+            .sum::<usize>(),
+        count
+    );
+}
+
+#[test]
+fn active_x_controls_roundtrip() {
+    let path = std::path::Path::new("./tests/test_files/active_x.xlsm");
+    let source = std::fs::read(path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let controls = book.sheet(0).unwrap().controls().active_x_control();
+    assert_eq!(controls.len(), 1);
+    assert_eq!(controls[0].name(), "CommandButton1");
+    assert!(controls[0].binary_data().is_some());
+
+    let output = workbook_to_xlsx_bytes(&book);
+    std::fs::write("./tests/result_files/active_x.xlsm", &output).unwrap();
+
+    let entry_bytes = |xlsx: &[u8], name: &str| {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(xlsx)).unwrap();
+        let mut entry = archive.by_name(name).unwrap();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).unwrap();
+        data
+    };
+    assert_eq!(
+        entry_bytes(&output, "xl/activeX/activeX1.xml"),
+        entry_bytes(&source, "xl/activeX/activeX1.xml")
+    );
+    assert_eq!(
+        entry_bytes(&output, "xl/activeX/activeX1.bin"),
+        entry_bytes(&source, "xl/activeX/activeX1.bin")
+    );
+
+    let rels = zip_entry_to_string(&output, "xl/activeX/_rels/activeX1.xml.rels");
+    assert!(rels.contains(
+        "Type=\"http://schemas.microsoft.com/office/2006/relationships/activeXControlBinary\""
+    ));
+    assert!(rels.contains("Target=\"activeX1.bin\""));
+
+    let sheet_rels = zip_entry_to_string(&output, "xl/worksheets/_rels/sheet1.xml.rels");
+    assert!(sheet_rels.contains("Target=\"../activeX/activeX1.xml\""));
+
+    let content_types = zip_entry_to_string(&output, "[Content_Types].xml");
+    assert!(content_types.contains(concat!(
+        "PartName=\"/xl/activeX/activeX1.xml\" ",
+        "ContentType=\"application/vnd.ms-office.activeX+xml\""
+    )));
+    assert!(content_types.contains(concat!(
+        "PartName=\"/xl/activeX/activeX1.bin\" ",
+        "ContentType=\"application/vnd.ms-office.activeX\""
+    )));
+
+    let sheet_xml = zip_entry_to_string(&output, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains("name=\"CommandButton1\""));
+
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(output), true).unwrap();
+    let controls = book.sheet(0).unwrap().controls().active_x_control();
+    assert_eq!(controls.len(), 1);
+    assert_eq!(
+        controls[0].binary_data().unwrap(),
+        entry_bytes(&source, "xl/activeX/activeX1.bin").as_slice()
+    );
+}
+
+#[test]
+fn data_bar_and_icon_set_x14_roundtrip() {
+    let cfvo = |value_type: ConditionalFormatValueObjectValues, val: &str| {
+        let mut obj = ConditionalFormatValueObject::default();
+        obj.set_type(value_type);
+        if !val.is_empty() {
+            obj.set_val(val);
+        }
+        obj
+    };
+    let color = |argb: &str| {
+        let mut obj = Color::default();
+        obj.set_argb_str(argb);
+        obj
+    };
+    let conditional_formatting = |sqref: &str, rule: ConditionalFormattingRule| {
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref(sqref);
+        let mut obj = ConditionalFormatting::default();
+        obj.set_sequence_of_references(seq);
+        obj.set_conditional_collection(vec![rule]);
+        obj
+    };
+
+    let mut data_bar = DataBar::default();
+    data_bar
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::AutoMin, ""))
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::AutoMax, ""))
+        .add_color_collection(color("FF638EC6"))
+        .set_gradient(false)
+        .set_border_color(color("FF1F4E79"))
+        .set_negative_fill_color(color("FFFF0000"))
+        .set_axis_color(color("FF000000"))
+        .set_axis_position(DataBarAxisPositionValues::Middle)
+        .set_direction(DataBarDirectionValues::RightToLeft);
+    let mut data_bar_rule = ConditionalFormattingRule::default();
+    data_bar_rule
+        .set_type(ConditionalFormatValues::DataBar)
+        .set_priority(1)
+        .set_data_bar(data_bar);
+
+    let mut icon_set = IconSet::default();
+    icon_set
+        .set_icon_set(IconSetValues::ThreeStars)
+        .set_reverse(true)
+        .set_show_value(false)
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Percent, "0"))
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Percent, "33"))
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Percent, "67"))
+        .add_custom_icon(ConditionalFormattingIcon::new(
+            IconSetValues::ThreeTrafficLights1,
+            0,
+        ))
+        .add_custom_icon(ConditionalFormattingIcon::new(IconSetValues::ThreeStars, 1))
+        .add_custom_icon(ConditionalFormattingIcon::new(IconSetValues::ThreeFlags, 2));
+    let mut icon_set_rule = ConditionalFormattingRule::default();
+    icon_set_rule
+        .set_type(ConditionalFormatValues::IconSet)
+        .set_priority(2)
+        .set_icon_set(icon_set);
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.set_conditional_formatting_collection(vec![
+        conditional_formatting("A1:A10", data_bar_rule),
+        conditional_formatting("B1:B10", icon_set_rule),
+    ]);
+
+    let path = std::path::Path::new("./tests/result_files/conditional_formatting_x14.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let collection = book.sheet(0).unwrap().conditional_formatting_collection();
+    assert_eq!(collection.len(), 2);
+
+    let rule = &collection[0].conditional_collection()[0];
+    assert!(!rule.id().is_empty());
+    let data_bar = rule.data_bar().unwrap();
+    assert_eq!(data_bar.cfvo_collection().len(), 2);
+    assert_eq!(
+        data_bar.cfvo_collection()[0].get_type(),
+        &ConditionalFormatValueObjectValues::AutoMin
+    );
+    assert!(!data_bar.gradient());
+    assert!(data_bar.border());
+    assert_eq!(data_bar.border_color().unwrap().argb_str(), "FF1F4E79");
+    assert_eq!(data_bar.negative_fill_color().unwrap().argb_str(), "FFFF0000");
+    assert_eq!(data_bar.axis_color().unwrap().argb_str(), "FF000000");
+    assert_eq!(data_bar.axis_position(), &DataBarAxisPositionValues::Middle);
+    assert_eq!(data_bar.direction(), &DataBarDirectionValues::RightToLeft);
+    assert_eq!(data_bar.color_collection()[0].argb_str(), "FF638EC6");
+
+    let rule = &collection[1].conditional_collection()[0];
+    assert_eq!(collection[1].sequence_of_references().get_sqref(), "B1:B10");
+    assert_eq!(rule.get_type(), &ConditionalFormatValues::IconSet);
+    let icon_set = rule.icon_set().unwrap();
+    assert_eq!(icon_set.icon_set(), &IconSetValues::ThreeStars);
+    assert!(icon_set.reverse());
+    assert!(!icon_set.show_value());
+    assert_eq!(icon_set.cfvo_collection().len(), 3);
+    assert_eq!(icon_set.cfvo_collection()[2].val(), "67");
+    assert_eq!(icon_set.custom_icons().len(), 3);
+    assert_eq!(icon_set.custom_icons()[2].icon_set(), &IconSetValues::ThreeFlags);
+    assert_eq!(icon_set.custom_icons()[2].icon_id(), 2);
+}
+
+#[test]
+fn conditional_format_evaluation() {
+    let rule_with_formulas = |priority: i32, operator, formulas: &[&str], fill: &str| {
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::CellIs)
+            .set_operator(operator)
+            .set_priority(priority);
+        let mut formula = Formula::default();
+        formula.set_string_value(formulas[0]);
+        rule.set_formula(formula);
+        if let Some(v) = formulas.get(1) {
+            let mut formula = Formula::default();
+            formula.set_string_value(*v);
+            rule.set_formula2(formula);
+        }
+        let mut style = Style::default();
+        style.fill_mut().pattern_fill_mut().background_color_mut().set_argb_str(fill);
+        style.font_mut().set_bold(true);
+        rule.set_style(style);
+        rule
+    };
+    let cfvo = |value_type: ConditionalFormatValueObjectValues, val: &str| {
+        let mut obj = ConditionalFormatValueObject::default();
+        obj.set_type(value_type);
+        if !val.is_empty() {
+            obj.set_val(val);
+        }
+        obj
+    };
+    let color = |argb: &str| {
+        let mut obj = Color::default();
+        obj.set_argb_str(argb);
+        obj
+    };
+    let conditional_formatting = |sqref: &str, rules: Vec<ConditionalFormattingRule>| {
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref(sqref);
+        let mut obj = ConditionalFormatting::default();
+        obj.set_sequence_of_references(seq);
+        obj.set_conditional_collection(rules);
+        obj
+    };
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    for row in 1..=5 {
+        sheet.cell_mut((1, row)).set_value_number(f64::from(row) * 10.0);
+        sheet.cell_mut((2, row)).set_value_number(f64::from(row) * 10.0);
+        sheet.cell_mut((3, row)).set_value_number(f64::from(row));
+    }
+    sheet.cell_mut("D1").set_value("apple");
+    sheet.cell_mut("D2").set_value("pear");
+    sheet.cell_mut("D3").set_value("Apple");
+    sheet.cell_mut("E1").set_value_number(25);
+
+    let mut stop = rule_with_formulas(
+        1,
+        ConditionalFormattingOperatorValues::GreaterThan,
+        &["$E$1"],
+        "FFFF0000",
+    );
+    stop.set_stop_if_true(true);
+    let between = rule_with_formulas(
+        2,
+        ConditionalFormattingOperatorValues::Between,
+        &["15", "45"],
+        "FF00FF00",
+    );
+
+    let mut color_scale = ColorScale::default();
+    color_scale
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Min, ""))
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Max, ""))
+        .add_color_collection(color("FF000000"))
+        .add_color_collection(color("FFFFFFFF"));
+    let mut color_scale_rule = ConditionalFormattingRule::default();
+    color_scale_rule
+        .set_type(ConditionalFormatValues::ColorScale)
+        .set_priority(3)
+        .set_color_scale(color_scale);
+
+    let mut data_bar = DataBar::default();
+    data_bar
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Min, ""))
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Max, ""))
+        .add_color_collection(color("FF638EC6"))
+        .set_min_length(0)
+        .set_max_length(100);
+    let mut data_bar_rule = ConditionalFormattingRule::default();
+    data_bar_rule
+        .set_type(ConditionalFormatValues::DataBar)
+        .set_priority(4)
+        .set_data_bar(data_bar);
+
+    let mut icon_set = IconSet::default();
+    icon_set
+        .set_icon_set(IconSetValues::ThreeArrows)
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Percent, "0"))
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Percent, "33"))
+        .add_cfvo_collection(cfvo(ConditionalFormatValueObjectValues::Percent, "67"));
+    let mut icon_set_rule = ConditionalFormattingRule::default();
+    icon_set_rule
+        .set_type(ConditionalFormatValues::IconSet)
+        .set_priority(5)
+        .set_icon_set(icon_set);
+
+    let mut duplicate_rule = ConditionalFormattingRule::default();
+    duplicate_rule
+        .set_type(ConditionalFormatValues::DuplicateValues)
+        .set_priority(6);
+    let mut top_rule = ConditionalFormattingRule::default();
+    top_rule
+        .set_type(ConditionalFormatValues::Top10)
+        .set_priority(7)
+        .set_rank(2);
+
+    sheet.set_conditional_formatting_collection(vec![
+        conditional_formatting("A1:A5", vec![between, stop]),
+        conditional_formatting("B1:B5", vec![color_scale_rule]),
+        conditional_formatting("C1:C5", vec![data_bar_rule, icon_set_rule, top_rule]),
+        conditional_formatting("D1:D5", vec![duplicate_rule]),
+    ]);
+
+    // cellIs with stopIfTrue
+    let matches = sheet.conditional_format_matches("A1:A5");
+    let priorities = |coordinate: &str| {
+        matches
+            .iter()
+            .filter(|v| v.coordinate() == coordinate)
+            .map(ConditionalFormatMatch::priority)
+            .collect::<Vec<_>>()
+    };
+    assert!(priorities("A1").is_empty());
+    assert_eq!(priorities("A2"), vec![2]);
+    assert_eq!(priorities("A3"), vec![1]);
+    assert_eq!(priorities("A5"), vec![1]);
+    let style = sheet.effective_style("A2");
+    assert_eq!(style.background_color().unwrap().argb_str(), "FF00FF00");
+    assert!(style.font().unwrap().bold());
+    assert_eq!(
+        sheet.effective_style("A4").background_color().unwrap().argb_str(),
+        "FFFF0000"
+    );
+
+    // color scale
+    assert_eq!(
+        sheet.effective_style("B1").background_color().unwrap().argb_str(),
+        "FF000000"
+    );
+    assert_eq!(
+        sheet.effective_style("B3").background_color().unwrap().argb_str(),
+        "FF808080"
+    );
+
+    // data bar, icon set and top 10
+    let matches = sheet.conditional_format_matches("C1:C5");
+    let data_bar = |coordinate: &str| {
+        matches
+            .iter()
+            .find(|v| v.coordinate() == coordinate && v.data_bar_percent().is_some())
+            .and_then(ConditionalFormatMatch::data_bar_percent)
+    };
+    assert_eq!(data_bar("C1"), Some(0.0));
+    assert_eq!(data_bar("C3"), Some(50.0));
+    assert_eq!(data_bar("C5"), Some(100.0));
+    let icon_index = |coordinate: &str| {
+        matches
+            .iter()
+            .find(|v| v.coordinate() == coordinate && v.icon().is_some())
+            .and_then(ConditionalFormatMatch::icon_index)
+    };
+    assert_eq!(icon_index("C1"), Some(0));
+    assert_eq!(icon_index("C3"), Some(1));
+    assert_eq!(icon_index("C5"), Some(2));
+    let top = matches
+        .iter()
+        .filter(|v| v.rule_type() == &ConditionalFormatValues::Top10)
+        .map(ConditionalFormatMatch::coordinate)
+        .collect::<Vec<_>>();
+    assert_eq!(top, vec!["C4", "C5"]);
+
+    // duplicate values ignore case
+    let duplicates = sheet
+        .conditional_format_matches("D1:D5")
+        .into_iter()
+        .map(|v| v.coordinate().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(duplicates, vec!["D1", "D3"]);
+
+    // The second formula of `between` survives a round trip.
+    let path = std::path::Path::new("./tests/result_files/conditional_format_evaluation.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    let rule = &sheet.conditional_formatting_collection()[0].conditional_collection()[0];
+    assert_eq!(rule.formula().unwrap().address_str(), "15");
+    assert_eq!(rule.formula2().unwrap().address_str(), "45");
+    assert_eq!(
+        sheet.effective_style("A2").background_color().unwrap().argb_str(),
+        "FF00FF00"
+    );
+}
+
+#[test]
+fn conditional_format_whole_columns_and_rows() {
+    let rule = |priority: i32, value: &str| {
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::CellIs)
+            .set_operator(ConditionalFormattingOperatorValues::GreaterThan)
+            .set_priority(priority);
+        let mut formula = Formula::default();
+        formula.set_string_value(value);
+        rule.set_formula(formula);
+        let mut style = Style::default();
+        style.set_background_color_solid("FFFF0000");
+        rule.set_style(style);
+        rule
+    };
+    let conditional_formatting = |sqref: &str, rule: ConditionalFormattingRule| {
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref(sqref);
+        let mut obj = ConditionalFormatting::default();
+        obj.set_sequence_of_references(seq);
+        obj.add_conditional_collection(rule);
+        obj
+    };
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    for row in 1..=5 {
+        sheet.cell_mut((1, row)).set_value_number(f64::from(row) * 10.0);
+        sheet.cell_mut((3, row)).set_value_number(f64::from(row));
+    }
+    sheet.set_conditional_formatting_collection(vec![
+        conditional_formatting("A:A", rule(1, "25")),
+        conditional_formatting("1:3", rule(2, "2")),
+    ]);
+
+    let coordinates = |range: &str, priority: i32| {
+        sheet
+            .conditional_format_matches(range)
+            .iter()
+            .filter(|v| v.priority() == priority)
+            .map(|v| v.coordinate().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(coordinates("A:A", 1), vec!["A3", "A4", "A5"]);
+    assert_eq!(coordinates("1:3", 2), vec!["A1", "A2", "A3", "C3"]);
+    assert_eq!(coordinates("A4:C4", 2), Vec::<String>::new());
+
+    assert_eq!(
+        sheet.effective_style("A5").background_color().unwrap().argb_str(),
+        "FFFF0000"
+    );
+    assert_eq!(
+        sheet.effective_style("C3").background_color().unwrap().argb_str(),
+        "FFFF0000"
+    );
+    assert!(sheet.effective_style("C4").background_color().is_none());
+    assert!(sheet.effective_style("A1048576").background_color().is_none());
+
+    sheet.add_conditional_format("C:C", Rule::contains_text("4"));
+    sheet.add_conditional_format("2:2", Rule::formula("=A2>10"));
+    let rules = sheet
+        .conditional_formatting_collection()
+        .iter()
+        .flat_map(ConditionalFormatting::conditional_collection)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rules[2].formula().unwrap().address_str(),
+        "NOT(ISERROR(SEARCH(\"4\",C1)))"
+    );
+    assert_eq!(rules[3].formula().unwrap().address_str(), "A2>10");
+    let priorities = sheet
+        .conditional_format_matches("C4")
+        .iter()
+        .map(ConditionalFormatMatch::priority)
+        .collect::<Vec<_>>();
+    assert_eq!(priorities, vec![3]);
+}
+
+#[test]
+fn add_conditional_format_builders() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    for row in 1..=5 {
+        sheet.cell_mut((1, row)).set_value_number(f64::from(row) * 10.0);
+        sheet.cell_mut((2, row)).set_value_number(f64::from(row));
+    }
+    sheet.cell_mut("C1").set_value("apple");
+    sheet.cell_mut("C2").set_value("pear");
+
+    let mut style = Style::default();
+    style.set_background_color_solid("FFFF0000");
+    sheet.add_conditional_format(
+        "A1:A5",
+        Rule::cell_is(ConditionalFormattingOperatorValues::GreaterThan, "25").style(style),
+    );
+    sheet.add_conditional_format("C1:C5", Rule::contains_text("App"));
+    sheet.add_conditional_format("A1:A5", Rule::top_n(1));
+    sheet.add_conditional_format("B1:B5", Rule::duplicates());
+    sheet.add_conditional_format("A1:C5", Rule::formula("=MOD(ROW(),2)=0"));
+
+    let rules = sheet
+        .conditional_formatting_collection()
+        .iter()
+        .flat_map(ConditionalFormatting::conditional_collection)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rules.iter().map(|v| v.priority()).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        rules[1].formula().unwrap().address_str(),
+        "NOT(ISERROR(SEARCH(\"App\",C1)))"
+    );
+    assert_eq!(rules[4].formula().unwrap().address_str(), "MOD(ROW(),2)=0");
+    assert_eq!(
+        sheet.effective_style("A3").background_color().unwrap().argb_str(),
+        "FFFF0000"
+    );
+    assert!(sheet.effective_style("A2").background_color().is_none());
+
+    sheet.insert_new_row(1, 2);
+    sheet.remove_column("B", 1);
+
+    let sqrefs = sheet
+        .conditional_formatting_collection()
+        .iter()
+        .map(|v| v.sequence_of_references().get_sqref())
+        .collect::<Vec<_>>();
+    assert_eq!(sqrefs, vec!["A3:A7", "B3:B7", "A3:A7", "A3:B7"]);
+    let contains = &sheet.conditional_formatting_collection()[1].conditional_collection()[0];
+    assert_eq!(
+        contains.formula().unwrap().address_str(),
+        "NOT(ISERROR(SEARCH(\"App\",B3)))"
+    );
+    let priorities = sheet
+        .conditional_format_matches("B3")
+        .iter()
+        .map(ConditionalFormatMatch::priority)
+        .collect::<Vec<_>>();
+    assert_eq!(priorities, vec![2]);
+    assert_eq!(
+        sheet.effective_style("A5").background_color().unwrap().argb_str(),
+        "FFFF0000"
+    );
+}
+
+#[test]
+fn conditional_format_expressions() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    for row in 1..=4 {
+        sheet.cell_mut((1, row)).set_value_number(f64::from(row) * 10.0);
+    }
+    sheet.cell_mut("B1").set_value("x");
+    sheet.cell_mut("B3").set_value("y");
+
+    let mut style = Style::default();
+    style.set_background_color_solid("FF00FF00");
+    sheet.add_conditional_format("A1:A4", Rule::formula("=MOD(ROW(),2)=0").style(style));
+    sheet.add_conditional_format("A1:A4", Rule::formula("=AND($A1>=20,ISBLANK($B1))"));
+    sheet.add_conditional_format("A1:A4", Rule::formula("=(-$A1+5)*2^2<-90"));
+    sheet.add_conditional_format(
+        "A1:A4",
+        Rule::formula("=VLOOKUP($A1,$C$1:$D$4,2,FALSE)>0").stop_if_true(true),
+    );
+
+    let matches = sheet.conditional_format_matches("A1:A4");
+    let priorities = |coordinate: &str| {
+        matches
+            .iter()
+            .filter(|v| v.coordinate() == coordinate && !v.is_unsupported())
+            .map(ConditionalFormatMatch::priority)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(priorities("A1"), Vec::<i32>::new());
+    assert_eq!(priorities("A2"), vec![1, 2]);
+    assert_eq!(priorities("A3"), vec![3]);
+    assert_eq!(priorities("A4"), vec![1, 2, 3]);
+    assert_eq!(
+        sheet.effective_style("A2").background_color().unwrap().argb_str(),
+        "FF00FF00"
+    );
+    assert!(sheet.effective_style("A3").background_color().is_none());
+
+    // A function the evaluator does not know is reported, not treated as
+    // false, and does not stop the evaluation.
+    let unsupported = matches
+        .iter()
+        .filter(|v| v.is_unsupported())
+        .collect::<Vec<_>>();
+    assert_eq!(unsupported.len(), 4);
+    assert!(unsupported.iter().all(|v| v.priority() == 4 && v.style().is_none()));
+
+    // Custom icons follow a reversed icon order.
+    let mut icon_set = IconSet::default();
+    icon_set
+        .set_icon_set(IconSetValues::ThreeArrows)
+        .set_reverse(true)
+        .add_custom_icon(ConditionalFormattingIcon::new(IconSetValues::ThreeFlags, 0))
+        .add_custom_icon(ConditionalFormattingIcon::new(IconSetValues::ThreeFlags, 1))
+        .add_custom_icon(ConditionalFormattingIcon::new(IconSetValues::ThreeFlags, 2));
+    for threshold in ["0", "33", "67"] {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(ConditionalFormatValueObjectValues::Percent)
+            .set_val(threshold);
+        icon_set.add_cfvo_collection(cfvo);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::IconSet)
+        .set_priority(1)
+        .set_icon_set(icon_set);
+    let mut sequence = SequenceOfReferences::default();
+    sequence.set_sqref("A1:A4");
+    let mut conditional_formatting = ConditionalFormatting::default();
+    conditional_formatting
+        .set_sequence_of_references(sequence)
+        .add_conditional_collection(rule);
+    sheet.set_conditional_formatting_collection(vec![conditional_formatting]);
+
+    let icon = |coordinate: &str| {
+        let matches = sheet.conditional_format_matches(coordinate);
+        let icon_match = &matches[0];
+        (icon_match.icon().unwrap().icon_id(), icon_match.icon_index())
+    };
+    assert_eq!(icon("A1"), (2, Some(2)));
+    assert_eq!(icon("A4"), (0, Some(0)));
+}
+
+#[test]
+fn data_validation_evaluation() {
+    let mut book = new_file();
+    let lookups = book.new_sheet("Lookups").unwrap();
+    lookups.cell_mut("A1").set_value("Apple");
+    lookups.cell_mut("A2").set_value("Banana");
+    lookups.cell_mut("A3").set_value("Cherry");
+    lookups
+        .add_defined_name("Fruits", "Lookups!$A$1:$A$3")
+        .unwrap();
+
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value_number(5);
+    sheet.cell_mut("A2").set_value_number(2.5);
+    sheet.cell_mut("A3").set_value_number(20);
+    sheet.cell_mut("B1").set_value("banana");
+    sheet.cell_mut("B2").set_value("Kiwi");
+    sheet.cell_mut("C1").set_value("Yes");
+    sheet.cell_mut("C2").set_value("Maybe");
+    sheet.cell_mut("D1").set_value("abc");
+    sheet.cell_mut("D2").set_value("abcdef");
+    sheet.cell_mut("E1").set_value_number(45322); // 2024-01-31
+    sheet.cell_mut("E2").set_value_number(45352); // 2024-03-01
+    sheet.cell_mut("F1").set_value_number(3);
+    sheet.cell_mut("G1").set_value_number(4);
+    sheet.cell_mut("F2").set_value_number(5);
+    sheet.cell_mut("G2").set_value_number(1);
+    sheet.cell_mut("H1").set_value("Cherry");
+
+    let mut whole = DataValidation::whole_number(DataValidationOperatorValues::Between, "1");
+    whole.set_formula2("10").set_error_message("1 to 10");
+    sheet.add_data_validation("A1:A3", whole);
+    sheet.add_data_validation("B1:B2", DataValidation::list_from_range("Lookups!A1:A3"));
+    sheet.add_data_validation("C1:C2", DataValidation::list(["Yes", "No"]));
+    sheet.add_data_validation(
+        "D1:D2",
+        DataValidation::text_length(DataValidationOperatorValues::LessThanOrEqual, "5"),
+    );
+    sheet.add_data_validation(
+        "E1:E2",
+        DataValidation::date(DataValidationOperatorValues::LessThan, "DATE(2024,2,1)"),
+    );
+    sheet.add_data_validation("G1:G2", DataValidation::custom("=G1>F1"));
+    sheet.add_data_validation("H1", DataValidation::list_from_name("Fruits"));
+
+    assert_eq!(
+        sheet.data_validations().unwrap().data_validation_list()[1].formula1(),
+        "Lookups!$A$1:$A$3"
+    );
+    assert_eq!(
+        sheet.data_validations().unwrap().data_validation_list()[2].formula1(),
+        "\"Yes,No\""
+    );
+
+    let result = sheet.validate_cell("A1");
+    assert!(result.is_valid());
+    assert_eq!(result.validation_type(), Some(&DataValidationValues::Whole));
+    let result = sheet.validate_cell("A2");
+    assert!(!result.is_valid());
+    assert_eq!(result.error_message(), "1 to 10");
+    assert!(!sheet.validate_cell("A3").is_valid());
+    assert!(sheet.validate_cell("A4").is_valid());
+    assert!(sheet.validate_cell("Z1").validation_type().is_none());
+    assert!(sheet.validate_cell("C1").is_valid());
+    assert!(!sheet.validate_cell("C2").is_valid());
+    assert!(sheet.validate_cell("D1").is_valid());
+    assert!(!sheet.validate_cell("D2").is_valid());
+    assert!(sheet.validate_cell("E1").is_valid());
+    assert!(!sheet.validate_cell("E2").is_valid());
+    assert!(sheet.validate_cell("G1").is_valid());
+    assert!(!sheet.validate_cell("G2").is_valid());
+
+    // Sources on another worksheet need the workbook.
+    let result = sheet.validate_cell("B1");
+    assert!(result.is_valid());
+    assert!(!result.is_evaluated());
+
+    let invalid = |results: Vec<ValidationResult>| {
+        results
+            .iter()
+            .map(|v| format!("{}!{}", v.sheet_name(), v.coordinate()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        invalid(sheet.validate_all()),
+        vec![
+            "Sheet1!A2",
+            "Sheet1!C2",
+            "Sheet1!D2",
+            "Sheet1!E2",
+            "Sheet1!G2",
+            "Sheet1!A3",
+        ]
+    );
+    assert_eq!(
+        invalid(book.validate_all()),
+        vec![
+            "Sheet1!A2",
+            "Sheet1!B2",
+            "Sheet1!C2",
+            "Sheet1!D2",
+            "Sheet1!E2",
+            "Sheet1!G2",
+            "Sheet1!A3",
+        ]
+    );
+
+    book.sheet_mut(0).unwrap().cell_mut("H1").set_value("Grape");
+    assert_eq!(invalid(book.validate_all())[0], "Sheet1!H1");
+
+    // Bounds and custom rules share the evaluator of conditional formatting.
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("I1").set_value_number(0.25); // 06:00
+    sheet.cell_mut("I2").set_value_number(0.75); // 18:00
+    sheet.add_data_validation(
+        "I1:I2",
+        DataValidation::time(DataValidationOperatorValues::LessThan, "TIME(36,0,0)"),
+    );
+    sheet.cell_mut("J1").set_value_number(0.5);
+    sheet.add_data_validation(
+        "J1",
+        DataValidation::time(DataValidationOperatorValues::LessThan, "TIME(4294967295,0,0)"),
+    );
+    sheet.cell_mut("K1").set_value_number(45322);
+    sheet.add_data_validation(
+        "K1",
+        DataValidation::date(DataValidationOperatorValues::Equal, "DATE(2023,13,31)"),
+    );
+    sheet.cell_mut("L1").set_value("ab");
+    sheet.cell_mut("L2").set_value("abc");
+    sheet.add_data_validation(
+        "L1:L2",
+        DataValidation::custom("=AND(LEN(L1)<3,NOT(ISNUMBER(L1)))"),
+    );
+    assert!(sheet.validate_cell("I1").is_valid());
+    assert!(!sheet.validate_cell("I2").is_valid());
+    assert!(sheet.validate_cell("J1").is_valid());
+    assert!(!sheet.validate_cell("J1").is_evaluated());
+    assert!(sheet.validate_cell("K1").is_valid());
+    assert!(sheet.validate_cell("L1").is_valid());
+    assert!(!sheet.validate_cell("L2").is_valid());
+}
+
+#[test]
+fn data_validation_whole_columns_and_rows() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value_number(5);
+    sheet.cell_mut("A2").set_value_number(50);
+    sheet.cell_mut("B3").set_value("abcdef");
+    sheet.cell_mut("C3").set_value("abc");
+    sheet.add_data_validation(
+        "A:A",
+        DataValidation::whole_number(DataValidationOperatorValues::LessThan, "10"),
+    );
+    sheet.add_data_validation(
+        "3:3",
+        DataValidation::text_length(DataValidationOperatorValues::LessThanOrEqual, "5"),
+    );
+
+    assert!(sheet.validate_cell("A1").is_valid());
+    assert!(!sheet.validate_cell("A2").is_valid());
+    assert!(!sheet.validate_cell("B3").is_valid());
+    assert!(sheet.validate_cell("C3").is_valid());
+    assert!(sheet.validate_cell("A1048576").validation_type().is_some());
+    assert!(sheet.validate_cell("B4").validation_type().is_none());
+    let invalid = sheet
+        .validate_all()
+        .iter()
+        .map(|v| v.coordinate().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(invalid, vec!["A2", "B3"]);
+}
+
+#[test]
+fn table_styles_and_named_styles() {
+    let mut book = new_file();
+
+    let mut header = Style::default();
+    header.font_mut().set_bold(true);
+    header.set_background_color("FF1F4E79");
+    let mut stripe = Style::default();
+    stripe.set_background_color("FFDDEBF7");
+
+    let mut table_style = TableStyle::default();
+    table_style.set_name("Brand Table").set_pivot(false);
+    let mut element = TableStyleElement::default();
+    element
+        .set_type(TableStyleElementValues::HeaderRow)
+        .set_style(header);
+    table_style.add_element(element);
+    let mut element = TableStyleElement::default();
+    element
+        .set_type(TableStyleElementValues::FirstRowStripe)
+        .set_size(2)
+        .set_style(stripe);
+    table_style.add_element(element);
+    book.add_table_style(table_style);
+
+    let mut heading = Style::default();
+    heading.font_mut().set_bold(true).set_size(14.0);
+    heading.set_background_color("FFFFC000");
+    book.add_named_style("Brand Heading", &heading);
+    book.add_named_style("Good", &Style::default());
+
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Title").set_named_style("Brand Heading");
+    sheet.cell_mut("A2").set_value("Plain");
+
+    let path = std::path::Path::new("./tests/result_files/table_and_named_styles.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+
+    assert_eq!(book.table_styles().len(), 1);
+    let table_style = &book.table_styles()[0];
+    assert_eq!(table_style.name(), "Brand Table");
+    assert!(!table_style.pivot());
+    assert!(table_style.table());
+    let element = table_style
+        .element(&TableStyleElementValues::HeaderRow)
+        .unwrap();
+    assert!(element.style().font().unwrap().bold());
+    assert_eq!(
+        element.style().background_color().unwrap().argb_str(),
+        "FF1F4E79"
+    );
+    let element = table_style
+        .element(&TableStyleElementValues::FirstRowStripe)
+        .unwrap();
+    assert_eq!(element.size(), 2);
+    assert_eq!(
+        element.style().background_color().unwrap().argb_str(),
+        "FFDDEBF7"
+    );
+
+    let heading = book.named_style("brand heading").unwrap();
+    assert!(heading.font().unwrap().bold());
+    assert!(book.named_style("Good").is_some());
+    assert!(book.named_style("Missing").is_none());
+
+    let sheet = book.sheet(0).unwrap();
+    let style = sheet.cell("A1").unwrap().style();
+    assert_eq!(style.named_style(), Some("Brand Heading"));
+    assert!(style.font().unwrap().bold());
+    assert_eq!(style.background_color().unwrap().argb_str(), "FFFFC000");
+    assert_eq!(sheet.cell("A2").unwrap().style().named_style(), None);
+}
+
+#[test]
+fn table_autofilter_totals_and_structured_references() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Qty");
+    sheet.cell_mut("B1").set_value("Price");
+    sheet.cell_mut("C1").set_value("Amount");
+    sheet.cell_mut("A2").set_value_number(2);
+    sheet.cell_mut("B2").set_value_number(5);
+    sheet.cell_mut("C2").set_formula("[@Qty]*[@Price]");
+    sheet.cell_mut("E1").set_formula("SUM(Orders[Qty])");
+
+    let mut table = Table::new("Orders", ("A1", "C2"));
+    table.add_column(TableColumn::new("Qty"));
+    table.add_column(TableColumn::new("Price"));
+    let mut column = TableColumn::new("Amount");
+    column.set_calculated_column_formula("[@Qty]*[@Price]".to_string());
+    table.add_column(column);
+    let mut header = Style::default();
+    header.font_mut().set_bold(true);
+    table.set_header_row_style(header);
+    let mut custom_filters = CustomFilters::default();
+    custom_filters.add_custom_filter(CustomFilter::new(
+        FilterOperatorValues::GreaterThan,
+        "1",
+    ));
+    let mut filter_column = FilterColumn::new(0);
+    filter_column.set_custom_filters(custom_filters);
+    table.auto_filter_mut().add_filter_column(filter_column);
+    sheet.add_table(table);
+
+    assert_eq!(sheet.append_table_row("Orders", ["3", "4"]).unwrap(), 3);
+    assert_eq!(sheet.value("A3"), "3");
+    assert_eq!(sheet.cell("C3").unwrap().formula(), "[@Qty]*[@Price]");
+
+    sheet.set_table_totals_row("Orders", true).unwrap();
+    assert_eq!(sheet.value("A4"), "Total");
+    assert_eq!(
+        sheet.cell("C4").unwrap().formula(),
+        "SUBTOTAL(109,Orders[Amount])"
+    );
+    assert_eq!(sheet.append_table_row("Orders", ["1", "1"]).unwrap(), 4);
+    assert_eq!(sheet.value("A5"), "Total");
+    assert_eq!(
+        sheet.table("orders").unwrap().area().1.to_string(),
+        "C5"
+    );
+
+    let path = std::path::Path::new("./tests/result_files/table_model.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let mut book = reader::xlsx::read(path).unwrap();
+
+    let sheet = book.sheet_mut(0).unwrap();
+    let table = sheet.table("Orders").unwrap();
+    assert!(table.has_totals_row());
+    assert!(table.header_row_style().unwrap().font().unwrap().bold());
+    assert_eq!(
+        table.columns()[2].calculated_column_formula().unwrap(),
+        "[@Qty]*[@Price]"
+    );
+    let filter_column = &table.auto_filter().filter_column()[0];
+    let custom_filter = &filter_column.custom_filters().unwrap().custom_filter()[0];
+    assert_eq!(custom_filter.operator(), &FilterOperatorValues::GreaterThan);
+    assert_eq!(custom_filter.val(), "1");
+
+    sheet.insert_new_row(1, 1);
+    let table = sheet.table("Orders").unwrap();
+    assert_eq!(table.area().0.to_string(), "A2");
+    assert_eq!(table.area().1.to_string(), "C6");
+
+    book.rename_table("orders", "Sales").unwrap();
+    book.rename_table_column("Sales", "Qty", "Quantity").unwrap();
+    assert!(book.rename_table("Missing", "Other").is_err());
+    assert!(book.rename_table_column("Sales", "Price", "Amount").is_err());
+
+    let sheet = book.sheet(0).unwrap();
+    let table = sheet.table("Sales").unwrap();
+    assert_eq!(table.columns()[0].name(), "Quantity");
+    assert_eq!(
+        table.columns()[2].calculated_column_formula().unwrap(),
+        "[@Quantity]*[@Price]"
+    );
+    assert_eq!(sheet.value("A2"), "Quantity");
+    assert_eq!(sheet.cell("C3").unwrap().formula(), "[@Quantity]*[@Price]");
+    assert_eq!(sheet.cell("E2").unwrap().formula(), "SUM(Sales[Quantity])");
+    assert_eq!(
+        sheet.cell("C6").unwrap().formula(),
+        "SUBTOTAL(109,Sales[Amount])"
+    );
+}
+
+#[test]
+fn table_rows_move_cells_below_the_table_down() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Item");
+    sheet.cell_mut("B1").set_value("Qty");
+    sheet.cell_mut("A2").set_value("Apple");
+    sheet.cell_mut("B2").set_value_number(3);
+    sheet.cell_mut("A3").set_value("Note");
+    sheet.cell_mut("B4").set_value("Footer");
+    let mut table = Table::new("Orders", ("A1", "B2"));
+    table.add_column(TableColumn::new("Item"));
+    table.add_column(TableColumn::new("Qty"));
+    sheet.add_table(table);
+
+    assert_eq!(sheet.append_table_row("Orders", ["Pear", "5"]).unwrap(), 3);
+    assert_eq!(sheet.value("A3"), "Pear");
+    assert_eq!(sheet.value("A4"), "Note");
+    assert_eq!(sheet.value("B5"), "Footer");
+
+    sheet.set_table_totals_row("Orders", true).unwrap();
+    assert_eq!(sheet.value("A4"), "Total");
+    assert_eq!(sheet.value("A5"), "Note");
+    assert_eq!(sheet.value("B6"), "Footer");
+    assert_eq!(sheet.table("Orders").unwrap().area().1.to_string(), "B4");
+
+    // Nothing moves when the row below the table is empty.
+    sheet.set_table_totals_row("Orders", false).unwrap();
+    assert_eq!(sheet.append_table_row("Orders", ["Plum", "1"]).unwrap(), 4);
+    assert_eq!(sheet.value("A5"), "Note");
+    assert_eq!(sheet.value("B6"), "Footer");
+}
+
+#[test]
+fn defined_names_and_sheet_rename_propagation() {
+    let mut book = new_file();
+    book.new_sheet("Data").unwrap();
+
+    book.names_mut()
+        .add("Items", "Data!$A$1:$A$3", None)
+        .unwrap();
+    book.names_mut().add("Rate", "0.08", None).unwrap();
+    book.names_mut().add("Rate", "0.1", Some("Sheet1")).unwrap();
+    book.names_mut()
+        .add("Total", "SUM(Data!$B$1:$B$3)", None)
+        .unwrap();
+    assert!(matches!(
+        book.names_mut().add("A1", "0", None),
+        Err(XlsxError::InvalidName())
+    ));
+    assert!(matches!(
+        book.names_mut().add("rate", "0", None),
+        Err(XlsxError::NameDuplicate())
+    ));
+
+    assert!(matches!(
+        book.names().resolve("items", None),
+        Some(DefinedNameValue::Range(v)) if v[0].sheet_name() == "Data"
+    ));
+    assert!(matches!(
+        book.names().resolve("Rate", Some("Sheet1")),
+        Some(DefinedNameValue::Constant(v)) if &*v == "0.1"
+    ));
+    assert!(matches!(
+        book.names().resolve("Rate", Some("Data")),
+        Some(DefinedNameValue::Constant(v)) if &*v == "0.08"
+    ));
+    assert!(matches!(
+        book.names().resolve("Total", None),
+        Some(DefinedNameValue::Formula(_))
+    ));
+
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_formula("SUM(Data!A1:A3)*Rate");
+    sheet.cell_mut("A2").set_formula("Sheet1!A1+1");
+    sheet.add_data_validation("C1:C5", DataValidation::list_from_range("Data!A1:A3"));
+    sheet.add_conditional_format("D1:D5", Rule::formula("Data!$A$1>0"));
+    let mut from_marker = drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("F1");
+    to_marker.set_coordinate("J10");
+    let mut chart = Chart::default();
+    chart.new_chart(
+        &ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Data!$A$1:$A$3"],
+    );
+    sheet.add_chart(chart);
+
+    book.set_sheet_name(1, "Sales Data").unwrap();
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(
+        sheet.cell("A1").unwrap().formula(),
+        "SUM('Sales Data'!A1:A3)*Rate"
+    );
+    assert_eq!(sheet.cell("A2").unwrap().formula(), "Sheet1!A1+1");
+    let data_validation = &sheet.data_validations().unwrap().data_validation_list()[0];
+    assert_eq!(data_validation.formula1(), "'Sales Data'!$A$1:$A$3");
+    let rule = &sheet.conditional_formatting_collection()[0].conditional_collection()[0];
+    assert_eq!(rule.formula().unwrap().address_str(), "'Sales Data'!$A$1>0");
+    let mut chart = sheet.worksheet_drawing().chart_collection()[0].clone();
+    assert_eq!(
+        chart.plot_area_mut().formula_mut()[0].address().sheet_name(),
+        "Sales Data"
+    );
+    assert_eq!(
+        book.names().get("Items", None).unwrap().address(),
+        "'Sales Data'!$A$1:$A$3"
+    );
+    assert_eq!(
+        book.names().get("Total", None).unwrap().address(),
+        "SUM('Sales Data'!$B$1:$B$3)"
+    );
+
+    let path = std::path::Path::new("./tests/result_files/defined_names.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let mut book = reader::xlsx::read(path).unwrap();
+    assert_eq!(
+        book.names().get("Rate", Some("Sheet1")).unwrap().address(),
+        "0.1"
+    );
+
+    book.remove_sheet_by_name("Sales Data").unwrap();
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.cell("A1").unwrap().formula(), "SUM(#REF!A1:A3)*Rate");
+    let data_validation = &sheet.data_validations().unwrap().data_validation_list()[0];
+    assert_eq!(data_validation.formula1(), "#REF!$A$1:$A$3");
+    assert_eq!(
+        book.names().get("Items", None).unwrap().address(),
+        "#REF!$A$1:$A$3"
+    );
+    assert_eq!(
+        book.names()
+            .get("Rate", Some("Sheet1"))
+            .unwrap()
+            .local_sheet_id(),
+        0
+    );
+    assert!(book.names_mut().remove("Rate", Some("Sheet1")).is_ok());
+    assert_eq!(
+        book.names().get("Rate", Some("Sheet1")).unwrap().address(),
+        "0.08"
+    );
+    assert_eq!(book.names().iter().count(), 3);
+
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.add_defined_name("Local", "$A$1").unwrap();
+    assert!(matches!(
+        sheet.add_defined_name("local", "$A$2"),
+        Err(XlsxError::NameDuplicate())
+    ));
+    assert!(matches!(
+        sheet.add_defined_name("R1C1", "$A$2"),
+        Err(XlsxError::InvalidName())
+    ));
+}
+
+#[test]
+fn remove_sheet_keeps_unrelated_lazy_sheets() {
+    let mut book = new_file();
+    book.new_sheet("Data").unwrap();
+    book.new_sheet("Other").unwrap();
+    book.sheet_mut(0)
+        .unwrap()
+        .cell_mut("A1")
+        .set_formula("SUM(Data!A1:A3)");
+    book.sheet_mut(2).unwrap().cell_mut("A1").set_value("Data");
+    book.sheet_mut(2)
+        .unwrap()
+        .cell_mut("A2")
+        .set_value_number(1);
+    let path = std::path::Path::new("./tests/result_files/remove_sheet_lazy.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+
+    let mut book = reader::xlsx::lazy_read(path).unwrap();
+    book.remove_sheet_by_name("Data").unwrap();
+    assert_eq!(
+        book.sheet(0).unwrap().cell("A1").unwrap().formula(),
+        "SUM(#REF!A1:A3)"
+    );
+    // "Data" is in the sheet as a shared string and in "sheetData"
+    assert!(matches!(book.sheet(1), Err(XlsxError::NotDeserialized())));
+
+    book.set_sheet_name(1, "Renamed").unwrap();
+    assert!(matches!(book.sheet(1), Err(XlsxError::NotDeserialized())));
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    assert_eq!(book.sheet(1).unwrap().name(), "Renamed");
+    assert_eq!(book.sheet(1).unwrap().cell("A2").unwrap().value(), "1");
+}
+
+#[test]
+fn rename_and_remove_sheet_with_unreadable_lazy_sheet() {
+    use std::io::{
+        Cursor,
+        Read,
+        Write,
+    };
+
+    let mut book = new_file();
+    book.new_sheet("Data").unwrap();
+    book.sheet_mut(0)
+        .unwrap()
+        .cell_mut("A1")
+        .set_formula("SUM(Data!A1:A3)");
+    let mut data = Vec::new();
+    writer::xlsx::write_writer(&book, &mut data).unwrap();
+
+    // The sheet that refers to "Data" has a malformed cell reference.
+    let mut zin = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+    let mut zout = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..zin.len() {
+        let mut file = zin.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        if name == "xl/worksheets/sheet1.xml" {
+            text = text.replacen("r=\"A1\"", "r=\"!!\"", 1);
+        }
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zout.write_all(text.as_bytes()).unwrap();
+    }
+    let data = zout.finish().unwrap().into_inner();
+
+    let mut book = reader::xlsx::read_reader(Cursor::new(data), false).unwrap();
+    assert!(book.set_sheet_name(1, "Renamed").is_err());
+    assert_eq!(book.sheet_collection_no_check()[1].name(), "Data");
+    assert!(book.remove_sheet(1).is_err());
+    assert_eq!(book.sheet_count(), 2);
+    assert!(matches!(book.sheet(0), Err(XlsxError::NotDeserialized())));
+}
+
+#[test]
+fn print_titles_print_area_and_page_fit() {
+    let mut book = new_file();
+    book.new_sheet("Report").unwrap();
+    let sheet = book.sheet_mut(1).unwrap();
+    sheet.set_print_area("A1:D20");
+    sheet
+        .set_print_title_rows(1..=2)
+        .unwrap()
+        .set_print_title_columns(1..=1)
+        .unwrap();
+    assert!(matches!(
+        sheet.set_print_title_columns(0..=0),
+        Err(XlsxError::InvalidRange(_))
+    ));
+    assert!(sheet.set_print_title_rows(3..=2).is_err());
+    assert!(sheet.set_print_title_columns(1..=16_385).is_err());
+    sheet.set_auto_filter("A2:D20");
+    sheet
+        .page_setup_mut()
+        .set_paper(structs::PaperSize::A4)
+        .fit_to_pages(1, 0);
+    assert_eq!(sheet.print_area().unwrap(), "Report!$A$1:$D$20");
+    assert_eq!(sheet.print_title_rows(), Some(1..=2));
+    assert_eq!(sheet.print_title_columns(), Some(1..=1));
+
+    sheet.insert_new_row(1, 1);
+    sheet.insert_new_column("A", 1);
+    assert_eq!(sheet.print_area().unwrap(), "Report!$B$2:$E$21");
+    assert_eq!(sheet.print_title_rows(), Some(2..=3));
+    assert_eq!(sheet.print_title_columns(), Some(2..=2));
+    assert_eq!(sheet.auto_filter().unwrap().range().range(), "B3:E21");
+
+    let path = std::path::Path::new("./tests/result_files/print_titles.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let mut book = reader::xlsx::read(path).unwrap();
+    let sheet = book.sheet_by_name_mut("Report").unwrap();
+    assert_eq!(sheet.print_area().unwrap(), "Report!$B$2:$E$21");
+    assert_eq!(sheet.print_title_rows(), Some(2..=3));
+    assert_eq!(sheet.print_title_columns(), Some(2..=2));
+    assert_eq!(sheet.page_setup().paper(), Some(structs::PaperSize::A4));
+    assert!(sheet.page_setup().fit_to_page());
+    assert_eq!(sheet.page_setup().fit_to_width(), 1);
+    assert_eq!(sheet.page_setup().fit_to_height(), 0);
+    let filter_database = sheet
+        .defined_names()
+        .iter()
+        .find(|v| v.name() == "_xlnm._FilterDatabase")
+        .unwrap();
+    assert_eq!(filter_database.address(), "Report!$B$3:$E$21");
+    assert_eq!(filter_database.local_sheet_id(), 1);
+
+    sheet.remove_print_area().remove_print_titles();
+    assert!(sheet.print_area().is_none());
+    assert!(sheet.print_title_rows().is_none());
+}
+
+#[test]
+fn header_footer_sections_and_images() {
+    use structs::{
+        HeaderFooterField,
+        HeaderFooterImageSection,
+        HeaderFooterPosition,
+        HeaderFooterSection,
+    };
+
+    let value = "&L&\"Arial,Bold\"&14Invoice &&Co&C&D &T&RPage &P of &N";
+    let section = HeaderFooterSection::parse(value);
+    assert_eq!(
+        section.left(),
+        [
+            HeaderFooterField::Font {
+                name:  "Arial".into(),
+                style: Some("Bold".into()),
+            },
+            HeaderFooterField::FontSize(14),
+            HeaderFooterField::Text("Invoice &Co".into()),
+        ]
+    );
+    assert_eq!(
+        section.center(),
+        [
+            HeaderFooterField::Date,
+            HeaderFooterField::Text(" ".into()),
+            HeaderFooterField::Time,
+        ]
+    );
+    assert_eq!(section.right().len(), 4);
+    assert_eq!(section.to_string(), value);
+    assert_eq!(HeaderFooterSection::parse("Draft").center().len(), 1);
+
+    // A string is given back as it was written.
+    for value in [
+        "&L&\"Arial\"&12 3&C&\"-,Bold\"&K01+000Total&R&Q&",
+        "&L&14123&CPage &P&R&\"Arial,\"&Z&F",
+        "&L&\"Arial",
+        "&C&012x",
+    ] {
+        assert_eq!(HeaderFooterSection::parse(value).to_string(), value);
+    }
+    assert_eq!(
+        HeaderFooterSection::parse("&L&\"Arial\"").left(),
+        [HeaderFooterField::Font {
+            name:  "Arial".into(),
+            style: None,
+        }]
+    );
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Invoice");
+    let mut comment = Comment::default();
+    comment.new_comment("A1").set_text_string("note");
+    sheet.add_comments(comment);
+
+    let mut footer = HeaderFooterSection::default();
+    footer
+        .add_file_name(HeaderFooterPosition::Left)
+        .add_sheet_name(HeaderFooterPosition::Right)
+        .add_font_size(HeaderFooterPosition::Right, 8);
+    let header_footer = sheet.header_footer_mut();
+    header_footer.odd_footer_mut().set_section(&footer);
+    header_footer
+        .odd_header_mut()
+        .set_value("&RPage &P");
+    header_footer
+        .set_different_first(true)
+        .first_header_mut()
+        .set_value("&CFirst page");
+    let mut image = helper::binary::make_media_object("./images/sample1.png");
+    image.set_image_name("logo.png").set_image_title("logo");
+    header_footer.set_image(HeaderFooterImageSection::LeftHeader, image);
+    assert_eq!(header_footer.odd_header().value(), "&L&G&RPage &P");
+    assert!(header_footer.image(HeaderFooterImageSection::LeftHeader).unwrap().width() > 0.0);
+
+    let path = std::path::Path::new("./tests/result_files/header_footer_image.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let mut book = reader::xlsx::read(path).unwrap();
+    let sheet = book.sheet_mut(0).unwrap();
+    assert_eq!(sheet.comments().len(), 1);
+    assert_eq!(sheet.ole_objects().ole_object().len(), 0);
+    let header_footer = sheet.header_footer_mut();
+    assert!(header_footer.different_first());
+    assert_eq!(header_footer.first_header().value(), "&CFirst page");
+    assert_eq!(header_footer.odd_footer().value(), "&L&F&R&A&8");
+    let image = header_footer
+        .image(HeaderFooterImageSection::LeftHeader)
+        .unwrap();
+    assert_eq!(image.image().image_name(), "logo.png");
+    assert_eq!(image.image().image_title(), "logo");
+    assert_eq!(image.section(), Some(HeaderFooterImageSection::LeftHeader));
+
+    header_footer.remove_image(HeaderFooterImageSection::LeftHeader);
+    assert!(header_footer.images().is_empty());
+    assert_eq!(header_footer.odd_header().value(), "&RPage &P");
+}
+
+#[test]
+fn rich_text_builder_export_and_phonetic_runs() {
+    use umya_spreadsheet::structs::{
+        RichText,
+        drawing::spreadsheet::TextBody,
+    };
+
+    let rich_text = RichText::builder()
+        .text("Total: ")
+        .bold()
+        .color("FF0000")
+        .text("42")
+        .italic()
+        .size(14.0)
+        .text("\n1 < 2")
+        .strikethrough()
+        .build();
+    assert_eq!(rich_text.text(), "Total: 42\n1 < 2");
+    assert_eq!(
+        rich_text.to_html(),
+        concat!(
+            r##"<font color="#FF0000"><b>Total: </b></font>"##,
+            r#"<font style="font-size:14pt"><i>42</i></font><del><br>1 &lt; 2</del>"#,
+        )
+    );
+    assert_eq!(rich_text.to_markdown(), "**Total:** *42*  \n~~1 \\< 2~~");
+    let html = helper::html::html_to_richtext(&rich_text.to_html()).unwrap();
+    assert_eq!(html.text(), rich_text.text());
+    assert_eq!(html.to_html(), rich_text.to_html());
+
+    let mut text_body = TextBody::default();
+    text_body.set_rich_text(&rich_text);
+    assert_eq!(text_body.paragraph().len(), 2);
+    assert_eq!(text_body.paragraph()[0].run().len(), 2);
+    assert_eq!(text_body.paragraph()[1].run()[0].run_properties().strike(), "sngStrike");
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet
+        .cell_mut("A1")
+        .set_rich_text(RichText::builder().text("東京").phonetic("トウキョウ").build());
+    sheet
+        .cell_mut("A2")
+        .set_rich_text(RichText::builder().text("大阪").bold().phonetic("オオサカ").build());
+    let mut comment = Comment::default();
+    comment.new_comment("A1").set_rich_text(rich_text.clone());
+    sheet.add_comments(comment);
+
+    let path = std::path::Path::new("./tests/result_files/rich_text_builder.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    for (coordinate, text, phonetic) in [("A1", "東京", "トウキョウ"), ("A2", "大阪", "オオサカ")] {
+        let value = sheet.cell(coordinate).unwrap().raw_value().rich_text().unwrap();
+        assert_eq!(value.text(), text);
+        assert_eq!(value.phonetic_runs().len(), 1);
+        assert_eq!(value.phonetic_runs()[0].text(), phonetic);
+        assert_eq!(value.phonetic_runs()[0].base_end(), 2);
+    }
+    let comment = &sheet.comments()[0];
+    assert!(comment.text().text().is_none());
+    assert_eq!(comment.text().rich_text().unwrap().to_html(), rich_text.to_html());
+}
+
+#[test]
+fn read_malformed_file_with_options() {
+    use std::io::{
+        Cursor,
+        Read,
+        Write,
+    };
+
+    use umya_spreadsheet::structs::ReadOptions;
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("first");
+    sheet.cell_mut("B2").set_value_number(2);
+    let mut data = Vec::new();
+    writer::xlsx::write_writer(&book, &mut data).unwrap();
+
+    // A `col` element without `min` and a docProps part that is not well-formed.
+    let mut zin = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+    let mut zout = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..zin.len() {
+        let mut file = zin.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        if name == "xl/worksheets/sheet1.xml" {
+            text = text.replacen("<sheetData", "<cols><col max=\"2\"/></cols><sheetData", 1);
+        }
+        if name == "docProps/app.xml" {
+            text = "<Properties><Application>x</Company></Properties>".into();
+        }
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zout.write_all(text.as_bytes()).unwrap();
+    }
+    let data = zout.finish().unwrap().into_inner();
+
+    let error = reader::xlsx::read_reader(Cursor::new(data.clone()), true).unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(location.part(), "docProps/app.xml");
+    assert!(location.position() > 0);
+
+    let mut options = ReadOptions::default();
+    options.set_strict(false);
+    let (mut book, warnings) =
+        reader::xlsx::read_reader_with_options(Cursor::new(data), &options).unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert!(matches!(warnings[0], XlsxError::InvalidXml(..)));
+    let XlsxError::MissingAttribute(location, attribute) = &warnings[1] else {
+        panic!("unexpected warning: {}", warnings[1]);
+    };
+    assert_eq!(location.part(), "xl/worksheets/sheet1.xml");
+    assert_eq!(location.element(), "col");
+    assert_eq!(attribute, "min");
+
+    let sheet = book.sheet_mut(0).unwrap();
+    assert_eq!(sheet.value("A1"), "first");
+    assert_eq!(sheet.value("B2"), "2");
+}
+
+#[test]
+fn read_malformed_attributes() {
+    use std::io::{
+        Cursor,
+        Read,
+        Write,
+    };
+
+    use umya_spreadsheet::structs::ReadOptions;
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value_number(1.5);
+    sheet
+        .style_mut("A1")
+        .number_format_mut()
+        .set_format_code("0.000");
+    sheet.cell_mut("B2").set_value("second");
+    let mut comment = Comment::default();
+    comment.new_comment("B2");
+    comment.set_text_string("note");
+    sheet.add_comments(comment);
+    let mut data = Vec::new();
+    writer::xlsx::write_writer(&book, &mut data).unwrap();
+
+    let replace = |part: &str, from: &str, to: &str| {
+        let mut zin = zip::ZipArchive::new(Cursor::new(data.clone())).unwrap();
+        let mut zout = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for i in 0..zin.len() {
+            let mut file = zin.by_index(i).unwrap();
+            let name = file.name().to_string();
+            let mut text = String::new();
+            file.read_to_string(&mut text).unwrap();
+            if name == part {
+                assert!(text.contains(from), "{from} not in {part}");
+                text = text.replacen(from, to, 1);
+            }
+            zout.start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zout.write_all(text.as_bytes()).unwrap();
+        }
+        zout.finish().unwrap().into_inner()
+    };
+    let cases = [
+        ("xl/styles.xml", "numFmtId=\"176\"", "numFmtId=\"x\"", "numFmt"),
+        ("xl/worksheets/sheet1.xml", "r=\"B2\"", "r=\"Bx\"", "c"),
+        ("xl/comments1.xml", "ref=\"B2\"", "ref=\"2B\"", "comment"),
+        ("xl/comments1.xml", "authorId=\"0\"", "authorId=\"9\"", "comment"),
+    ];
+    for (part, from, to, element) in cases {
+        let data = replace(part, from, to);
+        let error = reader::xlsx::read_reader(Cursor::new(data.clone()), true).unwrap_err();
+        let XlsxError::InvalidElement(location, _) = &error else {
+            panic!("unexpected error: {error}");
+        };
+        assert_eq!(location.part(), part);
+        assert_eq!(location.element(), element);
+
+        let mut options = ReadOptions::default();
+        options.set_strict(false);
+        let (_, warnings) =
+            reader::xlsx::read_reader_with_options(Cursor::new(data), &options).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(warnings[0], XlsxError::InvalidElement(..)));
+    }
+}
+
+#[test]
+fn read_and_write_unknown_parts() {
+    use std::io::{
+        Cursor,
+        Read,
+        Write,
+    };
+
+    use umya_spreadsheet::structs::ReadOptions;
+
+    let mut book = new_file();
+    book.sheet_mut(0).unwrap().cell_mut("A1").set_value("first");
+    let mut data = Vec::new();
+    writer::xlsx::write_writer(&book, &mut data).unwrap();
+
+    // A custom XML part, a slicer and elements the crate does not support.
+    let mut files: Vec<(String, String)> = Vec::new();
+    let mut zin = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+    for i in 0..zin.len() {
+        let mut file = zin.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        match name.as_str() {
+            "[Content_Types].xml" => {
+                text = text.replacen(
+                    "</Types>",
+                    "<Override PartName=\"/customXml/itemProps1.xml\" \
+                     ContentType=\"application/vnd.openxmlformats-officedocument.customXmlProperties+xml\"/>\
+                     <Override PartName=\"/xl/slicers/slicer1.xml\" \
+                     ContentType=\"application/vnd.ms-excel.slicer+xml\"/></Types>",
+                    1,
+                );
+            }
+            "xl/_rels/workbook.xml.rels" => {
+                text = text.replacen(
+                    "</Relationships>",
+                    "<Relationship Id=\"rId99\" \
+                     Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml\" \
+                     Target=\"../customXml/item1.xml\"/></Relationships>",
+                    1,
+                );
+            }
+            "xl/workbook.xml" => {
+                text = text.replacen(
+                    "</workbook>",
+                    "<extLst><ext uri=\"{140A7094-0E35-4892-8432-C4D2E57EDEB5}\" \
+                     xmlns:x15=\"http://schemas.microsoft.com/office/spreadsheetml/2010/11/main\">\
+                     <x15:workbookPr chartTrackingRefBase=\"1\"/></ext></extLst></workbook>",
+                    1,
+                );
+            }
+            "xl/worksheets/sheet1.xml" => {
+                // nested in a supported element
+                text = text.replacen(
+                    "<sheetView workbookViewId=\"0\"/>",
+                    "<sheetView workbookViewId=\"0\" x14ac:nestedAttribute=\"1\">\
+                     <x14ac:nestedElement/></sheetView>",
+                    1,
+                );
+                text = text.replacen(
+                    "</worksheet>",
+                    "<ignoredErrors><ignoredError sqref=\"A1\" numberStoredAsText=\"1\"/>\
+                     </ignoredErrors><extLst><ext uri=\"{3A4CF648-6AED-40f4-86FF-DC5316D8AED3}\">\
+                     <x14:slicerList><x14:slicer r:id=\"rId1\"/></x14:slicerList></ext></extLst>\
+                     </worksheet>",
+                    1,
+                );
+            }
+            "xl/styles.xml" => {
+                // in a part other than the workbook and the worksheets
+                text = text.replacen("</styleSheet>", "<unknownStyles/></styleSheet>", 1);
+            }
+            _ => {}
+        }
+        files.push((name, text));
+    }
+    files.push((
+        "xl/worksheets/_rels/sheet1.xml.rels".into(),
+        "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+         <Relationship Id=\"rId1\" \
+         Type=\"http://schemas.microsoft.com/office/2007/relationships/slicer\" \
+         Target=\"../slicers/slicer1.xml\"/></Relationships>"
+            .into(),
+    ));
+    files.push(("xl/slicers/slicer1.xml".into(), "<slicers/>".into()));
+    files.push(("customXml/item1.xml".into(), "<root>data</root>".into()));
+    files.push((
+        "customXml/_rels/item1.xml.rels".into(),
+        "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+         <Relationship Id=\"rId1\" \
+         Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps\" \
+         Target=\"itemProps1.xml\"/></Relationships>"
+            .into(),
+    ));
+    files.push(("customXml/itemProps1.xml".into(), "<datastoreItem/>".into()));
+    let mut zout = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, text) in files {
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zout.write_all(text.as_bytes()).unwrap();
+    }
+    let data = zout.finish().unwrap().into_inner();
+
+    let read_part = |data: &[u8], name: &str| -> Option<String> {
+        let mut zin = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+        let mut file = zin.by_name(name).ok()?;
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        Some(text)
+    };
+
+    // Dropped by default.
+    let book = reader::xlsx::read_reader(Cursor::new(data.clone()), true).unwrap();
+    let mut written = Vec::new();
+    writer::xlsx::write_writer(&book, &mut written).unwrap();
+    assert!(read_part(&written, "customXml/item1.xml").is_none());
+
+    let mut options = ReadOptions::default();
+    options.set_preserve_unknown(true);
+    let (mut book, warnings) =
+        reader::xlsx::read_reader_with_options(Cursor::new(data), &options).unwrap();
+    assert!(warnings.is_empty());
+    book.sheet_mut(0).unwrap().cell_mut("B1").set_value("second");
+    let mut written = Vec::new();
+    writer::xlsx::write_writer(&book, &mut written).unwrap();
+
+    assert_eq!(read_part(&written, "customXml/item1.xml").unwrap(), "<root>data</root>");
+    assert!(read_part(&written, "customXml/itemProps1.xml").is_some());
+    assert!(read_part(&written, "customXml/_rels/item1.xml.rels").is_some());
+    assert!(read_part(&written, "xl/slicers/slicer1.xml").is_some());
+    let content_types = read_part(&written, "[Content_Types].xml").unwrap();
+    assert!(content_types.contains("/customXml/itemProps1.xml"));
+    assert!(content_types.contains("/xl/slicers/slicer1.xml"));
+    let workbook_rels = read_part(&written, "xl/_rels/workbook.xml.rels").unwrap();
+    assert!(workbook_rels.contains("Target=\"../customXml/item1.xml\""));
+    let workbook = read_part(&written, "xl/workbook.xml").unwrap();
+    assert!(workbook.contains("<x15:workbookPr chartTrackingRefBase=\"1\"/></ext></extLst>"));
+
+    let sheet = read_part(&written, "xl/worksheets/sheet1.xml").unwrap();
+    let ignored_errors = sheet.find("<ignoredErrors>").unwrap();
+    let ext_lst = sheet.find("<extLst>").unwrap();
+    assert!(sheet.find("<pageMargins").unwrap() < ignored_errors);
+    assert!(ignored_errors < ext_lst);
+    assert!(sheet.contains("<x14:slicer r:id=\"rIdP1\"/>"));
+    let sheet_rels = read_part(&written, "xl/worksheets/_rels/sheet1.xml.rels").unwrap();
+    assert!(sheet_rels.contains("Id=\"rIdP1\""));
+    assert!(sheet_rels.contains("Target=\"../slicers/slicer1.xml\""));
+
+    // Nested in a supported element and in the other parts.
+    let sheet_view = sheet.find("<sheetView ").unwrap();
+    let sheet_view = &sheet[sheet_view..sheet.find("</sheetView>").unwrap()];
+    assert!(sheet_view.contains("x14ac:nestedAttribute=\"1\""));
+    assert!(sheet_view.contains("<x14ac:nestedElement/>"));
+    let styles = read_part(&written, "xl/styles.xml").unwrap();
+    assert!(styles.contains("<unknownStyles/>"));
+    assert!(styles.find("<unknownStyles/>").unwrap() < styles.find("</styleSheet>").unwrap());
+
+    // A second cycle keeps everything as it is.
+    let (book, _) = reader::xlsx::read_reader_with_options(Cursor::new(written.clone()), &options)
+        .unwrap();
+    assert_eq!(book.sheet(0).unwrap().value("B1"), "second");
+    let mut rewritten = Vec::new();
+    writer::xlsx::write_writer(&book, &mut rewritten).unwrap();
+    assert_eq!(
+        read_part(&rewritten, "xl/worksheets/sheet1.xml"),
+        read_part(&written, "xl/worksheets/sheet1.xml")
+    );
+    assert_eq!(
+        read_part(&rewritten, "xl/_rels/workbook.xml.rels"),
+        read_part(&written, "xl/_rels/workbook.xml.rels")
+    );
+    assert_eq!(
+        read_part(&rewritten, "xl/styles.xml"),
+        read_part(&written, "xl/styles.xml")
+    );
+}
+
+#[test]
+fn read_and_write_strict() {
+    use std::io::{
+        Cursor,
+        Read,
+        Write,
+    };
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("text");
+    sheet.cell_mut("B1").set_value_number(12.5);
+    let mut data = Vec::new();
+    writer::xlsx::write_writer_strict(&book, &mut data).unwrap();
+
+    let read_part = |data: &[u8], name: &str| -> String {
+        let mut zin = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+        let mut file = zin.by_name(name).unwrap();
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        text
+    };
+    let workbook = read_part(&data, "xl/workbook.xml");
+    assert!(workbook.contains("xmlns=\"http://purl.oclc.org/ooxml/spreadsheetml/main\""));
+    assert!(workbook.contains("conformance=\"strict\""));
+    let rels = read_part(&data, "_rels/.rels");
+    assert!(rels.contains(
+        "Type=\"http://purl.oclc.org/ooxml/officeDocument/relationships/officeDocument\""
+    ));
+    assert!(rels.contains(
+        "Type=\"http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties\""
+    ));
+    let sheet = read_part(&data, "xl/worksheets/sheet1.xml");
+    assert!(!sheet.contains("http://schemas.openxmlformats.org/spreadsheetml/2006/main"));
+
+    // Strict files may store dates as ISO 8601 values.
+    let mut zin = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+    let mut zout = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..zin.len() {
+        let mut file = zin.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        if name == "xl/worksheets/sheet1.xml" {
+            text = text.replacen(
+                "</row>",
+                "<c r=\"C1\" t=\"d\"><v>2021-01-01T12:00:00Z</v></c>\
+                 <c r=\"D1\" t=\"d\"><v>2021-01-01</v></c>\
+                 <c r=\"E1\" t=\"d\"><v>T06:00:00</v></c></row>",
+                1,
+            );
+        }
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zout.write_all(text.as_bytes()).unwrap();
+    }
+    let data = zout.finish().unwrap().into_inner();
+
+    let book = reader::xlsx::read_reader(Cursor::new(data), true).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.value("A1"), "text");
+    assert_eq!(sheet.value("B1"), "12.5");
+    assert_eq!(sheet.value("C1"), "44197.5");
+    assert_eq!(sheet.value("D1"), "44197");
+    assert_eq!(sheet.value("E1"), "0.25");
+    assert_eq!(
+        sheet.cell("C1").unwrap().formatted_value(),
+        "2021-01-01 12:00:00"
+    );
+    assert_eq!(sheet.cell("D1").unwrap().formatted_value(), "2021-01-01");
+    assert_eq!(sheet.cell("E1").unwrap().formatted_value(), "6:00:00");
+}
+
+#[test]
+fn read_xls() {
+    use std::io::{
+        Cursor,
+        Write,
+    };
+
+    fn rec(kind: u16, data: &[u8]) -> Vec<u8> {
+        let mut v = kind.to_le_bytes().to_vec();
+        v.extend_from_slice(&u16::try_from(data.len()).unwrap().to_le_bytes());
+        v.extend_from_slice(data);
+        v
+    }
+    fn cat(parts: &[&[u8]]) -> Vec<u8> {
+        parts.concat()
+    }
+    fn bof(kind: u16) -> Vec<u8> {
+        rec(0x0809, &cat(&[&0x0600u16.to_le_bytes(), &kind.to_le_bytes(), &[0; 12]]))
+    }
+    fn font(weight: u16, color: u16) -> Vec<u8> {
+        let head = [200u16, 0, color, weight, 0].map(u16::to_le_bytes).concat();
+        rec(0x0031, &cat(&[&head, &[0, 0, 0, 0, 5, 0], b"Arial"]))
+    }
+    fn xf(font: u16, format: u16, align: u8, border1: u32, border2: u32, fill: u16) -> Vec<u8> {
+        let head = [font, format, 0x0001].map(u16::to_le_bytes).concat();
+        rec(
+            0x00E0,
+            &cat(&[
+                &head,
+                &[align, 0, 0, 0],
+                &border1.to_le_bytes(),
+                &border2.to_le_bytes(),
+                &fill.to_le_bytes(),
+            ]),
+        )
+    }
+    fn cell(kind: u16, row: u16, col: u16, xf: u16, data: &[u8]) -> Vec<u8> {
+        let head = [row, col, xf].map(u16::to_le_bytes).concat();
+        rec(kind, &cat(&[&head, data]))
+    }
+    fn formula(row: u16, col: u16, result: [u8; 8], rgce: &[u8]) -> Vec<u8> {
+        let size = u16::try_from(rgce.len()).unwrap().to_le_bytes();
+        cell(0x0006, row, col, 0, &cat(&[&result, &[0; 6], &size, rgce]))
+    }
+    fn words(values: &[u16]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+    let globals = |offsets: [u32; 2]| -> Vec<u8> {
+        let mut sst = cat(&[&words(&[2, 0, 2, 0]), &words(&[5]), &[0], b"Hello"]);
+        sst.extend_from_slice(&cat(&[&words(&[5]), &[0, b'W', 0xF6]]));
+        let total = cat(&[&[0x3A], &words(&[1, 0, 0])]);
+        let print_area = cat(&[&[0x3B], &words(&[0, 0, 1, 0, 1])]);
+        cat(&[
+            &bof(0x0005),
+            &font(400, 0x7FFF),
+            &font(700, 10),
+            &rec(0x041E, &cat(&[&words(&[164, 5]), &[0], b"0.000"])),
+            &xf(0, 0, 0x20, 0, 0, 0x20C0),
+            &xf(1, 164, 0x22, 1 | (8 << 16), 1 << 26, 13 | (64 << 7)),
+            &xf(0, 14, 0x20, 0, 0, 0x20C0),
+            &xf(0, 20, 0x20, 0, 0, 0x20C0),
+            &rec(0x0022, &words(&[1])),
+            &rec(0x0085, &cat(&[&offsets[0].to_le_bytes(), &[0, 0, 6, 0], b"Sheet1"])),
+            &rec(0x0085, &cat(&[&offsets[1].to_le_bytes(), &[1, 0, 6, 0], b"Sheet2"])),
+            &rec(0x00FC, &sst),
+            &rec(0x003C, &cat(&[&[1], &words(&[u16::from(b'r'), u16::from(b'l'), 100])])),
+            &rec(0x01AE, &words(&[2, 0x0401])),
+            &rec(0x0017, &words(&[2, 0, 0, 0, 0, 1, 1])),
+            &rec(
+                0x0018,
+                &cat(&[&[0, 0, 0, 5], &words(&[7, 0, 0]), &[0; 4], &[0], b"Total", &total]),
+            ),
+            &rec(
+                0x0018,
+                &cat(&[&[0x20, 0, 0, 1], &words(&[11, 0, 1]), &[0; 4], &[0, 6], &print_area]),
+            ),
+            &rec(0x000A, &[]),
+        ])
+    };
+    // SUM(A2:B2), A1&"!", shared A2 and Sheet2!A1+1
+    let sum = cat(&[&[0x25], &words(&[1, 1, 0xC000, 0xC001]), &[0x22, 1, 4, 0]]);
+    let concat = cat(&[&[0x24], &words(&[0, 0xC000]), &[0x17, 1, 0, b'!', 0x08]]);
+    let exp = cat(&[&[0x01], &words(&[5, 0])]);
+    let shared = cat(&[&words(&[5, 5]), &[0, 1, 0, 2], &words(&[5]), &[0x2C]]);
+    let shared = cat(&[&shared, &words(&[0xFFFC, 0xC000])]);
+    let ref_3d = cat(&[&[0x3A], &words(&[1, 0, 0xC000]), &[0x1E, 1, 0, 0x03]]);
+    let sheet1 = cat(&[
+        &bof(0x0010),
+        &rec(0x007D, &words(&[0, 0, 20 * 256, 0, 0, 0])),
+        &rec(0x0208, &cat(&[&words(&[0, 0, 2, 600, 0, 0]), &0x140u32.to_le_bytes()])),
+        &cell(0x00FD, 0, 0, 1, &0u32.to_le_bytes()),
+        &cell(0x00FD, 0, 1, 0, &1u32.to_le_bytes()),
+        &cell(0x0203, 1, 0, 0, &1.5f64.to_le_bytes()),
+        &cell(0x027E, 1, 1, 0, &((42u32 << 2) | 2).to_le_bytes()),
+        &rec(
+            0x00BD,
+            &cat(&[
+                &words(&[2, 0, 0]),
+                &((123u32 << 2) | 3).to_le_bytes(),
+                &words(&[0]),
+                &((7u32 << 2) | 2).to_le_bytes(),
+                &words(&[1]),
+            ]),
+        ),
+        &cell(0x0203, 1, 2, 2, &0f64.to_le_bytes()),
+        &cell(0x0203, 1, 3, 3, &0.5f64.to_le_bytes()),
+        &cell(0x0205, 3, 0, 0, &[1, 0]),
+        &cell(0x0205, 3, 1, 0, &[0x07, 1]),
+        &formula(4, 0, 43.5f64.to_le_bytes(), &sum),
+        &formula(4, 1, [0, 0, 0, 0, 0, 0, 0xFF, 0xFF], &concat),
+        &rec(0x0207, &cat(&[&words(&[6]), &[0], b"Hello!"])),
+        &formula(5, 0, 1.5f64.to_le_bytes(), &exp),
+        &rec(0x04BC, &shared),
+        &formula(5, 1, 42f64.to_le_bytes(), &exp),
+        &formula(6, 0, 11f64.to_le_bytes(), &ref_3d),
+        &rec(0x00E5, &words(&[1, 7, 7, 0, 1])),
+        &rec(0x000A, &[]),
+    ]);
+    let sheet2 = cat(&[
+        &bof(0x0010),
+        &cell(0x0203, 0, 0, 0, &10f64.to_le_bytes()),
+        &rec(0x000A, &[]),
+    ]);
+    let offset = u32::try_from(globals([0, 0]).len()).unwrap();
+    let sheet1_len = u32::try_from(sheet1.len()).unwrap();
+    let stream = cat(&[&globals([offset, offset + sheet1_len]), &sheet1, &sheet2]);
+
+    let mut compound_file = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+    compound_file
+        .create_stream("/Workbook")
+        .unwrap()
+        .write_all(&stream)
+        .unwrap();
+    compound_file.flush().unwrap();
+    let data = compound_file.into_inner().into_inner();
+
+    let book = reader::xls::read_reader(Cursor::new(data)).unwrap();
+    assert_eq!(book.sheet_count(), 2);
+    assert_eq!(book.sheet(1).unwrap().sheet_state(), "hidden");
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.name(), "Sheet1");
+    assert_eq!(sheet.value("A1"), "Hello");
+    assert_eq!(sheet.value("B1"), "Wörld");
+    assert_eq!(sheet.value("A2"), "1.5");
+    assert_eq!(sheet.value("B2"), "42");
+    assert_eq!(sheet.value("A3"), "1.23");
+    assert_eq!(sheet.value("B3"), "7");
+    // 1904-01-01 in the 1904 date system, a time stays as it is
+    assert_eq!(sheet.value("C2"), "1462");
+    assert_eq!(sheet.value("D2"), "0.5");
+    assert_eq!(sheet.value("A4"), "TRUE");
+    assert_eq!(sheet.value("B4"), "#DIV/0!");
+    assert_eq!(sheet.cell("A5").unwrap().formula(), "SUM(A2:B2)");
+    assert_eq!(sheet.value("A5"), "43.5");
+    assert_eq!(sheet.cell("B5").unwrap().formula(), "A1&\"!\"");
+    assert_eq!(sheet.value("B5"), "Hello!");
+    assert_eq!(sheet.cell("A6").unwrap().formula(), "A2");
+    assert_eq!(sheet.cell("B6").unwrap().formula(), "B2");
+    assert_eq!(sheet.cell("A7").unwrap().formula(), "Sheet2!A1+1");
+    assert_eq!(sheet.merge_cells()[0].range(), "A8:B8");
+    assert_eq!(sheet.column_dimension("A").unwrap().width(), 20.0);
+    assert_eq!(sheet.row_dimension(1).unwrap().height(), 30.0);
+    assert_eq!(sheet.print_area().unwrap(), "Sheet1!$A$1:$B$2");
+
+    let style = sheet.style("A1");
+    assert!(style.font().unwrap().bold());
+    assert_eq!(style.number_format().unwrap().format_code(), "0.000");
+    assert_eq!(
+        style.fill().unwrap().pattern_fill().unwrap().foreground_color().unwrap().argb_str(),
+        "FFFFFF00"
+    );
+    assert_eq!(style.borders().unwrap().left().border_style(), "thin");
+
+    let total = &book.sheet(1).unwrap().defined_names()[0];
+    assert_eq!(total.name(), "Total");
+    assert_eq!(total.address(), "Sheet2!$A$1");
+
+    // The workbook can be saved as xlsx.
+    let mut written = Vec::new();
+    writer::xlsx::write_writer(&book, &mut written).unwrap();
+    let book = reader::xlsx::read_reader(Cursor::new(written), true).unwrap();
+    assert_eq!(book.sheet(0).unwrap().cell("A5").unwrap().formula(), "SUM(A2:B2)");
+    assert_eq!(book.sheet(0).unwrap().value("B1"), "Wörld");
+}
+
+#[test]
+fn read_xls_written_by_excel() {
+    let path = std::path::Path::new("./tests/test_files/excel_97_2003.xls");
+    let book = reader::xls::read(path).unwrap();
+    let names: Vec<&str> = book.sheet_collection().iter().map(Worksheet::name).collect();
+    assert_eq!(names, ["datatypes", "Sheet1", "issue2", "issue5", "issue6"]);
+
+    let sheet = book.sheet_by_name("datatypes").unwrap();
+    assert_eq!(sheet.value("A1"), "1");
+    assert_eq!(sheet.value("A2"), "1.5");
+    assert_eq!(sheet.cell("A3").unwrap().formula(), "CONCATENATE(\"a\",\"b\")");
+    assert_eq!(sheet.value("A3"), "ab");
+    assert_eq!(sheet.cell("A4").unwrap().formula(), "A1>A2");
+    assert_eq!(sheet.value("A4"), "FALSE");
+    assert_eq!(sheet.value("A5"), "test");
+    assert_eq!(sheet.value("A6"), "42663");
+    let style = sheet.style("A6");
+    assert_eq!(style.number_format().unwrap().format_code(), "m/d/yyyy");
+    assert_eq!(sheet.cell("A6").unwrap().formatted_value(), "10/20/2016");
+    assert_eq!(style.font().unwrap().name(), "Calibri");
+
+    let sheet = book.sheet_by_name("issue5").unwrap();
+    assert_eq!(sheet.style("A1").number_format().unwrap().format_code(), "0");
+    assert_eq!(sheet.cell("A1").unwrap().formatted_value(), "1");
+
+    let sheet = book.sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.cell("A2").unwrap().formula(), "B1+OneRange");
+    assert_eq!(
+        book.names().get("OneRange", None).unwrap().address(),
+        "Sheet1!$A$1"
+    );
+    assert_eq!(
+        book.names().get("MyDataTypes", None).unwrap().address(),
+        "datatypes!$A$1:$A$6"
+    );
+}
+
+#[test]
+fn read_and_write_xlsb() {
+    use std::io::Cursor;
+
+    let mut book = new_file();
+    book.new_sheet("Data").unwrap();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Hello");
+    sheet.cell_mut("B1").set_value("Wörld");
+    sheet.cell_mut("A2").set_value_number(1.5);
+    sheet.cell_mut("B2").set_value_number(42);
+    sheet.cell_mut("A3").set_value_bool(true);
+    sheet.cell_mut("A4").set_formula("SUM(A2:B2)");
+    sheet.cell_mut("B4").set_formula("IF(A3,Data!A1*2,\"no\")");
+    sheet.cell_mut("A5").set_formula("Rate*$B$2");
+    sheet.add_merge_cells("A6:B6");
+    sheet.column_dimension_mut("A").set_width(20.0);
+    sheet.row_dimension_mut(1).set_height(30.0);
+    sheet.style_mut("A1").font_mut().set_bold(true);
+    sheet
+        .style_mut("A2")
+        .number_format_mut()
+        .set_format_code("0.000");
+    sheet
+        .style_mut("B2")
+        .set_background_color("FFFFFF00");
+    book.sheet_mut(1).unwrap().cell_mut("A1").set_value_number(3);
+    book.names_mut().add("Rate", "0.1", None).unwrap();
+    book.names_mut()
+        .add("Items", "Data!$A$1:$A$3", None)
+        .unwrap();
+
+    let mut data = Vec::new();
+    writer::xlsb::write_writer(&book, &mut data).unwrap();
+
+    let book = reader::xlsb::read_reader(Cursor::new(data)).unwrap();
+    assert_eq!(book.sheet_count(), 2);
+    assert_eq!(book.sheet(1).unwrap().name(), "Data");
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.value("A1"), "Hello");
+    assert_eq!(sheet.value("B1"), "Wörld");
+    assert_eq!(sheet.value("A2"), "1.5");
+    assert_eq!(sheet.value("B2"), "42");
+    assert_eq!(sheet.value("A3"), "TRUE");
+    assert_eq!(sheet.cell("A4").unwrap().formula(), "SUM(A2:B2)");
+    assert_eq!(sheet.cell("B4").unwrap().formula(), "IF(A3,Data!A1*2,\"no\")");
+    assert_eq!(sheet.cell("A5").unwrap().formula(), "Rate*$B$2");
+    assert_eq!(sheet.merge_cells()[0].range(), "A6:B6");
+    assert_eq!(sheet.column_dimension("A").unwrap().width(), 20.0);
+    assert_eq!(sheet.row_dimension(1).unwrap().height(), 30.0);
+    assert!(sheet.style("A1").font().unwrap().bold());
+    assert_eq!(
+        sheet.style("A2").number_format().unwrap().format_code(),
+        "0.000"
+    );
+    assert_eq!(
+        sheet
+            .style("B2")
+            .fill()
+            .unwrap()
+            .pattern_fill()
+            .unwrap()
+            .foreground_color()
+            .unwrap()
+            .argb_str(),
+        "FFFFFF00"
+    );
+    assert_eq!(book.sheet(1).unwrap().value("A1"), "3");
+
+    let rate = &book.defined_names()[0];
+    assert_eq!(rate.name(), "Rate");
+    assert_eq!(rate.address(), "0.1");
+    let items = &book.sheet(1).unwrap().defined_names()[0];
+    assert_eq!(items.name(), "Items");
+    assert_eq!(items.address(), "Data!$A$1:$A$3");
+}
+
+#[test]
+fn read_xlsb_written_by_excel() {
+    let path = std::path::Path::new("./tests/test_files/excel_binary.xlsb");
+    let book = reader::xlsb::read(path).unwrap();
+    let names: Vec<&str> = book
+        .sheet_collection()
+        .iter()
+        .map(Worksheet::name)
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "datatypes",
+            "issue2",
+            "Sheet1",
+            "issue5",
+            "issue6",
+            "spc_chrs"
+        ]
+    );
+
+    let sheet = book.sheet_by_name("datatypes").unwrap();
+    assert_eq!(sheet.value("A1"), "1");
+    assert_eq!(sheet.value("A2"), "1.5");
+    assert_eq!(
+        sheet.cell("A3").unwrap().formula(),
+        "CONCATENATE(\"a\",\"b\")"
+    );
+    assert_eq!(sheet.value("A3"), "ab");
+    assert_eq!(sheet.cell("A4").unwrap().formula(), "A1>A2");
+    assert_eq!(sheet.value("A4"), "FALSE");
+    assert_eq!(sheet.value("A5"), "test");
+    assert_eq!(sheet.value("A6"), "42663");
+    let style = sheet.style("A6");
+    assert_eq!(style.number_format().unwrap().format_code(), "m/d/yyyy");
+    assert_eq!(sheet.cell("A6").unwrap().formatted_value(), "10/20/2016");
+    assert!(!style.font().unwrap().bold());
+    assert_eq!(
+        style.fill().unwrap().pattern_fill().unwrap().pattern_type(),
+        &PatternValues::None
+    );
+    assert!(
+        style
+            .fill()
+            .unwrap()
+            .pattern_fill()
+            .unwrap()
+            .foreground_color()
+            .is_none()
+    );
+
+    let sheet = book.sheet_by_name("issue5").unwrap();
+    assert_eq!(
+        sheet.style("A1").number_format().unwrap().format_code(),
+        "0"
+    );
+    assert_eq!(sheet.cell("A1").unwrap().formatted_value(), "1");
+
+    let sheet = book.sheet_by_name("spc_chrs").unwrap();
+    assert_eq!(sheet.value("A1"), "&");
+    assert_eq!(sheet.value("A2"), "<");
+    assert_eq!(sheet.value("A3"), ">");
+    assert_eq!(sheet.value("A4"), "aaa ' aaa");
+    assert_eq!(sheet.value("A5"), "\"");
+    assert_eq!(sheet.value("A6"), "☺");
+    assert_eq!(sheet.value("A8"), "àâéêèçöïî«»");
+
+    let sheet = book.sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.cell("A2").unwrap().formula(), "B1+OneRange");
+    assert_eq!(
+        book.names().get("OneRange", None).unwrap().address(),
+        "Sheet1!$A$1"
+    );
+    assert_eq!(
+        book.names().get("MyDataTypes", None).unwrap().address(),
+        "datatypes!$A$1:$A$6"
+    );
+}
+
+#[test]
+fn read_and_write_ods() {
+    use std::io::Cursor;
+
+    let mut book = new_file();
+    book.new_sheet("My Data").unwrap();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Hello");
+    sheet.cell_mut("B1").set_value("Wörld  and\ttab");
+    sheet.cell_mut("A2").set_value_number(1.5);
+    sheet.cell_mut("B2").set_value_number(45306);
+    sheet.cell_mut("A3").set_value_bool(true);
+    sheet.cell_mut("A4").set_formula("SUM(A2:B2)");
+    sheet.cell_mut("B4").set_formula("IF(A3,'My Data'!A1&\"x\",\"no\")");
+    sheet.cell_mut("A5").set_formula("Rate*$B$2");
+    sheet.cell_mut("B5").set_error("#DIV/0!");
+    sheet.add_merge_cells("A6:B7");
+    sheet.column_dimension_mut("A").set_width(20.0);
+    sheet.row_dimension_mut(1).set_height(30.0);
+    sheet.style_mut("A1").font_mut().set_bold(true);
+    sheet
+        .style_mut("A2")
+        .number_format_mut()
+        .set_format_code("#,##0.000");
+    sheet
+        .style_mut("B2")
+        .number_format_mut()
+        .set_format_code("yyyy-mm-dd");
+    sheet
+        .style_mut("A3")
+        .set_background_color("FFFFFF00");
+    let mut marker = drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("C3");
+    let mut image = Image::default();
+    image.new_image("./images/sample1.png", marker);
+    sheet.add_image(image);
+    book.sheet_mut(1).unwrap().cell_mut("A1").set_value_number(3);
+    book.sheet_mut(1).unwrap().set_sheet_state("hidden".into());
+    book.names_mut().add("Rate", "0.1", None).unwrap();
+    book.names_mut()
+        .add("Items", "'My Data'!$A$1:$A$3", None)
+        .unwrap();
+
+    let mut data = Vec::new();
+    writer::ods::write_writer(&book, &mut data).unwrap();
+
+    let book = reader::ods::read_reader(Cursor::new(data.clone())).unwrap();
+    assert_eq!(book.sheet_count(), 2);
+    assert_eq!(book.sheet(1).unwrap().name(), "My Data");
+    assert_eq!(book.sheet(1).unwrap().sheet_state(), "hidden");
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.value("A1"), "Hello");
+    assert_eq!(sheet.value("B1"), "Wörld  and\ttab");
+    assert_eq!(sheet.value("A2"), "1.5");
+    assert_eq!(sheet.value("B2"), "45306");
+    assert_eq!(sheet.value("A3"), "TRUE");
+    assert_eq!(sheet.cell("A4").unwrap().formula(), "SUM(A2:B2)");
+    assert_eq!(
+        sheet.cell("B4").unwrap().formula(),
+        "IF(A3,'My Data'!A1&\"x\",\"no\")"
+    );
+    assert_eq!(sheet.cell("A5").unwrap().formula(), "Rate*$B$2");
+    assert_eq!(sheet.value("B5"), "#DIV/0!");
+    assert_eq!(sheet.merge_cells()[0].range(), "A6:B7");
+    assert_eq!(sheet.column_dimension("A").unwrap().width(), 20.0);
+    assert_eq!(sheet.row_dimension(1).unwrap().height(), 30.0);
+    assert!(sheet.style("A1").font().unwrap().bold());
+    assert_eq!(
+        sheet.style("A2").number_format().unwrap().format_code(),
+        "#,##0.000"
+    );
+    assert_eq!(
+        sheet.style("B2").number_format().unwrap().format_code(),
+        "yyyy-mm-dd"
+    );
+    assert_eq!(
+        sheet.style("A3").background_color().unwrap().argb_str(),
+        "FFFFFF00"
+    );
+    assert_eq!(sheet.image_collection()[0].coordinate(), "C3");
+    assert_eq!(book.sheet(1).unwrap().value("A1"), "3");
+
+    let rate = &book.defined_names()[0];
+    assert_eq!(rate.name(), "Rate");
+    assert_eq!(rate.address(), "0.1");
+    let items = &book.sheet(1).unwrap().defined_names()[0];
+    assert_eq!(items.name(), "Items");
+    assert_eq!(items.address(), "'My Data'!$A$1:$A$3");
+
+    // An image too large for the sheet is skipped.
+    let mut zin = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+    let mut zout = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..zin.len() {
+        let mut file = zin.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut part = Vec::new();
+        std::io::Read::read_to_end(&mut file, &mut part).unwrap();
+        if name == "content.xml" {
+            let text = String::from_utf8(part).unwrap();
+            let start = text.find("svg:width=\"").unwrap() + 11;
+            let end = start + text[start..].find('"').unwrap();
+            part = format!("{}99999999999cm{}", &text[..start], &text[end..]).into_bytes();
+        }
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        std::io::Write::write_all(&mut zout, &part).unwrap();
+    }
+    let data = zout.finish().unwrap().into_inner();
+    let book = reader::ods::read_reader(Cursor::new(data)).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.value("A1"), "Hello");
+    assert!(sheet.image_collection().is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_workbook() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = reader::xlsx::read(path).unwrap();
+
+    let json = serde_json::to_value(&book).unwrap();
+    let mut book: Workbook = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&book).unwrap(), json);
+
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.value("E12"), "英語");
+    assert!(sheet.cell("ZZ100").is_none());
+
+    book.sheet_mut(0).unwrap().cell_mut("A1").set_value("TEST2");
+    let path = std::path::Path::new("./tests/result_files/serde_workbook.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    assert_eq!(book.sheet(0).unwrap().value("A1"), "TEST2");
+
+    // A worksheet that is not read yet can not be serialized.
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let mut book = reader::xlsx::lazy_read(path).unwrap();
+    assert!(serde_json::to_string(&book).is_err());
+    book.read_sheet_collection();
+    assert!(serde_json::to_string(&book).is_ok());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_records() {
+    use serde_json::json;
+    use umya_spreadsheet::structs::{
+        ColumnSpec,
+        RecordColumn,
+        RecordsOption,
+    };
+
+    let records = vec![
+        json!({"name": "apple", "price": 1.5, "count": 3, "date": "2024-01-15", "ok": true}),
+        json!({"name": "pear", "price": null, "count": 10, "date": "2024-02-01T12:30:00"}),
+        json!({"name": "fig", "tags": ["a", "b"], "date": "unknown"}),
+    ];
+    let mut spec = ColumnSpec::default();
+    for (key, header, format) in [
+        ("name", "Name", None),
+        ("date", "Date", Some("yyyy-mm-dd")),
+        ("price", "Price", Some("#,##0.00")),
+        ("count", "Count", None),
+        ("ok", "OK", None),
+        ("tags", "Tags", None),
+    ] {
+        let mut column = RecordColumn::default();
+        column.set_key(key).set_header(header);
+        if let Some(v) = format {
+            column.set_number_format(v);
+        }
+        spec.add_column(column);
+    }
+    let mut style = Style::default();
+    style.font_mut().set_bold(true);
+    spec.set_header_style(style);
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.from_records(&records, &spec).unwrap();
+    assert_eq!(sheet.value("A1"), "Name");
+    assert_eq!(sheet.value("B1"), "Date");
+    assert!(sheet.style("C1").font().unwrap().bold());
+    assert_eq!(sheet.cell("B2").unwrap().value_number(), Some(45306.0));
+    assert_eq!(sheet.cell("B3").unwrap().value_number(), Some(45323.520833333336));
+    assert_eq!(sheet.value("B4"), "unknown");
+    assert_eq!(
+        sheet.style("C2").number_format().unwrap().format_code(),
+        "#,##0.00"
+    );
+    assert!(sheet.cell("C3").is_none());
+    assert_eq!(sheet.value("E2"), "TRUE");
+    assert_eq!(sheet.value("F4"), r#"["a","b"]"#);
+    sheet.cell_mut("G1").set_value("Error");
+    sheet.cell_mut("G2").set_error("#N/A");
+    assert!(sheet
+        .from_records(&[json!([1, 2])], &ColumnSpec::default())
+        .is_err());
+
+    let result = sheet.to_records(1, "A:G").unwrap();
+    assert_eq!(result.len(), 3);
+    assert_eq!(
+        serde_json::Value::Object(result[0].clone()),
+        json!({"Name": "apple", "Date": 45306, "Price": 1.5, "Count": 3, "OK": true,
+               "Tags": null, "Error": "#N/A"})
+    );
+    let mut option = RecordsOption::default();
+    option.set_date_as_iso(true);
+    let result = sheet.to_records_with_option(1, "A2:B4", &option).unwrap();
+    assert_eq!(result[0]["Date"], "2024-01-15");
+    assert_eq!(result[1]["Date"], "2024-02-01T12:30:00");
+    assert_eq!(result[2]["Date"], "unknown");
+    assert_eq!(sheet.to_records(1, "2:4").unwrap().len(), 3);
+    for range in ["", "A1:D", "B:A", "A0:B2", "A:XFE", "A1:B2:C3", "A1-B2"] {
+        assert!(
+            matches!(sheet.to_records(1, range), Err(XlsxError::InvalidRange(_))),
+            "{range}"
+        );
+    }
+
+    // Records of the keys in order when there are no columns.
+    let mut sheet = Worksheet::default();
+    sheet
+        .from_records(&[json!({"b": 1}), json!({"a": 2, "b": 3})], &ColumnSpec::default())
+        .unwrap();
+    assert_eq!(sheet.value("A1"), "b");
+    assert_eq!(sheet.value("B1"), "a");
+    assert_eq!(sheet.value("B3"), "2");
+
+    let path = std::path::Path::new("./tests/result_files/json_records.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let mut ndjson = Vec::new();
+    reader::xlsx::read_sheet_by_name_ndjson(path, "Sheet1", 1, &option, &mut ndjson).unwrap();
+    let lines: Vec<serde_json::Value> = String::from_utf8(ndjson)
+        .unwrap()
+        .lines()
+        .map(|v| serde_json::from_str(v).unwrap())
+        .collect();
+    let expected: Vec<serde_json::Value> = book
+        .sheet(0)
+        .unwrap()
+        .to_records_with_option(1, "A:G", &option)
+        .unwrap()
+        .into_iter()
+        .map(serde_json::Value::Object)
+        .collect();
+    assert_eq!(lines, expected);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_rows() {
+    use umya_spreadsheet::structs::{
+        RecordColumn,
+        RowMapping,
+    };
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Order {
+        name:     String,
+        price:    f64,
+        count:    i64,
+        paid:     bool,
+        note:     Option<String>,
+        date:     String,
+        discount: Option<f64>,
+    }
+
+    let orders = vec![
+        Order {
+            name:     "apple".to_string(),
+            price:    1.5,
+            count:    3,
+            paid:     true,
+            note:     Some("fresh".to_string()),
+            date:     "2024-01-15".to_string(),
+            discount: None,
+        },
+        Order {
+            name:     "pear".to_string(),
+            price:    2.0,
+            count:    10,
+            paid:     false,
+            note:     None,
+            date:     "2024-02-01T12:30:00".to_string(),
+            discount: Some(0.25),
+        },
+    ];
+    let mut mapping = RowMapping::default();
+    mapping.set_header_row(2);
+    let mut column = RecordColumn::default();
+    column
+        .set_key("date")
+        .set_header("Order Date")
+        .set_number_format("yyyy-mm-dd");
+    mapping.add_column(column);
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Orders");
+    sheet.write_rows(&orders, &mapping).unwrap();
+    assert_eq!(sheet.value("A2"), "name");
+    assert_eq!(sheet.value("F2"), "Order Date");
+    assert_eq!(sheet.cell("F3").unwrap().value_number(), Some(45306.0));
+    assert!(sheet.cell("E4").is_none());
+    assert!(sheet.write_rows(&[1, 2], &mapping).is_err());
+
+    let path = std::path::Path::new("./tests/result_files/serde_rows.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let rows: Vec<Order> = reader::xlsx::read_rows_as(path, "Sheet1", &mapping)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows, orders);
+
+    // The errors report the coordinate of the cell.
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("C3").set_value("many");
+    sheet.cell_mut("B4").set_error("#N/A");
+    sheet.cell_mut("C5").set_value_number(1.5);
+    writer::xlsx::write(&book, path).unwrap();
+    let errors: Vec<String> = reader::xlsx::read_rows_as::<Order, _>(path, "Sheet1", &mapping)
+        .unwrap()
+        .map(|v| v.unwrap_err().to_string())
+        .collect();
+    assert_eq!(errors.len(), 3);
+    assert!(errors[0].starts_with("Invalid cell C3:"));
+    assert!(errors[1].starts_with("Invalid cell B4:"));
+    assert!(errors[2].starts_with("Invalid cell B5:"));
+
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Weight {
+        weight: f64,
+    }
+    let error = reader::xlsx::read_rows_as::<Weight, _>(path, "Sheet1", &mapping)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(error.to_string(), "Invalid cell 3:3: missing field `weight`");
+    assert!(reader::xlsx::read_rows_as::<Order, _>(path, "Sheet2", &mapping).is_err());
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn read_and_write_async() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let data = std::fs::read(path).unwrap();
+    let book = reader::xlsx::read_async(std::io::Cursor::new(data), true)
+        .await
+        .unwrap();
+    assert_eq!(book.sheet_by_name("Sheet1").unwrap().value("E12"), "英語");
+
+    // Write to one end of a pipe while the other end reads it without seeking.
+    let (client, server) = tokio::io::duplex(1024);
+    let (written, read) = tokio::join!(
+        writer::xlsx::write_async(&book, client),
+        reader::xlsx::read_stream_async(server, true),
+    );
+    written.unwrap();
+    let read = read.unwrap();
+    assert_eq!(read.sheet_count(), book.sheet_count());
+    assert_eq!(read.sheet_by_name("Sheet1").unwrap().value("E12"), "英語");
+
+    let mut buffer = Vec::new();
+    writer::xlsx::write_async(&read, &mut buffer).await.unwrap();
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(buffer.clone()), true).unwrap();
+    assert_eq!(book.sheet_by_name("Sheet1").unwrap().value("E12"), "英語");
+
+    // The entries are written with data descriptors, and their CRC is still
+    // checked against the central directory.
+    let name = b"xl/workbook.xml";
+    let header = buffer
+        .windows(4)
+        .enumerate()
+        .filter(|(_, window)| window == b"PK\x01\x02")
+        .map(|(index, _)| index)
+        .find(|index| buffer[index + 46..].starts_with(name))
+        .unwrap();
+    assert_ne!(buffer[header + 8] & 0x08, 0);
+    buffer[header + 16] ^= 0xFF;
+    let (mut client, server) = tokio::io::duplex(1024);
+    let feed = async move {
+        tokio::io::AsyncWriteExt::write_all(&mut client, &buffer)
+            .await
+            .unwrap();
+    };
+    let ((), read) = tokio::join!(feed, reader::xlsx::read_stream_async(server, true));
+    assert!(read.unwrap_err().to_string().contains("Invalid checksum"));
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn read_async_in_chunks() {
+    use std::{
+        io::{
+            Cursor,
+            Read,
+            Seek,
+            SeekFrom,
+        },
+        pin::Pin,
+        task::{
+            Context,
+            Poll,
+        },
+    };
+
+    use tokio::io::{
+        AsyncRead,
+        AsyncSeek,
+        AsyncWriteExt,
+        ReadBuf,
+    };
+
+    /// A reader that returns a few bytes at a time.
+    struct Chunked(Cursor<Vec<u8>>);
+
+    impl AsyncRead for Chunked {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            let mut chunk = [0; 7];
+            let size = buf.remaining().min(chunk.len());
+            let size = self.0.read(&mut chunk[..size])?;
+            buf.put_slice(&chunk[..size]);
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    impl AsyncSeek for Chunked {
+        fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> std::io::Result<()> {
+            self.0.seek(position).map(|_| ())
+        }
+
+        fn poll_complete(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<u64>> {
+            Poll::Ready(Ok(self.0.position()))
+        }
+    }
+
+    let data = std::fs::read("./tests/test_files/aaa.xlsx").unwrap();
+    let book = reader::xlsx::read_async(Chunked(Cursor::new(data.clone())), true)
+        .await
+        .unwrap();
+    assert_eq!(book.sheet_by_name("Sheet1").unwrap().value("E12"), "英語");
+
+    // Feed one end of a pipe a few bytes at a time while the other end reads
+    // it.
+    let (mut client, server) = tokio::io::duplex(16);
+    let feed = async move {
+        for chunk in data.chunks(64) {
+            client.write_all(chunk).await.unwrap();
+            tokio::task::yield_now().await;
+        }
+    };
+    let ((), read) = tokio::join!(feed, reader::xlsx::read_stream_async(server, true));
+    let read = read.unwrap();
+    assert_eq!(read.sheet_count(), book.sheet_count());
+    assert_eq!(read.sheet_by_name("Sheet1").unwrap().value("E12"), "英語");
+}
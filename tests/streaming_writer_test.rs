@@ -34,6 +34,7 @@ const SHEETS: [&str; 3] = ["Sheet1", "Sheet2", "データ"];
 
 /// Build a deterministic, multi-sheet workbook covering the common cell value
 /// kinds (string, number, bool, formula) plus a non-ASCII string value.
+#[allow(clippy::approx_constant)]
 fn build_book() -> Workbook {
     let mut book = new_file(); // creates "Sheet1"
     book.new_sheet("Sheet2").unwrap();